@@ -29,3 +29,13 @@ pub const DEFAULT_LOGICAL_COMPACTION_WINDOW: Duration =
 /// `DEFAULT_LOGICAL_COMPACTION_WINDOW` as an `EpochMillis` timestamp
 pub const DEFAULT_LOGICAL_COMPACTION_WINDOW_TS: Timestamp =
     Timestamp::new(DEFAULT_LOGICAL_COMPACTION_WINDOW_MILLIS);
+
+/// Converts a logical compaction window into an `EpochMillis` timestamp,
+/// saturating windows too large to represent.
+pub fn compaction_window_ts(window: Duration) -> Timestamp {
+    Timestamp::new(u64::try_from(window.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// The default amount of history a durable subscription retains beyond its
+/// acknowledged frontier, if it does not specify `RETAIN HISTORY`.
+pub const DEFAULT_SUBSCRIPTION_RETAIN_HISTORY: Duration = Duration::from_secs(60 * 60);
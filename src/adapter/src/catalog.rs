@@ -59,7 +59,7 @@ use mz_repr::adt::mz_acl_item::{merge_mz_acl_items, AclMode, MzAclItem, Privileg
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::namespaces::MZ_TEMP_SCHEMA;
 use mz_repr::role_id::RoleId;
-use mz_repr::{Diff, GlobalId, ScalarType};
+use mz_repr::{ColumnName, Diff, GlobalId, ScalarType};
 use mz_secrets::InMemorySecretsController;
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::catalog::{
@@ -80,6 +80,7 @@ use mz_sql::plan::{
 use mz_sql::session::user::{MZ_SUPPORT_ROLE_ID, MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
 use mz_sql::session::vars::{
     ConnectionCounter, OwnedVarInput, SystemVars, Var, VarInput, ENABLE_PERSIST_TXN_TABLES,
+    PERSIST_STATE_HISTORY_RETENTION,
 };
 use mz_sql::{plan, rbac, DEFAULT_SCHEMA};
 use mz_sql_parser::ast::{
@@ -241,6 +242,7 @@ pub struct ConnCatalog<'a> {
     database: Option<DatabaseId>,
     search_path: Vec<(ResolvedDatabaseSpecifier, SchemaSpecifier)>,
     role_id: RoleId,
+    is_superuser: bool,
     prepared_statements: Option<&'a BTreeMap<String, PreparedStatement>>,
     notices_tx: UnboundedSender<AdapterNotice>,
 }
@@ -396,6 +398,12 @@ impl Catalog {
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
+            CatalogType::Inet => CatalogType::Inet,
+            CatalogType::Cidr => CatalogType::Cidr,
+            CatalogType::MacAddr => CatalogType::MacAddr,
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
         };
 
         BuiltinType {
@@ -960,7 +968,7 @@ impl Catalog {
         let Some(schema) = self.state.temporary_schemas.remove(conn_id) else {
             return Ok(());
         };
-        if !schema.items.is_empty() {
+        if schema.has_items() {
             return Err(Error::new(ErrorKind::SchemaNotEmpty(MZ_TEMP_SCHEMA.into())));
         }
         Ok(())
@@ -1937,6 +1945,7 @@ impl Catalog {
                             serialized_item,
                             owner_id,
                             privileges.clone(),
+                            BTreeMap::new(),
                         )?;
                     }
 
@@ -2022,15 +2031,20 @@ impl Catalog {
                 }
                 Op::DropObject(id) => {
                     // Drop any associated comments.
-                    let comment_id = state.get_comment_id(id.clone());
-                    let deleted = tx.drop_comments(comment_id)?;
-                    let dropped = state.comments.drop_comments(comment_id);
-                    mz_ore::soft_assert_eq!(deleted, dropped, "transaction and state out of sync");
+                    if let Some(comment_id) = state.get_comment_id(id.clone()) {
+                        let deleted = tx.drop_comments(comment_id)?;
+                        let dropped = state.comments.drop_comments(comment_id);
+                        mz_ore::soft_assert_eq!(
+                            deleted,
+                            dropped,
+                            "transaction and state out of sync"
+                        );
 
-                    let updates = dropped.into_iter().map(|(id, col_pos, comment)| {
-                        state.pack_comment_update(id, col_pos, &comment, -1)
-                    });
-                    builtin_table_updates.extend(updates);
+                        let updates = dropped.into_iter().map(|(id, col_pos, comment)| {
+                            state.pack_comment_update(id, col_pos, &comment, -1)
+                        });
+                        builtin_table_updates.extend(updates);
+                    }
 
                     // Drop the object.
                     match id {
@@ -2489,6 +2503,46 @@ impl Catalog {
                         }),
                     )?;
                 }
+                Op::UpdateColumnPrivilege {
+                    id,
+                    column,
+                    privilege,
+                    variant,
+                } => {
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    let entry = state.get_entry_mut(&id);
+                    let privileges = entry.column_privileges.entry(column.clone()).or_default();
+                    match variant {
+                        UpdatePrivilegeVariant::Grant => privileges.grant(privilege.clone()),
+                        UpdatePrivilegeVariant::Revoke => privileges.revoke(&privilege),
+                    }
+                    if privileges.all_values().next().is_none() {
+                        entry.column_privileges.remove(&column);
+                    }
+                    if !entry.item().is_temporary() {
+                        tx.update_item(id, entry.clone().into())?;
+                    }
+                    builtin_table_updates.extend(state.pack_item_update(id, 1));
+                    let object_type = state.get_entry(&id).item().typ();
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        variant.into(),
+                        catalog_type_to_audit_object_type(object_type),
+                        EventDetails::UpdateColumnPrivilegeV1(
+                            mz_audit_log::UpdateColumnPrivilegeV1 {
+                                object_id: ObjectId::Item(id).to_string(),
+                                column_name: column.to_string(),
+                                grantee_id: privilege.grantee.to_string(),
+                                grantor_id: privilege.grantor.to_string(),
+                                privileges: privilege.acl_mode.to_string(),
+                            },
+                        ),
+                    )?;
+                }
                 Op::UpdateDefaultPrivilege {
                     privilege_object,
                     privilege_acl_item,
@@ -2810,7 +2864,7 @@ impl Catalog {
                     };
 
                     // Update all of the items in the schema.
-                    for (_name, item_id) in &schema.items {
+                    for (_name, item_id) in schema.items.iter().chain(&schema.functions) {
                         // Update the item itself.
                         update_item(item_id)?;
 
@@ -3020,6 +3074,13 @@ impl Catalog {
                                 entry.owner_id,
                                 new_owner,
                             );
+                            for column_privileges in entry.column_privileges.values_mut() {
+                                Self::update_privilege_owners(
+                                    column_privileges,
+                                    entry.owner_id,
+                                    new_owner,
+                                );
+                            }
                             entry.owner_id = new_owner;
                             if !entry.item().is_temporary() {
                                 tx.update_item(*id, entry.clone().into())?;
@@ -3076,6 +3137,21 @@ impl Catalog {
                     ));
                 }
                 Op::UpdateItem { id, name, to_item } => {
+                    // The rows describing the keys of the indexes on a table
+                    // depend on the table's columns.
+                    let index_ids: Vec<_> = match &to_item {
+                        CatalogItem::Table(_) => state
+                            .get_entry(&id)
+                            .used_by()
+                            .iter()
+                            .filter(|id| state.get_entry(id).is_index())
+                            .copied()
+                            .collect(),
+                        _ => vec![],
+                    };
+                    for index_id in &index_ids {
+                        builtin_table_updates.extend(state.pack_item_update(*index_id, -1));
+                    }
                     builtin_table_updates.extend(state.pack_item_update(id, -1));
                     Self::update_item(
                         state,
@@ -3084,10 +3160,19 @@ impl Catalog {
                         name.clone(),
                         to_item.clone(),
                     )?;
+                    for index_id in &index_ids {
+                        builtin_table_updates.extend(state.pack_item_update(*index_id, 1));
+                    }
                     let entry = state.get_entry(&id);
-                    tx.update_item(id, entry.clone().into())?;
+                    if !entry.item().is_temporary() {
+                        tx.update_item(id, entry.clone().into())?;
+                    }
 
-                    if Self::should_audit_log_item(&to_item) {
+                    // Subscriptions are updated whenever their consumer
+                    // acknowledges updates, which is too frequent to audit.
+                    if Self::should_audit_log_item(&to_item)
+                        && !matches!(to_item, CatalogItem::Subscription(_))
+                    {
                         let name = Self::full_name_detail(
                             &state
                                 .resolve_full_name(&name, session.map(|session| session.conn_id())),
@@ -3127,10 +3212,18 @@ impl Catalog {
                 Op::ResetSystemConfiguration { name } => {
                     state.remove_system_configuration(&name)?;
                     tx.remove_system_config(&name);
+                    if name == PERSIST_STATE_HISTORY_RETENTION.name() {
+                        tx.set_history_retention(
+                            state.system_config().persist_state_history_retention(),
+                        );
+                    }
                 }
                 Op::ResetAllSystemConfiguration => {
                     state.clear_system_configuration();
                     tx.clear_system_configs();
+                    tx.set_history_retention(
+                        state.system_config().persist_state_history_retention(),
+                    );
                 }
                 Op::UpdateRotatedKeys {
                     id,
@@ -3196,7 +3289,12 @@ impl Catalog {
             &old_entry.name().qualifiers.schema_spec,
             conn_id,
         );
-        schema.items.remove(&old_entry.name().item);
+        let items = if let CatalogItem::Func(_) = old_entry.item() {
+            &mut schema.functions
+        } else {
+            &mut schema.items
+        };
+        items.remove(&old_entry.name().item);
 
         // Dropped deps
         let dropped_references: Vec<_> = old_entry
@@ -3230,8 +3328,14 @@ impl Catalog {
         let mut new_entry = old_entry.clone();
         new_entry.name = to_name;
         new_entry.item = to_item;
+        // Privileges on columns that the item no longer has are dropped with the columns.
+        if let Some(desc) = new_entry.item.desc_opt() {
+            new_entry
+                .column_privileges
+                .retain(|column, _| desc.get_by_name(column).is_some());
+        }
 
-        schema.items.insert(new_entry.name().item.clone(), id);
+        items.insert(new_entry.name().item.clone(), id);
 
         for u in dropped_references {
             // OK if we no longer have this entry because we are dropping our
@@ -3292,6 +3396,9 @@ impl Catalog {
                 state.system_configuration.enable_persist_txn_tables(),
             )?;
         }
+        if name == PERSIST_STATE_HISTORY_RETENTION.name() {
+            tx.set_history_retention(state.system_configuration.persist_state_history_retention());
+        }
         Ok(())
     }
 
@@ -3666,6 +3773,8 @@ pub(crate) fn system_object_type_to_audit_object_type(
             mz_sql::catalog::ObjectType::Database => ObjectType::Database,
             mz_sql::catalog::ObjectType::Schema => ObjectType::Schema,
             mz_sql::catalog::ObjectType::Func => ObjectType::Func,
+            mz_sql::catalog::ObjectType::Policy => ObjectType::Policy,
+            mz_sql::catalog::ObjectType::Subscription => ObjectType::Subscription,
         },
         SystemObjectType::System => ObjectType::System,
     }
@@ -3797,6 +3906,12 @@ pub enum Op {
         privilege: MzAclItem,
         variant: UpdatePrivilegeVariant,
     },
+    UpdateColumnPrivilege {
+        id: GlobalId,
+        column: ColumnName,
+        privilege: MzAclItem,
+        variant: UpdatePrivilegeVariant,
+    },
     UpdateDefaultPrivilege {
         privilege_object: DefaultPrivilegeObject,
         privilege_acl_item: DefaultPrivilegeAclItem,
@@ -3899,7 +4014,8 @@ impl ExprHumanizer for ConnCatalog<'_> {
             Record {
                 custom_id: Some(id),
                 ..
-            } => {
+            }
+            | Enum { custom_id: id, .. } => {
                 let item = self.get_item(id);
                 self.minimal_qualification(item.name()).to_string()
             }
@@ -3969,6 +4085,10 @@ impl SessionCatalog for ConnCatalog<'_> {
         &self.role_id
     }
 
+    fn is_superuser(&self) -> bool {
+        self.is_superuser
+    }
+
     fn get_prepared_statement_desc(&self, name: &str) -> Option<&StatementDesc> {
         self.prepared_statements
             .as_ref()
@@ -4359,7 +4479,7 @@ impl SessionCatalog for ConnCatalog<'_> {
     }
 
     fn get_item_comments(&self, id: &GlobalId) -> Option<&BTreeMap<Option<usize>, String>> {
-        let comment_id = self.state.get_comment_id(ObjectId::Item(*id));
+        let comment_id = self.state.get_comment_id(ObjectId::Item(*id))?;
         self.state.comments.get_object_comments(comment_id)
     }
 }
@@ -5647,6 +5767,9 @@ mod tests {
                             panic!("{typ:?} type found in {full_name}");
                         }
                         ScalarType::AclItem
+                        | ScalarType::Inet
+                        | ScalarType::Cidr
+                        | ScalarType::MacAddr
                         | ScalarType::Bool
                         | ScalarType::Int16
                         | ScalarType::Int32
@@ -5674,6 +5797,7 @@ mod tests {
                         | ScalarType::RegClass
                         | ScalarType::Int2Vector
                         | ScalarType::Range { .. }
+                        | ScalarType::Enum { .. }
                         | ScalarType::PgLegacyName => {}
                     }
                 }
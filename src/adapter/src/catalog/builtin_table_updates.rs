@@ -16,16 +16,16 @@ use mz_catalog::builtin::{
     MZ_AGGREGATES, MZ_ARRAY_TYPES, MZ_AUDIT_EVENTS, MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES,
     MZ_CLUSTERS, MZ_CLUSTER_LINKS, MZ_CLUSTER_REPLICAS, MZ_CLUSTER_REPLICA_METRICS,
     MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_COMMENTS, MZ_CONNECTIONS,
-    MZ_DATABASES, MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS,
-    MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES,
-    MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
-    MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS,
-    MZ_WEBHOOKS_SOURCES,
+    MZ_DATABASES, MZ_DEFAULT_PRIVILEGES, MZ_DURABLE_SUBSCRIPTIONS, MZ_EGRESS_IPS, MZ_ENUM_LABELS,
+    MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_INTERNAL_CLUSTER_REPLICAS, MZ_KAFKA_CONNECTIONS,
+    MZ_KAFKA_SINKS, MZ_KAFKA_SOURCES, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS,
+    MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS, MZ_POLICIES, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES,
+    MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCES,
+    MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES,
+    MZ_TABLES, MZ_TYPES, MZ_TYPE_PG_METADATA, MZ_VIEWS, MZ_WEBHOOKS_SOURCES,
 };
 use mz_catalog::memory::error::{Error, ErrorKind};
-use mz_catalog::memory::objects::Table;
+use mz_catalog::memory::objects::{Policy, Subscription, Table};
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_controller::clusters::{
     ClusterStatus, ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ProcessId,
@@ -151,6 +151,7 @@ impl CatalogState {
                         Datum::UInt32(role.oid),
                         Datum::String(&role.name),
                         Datum::from(role.attributes.inherit),
+                        Datum::from(role.attributes.bypass_rls),
                     ]),
                     diff,
                 })
@@ -409,7 +410,7 @@ impl CatalogState {
                     self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
                 }
                 CatalogItem::Func(func) => {
-                    self.pack_func_update(id, schema_id, name, owner_id, func, diff)
+                    self.pack_func_update(id, oid, schema_id, name, owner_id, func, diff)
                 }
                 CatalogItem::Secret(_) => {
                     self.pack_secret_update(id, oid, schema_id, name, owner_id, privileges, diff)
@@ -417,6 +418,18 @@ impl CatalogState {
                 CatalogItem::Connection(connection) => self.pack_connection_update(
                     id, oid, schema_id, name, owner_id, privileges, connection, diff,
                 ),
+                CatalogItem::Policy(policy) => {
+                    self.pack_policy_update(id, oid, schema_id, name, owner_id, policy, diff)
+                }
+                CatalogItem::Subscription(subscription) => self.pack_subscription_update(
+                    id,
+                    oid,
+                    schema_id,
+                    name,
+                    owner_id,
+                    subscription,
+                    diff,
+                ),
             };
 
         if !entry.item().is_temporary() {
@@ -439,6 +452,10 @@ impl CatalogState {
                     .map(|d| Datum::String(d))
                     .unwrap_or(Datum::Null);
                 let pgtype = mz_pgrepr::Type::from(&column_type.scalar_type);
+                let column_privileges_row = match entry.column_privileges().get(column_name) {
+                    Some(column_privileges) => self.pack_privilege_array_row(column_privileges),
+                    None => self.pack_privilege_array_row(&PrivilegeMap::new()),
+                };
                 updates.push(BuiltinTableUpdate {
                     id: self.resolve_builtin_table(&MZ_COLUMNS),
                     row: Row::pack_slice(&[
@@ -450,6 +467,7 @@ impl CatalogState {
                         default,
                         Datum::UInt32(pgtype.oid()),
                         Datum::Int32(pgtype.typmod()),
+                        column_privileges_row.unpack_first(),
                     ]),
                     diff,
                 });
@@ -1040,6 +1058,20 @@ impl CatalogState {
             diff,
         });
 
+        if let CatalogType::Enum { labels } = &typ.details.typ {
+            for (position, label) in labels.iter().enumerate() {
+                out.push(BuiltinTableUpdate {
+                    id: self.resolve_builtin_table(&MZ_ENUM_LABELS),
+                    row: Row::pack_slice(&[
+                        Datum::String(&id.to_string()),
+                        Datum::UInt64(u64::cast_from(position)),
+                        Datum::String(label),
+                    ]),
+                    diff,
+                });
+            }
+        }
+
         if let Some(pg_metadata) = &typ.details.pg_metadata {
             out.push(BuiltinTableUpdate {
                 id: self.resolve_builtin_table(&MZ_TYPE_PG_METADATA),
@@ -1058,6 +1090,7 @@ impl CatalogState {
     fn pack_func_update(
         &self,
         id: GlobalId,
+        oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
         owner_id: &RoleId,
@@ -1065,7 +1098,13 @@ impl CatalogState {
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         let mut updates = vec![];
-        for func_impl_details in func.inner.func_impls() {
+        for mut func_impl_details in func.inner().func_impls() {
+            // Builtin function implementations carry their own OIDs, while
+            // user-defined functions have a single implementation that is
+            // identified by the OID of the catalog entry.
+            if func.create_sql.is_some() {
+                func_impl_details.oid = oid;
+            }
             let arg_type_ids = func_impl_details
                 .arg_typs
                 .iter()
@@ -1112,7 +1151,7 @@ impl CatalogState {
                 diff,
             });
 
-            if let mz_sql::func::Func::Aggregate(_) = func.inner {
+            if let mz_sql::func::Func::Aggregate(_) = func.inner() {
                 updates.push(BuiltinTableUpdate {
                     id: self.resolve_builtin_table(&MZ_AGGREGATES),
                     row: Row::pack_slice(&[
@@ -1169,6 +1208,59 @@ impl CatalogState {
         }
     }
 
+    fn pack_policy_update(
+        &self,
+        id: GlobalId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        policy: &Policy,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate> {
+        vec![BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_POLICIES),
+            row: Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                Datum::String(&policy.on.to_string()),
+                Datum::String(&owner_id.to_string()),
+                Datum::String(&policy.using.to_ast_string_stable()),
+            ]),
+            diff,
+        }]
+    }
+
+    fn pack_subscription_update(
+        &self,
+        id: GlobalId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        subscription: &Subscription,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate> {
+        vec![BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_DURABLE_SUBSCRIPTIONS),
+            row: Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                Datum::String(&subscription.on.to_string()),
+                Datum::String(&owner_id.to_string()),
+                match subscription.acknowledged {
+                    Some(acknowledged) => Datum::MzTimestamp(acknowledged),
+                    None => Datum::Null,
+                },
+            ]),
+            diff,
+        }]
+    }
+
     fn pack_secret_update(
         &self,
         id: GlobalId,
@@ -1373,12 +1465,14 @@ impl CatalogState {
 
     pub fn pack_session_update(&self, conn: &ConnMeta, diff: Diff) -> BuiltinTableUpdate {
         let connect_dt = mz_ore::now::to_datetime(conn.connected_at());
+        let client_ip = conn.client_ip().map(|ip| ip.to_string());
         BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_SESSIONS),
             row: Row::pack_slice(&[
                 Datum::UInt32(conn.conn_id().unhandled()),
                 Datum::String(&conn.authenticated_role_id().to_string()),
                 Datum::TimestampTz(connect_dt.try_into().expect("must fit")),
+                Datum::from(client_ip.as_deref()),
             ]),
             diff,
         }
@@ -427,7 +427,7 @@ impl CatalogState {
                 }
 
                 // Make sure the items in the schema are consistent.
-                for (item_name, item_id) in &schema.items {
+                for (item_name, item_id) in schema.items.iter().chain(&schema.functions) {
                     let Some(entry) = self.entry_by_id.get(item_id) else {
                         item_inconsistencies.push(ItemInconsistency::NonExistentItem {
                             db_id: *db_id,
@@ -488,6 +488,9 @@ impl CatalogState {
                         )
                         | Statement::CreateTable(ast::CreateTableStatement { name, .. })
                         | Statement::CreateType(ast::CreateTypeStatement { name, .. })
+                        | Statement::CreateFunction(ast::CreateFunctionStatement {
+                            name, ..
+                        })
                         | Statement::CreateSecret(ast::CreateSecretStatement { name, .. }) => {
                             let [db_component, schema_component, item_component] = &name.0[..]
                             else {
@@ -30,8 +30,8 @@ use mz_catalog::durable::objects::{
 use mz_catalog::durable::{Transaction, SYSTEM_CLUSTER_ID_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, CommentsMap, DataSourceDesc, Database, DefaultPrivileges, Func, Log,
-    Role, Schema, Source, Table, Type,
+    CatalogEntry, CatalogItem, CommentsMap, DataSourceDesc, Database, DefaultPrivileges, Func,
+    FuncInner, Log, Role, Schema, Source, Table, Type,
 };
 use mz_catalog::SYSTEM_CONN_ID;
 use mz_cluster_client::ReplicaId;
@@ -45,7 +45,7 @@ use mz_ore::now::to_datetime;
 use mz_pgrepr::oid::FIRST_USER_OID;
 use mz_repr::adt::mz_acl_item::PrivilegeMap;
 use mz_repr::role_id::RoleId;
-use mz_repr::GlobalId;
+use mz_repr::{ColumnName, GlobalId};
 use mz_sql::catalog::{
     CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem, CatalogItemType, CatalogSchema,
     CatalogType,
@@ -88,6 +88,7 @@ pub struct BuiltinMigrationMetadata {
         QualifiedItemName,
         RoleId,
         PrivilegeMap,
+        BTreeMap<ColumnName, PrivilegeMap>,
         CatalogItemRebuilder,
     )>,
     pub introspection_source_index_updates:
@@ -399,6 +400,7 @@ impl Catalog {
             // Now that LD is loaded, set the intended catalog timeout.
             // TODO: Move this into the catalog constructor.
             txn.set_connection_timeout(state.system_config().crdb_connect_timeout());
+            txn.set_history_retention(state.system_config().persist_state_history_retention());
 
             // Add any new builtin Clusters or Cluster Replicas that may be newly defined.
             if !is_read_only {
@@ -598,7 +600,11 @@ impl Catalog {
                                 id,
                                 oid,
                                 name.clone(),
-                                CatalogItem::Func(Func { inner: func.inner }),
+                                CatalogItem::Func(Func {
+                                    create_sql: None,
+                                    inner: FuncInner::Builtin(func.inner),
+                                    resolved_ids: ResolvedIds(BTreeSet::new()),
+                                }),
                                 MZ_SYSTEM_ROLE_ID,
                                 PrivilegeMap::default(),
                             );
@@ -1400,7 +1406,9 @@ impl Catalog {
                 CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
-                | CatalogItem::Connection(_) => unreachable!(
+                | CatalogItem::Connection(_)
+                | CatalogItem::Policy(_)
+                | CatalogItem::Subscription(_) => unreachable!(
                     "impossible to migrate schema for builtin {}",
                     entry.item().typ()
                 ),
@@ -1425,6 +1433,7 @@ impl Catalog {
                 name,
                 entry.owner_id().clone(),
                 entry.privileges().clone(),
+                entry.column_privileges().clone(),
                 item_rebuilder,
             ));
         }
@@ -1470,11 +1479,12 @@ impl Catalog {
         for id in migration_metadata.all_drop_ops.drain(..) {
             state.drop_item(id);
         }
-        for (id, oid, name, owner_id, privileges, item_rebuilder) in
+        for (id, oid, name, owner_id, privileges, column_privileges, item_rebuilder) in
             migration_metadata.all_create_ops.drain(..)
         {
             let item = item_rebuilder.build(state);
             state.insert_item(id, oid, name, item, owner_id, privileges);
+            state.get_entry_mut(&id).column_privileges = column_privileges;
         }
         for (cluster_id, updates) in &migration_metadata.introspection_source_index_updates {
             let log_indexes = &mut state
@@ -1509,6 +1519,16 @@ impl Catalog {
                 serialized_item,
                 entry.owner_id().clone(),
                 entry.privileges().all_values_owned().collect(),
+                entry
+                    .column_privileges()
+                    .iter()
+                    .map(|(column, privileges)| {
+                        (
+                            column.as_str().to_string(),
+                            privileges.all_values_owned().collect(),
+                        )
+                    })
+                    .collect(),
             )?;
         }
         txn.update_system_object_mappings(std::mem::take(
@@ -1634,6 +1654,16 @@ impl Catalog {
                 item.owner_id,
                 PrivilegeMap::from_mz_acl_items(item.privileges),
             );
+            state.get_entry_mut(&item.id).column_privileges = item
+                .column_privileges
+                .into_iter()
+                .map(|(column, privileges)| {
+                    (
+                        ColumnName::from(column),
+                        PrivilegeMap::from_mz_acl_items(privileges),
+                    )
+                })
+                .collect();
         }
 
         // Error on any unsatisfied dependencies.
@@ -1645,6 +1675,7 @@ impl Catalog {
                 create_sql: _,
                 owner_id: _,
                 privileges: _,
+                column_privileges: _,
             } = dependents.remove(0);
             let schema = state.find_non_temp_schema(&schema_id);
             let name = QualifiedItemName {
@@ -1673,6 +1704,7 @@ impl Catalog {
                 create_sql: _,
                 owner_id: _,
                 privileges: _,
+                column_privileges: _,
             } = dependents.remove(0);
             let schema = state.find_non_temp_schema(&schema_id);
             let name = QualifiedItemName {
@@ -1940,6 +1972,8 @@ mod builtin_migration_tests {
                         resolved_ids: ResolvedIds(BTreeSet::from_iter(resolved_ids)),
                         cluster_id: ClusterId::User(1),
                         non_null_assertions: vec![],
+                        custom_logical_compaction_window: None,
+                        refresh_schedule: None,
                     })
                 }
                 SimplifiedItem::Index { on } => {
@@ -2123,7 +2157,7 @@ mod builtin_migration_tests {
                 migration_metadata
                     .all_create_ops
                     .into_iter()
-                    .map(|(_, _, name, _, _, _)| name.item)
+                    .map(|(_, _, name, _, _, _, _)| name.item)
                     .collect::<Vec<_>>(),
                 test_case.expected_all_create_ops,
                 "{} test failed with wrong all create ops",
@@ -30,8 +30,8 @@ use mz_catalog::builtin::{Builtin, BuiltinCluster, BuiltinLog, BuiltinSource, Bu
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, Cluster, ClusterConfig, ClusterReplica, ClusterReplicaProcessStatus,
-    CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    Role, Schema, Secret, Sink, Source, Table, Type, View,
+    CommentsMap, Connection, DataSourceDesc, Database, DefaultPrivileges, Func, FuncInner, Index,
+    MaterializedView, Policy, Role, Schema, Secret, Sink, Source, Subscription, Table, Type, View,
 };
 use mz_catalog::{LINKED_CLUSTER_REPLICA_NAME, SYSTEM_CONN_ID};
 use mz_controller::clusters::{
@@ -62,9 +62,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreatePolicyPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateSubscriptionPlan,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
 use mz_sql::session::vars::{SystemVars, Var, VarInput, DEFAULT_DATABASE_NAME};
@@ -202,6 +202,7 @@ impl CatalogState {
             database,
             search_path,
             role_id: session.current_role_id().clone(),
+            is_superuser: session.is_superuser(),
             prepared_statements: Some(session.prepared_statements()),
             notices_tx: session.retain_notice_transmitter(),
         }
@@ -221,6 +222,7 @@ impl CatalogState {
             // Leaving the system's search path empty allows us to catch issues
             // where catalog object names have not been normalized correctly.
             search_path: Vec::new(),
+            is_superuser: role_id == MZ_SYSTEM_ROLE_ID,
             role_id,
             prepared_statements: None,
             notices_tx,
@@ -285,7 +287,9 @@ impl CatalogState {
             | CatalogItem::Source(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
-            | CatalogItem::Secret(_) => (),
+            | CatalogItem::Secret(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => (),
         }
     }
 
@@ -437,6 +441,9 @@ impl CatalogState {
             for item_id in schema.item_ids().values() {
                 dependents.extend_from_slice(&self.item_dependents(*item_id, seen));
             }
+            for function_id in schema.functions.values() {
+                dependents.extend_from_slice(&self.item_dependents(*function_id, seen));
+            }
             dependents.push(object_id)
         }
         dependents
@@ -826,7 +833,9 @@ impl CatalogState {
                 defaults: table.defaults,
                 conn_id: None,
                 resolved_ids,
-                custom_logical_compaction_window,
+                custom_logical_compaction_window: table
+                    .compaction_window
+                    .or(custom_logical_compaction_window),
                 is_retained_metrics_object,
             }),
             Plan::CreateSource(CreateSourcePlan {
@@ -869,7 +878,9 @@ impl CatalogState {
                 desc: source.desc,
                 timeline,
                 resolved_ids,
-                custom_logical_compaction_window,
+                custom_logical_compaction_window: source
+                    .compaction_window
+                    .or(custom_logical_compaction_window),
                 is_retained_metrics_object,
             }),
             Plan::CreateView(CreateViewPlan { view, .. }) => {
@@ -914,6 +925,10 @@ impl CatalogState {
                     resolved_ids,
                     cluster_id: materialized_view.cluster_id,
                     non_null_assertions: materialized_view.non_null_assertions,
+                    custom_logical_compaction_window: materialized_view
+                        .compaction_window
+                        .or(custom_logical_compaction_window),
+                    refresh_schedule: materialized_view.refresh_schedule,
                 })
             }
             Plan::CreateIndex(CreateIndexPlan { index, .. }) => CatalogItem::Index(Index {
@@ -956,6 +971,30 @@ impl CatalogState {
                 },
                 resolved_ids,
             }),
+            Plan::CreateFunction(CreateFunctionPlan { name, function, .. }) => {
+                CatalogItem::Func(Func {
+                    create_sql: Some(function.create_sql.clone()),
+                    inner: FuncInner::User(Arc::new(mz_sql::func::sql_function(
+                        &name.item, &function,
+                    ))),
+                    resolved_ids,
+                })
+            }
+            Plan::CreatePolicy(CreatePolicyPlan { policy, .. }) => CatalogItem::Policy(Policy {
+                create_sql: policy.create_sql,
+                on: policy.on,
+                using: policy.using,
+                resolved_ids,
+            }),
+            Plan::CreateSubscription(CreateSubscriptionPlan { subscription, .. }) => {
+                CatalogItem::Subscription(Subscription {
+                    create_sql: subscription.create_sql,
+                    on: subscription.on,
+                    acknowledged: subscription.acknowledged,
+                    retain_history: subscription.retain_history,
+                    resolved_ids,
+                })
+            }
             Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
                 create_sql: secret.create_sql,
             }),
@@ -1042,6 +1081,7 @@ impl CatalogState {
             referenced_by: Vec::new(),
             owner_id,
             privileges,
+            column_privileges: BTreeMap::new(),
         };
         for u in &entry.references().0 {
             match self.entry_by_id.get_mut(u) {
@@ -1113,10 +1153,17 @@ impl CatalogState {
             &metadata.name().qualifiers.schema_spec,
             conn_id,
         );
-        schema
-            .items
-            .remove(&metadata.name().item)
-            .expect("catalog out of sync");
+        if let CatalogItem::Func(_) = metadata.item() {
+            schema
+                .functions
+                .remove(&metadata.name().item)
+                .expect("catalog out of sync");
+        } else {
+            schema
+                .items
+                .remove(&metadata.name().item)
+                .expect("catalog out of sync");
+        }
 
         if !id.is_system() {
             if let Some(cluster_id) = metadata.item().cluster_id() {
@@ -1813,9 +1860,10 @@ impl CatalogState {
         )
     }
 
-    /// For an [`ObjectId`] gets the corresponding [`CommentObjectId`].
-    pub(super) fn get_comment_id(&self, object_id: ObjectId) -> CommentObjectId {
-        match object_id {
+    /// For an [`ObjectId`] gets the corresponding [`CommentObjectId`], or
+    /// `None` if the object cannot have comments.
+    pub(super) fn get_comment_id(&self, object_id: ObjectId) -> Option<CommentObjectId> {
+        let comment_id = match object_id {
             ObjectId::Item(global_id) => {
                 let entry = self.get_entry(&global_id);
                 match entry.item_type() {
@@ -1831,6 +1879,7 @@ impl CatalogState {
                     CatalogItemType::Connection => CommentObjectId::Connection(global_id),
                     CatalogItemType::Type => CommentObjectId::Type(global_id),
                     CatalogItemType::Secret => CommentObjectId::Secret(global_id),
+                    CatalogItemType::Policy | CatalogItemType::Subscription => return None,
                 }
             }
             ObjectId::Role(role_id) => CommentObjectId::Role(role_id),
@@ -1840,7 +1889,8 @@ impl CatalogState {
             ObjectId::ClusterReplica(cluster_replica_id) => {
                 CommentObjectId::ClusterReplica(cluster_replica_id)
             }
-        }
+        };
+        Some(comment_id)
     }
 
     /// Return current system configuration.
@@ -32,6 +32,7 @@ use mz_repr::{GlobalId, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement};
 use mz_sql::catalog::{EnvironmentId, SessionCatalog};
 use mz_sql::session::hint::ApplicationNameHint;
+use mz_sql::session::scram::ScramVerifier;
 use mz_sql::session::user::{User, SUPPORT_USER};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::parser::{ParserStatementError, StatementParseResult};
@@ -159,6 +160,7 @@ impl Client {
         let secret_key = session.secret_key();
         let uuid = session.uuid();
         let application_name = session.application_name().into();
+        let client_ip = session.client_ip();
         let notice_tx = session.retain_notice_transmitter();
 
         let (tx, rx) = oneshot::channel();
@@ -170,6 +172,7 @@ impl Client {
             secret_key,
             uuid,
             application_name,
+            client_ip,
             notice_tx,
         });
 
@@ -261,6 +264,19 @@ Issue a SQL query to get started. Need help?
         Ok(client)
     }
 
+    /// Returns the verifier of the password of the role named `role_name`, or
+    /// `None` if no such role exists or the role has no password.
+    ///
+    /// Used to authenticate connections before a session exists.
+    pub async fn role_password_verifier(&self, role_name: &str) -> Option<ScramVerifier> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::CatalogSnapshot { tx });
+        let CatalogSnapshot { catalog } = rx.await.expect("sender dropped");
+        catalog
+            .try_get_role_by_name(role_name)
+            .and_then(|role| role.attributes.password_verifier())
+    }
+
     /// Cancels the query currently running on the specified connection.
     pub fn cancel_request(&mut self, conn_id: ConnectionIdType, secret_key: u32) {
         self.send(Command::CancelRequest {
@@ -293,11 +309,13 @@ Issue a SQL query to get started. Need help?
             .execute(EMPTY_PORTAL.into(), futures::future::pending(), None)
             .await?
         {
-            (ExecuteResponse::SendingRows { future, span: _ }, _) => match future.await {
-                PeekResponseUnary::Rows(rows) => Ok(rows),
-                PeekResponseUnary::Canceled => bail!("query canceled"),
-                PeekResponseUnary::Error(e) => bail!(e),
-            },
+            (ExecuteResponse::SendingRows { rows, span: _ }, _) => {
+                match PeekResponseUnary::collect(rows).await {
+                    PeekResponseUnary::Rows(rows) => Ok(rows),
+                    PeekResponseUnary::Canceled => bail!("query canceled"),
+                    PeekResponseUnary::Error(e) => bail!(e),
+                }
+            }
             r => bail!("unsupported response type: {r:?}"),
         }
     }
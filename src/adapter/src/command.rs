@@ -9,21 +9,21 @@
 
 use std::any::Any;
 use std::collections::{BTreeMap, BTreeSet};
-use std::future::Future;
-use std::pin::Pin;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use derivative::Derivative;
 use enum_kinds::EnumKind;
 use futures::future::BoxFuture;
+use futures::Stream;
 use mz_adapter_types::connection::{ConnectionId, ConnectionIdType};
 use mz_ore::collections::CollectionExt;
 use mz_ore::soft_assert;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::role_id::RoleId;
-use mz_repr::{GlobalId, Row};
+use mz_repr::{GlobalId, RelationDesc, Row};
 use mz_sql::ast::{FetchDirection, Raw, Statement};
 use mz_sql::catalog::ObjectType;
 use mz_sql::plan::{ExecuteTimeout, Plan, PlanKind};
@@ -63,6 +63,7 @@ pub enum Command {
         secret_key: u32,
         uuid: Uuid,
         application_name: String,
+        client_ip: Option<IpAddr>,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
     },
 
@@ -174,7 +175,7 @@ pub struct Response<T> {
     pub session: Session,
 }
 
-pub type RowsFuture = Pin<Box<dyn Future<Output = PeekResponseUnary> + Send>>;
+pub type RowsStream = Box<dyn Stream<Item = PeekResponseUnary> + Unpin + Send + Sync>;
 
 /// The response to [`Client::startup`](crate::Client::startup).
 #[derive(Derivative)]
@@ -316,6 +317,12 @@ pub enum ExecuteResponse {
     CreatedMaterializedView,
     /// The requested type was created.
     CreatedType,
+    /// The requested function was created.
+    CreatedFunction,
+    /// The requested policy was created.
+    CreatedPolicy,
+    /// The requested subscription was created.
+    CreatedSubscription,
     /// The requested prepared statement was removed.
     Deallocate { all: bool },
     /// The requested cursor was declared.
@@ -348,6 +355,15 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// Updates to the requested relation will be delivered as notifications
+    /// on the specified channel.
+    Listening {
+        /// The name of the channel.
+        channel: String,
+        /// The description of the relation and a stream of its updates, or
+        /// `None` if the session was already listening on the channel.
+        updates: Option<(RelationDesc, RowBatchStream)>,
+    },
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -358,10 +374,10 @@ pub enum ExecuteResponse {
     RevokedPrivilege,
     /// The requested role was revoked.
     RevokedRole,
-    /// Rows will be delivered via the specified future.
+    /// Rows will be delivered via the specified stream.
     SendingRows {
         #[derivative(Debug = "ignore")]
-        future: RowsFuture,
+        rows: RowsStream,
         #[derivative(Debug = "ignore")]
         span: tracing::Span,
     },
@@ -396,6 +412,9 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The session stopped listening on the specified channel, or on all
+    /// channels if `None`.
+    Unlistened { channel: Option<String> },
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -484,6 +503,9 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
                 Ok(ExecuteResponse::CreatedMaterializedView)
             }
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
+            ExecuteResponseKind::CreatedFunction => Ok(ExecuteResponse::CreatedFunction),
+            ExecuteResponseKind::CreatedPolicy => Ok(ExecuteResponse::CreatedPolicy),
+            ExecuteResponseKind::CreatedSubscription => Ok(ExecuteResponse::CreatedSubscription),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
             ExecuteResponseKind::Deleted => Err(()),
@@ -496,6 +518,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listening => Err(()),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
@@ -507,6 +530,7 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Unlistened => Err(()),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsImmediate => Err(()),
@@ -543,6 +567,9 @@ impl ExecuteResponse {
             CreatedViews { .. } => Some("CREATE VIEWS".into()),
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
             CreatedType => Some("CREATE TYPE".into()),
+            CreatedFunction => Some("CREATE FUNCTION".into()),
+            CreatedPolicy => Some("CREATE POLICY".into()),
+            CreatedSubscription => Some("CREATE SUBSCRIPTION".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
@@ -564,6 +591,7 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listening { .. } => Some("LISTEN".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -576,6 +604,7 @@ impl ExecuteResponse {
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Unlistened { .. } => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
         }
@@ -601,6 +630,7 @@ impl ExecuteResponse {
             | AlterSchemaRename
             | AlterSchemaSwap
             | AlterSecret
+            | AlterSubscription
             | AlterSink
             | AlterConnection
             | AlterSource
@@ -634,6 +664,9 @@ impl ExecuteResponse {
             CreateMaterializedView => vec![CreatedMaterializedView],
             CreateIndex => vec![CreatedIndex],
             CreateType => vec![CreatedType],
+            CreateFunction => vec![CreatedFunction],
+            CreatePolicy => vec![CreatedPolicy],
+            CreateSubscription => vec![CreatedSubscription],
             PlanKind::Deallocate => vec![ExecuteResponseKind::Deallocate],
             Declare => vec![DeclaredCursor],
             DiscardTemp => vec![DiscardedTemp],
@@ -656,6 +689,7 @@ impl ExecuteResponse {
             GrantPrivileges => vec![GrantedPrivilege],
             GrantRole => vec![GrantedRole],
             Insert => vec![Inserted, SendingRowsImmediate],
+            Listen => vec![Listening],
             PlanKind::Prepare => vec![ExecuteResponseKind::Prepare],
             PlanKind::Raise => vec![ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => vec![ExecuteResponseKind::ReassignOwned],
@@ -666,6 +700,7 @@ impl ExecuteResponse {
             }
             PlanKind::Subscribe => vec![Subscribing, CopyTo],
             StartTransaction => vec![StartedTransaction],
+            Unlisten => vec![Unlistened],
             SideEffectingFunc => vec![SendingRowsImmediate],
             ValidateConnection => vec![ExecuteResponseKind::ValidatedConnection],
         }
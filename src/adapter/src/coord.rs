@@ -67,7 +67,7 @@
 //!
 
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 use std::ops::Neg;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
@@ -209,6 +209,14 @@ pub enum Message<T = mz_repr::Timestamp> {
     RemovePendingPeeks {
         conn_id: ConnectionId,
     },
+    /// Requests the next batch of rows of a streaming peek.
+    ContinuePeek {
+        uuid: Uuid,
+    },
+    /// Stops a streaming peek whose remaining rows are not wanted anymore.
+    StopPeek {
+        uuid: Uuid,
+    },
     LinearizeReads(Vec<PendingReadTxn>),
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
@@ -263,6 +271,8 @@ impl Message {
             Message::AdvanceTimelines => "advance_timelines",
             Message::ClusterEvent(_) => "cluster_event",
             Message::RemovePendingPeeks { .. } => "remove_pending_peeks",
+            Message::ContinuePeek { .. } => "continue_peek",
+            Message::StopPeek { .. } => "stop_peek",
             Message::LinearizeReads(_) => "linearize_reads",
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
@@ -568,6 +578,8 @@ pub struct ConnMeta {
     secret_key: u32,
     /// The time when the session's connection was initiated.
     connected_at: EpochMillis,
+    /// The IP address of the client, if known.
+    client_ip: Option<IpAddr>,
     user: User,
     application_name: String,
     uuid: Uuid,
@@ -577,6 +589,11 @@ pub struct ConnMeta {
     /// any, is cleared.
     drop_sinks: Vec<ComputeSinkId>,
 
+    /// Sinks that deliver notifications to the session, by channel. Unlike
+    /// `drop_sinks`, these outlive transactions. They are dropped by
+    /// `UNLISTEN` or when the session ends.
+    listen_sinks: BTreeMap<String, ComputeSinkId>,
+
     /// Channel on which to send notices to a session.
     notice_tx: mpsc::UnboundedSender<AdapterNotice>,
 
@@ -610,6 +627,10 @@ impl ConnMeta {
     pub fn connected_at(&self) -> EpochMillis {
         self.connected_at
     }
+
+    pub fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
 }
 
 #[derive(Debug)]
@@ -982,6 +1003,19 @@ pub struct Coordinator {
     /// A map from active subscribes to the subscribe description.
     active_subscribes: BTreeMap<GlobalId, ActiveSubscribe>,
 
+    /// Read holds that keep the history of durable subscriptions available,
+    /// keyed by the ID of the subscription.
+    subscription_read_holds:
+        BTreeMap<GlobalId, crate::coord::read_policy::ReadHolds<mz_repr::Timestamp>>,
+
+    /// Indexes on tables whose columns have been altered since the index's
+    /// dataflow was created.
+    ///
+    /// These keep arranging the table's previous columns, so they must not be
+    /// used to read the table. They are rebuilt for the table's current columns
+    /// when the coordinator restarts.
+    stale_indexes: BTreeSet<GlobalId>,
+
     /// Serializes accesses to write critical sections.
     write_lock: Arc<tokio::sync::Mutex<()>>,
     /// Holds plans deferred due to write lock.
@@ -1087,6 +1121,9 @@ impl Coordinator {
         // u64 anyway.
         let mut policies_to_set: BTreeMap<Timestamp, CollectionIdBundle> = Default::default();
         policies_to_set.insert(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS, Default::default());
+        // Materialized views with a refresh schedule additionally hold back their
+        // `since` to their latest refresh; see `update_refresh_schedule_read_policy`.
+        let mut refresh_policies = Vec::new();
 
         debug!("coordinator init: creating compute replicas");
         let mut replicas_to_start = vec![];
@@ -1329,11 +1366,15 @@ impl Coordinator {
                 }
                 CatalogItem::View(_) => (),
                 CatalogItem::MaterializedView(mview) => {
+                    let policy = policy.expect("materialized views have a compaction window");
                     policies_to_set
-                        .entry(policy.expect("materialized views have a compaction window"))
+                        .entry(policy)
                         .or_insert_with(Default::default)
                         .storage_ids
                         .insert(entry.id());
+                    if let Some(refresh_schedule) = &mview.refresh_schedule {
+                        refresh_policies.push((entry.id(), refresh_schedule.clone(), policy));
+                    }
 
                     let mut df_desc = self
                         .catalog()
@@ -1370,7 +1411,9 @@ impl Coordinator {
                 CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
-                | CatalogItem::Secret(_) => {}
+                | CatalogItem::Secret(_)
+                | CatalogItem::Policy(_)
+                | CatalogItem::Subscription(_) => {}
             }
         }
 
@@ -1396,12 +1439,18 @@ impl Coordinator {
         //
         // TODO -- Improve `initialize_read_policies` API so we can avoid calling this in a loop.
         //
-        // As of this writing, there can only be at most two keys in `policies_to_set`,
-        // so the extra load isn't crazy, but that might not be true in general if we
-        // open up custom compaction windows to users.
+        // Objects created with `RETAIN HISTORY` carry their own compaction window, so
+        // there is one key in `policies_to_set` per distinct window in use. In practice
+        // that is a handful, so the extra load isn't crazy.
+        //
+        // Durable subscriptions must hold back compaction before it is relaxed.
+        self.bootstrap_subscription_read_holds().await;
         for (ts, policies) in policies_to_set {
             self.initialize_read_policies(&policies, Some(ts)).await;
         }
+        for (id, refresh_schedule, compaction_window) in refresh_policies {
+            self.update_refresh_schedule_read_policy(id, refresh_schedule, compaction_window);
+        }
 
         debug!("coordinator init: announcing completion of initialization to controller");
         // Announce the completion of initialization.
@@ -1430,6 +1479,13 @@ impl Coordinator {
             .unwrap_or_terminate("cannot fail to append");
         self.apply_local_write(write_ts).await;
 
+        // Finish altering any tables whose alterations the catalog recorded,
+        // but that storage did not get to commit before the restart.
+        for id in self.controller.storage.uncommitted_table_alters() {
+            debug!("coordinator init: committing alteration of table {id}");
+            self.commit_alter_table(id).await;
+        }
+
         // Add builtin table updates the clear the contents of all system tables
         debug!("coordinator init: resetting system tables");
         let read_ts = self.get_local_read_ts().await;
@@ -1681,6 +1737,7 @@ impl Coordinator {
                         internal_view_id,
                         mv.desc.iter_names().cloned().collect(),
                         mv.non_null_assertions.clone(),
+                        mv.refresh_schedule.clone(),
                         debug_name,
                         optimizer_config.clone(),
                     );
@@ -2378,6 +2435,8 @@ pub fn serve(
                     client_pending_peeks: BTreeMap::new(),
                     pending_real_time_recency_timestamp: BTreeMap::new(),
                     active_subscribes: BTreeMap::new(),
+                    subscription_read_holds: BTreeMap::new(),
+                    stale_indexes: BTreeSet::new(),
                     write_lock: Arc::new(tokio::sync::Mutex::new(())),
                     write_lock_wait_group: VecDeque::new(),
                     pending_writes: Vec::new(),
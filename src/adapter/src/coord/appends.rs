@@ -9,17 +9,20 @@
 
 //! Logic and types for all appends executed by the [`Coordinator`].
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use std::time::Duration;
 
+use bytes::BytesMut;
 use derivative::Derivative;
 use futures::future::BoxFuture;
 use futures::FutureExt;
+use itertools::Itertools;
+use mz_catalog::memory::objects::CatalogItem;
 use mz_ore::metrics::MetricsFutureExt;
 use mz_ore::task;
 use mz_ore::vec::VecExt;
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
 use mz_sql::plan::Plan;
 use mz_storage_client::client::TimestamplessUpdate;
 use tokio::sync::{oneshot, Notify, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
@@ -28,7 +31,8 @@ use tracing::{warn, Instrument, Span};
 use crate::catalog::BuiltinTableUpdate;
 use crate::coord::timeline::WriteTimestamp;
 use crate::coord::{Coordinator, Message, PendingTxn, PlanValidity};
-use crate::session::{Session, WriteOp};
+use crate::error::AdapterError;
+use crate::session::{EndTransactionAction, Session, WriteOp};
 use crate::util::{CompletedClientTransmitter, ResultExt};
 use crate::ExecuteContext;
 
@@ -311,6 +315,7 @@ impl Coordinator {
         }
 
         let mut appends: BTreeMap<GlobalId, Vec<(Row, Diff)>> = BTreeMap::new();
+        let mut table_keys = BTreeMap::new();
         let mut responses = Vec::with_capacity(self.pending_writes.len());
         let should_block = pending_writes.iter().any(|write| write.should_block());
         let mut notifies = Vec::new();
@@ -327,6 +332,28 @@ impl Coordinator {
                             action,
                         },
                 } => {
+                    // If a table that some write was targeting has had columns added or
+                    // dropped while the write was waiting, then its rows no longer match the
+                    // table, and the entire transaction is rejected.
+                    if let Some(table) = self.altered_write_target(&writes) {
+                        responses.push(CompletedClientTransmitter::new(
+                            ctx,
+                            Err(AdapterError::ConcurrentTableAlter(table)),
+                            EndTransactionAction::Rollback,
+                        ));
+                        continue;
+                    }
+                    if let Err(e) = self
+                        .check_table_keys(&writes, timestamp, &mut table_keys)
+                        .await
+                    {
+                        responses.push(CompletedClientTransmitter::new(
+                            ctx,
+                            Err(e),
+                            EndTransactionAction::Rollback,
+                        ));
+                        continue;
+                    }
                     for WriteOp { id, rows } in writes {
                         // If the table that some write was targeting has been deleted while the
                         // write was waiting, then the write will be ignored and we respond to the
@@ -465,6 +492,110 @@ impl Coordinator {
             .expect("sending to self.internal_cmd_tx cannot fail");
     }
 
+    /// Returns the name of the first table targeted by `writes` whose columns
+    /// no longer match the rows being written to it, if any.
+    fn altered_write_target(&self, writes: &[WriteOp]) -> Option<String> {
+        writes.iter().find_map(|WriteOp { id, rows }| {
+            let entry = self.catalog().try_get_entry(id)?;
+            let CatalogItem::Table(table) = entry.item() else {
+                return None;
+            };
+            let arity = table.desc.arity();
+            rows.iter()
+                .any(|(row, _)| row.iter().count() != arity)
+                .then(|| entry.name().item.clone())
+        })
+    }
+
+    /// Checks that `writes` leave the values of each key of the tables they
+    /// write to unique.
+    ///
+    /// `table_keys` holds, for each table with keys written by the current group
+    /// commit, the values of its keys after the transactions accepted so far. A
+    /// table's entry is filled from a snapshot of the table just before
+    /// `timestamp` when the table is first written, and the entries are updated
+    /// if `writes` are accepted. Group commit holds the write lock while user
+    /// writes are applied, so no other writes can sneak in between the snapshot
+    /// and the append.
+    async fn check_table_keys(
+        &mut self,
+        writes: &[WriteOp],
+        timestamp: Timestamp,
+        table_keys: &mut BTreeMap<GlobalId, Vec<BTreeSet<Row>>>,
+    ) -> Result<(), AdapterError> {
+        let mut updates_by_table: BTreeMap<GlobalId, Vec<(Row, Diff)>> = BTreeMap::new();
+        for WriteOp { id, rows } in writes {
+            updates_by_table
+                .entry(*id)
+                .or_default()
+                .extend(rows.iter().cloned());
+        }
+
+        let mut changes = Vec::new();
+        for (id, mut updates) in updates_by_table {
+            let desc = match self.catalog().try_get_entry(&id).map(|entry| entry.item()) {
+                Some(CatalogItem::Table(table)) if !table.desc.typ().keys.is_empty() => {
+                    table.desc.clone()
+                }
+                _ => continue,
+            };
+            let keys = &desc.typ().keys;
+            differential_dataflow::consolidation::consolidate(&mut updates);
+            // Writes that only retract rows cannot produce duplicate keys.
+            if updates.iter().all(|(_, diff)| *diff < 0) {
+                continue;
+            }
+
+            if !table_keys.contains_key(&id) {
+                let snapshot = self
+                    .controller
+                    .storage
+                    .snapshot(id, timestamp.saturating_sub(1))
+                    .await
+                    .map_err(AdapterError::Storage)?;
+                let mut index = vec![BTreeSet::new(); keys.len()];
+                for (row, diff) in snapshot {
+                    if diff > 0 {
+                        for (key, index) in keys.iter().zip(index.iter_mut()) {
+                            index.insert(key_of(&row, key));
+                        }
+                    }
+                }
+                table_keys.insert(id, index);
+            }
+            let index = &table_keys[&id];
+
+            let mut retracted = vec![BTreeSet::new(); keys.len()];
+            for (row, _) in updates.iter().filter(|(_, diff)| *diff < 0) {
+                for (key, retracted) in keys.iter().zip(retracted.iter_mut()) {
+                    retracted.insert(key_of(row, key));
+                }
+            }
+            let mut added = vec![BTreeSet::new(); keys.len()];
+            for (row, diff) in updates.iter().filter(|(_, diff)| *diff > 0) {
+                for (i, key) in keys.iter().enumerate() {
+                    let values = key_of(row, key);
+                    let exists = index[i].contains(&values) && !retracted[i].contains(&values);
+                    if *diff > 1 || exists || !added[i].insert(values) {
+                        return Err(unique_violation(&desc, row, key));
+                    }
+                }
+            }
+            changes.push((id, retracted, added));
+        }
+
+        for (id, retracted, added) in changes {
+            let index = table_keys.get_mut(&id).expect("filled above");
+            for ((index, retracted), added) in index.iter_mut().zip(retracted).zip(added) {
+                for values in retracted {
+                    index.remove(&values);
+                }
+                index.extend(added);
+            }
+        }
+        Ok(())
+    }
+
     /// Submit a write to be executed during the next group commit and trigger a group commit.
     pub(crate) fn submit_write(&mut self, pending_write_txn: PendingWriteTxn) {
         self.pending_writes.push(pending_write_txn);
@@ -561,6 +692,33 @@ impl Coordinator {
     }
 }
 
+/// Returns the values of `row` for the columns of `key`.
+pub(crate) fn key_of(row: &Row, key: &[usize]) -> Row {
+    let datums = row.unpack();
+    Row::pack(key.iter().map(|idx| datums[*idx]))
+}
+
+/// Returns the error reported when `row` violates `key` of the table
+/// described by `desc`.
+pub(crate) fn unique_violation(desc: &RelationDesc, row: &Row, key: &[usize]) -> AdapterError {
+    let datums = row.unpack();
+    let columns = key.iter().map(|idx| desc.get_name(*idx).as_str());
+    let values = key.iter().map(|idx| {
+        match mz_pgrepr::Value::from_datum(datums[*idx], &desc.typ().column_types[*idx].scalar_type)
+        {
+            Some(value) => {
+                let mut buf = BytesMut::new();
+                value.encode_text(&mut buf);
+                String::from_utf8_lossy(&buf).into_owned()
+            }
+            None => "null".into(),
+        }
+    });
+    AdapterError::UniqueViolation {
+        key: format!("({})=({})", columns.format(", "), values.format(", ")),
+    }
+}
+
 /// Returns two sides of a "channel" that can be used to notify the coordinator when we want a
 /// group commit to be run.
 pub fn notifier() -> (GroupCommitNotifier, GroupCommitWaiter) {
@@ -11,7 +11,8 @@
 //! client via some external Materialize API (ex: HTTP and psql).
 
 use std::any::Any;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
@@ -26,14 +27,14 @@ use mz_repr::role_id::RoleId;
 use mz_sql::ast::{
     CopyRelation, CopyStatement, InsertSource, Query, Raw, SetExpr, Statement, SubscribeStatement,
 };
-use mz_sql::catalog::RoleAttributes;
+use mz_sql::catalog::{CatalogRole, RoleAttributes};
 use mz_sql::names::{PartialItemName, ResolvedIds};
 use mz_sql::plan::{
     AbortTransactionPlan, CommitTransactionPlan, CreateRolePlan, Params, Plan, TransactionType,
 };
 use mz_sql::rbac;
 use mz_sql::rbac::CREATE_ITEM_USAGE;
-use mz_sql::session::user::User;
+use mz_sql::session::user::{User, MZ_SYSTEM_ROLE_ID};
 use mz_sql::session::vars::{
     EndTransactionAction, OwnedVarInput, Var, STATEMENT_LOGGING_SAMPLE_RATE,
 };
@@ -75,6 +76,7 @@ impl Coordinator {
                     secret_key,
                     uuid,
                     application_name,
+                    client_ip,
                     notice_tx,
                 } => {
                     // Note: We purposefully do not use a ClientTransmitter here because startup
@@ -87,6 +89,7 @@ impl Coordinator {
                         secret_key,
                         uuid,
                         application_name,
+                        client_ip,
                         notice_tx,
                     )
                     .await;
@@ -240,6 +243,7 @@ impl Coordinator {
         secret_key: u32,
         uuid: uuid::Uuid,
         application_name: String,
+        client_ip: Option<IpAddr>,
         notice_tx: mpsc::UnboundedSender<AdapterNotice>,
     ) {
         // Early return if successful, otherwise cleanup any possible state.
@@ -272,7 +276,9 @@ impl Coordinator {
                     secret_key,
                     notice_tx,
                     drop_sinks: Vec::new(),
+                    listen_sinks: BTreeMap::new(),
                     connected_at: self.now(),
+                    client_ip,
                     user,
                     application_name,
                     uuid,
@@ -334,11 +340,71 @@ impl Coordinator {
             .try_get_role_by_name(&user.name)
             .expect("created above")
             .id;
+        if let Some(groups) = user
+            .external_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.groups.as_ref())
+        {
+            self.sync_role_membership_for_startup(role_id, groups)
+                .await?;
+        }
         self.catalog_mut()
             .create_temporary_schema(conn_id, role_id)?;
         Ok(role_id)
     }
 
+    /// Synchronizes the role memberships of `member_id` with the groups that an
+    /// external authentication provider reports for the user.
+    ///
+    /// The user is granted membership in each existing role named by a group,
+    /// and loses membership in every other role. Groups that do not name a role,
+    /// that name a reserved role, or that would make role membership circular
+    /// are ignored.
+    async fn sync_role_membership_for_startup(
+        &mut self,
+        member_id: RoleId,
+        groups: &[String],
+    ) -> Result<(), AdapterError> {
+        let catalog = self.catalog();
+        let group_role_ids: BTreeSet<_> = groups
+            .iter()
+            .filter_map(|group| catalog.try_get_role_by_name(group))
+            .map(|role| role.id)
+            .filter(|role_id| {
+                *role_id != member_id
+                    && catalog.ensure_not_reserved_role(role_id).is_ok()
+                    && !catalog
+                        .state()
+                        .collect_role_membership(role_id)
+                        .contains(&member_id)
+            })
+            .collect();
+        let membership = catalog.get_role(&member_id).membership();
+        let grants = group_role_ids
+            .iter()
+            .filter(|role_id| !membership.contains_key(role_id))
+            .map(|role_id| catalog::Op::GrantRole {
+                role_id: *role_id,
+                member_id,
+                grantor_id: MZ_SYSTEM_ROLE_ID,
+            });
+        let revokes = membership
+            .iter()
+            .filter(|(role_id, _)| !group_role_ids.contains(role_id))
+            .map(|(role_id, grantor_id)| catalog::Op::RevokeRole {
+                role_id: *role_id,
+                member_id,
+                grantor_id: *grantor_id,
+            });
+        let ops: Vec<_> = grants.chain(revokes).collect();
+        if ops.is_empty() {
+            return Ok(());
+        }
+        // Like creating the user's role, this is done by the system rather than
+        // a user request, so no session is attributed in the audit log.
+        self.catalog_transact(None, ops).await
+    }
+
     /// Handles an execute command.
     #[tracing::instrument(level = "debug", skip_all)]
     pub(crate) async fn handle_execute(
@@ -510,6 +576,7 @@ impl Coordinator {
                     | Statement::ExplainTimestamp(_)
                     | Statement::ExplainSinkSchema(_)
                     | Statement::Fetch(_)
+                    | Statement::Listen(_)
                     | Statement::Prepare(_)
                     | Statement::Rollback(_)
                     | Statement::Select(_)
@@ -519,6 +586,7 @@ impl Coordinator {
                     | Statement::ResetVariable(_)
                     | Statement::StartTransaction(_)
                     | Statement::Subscribe(_)
+                    | Statement::Unlisten(_)
                     | Statement::Raise(_) => {
                         // Always safe.
                     }
@@ -530,7 +598,7 @@ impl Coordinator {
                                 body: SetExpr::Values(..),
                                 ..
                             }) | InsertSource::DefaultValues
-                        ) =>
+                        ) && insert_statement.on_conflict.is_none() =>
                     {
                         // Inserting from default? values statements
                         // is always safe.
@@ -561,8 +629,10 @@ impl Coordinator {
                     | Statement::AlterOwner(_)
                     | Statement::AlterRole(_)
                     | Statement::AlterSecret(_)
+                    | Statement::AlterSubscription(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
+                    | Statement::AlterTable(_)
                     | Statement::AlterSystemReset(_)
                     | Statement::AlterSystemResetAll(_)
                     | Statement::AlterSystemSet(_)
@@ -580,6 +650,9 @@ impl Coordinator {
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
+                    | Statement::CreateFunction(_)
+                    | Statement::CreatePolicy(_)
+                    | Statement::CreateSubscription(_)
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::Delete(_)
@@ -781,6 +854,7 @@ impl Coordinator {
                 // so we don't need to do anything with `ctx_extra` here.
                 ctx_extra: _,
                 is_fast_path: _,
+                rows_returned: _,
             } in self.cancel_pending_peeks(&conn_id)
             {
                 // Cancel messages can be sent after the connection has hung
@@ -807,6 +881,7 @@ impl Coordinator {
         // We do not need to call clear_transaction here because there are no side effects to run
         // based on any session transaction state.
         self.clear_connection(&conn_id);
+        self.clear_listen_sinks(&conn_id);
 
         self.drop_temp_items(&conn_id).await;
         self.catalog_mut()
@@ -67,6 +67,15 @@ impl ComputeInstanceSnapshot {
             })
     }
 
+    /// Returns a snapshot of the instance that contains no collections, for
+    /// building dataflows that must not read from existing indexes.
+    pub fn new_without_collections(id: ComputeInstanceId) -> Self {
+        ComputeInstanceSnapshot {
+            instance_id: id,
+            collections: BTreeSet::new(),
+        }
+    }
+
     /// Return the ID of this compute instance.
     pub fn instance_id(&self) -> ComputeInstanceId {
         self.instance_id
@@ -110,6 +119,12 @@ pub enum ExprPrepStyle<'a> {
         session: &'a Session,
         catalog_state: &'a CatalogState,
     },
+    /// The expression is being prepared for installation as a SUBSCRIBE in
+    /// the specified session.
+    Subscribe {
+        session: &'a Session,
+        catalog_state: &'a CatalogState,
+    },
     /// The expression is being prepared for evaluation in an AS OF or UP TO clause.
     AsOfUpTo,
     /// The expression is being prepared for evaluation in a CHECK expression of a webhook source.
@@ -138,11 +153,18 @@ impl Coordinator {
     }
 
     /// Return a reference-less snapshot to the indicated compute instance.
+    ///
+    /// Stale indexes on altered tables are left out, so that they are not used
+    /// to read their tables.
     pub fn instance_snapshot(
         &self,
         id: ComputeInstanceId,
     ) -> Result<ComputeInstanceSnapshot, InstanceMissing> {
-        ComputeInstanceSnapshot::new(&self.controller, id)
+        let mut snapshot = ComputeInstanceSnapshot::new(&self.controller, id)?;
+        snapshot
+            .collections
+            .retain(|id| !self.stale_indexes.contains(id));
+        Ok(snapshot)
     }
 
     /// Call into the compute controller to install a finalized dataflow, and
@@ -408,7 +430,9 @@ impl<'a> DataflowBuilder<'a> {
                 | CatalogItem::Log(_)
                 | CatalogItem::Index(_)
                 | CatalogItem::Sink(_)
-                | CatalogItem::Func(_) => Ok(false),
+                | CatalogItem::Func(_)
+                | CatalogItem::Policy(_)
+                | CatalogItem::Subscription(_) => Ok(false),
             }
         })
     }
@@ -449,6 +473,7 @@ pub fn prep_relation_expr(
             })
         }
         ExprPrepStyle::OneShot { .. }
+        | ExprPrepStyle::Subscribe { .. }
         | ExprPrepStyle::AsOfUpTo
         | ExprPrepStyle::WebhookValidation { .. } => expr
             .0
@@ -462,6 +487,8 @@ pub fn prep_relation_expr(
 /// How we prepare the scalar expression depends on which `style` is specificed.
 ///
 /// * `OneShot`: Calls to all unmaterializable functions are replaced.
+/// * `Subscribe`: Calls to all unmaterializable functions except `mz_now()` and
+///   `now()` are replaced, as the latter change over the SUBSCRIBE's lifetime.
 /// * `Index`: An error is produced if a call to an unmaterializable function is encountered.
 /// * `AsOfUpTo`: An error is produced if a call to an unmaterializable function is encountered.
 /// * `WebhookValidation`: Only calls to `UnmaterializableFunc::CurrentTimestamp` are replaced,
@@ -485,6 +512,29 @@ pub fn prep_scalar_expr(
             Ok(())
         }),
 
+        // Evaluate the unmaterializable functions that are fixed for the
+        // lifetime of the session, e.g. those used by row-level security
+        // policies.
+        ExprPrepStyle::Subscribe {
+            session,
+            catalog_state,
+        } => expr.try_visit_mut_post(&mut |e| {
+            if let MirScalarExpr::CallUnmaterializable(f) = e {
+                if !matches!(
+                    f,
+                    UnmaterializableFunc::MzNow | UnmaterializableFunc::CurrentTimestamp
+                ) {
+                    *e = eval_unmaterializable_func(
+                        catalog_state,
+                        f,
+                        EvalTime::NotAvailable,
+                        session,
+                    )?;
+                }
+            }
+            Ok(())
+        }),
+
         // Reject the query if it contains any unmaterializable function calls.
         ExprPrepStyle::Index | ExprPrepStyle::AsOfUpTo => {
             let mut last_observed_unmaterializable_func = None;
@@ -600,6 +650,12 @@ fn eval_unmaterializable_func(
         UnmaterializableFunc::ViewableVariables => pack_dict(
             viewable_variables(state, session)
                 .map(|var| (var.name().to_lowercase(), var.value()))
+                .chain(
+                    session
+                        .vars()
+                        .custom_vars()
+                        .map(|(name, value)| (name.to_string(), value.to_string())),
+                )
                 .collect(),
         ),
         UnmaterializableFunc::CurrentTimestamp => {
@@ -615,6 +671,13 @@ fn eval_unmaterializable_func(
         UnmaterializableFunc::IsRbacEnabled => pack(Datum::from(
             rbac::is_rbac_enabled_for_session(state.system_config(), session.vars()),
         )),
+        UnmaterializableFunc::MzBypassesRls => pack(Datum::from(
+            session.is_superuser()
+                || state
+                    .get_role(session.current_role_id())
+                    .attributes
+                    .bypass_rls,
+        )),
         UnmaterializableFunc::MzEnvironmentId => {
             pack(Datum::from(&*state.config().environment_id.to_string()))
         }
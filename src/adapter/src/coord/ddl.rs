@@ -175,6 +175,7 @@ impl Coordinator {
         let mut indexes_to_drop = vec![];
         let mut materialized_views_to_drop = vec![];
         let mut views_to_drop = vec![];
+        let mut subscriptions_to_drop = vec![];
         let mut replication_slots_to_drop: Vec<(mz_postgres_util::Config, String)> = vec![];
         let mut secrets_to_drop = vec![];
         let mut vpc_endpoints_to_drop = vec![];
@@ -238,6 +239,7 @@ impl Coordinator {
                             materialized_views_to_drop.push((*cluster_id, *id));
                         }
                         CatalogItem::View(_) => views_to_drop.push(*id),
+                        CatalogItem::Subscription(_) => subscriptions_to_drop.push(*id),
                         CatalogItem::Secret(_) => {
                             secrets_to_drop.push(*id);
                         }
@@ -447,6 +449,9 @@ impl Coordinator {
                     assert_eq!(should_be_empty, became_empty, "emptiness did not match!");
                 }
             }
+            for id in subscriptions_to_drop {
+                self.release_subscription_read_holds(&id);
+            }
             if !sources_to_drop.is_empty() {
                 self.drop_sources(sources_to_drop);
             }
@@ -464,6 +469,9 @@ impl Coordinator {
                         conn_meta
                             .drop_sinks
                             .retain(|sink| !subscribe_sinks_to_drop.contains(sink));
+                        conn_meta
+                            .listen_sinks
+                            .retain(|_, sink| !subscribe_sinks_to_drop.contains(sink));
                         // Send notice on a best effort basis.
                         let _ = conn_meta
                             .notice_tx
@@ -696,6 +704,7 @@ impl Coordinator {
     pub(crate) fn drop_indexes(&mut self, indexes: Vec<(ClusterId, GlobalId)>) {
         let mut by_cluster: BTreeMap<_, Vec<_>> = BTreeMap::new();
         for (cluster_id, id) in indexes {
+            self.stale_indexes.remove(&id);
             if self.drop_compute_read_policy(&id) {
                 by_cluster.entry(cluster_id).or_default().push(id);
             } else {
@@ -1033,7 +1042,9 @@ impl Coordinator {
                         | CatalogItem::View(_)
                         | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
-                        | CatalogItem::Func(_) => {}
+                        | CatalogItem::Func(_)
+                        | CatalogItem::Policy(_)
+                        | CatalogItem::Subscription(_) => {}
                     }
                 }
                 Op::DropObject(id) => match id {
@@ -1098,7 +1109,9 @@ impl Coordinator {
                             | CatalogItem::View(_)
                             | CatalogItem::Index(_)
                             | CatalogItem::Type(_)
-                            | CatalogItem::Func(_) => {}
+                            | CatalogItem::Func(_)
+                            | CatalogItem::Policy(_)
+                            | CatalogItem::Subscription(_) => {}
                         }
                     }
                 },
@@ -1126,13 +1139,16 @@ impl Coordinator {
                     | CatalogItem::View(_)
                     | CatalogItem::Index(_)
                     | CatalogItem::Type(_)
-                    | CatalogItem::Func(_) => {}
+                    | CatalogItem::Func(_)
+                    | CatalogItem::Policy(_)
+                    | CatalogItem::Subscription(_) => {}
                 },
                 Op::AlterRole { .. }
                 | Op::AlterSink { .. }
                 | Op::AlterSetCluster { .. }
                 | Op::AlterSource { .. }
                 | Op::UpdatePrivilege { .. }
+                | Op::UpdateColumnPrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
                 | Op::GrantRole { .. }
                 | Op::RenameCluster { .. }
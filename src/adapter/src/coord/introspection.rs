@@ -22,7 +22,8 @@ use mz_expr::CollectionPlan;
 use mz_repr::GlobalId;
 use mz_sql::catalog::SessionCatalog;
 use mz_sql::plan::{
-    ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, Plan, SubscribeFrom,
+    ExplainPlanPlan, ExplainTimestampPlan, Explainee, ExplaineeStatement, ListenPlan, Plan,
+    SubscribeFrom,
 };
 use smallvec::SmallVec;
 
@@ -45,7 +46,10 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
             plan.source.depends_on(),
             plan.source.could_run_expensive_function(),
         ),
-        Plan::Subscribe(plan) => (
+        Plan::Subscribe(plan)
+        | Plan::Listen(ListenPlan {
+            subscribe: plan, ..
+        }) => (
             plan.from.depends_on(),
             match &plan.from {
                 SubscribeFrom::Id(_) => false,
@@ -78,6 +82,9 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreatePolicy(_)
+        | Plan::CreateSubscription(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -107,6 +114,7 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::AlterIndexSetOptions(_)
         | Plan::AlterIndexResetOptions(_)
         | Plan::AlterSink(_)
+        | Plan::AlterTable(_)
         | Plan::AlterConnection(_)
         | Plan::AlterSource(_)
         | Plan::PurifiedAlterSource { .. }
@@ -116,6 +124,7 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterSubscription(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
         | Plan::AlterSystemResetAll(_)
@@ -124,6 +133,7 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::Declare(_)
         | Plan::Fetch(_)
         | Plan::Close(_)
+        | Plan::Unlisten(_)
         | Plan::ReadThenWrite(_)
         | Plan::Prepare(_)
         | Plan::Execute(_)
@@ -209,7 +219,10 @@ pub fn check_cluster_restrictions(
     // 'mz_introspection' cluster to be "read-only", which restricts these actions.
     let depends_on: Box<dyn Iterator<Item = GlobalId>> = match plan {
         Plan::ReadThenWrite(plan) => Box::new(plan.selection.depends_on().into_iter()),
-        Plan::Subscribe(plan) => match plan.from {
+        Plan::Subscribe(plan)
+        | Plan::Listen(ListenPlan {
+            subscribe: plan, ..
+        }) => match plan.from {
             SubscribeFrom::Id(id) => Box::new(std::iter::once(id)),
             SubscribeFrom::Query { ref expr, .. } => Box::new(expr.depends_on().into_iter()),
         },
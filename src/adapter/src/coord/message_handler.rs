@@ -103,6 +103,12 @@ impl Coordinator {
                 Message::RemovePendingPeeks { conn_id } => {
                     self.cancel_pending_peeks(&conn_id);
                 }
+                Message::ContinuePeek { uuid } => {
+                    self.continue_peek(uuid);
+                }
+                Message::StopPeek { uuid } => {
+                    self.stop_peek(uuid);
+                }
                 Message::LinearizeReads(pending_read_txns) => {
                     self.message_linearize_reads(pending_read_txns).await;
                 }
@@ -170,6 +176,7 @@ impl Coordinator {
                     persist_location: _,
                     relation_desc: _,
                     txns_shard: _,
+                    table_layout: _,
                 } = &collection.collection_metadata;
                 [*remap_shard, *status_shard, Some(*data_shard)].into_iter()
             })
@@ -15,9 +15,11 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::num::NonZeroUsize;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
 use differential_dataflow::consolidation::consolidate;
-use futures::TryFutureExt;
+use futures::{ready, Stream, StreamExt};
 use mz_adapter_types::connection::ConnectionId;
 use mz_cluster_client::ReplicaId;
 use mz_compute_client::protocol::command::PeekTarget;
@@ -36,17 +38,19 @@ use mz_repr::explain::{CompactScalarSeq, ExprHumanizer, IndexUsageType, Indices,
 use mz_repr::{Diff, GlobalId, RelationType, Row};
 use serde::{Deserialize, Serialize};
 use timely::progress::Timestamp;
-use tokio::sync::oneshot;
+use tokio::sync::mpsc;
 use uuid::Uuid;
 
+use crate::command::RowsStream;
 use crate::coord::timestamp_selection::TimestampDetermination;
+use crate::coord::Message;
 use crate::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use crate::util::ResultExt;
 use crate::{AdapterError, ExecuteContextExtra, ExecuteResponse};
 
 #[derive(Debug)]
 pub(crate) struct PendingPeek {
-    pub(crate) sender: oneshot::Sender<PeekResponse>,
+    pub(crate) sender: mpsc::UnboundedSender<PeekResponse>,
     pub(crate) conn_id: ConnectionId,
     pub(crate) cluster_id: ClusterId,
     /// All `GlobalId`s that the peek depend on.
@@ -55,12 +59,24 @@ pub(crate) struct PendingPeek {
     /// needed by the coordinator for retiring it.
     pub(crate) ctx_extra: ExecuteContextExtra,
     pub(crate) is_fast_path: bool,
+    /// The number of rows the peek has returned in batches so far.
+    pub(crate) rows_returned: u64,
+}
+
+impl PendingPeek {
+    fn execution_strategy(&self) -> StatementExecutionStrategy {
+        if self.is_fast_path {
+            StatementExecutionStrategy::FastPath
+        } else {
+            StatementExecutionStrategy::Standard
+        }
+    }
 }
 
 /// The response from a `Peek`, with row multiplicities represented in unary.
 ///
-/// Note that each `Peek` expects to generate exactly one `PeekResponse`, i.e.
-/// we expect a 1:1 contract between `Peek` and `PeekResponseUnary`.
+/// A `Peek` generates any number of `Rows` responses, which can be followed by
+/// a single `Error` or `Canceled` response.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum PeekResponseUnary {
     Rows(Vec<Row>),
@@ -68,6 +84,139 @@ pub enum PeekResponseUnary {
     Canceled,
 }
 
+impl PeekResponseUnary {
+    /// Collects all batches of rows in `stream` into a single response.
+    pub async fn collect(mut stream: RowsStream) -> PeekResponseUnary {
+        let mut rows = Vec::new();
+        while let Some(response) = stream.next().await {
+            match response {
+                PeekResponseUnary::Rows(batch) => rows.extend(batch),
+                response @ (PeekResponseUnary::Error(_) | PeekResponseUnary::Canceled) => {
+                    return response;
+                }
+            }
+        }
+        PeekResponseUnary::Rows(rows)
+    }
+}
+
+/// The responses of a pending peek, with the peek's finishing applied.
+///
+/// Replicas stream the results of peeks that need not be ordered in bounded batches. The next
+/// batch is only requested once the current one is handed out, so a consumer that stops polling
+/// the stream (e.g., a portal that has returned as many rows as its client asked for) also stops
+/// the peek from producing more rows. Ordered results are collected in full before the finishing
+/// is applied.
+///
+/// Dropping the stream before the peek has concluded stops the peek.
+struct PeekResponseStream {
+    uuid: Uuid,
+    rx: mpsc::UnboundedReceiver<PeekResponse>,
+    internal_cmd_tx: mpsc::UnboundedSender<Message>,
+    /// The finishing still to apply to the remainder of the result.
+    finishing: RowSetFinishing,
+    max_result_size: u32,
+    /// The rows of an ordered result received so far.
+    buffered: Vec<(Row, NonZeroUsize)>,
+    /// Whether the peek has concluded, or has been stopped.
+    done: bool,
+}
+
+impl PeekResponseStream {
+    /// Applies the finishing to a batch of an unordered result, and advances the finishing's
+    /// offset and limit past the batch.
+    fn finish_batch(&mut self, rows: Vec<(Row, NonZeroUsize)>) -> Result<Vec<Row>, String> {
+        let count = rows
+            .iter()
+            .fold(0, |sum: usize, (_, count)| sum.saturating_add(count.get()));
+        let rows = self.finishing.finish(rows, self.max_result_size)?;
+        self.finishing.offset = self.finishing.offset.saturating_sub(count);
+        if let Some(limit) = &mut self.finishing.limit {
+            *limit -= rows.len();
+        }
+        Ok(rows)
+    }
+
+    fn send(&self, message: Message) {
+        // It is not an error for this message to arrive after the coordinator has shut down.
+        let _ = self.internal_cmd_tx.send(message);
+    }
+}
+
+impl Stream for PeekResponseStream {
+    type Item = PeekResponseUnary;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        while !this.done {
+            let Some(response) = ready!(this.rx.poll_recv(cx)) else {
+                this.done = true;
+                return Poll::Ready(Some(PeekResponseUnary::Error("channel closed".into())));
+            };
+            let uuid = this.uuid;
+            let response = match response {
+                PeekResponse::Batch(rows) if !this.finishing.order_by.is_empty() => {
+                    this.buffered.extend(rows);
+                    this.send(Message::ContinuePeek { uuid });
+                    continue;
+                }
+                PeekResponse::Batch(rows) => match this.finish_batch(rows) {
+                    Ok(rows) if this.finishing.limit == Some(0) => {
+                        this.done = true;
+                        this.send(Message::StopPeek { uuid });
+                        PeekResponseUnary::Rows(rows)
+                    }
+                    Ok(rows) => {
+                        // Have the next batch prepared while this one is handed out.
+                        this.send(Message::ContinuePeek { uuid });
+                        if rows.is_empty() {
+                            continue;
+                        }
+                        PeekResponseUnary::Rows(rows)
+                    }
+                    Err(e) => {
+                        this.done = true;
+                        this.send(Message::StopPeek { uuid });
+                        PeekResponseUnary::Error(e)
+                    }
+                },
+                PeekResponse::Rows(rows) => {
+                    this.done = true;
+                    let rows = if this.finishing.order_by.is_empty() {
+                        this.finish_batch(rows)
+                    } else {
+                        let mut buffered = std::mem::take(&mut this.buffered);
+                        buffered.extend(rows);
+                        this.finishing.finish(buffered, this.max_result_size)
+                    };
+                    match rows {
+                        Ok(rows) => PeekResponseUnary::Rows(rows),
+                        Err(e) => PeekResponseUnary::Error(e),
+                    }
+                }
+                PeekResponse::Canceled => {
+                    this.done = true;
+                    PeekResponseUnary::Canceled
+                }
+                PeekResponse::Error(e) => {
+                    this.done = true;
+                    PeekResponseUnary::Error(e)
+                }
+            };
+            return Poll::Ready(Some(response));
+        }
+        Poll::Ready(None)
+    }
+}
+
+impl Drop for PeekResponseStream {
+    fn drop(&mut self) {
+        if !self.done {
+            self.send(Message::StopPeek { uuid: self.uuid });
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct PeekDataflowPlan<T = mz_repr::Timestamp> {
     desc: DataflowDescription<mz_compute_types::plan::Plan<T>, (), T>,
@@ -538,7 +687,7 @@ impl crate::coord::Coordinator {
         };
 
         // Endpoints for sending and receiving peek responses.
-        let (rows_tx, rows_rx) = tokio::sync::oneshot::channel();
+        let (rows_tx, rows_rx) = mpsc::unbounded_channel();
 
         // Generate unique UUID. Guaranteed to be unique to all pending peeks, there's an very
         // small but unlikely chance that it's not unique to completed peeks.
@@ -558,6 +707,7 @@ impl crate::coord::Coordinator {
                 depends_on: source_ids,
                 ctx_extra: std::mem::take(ctx_extra),
                 is_fast_path,
+                rows_returned: 0,
             },
         );
         self.client_pending_peeks
@@ -582,17 +732,15 @@ impl crate::coord::Coordinator {
             .unwrap_or_terminate("cannot fail to peek");
 
         // Prepare the receiver to return as a response.
-        let rows_rx = rows_rx.map_ok_or_else(
-            |e| PeekResponseUnary::Error(e.to_string()),
-            move |resp| match resp {
-                PeekResponse::Rows(rows) => match finishing.finish(rows, max_result_size) {
-                    Ok(rows) => PeekResponseUnary::Rows(rows),
-                    Err(e) => PeekResponseUnary::Error(e),
-                },
-                PeekResponse::Canceled => PeekResponseUnary::Canceled,
-                PeekResponse::Error(e) => PeekResponseUnary::Error(e),
-            },
-        );
+        let rows = PeekResponseStream {
+            uuid,
+            rx: rows_rx,
+            internal_cmd_tx: self.internal_cmd_tx.clone(),
+            finishing,
+            max_result_size,
+            buffered: Vec::new(),
+            done: false,
+        };
 
         // If it was created, drop the dataflow once the peek command is sent.
         if let Some(index_id) = drop_dataflow {
@@ -601,7 +749,7 @@ impl crate::coord::Coordinator {
         }
 
         Ok(crate::ExecuteResponse::SendingRows {
-            future: Box::pin(rows_rx),
+            rows: Box::new(rows),
             span: tracing::Span::current(),
         })
     }
@@ -652,34 +800,40 @@ impl crate::coord::Coordinator {
         response: PeekResponse,
         otel_ctx: OpenTelemetryContext,
     ) {
-        // We expect exactly one peek response, which we forward. Then we clean up the
+        // A streaming peek sends batches of rows before its final response. We forward them,
+        // and keep the peek's state until the final response arrives.
+        if let PeekResponse::Batch(rows) = &response {
+            let batch_rows: u64 = rows.iter().map(|(_, n)| u64::cast_from(n.get())).sum();
+            if let Some(pending_peek) = self.pending_peeks.get_mut(&uuid) {
+                pending_peek.rows_returned += batch_rows;
+                otel_ctx.attach_as_parent();
+                let _ = pending_peek.sender.send(response);
+            }
+            return;
+        }
+
+        // We expect exactly one final peek response, which we forward. Then we clean up the
         // peek's state in the coordinator.
-        if let Some(PendingPeek {
-            sender: rows_tx,
-            conn_id: _,
-            cluster_id: _,
-            depends_on: _,
-            ctx_extra,
-            is_fast_path,
-        }) = self.remove_pending_peek(&uuid)
-        {
+        if let Some(pending_peek) = self.remove_pending_peek(&uuid) {
             let reason = match &response {
                 PeekResponse::Rows(r) => {
                     let rows_returned: u64 = r.iter().map(|(_, n)| u64::cast_from(n.get())).sum();
                     StatementEndedExecutionReason::Success {
-                        rows_returned: Some(rows_returned),
-                        execution_strategy: Some(if is_fast_path {
-                            StatementExecutionStrategy::FastPath
-                        } else {
-                            StatementExecutionStrategy::Standard
-                        }),
+                        rows_returned: Some(pending_peek.rows_returned + rows_returned),
+                        execution_strategy: Some(pending_peek.execution_strategy()),
                     }
                 }
                 PeekResponse::Error(e) => {
                     StatementEndedExecutionReason::Errored { error: e.clone() }
                 }
                 PeekResponse::Canceled => StatementEndedExecutionReason::Canceled,
+                PeekResponse::Batch(_) => unreachable!("handled above"),
             };
+            let PendingPeek {
+                sender: rows_tx,
+                ctx_extra,
+                ..
+            } = pending_peek;
             self.retire_execution(reason, ctx_extra);
             otel_ctx.attach_as_parent();
             // Peek cancellations are best effort, so we might still
@@ -690,6 +844,36 @@ impl crate::coord::Coordinator {
         // longer in `self.pending_peeks`, so we quietly ignore them.
     }
 
+    /// Requests the next batch of rows of a streaming peek.
+    pub(crate) fn continue_peek(&mut self, uuid: Uuid) {
+        // The peek might have been canceled in the meantime.
+        if let Some(pending_peek) = self.pending_peeks.get(&uuid) {
+            let cluster_id = pending_peek.cluster_id;
+            // The cluster might have been dropped in the meantime, in which case the peek is
+            // about to be canceled.
+            let _ = self
+                .controller
+                .active_compute()
+                .continue_peek(cluster_id, uuid);
+        }
+    }
+
+    /// Stops a streaming peek whose remaining rows are not wanted anymore, e.g., because the
+    /// query's limit has been reached or its portal was closed.
+    pub(crate) fn stop_peek(&mut self, uuid: Uuid) {
+        if let Some(pending_peek) = self.remove_pending_peek(&uuid) {
+            let _ = self
+                .controller
+                .active_compute()
+                .cancel_peek(pending_peek.cluster_id, uuid);
+            let reason = StatementEndedExecutionReason::Success {
+                rows_returned: Some(pending_peek.rows_returned),
+                execution_strategy: Some(pending_peek.execution_strategy()),
+            };
+            self.retire_execution(reason, pending_peek.ctx_extra);
+        }
+    }
+
     /// Clean up a peek's state.
     pub(crate) fn remove_pending_peek(&mut self, uuid: &Uuid) -> Option<PendingPeek> {
         let pending_peek = self.pending_peeks.remove(uuid);
@@ -22,10 +22,14 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::hash::Hash;
+use std::sync::Arc;
 
 use differential_dataflow::lattice::Lattice;
 use itertools::Itertools;
+use mz_adapter_types::compaction::{compaction_window_ts, DEFAULT_SUBSCRIPTION_RETAIN_HISTORY};
+use mz_catalog::memory::objects::{CatalogItem, Subscription};
 use mz_compute_types::ComputeInstanceId;
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{GlobalId, Timestamp};
 use mz_storage_client::controller::ReadPolicy;
 use timely::progress::frontier::MutableAntichain;
@@ -286,6 +290,18 @@ impl crate::coord::Coordinator {
                         .holds
                         .update_iter(time.iter().map(|t| (*t, 1)));
                 }
+                // Durable subscriptions keep the history of their inputs
+                // readable across restarts.
+                for read_holds in self.subscription_read_holds.values() {
+                    for (time, _) in read_holds
+                        .storage_ids()
+                        .filter(|(_, hold_id)| **hold_id == id)
+                    {
+                        read_capability
+                            .holds
+                            .update_iter(time.iter().map(|t| (*t, 1)));
+                    }
+                }
                 self.storage_read_capabilities.insert(id, read_capability);
                 storage_policy_updates.push((id, self.storage_read_capabilities[&id].policy()));
             }
@@ -313,6 +329,45 @@ impl crate::coord::Coordinator {
         policy.into()
     }
 
+    /// Installs the base read policy of a materialized view that is refreshed
+    /// according to `refresh_schedule`.
+    ///
+    /// The write frontier of such a view sits at its next refresh, so lagging
+    /// it by the compaction window alone would advance the `since` into the
+    /// future, forcing queries to pick (and wait for) a timestamp right before
+    /// the next refresh. The policy additionally holds the `since` back to the
+    /// latest refresh before the write frontier, so that queries at the current
+    /// time read the contents of the latest refresh. Before the first scheduled
+    /// refresh, the `since` stays at the refresh performed on creation.
+    pub(crate) fn update_refresh_schedule_read_policy(
+        &mut self,
+        id: GlobalId,
+        refresh_schedule: RefreshSchedule,
+        compaction_window_ms: Timestamp,
+    ) {
+        let last_refresh = ReadPolicy::LagWriteFrontier(Arc::new(move |upper| {
+            let time = match upper.first() {
+                Some(upper) => {
+                    let before_upper = upper.step_back().unwrap_or(*upper);
+                    // A frontier behind the current `since` leaves it
+                    // unchanged.
+                    refresh_schedule
+                        .round_down_timestamp(before_upper)
+                        .unwrap_or_else(Timestamp::minimum)
+                }
+                None => refresh_schedule
+                    .last_refresh()
+                    .unwrap_or_else(Timestamp::minimum),
+            };
+            Antichain::from_elem(time)
+        }));
+        let base_policy = ReadPolicy::Multiple(vec![
+            ReadPolicy::lag_writes_by(compaction_window_ms, SINCE_GRANULARITY),
+            last_refresh,
+        ]);
+        self.update_storage_base_read_policies(vec![(id, base_policy)]);
+    }
+
     pub(crate) fn update_storage_base_read_policies(
         &mut self,
         base_policies: Vec<(GlobalId, ReadPolicy<mz_repr::Timestamp>)>,
@@ -612,4 +667,104 @@ impl crate::coord::Coordinator {
             }
         }
     }
+
+    /// Returns the storage collections that a durable subscription on `on`
+    /// reads from, looking through views.
+    ///
+    /// Subscriptions resume from persist rather than from indexes, so these
+    /// are the collections whose history must be retained.
+    pub(crate) fn subscription_id_bundle(&self, on: GlobalId) -> CollectionIdBundle {
+        let mut id_bundle = CollectionIdBundle::default();
+        let mut todo = vec![on];
+        while let Some(id) = todo.pop() {
+            if self.controller.storage.collection(id).is_ok() {
+                id_bundle.storage_ids.insert(id);
+            } else if let CatalogItem::View(_) = self.catalog().get_entry(&id).item() {
+                todo.extend(self.catalog().get_entry(&id).uses());
+            }
+        }
+        id_bundle
+    }
+
+    /// Returns the time at which the history of `subscription` must remain
+    /// readable.
+    ///
+    /// This is the time just before its acknowledged frontier, unless that has
+    /// fallen further behind than the subscription retains history for. A
+    /// subscription that has not acknowledged any updates yet retains all the
+    /// history it can, so that its consumer can acknowledge a first frontier.
+    async fn subscription_read_hold_time(&self, subscription: &Subscription) -> Timestamp {
+        let retain_history = subscription
+            .retain_history
+            .unwrap_or(DEFAULT_SUBSCRIPTION_RETAIN_HISTORY);
+        let retained = self
+            .get_local_read_ts()
+            .await
+            .saturating_sub(compaction_window_ts(retain_history));
+        match subscription.acknowledged {
+            Some(acknowledged) => {
+                std::cmp::max(acknowledged.step_back().unwrap_or(acknowledged), retained)
+            }
+            None => retained,
+        }
+    }
+
+    /// Computes the read holds of all durable subscriptions in the catalog.
+    ///
+    /// This must be called during bootstrap after all storage collections have
+    /// been created but before their read policies are initialized, which
+    /// applies the holds.
+    pub(crate) async fn bootstrap_subscription_read_holds(&mut self) {
+        let subscriptions: Vec<_> = self
+            .catalog()
+            .entries()
+            .filter_map(|entry| match entry.item() {
+                CatalogItem::Subscription(subscription) => Some((entry.id(), subscription.clone())),
+                _ => None,
+            })
+            .collect();
+        for (id, subscription) in subscriptions {
+            let time = self.subscription_read_hold_time(&subscription).await;
+            let id_bundle = self.subscription_id_bundle(subscription.on);
+            let read_holds = self.initialize_read_holds(time, &id_bundle);
+            self.subscription_read_holds.insert(id, read_holds);
+        }
+    }
+
+    /// Acquires or advances the read holds of the durable subscription `id` to
+    /// reflect its acknowledged frontier and the current time.
+    ///
+    /// Read holds only ever advance, so lengthening the history a subscription
+    /// retains does not bring back history that has already been released.
+    pub(crate) async fn update_subscription_read_holds(&mut self, id: GlobalId) {
+        let CatalogItem::Subscription(subscription) = self.catalog().get_entry(&id).item() else {
+            return;
+        };
+        let subscription = subscription.clone();
+        let time = self.subscription_read_hold_time(&subscription).await;
+        let read_holds = match self.subscription_read_holds.remove(&id) {
+            Some(read_holds) => self.update_read_hold(read_holds, time),
+            None => {
+                let id_bundle = self.subscription_id_bundle(subscription.on);
+                self.acquire_read_holds(time, &id_bundle)
+            }
+        };
+        self.subscription_read_holds.insert(id, read_holds);
+    }
+
+    /// Advances the read holds of all durable subscriptions to reflect the
+    /// current time.
+    pub(crate) async fn advance_subscription_read_holds(&mut self) {
+        let ids: Vec<_> = self.subscription_read_holds.keys().copied().collect();
+        for id in ids {
+            self.update_subscription_read_holds(id).await;
+        }
+    }
+
+    /// Releases the read holds of the durable subscription `id`, if any.
+    pub(crate) fn release_subscription_read_holds(&mut self, id: &GlobalId) {
+        if let Some(read_holds) = self.subscription_read_holds.remove(id) {
+            self.release_read_hold(&read_holds);
+        }
+    }
 }
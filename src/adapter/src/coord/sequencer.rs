@@ -217,6 +217,24 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreatePolicy(plan) => {
+                    let result = self
+                        .sequence_create_policy(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateSubscription(plan) => {
+                    let result = self
+                        .sequence_create_subscription(ctx.session_mut(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::Comment(plan) => {
                     let result = self.sequence_comment_on(ctx.session(), plan).await;
                     ctx.retire(result);
@@ -304,6 +322,14 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::Listen(plan) => {
+                    let result = self.sequence_listen(&mut ctx, plan, target_cluster).await;
+                    ctx.retire(result);
+                }
+                Plan::Unlisten(plan) => {
+                    let result = self.sequence_unlisten(ctx.session(), plan);
+                    ctx.retire(result);
+                }
                 Plan::SideEffectingFunc(plan) => {
                     ctx.retire(self.sequence_side_effecting_func(plan));
                 }
@@ -417,10 +443,17 @@ impl Coordinator {
                     let result = self.sequence_alter_secret(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterSubscription(plan) => {
+                    let result = self.sequence_alter_subscription(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::AlterSink(plan) => {
                     let result = self.sequence_alter_sink(ctx.session(), plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterTable(plan) => {
+                    self.sequence_alter_table(ctx, plan).await;
+                }
                 Plan::PurifiedAlterSource {
                     alter_source,
                     subsources,
@@ -454,6 +487,7 @@ impl Coordinator {
                 Plan::DiscardAll => {
                     let ret = if let TransactionStatus::Started(_) = ctx.session().transaction() {
                         self.clear_transaction(ctx.session_mut());
+                        self.clear_listen_sinks(ctx.session().conn_id());
                         self.drop_temp_items(ctx.session().conn_id()).await;
                         ctx.session_mut().reset();
                         Ok(ExecuteResponse::DiscardedAll)
@@ -770,7 +804,12 @@ impl Coordinator {
                 affected_rows += diff;
             }
 
-            if !all_positive_diffs {
+            if !all_positive_diffs && matches!(plan.kind, MutationKind::Insert) {
+                // Inserts only retract the rows that `ON CONFLICT DO UPDATE`
+                // replaces, and each replaced row is affected once, through
+                // its replacement.
+                affected_rows = plan.updates.iter().map(|(_, diff)| (*diff).max(0)).sum();
+            } else if !all_positive_diffs {
                 // Consolidate rows. This is useful e.g. for an UPDATE where the row
                 // doesn't change, and we need to reflect that in the number of
                 // affected rows.
@@ -26,15 +26,17 @@ use mz_expr::{
     RowSetFinishing,
 };
 use mz_ore::collections::{CollectionExt, HashSet};
+use mz_ore::str::StrExt;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::vec::VecExt;
 use mz_ore::{soft_assert, task};
 use mz_repr::adt::jsonb::Jsonb;
-use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::json::json_string;
 use mz_repr::explain::{
     ExplainFormat, ExprHumanizer, ExprHumanizerExt, TransientItem, UsedIndexes,
 };
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Datum, Diff, GlobalId, RelationDesc, Row, RowArena, Timestamp};
 use mz_sql::ast::{ExplainStage, IndexOptionName};
@@ -48,14 +50,17 @@ use mz_sql::names::{
     SchemaSpecifier, SystemObjectId,
 };
 // Import `plan` module, but only import select elements to avoid merge conflicts on use statements.
-use mz_adapter_types::compaction::DEFAULT_LOGICAL_COMPACTION_WINDOW_TS;
+use mz_adapter_types::compaction::{
+    compaction_window_ts, DEFAULT_LOGICAL_COMPACTION_WINDOW, DEFAULT_LOGICAL_COMPACTION_WINDOW_TS,
+};
 use mz_adapter_types::connection::ConnectionId;
 use mz_catalog::memory::objects::{
-    CatalogItem, Cluster, Connection, DataSourceDesc, Secret, Sink, Source, Table, Type, View,
+    CatalogItem, Cluster, Connection, DataSourceDesc, Func, FuncInner, Policy, Secret, Sink,
+    Source, Subscription, Table, Type, View,
 };
 use mz_sql::plan::{
     AlterConnectionAction, AlterConnectionPlan, AlterOptionParameter, ExplainSinkSchemaPlan,
-    Explainee, Index, IndexOption, MaterializedView, MutationKind, Params, Plan,
+    Explainee, Index, IndexOption, MaterializedView, MutationKind, Params, Plan, PlanContext,
     PlannedAlterRoleOption, PlannedRoleVariable, QueryWhen, SideEffectingFunc,
     SourceSinkClusterConfig, UpdatePrivilege, VariableValue,
 };
@@ -76,6 +81,7 @@ use mz_storage_client::controller::{
 };
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::controller::StorageError;
+use mz_storage_types::sources::Timeline;
 use mz_transform::dataflow::DataflowMetainfo;
 use mz_transform::optimizer_notices::OptimizerNotice;
 use mz_transform::{EmptyStatisticsOracle, StatisticsOracle};
@@ -87,14 +93,14 @@ use tracing_core::callsite::rebuild_interest_cache;
 
 use crate::catalog::{self, Catalog, ConnCatalog, UpdatePrivilegeVariant};
 use crate::command::{ExecuteResponse, Response};
-use crate::coord::appends::{Deferred, DeferredPlan, PendingWriteTxn};
+use crate::coord::appends::{key_of, unique_violation, Deferred, DeferredPlan, PendingWriteTxn};
 use crate::coord::dataflows::{
-    dataflow_import_id_bundle, prep_scalar_expr, EvalTime, ExprPrepStyle,
+    dataflow_import_id_bundle, prep_scalar_expr, ComputeInstanceSnapshot, EvalTime, ExprPrepStyle,
 };
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::{FastPathPlan, PeekDataflowPlan, PeekPlan, PlannedPeek};
 use crate::coord::read_policy::SINCE_GRANULARITY;
-use crate::coord::timeline::TimelineContext;
+use crate::coord::timeline::{TimelineContext, WriteTimestamp};
 use crate::coord::timestamp_selection::{
     TimestampContext, TimestampDetermination, TimestampProvider, TimestampSource,
 };
@@ -109,7 +115,9 @@ use crate::explain::explain_dataflow;
 use crate::explain::optimizer_trace::OptimizerTrace;
 use crate::notice::{AdapterNotice, DroppedInUseIndex};
 use crate::optimize::{self, Optimize, OptimizerConfig};
-use crate::session::{EndTransactionAction, Session, TransactionOps, TransactionStatus, WriteOp};
+use crate::session::{
+    EndTransactionAction, RowBatchStream, Session, TransactionOps, TransactionStatus, WriteOp,
+};
 use crate::subscribe::ActiveSubscribe;
 use crate::util::{viewable_variables, ClientTransmitter, ComputeSinkId, ResultExt};
 use crate::{guard_write_critical_section, PeekResponseUnary, TimestampExplanation};
@@ -241,8 +249,16 @@ impl Coordinator {
 
         match self.catalog_transact(Some(session), ops).await {
             Ok(()) => {
-                let mut source_ids = Vec::with_capacity(sources.len());
+                // Sources may carry a custom compaction window from `RETAIN
+                // HISTORY`, so group them by window before installing policies.
+                let mut source_ids: BTreeMap<_, Vec<_>> = BTreeMap::new();
                 for (source_id, source) in sources {
+                    let compaction_window = compaction_window_ts(
+                        source
+                            .custom_logical_compaction_window
+                            .unwrap_or(DEFAULT_LOGICAL_COMPACTION_WINDOW),
+                    );
+
                     let source_status_collection_id =
                         Some(self.catalog().resolve_builtin_storage_collection(
                             &mz_catalog::builtin::MZ_SOURCE_STATUS_HISTORY,
@@ -297,14 +313,16 @@ impl Coordinator {
                         .await
                         .unwrap_or_terminate("cannot fail to create collections");
 
-                    source_ids.push(source_id);
+                    source_ids
+                        .entry(compaction_window)
+                        .or_default()
+                        .push(source_id);
                 }
 
-                self.initialize_storage_read_policies(
-                    source_ids,
-                    Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
-                )
-                .await;
+                for (compaction_window, source_ids) in source_ids {
+                    self.initialize_storage_read_policies(source_ids, Some(compaction_window))
+                        .await;
+                }
 
                 Ok(ExecuteResponse::CreatedSource)
             }
@@ -555,7 +573,7 @@ impl Coordinator {
             defaults: table.defaults,
             conn_id: conn_id.cloned(),
             resolved_ids,
-            custom_logical_compaction_window: None,
+            custom_logical_compaction_window: table.compaction_window,
             is_retained_metrics_object: false,
         };
         let table_oid = self.catalog_mut().allocate_oid()?;
@@ -585,9 +603,12 @@ impl Coordinator {
                     .unwrap_or_terminate("cannot fail to create collections");
                 self.apply_local_write(register_ts).await;
 
+                let compaction_window = table
+                    .custom_logical_compaction_window
+                    .unwrap_or(DEFAULT_LOGICAL_COMPACTION_WINDOW);
                 self.initialize_storage_read_policies(
                     vec![table_id],
-                    Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
+                    Some(compaction_window_ts(compaction_window)),
                 )
                 .await;
 
@@ -934,6 +955,8 @@ impl Coordinator {
                     column_names,
                     cluster_id,
                     non_null_assertions,
+                    compaction_window,
+                    refresh_schedule,
                 },
             replace: _,
             drop_ids,
@@ -983,6 +1006,7 @@ impl Coordinator {
             internal_view_id,
             column_names.clone(),
             non_null_assertions.clone(),
+            refresh_schedule.clone(),
             debug_name,
             optimizer_config,
         );
@@ -1011,6 +1035,8 @@ impl Coordinator {
                 resolved_ids,
                 cluster_id,
                 non_null_assertions,
+                custom_logical_compaction_window: compaction_window,
+                refresh_schedule: refresh_schedule.clone(),
             }),
             owner_id: *session.current_role_id(),
         });
@@ -1057,11 +1083,18 @@ impl Coordinator {
                     .await
                     .unwrap_or_terminate("cannot fail to append");
 
-                self.initialize_storage_read_policies(
-                    vec![id],
-                    Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
-                )
-                .await;
+                let compaction_window_ms = compaction_window_ts(
+                    compaction_window.unwrap_or(DEFAULT_LOGICAL_COMPACTION_WINDOW),
+                );
+                self.initialize_storage_read_policies(vec![id], Some(compaction_window_ms))
+                    .await;
+                if let Some(refresh_schedule) = refresh_schedule {
+                    self.update_refresh_schedule_read_policy(
+                        id,
+                        refresh_schedule,
+                        compaction_window_ms,
+                    );
+                }
 
                 self.ship_dataflow(df_desc, cluster_id).await;
 
@@ -1220,6 +1253,158 @@ impl Coordinator {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let mut func = Func {
+            create_sql: Some(plan.function.create_sql.clone()),
+            inner: FuncInner::User(Arc::new(mz_sql::func::sql_function(
+                &plan.name.item,
+                &plan.function,
+            ))),
+            resolved_ids,
+        };
+        let ops = match plan.replace {
+            Some(id) if plan.in_place => {
+                // Objects that depend on the function keep the body that they
+                // were planned with until they are recreated, so the function
+                // keeps the dependencies of its previous body while they exist.
+                let entry = self.catalog().get_entry(&id);
+                if !entry.used_by().is_empty() {
+                    func.resolved_ids
+                        .0
+                        .extend(entry.references().0.iter().copied());
+                }
+                vec![catalog::Op::UpdateItem {
+                    id,
+                    name: plan.name,
+                    to_item: CatalogItem::Func(func),
+                }]
+            }
+            replace => {
+                let mut ops = vec![];
+                if let Some(id) = replace {
+                    ops.push(catalog::Op::DropObject(ObjectId::Item(id)));
+                }
+                let id = self.catalog_mut().allocate_user_id().await?;
+                let oid = self.catalog_mut().allocate_oid()?;
+                ops.push(catalog::Op::CreateItem {
+                    id,
+                    oid,
+                    name: plan.name,
+                    item: CatalogItem::Func(func),
+                    owner_id: *session.current_role_id(),
+                });
+                ops
+            }
+        };
+        match self.catalog_transact(Some(session), ops).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_policy(
+        &mut self,
+        session: &Session,
+        plan: plan::CreatePolicyPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let policy = Policy {
+            create_sql: plan.policy.create_sql,
+            on: plan.policy.on,
+            using: plan.policy.using,
+            resolved_ids,
+        };
+        let id = self.catalog_mut().allocate_user_id().await?;
+        let oid = self.catalog_mut().allocate_oid()?;
+        let op = catalog::Op::CreateItem {
+            id,
+            oid,
+            name: plan.name,
+            item: CatalogItem::Policy(policy),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedPolicy),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub(super) async fn sequence_create_subscription(
+        &mut self,
+        session: &mut Session,
+        plan: plan::CreateSubscriptionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::CreateSubscriptionPlan {
+            name,
+            subscription,
+            if_not_exists,
+        } = plan;
+
+        // Subscriptions resume from the durable history of their inputs, which
+        // introspection sources do not have.
+        if !self
+            .catalog()
+            .state()
+            .introspection_dependencies(subscription.on)
+            .is_empty()
+        {
+            coord_bail!("subscriptions cannot depend on introspection sources");
+        }
+        // The history of a subscription is retained relative to the current
+        // time, which only exists in the real-time timeline.
+        match self.validate_timeline_context(vec![subscription.on])? {
+            TimelineContext::TimelineDependent(Timeline::EpochMilliseconds)
+            | TimelineContext::TimestampIndependent
+            | TimelineContext::TimestampDependent => {}
+            TimelineContext::TimelineDependent(_) => {
+                coord_bail!("subscriptions can only depend on objects in the real-time timeline");
+            }
+        }
+
+        let subscription = Subscription {
+            create_sql: subscription.create_sql,
+            on: subscription.on,
+            acknowledged: subscription.acknowledged,
+            retain_history: subscription.retain_history,
+            resolved_ids,
+        };
+        let id = self.catalog_mut().allocate_user_id().await?;
+        let oid = self.catalog_mut().allocate_oid()?;
+        let op = catalog::Op::CreateItem {
+            id,
+            oid,
+            name: name.clone(),
+            item: CatalogItem::Subscription(subscription),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => {
+                self.update_subscription_read_holds(id).await;
+                Ok(ExecuteResponse::CreatedSubscription)
+            }
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind:
+                    mz_catalog::memory::error::ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: name.item,
+                    ty: "subscription",
+                });
+                Ok(ExecuteResponse::CreatedSubscription)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     pub(super) async fn sequence_comment_on(
         &mut self,
         session: &Session,
@@ -1347,6 +1532,15 @@ impl Coordinator {
                 &id,
                 &catalog,
             );
+            for column_privileges in entry.column_privileges().values() {
+                privilege_check(
+                    column_privileges,
+                    dropped_roles,
+                    &mut dependent_objects,
+                    &id,
+                    &catalog,
+                );
+            }
         }
         for database in self.catalog.databases() {
             let database_id = SystemObjectId::Object(database.id().into());
@@ -1465,6 +1659,7 @@ impl Coordinator {
         }
 
         let mut privilege_revokes = plan.privilege_revokes;
+        let mut column_privilege_revokes = plan.column_privilege_revokes;
 
         // Make sure this stays in sync with the beginning of `rbac::check_plan`.
         let session_catalog = self.catalog().for_session(session);
@@ -1479,6 +1674,13 @@ impl Coordinator {
                     !role_membership.contains(&privilege.grantor)
                 })
                 .map(|(object_id, _)| object_id)
+                .chain(
+                    column_privilege_revokes
+                        .drain_filter_swapping(|(_, _, privilege)| {
+                            !role_membership.contains(&privilege.grantor)
+                        })
+                        .map(|(id, _, _)| SystemObjectId::Object(id.into())),
+                )
                 .collect();
             for invalid_revoke in invalid_revokes {
                 let object_description =
@@ -1494,6 +1696,17 @@ impl Coordinator {
                 variant: UpdatePrivilegeVariant::Revoke,
             }
         });
+        let column_privilege_revoke_ops =
+            column_privilege_revokes
+                .into_iter()
+                .map(
+                    |(id, column, privilege)| catalog::Op::UpdateColumnPrivilege {
+                        id,
+                        column,
+                        privilege,
+                        variant: UpdatePrivilegeVariant::Revoke,
+                    },
+                );
         let default_privilege_revoke_ops = plan.default_privilege_revokes.into_iter().map(
             |(privilege_object, privilege_acl_item)| catalog::Op::UpdateDefaultPrivilege {
                 privilege_object,
@@ -1509,6 +1722,7 @@ impl Coordinator {
         } = self.sequence_drop_common(session, plan.drop_ids)?;
 
         let ops = privilege_revoke_ops
+            .chain(column_privilege_revoke_ops)
             .chain(default_privilege_revoke_ops)
             .chain(drop_ops.into_iter())
             .collect();
@@ -1731,9 +1945,13 @@ impl Coordinator {
         &mut self,
         session: &Session,
     ) -> Result<ExecuteResponse, AdapterError> {
-        let mut rows = viewable_variables(self.catalog().state(), session)
-            .map(|v| (v.name(), v.value(), v.description()))
-            .collect::<Vec<_>>();
+        let mut rows =
+            viewable_variables(self.catalog().state(), session)
+                .map(|v| (v.name(), v.value(), v.description()))
+                .chain(session.vars().custom_vars().map(|(name, value)| {
+                    (name, value.to_string(), "Custom configuration parameter.")
+                }))
+                .collect::<Vec<_>>();
         rows.sort_by_cached_key(|(name, _, _)| name.to_lowercase());
         Ok(Self::send_immediate_rows(
             rows.into_iter()
@@ -1782,6 +2000,11 @@ impl Coordinator {
             };
         }
 
+        if let Some(value) = session.vars().custom_var(&plan.name) {
+            let row = Row::pack_slice(&[Datum::String(value)]);
+            return Ok(Self::send_immediate_rows(vec![row]));
+        }
+
         let variable = session
             .vars()
             .get(Some(self.catalog().system_config()), &plan.name)
@@ -2203,6 +2426,7 @@ impl Coordinator {
             when,
             finishing,
             copy_to,
+            column_references: _,
         } = plan;
 
         // Collect optimizer parameters.
@@ -2819,17 +3043,120 @@ impl Coordinator {
     pub(super) async fn sequence_subscribe(
         &mut self,
         ctx: &mut ExecuteContext,
-        plan: plan::SubscribePlan,
+        mut plan: plan::SubscribePlan,
+        target_cluster: TargetCluster,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // SUBSCRIBE AS OF, similar to peeks, doesn't need to worry about transaction
+        // timestamp semantics.
+        if plan.when == QueryWhen::Immediately {
+            // If this isn't a SUBSCRIBE AS OF, the SUBSCRIBE can be in a transaction if it's the
+            // only operation.
+            ctx.session_mut()
+                .add_transaction_ops(TransactionOps::Subscribe)?;
+        }
+
+        let copy_to = plan.copy_to.take();
+        let (sink, _desc, rx) = self.ship_subscribe(ctx, plan, target_cluster).await?;
+
+        self.active_conns
+            .get_mut(ctx.session().conn_id())
+            .expect("must exist for active sessions")
+            .drop_sinks
+            .push(sink);
+
+        let resp = ExecuteResponse::Subscribing {
+            rx,
+            ctx_extra: std::mem::take(ctx.extra_mut()),
+        };
+        match copy_to {
+            None => Ok(resp),
+            Some(format) => Ok(ExecuteResponse::CopyTo {
+                format,
+                resp: Box::new(resp),
+            }),
+        }
+    }
+
+    pub(super) async fn sequence_listen(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::ListenPlan,
         target_cluster: TargetCluster,
     ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::ListenPlan { channel, subscribe } = plan;
+
+        // Like in PostgreSQL, listening on a channel more than once has no
+        // effect.
+        let conn_meta = self
+            .active_conns
+            .get(ctx.session().conn_id())
+            .expect("must exist for active sessions");
+        if conn_meta.listen_sinks.contains_key(&channel) {
+            return Ok(ExecuteResponse::Listening {
+                channel,
+                updates: None,
+            });
+        }
+
+        // Unlike a SUBSCRIBE, the subscription is not part of the transaction.
+        // It lives until the session stops listening on the channel.
+        let (sink, desc, rx) = self.ship_subscribe(ctx, subscribe, target_cluster).await?;
+        self.active_conns
+            .get_mut(ctx.session().conn_id())
+            .expect("must exist for active sessions")
+            .listen_sinks
+            .insert(channel.clone(), sink);
+
+        Ok(ExecuteResponse::Listening {
+            channel,
+            updates: Some((desc, rx)),
+        })
+    }
+
+    pub(super) fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        plan: plan::UnlistenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::UnlistenPlan { channel } = plan;
+        match &channel {
+            Some(channel) => {
+                // Like in PostgreSQL, it is not an error to stop listening on a
+                // channel that the session is not listening on.
+                let sink = self
+                    .active_conns
+                    .get_mut(session.conn_id())
+                    .expect("must exist for active sessions")
+                    .listen_sinks
+                    .remove(channel);
+                self.drop_compute_sinks(sink);
+            }
+            None => self.clear_listen_sinks(session.conn_id()),
+        }
+        Ok(ExecuteResponse::Unlistened { channel })
+    }
+
+    /// Optimizes a subscribe and ships its dataflow.
+    ///
+    /// Returns the sink of the dataflow, the description of the relation that
+    /// it subscribes to, and the stream of its updates. The caller is
+    /// responsible for dropping the sink.
+    async fn ship_subscribe(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::SubscribePlan,
+        target_cluster: TargetCluster,
+    ) -> Result<(ComputeSinkId, RelationDesc, RowBatchStream), AdapterError> {
         let plan::SubscribePlan {
             from,
             with_snapshot,
             when,
-            copy_to,
+            copy_to: _,
             emit_progress,
             up_to,
             output,
+            column_references: _,
+            subscription,
         } = plan;
 
         let cluster = self
@@ -2849,15 +3176,6 @@ impl Coordinator {
             })
             .transpose()?;
 
-        // SUBSCRIBE AS OF, similar to peeks, doesn't need to worry about transaction
-        // timestamp semantics.
-        if when == QueryWhen::Immediately {
-            // If this isn't a SUBSCRIBE AS OF, the SUBSCRIBE can be in a transaction if it's the
-            // only operation.
-            ctx.session_mut()
-                .add_transaction_ops(TransactionOps::Subscribe)?;
-        }
-
         let depends_on = from.depends_on();
 
         // Run `check_log_reads` and emit notices.
@@ -2878,10 +3196,14 @@ impl Coordinator {
             timeline = TimelineContext::TimestampDependent;
         }
 
-        // Collect optimizer parameters.
-        let compute_instance = self
-            .instance_snapshot(cluster_id)
-            .expect("compute instance does not exist");
+        // Collect optimizer parameters. Resuming a durable subscription reads
+        // from the history that it retains in storage, not from indexes.
+        let compute_instance = match subscription {
+            Some(_) => ComputeInstanceSnapshot::new_without_collections(cluster_id),
+            None => self
+                .instance_snapshot(cluster_id)
+                .expect("compute instance does not exist"),
+        };
         let id = self.allocate_transient_id()?;
         let conn_id = ctx.session().conn_id().clone();
         let up_to = up_to
@@ -2901,13 +3223,37 @@ impl Coordinator {
         );
 
         // MIR ⇒ MIR optimization (global)
-        let global_mir_plan = optimizer.optimize(from)?;
+        let mut global_mir_plan = optimizer.optimize(from)?;
+        global_mir_plan.prep_session_exprs(ctx.session(), self.catalog().state())?;
         // Timestamp selection
+        let id_bundle = global_mir_plan.id_bundle(optimizer.cluster_id());
+        if let (Some(subscription_id), QueryWhen::AtTimestamp(_)) = (subscription, &when) {
+            // Report a subscription that fell too far behind in terms of its
+            // own frontiers, rather than those of its inputs.
+            let since = self.least_valid_read(&id_bundle).into_option();
+            let entry = self.catalog().get_entry(&subscription_id);
+            if let CatalogItem::Subscription(Subscription {
+                acknowledged: Some(acknowledged),
+                ..
+            }) = entry.item()
+            {
+                let resume_ts = acknowledged.step_back().unwrap_or(*acknowledged);
+                if let Some(since) = since.filter(|since| resume_ts < *since) {
+                    coord_bail!(
+                        "subscription {} cannot resume from {}: \
+                        its history has been compacted up to {}",
+                        entry.name().item.quoted(),
+                        acknowledged,
+                        since,
+                    );
+                }
+            }
+        }
         let oracle_read_ts = self.oracle_read_ts(&ctx.session, &timeline, &when).await;
         let as_of = self
             .determine_timestamp(
                 ctx.session(),
-                &global_mir_plan.id_bundle(optimizer.cluster_id()),
+                &id_bundle,
                 &when,
                 cluster_id,
                 &timeline,
@@ -2944,6 +3290,7 @@ impl Coordinator {
         self.emit_optimizer_notices(ctx.session(), &global_lir_plan.df_meta().optimizer_notices);
 
         let sink_id = global_lir_plan.sink_id();
+        let desc = global_lir_plan.sink_desc().from_desc.clone();
 
         let (tx, rx) = mpsc::unbounded_channel();
         let active_subscribe = ActiveSubscribe {
@@ -2973,26 +3320,11 @@ impl Coordinator {
                 .unwrap_or_terminate("cannot fail to set subscribe target replica");
         }
 
-        self.active_conns
-            .get_mut(ctx.session().conn_id())
-            .expect("must exist for active sessions")
-            .drop_sinks
-            .push(ComputeSinkId {
-                cluster_id,
-                global_id: sink_id,
-            });
-
-        let resp = ExecuteResponse::Subscribing {
-            rx,
-            ctx_extra: std::mem::take(ctx.extra_mut()),
+        let sink = ComputeSinkId {
+            cluster_id,
+            global_id: sink_id,
         };
-        match copy_to {
-            None => Ok(resp),
-            Some(format) => Ok(ExecuteResponse::CopyTo {
-                format,
-                resp: Box::new(resp),
-            }),
-        }
+        Ok((sink, desc, rx))
     }
 
     pub(super) async fn sequence_explain_plan(
@@ -3215,6 +3547,7 @@ impl Coordinator {
                 cluster_id,
                 broken,
                 non_null_assertions,
+                refresh_schedule,
             } => {
                 // Please see the docs on `explain_query_optimizer_pipeline` above.
                 self.explain_create_materialized_view_optimizer_pipeline(
@@ -3224,6 +3557,7 @@ impl Coordinator {
                     cluster_id,
                     broken,
                     non_null_assertions,
+                    refresh_schedule,
                     &config,
                     root_dispatch,
                 )
@@ -3555,6 +3889,7 @@ impl Coordinator {
         target_cluster_id: ClusterId,
         broken: bool,
         non_null_assertions: Vec<usize>,
+        refresh_schedule: Option<RefreshSchedule>,
         explain_config: &mz_repr::explain::ExplainConfig,
         _root_dispatch: tracing::Dispatch,
     ) -> Result<
@@ -3595,6 +3930,7 @@ impl Coordinator {
             internal_view_id,
             column_names.clone(),
             non_null_assertions,
+            refresh_schedule,
             debug_name,
             optimizer_config,
         );
@@ -3993,7 +4329,11 @@ impl Coordinator {
         };
 
         match optimized_mir.into_inner() {
-            selection if selection.as_const().is_some() && plan.returning.is_empty() => {
+            selection
+                if selection.as_const().is_some()
+                    && plan.returning.is_empty()
+                    && plan.conflicts.is_none() =>
+            {
                 let catalog = self.owned_catalog();
                 mz_ore::task::spawn(|| "coord::sequence_inner", async move {
                     let result =
@@ -4031,11 +4371,18 @@ impl Coordinator {
                     return;
                 }
 
+                // Inserts that check for conflicts also read the column that
+                // tags the table's existing rows.
+                let selection_arity = if plan.conflicts.is_some() {
+                    desc_arity + 1
+                } else {
+                    desc_arity
+                };
                 let finishing = RowSetFinishing {
                     order_by: vec![],
                     limit: None,
                     offset: 0,
-                    project: (0..desc_arity).collect(),
+                    project: (0..selection_arity).collect(),
                 };
 
                 let read_then_write_plan = plan::ReadThenWritePlan {
@@ -4045,6 +4392,7 @@ impl Coordinator {
                     assignments: BTreeMap::new(),
                     kind: MutationKind::Insert,
                     returning: plan.returning,
+                    conflicts: plan.conflicts,
                 };
 
                 self.sequence_read_then_write(ctx, read_then_write_plan)
@@ -4073,6 +4421,7 @@ impl Coordinator {
             assignments,
             finishing,
             returning,
+            conflicts,
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
@@ -4166,6 +4515,7 @@ impl Coordinator {
                 when: QueryWhen::Freshest,
                 finishing,
                 copy_to: None,
+                column_references: BTreeMap::new(),
             },
             TargetCluster::Active,
         )
@@ -4201,6 +4551,17 @@ impl Coordinator {
             }
 
             let make_diffs = move |rows: Vec<Row>| -> Result<Vec<(Row, Diff)>, AdapterError> {
+                if let Some(conflicts) = &conflicts {
+                    let diffs = resolve_insert_conflicts(&desc, conflicts, rows)?;
+                    for (row, diff) in &diffs {
+                        if *diff > 0 {
+                            for (idx, datum) in row.iter().enumerate() {
+                                desc.constraints_met(idx, &datum)?;
+                            }
+                        }
+                    }
+                    return Ok(diffs);
+                }
                 let arena = RowArena::new();
                 // Use 2x row len incase there's some assignments.
                 let mut diffs = Vec::with_capacity(rows.len() * 2);
@@ -4244,15 +4605,13 @@ impl Coordinator {
                 Ok(diffs)
             };
             let diffs = match peek_response {
-                ExecuteResponse::SendingRows {
-                    future: batch,
-                    span: _,
-                } => {
+                ExecuteResponse::SendingRows { rows, span: _ } => {
                     // TODO(jkosh44): This timeout should be removed;
                     // we should instead periodically ensure clusters are
                     // healthy and actively cancel any work waiting on unhealthy
                     // clusters.
-                    match tokio::time::timeout(timeout_dur, batch).await {
+                    match tokio::time::timeout(timeout_dur, PeekResponseUnary::collect(rows)).await
+                    {
                         Ok(res) => match res {
                             PeekResponseUnary::Rows(rows) => make_diffs(rows),
                             PeekResponseUnary::Canceled => Err(AdapterError::Canceled),
@@ -4395,6 +4754,298 @@ impl Coordinator {
         });
     }
 
+    /// Adds a column to or drops a column from a table.
+    ///
+    /// The rows already stored for the table are rewritten to the table's new
+    /// columns once the catalog records them, so this holds the write lock to
+    /// keep other writes from interleaving with the rewrite.
+    pub(super) async fn sequence_alter_table(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::AlterTablePlan,
+    ) {
+        let dependency_ids = BTreeSet::from([plan.id]);
+        guard_write_critical_section!(self, ctx, Plan::AlterTable(plan), dependency_ids);
+
+        let result = self
+            .sequence_alter_table_inner(ctx.session_mut(), plan)
+            .await;
+        ctx.retire(result);
+    }
+
+    async fn sequence_alter_table_inner(
+        &mut self,
+        session: &mut Session,
+        plan: plan::AlterTablePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTablePlan { id, table, action } = plan;
+
+        let entry = self.catalog().get_entry(&id);
+        let name = entry.name().clone();
+        let table_name = name.item.clone();
+        let CatalogItem::Table(old_table) = entry.item() else {
+            coord_bail!("{} is not a table", table_name.quoted());
+        };
+
+        // The plan was made against the table's columns at the time it was
+        // planned, which might have changed while waiting for the write lock.
+        let old_desc = old_table.desc.clone();
+        let old_arity = old_desc.arity();
+        let (adding, column) = match &action {
+            plan::AlterTableAction::AddColumn { .. } => {
+                if table.desc.arity() != old_arity + 1
+                    || old_desc.iter().ne(table.desc.iter().take(old_arity))
+                {
+                    return Err(AdapterError::ConcurrentTableAlter(table_name));
+                }
+                (true, table.desc.get_name(old_arity))
+            }
+            plan::AlterTableAction::DropColumn { index } => {
+                let kept = old_desc
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| i != index)
+                    .map(|(_, column)| column);
+                if *index >= old_arity || kept.ne(table.desc.iter()) {
+                    return Err(AdapterError::ConcurrentTableAlter(table_name));
+                }
+                (false, old_desc.get_name(*index))
+            }
+        };
+        let column = column.as_str().to_string();
+
+        let create_stmt = mz_sql::parse::parse(&table.create_sql)
+            .expect("planned create sql is valid")
+            .into_element()
+            .ast;
+        let (_, resolved_ids) =
+            mz_sql::names::resolve(&self.catalog().for_session(session), create_stmt)?;
+        let new_table = Table {
+            create_sql: Some(table.create_sql),
+            desc: table.desc,
+            defaults: table.defaults,
+            conn_id: old_table.conn_id.clone(),
+            resolved_ids,
+            custom_logical_compaction_window: old_table.custom_logical_compaction_window,
+            is_retained_metrics_object: old_table.is_retained_metrics_object,
+        };
+
+        let dependents =
+            self.validate_alter_table_dependents(session, id, &new_table, adding, &column)?;
+
+        // Evaluate the default for the added column once, as every existing
+        // row takes on the same value.
+        let missing = match action {
+            plan::AlterTableAction::AddColumn { mut default } => {
+                prep_scalar_expr(
+                    &mut default,
+                    ExprPrepStyle::OneShot {
+                        logical_time: EvalTime::NotAvailable,
+                        session,
+                        catalog_state: self.catalog().state(),
+                    },
+                )?;
+                let temp_storage = RowArena::new();
+                Row::pack_slice(&[default.eval(&[], &temp_storage)?])
+            }
+            plan::AlterTableAction::DropColumn { .. } => Row::default(),
+        };
+
+        // The indexes on the table are updated before the table itself, as
+        // updating the table refreshes the builtin table rows that describe
+        // their keys in terms of the table's columns.
+        let (indexes, dependents): (Vec<_>, Vec<_>) = dependents
+            .into_iter()
+            .partition(|(_, _, item)| matches!(item, CatalogItem::Index(_)));
+        let index_ids: Vec<_> = indexes.iter().map(|(id, _, _)| *id).collect();
+        let mut ops: Vec<_> = indexes
+            .into_iter()
+            .map(|(id, name, to_item)| catalog::Op::UpdateItem { id, name, to_item })
+            .collect();
+        ops.push(catalog::Op::UpdateItem {
+            id,
+            name,
+            to_item: CatalogItem::Table(new_table.clone()),
+        });
+        ops.extend(
+            dependents
+                .into_iter()
+                .map(|(id, name, to_item)| catalog::Op::UpdateItem { id, name, to_item }),
+        );
+
+        // Whether the catalog records the table's new columns decides whether
+        // the alteration takes place. Storage durably prepares it beforehand,
+        // so that it decides the same way if the process restarts in between.
+        self.controller
+            .storage
+            .prepare_alter_table(id, new_table.desc.clone(), missing)
+            .await?;
+        if let Err(e) = self.catalog_transact(Some(session), ops).await {
+            self.controller
+                .storage
+                .abort_alter_table(id)
+                .await
+                .unwrap_or_terminate("cannot fail to abort table alteration");
+            return Err(e);
+        }
+        self.commit_alter_table(id).await;
+        self.trigger_group_commit();
+
+        // The dataflows of the table's indexes keep arranging its previous
+        // columns, which existing dataflows that read from them expect.
+        for index_id in index_ids {
+            if self.stale_indexes.insert(index_id) {
+                let index = self.catalog().get_entry(&index_id);
+                session.add_notice(AdapterNotice::StaleIndex {
+                    index_name: self
+                        .catalog()
+                        .resolve_full_name(index.name(), Some(session.conn_id()))
+                        .to_string(),
+                    table_name: table_name.clone(),
+                });
+            }
+        }
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
+    /// Commits the alteration of table `id`'s columns that storage prepared,
+    /// once the catalog records the table's new columns.
+    pub(crate) async fn commit_alter_table(&mut self, id: GlobalId) {
+        let read_ts = self.get_local_read_ts().await;
+        let WriteTimestamp {
+            timestamp,
+            advance_to,
+        } = self.get_local_write_ts().await;
+        self.controller
+            .storage
+            .commit_alter_table(id, read_ts, timestamp, advance_to)
+            .await
+            .unwrap_or_terminate("cannot fail to commit table alteration");
+        self.apply_local_write(timestamp).await;
+    }
+
+    /// Validates that the objects that depend on table `id` remain valid, and
+    /// unchanged, when the table's definition becomes `new_table`.
+    ///
+    /// Each dependent is replanned against a copy of the catalog in which the
+    /// table has been altered. Returns the replanned views, materialized views
+    /// and indexes, whose plans refer to the table's columns by position.
+    fn validate_alter_table_dependents(
+        &self,
+        session: &Session,
+        id: GlobalId,
+        new_table: &Table,
+        adding: bool,
+        column: &str,
+    ) -> Result<Vec<(GlobalId, QualifiedItemName, CatalogItem)>, AdapterError> {
+        let entry = self.catalog().get_entry(&id);
+        let mut state = self.catalog().state().clone();
+        Catalog::update_item(
+            &mut state,
+            &mut vec![],
+            id,
+            entry.name().clone(),
+            CatalogItem::Table(new_table.clone()),
+        )?;
+        let catalog = state.for_session(session);
+
+        let mut replanned = vec![];
+        for dependent_id in entry.used_by() {
+            let dependent = self.catalog().get_entry(dependent_id);
+            let dependent_error = || AdapterError::AlterTableDependentObject {
+                adding,
+                table: entry.name().item.clone(),
+                column: column.to_string(),
+                dependent: format!(
+                    "{} {}",
+                    dependent.item_type(),
+                    self.catalog()
+                        .resolve_full_name(dependent.name(), Some(session.conn_id()))
+                        .to_string()
+                        .quoted()
+                ),
+            };
+
+            let desc = match dependent.item() {
+                // Policies only need their `USING` expression to remain valid
+                // for the table's new columns.
+                CatalogItem::Policy(policy) => {
+                    let scx = plan::StatementContext::new(None, &catalog);
+                    plan::plan_policy_expr(&scx, &new_table.desc, &policy.using)
+                        .map_err(|_| dependent_error())?;
+                    continue;
+                }
+                // Indexes need their keys to remain valid for the table's new
+                // columns, which they are rebuilt for on restart.
+                CatalogItem::Index(old_index) => {
+                    let item = state
+                        .parse_item(
+                            *dependent_id,
+                            dependent.create_sql().to_string(),
+                            Some(&PlanContext::zero()),
+                            old_index.is_retained_metrics_object,
+                            old_index.custom_logical_compaction_window,
+                        )
+                        .map_err(|_| dependent_error())?;
+                    let CatalogItem::Index(index) = item else {
+                        return Err(dependent_error());
+                    };
+                    let item = CatalogItem::Index(mz_catalog::memory::objects::Index {
+                        conn_id: old_index.conn_id.clone(),
+                        ..index
+                    });
+                    replanned.push((*dependent_id, dependent.name().clone(), item));
+                    continue;
+                }
+                CatalogItem::View(View { desc, .. })
+                | CatalogItem::MaterializedView(mz_catalog::memory::objects::MaterializedView {
+                    desc,
+                    ..
+                }) => desc,
+                // Conservatively reject other kinds of dependents, e.g. sinks,
+                // whose output would change with the table's columns.
+                _ => return Err(dependent_error()),
+            };
+
+            let item = state
+                .parse_item(
+                    *dependent_id,
+                    dependent.create_sql().to_string(),
+                    Some(&PlanContext::zero()),
+                    dependent.item().is_retained_metrics_object(),
+                    dependent.item().custom_logical_compaction_window(),
+                )
+                .map_err(|_| dependent_error())?;
+
+            // Views must keep their columns, e.g. not pick up an added column
+            // through a `SELECT *`.
+            let item = match item {
+                CatalogItem::View(view) if view.desc.iter_names().eq(desc.iter_names()) => {
+                    let CatalogItem::View(old_view) = dependent.item() else {
+                        unreachable!("replanned as a view");
+                    };
+                    CatalogItem::View(View {
+                        desc: desc.clone(),
+                        conn_id: old_view.conn_id.clone(),
+                        ..view
+                    })
+                }
+                CatalogItem::MaterializedView(mview)
+                    if mview.desc.iter_names().eq(desc.iter_names()) =>
+                {
+                    CatalogItem::MaterializedView(mz_catalog::memory::objects::MaterializedView {
+                        desc: desc.clone(),
+                        ..mview
+                    })
+                }
+                _ => return Err(dependent_error()),
+            };
+            replanned.push((*dependent_id, dependent.name().clone(), item));
+        }
+        Ok(replanned)
+    }
+
     pub(super) async fn sequence_alter_item_rename(
         &mut self,
         session: &mut Session,
@@ -4550,6 +5201,12 @@ impl Coordinator {
                 if let Some(inherit) = attrs.inherit {
                     attributes.inherit = inherit;
                 }
+                if let Some(password_hash) = attrs.password_hash {
+                    attributes.password_hash = password_hash;
+                }
+                if let Some(bypass_rls) = attrs.bypass_rls {
+                    attributes.bypass_rls = bypass_rls;
+                }
             }
             PlannedAlterRoleOption::Variable(variable) => {
                 // Get the variable to make sure it's valid and visible.
@@ -4606,6 +5263,51 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Secret))
     }
 
+    pub(super) async fn sequence_alter_subscription(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterSubscriptionPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterSubscriptionPlan {
+            id,
+            create_sql,
+            acknowledged,
+            retain_history,
+        } = plan;
+
+        let entry = self.catalog().get_entry(&id);
+        let CatalogItem::Subscription(subscription) = entry.item() else {
+            coord_bail!("{} is not a subscription", entry.name().item.quoted());
+        };
+        // Another session may have acknowledged more updates since this
+        // statement was planned.
+        if let (Some(acknowledged), Some(current)) = (acknowledged, subscription.acknowledged) {
+            if acknowledged < current {
+                coord_bail!(
+                    "cannot acknowledge {} because {} has already been acknowledged",
+                    acknowledged,
+                    current
+                );
+            }
+        }
+        let subscription = Subscription {
+            create_sql,
+            on: subscription.on,
+            acknowledged: acknowledged.or(subscription.acknowledged),
+            retain_history: retain_history.or(subscription.retain_history),
+            resolved_ids: subscription.resolved_ids.clone(),
+        };
+        let op = catalog::Op::UpdateItem {
+            id,
+            name: entry.name().clone(),
+            to_item: CatalogItem::Subscription(subscription),
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+        self.update_subscription_read_holds(id).await;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Subscription))
+    }
+
     pub(super) async fn sequence_alter_sink(
         &mut self,
         session: &Session,
@@ -5390,8 +6092,15 @@ impl Coordinator {
 
                 self.catalog_transact(Some(session), ops).await?;
 
-                let mut source_ids = Vec::with_capacity(sources.len());
+                // The new subsources carry their source's compaction window.
+                let mut source_ids: BTreeMap<_, Vec<_>> = BTreeMap::new();
                 for (source_id, source) in sources {
+                    let compaction_window = compaction_window_ts(
+                        source
+                            .custom_logical_compaction_window
+                            .unwrap_or(DEFAULT_LOGICAL_COMPACTION_WINDOW),
+                    );
+
                     let source_status_collection_id =
                         Some(self.catalog().resolve_builtin_storage_collection(
                             &mz_catalog::builtin::MZ_SOURCE_STATUS_HISTORY,
@@ -5428,7 +6137,10 @@ impl Coordinator {
                         .await
                         .unwrap_or_terminate("cannot fail to create collections");
 
-                    source_ids.push(source_id);
+                    source_ids
+                        .entry(compaction_window)
+                        .or_default()
+                        .push(source_id);
                 }
 
                 // Commit the new ingestion to storage.
@@ -5438,11 +6150,10 @@ impl Coordinator {
                     .await
                     .expect("altering collection after txn must succeed");
 
-                self.initialize_storage_read_policies(
-                    source_ids,
-                    Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
-                )
-                .await;
+                for (compaction_window, source_ids) in source_ids {
+                    self.initialize_storage_read_policies(source_ids, Some(compaction_window))
+                        .await;
+                }
             }
         }
 
@@ -5640,6 +6351,7 @@ impl Coordinator {
 
         for UpdatePrivilege {
             acl_mode,
+            column_acl_modes,
             target_id,
             grantor,
         } in update_privileges
@@ -5714,6 +6426,35 @@ impl Coordinator {
                     // no-op
                     _ => {}
                 }
+
+                if let SystemObjectId::Object(ObjectId::Item(id)) = &target_id {
+                    let column_privileges = self.catalog().get_entry(id).column_privileges();
+                    for (column, acl_mode) in &column_acl_modes {
+                        let existing_acl_mode = column_privileges
+                            .get(column)
+                            .and_then(|privileges| privileges.get_acl_item(grantee, &grantor))
+                            .map(|privilege| privilege.acl_mode)
+                            .unwrap_or_else(AclMode::empty);
+                        let needs_update = match variant {
+                            UpdatePrivilegeVariant::Grant => !existing_acl_mode.contains(*acl_mode),
+                            UpdatePrivilegeVariant::Revoke => {
+                                !existing_acl_mode.intersection(*acl_mode).is_empty()
+                            }
+                        };
+                        if needs_update {
+                            ops.push(catalog::Op::UpdateColumnPrivilege {
+                                id: *id,
+                                column: column.clone(),
+                                privilege: MzAclItem {
+                                    grantee: *grantee,
+                                    grantor,
+                                    acl_mode: *acl_mode,
+                                },
+                                variant,
+                            });
+                        }
+                    }
+                }
             }
         }
 
@@ -6117,6 +6858,122 @@ where
         .then_some(AdapterNotice::PerReplicaLogRead { log_names }))
 }
 
+/// Splits the rows read by a write into a table with keys into the rows the
+/// write proposes to change and the existing rows of the table they may
+/// conflict with, stripping the trailing column that tells them apart.
+fn split_conflict_rows(arity: usize, rows: Vec<Row>) -> (Vec<Row>, Vec<Row>) {
+    let mut proposed = Vec::new();
+    let mut existing = Vec::new();
+    for row in rows {
+        let datums = row.unpack();
+        let row = Row::pack_slice(&datums[..arity]);
+        if datums[arity] == Datum::True {
+            existing.push(row);
+        } else {
+            proposed.push(row);
+        }
+    }
+    (proposed, existing)
+}
+
+/// Computes the updates of an insert into a table with keys.
+///
+/// `rows` holds the rows proposed for insertion and the existing rows of the
+/// table that share a key with any of them, as produced by the insert's
+/// selection. A proposed row that conflicts with another row on the key of the
+/// `ON CONFLICT` clause is resolved as directed by the clause, and is rejected
+/// otherwise.
+fn resolve_insert_conflicts(
+    desc: &RelationDesc,
+    conflicts: &plan::InsertConflicts,
+    rows: Vec<Row>,
+) -> Result<Vec<(Row, Diff)>, AdapterError> {
+    let plan::InsertConflicts { keys, on_conflict } = conflicts;
+    let arity = desc.arity();
+
+    // For each key, the rows of the table by their values for that key, as
+    // they stand after applying the updates computed so far.
+    let mut index: Vec<BTreeMap<Row, Row>> = vec![BTreeMap::new(); keys.len()];
+    let (proposed, existing) = split_conflict_rows(arity, rows);
+    for row in existing {
+        for (key, index) in keys.iter().zip(index.iter_mut()) {
+            index.insert(key_of(&row, key), row.clone());
+        }
+    }
+
+    let arena = RowArena::new();
+    let mut diffs = Vec::with_capacity(proposed.len());
+    // Rows inserted or updated by this command.
+    let mut written = BTreeSet::new();
+    for row in proposed {
+        let conflicting: Vec<_> = keys
+            .iter()
+            .zip(index.iter())
+            .enumerate()
+            .filter_map(|(i, (key, index))| index.get(&key_of(&row, key)).map(|c| (i, c.clone())))
+            .collect();
+        if conflicting.is_empty() {
+            for (key, index) in keys.iter().zip(index.iter_mut()) {
+                index.insert(key_of(&row, key), row.clone());
+            }
+            written.insert(row.clone());
+            diffs.push((row, 1));
+            continue;
+        }
+
+        let resolution = conflicting
+            .iter()
+            .find(|(i, _)| on_conflict.key.map_or(true, |key| key == *i))
+            .map(|(_, existing)| (existing, &on_conflict.action));
+        let (existing, assignments, selection) = match resolution {
+            None => {
+                let (i, _) = &conflicting[0];
+                return Err(unique_violation(desc, &row, &keys[*i]));
+            }
+            Some((_, plan::OnConflictAction::DoNothing)) => continue,
+            Some((
+                existing,
+                plan::OnConflictAction::DoUpdate {
+                    assignments,
+                    selection,
+                },
+            )) => (existing, assignments, selection),
+        };
+        if written.contains(existing) {
+            return Err(AdapterError::OnConflictAffectsRowTwice);
+        }
+
+        // The `ON CONFLICT` expressions see the existing row followed by the
+        // proposed row.
+        let datums: Vec<_> = existing.iter().chain(row.iter()).collect();
+        if let Some(selection) = selection {
+            if selection.eval(&datums, &arena)? != Datum::True {
+                continue;
+            }
+        }
+        let mut updated = datums[..arity].to_vec();
+        for (idx, expr) in assignments {
+            updated[*idx] = expr.eval(&datums, &arena)?;
+        }
+        let updated = Row::pack_slice(&updated);
+
+        for (key, index) in keys.iter().zip(index.iter_mut()) {
+            index.remove(&key_of(existing, key));
+        }
+        for (key, index) in keys.iter().zip(index.iter_mut()) {
+            let key_values = key_of(&updated, key);
+            if index.contains_key(&key_values) {
+                return Err(unique_violation(desc, &updated, key));
+            }
+            index.insert(key_values, updated.clone());
+        }
+        written.insert(updated.clone());
+        diffs.push((existing.clone(), -1));
+        diffs.push((updated, 1));
+    }
+    Ok(diffs)
+}
+
 /// Return a [`SourceSinkClusterConfig`] based on the possibly altered
 /// parameters.
 fn alter_storage_cluster_config(size: AlterOptionParameter) -> Option<SourceSinkClusterConfig> {
@@ -208,6 +208,16 @@ impl Coordinator {
         }
     }
 
+    /// Drops the sinks that deliver notifications to a connection.
+    pub(crate) fn clear_listen_sinks(&mut self, conn_id: &ConnectionId) {
+        let conn_meta = self
+            .active_conns
+            .get_mut(conn_id)
+            .expect("must exist for active session");
+        let listen_sinks = std::mem::take(&mut conn_meta.listen_sinks);
+        self.drop_compute_sinks(listen_sinks.into_values());
+    }
+
     /// Handle adding metadata associated with a SUBSCRIBE query.
     pub(crate) async fn add_active_subscribe(
         &mut self,
@@ -244,6 +254,14 @@ impl Coordinator {
                 .active_subscribes
                 .with_label_values(&[session_type])
                 .dec();
+
+            // The subscribe may have been delivering notifications, e.g., if
+            // its cluster was dropped.
+            if let Some(conn_meta) = self.active_conns.get_mut(&active_subscribe.conn_id) {
+                conn_meta
+                    .listen_sinks
+                    .retain(|_, sink| sink.global_id != id);
+            }
         }
         // Note: Drop sinks are removed at commit time.
     }
@@ -396,6 +396,8 @@ impl Coordinator {
                         | CatalogItem::Func(_)
                         | CatalogItem::Secret(_)
                         | CatalogItem::Connection(_)
+                        | CatalogItem::Policy(_)
+                        | CatalogItem::Subscription(_)
                         | CatalogItem::Log(_) => {}
                     }
                 }
@@ -544,7 +546,9 @@ impl Coordinator {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
-                    | CatalogItem::Connection(_) => {}
+                    | CatalogItem::Connection(_)
+                    | CatalogItem::Policy(_)
+                    | CatalogItem::Subscription(_) => {}
                 }
             }
         }
@@ -726,5 +730,6 @@ impl Coordinator {
             self.global_timelines
                 .insert(timeline, TimelineState { oracle, read_holds });
         }
+        self.advance_subscription_read_holds().await;
     }
 }
@@ -100,8 +100,28 @@ pub enum AdapterError {
     },
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// A write would have produced two rows with the same key. `key`
+    /// describes the key's columns and values, e.g. `(a, b)=(1, 2)`.
+    UniqueViolation {
+        key: String,
+    },
+    /// An `INSERT ... ON CONFLICT DO UPDATE` proposed two rows that conflict
+    /// with the same row.
+    OnConflictAffectsRowTwice,
     /// Transaction cluster was dropped in the middle of a transaction.
     ConcurrentClusterDrop,
+    /// The named table's columns changed while an operation on it was
+    /// in progress.
+    ConcurrentTableAlter(String),
+    /// An `ALTER TABLE` would have changed or broken an object that depends
+    /// on the table.
+    AlterTableDependentObject {
+        /// Whether the column was being added (as opposed to dropped).
+        adding: bool,
+        table: String,
+        column: String,
+        dependent: String,
+    },
     /// Target cluster has no replicas to service query.
     NoClusterReplicasAvailable(String),
     /// The named operation cannot be run in a transaction.
@@ -314,6 +334,18 @@ impl AdapterError {
                 storage_error.source().map(|source_error| source_error.to_string_with_causes())
             }
             AdapterError::ReadOnlyTransaction => Some("SELECT queries cannot be combined with other query types, including SUBSCRIBE.".into()),
+            AdapterError::UniqueViolation { key } => Some(format!("Key {key} already exists.")),
+            AdapterError::AlterTableDependentObject {
+                adding: false,
+                table,
+                column,
+                dependent,
+            } => Some(format!(
+                "{} depends on column {} of table {}",
+                dependent,
+                column.quoted(),
+                table.quoted()
+            )),
             AdapterError::InvalidAlter(_, e) => e.detail(),
             _ => None,
         }
@@ -329,6 +361,11 @@ impl AdapterError {
             ),
             AdapterError::Catalog(c) => c.hint(),
             AdapterError::Eval(e) => e.hint(),
+            AdapterError::OnConflictAffectsRowTwice => Some(
+                "Ensure that no rows proposed for insertion within the same command have \
+                duplicate constrained values."
+                    .into(),
+            ),
             AdapterError::InvalidClusterReplicaAz { expected, az: _ } => {
                 Some(if expected.is_empty() {
                     "No availability zones configured; do not specify AVAILABILITY ZONE".into()
@@ -418,7 +455,13 @@ impl AdapterError {
             AdapterError::SourceOrSinkSizeRequired { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::InvalidTableMutationSelection => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::UniqueViolation { .. } => SqlState::UNIQUE_VIOLATION,
+            AdapterError::OnConflictAffectsRowTwice => SqlState::CARDINALITY_VIOLATION,
             AdapterError::ConcurrentClusterDrop => SqlState::INVALID_TRANSACTION_STATE,
+            AdapterError::ConcurrentTableAlter(_) => SqlState::T_R_SERIALIZATION_FAILURE,
+            AdapterError::AlterTableDependentObject { .. } => {
+                SqlState::DEPENDENT_OBJECTS_STILL_EXIST
+            }
             AdapterError::NoClusterReplicasAvailable(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::OperationProhibitsTransaction(_) => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterError::OperationRequiresTransaction(_) => SqlState::NO_ACTIVE_SQL_TRANSACTION,
@@ -551,9 +594,41 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::UniqueViolation { .. } => {
+                f.write_str("duplicate key value violates unique constraint")
+            }
+            AdapterError::OnConflictAffectsRowTwice => {
+                f.write_str("ON CONFLICT DO UPDATE command cannot affect row a second time")
+            }
             AdapterError::ConcurrentClusterDrop => {
                 write!(f, "the transaction's active cluster has been dropped")
             }
+            AdapterError::ConcurrentTableAlter(table) => {
+                write!(f, "table {} was concurrently altered", table.quoted())
+            }
+            AdapterError::AlterTableDependentObject {
+                adding: true,
+                table,
+                column,
+                dependent,
+            } => write!(
+                f,
+                "cannot add column {} to table {} because it would change the definition of {}",
+                column.quoted(),
+                table.quoted(),
+                dependent
+            ),
+            AdapterError::AlterTableDependentObject {
+                adding: false,
+                table,
+                column,
+                ..
+            } => write!(
+                f,
+                "cannot drop column {} of table {} because other objects depend on it",
+                column.quoted(),
+                table.quoted()
+            ),
             AdapterError::NoClusterReplicasAvailable(cluster) => {
                 write!(
                     f,
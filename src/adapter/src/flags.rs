@@ -179,6 +179,7 @@ fn persist_config(config: &SystemVars) -> PersistParameters {
     PersistParameters {
         blob_target_size: Some(config.persist_blob_target_size()),
         blob_cache_mem_limit_bytes: Some(config.persist_blob_cache_mem_limit_bytes()),
+        blob_cache_disk_limit_bytes: Some(config.persist_blob_cache_disk_limit_bytes()),
         compaction_minimum_timeout: Some(config.persist_compaction_minimum_timeout()),
         consensus_connect_timeout: Some(config.crdb_connect_timeout()),
         consensus_tcp_user_timeout: Some(config.crdb_tcp_user_timeout()),
@@ -202,11 +203,13 @@ fn persist_config(config: &SystemVars) -> PersistParameters {
             clamp: config.persist_txns_data_shard_retryer_clamp(),
         }),
         reader_lease_duration: Some(config.persist_reader_lease_duration()),
+        state_history_retention: Some(config.persist_state_history_retention()),
         stats_audit_percent: Some(config.persist_stats_audit_percent()),
         stats_collection_enabled: Some(config.persist_stats_collection_enabled()),
         stats_filter_enabled: Some(config.persist_stats_filter_enabled()),
         stats_budget_bytes: Some(config.persist_stats_budget_bytes()),
         stats_untrimmable_columns: Some(config.persist_stats_untrimmable_columns()),
+        blob_compression: Some(config.persist_blob_compression()),
         pubsub_client_enabled: Some(config.persist_pubsub_client_enabled()),
         pubsub_push_diff_enabled: Some(config.persist_pubsub_push_diff_enabled()),
         rollup_threshold: Some(config.persist_rollup_threshold()),
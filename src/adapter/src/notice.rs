@@ -125,6 +125,10 @@ pub enum AdapterNotice {
     PerReplicaLogRead {
         log_names: Vec<String>,
     },
+    StaleIndex {
+        index_name: String,
+        table_name: String,
+    },
     Welcome(String),
 }
 
@@ -179,12 +183,15 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
+                PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::ColumnDoesNotExist { .. } => Severity::Notice,
             },
             AdapterNotice::UnknownSessionDatabase(_) => Severity::Notice,
             AdapterNotice::OptimizerNotice { .. } => Severity::Notice,
             AdapterNotice::WebhookSourceCreated { .. } => Severity::Notice,
             AdapterNotice::DroppedInUseIndex { .. } => Severity::Notice,
             AdapterNotice::PerReplicaLogRead { .. } => Severity::Notice,
+            AdapterNotice::StaleIndex { .. } => Severity::Warning,
             AdapterNotice::Welcome(_) => Severity::Notice,
         }
     }
@@ -229,6 +236,7 @@ impl AdapterNotice {
             ),
             AdapterNotice::OptimizerNotice { notice: _, hint } => Some(hint.clone()),
             AdapterNotice::DroppedInUseIndex(..) => Some("To free up the resources used by the index, recreate all the above-mentioned objects.".into()),
+            AdapterNotice::StaleIndex { .. } => Some("Drop and recreate the index to index the table's new columns.".into()),
             _ => None
         }
     }
@@ -268,12 +276,15 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
+                PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::ColumnDoesNotExist { .. } => SqlState::UNDEFINED_COLUMN,
             },
             AdapterNotice::UnknownSessionDatabase(_) => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::OptimizerNotice { .. } => SqlState::SUCCESSFUL_COMPLETION,
             AdapterNotice::DroppedInUseIndex { .. } => SqlState::WARNING,
             AdapterNotice::WebhookSourceCreated { .. } => SqlState::WARNING,
             AdapterNotice::PerReplicaLogRead { .. } => SqlState::WARNING,
+            AdapterNotice::StaleIndex { .. } => SqlState::WARNING,
             AdapterNotice::Welcome(_) => SqlState::SUCCESSFUL_COMPLETION,
         }
     }
@@ -425,6 +436,17 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::PerReplicaLogRead { log_names } => {
                 write!(f, "Queried introspection relations: {}. Unlike other objects in Materialize, results from querying these objects depend on the current values of the `cluster` and `cluster_replica` session variables.", log_names.join(", "))
             }
+            AdapterNotice::StaleIndex {
+                index_name,
+                table_name,
+            } => {
+                write!(
+                    f,
+                    "index {} still arranges the previous columns of table {}, and is not used to read the table until it is recreated or Materialize is restarted",
+                    index_name.quoted(),
+                    table_name.quoted()
+                )
+            }
             AdapterNotice::Welcome(message) => message.fmt(f),
         }
     }
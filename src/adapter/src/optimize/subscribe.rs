@@ -28,14 +28,16 @@ use mz_transform::Optimizer as TransformOptimizer;
 use timely::progress::Antichain;
 use tracing::{span, Level};
 
-use crate::catalog::Catalog;
+use crate::catalog::{Catalog, CatalogState};
 use crate::coord::dataflows::{
-    dataflow_import_id_bundle, ComputeInstanceSnapshot, DataflowBuilder,
+    dataflow_import_id_bundle, prep_relation_expr, prep_scalar_expr, ComputeInstanceSnapshot,
+    DataflowBuilder, ExprPrepStyle,
 };
 use crate::optimize::{
     LirDataflowDescription, MirDataflowDescription, Optimize, OptimizerConfig, OptimizerError,
 };
-use crate::CollectionIdBundle;
+use crate::session::Session;
+use crate::{AdapterError, CollectionIdBundle};
 
 pub struct Optimizer {
     /// A typechecking context to use throughout the optimizer pipeline.
@@ -183,6 +185,7 @@ impl Optimize<SubscribeFrom> for Optimizer {
                     up_to: self.up_to.map(Antichain::from_elem).unwrap_or_default(),
                     // No `FORCE NOT NULL` for subscribes
                     non_null_assertions: vec![],
+                    refresh_schedule: None,
                 };
 
                 let mut df_builder =
@@ -223,6 +226,7 @@ impl Optimize<SubscribeFrom> for Optimizer {
                     up_to: self.up_to.map(Antichain::from_elem).unwrap_or_default(),
                     // No `FORCE NOT NULL` for subscribes
                     non_null_assertions: vec![],
+                    refresh_schedule: None,
                 };
 
                 let mut df_builder =
@@ -250,6 +254,24 @@ impl Optimize<SubscribeFrom> for Optimizer {
 }
 
 impl GlobalMirPlan<Unresolved> {
+    /// Resolves the calls to unmaterializable functions that are fixed for the
+    /// `session` running the `SUBSCRIBE`, e.g. those in row-level security
+    /// policies.
+    pub fn prep_session_exprs(
+        &mut self,
+        session: &Session,
+        catalog_state: &CatalogState,
+    ) -> Result<(), AdapterError> {
+        let style = ExprPrepStyle::Subscribe {
+            session,
+            catalog_state,
+        };
+        self.df_desc.visit_children(
+            |r| prep_relation_expr(r, style),
+            |s| prep_scalar_expr(s, style),
+        )
+    }
+
     /// Produces the [`GlobalMirPlan`] with [`Resolved`] timestamp.
     ///
     /// We need to resolve timestamps before the `GlobalMirPlan ⇒ GlobalLirPlan`
@@ -14,6 +14,7 @@
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
 use std::mem;
+use std::net::IpAddr;
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
@@ -87,6 +88,8 @@ pub struct Session<T = mz_repr::Timestamp> {
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
+    /// The IP address of the client, if known.
+    client_ip: Option<IpAddr>,
     // Token allowing us to access `Arc<QCell<StatementLogging>>`
     // metadata. We want these to be reference-counted, because the same
     // statement might be referenced from multiple portals simultaneously.
@@ -187,6 +190,7 @@ impl<T: TimestampManipulation> Session<T> {
             next_transaction_id: 0,
             secret_key: rand::thread_rng().gen(),
             external_metadata_rx: None,
+            client_ip: None,
             qcell_owner: QCellOwner::new(),
         }
     }
@@ -196,6 +200,16 @@ impl<T: TimestampManipulation> Session<T> {
         &self.conn_id
     }
 
+    /// Returns the IP address of the client, if known.
+    pub fn client_ip(&self) -> Option<IpAddr> {
+        self.client_ip
+    }
+
+    /// Records the IP address of the client.
+    pub fn set_client_ip(&mut self, client_ip: IpAddr) {
+        self.client_ip = Some(client_ip);
+    }
+
     /// Returns the secret key associated with the session.
     pub fn secret_key(&self) -> u32 {
         self.secret_key
@@ -130,6 +130,7 @@ pub fn analyze_audited_statement(
         StatementKind::AlterSecret => Some((StatementAction::Alter, ObjectType::Secret)),
         StatementKind::AlterSink => Some((StatementAction::Alter, ObjectType::Sink)),
         StatementKind::AlterSource => Some((StatementAction::Alter, ObjectType::Source)),
+        StatementKind::AlterTable => Some((StatementAction::Alter, ObjectType::Table)),
         StatementKind::CreateCluster => Some((StatementAction::Create, ObjectType::Cluster)),
         StatementKind::CreateClusterReplica => {
             Some((StatementAction::Create, ObjectType::ClusterReplica))
@@ -140,6 +141,10 @@ pub fn analyze_audited_statement(
         StatementKind::CreateMaterializedView => {
             Some((StatementAction::Create, ObjectType::MaterializedView))
         }
+        StatementKind::CreatePolicy => Some((StatementAction::Create, ObjectType::Policy)),
+        StatementKind::CreateSubscription => {
+            Some((StatementAction::Create, ObjectType::Subscription))
+        }
         StatementKind::CreateRole => Some((StatementAction::Create, ObjectType::Role)),
         StatementKind::CreateSchema => Some((StatementAction::Create, ObjectType::Schema)),
         StatementKind::CreateSecret => Some((StatementAction::Create, ObjectType::Secret)),
@@ -176,11 +176,13 @@ pub enum ObjectType {
     Func,
     Index,
     MaterializedView,
+    Policy,
     Role,
     Secret,
     Schema,
     Sink,
     Source,
+    Subscription,
     System,
     Table,
     Type,
@@ -197,11 +199,13 @@ impl ObjectType {
             ObjectType::Func => "Function",
             ObjectType::Index => "Index",
             ObjectType::MaterializedView => "Materialized View",
+            ObjectType::Policy => "Policy",
             ObjectType::Role => "Role",
             ObjectType::Schema => "Schema",
             ObjectType::Secret => "Secret",
             ObjectType::Sink => "Sink",
             ObjectType::Source => "Source",
+            ObjectType::Subscription => "Subscription",
             ObjectType::System => "System",
             ObjectType::Table => "Table",
             ObjectType::Type => "Type",
@@ -227,6 +231,7 @@ pub enum EventDetails {
     RevokeRoleV1(RevokeRoleV1),
     RevokeRoleV2(RevokeRoleV2),
     UpdatePrivilegeV1(UpdatePrivilegeV1),
+    UpdateColumnPrivilegeV1(UpdateColumnPrivilegeV1),
     AlterDefaultPrivilegeV1(AlterDefaultPrivilegeV1),
     UpdateOwnerV1(UpdateOwnerV1),
     IdFullNameV1(IdFullNameV1),
@@ -379,6 +384,15 @@ pub struct UpdatePrivilegeV1 {
     pub privileges: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
+pub struct UpdateColumnPrivilegeV1 {
+    pub object_id: String,
+    pub column_name: String,
+    pub grantee_id: String,
+    pub grantor_id: String,
+    pub privileges: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialOrd, PartialEq, Eq, Ord, Hash, Arbitrary)]
 pub struct AlterDefaultPrivilegeV1 {
     pub role_id: String,
@@ -452,6 +466,9 @@ impl EventDetails {
             EventDetails::RevokeRoleV1(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::RevokeRoleV2(v) => serde_json::to_value(v).expect("must serialize"),
             EventDetails::UpdatePrivilegeV1(v) => serde_json::to_value(v).expect("must serialize"),
+            EventDetails::UpdateColumnPrivilegeV1(v) => {
+                serde_json::to_value(v).expect("must serialize")
+            }
             EventDetails::AlterDefaultPrivilegeV1(v) => {
                 serde_json::to_value(v).expect("must serialize")
             }
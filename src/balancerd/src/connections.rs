@@ -0,0 +1,656 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Tracking and proxying of pgwire sessions.
+//!
+//! The balancer tracks each proxied pgwire connection by its tenant, to enforce
+//! per-tenant connection limits, and by the environmentd it is proxied to, to
+//! support draining an environmentd before it is shut down, e.g., during an
+//! upgrade.
+//!
+//! Draining an environmentd rejects new connections to it and terminates each
+//! of its existing sessions as soon as the session is idle, i.e., not in a
+//! transaction and not waiting on a response. To learn when that is, the proxy
+//! follows the framing of the pgwire messages in each direction, but never
+//! buffers or modifies them.
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::BytesMut;
+use mz_pgwire_common::ErrorResponse;
+use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::watch;
+use tokio::time::{self, Instant};
+use tokio_postgres::error::SqlState;
+use tokio_util::codec::Encoder;
+
+use crate::codec::{BackendMessage, Codec};
+
+/// How long past its drain deadline a session may take to shut down before the
+/// proxy abandons it, e.g., because the client is not reading.
+const FORCE_CLOSE_GRACE: Duration = Duration::from_secs(5);
+
+/// Tracks the pgwire connections that the balancer is proxying.
+#[derive(Debug)]
+pub(crate) struct ConnectionTracker {
+    /// The maximum number of connections of any one tenant, if any.
+    max_connections_per_tenant: Option<usize>,
+    state: Mutex<TrackerState>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    /// The number of connections of each tenant that has any.
+    tenants: BTreeMap<String, usize>,
+    /// Each environmentd that has connections or is draining.
+    backends: BTreeMap<SocketAddr, Backend>,
+}
+
+#[derive(Debug)]
+struct Backend {
+    /// The number of connections proxied to the backend.
+    connections: usize,
+    /// The deadline by which the backend's sessions must be terminated, if the
+    /// backend is draining.
+    drain_tx: watch::Sender<Option<Instant>>,
+}
+
+impl Backend {
+    fn new() -> Backend {
+        let (drain_tx, _) = watch::channel(None);
+        Backend {
+            connections: 0,
+            drain_tx,
+        }
+    }
+
+    fn is_draining(&self) -> bool {
+        self.drain_tx.borrow().is_some()
+    }
+}
+
+/// The reason a new connection was refused by [`ConnectionTracker::register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RegisterError {
+    /// The backend is draining.
+    Draining,
+    /// The tenant has reached its connection limit.
+    TooManyConnections { limit: usize },
+}
+
+impl RegisterError {
+    /// Returns a label for the error, for use in metrics.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            RegisterError::Draining => "draining",
+            RegisterError::TooManyConnections { .. } => "tenant_limit",
+        }
+    }
+}
+
+impl From<RegisterError> for ErrorResponse {
+    fn from(err: RegisterError) -> ErrorResponse {
+        match err {
+            RegisterError::Draining => ErrorResponse {
+                hint: Some("Try connecting again shortly.".into()),
+                ..ErrorResponse::fatal(
+                    SqlState::CANNOT_CONNECT_NOW,
+                    "the environment is restarting",
+                )
+            },
+            RegisterError::TooManyConnections { limit } => ErrorResponse::fatal(
+                SqlState::TOO_MANY_CONNECTIONS,
+                format!("too many connections; the limit is {limit} connections per tenant"),
+            ),
+        }
+    }
+}
+
+impl ConnectionTracker {
+    pub(crate) fn new(max_connections_per_tenant: Option<usize>) -> ConnectionTracker {
+        ConnectionTracker {
+            max_connections_per_tenant,
+            state: Mutex::new(TrackerState::default()),
+        }
+    }
+
+    /// Registers a new connection of `tenant` to the environmentd at `addr`.
+    ///
+    /// The connection is tracked until the returned guard is dropped.
+    pub(crate) fn register(
+        self: &Arc<Self>,
+        tenant: String,
+        addr: SocketAddr,
+    ) -> Result<ConnectionGuard, RegisterError> {
+        let mut state = self.state.lock().expect("lock poisoned");
+        if state
+            .backends
+            .get(&addr)
+            .map_or(false, Backend::is_draining)
+        {
+            return Err(RegisterError::Draining);
+        }
+        let count = state.tenants.entry(tenant.clone()).or_default();
+        if let Some(limit) = self.max_connections_per_tenant {
+            if *count >= limit {
+                if *count == 0 {
+                    state.tenants.remove(&tenant);
+                }
+                return Err(RegisterError::TooManyConnections { limit });
+            }
+        }
+        *count += 1;
+        let backend = state.backends.entry(addr).or_insert_with(Backend::new);
+        backend.connections += 1;
+        Ok(ConnectionGuard {
+            tracker: Arc::clone(self),
+            tenant,
+            addr,
+            drain_rx: backend.drain_tx.subscribe(),
+        })
+    }
+
+    /// Starts draining the environmentd at `addr`.
+    ///
+    /// New connections to the environmentd are rejected until
+    /// [`ConnectionTracker::undrain`] is called. Its existing sessions are
+    /// terminated once they are idle, or at `deadline` at the latest.
+    pub(crate) fn drain(&self, addr: SocketAddr, deadline: Instant) {
+        let mut state = self.state.lock().expect("lock poisoned");
+        let backend = state.backends.entry(addr).or_insert_with(Backend::new);
+        backend.drain_tx.send_replace(Some(deadline));
+    }
+
+    /// Stops draining the environmentd at `addr`, if it is draining.
+    pub(crate) fn undrain(&self, addr: SocketAddr) {
+        let mut state = self.state.lock().expect("lock poisoned");
+        if let Some(backend) = state.backends.get(&addr) {
+            backend.drain_tx.send_replace(None);
+            if backend.connections == 0 {
+                state.backends.remove(&addr);
+            }
+        }
+    }
+
+    /// Returns the number of connections proxied to the environmentd at
+    /// `addr`.
+    pub(crate) fn connections(&self, addr: SocketAddr) -> usize {
+        let state = self.state.lock().expect("lock poisoned");
+        state.backends.get(&addr).map_or(0, |b| b.connections)
+    }
+}
+
+/// A connection registered with a [`ConnectionTracker`].
+#[derive(Debug)]
+pub(crate) struct ConnectionGuard {
+    tracker: Arc<ConnectionTracker>,
+    tenant: String,
+    addr: SocketAddr,
+    drain_rx: watch::Receiver<Option<Instant>>,
+}
+
+impl ConnectionGuard {
+    /// Returns a receiver of the drain deadline of the connection's backend.
+    pub(crate) fn drain_rx(&self) -> watch::Receiver<Option<Instant>> {
+        self.drain_rx.clone()
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        let mut state = self.tracker.state.lock().expect("lock poisoned");
+        if let Some(count) = state.tenants.get_mut(&self.tenant) {
+            *count -= 1;
+            if *count == 0 {
+                state.tenants.remove(&self.tenant);
+            }
+        }
+        if let Some(backend) = state.backends.get_mut(&self.addr) {
+            backend.connections -= 1;
+            // Draining backends are remembered until they are undrained, so
+            // that they keep rejecting new connections.
+            if backend.connections == 0 && !backend.is_draining() {
+                state.backends.remove(&self.addr);
+            }
+        }
+    }
+}
+
+/// How a proxied session ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SessionEnd {
+    /// The client or the backend closed the connection.
+    Closed,
+    /// The backend was drained and the session was terminated while idle.
+    DrainedIdle,
+    /// The backend was drained and the session was terminated at the drain
+    /// deadline.
+    DrainedTimeout,
+}
+
+impl SessionEnd {
+    /// Returns a label for the end, for use in metrics.
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            SessionEnd::Closed => "closed",
+            SessionEnd::DrainedIdle => "drained_idle",
+            SessionEnd::DrainedTimeout => "drained_timeout",
+        }
+    }
+}
+
+/// Proxies a pgwire session between `client` and `backend` until either closes
+/// the connection or the backend is drained.
+///
+/// `initial` holds bytes that were already read from the backend and must be
+/// sent to the client first.
+pub(crate) async fn proxy_session<C, B>(
+    client: &mut C,
+    backend: &mut B,
+    initial: &[u8],
+    mut drain_rx: watch::Receiver<Option<Instant>>,
+) -> Result<SessionEnd, io::Error>
+where
+    C: AsyncRead + AsyncWrite + Unpin,
+    B: AsyncRead + AsyncWrite + Unpin,
+{
+    let state = Mutex::new(SessionState::default());
+    let (mut client_read, mut client_write) = io::split(client);
+    let (mut backend_read, mut backend_write) = io::split(backend);
+
+    let client_to_backend = async {
+        let mut framer = MessageFramer::default();
+        let mut buf = vec![0; 8192];
+        loop {
+            let n = client_read.read(&mut buf).await?;
+            if n == 0 {
+                backend_write.shutdown().await?;
+                return Ok::<_, io::Error>(());
+            }
+            {
+                let mut state = state.lock().expect("lock poisoned");
+                // Once the session is terminated, the client's messages must
+                // not reach the backend, as the client will not learn their
+                // outcome.
+                if state.terminated {
+                    return Ok(());
+                }
+                framer.feed(&buf[..n], |frame| {
+                    if let Frame::Start(ty) = frame {
+                        state.on_frontend_message(ty);
+                    }
+                });
+                state.client_partial = !framer.at_boundary();
+            }
+            backend_write.write_all(&buf[..n]).await?;
+        }
+    };
+
+    let mut deadline_rx = drain_rx.clone();
+    let backend_to_client = async {
+        let mut framer = MessageFramer::default();
+        let mut buf = vec![0; 8192];
+        let mut n = initial.len();
+        buf[..n].copy_from_slice(initial);
+        let mut drain_deadline = *drain_rx.borrow_and_update();
+        let mut drain_open = true;
+        loop {
+            if n > 0 {
+                {
+                    let mut state = state.lock().expect("lock poisoned");
+                    framer.feed(&buf[..n], |frame| {
+                        if let Frame::End { ty: b'Z', first } = frame {
+                            state.on_ready_for_query(first);
+                        }
+                    });
+                }
+                client_write.write_all(&buf[..n]).await?;
+                client_write.flush().await?;
+            }
+
+            if let Some(deadline) = drain_deadline {
+                let end = {
+                    let mut state = state.lock().expect("lock poisoned");
+                    let end = if state.is_idle() && framer.at_boundary() {
+                        Some(SessionEnd::DrainedIdle)
+                    } else if Instant::now() >= deadline {
+                        Some(SessionEnd::DrainedTimeout)
+                    } else {
+                        None
+                    };
+                    state.terminated = end.is_some();
+                    end
+                };
+                if let Some(end) = end {
+                    // Tell the client why the session ended, unless that would
+                    // interrupt a message from the backend.
+                    if framer.at_boundary() {
+                        client_write.write_all(&admin_shutdown_message()).await?;
+                    }
+                    client_write.shutdown().await?;
+                    return Ok(end);
+                }
+            }
+
+            n = 0;
+            tokio::select! {
+                res = backend_read.read(&mut buf) => {
+                    n = res?;
+                    if n == 0 {
+                        client_write.shutdown().await?;
+                        return Ok(SessionEnd::Closed);
+                    }
+                }
+                res = drain_rx.changed(), if drain_open => match res {
+                    Ok(()) => drain_deadline = *drain_rx.borrow_and_update(),
+                    Err(_) => drain_open = false,
+                },
+                _ = time::sleep_until(drain_deadline.unwrap_or_else(Instant::now)),
+                    if drain_deadline.is_some() => {}
+            }
+        }
+    };
+
+    tokio::pin!(client_to_backend);
+    tokio::pin!(backend_to_client);
+    let mut client_done = false;
+    loop {
+        tokio::select! {
+            res = &mut client_to_backend, if !client_done => {
+                res?;
+                client_done = true;
+            }
+            res = &mut backend_to_client => return res,
+            // A session that could not shut down by its drain deadline, e.g.,
+            // because the client is not reading, is abandoned.
+            () = force_close(&mut deadline_rx) => return Ok(SessionEnd::DrainedTimeout),
+        }
+    }
+}
+
+/// Resolves once the drain deadline that `drain_rx` reports has been passed by
+/// [`FORCE_CLOSE_GRACE`].
+async fn force_close(drain_rx: &mut watch::Receiver<Option<Instant>>) {
+    loop {
+        let deadline = *drain_rx.borrow_and_update();
+        let changed = async {
+            if drain_rx.changed().await.is_err() {
+                futures::future::pending::<()>().await;
+            }
+        };
+        match deadline {
+            Some(deadline) => tokio::select! {
+                _ = time::sleep_until(deadline + FORCE_CLOSE_GRACE) => return,
+                () = changed => {}
+            },
+            None => changed.await,
+        }
+    }
+}
+
+/// Returns the encoded `ErrorResponse` with which drained sessions are
+/// terminated.
+fn admin_shutdown_message() -> BytesMut {
+    let err = ErrorResponse {
+        hint: Some("Try connecting again shortly.".into()),
+        ..ErrorResponse::fatal(
+            SqlState::ADMIN_SHUTDOWN,
+            "terminating connection because the environment is restarting",
+        )
+    };
+    let mut buf = BytesMut::new();
+    Codec::new()
+        .encode(BackendMessage::ErrorResponse(err), &mut buf)
+        .expect("encoding to memory cannot fail");
+    buf
+}
+
+/// The state of a proxied session, as far as it matters for draining.
+#[derive(Debug, Default)]
+struct SessionState {
+    /// The number of queries and syncs the client has sent that the backend
+    /// has not yet answered with a `ReadyForQuery` message.
+    pending: usize,
+    /// Whether the client has sent extended query messages that have not been
+    /// followed by a sync.
+    unsynced: bool,
+    /// Whether the client has sent only part of a message.
+    client_partial: bool,
+    /// The transaction status of the last `ReadyForQuery` message, if any.
+    transaction_status: Option<u8>,
+    /// Whether the session has been terminated.
+    terminated: bool,
+}
+
+impl SessionState {
+    fn on_frontend_message(&mut self, ty: u8) {
+        match ty {
+            // Simple queries, syncs, and function calls are each answered by
+            // exactly one `ReadyForQuery` message.
+            b'Q' | b'S' | b'F' => {
+                self.pending += 1;
+                self.unsynced = false;
+            }
+            // Parse, Bind, Describe, Execute, Close, and Flush.
+            b'P' | b'B' | b'D' | b'E' | b'C' | b'H' => self.unsynced = true,
+            // Passwords, copy data, and terminations don't change whether the
+            // session is idle.
+            _ => (),
+        }
+    }
+
+    fn on_ready_for_query(&mut self, transaction_status: Option<u8>) {
+        self.pending = self.pending.saturating_sub(1);
+        self.transaction_status = transaction_status;
+    }
+
+    /// Whether the session may be terminated without interrupting the client.
+    fn is_idle(&self) -> bool {
+        self.pending == 0
+            && !self.unsynced
+            && !self.client_partial
+            && self.transaction_status == Some(b'I')
+    }
+}
+
+/// A message boundary found by a [`MessageFramer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    /// The header of a message of the given type was read.
+    Start(u8),
+    /// A message of the given type was read, whose body begins with `first`
+    /// unless the body is empty.
+    End { ty: u8, first: Option<u8> },
+}
+
+/// Follows the framing of a stream of pgwire messages after the startup
+/// phase, where each message is a type byte followed by a length.
+#[derive(Debug, Default)]
+struct MessageFramer {
+    header: [u8; 5],
+    header_len: usize,
+    /// The number of bytes of the body of the current message that have not
+    /// been read, if its header has been read.
+    remaining: Option<usize>,
+    first: Option<u8>,
+}
+
+impl MessageFramer {
+    /// Advances the framer over `bytes`, reporting each message boundary in
+    /// them to `f`.
+    fn feed(&mut self, mut bytes: &[u8], mut f: impl FnMut(Frame)) {
+        while !bytes.is_empty() {
+            match self.remaining {
+                None => {
+                    let n = (self.header.len() - self.header_len).min(bytes.len());
+                    self.header[self.header_len..self.header_len + n].copy_from_slice(&bytes[..n]);
+                    self.header_len += n;
+                    bytes = &bytes[n..];
+                    if self.header_len < self.header.len() {
+                        continue;
+                    }
+                    let len = i32::from_be_bytes(self.header[1..5].try_into().expect("4 bytes"));
+                    // The length includes itself. Malformed lengths are the
+                    // recipient's problem; treat them as empty bodies.
+                    let body_len = usize::try_from(len).unwrap_or(0).saturating_sub(4);
+                    self.remaining = Some(body_len);
+                    self.first = None;
+                    f(Frame::Start(self.header[0]));
+                }
+                Some(remaining) => {
+                    let n = remaining.min(bytes.len());
+                    if self.first.is_none() && n > 0 {
+                        self.first = Some(bytes[0]);
+                    }
+                    self.remaining = Some(remaining - n);
+                    bytes = &bytes[n..];
+                }
+            }
+            if self.remaining == Some(0) {
+                f(Frame::End {
+                    ty: self.header[0],
+                    first: self.first,
+                });
+                self.remaining = None;
+                self.header_len = 0;
+            }
+        }
+    }
+
+    /// Whether the framer is between messages.
+    fn at_boundary(&self) -> bool {
+        self.remaining.is_none() && self.header_len == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    use tokio::time::Instant;
+
+    use super::{ConnectionTracker, Frame, MessageFramer, RegisterError, SessionState};
+
+    #[mz_ore::test]
+    fn test_message_framer() {
+        // CommandComplete("SELECT 1") followed by ReadyForQuery('I'), fed one
+        // byte at a time.
+        let mut bytes = vec![b'C', 0, 0, 0, 13];
+        bytes.extend_from_slice(b"SELECT 1\0");
+        bytes.extend_from_slice(&[b'Z', 0, 0, 0, 5, b'I']);
+
+        let mut framer = MessageFramer::default();
+        let mut frames = vec![];
+        for b in &bytes {
+            framer.feed(&[*b], |frame| frames.push(frame));
+        }
+        assert!(framer.at_boundary());
+        assert_eq!(
+            frames,
+            vec![
+                Frame::Start(b'C'),
+                Frame::End {
+                    ty: b'C',
+                    first: Some(b'S')
+                },
+                Frame::Start(b'Z'),
+                Frame::End {
+                    ty: b'Z',
+                    first: Some(b'I')
+                },
+            ]
+        );
+
+        // Sync, which has an empty body, followed by part of a Query.
+        let mut frames = vec![];
+        framer.feed(&[b'S', 0, 0, 0, 4, b'Q', 0], |frame| frames.push(frame));
+        assert!(!framer.at_boundary());
+        assert_eq!(
+            frames,
+            vec![
+                Frame::Start(b'S'),
+                Frame::End {
+                    ty: b'S',
+                    first: None
+                }
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_session_idle() {
+        let mut state = SessionState::default();
+        // Not idle until the backend is ready for a query.
+        assert!(!state.is_idle());
+        state.on_ready_for_query(Some(b'I'));
+        assert!(state.is_idle());
+
+        // Pipelined queries are idle only once all of them are answered.
+        state.on_frontend_message(b'Q');
+        state.on_frontend_message(b'Q');
+        state.on_ready_for_query(Some(b'I'));
+        assert!(!state.is_idle());
+        state.on_ready_for_query(Some(b'I'));
+        assert!(state.is_idle());
+
+        // Extended queries are idle only once synced and answered.
+        state.on_frontend_message(b'P');
+        state.on_frontend_message(b'B');
+        assert!(!state.is_idle());
+        state.on_frontend_message(b'S');
+        state.on_ready_for_query(Some(b'I'));
+        assert!(state.is_idle());
+
+        // Open transactions are not idle.
+        state.on_frontend_message(b'Q');
+        state.on_ready_for_query(Some(b'T'));
+        assert!(!state.is_idle());
+    }
+
+    #[mz_ore::test]
+    fn test_connection_tracker() {
+        let tracker = Arc::new(ConnectionTracker::new(Some(1)));
+        let addr1: SocketAddr = "10.0.0.1:6875".parse().unwrap();
+        let addr2: SocketAddr = "10.0.0.2:6875".parse().unwrap();
+
+        let guard = tracker.register("a".into(), addr1).unwrap();
+        assert_eq!(
+            tracker.register("a".into(), addr1).unwrap_err(),
+            RegisterError::TooManyConnections { limit: 1 }
+        );
+        let other = tracker.register("b".into(), addr1).unwrap();
+        assert_eq!(tracker.connections(addr1), 2);
+
+        // Draining rejects new connections and notifies existing ones.
+        let deadline = Instant::now();
+        tracker.drain(addr1, deadline);
+        assert_eq!(*guard.drain_rx().borrow(), Some(deadline));
+        drop(other);
+        assert_eq!(
+            tracker.register("c".into(), addr1).unwrap_err(),
+            RegisterError::Draining
+        );
+        let _new = tracker.register("c".into(), addr2).unwrap();
+
+        // Dropping the last connection keeps rejecting new ones until the
+        // backend is undrained.
+        drop(guard);
+        assert_eq!(tracker.connections(addr1), 0);
+        assert_eq!(
+            tracker.register("a".into(), addr1).unwrap_err(),
+            RegisterError::Draining
+        );
+        tracker.undrain(addr1);
+        let _guard = tracker.register("a".into(), addr1).unwrap();
+    }
+}
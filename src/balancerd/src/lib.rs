@@ -83,17 +83,22 @@
 //! hostname is resolved to an IP address, and the connection is proxied to that address which has a
 //! running environmentd's pgwire port. When a new HTTPS connection starts, its SNI hostname is used
 //! to generate an internal hostname that is resolved to an IP address, which is similarly proxied.
+//!
+//! pgwire connections can be limited per tenant, and the environmentd that they are proxied to can be
+//! drained through the internal HTTP server before it is shut down. See the [`connections`] module.
 
 mod codec;
+mod connections;
 
 use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use axum::extract::Query;
 use axum::response::IntoResponse;
-use axum::{routing, Router};
+use axum::{routing, Extension, Router};
 use bytes::BytesMut;
 use futures::TryFutureExt;
 use hyper::StatusCode;
@@ -107,18 +112,22 @@ use mz_pgwire_common::{
     decode_startup, Conn, ErrorResponse, FrontendMessage, FrontendStartupMessage,
     ACCEPT_SSL_ENCRYPTION, REJECT_ENCRYPTION, VERSION_3,
 };
+use mz_server_core::proxy_protocol::ProxyHeader;
 use mz_server_core::{listen, ConnectionStream, ListenerHandle, TlsCertConfig, TlsConfig, TlsMode};
 use openssl::ssl::{NameType, Ssl, SslContext};
 use prometheus::{IntCounterVec, IntGaugeVec};
 use semver::Version;
+use serde::Deserialize;
 use tokio::io::{self, AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio::net::TcpStream;
+use tokio::sync::watch;
 use tokio::task::JoinSet;
 use tokio_openssl::SslStream;
 use tokio_postgres::error::SqlState;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::codec::{BackendMessage, FramedConn};
+use crate::connections::{ConnectionTracker, SessionEnd};
 
 /// Balancer build information.
 pub const BUILD_INFO: BuildInfo = build_info!();
@@ -137,6 +146,12 @@ pub struct BalancerConfig {
     https_addr_template: String,
     tls: Option<TlsCertConfig>,
     metrics_registry: MetricsRegistry,
+    /// The maximum number of pgwire connections of any one tenant, if any.
+    max_connections_per_tenant: Option<usize>,
+    /// Whether to send a PROXY protocol header with the address of the client to environmentd.
+    proxy_protocol: bool,
+    /// The default time after which a drain terminates sessions that are not idle.
+    drain_timeout: Duration,
 }
 
 impl BalancerConfig {
@@ -149,6 +164,9 @@ impl BalancerConfig {
         https_addr_template: String,
         tls: Option<TlsCertConfig>,
         metrics_registry: MetricsRegistry,
+        max_connections_per_tenant: Option<usize>,
+        proxy_protocol: bool,
+        drain_timeout: Duration,
     ) -> Self {
         Self {
             build_version: build_info.semver_version(),
@@ -159,6 +177,9 @@ impl BalancerConfig {
             https_addr_template,
             tls,
             metrics_registry,
+            max_connections_per_tenant,
+            proxy_protocol,
+            drain_timeout,
         }
     }
 }
@@ -192,7 +213,7 @@ pub struct BalancerService {
     cfg: BalancerConfig,
     pub pgwire: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
     pub https: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
-    internal_http: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
+    pub internal_http: (ListenerHandle, Pin<Box<dyn ConnectionStream>>),
     _metrics: BalancerMetrics,
 }
 
@@ -228,11 +249,15 @@ impl BalancerService {
 
         let metrics = ServerMetricsConfig::register_into(&self.cfg.metrics_registry);
 
+        let tracker = Arc::new(ConnectionTracker::new(self.cfg.max_connections_per_tenant));
+
         let mut set = JoinSet::new();
         {
             let pgwire = PgwireBalancer {
                 resolver: Arc::new(self.cfg.resolver),
                 tls: pgwire_tls,
+                tracker: Arc::clone(&tracker),
+                proxy_protocol: self.cfg.proxy_protocol,
                 metrics: ServerMetrics::new(metrics.clone(), "pgwire"),
             };
             set.spawn_named(|| "pgwire_stream", async move {
@@ -261,7 +286,15 @@ impl BalancerService {
                     "/api/livez",
                     routing::get(mz_http_util::handle_liveness_check),
                 )
-                .route("/api/readyz", routing::get(handle_readiness_check));
+                .route("/api/readyz", routing::get(handle_readiness_check))
+                .route(
+                    "/api/drain",
+                    routing::post(handle_drain).delete(handle_undrain),
+                )
+                .layer(Extension(DrainState {
+                    tracker,
+                    default_timeout: self.cfg.drain_timeout,
+                }));
 
             let internal_http = InternalHttpServer { router };
             set.spawn_named(|| "internal_http_stream", async move {
@@ -292,6 +325,65 @@ async fn handle_readiness_check() -> impl IntoResponse {
     (StatusCode::OK, "ready")
 }
 
+#[derive(Clone)]
+struct DrainState {
+    tracker: Arc<ConnectionTracker>,
+    default_timeout: Duration,
+}
+
+#[derive(Deserialize)]
+struct DrainParams {
+    /// The address of the environmentd to drain, as it is resolved by the balancer.
+    addr: SocketAddr,
+    /// The time after which sessions that are not idle are terminated, like `5m`.
+    timeout: Option<String>,
+}
+
+/// Drains an environmentd: rejects new connections to it, and waits until all of its sessions have
+/// been terminated, which happens once each is idle or the drain timeout has passed.
+async fn handle_drain(
+    Extension(state): Extension<DrainState>,
+    Query(params): Query<DrainParams>,
+) -> impl IntoResponse {
+    let timeout = match params.timeout.as_deref().map(humantime::parse_duration) {
+        None => state.default_timeout,
+        Some(Ok(timeout)) => timeout,
+        Some(Err(e)) => return (StatusCode::BAD_REQUEST, format!("invalid timeout: {e}")),
+    };
+    let addr = params.addr;
+    let deadline = tokio::time::Instant::now() + timeout;
+    info!(%addr, ?timeout, "draining environmentd");
+    state.tracker.drain(addr, deadline);
+
+    // Sessions are terminated by their proxies; wait for them to go away. Sessions that could not
+    // be terminated cleanly are abandoned shortly after the deadline, so wait a bit longer.
+    let give_up = deadline + Duration::from_secs(10);
+    loop {
+        let connections = state.tracker.connections(addr);
+        if connections == 0 {
+            return (StatusCode::OK, "drained".to_string());
+        }
+        if tokio::time::Instant::now() >= give_up {
+            return (
+                StatusCode::GATEWAY_TIMEOUT,
+                format!("{connections} connections remain"),
+            );
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// Stops draining an environmentd, so that it accepts new connections again.
+#[allow(clippy::unused_async)]
+async fn handle_undrain(
+    Extension(state): Extension<DrainState>,
+    Query(params): Query<DrainParams>,
+) -> impl IntoResponse {
+    info!(addr = %params.addr, "undraining environmentd");
+    state.tracker.undrain(params.addr);
+    (StatusCode::OK, "undrained")
+}
+
 struct InternalHttpServer {
     router: Router,
 }
@@ -332,6 +424,8 @@ impl Drop for GaugeGuard {
 struct ServerMetricsConfig {
     connection_status: IntCounterVec,
     active_connections: IntGaugeVec,
+    connection_rejections: IntCounterVec,
+    session_ends: IntCounterVec,
 }
 
 impl ServerMetricsConfig {
@@ -346,9 +440,21 @@ impl ServerMetricsConfig {
             help: "Count of currently open network connections.",
             var_labels: ["source"],
         ));
+        let connection_rejections = registry.register(metric!(
+            name: "mz_balancer_connection_rejections",
+            help: "Count of network connections rejected by the balancer, by reason",
+            var_labels: ["source", "reason"],
+        ));
+        let session_ends = registry.register(metric!(
+            name: "mz_balancer_session_ends",
+            help: "Count of proxied sessions that ended, by how they ended",
+            var_labels: ["source", "end"],
+        ));
         Self {
             connection_status,
             active_connections,
+            connection_rejections,
+            session_ends,
         }
     }
 }
@@ -378,6 +484,18 @@ impl ServerMetrics {
             .with_label_values(&[self.source, Self::status_label(is_ok)])
     }
 
+    fn connection_rejections(&self, reason: &str) -> IntCounter {
+        self.inner
+            .connection_rejections
+            .with_label_values(&[self.source, reason])
+    }
+
+    fn session_ends(&self, end: SessionEnd) -> IntCounter {
+        self.inner
+            .session_ends
+            .with_label_values(&[self.source, end.label()])
+    }
+
     fn active_connections(&self) -> GaugeGuard {
         self.inner
             .active_connections
@@ -397,6 +515,8 @@ impl ServerMetrics {
 struct PgwireBalancer {
     tls: Option<TlsConfig>,
     resolver: Arc<Resolver>,
+    tracker: Arc<ConnectionTracker>,
+    proxy_protocol: bool,
     metrics: ServerMetrics,
 }
 
@@ -407,7 +527,10 @@ impl PgwireBalancer {
         version: i32,
         params: BTreeMap<String, String>,
         resolver: &Resolver,
+        tracker: &Arc<ConnectionTracker>,
         tls_mode: Option<TlsMode>,
+        proxy_header: Option<ProxyHeader>,
+        metrics: &ServerMetrics,
     ) -> Result<(), io::Error>
     where
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
@@ -446,13 +569,34 @@ impl PgwireBalancer {
             }
         };
 
-        if let Err(err) = Self::stream(conn, resolved.addr, resolved.password, params).await {
-            return conn
-                .send(ErrorResponse::fatal(
-                    SqlState::INVALID_PASSWORD,
-                    err.to_string(),
-                ))
-                .await;
+        let guard = match tracker.register(resolved.tenant, resolved.addr) {
+            Ok(guard) => guard,
+            Err(err) => {
+                debug!(addr = %resolved.addr, "pgwire connection rejected: {err:?}");
+                metrics.connection_rejections(err.label()).inc();
+                return conn.send(ErrorResponse::from(err)).await;
+            }
+        };
+
+        match Self::stream(
+            conn,
+            resolved.addr,
+            resolved.password,
+            params,
+            proxy_header,
+            guard.drain_rx(),
+        )
+        .await
+        {
+            Ok(end) => metrics.session_ends(end).inc(),
+            Err(err) => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_PASSWORD,
+                        err.to_string(),
+                    ))
+                    .await;
+            }
         }
 
         Ok(())
@@ -464,11 +608,21 @@ impl PgwireBalancer {
         envd_addr: SocketAddr,
         password: Option<String>,
         params: BTreeMap<String, String>,
-    ) -> Result<(), anyhow::Error>
+        proxy_header: Option<ProxyHeader>,
+        drain_rx: watch::Receiver<Option<tokio::time::Instant>>,
+    ) -> Result<SessionEnd, anyhow::Error>
     where
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
     {
         let mut mz_stream = TcpStream::connect(envd_addr).await?;
+
+        // The PROXY protocol header, if any, must precede everything else.
+        if let Some(proxy_header) = proxy_header {
+            let mut header = Vec::new();
+            proxy_header.encode(&mut header);
+            mz_stream.write_all(&header).await?;
+        }
+
         let mut buf = BytesMut::new();
 
         // Send initial startup and password messages.
@@ -488,7 +642,7 @@ impl PgwireBalancer {
         // 'R' for auth message, 0008 for message length, 0003 for password cleartext variant.
         // See: https://www.postgresql.org/docs/current/protocol-message-formats.html#PROTOCOL-MESSAGE-FORMATS-AUTHENTICATIONCLEARTEXTPASSWORD
         const AUTH_PASSWORD_CLEARTEXT: [u8; 9] = [b'R', 0, 0, 0, 8, 0, 0, 0, 3];
-        if nread == AUTH_PASSWORD_CLEARTEXT.len()
+        let initial = if nread == AUTH_PASSWORD_CLEARTEXT.len()
             && maybe_auth_frame == AUTH_PASSWORD_CLEARTEXT
             && password.is_some()
         {
@@ -501,17 +655,19 @@ impl PgwireBalancer {
             password.encode(&mut buf)?;
             mz_stream.write_all(&buf).await?;
             mz_stream.flush().await?;
+            &[][..]
         } else {
             // Otherwise pass on the bytes we just got. This *might* even be a password request, but
             // we don't have a password. In which case it can be forwarded up to the client.
-            client_stream.write_all(&maybe_auth_frame[0..nread]).await?;
-        }
+            &maybe_auth_frame[0..nread]
+        };
 
-        // Now blindly shuffle bytes back and forth until closed.
+        // Now shuffle bytes back and forth until closed, or until the session is drained.
         // TODO: Limit total memory use.
-        tokio::io::copy_bidirectional(client_stream, &mut mz_stream).await?;
+        let end =
+            connections::proxy_session(client_stream, &mut mz_stream, initial, drain_rx).await?;
 
-        Ok(())
+        Ok(end)
     }
 }
 
@@ -521,12 +677,22 @@ impl mz_server_core::Server for PgwireBalancer {
     fn handle_connection(&self, conn: TcpStream) -> mz_server_core::ConnectionHandler {
         let tls = self.tls.clone();
         let resolver = Arc::clone(&self.resolver);
+        let tracker = Arc::clone(&self.tracker);
+        let proxy_protocol = self.proxy_protocol;
         let metrics = self.metrics.clone();
         Box::pin(async move {
             // TODO: Try to merge this with pgwire/server.rs to avoid the duplication. May not be
             // worth it.
             let active_guard = metrics.active_connections();
-            let result: Result<(), anyhow::Error> = async move {
+            let result: Result<(), anyhow::Error> = async {
+                let proxy_header = if proxy_protocol {
+                    Some(ProxyHeader {
+                        source: conn.peer_addr()?,
+                        destination: conn.local_addr()?,
+                    })
+                } else {
+                    None
+                };
                 let mut conn = Conn::Unencrypted(conn);
                 loop {
                     let message = decode_startup(&mut conn).await?;
@@ -543,7 +709,10 @@ impl mz_server_core::Server for PgwireBalancer {
                                 version,
                                 params,
                                 &resolver,
+                                &tracker,
                                 tls.map(|tls| tls.mode),
+                                proxy_header,
+                                &metrics,
                             )
                             .await?;
                             // TODO: Resolver lookup then begin relaying bytes.
@@ -699,18 +868,23 @@ impl Resolver {
                     }
                 };
 
-                let addr = addr_template.replace("{}", &claims.tenant_id.to_string());
+                let tenant = claims.tenant_id.to_string();
+                let addr = addr_template.replace("{}", &tenant);
                 let addr = lookup(&addr).await?;
                 Ok(ResolvedAddr {
                     addr,
                     password: Some(password),
+                    tenant,
                 })
             }
             Resolver::Static(addr) => {
+                // All connections go to the same environment, so treat them as one tenant.
+                let tenant = addr.clone();
                 let addr = lookup(addr).await?;
                 Ok(ResolvedAddr {
                     addr,
                     password: None,
+                    tenant,
                 })
             }
         }
@@ -738,4 +912,6 @@ pub struct FronteggResolver {
 struct ResolvedAddr {
     addr: SocketAddr,
     password: Option<String>,
+    /// The tenant whose environment the address belongs to.
+    tenant: String,
 }
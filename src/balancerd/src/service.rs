@@ -11,6 +11,7 @@
 
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use anyhow::Context;
 use jsonwebtoken::DecodingKey;
@@ -47,6 +48,20 @@ pub struct Args {
     #[clap(long, value_name = "HOST.{}.NAME:PORT")]
     https_resolver_template: String,
 
+    /// The maximum number of pgwire connections of any one tenant. Further connections are
+    /// rejected. Unlimited if not set.
+    #[clap(long, value_name = "N")]
+    max_connections_per_tenant: Option<usize>,
+    /// Begin each pgwire connection to environmentd with a PROXY protocol version 2 header that
+    /// carries the address of the client. environmentd must be started with
+    /// `--balancer-proxy-protocol`.
+    #[clap(long)]
+    proxy_protocol: bool,
+    /// How long draining an environmentd waits for its sessions to become idle before terminating
+    /// them, unless the drain request specifies a timeout.
+    #[clap(long, parse(try_from_str = humantime::parse_duration), default_value = "5m")]
+    drain_timeout: Duration,
+
     /// JWK used to validate JWTs during Frontegg authentication as a PEM public
     /// key. Can optionally be base64 encoded with the URL-safe alphabet.
     #[clap(long, env = "FRONTEGG_JWK", requires = "frontegg-resolver-template")]
@@ -132,6 +147,9 @@ pub async fn run(args: Args) -> Result<(), anyhow::Error> {
         args.https_resolver_template,
         args.tls.into_config()?,
         metrics_registry,
+        args.max_connections_per_tenant,
+        args.proxy_protocol,
+        args.drain_timeout,
     );
     let service = BalancerService::new(config).await?;
     service.serve().await?;
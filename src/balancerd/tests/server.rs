@@ -79,6 +79,7 @@
 
 use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
 
 use jsonwebtoken::{DecodingKey, EncodingKey};
 use mz_balancerd::{BalancerConfig, BalancerService, FronteggResolver, Resolver, BUILD_INFO};
@@ -92,6 +93,8 @@ use mz_ore::now::SYSTEM_TIME;
 use mz_ore::task;
 use mz_server_core::TlsCertConfig;
 use openssl::ssl::{SslConnectorBuilder, SslVerifyMode};
+use tokio_postgres::error::SqlState;
+use tokio_postgres::NoTls;
 use uuid::Uuid;
 
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
@@ -187,6 +190,9 @@ async fn test_balancer() {
             envd_server.inner.http_local_addr().to_string(),
             cert_config.clone(),
             MetricsRegistry::new(),
+            None,
+            false,
+            Duration::from_secs(60),
         );
         let balancer_server = BalancerService::new(balancer_cfg).await.unwrap();
         let balancer_pgwire_listen = balancer_server.pgwire.0.local_addr();
@@ -214,3 +220,78 @@ async fn test_balancer() {
         assert_eq!(res, 2);
     }
 }
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_balancer_drain() {
+    let envd_server = test_util::TestHarness::default()
+        .with_balancer_proxy_protocol()
+        .start()
+        .await;
+    let envd_addr = envd_server.inner.balancer_sql_local_addr();
+
+    let balancer_cfg = BalancerConfig::new(
+        &BUILD_INFO,
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+        Resolver::Static(envd_addr.to_string()),
+        envd_server.inner.http_local_addr().to_string(),
+        None,
+        MetricsRegistry::new(),
+        Some(1),
+        true,
+        Duration::from_secs(60),
+    );
+    let balancer_server = BalancerService::new(balancer_cfg).await.unwrap();
+    let balancer_pgwire_listen = balancer_server.pgwire.0.local_addr();
+    let balancer_http_listen = balancer_server.internal_http.0.local_addr();
+    task::spawn(|| "balancer", async {
+        balancer_server.serve().await.unwrap();
+    });
+    let conn_str = format!(
+        "user=materialize host={} port={}",
+        balancer_pgwire_listen.ip(),
+        balancer_pgwire_listen.port()
+    );
+
+    // environmentd learns the address of the client from the PROXY protocol header.
+    let (pg_client, conn) = tokio_postgres::connect(&conn_str, NoTls).await.unwrap();
+    let conn = task::spawn(|| "balancer-pg_client", conn);
+    let client_ip: Option<String> = pg_client
+        .query_one(
+            "SELECT client_ip FROM mz_internal.mz_sessions WHERE id::int8 = pg_backend_pid()",
+            &[],
+        )
+        .await
+        .unwrap()
+        .get(0);
+    assert_eq!(client_ip.as_deref(), Some("127.0.0.1"));
+
+    // The tenant is limited to one connection.
+    let err = tokio_postgres::connect(&conn_str, NoTls).await.unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::TOO_MANY_CONNECTIONS));
+
+    // Draining terminates the idle session, and waits for it to go away.
+    let http_client = reqwest::Client::new();
+    let drain_url = format!("http://{balancer_http_listen}/api/drain?addr={envd_addr}");
+    let res = http_client.post(&drain_url).send().await.unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    let err = conn.await.unwrap().unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::ADMIN_SHUTDOWN));
+    assert!(pg_client.query_one("SELECT 1", &[]).await.is_err());
+
+    // New connections to the draining environmentd are rejected.
+    let err = tokio_postgres::connect(&conn_str, NoTls).await.unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::CANNOT_CONNECT_NOW));
+
+    // Once undrained, new connections are accepted again.
+    let res = http_client.delete(&drain_url).send().await.unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    let (pg_client, conn) = tokio_postgres::connect(&conn_str, NoTls).await.unwrap();
+    task::spawn(|| "balancer-pg_client", async move {
+        conn.await.expect("balancer-pg_client")
+    });
+    let res: i32 = pg_client.query_one("SELECT 2", &[]).await.unwrap().get(0);
+    assert_eq!(res, 2);
+}
@@ -944,6 +944,20 @@ pub const TYPE_ANYNONARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYENUM: BuiltinType<NameReference> = BuiltinType {
+    name: "anyenum",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYENUM_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 3504,
+            typreceive_oid: 0,
+        }),
+    },
+};
+
 pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     name: "anyrange",
     schema: PG_CATALOG_SCHEMA,
@@ -1643,6 +1657,96 @@ pub const TYPE_ACL_ITEM_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INET: BuiltinType<NameReference> = BuiltinType {
+    name: "inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Inet,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 910,
+            typreceive_oid: 2496,
+        }),
+    },
+};
+
+pub const TYPE_INET_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INET.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_CIDR: BuiltinType<NameReference> = BuiltinType {
+    name: "cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Cidr,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 1267,
+            typreceive_oid: 2498,
+        }),
+    },
+};
+
+pub const TYPE_CIDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_CIDR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
+pub const TYPE_MACADDR: BuiltinType<NameReference> = BuiltinType {
+    name: "macaddr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_MACADDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::MacAddr,
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 436,
+            typreceive_oid: 2494,
+        }),
+    },
+};
+
+pub const TYPE_MACADDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_macaddr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_MACADDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_MACADDR.name,
+        },
+        array_id: None,
+        pg_metadata: Some(CatalogTypePgMetadata {
+            typinput_oid: 750,
+            typreceive_oid: 2400,
+        }),
+    },
+};
+
 pub const TYPE_INTERNAL: BuiltinType<NameReference> = BuiltinType {
     name: "internal",
     schema: PG_CATALOG_SCHEMA,
@@ -1797,6 +1901,13 @@ pub const MZ_ARRANGEMENT_HEAP_ALLOCATIONS_RAW: BuiltinLog = BuiltinLog {
     sensitivity: DataSensitivity::Public,
 };
 
+pub const MZ_ARRANGEMENT_HEAP_SPILLED_RAW: BuiltinLog = BuiltinLog {
+    name: "mz_arrangement_heap_spilled_raw",
+    schema: MZ_INTERNAL_SCHEMA,
+    variant: LogVariant::Compute(ComputeLog::ArrangementHeapSpilled),
+    sensitivity: DataSensitivity::Public,
+};
+
 pub const MZ_MESSAGE_BATCH_COUNTS_RECEIVED_RAW: BuiltinLog = BuiltinLog {
     name: "mz_message_batch_counts_received_raw",
     schema: MZ_INTERNAL_SCHEMA,
@@ -1935,7 +2046,11 @@ pub static MZ_COLUMNS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("type", ScalarType::String.nullable(false))
         .with_column("default", ScalarType::String.nullable(true))
         .with_column("type_oid", ScalarType::Oid.nullable(false))
-        .with_column("type_mod", ScalarType::Int32.nullable(false)),
+        .with_column("type_mod", ScalarType::Int32.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::MzAclItem)).nullable(false),
+        ),
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
@@ -2125,6 +2240,16 @@ pub static MZ_TYPE_PG_METADATA: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
+pub static MZ_ENUM_LABELS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_enum_labels",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("position", ScalarType::UInt64.nullable(false))
+        .with_column("label", ScalarType::String.nullable(false)),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
 pub static MZ_ARRAY_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_array_types",
     schema: MZ_CATALOG_SCHEMA,
@@ -2191,7 +2316,8 @@ pub static MZ_ROLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("id", ScalarType::String.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
-        .with_column("inherit", ScalarType::Bool.nullable(false)),
+        .with_column("inherit", ScalarType::Bool.nullable(false))
+        .with_column("bypass_rls", ScalarType::Bool.nullable(false)),
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
@@ -2673,7 +2799,8 @@ pub static MZ_SESSIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column(
             "connected_at",
             ScalarType::TimestampTz { precision: None }.nullable(false),
-        ),
+        )
+        .with_column("client_ip", ScalarType::String.nullable(true)),
     is_retained_metrics_object: false,
     sensitivity: DataSensitivity::Public,
 });
@@ -2712,6 +2839,36 @@ pub static MZ_COMMENTS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     sensitivity: DataSensitivity::Public,
 });
 
+pub static MZ_POLICIES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_policies",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("oid", ScalarType::Oid.nullable(false))
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("relation_id", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column("using", ScalarType::String.nullable(false)),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
+pub static MZ_DURABLE_SUBSCRIPTIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_durable_subscriptions",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("oid", ScalarType::Oid.nullable(false))
+        .with_column("schema_id", ScalarType::String.nullable(false))
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("relation_id", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column("acknowledged", ScalarType::MzTimestamp.nullable(true)),
+    is_retained_metrics_object: false,
+    sensitivity: DataSensitivity::Public,
+});
+
 pub static MZ_WEBHOOKS_SOURCES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_webhook_sources",
     schema: MZ_INTERNAL_SCHEMA,
@@ -3400,7 +3557,12 @@ pub const PG_TYPE: BuiltinView = BuiltinView {
     NULL::pg_catalog.int2 AS typlen,
     -- 'a' is used internally to denote an array type, but in postgres they show up
     -- as 'b'.
-    (CASE mztype WHEN 'a' THEN 'b' ELSE mztype END)::pg_catalog.char AS typtype,
+    (CASE
+        WHEN mztype = 'a' THEN 'b'
+        WHEN category = 'composite' THEN 'c'
+        WHEN category = 'enum' THEN 'e'
+        ELSE mztype
+    END)::pg_catalog.char AS typtype,
     (CASE category
         WHEN 'array' THEN 'A'
         WHEN 'bit-string' THEN 'V'
@@ -3573,10 +3735,14 @@ pub const PG_ENUM: BuiltinView = BuiltinView {
     column_defs: None,
     sql: "SELECT
     NULL::pg_catalog.oid AS oid,
-    NULL::pg_catalog.oid AS enumtypid,
-    NULL::pg_catalog.float4 AS enumsortorder,
-    NULL::pg_catalog.text AS enumlabel
-WHERE false",
+    mz_types.oid AS enumtypid,
+    (mz_enum_labels.position::pg_catalog.int8 + 1)::pg_catalog.float4 AS enumsortorder,
+    mz_enum_labels.label AS enumlabel
+FROM mz_internal.mz_enum_labels
+JOIN mz_catalog.mz_types ON mz_types.id = mz_enum_labels.id
+JOIN mz_catalog.mz_schemas ON mz_schemas.id = mz_types.schema_id
+LEFT JOIN mz_catalog.mz_databases d ON d.id = mz_schemas.database_id
+WHERE mz_schemas.database_id IS NULL OR d.name = pg_catalog.current_database()",
     sensitivity: DataSensitivity::Public,
 };
 
@@ -4104,6 +4270,16 @@ heap_allocations_cte AS (
         mz_internal.mz_arrangement_heap_allocations_raw
     GROUP BY
         operator_id, worker_id
+),
+heap_spilled_cte AS (
+    SELECT
+        operator_id,
+        worker_id,
+        pg_catalog.count(*) AS spilled
+    FROM
+        mz_internal.mz_arrangement_heap_spilled_raw
+    GROUP BY
+        operator_id, worker_id
 )
 SELECT
     batches_cte.operator_id,
@@ -4112,12 +4288,15 @@ SELECT
     batches_cte.batches,
     COALESCE(heap_size_cte.size, 0) AS size,
     COALESCE(heap_capacity_cte.capacity, 0) AS capacity,
-    COALESCE(heap_allocations_cte.allocations, 0) AS allocations
+    COALESCE(heap_allocations_cte.allocations, 0) AS allocations,
+    COALESCE(heap_size_cte.size, 0) - COALESCE(heap_spilled_cte.spilled, 0) AS resident,
+    COALESCE(heap_spilled_cte.spilled, 0) AS spilled
 FROM batches_cte
 LEFT OUTER JOIN records_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN heap_size_cte USING (operator_id, worker_id)
 LEFT OUTER JOIN heap_capacity_cte USING (operator_id, worker_id)
-LEFT OUTER JOIN heap_allocations_cte USING (operator_id, worker_id)",
+LEFT OUTER JOIN heap_allocations_cte USING (operator_id, worker_id)
+LEFT OUTER JOIN heap_spilled_cte USING (operator_id, worker_id)",
     sensitivity: DataSensitivity::Public,
 };
 
@@ -4132,7 +4311,9 @@ SELECT
     pg_catalog.sum(batches) AS batches,
     pg_catalog.sum(size) AS size,
     pg_catalog.sum(capacity) AS capacity,
-    pg_catalog.sum(allocations) AS allocations
+    pg_catalog.sum(allocations) AS allocations,
+    pg_catalog.sum(resident) AS resident,
+    pg_catalog.sum(spilled) AS spilled
 FROM mz_internal.mz_arrangement_sizes_per_worker
 GROUP BY operator_id",
     sensitivity: DataSensitivity::Public,
@@ -4531,6 +4712,63 @@ WHERE mz_catalog.mz_is_superuser() OR pg_has_role(current_role, member.oid, 'USA
     sensitivity: DataSensitivity::Public,
 };
 
+pub const INFORMATION_SCHEMA_COLUMN_PRIVILEGES: BuiltinView = BuiltinView {
+    name: "column_privileges",
+    schema: INFORMATION_SCHEMA,
+    column_defs: None,
+    sql: "
+SELECT
+    grantor,
+    grantee,
+    table_catalog,
+    table_schema,
+    table_name,
+    column_name,
+    privilege_type,
+    is_grantable
+FROM
+    (SELECT
+        grantor.name AS grantor,
+        CASE mz_internal.mz_aclitem_grantee(privileges)
+            WHEN 'p' THEN 'PUBLIC'
+            ELSE grantee.name
+        END AS grantee,
+        table_catalog,
+        table_schema,
+        table_name,
+        column_name,
+        unnest(mz_internal.mz_format_privileges(mz_internal.mz_aclitem_privileges(privileges))) AS privilege_type,
+        -- ADMIN OPTION isn't implemented.
+        'NO' AS is_grantable
+    FROM
+        (SELECT
+            unnest(columns.privileges) AS privileges,
+            CASE
+                WHEN schemas.database_id IS NULL THEN current_database()
+                ELSE databases.name
+            END AS table_catalog,
+            schemas.name AS table_schema,
+            relations.name AS table_name,
+            columns.name AS column_name
+        FROM mz_columns AS columns
+        JOIN mz_relations AS relations ON columns.id = relations.id
+        JOIN mz_schemas AS schemas ON relations.schema_id = schemas.id
+        LEFT JOIN mz_databases AS databases ON schemas.database_id = databases.id
+        WHERE schemas.database_id IS NULL OR databases.name = current_database())
+    JOIN mz_roles AS grantor ON mz_internal.mz_aclitem_grantor(privileges) = grantor.id
+    LEFT JOIN mz_roles AS grantee ON mz_internal.mz_aclitem_grantee(privileges) = grantee.id)
+WHERE
+    -- WHERE clause is not guaranteed to short-circuit and 'PUBLIC' will cause an error when passed
+    -- to pg_has_role. Therefore we need to use a CASE statement.
+    CASE
+        WHEN grantee = 'PUBLIC' THEN true
+        ELSE mz_catalog.mz_is_superuser()
+            OR pg_has_role(current_role, grantee, 'USAGE')
+            OR pg_has_role(current_role, grantor, 'USAGE')
+    END",
+    sensitivity: DataSensitivity::Public,
+};
+
 pub const INFORMATION_SCHEMA_COLUMNS: BuiltinView = BuiltinView {
     name: "columns",
     schema: INFORMATION_SCHEMA,
@@ -4904,8 +5142,7 @@ SELECT
     NULL::pg_catalog.bool AS rolcanlogin,
     -- MZ doesn't support replication in the same way Postgres does
     false AS rolreplication,
-    -- MZ doesn't how row level security
-    false AS rolbypassrls,
+    bypass_rls AS rolbypassrls,
     -- MZ doesn't have a connection limit
     -1 AS rolconnlimit,
     -- MZ doesn't have role passwords
@@ -5909,6 +6146,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_ANYARRAY),
         Builtin::Type(&TYPE_ANYELEMENT),
         Builtin::Type(&TYPE_ANYNONARRAY),
+        Builtin::Type(&TYPE_ANYENUM),
         Builtin::Type(&TYPE_ANYRANGE),
         Builtin::Type(&TYPE_BOOL),
         Builtin::Type(&TYPE_BOOL_ARRAY),
@@ -5994,6 +6232,12 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_MZ_ACL_ITEM_ARRAY),
         Builtin::Type(&TYPE_ACL_ITEM),
         Builtin::Type(&TYPE_ACL_ITEM_ARRAY),
+        Builtin::Type(&TYPE_INET),
+        Builtin::Type(&TYPE_INET_ARRAY),
+        Builtin::Type(&TYPE_CIDR),
+        Builtin::Type(&TYPE_CIDR_ARRAY),
+        Builtin::Type(&TYPE_MACADDR),
+        Builtin::Type(&TYPE_MACADDR_ARRAY),
         Builtin::Type(&TYPE_INTERNAL),
     ];
     for (schema, funcs) in &[
@@ -6032,6 +6276,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_CAPACITY_RAW),
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_ALLOCATIONS_RAW),
         Builtin::Log(&MZ_ARRANGEMENT_HEAP_SIZE_RAW),
+        Builtin::Log(&MZ_ARRANGEMENT_HEAP_SPILLED_RAW),
         Builtin::Log(&MZ_SCHEDULING_ELAPSED_RAW),
         Builtin::Log(&MZ_COMPUTE_OPERATOR_DURATIONS_HISTOGRAM_RAW),
         Builtin::Log(&MZ_SCHEDULING_PARKS_HISTOGRAM_RAW),
@@ -6056,6 +6301,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_MATERIALIZED_VIEWS),
         Builtin::Table(&MZ_TYPES),
         Builtin::Table(&MZ_TYPE_PG_METADATA),
+        Builtin::Table(&MZ_ENUM_LABELS),
         Builtin::Table(&MZ_ARRAY_TYPES),
         Builtin::Table(&MZ_BASE_TYPES),
         Builtin::Table(&MZ_LIST_TYPES),
@@ -6085,6 +6331,8 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_DEFAULT_PRIVILEGES),
         Builtin::Table(&MZ_SYSTEM_PRIVILEGES),
         Builtin::Table(&MZ_COMMENTS),
+        Builtin::Table(&MZ_POLICIES),
+        Builtin::Table(&MZ_DURABLE_SUBSCRIPTIONS),
         Builtin::Table(&MZ_WEBHOOKS_SOURCES),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECTS),
@@ -6179,6 +6427,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::View(&PG_TIMEZONE_ABBREVS),
         Builtin::View(&PG_TIMEZONE_NAMES),
         Builtin::View(&INFORMATION_SCHEMA_APPLICABLE_ROLES),
+        Builtin::View(&INFORMATION_SCHEMA_COLUMN_PRIVILEGES),
         Builtin::View(&INFORMATION_SCHEMA_COLUMNS),
         Builtin::View(&INFORMATION_SCHEMA_ENABLED_ROLES),
         Builtin::View(&INFORMATION_SCHEMA_KEY_COLUMN_USAGE),
@@ -91,8 +91,10 @@ impl StateUpdate {
             system_privileges,
             audit_log_updates,
             storage_usage_updates,
-            // Persist implementation does not use the connection timeout.
+            // Persist implementation does not use the connection timeout. Its
+            // history is retained along with that of every other shard.
             connection_timeout: _,
+            history_retention: _,
         } = txn_batch;
         let databases = from_batch(databases, ts, StateUpdateKind::Database);
         let schemas = from_batch(schemas, ts, StateUpdateKind::Schema);
@@ -769,6 +769,9 @@ impl DurableCatalogState for Connection {
             if let Some(connection_timeout) = txn_batch.connection_timeout {
                 catalog.stash.set_connect_timeout(connection_timeout).await;
             }
+            if let Some(history_retention) = txn_batch.history_retention {
+                catalog.stash.set_history_retention(history_retention);
+            }
 
             catalog
                 .stash
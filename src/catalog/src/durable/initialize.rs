@@ -244,6 +244,8 @@ pub async fn initialize(
             ObjectType::Database => mz_audit_log::ObjectType::Database,
             ObjectType::Schema => mz_audit_log::ObjectType::Schema,
             ObjectType::Func => mz_audit_log::ObjectType::Func,
+            ObjectType::Policy => mz_audit_log::ObjectType::Policy,
+            ObjectType::Subscription => mz_audit_log::ObjectType::Subscription,
         };
         audit_events.push((
             mz_audit_log::EventType::Grant,
@@ -380,6 +380,8 @@ pub struct Item {
     pub create_sql: String,
     pub owner_id: RoleId,
     pub privileges: Vec<MzAclItem>,
+    /// Privileges granted on individual columns, keyed by column name.
+    pub column_privileges: BTreeMap<String, Vec<MzAclItem>>,
 }
 
 impl DurableType<ItemKey, ItemValue> for Item {
@@ -392,6 +394,7 @@ impl DurableType<ItemKey, ItemValue> for Item {
                 create_sql: self.create_sql,
                 owner_id: self.owner_id,
                 privileges: self.privileges,
+                column_privileges: self.column_privileges,
             },
         )
     }
@@ -404,6 +407,7 @@ impl DurableType<ItemKey, ItemValue> for Item {
             create_sql: value.create_sql,
             owner_id: value.owner_id,
             privileges: value.privileges,
+            column_privileges: value.column_privileges,
         }
     }
 }
@@ -867,6 +871,7 @@ pub struct ItemValue {
     pub(crate) create_sql: String,
     pub(crate) owner_id: RoleId,
     pub(crate) privileges: Vec<MzAclItem>,
+    pub(crate) column_privileges: BTreeMap<String, Vec<MzAclItem>>,
 }
 
 #[derive(Clone, Debug, PartialOrd, PartialEq, Eq, Ord)]
@@ -14,8 +14,8 @@ use mz_audit_log::{
     CreateSourceSinkV1, CreateSourceSinkV2, DropClusterReplicaV1, EventDetails, EventType, EventV1,
     FullNameV1, GrantRoleV1, GrantRoleV2, IdFullNameV1, IdNameV1, RenameClusterReplicaV1,
     RenameClusterV1, RenameItemV1, RenameSchemaV1, RevokeRoleV1, RevokeRoleV2, SchemaV1, SchemaV2,
-    StorageUsageV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1, VersionedEvent,
-    VersionedStorageUsage,
+    StorageUsageV1, UpdateColumnPrivilegeV1, UpdateItemV1, UpdateOwnerV1, UpdatePrivilegeV1,
+    VersionedEvent, VersionedStorageUsage,
 };
 use mz_compute_client::controller::ComputeReplicaLogging;
 use mz_controller_types::ReplicaId;
@@ -482,6 +482,14 @@ impl RustType<proto::ItemValue> for ItemValue {
             definition: Some(definition),
             owner_id: Some(self.owner_id.into_proto()),
             privileges: self.privileges.into_proto(),
+            column_privileges: self
+                .column_privileges
+                .iter()
+                .map(|(column, privileges)| proto::ColumnPrivileges {
+                    column: column.clone(),
+                    privileges: privileges.into_proto(),
+                })
+                .collect(),
         }
     }
 
@@ -500,6 +508,11 @@ impl RustType<proto::ItemValue> for ItemValue {
             create_sql,
             owner_id: proto.owner_id.into_rust_if_some("ItemValue::owner_id")?,
             privileges: proto.privileges.into_rust()?,
+            column_privileges: proto
+                .column_privileges
+                .into_iter()
+                .map(|c| Ok((c.column, c.privileges.into_rust()?)))
+                .collect::<Result<_, TryFromProtoError>>()?,
         })
     }
 }
@@ -883,6 +896,8 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
     fn into_proto(&self) -> proto::RoleAttributes {
         proto::RoleAttributes {
             inherit: self.inherit,
+            password_hash: self.password_hash.clone(),
+            bypass_rls: self.bypass_rls,
         }
     }
 
@@ -890,6 +905,8 @@ impl RustType<proto::RoleAttributes> for RoleAttributes {
         let mut attributes = RoleAttributes::new();
 
         attributes.inherit = proto.inherit;
+        attributes.password_hash = proto.password_hash;
+        attributes.bypass_rls = proto.bypass_rls;
 
         Ok(attributes)
     }
@@ -958,6 +975,8 @@ impl RustType<proto::CatalogItemType> for CatalogItemType {
             CatalogItemType::Func => proto::CatalogItemType::Func,
             CatalogItemType::Secret => proto::CatalogItemType::Secret,
             CatalogItemType::Connection => proto::CatalogItemType::Connection,
+            CatalogItemType::Policy => proto::CatalogItemType::Policy,
+            CatalogItemType::Subscription => proto::CatalogItemType::Subscription,
         }
     }
 
@@ -973,6 +992,8 @@ impl RustType<proto::CatalogItemType> for CatalogItemType {
             proto::CatalogItemType::Func => CatalogItemType::Func,
             proto::CatalogItemType::Secret => CatalogItemType::Secret,
             proto::CatalogItemType::Connection => CatalogItemType::Connection,
+            proto::CatalogItemType::Policy => CatalogItemType::Policy,
+            proto::CatalogItemType::Subscription => CatalogItemType::Subscription,
             proto::CatalogItemType::Unknown => {
                 return Err(TryFromProtoError::unknown_enum_variant("CatalogItemType"))
             }
@@ -999,6 +1020,8 @@ impl RustType<proto::ObjectType> for ObjectType {
             ObjectType::Database => proto::ObjectType::Database,
             ObjectType::Schema => proto::ObjectType::Schema,
             ObjectType::Func => proto::ObjectType::Func,
+            ObjectType::Policy => proto::ObjectType::Policy,
+            ObjectType::Subscription => proto::ObjectType::Subscription,
         }
     }
 
@@ -1019,6 +1042,8 @@ impl RustType<proto::ObjectType> for ObjectType {
             proto::ObjectType::Database => Ok(ObjectType::Database),
             proto::ObjectType::Schema => Ok(ObjectType::Schema),
             proto::ObjectType::Func => Ok(ObjectType::Func),
+            proto::ObjectType::Policy => Ok(ObjectType::Policy),
+            proto::ObjectType::Subscription => Ok(ObjectType::Subscription),
             proto::ObjectType::Unknown => Err(TryFromProtoError::unknown_enum_variant(
                 "ObjectType::Unknown",
             )),
@@ -1388,10 +1413,14 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             mz_audit_log::ObjectType::MaterializedView => {
                 proto::audit_log_event_v1::ObjectType::MaterializedView
             }
+            mz_audit_log::ObjectType::Policy => proto::audit_log_event_v1::ObjectType::Policy,
             mz_audit_log::ObjectType::Role => proto::audit_log_event_v1::ObjectType::Role,
             mz_audit_log::ObjectType::Secret => proto::audit_log_event_v1::ObjectType::Secret,
             mz_audit_log::ObjectType::Schema => proto::audit_log_event_v1::ObjectType::Schema,
             mz_audit_log::ObjectType::Sink => proto::audit_log_event_v1::ObjectType::Sink,
+            mz_audit_log::ObjectType::Subscription => {
+                proto::audit_log_event_v1::ObjectType::Subscription
+            }
             mz_audit_log::ObjectType::Source => proto::audit_log_event_v1::ObjectType::Source,
             mz_audit_log::ObjectType::System => proto::audit_log_event_v1::ObjectType::System,
             mz_audit_log::ObjectType::Table => proto::audit_log_event_v1::ObjectType::Table,
@@ -1417,10 +1446,14 @@ impl RustType<proto::audit_log_event_v1::ObjectType> for mz_audit_log::ObjectTyp
             proto::audit_log_event_v1::ObjectType::MaterializedView => {
                 Ok(mz_audit_log::ObjectType::MaterializedView)
             }
+            proto::audit_log_event_v1::ObjectType::Policy => Ok(mz_audit_log::ObjectType::Policy),
             proto::audit_log_event_v1::ObjectType::Role => Ok(mz_audit_log::ObjectType::Role),
             proto::audit_log_event_v1::ObjectType::Secret => Ok(mz_audit_log::ObjectType::Secret),
             proto::audit_log_event_v1::ObjectType::Schema => Ok(mz_audit_log::ObjectType::Schema),
             proto::audit_log_event_v1::ObjectType::Sink => Ok(mz_audit_log::ObjectType::Sink),
+            proto::audit_log_event_v1::ObjectType::Subscription => {
+                Ok(mz_audit_log::ObjectType::Subscription)
+            }
             proto::audit_log_event_v1::ObjectType::Source => Ok(mz_audit_log::ObjectType::Source),
             proto::audit_log_event_v1::ObjectType::System => Ok(mz_audit_log::ObjectType::System),
             proto::audit_log_event_v1::ObjectType::Table => Ok(mz_audit_log::ObjectType::Table),
@@ -1805,6 +1838,30 @@ impl RustType<proto::audit_log_event_v1::UpdatePrivilegeV1> for UpdatePrivilegeV
     }
 }
 
+impl RustType<proto::audit_log_event_v1::UpdateColumnPrivilegeV1> for UpdateColumnPrivilegeV1 {
+    fn into_proto(&self) -> proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+        proto::audit_log_event_v1::UpdateColumnPrivilegeV1 {
+            object_id: self.object_id.to_string(),
+            column_name: self.column_name.to_string(),
+            grantee_id: self.grantee_id.to_string(),
+            grantor_id: self.grantor_id.to_string(),
+            privileges: self.privileges.to_string(),
+        }
+    }
+
+    fn from_proto(
+        proto: proto::audit_log_event_v1::UpdateColumnPrivilegeV1,
+    ) -> Result<Self, TryFromProtoError> {
+        Ok(UpdateColumnPrivilegeV1 {
+            object_id: proto.object_id,
+            column_name: proto.column_name,
+            grantee_id: proto.grantee_id,
+            grantor_id: proto.grantor_id,
+            privileges: proto.privileges,
+        })
+    }
+}
+
 impl RustType<proto::audit_log_event_v1::AlterDefaultPrivilegeV1> for AlterDefaultPrivilegeV1 {
     fn into_proto(&self) -> proto::audit_log_event_v1::AlterDefaultPrivilegeV1 {
         proto::audit_log_event_v1::AlterDefaultPrivilegeV1 {
@@ -1951,6 +2008,9 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             EventDetails::RevokeRoleV1(details) => RevokeRoleV1(details.into_proto()),
             EventDetails::RevokeRoleV2(details) => RevokeRoleV2(details.into_proto()),
             EventDetails::UpdatePrivilegeV1(details) => UpdatePrivilegeV1(details.into_proto()),
+            EventDetails::UpdateColumnPrivilegeV1(details) => {
+                UpdateColumnPrivilegeV1(details.into_proto())
+            }
             EventDetails::AlterDefaultPrivilegeV1(details) => {
                 AlterDefaultPrivilegeV1(details.into_proto())
             }
@@ -1992,6 +2052,9 @@ impl RustType<proto::audit_log_event_v1::Details> for EventDetails {
             RevokeRoleV1(details) => Ok(EventDetails::RevokeRoleV1(details.into_rust()?)),
             RevokeRoleV2(details) => Ok(EventDetails::RevokeRoleV2(details.into_rust()?)),
             UpdatePrivilegeV1(details) => Ok(EventDetails::UpdatePrivilegeV1(details.into_rust()?)),
+            UpdateColumnPrivilegeV1(details) => {
+                Ok(EventDetails::UpdateColumnPrivilegeV1(details.into_rust()?))
+            }
             AlterDefaultPrivilegeV1(details) => {
                 Ok(EventDetails::AlterDefaultPrivilegeV1(details.into_rust()?))
             }
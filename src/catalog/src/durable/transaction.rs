@@ -76,6 +76,7 @@ pub struct Transaction<'a> {
     audit_log_updates: Vec<(proto::AuditLogKey, (), i64)>,
     storage_usage_updates: Vec<(proto::StorageUsageKey, (), i64)>,
     connection_timeout: Option<Duration>,
+    history_retention: Option<Duration>,
 }
 
 impl<'a> Transaction<'a> {
@@ -128,6 +129,7 @@ impl<'a> Transaction<'a> {
             audit_log_updates: Vec::new(),
             storage_usage_updates: Vec::new(),
             connection_timeout: None,
+            history_retention: None,
         })
     }
 
@@ -475,6 +477,7 @@ impl<'a> Transaction<'a> {
         create_sql: String,
         owner_id: RoleId,
         privileges: Vec<MzAclItem>,
+        column_privileges: BTreeMap<String, Vec<MzAclItem>>,
     ) -> Result<(), CatalogError> {
         match self.items.insert(
             ItemKey { gid: id },
@@ -484,6 +487,7 @@ impl<'a> Transaction<'a> {
                 create_sql,
                 owner_id,
                 privileges,
+                column_privileges,
             },
         ) {
             Ok(_) => Ok(()),
@@ -1218,6 +1222,12 @@ impl<'a> Transaction<'a> {
         self.connection_timeout = Some(timeout);
     }
 
+    /// Sets how long the durable catalog keeps old versions of its contents
+    /// around, for point-in-time recovery.
+    pub fn set_history_retention(&mut self, retention: Duration) {
+        self.history_retention = Some(retention);
+    }
+
     pub(crate) fn into_parts(self) -> (TransactionBatch, &'a mut dyn DurableCatalogState) {
         let txn_batch = TransactionBatch {
             databases: self.databases.pending(),
@@ -1239,6 +1249,7 @@ impl<'a> Transaction<'a> {
             audit_log_updates: self.audit_log_updates,
             storage_usage_updates: self.storage_usage_updates,
             connection_timeout: self.connection_timeout,
+            history_retention: self.history_retention,
         };
         (txn_batch, self.durable_catalog)
     }
@@ -1292,6 +1303,7 @@ pub struct TransactionBatch {
     pub(crate) audit_log_updates: Vec<(proto::AuditLogKey, (), Diff)>,
     pub(crate) storage_usage_updates: Vec<(proto::StorageUsageKey, (), Diff)>,
     pub(crate) connection_timeout: Option<Duration>,
+    pub(crate) history_retention: Option<Duration>,
 }
 
 impl TransactionBatch {
@@ -1315,8 +1327,9 @@ impl TransactionBatch {
             system_privileges,
             audit_log_updates,
             storage_usage_updates,
-            // This doesn't get written down anywhere.
+            // These don't get written down anywhere.
             connection_timeout: _,
+            history_retention: _,
         } = self;
 
         databases.is_empty()
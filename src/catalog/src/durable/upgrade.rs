@@ -54,14 +54,14 @@ macro_rules! objects {
         }
     }
 
-objects!(v39, v40, v41, v42, v43, v44);
+objects!(v39, v40, v41, v42, v43, v44, v45, v46, v47, v48);
 
 /// The current version of the `Catalog`.
 ///
 /// We will initialize new `Catalog`es with this version, and migrate existing `Catalog`es to this
 /// version. Whenever the `Catalog` changes, e.g. the protobufs we serialize in the `Catalog`
 /// change, we need to bump this version.
-pub(crate) const CATALOG_VERSION: u64 = 44;
+pub(crate) const CATALOG_VERSION: u64 = 48;
 
 /// The minimum `Catalog` version number that we support migrating from.
 ///
@@ -90,6 +90,10 @@ pub(crate) mod stash {
     mod v41_to_v42;
     mod v42_to_v43;
     mod v43_to_v44;
+    mod v44_to_v45;
+    mod v45_to_v46;
+    mod v46_to_v47;
+    mod v47_to_v48;
 
     #[tracing::instrument(name = "stash::upgrade", level = "debug", skip_all)]
     pub(crate) async fn upgrade(stash: &mut Stash) -> Result<(), StashError> {
@@ -118,6 +122,10 @@ pub(crate) mod stash {
                             41 => v41_to_v42::upgrade(),
                             42 => v42_to_v43::upgrade(),
                             43 => v43_to_v44::upgrade(),
+                            44 => v44_to_v45::upgrade(),
+                            45 => v45_to_v46::upgrade(),
+                            46 => v46_to_v47::upgrade(),
+                            47 => v47_to_v48::upgrade(),
 
                             // Up-to-date, no migration needed!
                             CATALOG_VERSION => return Ok(CATALOG_VERSION),
@@ -288,6 +296,10 @@ pub(crate) mod persist {
                 41 => panic!("upgrades not implemented"),
                 42 => panic!("upgrades not implemented"),
                 43 => panic!("upgrades not implemented"),
+                44 => panic!("upgrades not implemented"),
+                45 => panic!("upgrades not implemented"),
+                46 => panic!("upgrades not implemented"),
+                47 => panic!("upgrades not implemented"),
 
                 // Up-to-date, no migration needed!
                 CATALOG_VERSION => Ok((CATALOG_VERSION, upper)),
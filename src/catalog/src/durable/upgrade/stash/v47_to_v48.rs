@@ -0,0 +1,23 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use mz_stash::upgrade::wire_compatible;
+
+use crate::durable::upgrade::{objects_v47 as v47, objects_v48 as v48};
+
+// Durable subscriptions only add new enum variants, which are encoded as plain integers, so every
+// message that references the extended enums must remain wire compatible.
+wire_compatible!(v47::GidMappingKey with v48::GidMappingKey);
+wire_compatible!(v47::DefaultPrivilegesKey with v48::DefaultPrivilegesKey);
+wire_compatible!(v47::AuditLogKey with v48::AuditLogKey);
+wire_compatible!(v47::ItemKey with v48::ItemKey);
+wire_compatible!(v47::ItemValue with v48::ItemValue);
+
+/// No-op migration for adding durable subscriptions.
+pub fn upgrade() {}
@@ -12,6 +12,7 @@
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -29,8 +30,9 @@ use mz_controller_types::{ClusterId, ReplicaId};
 use mz_expr::{CollectionPlan, MirScalarExpr, OptimizedMirRelationExpr};
 use mz_ore::collections::CollectionExt;
 use mz_repr::adt::mz_acl_item::{AclMode, PrivilegeMap};
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::role_id::RoleId;
-use mz_repr::{GlobalId, RelationDesc};
+use mz_repr::{ColumnName, GlobalId, RelationDesc, Timestamp};
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::Expr;
 use mz_sql::catalog::{
@@ -325,6 +327,7 @@ pub struct CatalogEntry {
     pub name: QualifiedItemName,
     pub owner_id: RoleId,
     pub privileges: PrivilegeMap,
+    pub column_privileges: BTreeMap<ColumnName, PrivilegeMap>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -340,6 +343,8 @@ pub enum CatalogItem {
     Func(Func),
     Secret(Secret),
     Connection(Connection),
+    Policy(Policy),
+    Subscription(Subscription),
 }
 
 impl From<CatalogEntry> for durable::Item {
@@ -351,6 +356,16 @@ impl From<CatalogEntry> for durable::Item {
             create_sql: entry.item.into_serialized(),
             owner_id: entry.owner_id,
             privileges: entry.privileges.into_all_values().collect(),
+            column_privileges: entry
+                .column_privileges
+                .into_iter()
+                .map(|(column, privileges)| {
+                    (
+                        column.as_str().to_string(),
+                        privileges.into_all_values().collect(),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -517,7 +532,10 @@ impl Source {
             desc: plan.source.desc,
             timeline: plan.timeline,
             resolved_ids,
-            custom_logical_compaction_window,
+            custom_logical_compaction_window: plan
+                .source
+                .compaction_window
+                .or(custom_logical_compaction_window),
             is_retained_metrics_object,
         }
     }
@@ -677,6 +695,8 @@ pub struct MaterializedView {
     pub resolved_ids: ResolvedIds,
     pub cluster_id: ClusterId,
     pub non_null_assertions: Vec<usize>,
+    pub custom_logical_compaction_window: Option<Duration>,
+    pub refresh_schedule: Option<RefreshSchedule>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -691,6 +711,32 @@ pub struct Index {
     pub is_retained_metrics_object: bool,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Policy {
+    pub create_sql: String,
+    /// The relation the policy restricts.
+    pub on: GlobalId,
+    /// The `USING` expression, which a row must satisfy to be visible to roles
+    /// that do not bypass row-level security.
+    #[serde(skip)]
+    pub using: Expr<Aug>,
+    pub resolved_ids: ResolvedIds,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Subscription {
+    pub create_sql: String,
+    /// The relation whose changes the subscription tracks.
+    pub on: GlobalId,
+    /// The frontier before which the consumer has processed all updates, if
+    /// any updates have been acknowledged.
+    pub acknowledged: Option<Timestamp>,
+    /// How far behind the current time the acknowledged frontier may lag
+    /// before updates are no longer retained for it.
+    pub retain_history: Option<Duration>,
+    pub resolved_ids: ResolvedIds,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Type {
     pub create_sql: Option<String>,
@@ -702,8 +748,33 @@ pub struct Type {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
+    /// The `CREATE FUNCTION` statement for user-defined functions, or `None`
+    /// for builtin functions.
+    pub create_sql: Option<String>,
     #[serde(skip)]
-    pub inner: &'static mz_sql::func::Func,
+    pub inner: FuncInner,
+    pub resolved_ids: ResolvedIds,
+}
+
+/// The implementation of a [`Func`].
+#[derive(Debug, Clone)]
+pub enum FuncInner {
+    /// A builtin function, whose implementation lives for the life of the
+    /// process.
+    Builtin(&'static mz_sql::func::Func),
+    /// A user-defined function, whose implementation is planned from its
+    /// `CREATE FUNCTION` statement.
+    User(Arc<mz_sql::func::Func>),
+}
+
+impl Func {
+    /// Returns the function's implementations.
+    pub fn inner(&self) -> &mz_sql::func::Func {
+        match &self.inner {
+            FuncInner::Builtin(func) => func,
+            FuncInner::User(func) => func,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -733,6 +804,8 @@ impl CatalogItem {
             CatalogItem::Func(_) => mz_sql::catalog::CatalogItemType::Func,
             CatalogItem::Secret(_) => mz_sql::catalog::CatalogItemType::Secret,
             CatalogItem::Connection(_) => mz_sql::catalog::CatalogItemType::Connection,
+            CatalogItem::Policy(_) => mz_sql::catalog::CatalogItemType::Policy,
+            CatalogItem::Subscription(_) => mz_sql::catalog::CatalogItemType::Subscription,
         }
     }
 
@@ -755,16 +828,15 @@ impl CatalogItem {
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
-    pub fn func(
-        &self,
-        entry: &CatalogEntry,
-    ) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self, entry: &CatalogEntry) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         match &self {
-            CatalogItem::Func(func) => Ok(func.inner),
+            CatalogItem::Func(func) => Ok(func.inner()),
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
                 actual_type: entry.item_type(),
@@ -809,7 +881,7 @@ impl CatalogItem {
     pub fn references(&self) -> &ResolvedIds {
         static EMPTY: Lazy<ResolvedIds> = Lazy::new(|| ResolvedIds(BTreeSet::new()));
         match self {
-            CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::Func(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -820,6 +892,8 @@ impl CatalogItem {
             CatalogItem::MaterializedView(mview) => &mview.resolved_ids,
             CatalogItem::Secret(_) => &*EMPTY,
             CatalogItem::Connection(connection) => &connection.resolved_ids,
+            CatalogItem::Policy(policy) => &policy.resolved_ids,
+            CatalogItem::Subscription(subscription) => &subscription.resolved_ids,
         }
     }
 
@@ -831,8 +905,9 @@ impl CatalogItem {
     pub fn uses(&self) -> BTreeSet<GlobalId> {
         let mut uses = self.references().0.clone();
         match self {
-            // TODO(jkosh44) This isn't really correct for functions. They may use other objects in
-            // their implementation. However, currently there's no way to get that information.
+            // TODO(jkosh44) This isn't really correct for builtin functions. They may use other
+            // objects in their implementation. However, currently there's no way to get that
+            // information. User-defined functions report the objects referenced by their body.
             CatalogItem::Func(_) => {}
             CatalogItem::Index(_) => {}
             CatalogItem::Sink(_) => {}
@@ -844,6 +919,8 @@ impl CatalogItem {
             CatalogItem::MaterializedView(mview) => uses.extend(mview.raw_expr.depends_on()),
             CatalogItem::Secret(_) => {}
             CatalogItem::Connection(_) => {}
+            CatalogItem::Policy(_) => {}
+            CatalogItem::Subscription(_) => {}
         }
         uses
     }
@@ -862,7 +939,9 @@ impl CatalogItem {
             | CatalogItem::Secret(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
@@ -936,12 +1015,26 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Connection(i))
             }
+            CatalogItem::Policy(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Policy(i))
+            }
+            CatalogItem::Subscription(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Subscription(i))
+            }
             CatalogItem::Type(i) => {
                 let mut i = i.clone();
                 i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
                 Ok(CatalogItem::Type(i))
             }
-            CatalogItem::Func(i) => Ok(CatalogItem::Func(i.clone())),
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
         }
     }
 
@@ -1004,6 +1097,11 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Func(i) if i.create_sql.is_some() && !rename_self => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
             CatalogItem::Func(_) | CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
@@ -1012,6 +1110,16 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Connection(i))
             }
+            CatalogItem::Policy(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Policy(i))
+            }
+            CatalogItem::Subscription(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Subscription(i))
+            }
         }
     }
 
@@ -1033,7 +1141,9 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
@@ -1055,7 +1165,9 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
@@ -1069,14 +1181,16 @@ impl CatalogItem {
             CatalogItem::Table(table) => table.custom_logical_compaction_window,
             CatalogItem::Source(source) => source.custom_logical_compaction_window,
             CatalogItem::Index(index) => index.custom_logical_compaction_window,
-            CatalogItem::MaterializedView(_)
-            | CatalogItem::Log(_)
+            CatalogItem::MaterializedView(mview) => mview.custom_logical_compaction_window,
+            CatalogItem::Log(_)
             | CatalogItem::View(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
@@ -1100,7 +1214,9 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => return None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => return None,
         };
         Some(custom_logical_compaction_window.unwrap_or(DEFAULT_LOGICAL_COMPACTION_WINDOW))
     }
@@ -1120,7 +1236,9 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => false,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => false,
         }
     }
 
@@ -1154,7 +1272,13 @@ impl CatalogItem {
                 .clone(),
             CatalogItem::Secret(secret) => secret.create_sql.clone(),
             CatalogItem::Connection(connection) => connection.create_sql.clone(),
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Policy(policy) => policy.create_sql.clone(),
+            CatalogItem::Subscription(subscription) => subscription.create_sql.clone(),
+            CatalogItem::Func(func) => func
+                .create_sql
+                .as_ref()
+                .expect("builtin functions cannot be serialized")
+                .clone(),
         }
     }
 
@@ -1180,7 +1304,11 @@ impl CatalogItem {
             CatalogItem::Type(typ) => typ.create_sql.expect("builtin types cannot be serialized"),
             CatalogItem::Secret(secret) => secret.create_sql,
             CatalogItem::Connection(connection) => connection.create_sql,
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Policy(policy) => policy.create_sql,
+            CatalogItem::Subscription(subscription) => subscription.create_sql,
+            CatalogItem::Func(func) => func
+                .create_sql
+                .expect("builtin functions cannot be serialized"),
         }
     }
 }
@@ -1204,7 +1332,7 @@ impl CatalogEntry {
     }
 
     /// Returns the [`mz_sql::func::Func`] associated with this `CatalogEntry`.
-    pub fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    pub fn func(&self) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         self.item.func(self)
     }
 
@@ -1323,7 +1451,9 @@ impl CatalogEntry {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => BTreeSet::new(),
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => BTreeSet::new(),
         }
     }
 
@@ -1346,7 +1476,9 @@ impl CatalogEntry {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
-            | CatalogItem::Connection(_) => None,
+            | CatalogItem::Connection(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Subscription(_) => None,
         }
     }
 
@@ -1445,6 +1577,11 @@ impl CatalogEntry {
     pub fn privileges(&self) -> &PrivilegeMap {
         &self.privileges
     }
+
+    /// Returns the privileges granted on individual columns of the entry.
+    pub fn column_privileges(&self) -> &BTreeMap<ColumnName, PrivilegeMap> {
+        &self.column_privileges
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -1843,7 +1980,7 @@ impl mz_sql::catalog::CatalogSchema for Schema {
     }
 
     fn has_items(&self) -> bool {
-        !self.items.is_empty()
+        !self.items.is_empty() || !self.functions.is_empty()
     }
 
     fn item_ids(&self) -> &BTreeMap<String, GlobalId> {
@@ -1962,7 +2099,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.desc(name)
     }
 
-    fn func(&self) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
+    fn func(&self) -> Result<&mz_sql::func::Func, SqlCatalogError> {
         self.func()
     }
 
@@ -1994,7 +2131,11 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
-            CatalogItem::Func(_) => "<builtin>",
+            CatalogItem::Policy(Policy { create_sql, .. }) => create_sql,
+            CatalogItem::Subscription(Subscription { create_sql, .. }) => create_sql,
+            CatalogItem::Func(Func { create_sql, .. }) => {
+                create_sql.as_deref().unwrap_or("<builtin>")
+            }
             CatalogItem::Log(_) => "<builtin>",
         }
     }
@@ -2019,6 +2160,25 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn policy_details(&self) -> Option<(&Expr<Aug>, GlobalId)> {
+        if let CatalogItem::Policy(Policy { using, on, .. }) = self.item() {
+            Some((using, *on))
+        } else {
+            None
+        }
+    }
+
+    fn subscription_details(&self) -> Option<(GlobalId, Option<Timestamp>)> {
+        if let CatalogItem::Subscription(Subscription {
+            on, acknowledged, ..
+        }) = self.item()
+        {
+            Some((*on, *acknowledged))
+        } else {
+            None
+        }
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         if let CatalogItem::Type(Type { details, .. }) = self.item() {
             Some(details)
@@ -2067,6 +2227,10 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         &self.privileges
     }
 
+    fn column_privileges(&self) -> &BTreeMap<ColumnName, PrivilegeMap> {
+        &self.column_privileges
+    }
+
     fn cluster_id(&self) -> Option<ClusterId> {
         self.item().cluster_id()
     }
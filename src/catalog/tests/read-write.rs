@@ -75,6 +75,8 @@
 #![warn(clippy::from_over_into)]
 // END LINT CONFIG
 
+use std::collections::BTreeMap;
+
 use itertools::Itertools;
 use mz_audit_log::{
     CreateClusterReplicaV1, EventDetails, EventType, EventV1, IdNameV1, StorageUsageV1,
@@ -409,6 +411,7 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE VIEW v AS SELECT 1".to_string(),
             owner_id: RoleId::User(1),
             privileges: vec![],
+            column_privileges: BTreeMap::new(),
         },
         Item {
             id: GlobalId::User(200),
@@ -417,6 +420,7 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             create_sql: "CREATE MATERIALIZED VIEW mv AS SELECT 2".to_string(),
             owner_id: RoleId::User(2),
             privileges: vec![],
+            column_privileges: BTreeMap::new(),
         },
     ];
 
@@ -433,6 +437,7 @@ async fn test_items(openable_state: impl OpenableDurableCatalogState) {
             item.create_sql.clone(),
             item.owner_id,
             item.privileges.clone(),
+            item.column_privileges.clone(),
         )
         .unwrap();
     }
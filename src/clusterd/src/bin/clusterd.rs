@@ -302,8 +302,15 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         .ok()
         .or_else(|| args.tracing.log_prefix.clone())
         .unwrap_or_default();
+    let mut persist_cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+    // Batch parts survive in scratch across restarts of this replica, so
+    // rehydration can skip refetching them from blob.
+    persist_cfg.blob_cache_disk_dir = args
+        .scratch_directory
+        .as_ref()
+        .map(|dir| dir.join("persist-blob-cache"));
     let persist_clients = Arc::new(PersistClientCache::new(
-        PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone()),
+        persist_cfg,
         &metrics_registry,
         |persist_cfg, metrics| {
             let cfg = PersistPubSubClientConfig {
@@ -553,6 +553,19 @@ where
         Ok(())
     }
 
+    /// Requests the next response of a streaming peek.
+    ///
+    /// Must only be called after a `PeekResponse::Batch` was received for the peek, and at most
+    /// once per received batch.
+    pub fn continue_peek(
+        &mut self,
+        instance_id: ComputeInstanceId,
+        uuid: Uuid,
+    ) -> Result<(), InstanceMissing> {
+        self.instance(instance_id)?.continue_peek(uuid);
+        Ok(())
+    }
+
     /// Assign a read policy to specific identifiers.
     ///
     /// The policies are assigned in the order presented, and repeated identifiers should
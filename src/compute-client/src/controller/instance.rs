@@ -819,6 +819,7 @@ where
                 with_snapshot: se.with_snapshot,
                 up_to: se.up_to,
                 non_null_assertions: se.non_null_assertions,
+                refresh_schedule: se.refresh_schedule,
             };
             sink_exports.insert(id, desc);
         }
@@ -968,6 +969,24 @@ where
         self.remove_peek(uuid);
     }
 
+    /// Requests the next response of a streaming peek.
+    ///
+    /// The request is sent only to the replica whose batches have been passed on for the peek.
+    pub fn continue_peek(&mut self, uuid: Uuid) {
+        let Some(replica_id) = self.compute.peeks.get(&uuid).and_then(|p| p.target_replica) else {
+            tracing::warn!("did not find streaming peek for {uuid}");
+            return;
+        };
+
+        if let Some(replica) = self.compute.replicas.get_mut(&replica_id) {
+            // If sending the command fails, the replica requires rehydration. Rehydration
+            // fails the peek, because it targets the replica.
+            if replica.send(ComputeCommand::ContinuePeek { uuid }).is_err() {
+                self.compute.failed_replicas.insert(replica_id);
+            }
+        }
+    }
+
     /// Assigns a read policy to specific identifiers.
     ///
     /// The policies are assigned in the order presented, and repeated identifiers should
@@ -1330,6 +1349,16 @@ where
             return None;
         }
 
+        if !response.is_terminal() {
+            // The remainder of a streamed result must come from the replica that started
+            // streaming it, so we pass on only that replica's responses from now on.
+            let peek = self.compute.peeks.get_mut(&uuid).expect("peek exists");
+            peek.target_replica = Some(replica_id);
+            return Some(ComputeControllerResponse::PeekResponse(
+                uuid, response, otel_ctx,
+            ));
+        }
+
         let duration = peek.requested_at.elapsed();
         self.compute
             .metrics
@@ -1426,7 +1455,8 @@ struct PendingPeek<T> {
     time: T,
     /// For replica-targeted peeks, this specifies the replica whose response we should pass on.
     ///
-    /// If this value is `None`, we pass on the first response.
+    /// If this value is `None`, we pass on the first response. Once a replica has started
+    /// streaming the result of an untargeted peek, this is set to that replica.
     target_replica: Option<ReplicaId>,
     /// The OpenTelemetry context for this peek.
     otel_ctx: OpenTelemetryContext,
@@ -254,6 +254,7 @@ pub enum ComputeLog {
     ArrangementHeapSize,
     ArrangementHeapCapacity,
     ArrangementHeapAllocations,
+    ArrangementHeapSpilled,
     ShutdownDuration,
     ErrorCount,
 }
@@ -272,6 +273,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
                 ComputeLog::ArrangementHeapSize => ArrangementHeapSize(()),
                 ComputeLog::ArrangementHeapCapacity => ArrangementHeapCapacity(()),
                 ComputeLog::ArrangementHeapAllocations => ArrangementHeapAllocations(()),
+                ComputeLog::ArrangementHeapSpilled => ArrangementHeapSpilled(()),
                 ComputeLog::ShutdownDuration => ShutdownDuration(()),
                 ComputeLog::ErrorCount => ErrorCount(()),
             }),
@@ -290,6 +292,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
             Some(ArrangementHeapSize(())) => Ok(ComputeLog::ArrangementHeapSize),
             Some(ArrangementHeapCapacity(())) => Ok(ComputeLog::ArrangementHeapCapacity),
             Some(ArrangementHeapAllocations(())) => Ok(ComputeLog::ArrangementHeapAllocations),
+            Some(ArrangementHeapSpilled(())) => Ok(ComputeLog::ArrangementHeapSpilled),
             Some(ShutdownDuration(())) => Ok(ComputeLog::ShutdownDuration),
             Some(ErrorCount(())) => Ok(ComputeLog::ErrorCount),
             None => Err(TryFromProtoError::missing_field("ProtoComputeLog::kind")),
@@ -421,7 +424,8 @@ impl LogVariant {
             | LogVariant::Differential(DifferentialLog::Sharing)
             | LogVariant::Compute(ComputeLog::ArrangementHeapSize)
             | LogVariant::Compute(ComputeLog::ArrangementHeapCapacity)
-            | LogVariant::Compute(ComputeLog::ArrangementHeapAllocations) => RelationDesc::empty()
+            | LogVariant::Compute(ComputeLog::ArrangementHeapAllocations)
+            | LogVariant::Compute(ComputeLog::ArrangementHeapSpilled) => RelationDesc::empty()
                 .with_column("operator_id", ScalarType::UInt64.nullable(false))
                 .with_column("worker_id", ScalarType::UInt64.nullable(false)),
 
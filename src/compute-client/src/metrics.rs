@@ -375,6 +375,7 @@ pub struct CommandMetrics<M> {
     pub allow_compaction: M,
     pub peek: M,
     pub cancel_peek: M,
+    pub continue_peek: M,
     pub initialization_complete: M,
     pub update_configuration: M,
 }
@@ -391,6 +392,7 @@ impl<M> CommandMetrics<M> {
             allow_compaction: build_metric("allow_compaction"),
             peek: build_metric("peek"),
             cancel_peek: build_metric("cancel_peek"),
+            continue_peek: build_metric("continue_peek"),
             initialization_complete: build_metric("initialization_complete"),
             update_configuration: build_metric("update_configuration"),
         }
@@ -408,6 +410,7 @@ impl<M> CommandMetrics<M> {
         f(&self.allow_compaction);
         f(&self.peek);
         f(&self.cancel_peek);
+        f(&self.continue_peek);
     }
 
     pub fn for_command<T>(&self, command: &ComputeCommand<T>) -> &M {
@@ -422,6 +425,7 @@ impl<M> CommandMetrics<M> {
             AllowCompaction { .. } => &self.allow_compaction,
             Peek(_) => &self.peek,
             CancelPeek { .. } => &self.cancel_peek,
+            ContinuePeek { .. } => &self.continue_peek,
         }
     }
 
@@ -435,6 +439,7 @@ impl<M> CommandMetrics<M> {
             AllowCompaction(_) => &self.allow_compaction,
             Peek(_) => &self.peek,
             CancelPeek(_) => &self.cancel_peek,
+            ContinuePeek(_) => &self.continue_peek,
             InitializationComplete(_) => &self.initialization_complete,
             UpdateConfiguration(_) => &self.update_configuration,
         }
@@ -516,7 +521,7 @@ impl<M> PeekMetrics<M> {
         use PeekResponse::*;
 
         match response {
-            Rows(_) => &self.rows,
+            Rows(_) | Batch(_) => &self.rows,
             Error(_) => &self.error,
             Canceled => &self.canceled,
         }
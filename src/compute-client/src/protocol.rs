@@ -89,6 +89,7 @@
 //!   - [`AllowCompaction`]
 //!   - [`Peek`]
 //!   - [`CancelPeek`]
+//!   - [`ContinuePeek`]
 //!   - [`UpdateConfiguration`]
 //!
 //! The compute controller must respect dependencies between commands. For example, it must send a
@@ -106,6 +107,7 @@
 //! [`AllowCompaction`]: self::command::ComputeCommand::AllowCompaction
 //! [`Peek`]: self::command::ComputeCommand::Peek
 //! [`CancelPeek`]: self::command::ComputeCommand::CancelPeek
+//! [`ContinuePeek`]: self::command::ComputeCommand::ContinuePeek
 //! [`UpdateConfiguration`]: self::command::ComputeCommand::UpdateConfiguration
 //! [`ComputeResponse`]: self::response::ComputeResponse
 //! [`Canceled`]: self::response::PeekResponse::Canceled
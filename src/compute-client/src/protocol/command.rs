@@ -188,13 +188,18 @@ pub enum ComputeCommand<T = mz_repr::Timestamp> {
     /// not provoke undefined behavior. Instead, the replica must produce a [`PeekResponse::Error`]
     /// in response.
     ///
-    /// After receiving a `Peek` command, the replica must eventually produce a single
+    /// After receiving a `Peek` command, the replica must eventually produce a single terminal
     /// [`PeekResponse`]:
     ///
     ///    * For peeks that were not cancelled: either [`Rows`] or [`Error`].
     ///    * For peeks that were cancelled: either [`Rows`], or [`Error`], or [`Canceled`].
     ///
+    /// Peeks whose [`RowSetFinishing`] does not order the result may be answered in a streaming
+    /// fashion: the replica then sends a bounded [`Batch`] of rows and waits for a
+    /// [`ContinuePeek`](Self::ContinuePeek) command before sending the next response.
+    ///
     /// [`PeekResponse`]: super::response::PeekResponse
+    /// [`Batch`]: super::response::PeekResponse::Batch
     /// [`PeekResponse::Error`]: super::response::PeekResponse::Error
     /// [`Rows`]: super::response::PeekResponse::Rows
     /// [`Error`]: super::response::PeekResponse::Error
@@ -220,6 +225,25 @@ pub enum ComputeCommand<T = mz_repr::Timestamp> {
         /// This Value must match a [`Peek::uuid`] value transmitted in a previous `Peek` command.
         uuid: Uuid,
     },
+
+    /// `ContinuePeek` instructs the replica to send the next response of the identified streaming
+    /// peek.
+    ///
+    /// The controller must only send a `ContinuePeek` command after it has received a
+    /// [`PeekResponse::Batch`] for the peek, and at most once per received batch. A replica that
+    /// has already sent a terminal response for the peek, or that never received the peek, must
+    /// ignore the command.
+    ///
+    /// `ContinuePeek` commands are addressed to the single replica whose batches the controller
+    /// passes on, and are not part of the command history replayed to other replicas.
+    ///
+    /// [`PeekResponse::Batch`]: super::response::PeekResponse::Batch
+    ContinuePeek {
+        /// The identifier of the peek request to continue.
+        ///
+        /// This Value must match a [`Peek::uuid`] value transmitted in a previous `Peek` command.
+        uuid: Uuid,
+    },
 }
 
 impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
@@ -246,6 +270,7 @@ impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
                 }
                 ComputeCommand::Peek(peek) => Peek(peek.into_proto()),
                 ComputeCommand::CancelPeek { uuid } => CancelPeek(uuid.into_proto()),
+                ComputeCommand::ContinuePeek { uuid } => ContinuePeek(uuid.into_proto()),
             }),
         }
     }
@@ -278,6 +303,9 @@ impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
             Some(CancelPeek(uuid)) => Ok(ComputeCommand::CancelPeek {
                 uuid: uuid.into_rust()?,
             }),
+            Some(ContinuePeek(uuid)) => Ok(ComputeCommand::ContinuePeek {
+                uuid: uuid.into_rust()?,
+            }),
             None => Err(TryFromProtoError::missing_field(
                 "ProtoComputeCommand::kind",
             )),
@@ -313,6 +341,9 @@ impl Arbitrary for ComputeCommand<mz_repr::Timestamp> {
             any_uuid()
                 .prop_map(|uuid| ComputeCommand::CancelPeek { uuid })
                 .boxed(),
+            any_uuid()
+                .prop_map(|uuid| ComputeCommand::ContinuePeek { uuid })
+                .boxed(),
         ])
     }
 }
@@ -354,8 +385,12 @@ pub struct ComputeParameters {
     ///   * [`PeekResponse::Rows`] is replaced by [`PeekResponse::Error`].
     ///   * The [`SubscribeBatch::updates`] field is populated with an [`Err`] value.
     ///
+    /// For peeks whose results are streamed, the maximum applies to each
+    /// [`PeekResponse::Batch`] separately.
+    ///
     /// [`PeekResponse::Rows`]: super::response::PeekResponse::Rows
     /// [`PeekResponse::Error`]: super::response::PeekResponse::Error
+    /// [`PeekResponse::Batch`]: super::response::PeekResponse::Batch
     /// [`SubscribeBatch::updates`]: super::response::SubscribeBatch::updates
     pub max_result_size: Option<u32>,
     /// The maximum number of in-flight bytes emitted by persist_sources feeding
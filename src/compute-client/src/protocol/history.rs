@@ -125,6 +125,10 @@ where
                 ComputeCommand::CancelPeek { uuid } => {
                     live_peeks.remove(&uuid);
                 }
+                ComputeCommand::ContinuePeek { .. } => {
+                    // Continuing a streaming peek only affects the replica that received the
+                    // command. A replayed `Peek` starts over from the beginning.
+                }
             }
         }
 
@@ -213,6 +217,7 @@ where
         }
 
         command_counts.cancel_peek.borrow().set(0);
+        command_counts.continue_peek.borrow().set(0);
 
         // Allow compaction only after emmitting peek commands.
         let count = u64::cast_from(final_frontiers.len());
@@ -235,7 +240,7 @@ where
     pub fn discard_peeks(&mut self) {
         self.commands.retain(|command| {
             use ComputeCommand::*;
-            let is_peek = matches!(command, Peek(_) | CancelPeek { .. });
+            let is_peek = matches!(command, Peek(_) | CancelPeek { .. } | ContinuePeek { .. });
             if is_peek {
                 self.metrics
                     .command_counts
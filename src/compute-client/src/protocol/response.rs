@@ -79,19 +79,25 @@ pub enum ComputeResponse<T = mz_repr::Timestamp> {
     /// `PeekResponse` reports the result of a previous [`Peek` command]. The peek is identified by
     /// a `Uuid` that matches the command's [`Peek::uuid`].
     ///
-    /// The replica must send exactly one `PeekResponse` for every [`Peek` command] it received.
+    /// The replica must send exactly one terminal `PeekResponse` (i.e., one that is not a
+    /// [`Batch`]) for every [`Peek` command] it received. For peeks whose result is streamed, the
+    /// terminal response is preceded by any number of [`Batch`] responses. After sending a
+    /// [`Batch`], the replica must not send further responses for the peek until it receives a
+    /// [`ContinuePeek` command] or a [`CancelPeek` command] for it.
     ///
     /// If the replica did not receive a [`CancelPeek` command] for a peek, it must not send a
     /// [`Canceled`] response for that peek. If the replica did receive a [`CancelPeek` command]
-    /// for a peek, it may send any of the three [`PeekResponse`] variants.
+    /// for a peek, it may send any of the terminal [`PeekResponse`] variants.
     ///
     /// The replica must not send `PeekResponse`s for peek IDs that were not previously specified
     /// in a [`Peek` command].
     ///
     /// [`Peek` command]: super::command::ComputeCommand::Peek
     /// [`CancelPeek` command]: super::command::ComputeCommand::CancelPeek
+    /// [`ContinuePeek` command]: super::command::ComputeCommand::ContinuePeek
     /// [`Peek::uuid`]: super::command::Peek::uuid
     /// [`Canceled`]: PeekResponse::Canceled
+    /// [`Batch`]: PeekResponse::Batch
     PeekResponse(Uuid, PeekResponse, OpenTelemetryContext),
 
     /// `SubscribeResponse` reports the results emitted by an active subscribe over some time
@@ -204,46 +210,77 @@ impl Arbitrary for ComputeResponse<mz_repr::Timestamp> {
 
 /// The response from a `Peek`.
 ///
-/// Note that each `Peek` expects to generate exactly one `PeekResponse`, i.e.
-/// we expect a 1:1 contract between `Peek` and `PeekResponse`.
+/// Note that each `Peek` expects to generate exactly one terminal `PeekResponse`, i.e.
+/// we expect a 1:1 contract between `Peek` and the responses that are not [`PeekResponse::Batch`].
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum PeekResponse {
     /// Returned rows of a successful peek.
+    ///
+    /// If the peek's result was streamed, these are the rows not already returned in previous
+    /// [`PeekResponse::Batch`]es.
     Rows(Vec<(Row, NonZeroUsize)>),
     /// Error of an unsuccessful peek.
     Error(String),
     /// The peek was canceled.
     Canceled,
+    /// A bounded part of the rows of a successful peek whose result is streamed.
+    ///
+    /// More responses follow, but only after the receiver asked for them with a
+    /// [`ContinuePeek`](super::command::ComputeCommand::ContinuePeek) command.
+    Batch(Vec<(Row, NonZeroUsize)>),
 }
 
 impl PeekResponse {
     pub fn unwrap_rows(self) -> Vec<(Row, NonZeroUsize)> {
         match self {
             PeekResponse::Rows(rows) => rows,
-            PeekResponse::Error(_) | PeekResponse::Canceled => {
+            PeekResponse::Error(_) | PeekResponse::Canceled | PeekResponse::Batch(_) => {
                 panic!("PeekResponse::unwrap_rows called on {:?}", self)
             }
         }
     }
+
+    /// Reports whether this response concludes its peek.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, PeekResponse::Batch(_))
+    }
+}
+
+fn rows_into_proto(rows: &[(Row, NonZeroUsize)]) -> proto_peek_response::ProtoRows {
+    proto_peek_response::ProtoRows {
+        rows: rows
+            .iter()
+            .map(|(r, d)| proto_peek_response::ProtoRow {
+                row: Some(r.into_proto()),
+                diff: d.into_proto(),
+            })
+            .collect(),
+    }
+}
+
+fn rows_from_proto(
+    rows: proto_peek_response::ProtoRows,
+) -> Result<Vec<(Row, NonZeroUsize)>, TryFromProtoError> {
+    rows.rows
+        .into_iter()
+        .map(|row| {
+            Ok((
+                row.row.into_rust_if_some("ProtoRow::row")?,
+                NonZeroUsize::from_proto(row.diff)?,
+            ))
+        })
+        .collect()
 }
 
 impl RustType<ProtoPeekResponse> for PeekResponse {
     fn into_proto(&self) -> ProtoPeekResponse {
         use proto_peek_response::Kind::*;
-        use proto_peek_response::*;
         ProtoPeekResponse {
             kind: Some(match self {
-                PeekResponse::Rows(rows) => Rows(ProtoRows {
-                    rows: rows
-                        .iter()
-                        .map(|(r, d)| ProtoRow {
-                            row: Some(r.into_proto()),
-                            diff: d.into_proto(),
-                        })
-                        .collect(),
-                }),
+                PeekResponse::Rows(rows) => Rows(rows_into_proto(rows)),
                 PeekResponse::Error(err) => proto_peek_response::Kind::Error(err.clone()),
                 PeekResponse::Canceled => Canceled(()),
+                PeekResponse::Batch(rows) => Batch(rows_into_proto(rows)),
             }),
         }
     }
@@ -251,19 +288,10 @@ impl RustType<ProtoPeekResponse> for PeekResponse {
     fn from_proto(proto: ProtoPeekResponse) -> Result<Self, TryFromProtoError> {
         use proto_peek_response::Kind::*;
         match proto.kind {
-            Some(Rows(rows)) => Ok(PeekResponse::Rows(
-                rows.rows
-                    .into_iter()
-                    .map(|row| {
-                        Ok((
-                            row.row.into_rust_if_some("ProtoRow::row")?,
-                            NonZeroUsize::from_proto(row.diff)?,
-                        ))
-                    })
-                    .collect::<Result<Vec<_>, TryFromProtoError>>()?,
-            )),
+            Some(Rows(rows)) => Ok(PeekResponse::Rows(rows_from_proto(rows)?)),
             Some(proto_peek_response::Kind::Error(err)) => Ok(PeekResponse::Error(err)),
             Some(Canceled(())) => Ok(PeekResponse::Canceled),
+            Some(Batch(rows)) => Ok(PeekResponse::Batch(rows_from_proto(rows)?)),
             None => Err(TryFromProtoError::missing_field("ProtoPeekResponse::kind")),
         }
     }
@@ -286,6 +314,15 @@ impl Arbitrary for PeekResponse {
             .boxed(),
             ".*".prop_map(PeekResponse::Error).boxed(),
             Just(PeekResponse::Canceled).boxed(),
+            proptest::collection::vec(
+                (
+                    any::<Row>(),
+                    (1..usize::MAX).prop_map(|u| NonZeroUsize::try_from(u).unwrap()),
+                ),
+                1..11,
+            )
+            .prop_map(PeekResponse::Batch)
+            .boxed(),
         ])
     }
 }
@@ -13,7 +13,7 @@
 
 //! Compute layer client and server.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::iter;
 
 use async_trait::async_trait;
@@ -125,13 +125,14 @@ pub struct PartitionedComputeState<T> {
     /// Pending responses for a peek; returnable once all are available.
     ///
     /// Tracking of responses for a peek is initialized when the first `PeekResponse` for that peek
-    /// is received. Once all shards have provided a `PeekResponse`, a unified peek response is
-    /// emitted and the peek tracking state is dropped again.
+    /// is received. Once all shards that have not yet concluded the peek have provided a
+    /// `PeekResponse`, a unified peek response is emitted. The peek tracking state is dropped
+    /// again once all shards have provided a terminal response.
     ///
-    /// The compute protocol requires that exactly one response is emitted for each peek. This
-    /// property ensures that a) we can eventually drop the tracking state maintained for a peek
-    /// and b) we won't re-initialize tracking for a peek we have already served.
-    peek_responses: BTreeMap<Uuid, BTreeMap<usize, PeekResponse>>,
+    /// The compute protocol requires that exactly one terminal response is emitted for each peek.
+    /// This property ensures that a) we can eventually drop the tracking state maintained for a
+    /// peek and b) we won't re-initialize tracking for a peek we have already served.
+    peek_responses: BTreeMap<Uuid, PendingPeekResponses>,
     /// Tracks in-progress `SUBSCRIBE`s, and the stashed rows we are holding back until their
     /// timestamps are complete.
     ///
@@ -158,6 +159,33 @@ pub struct PartitionedComputeState<T> {
     pending_subscribes: BTreeMap<GlobalId, PendingSubscribe<T>>,
 }
 
+/// Tracking state for the responses of a peek across all partitions.
+///
+/// Peeks whose results are streamed are answered in rounds: each shard that has not yet sent a
+/// terminal response sends one response per round, and the responses of a round are unified into
+/// a single response.
+#[derive(Debug)]
+struct PendingPeekResponses {
+    /// Shards that have not yet sent a terminal response.
+    active: BTreeSet<usize>,
+    /// Responses received in the current round.
+    round: BTreeMap<usize, PeekResponse>,
+    /// Whether a unified terminal response was already emitted.
+    ///
+    /// This happens when one shard fails the peek while others still have rows to send.
+    concluded: bool,
+}
+
+impl PendingPeekResponses {
+    fn new(parts: usize) -> Self {
+        Self {
+            active: (0..parts).collect(),
+            round: BTreeMap::new(),
+            concluded: false,
+        }
+    }
+}
+
 impl<T> Partitionable<ComputeCommand<T>, ComputeResponse<T>>
     for (ComputeCommand<T>, ComputeResponse<T>)
 where
@@ -294,33 +322,74 @@ where
             }
             ComputeResponse::PeekResponse(uuid, response, otel_ctx) => {
                 // Incorporate new peek responses; awaiting all responses.
+                let parts = self.parts;
                 let entry = self
                     .peek_responses
                     .entry(uuid)
-                    .or_insert_with(Default::default);
-                let novel = entry.insert(shard_id, response);
-                assert!(novel.is_none(), "Duplicate peek response");
+                    .or_insert_with(|| PendingPeekResponses::new(parts));
+                assert!(
+                    entry.active.contains(&shard_id),
+                    "Peek response from concluded shard"
+                );
+
+                if entry.concluded {
+                    // A unified terminal response was already emitted; we only wait for the
+                    // remaining shards to conclude the peek as well.
+                    if response.is_terminal() {
+                        entry.active.remove(&shard_id);
+                    }
+                    if entry.active.is_empty() {
+                        self.peek_responses.remove(&uuid);
+                    }
+                    return None;
+                }
+
+                let terminal = response.is_terminal();
+                let previous = entry.round.insert(shard_id, response);
+                // A shard that has sent a batch may still cancel the peek before it was asked to
+                // continue it.
+                match previous {
+                    None => (),
+                    Some(PeekResponse::Batch(_)) if terminal => (),
+                    Some(_) => panic!("Duplicate peek response"),
+                }
+
                 // We may be ready to respond.
-                if entry.len() == self.parts {
-                    let mut response = PeekResponse::Rows(Vec::new());
-                    for (_part, r) in std::mem::take(entry).into_iter() {
-                        response = match (response, r) {
-                            (_, PeekResponse::Canceled) => PeekResponse::Canceled,
-                            (PeekResponse::Canceled, _) => PeekResponse::Canceled,
-                            (_, PeekResponse::Error(e)) => PeekResponse::Error(e),
-                            (PeekResponse::Error(e), _) => PeekResponse::Error(e),
-                            (PeekResponse::Rows(mut rows), PeekResponse::Rows(r)) => {
-                                rows.extend(r.into_iter());
-                                PeekResponse::Rows(rows)
+                if entry.round.len() < entry.active.len() {
+                    return None;
+                }
+
+                let mut rows = Vec::new();
+                let mut failure = None;
+                for (part, r) in std::mem::take(&mut entry.round) {
+                    if r.is_terminal() {
+                        entry.active.remove(&part);
+                    }
+                    match r {
+                        PeekResponse::Rows(r) | PeekResponse::Batch(r) => rows.extend(r),
+                        PeekResponse::Canceled => failure = Some(PeekResponse::Canceled),
+                        PeekResponse::Error(e) => {
+                            if !matches!(failure, Some(PeekResponse::Canceled)) {
+                                failure = Some(PeekResponse::Error(e));
                             }
-                        };
+                        }
+                    }
+                }
+                let response = match failure {
+                    Some(failure) => failure,
+                    None if entry.active.is_empty() => PeekResponse::Rows(rows),
+                    None => PeekResponse::Batch(rows),
+                };
+
+                if response.is_terminal() {
+                    if entry.active.is_empty() {
+                        self.peek_responses.remove(&uuid);
+                    } else {
+                        entry.concluded = true;
                     }
-                    self.peek_responses.remove(&uuid);
-                    // We take the otel_ctx from the last peek, but they should all be the same
-                    Some(Ok(ComputeResponse::PeekResponse(uuid, response, otel_ctx)))
-                } else {
-                    None
                 }
+                // We take the otel_ctx from the last peek, but they should all be the same
+                Some(Ok(ComputeResponse::PeekResponse(uuid, response, otel_ctx)))
             }
             ComputeResponse::SubscribeResponse(id, response) => {
                 // Initialize tracking for this subscribe, if necessary.
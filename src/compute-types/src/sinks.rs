@@ -10,6 +10,7 @@
 //! Types for describing dataflow sinks.
 
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{GlobalId, RelationDesc};
 use mz_storage_types::controller::CollectionMetadata;
 use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
@@ -28,6 +29,8 @@ pub struct ComputeSinkDesc<S: 'static = (), T = mz_repr::Timestamp> {
     pub with_snapshot: bool,
     pub up_to: Antichain<T>,
     pub non_null_assertions: Vec<usize>,
+    /// If set, the sink only advances at the refresh times of this schedule.
+    pub refresh_schedule: Option<RefreshSchedule>,
 }
 
 impl Arbitrary for ComputeSinkDesc<CollectionMetadata, mz_repr::Timestamp> {
@@ -42,6 +45,7 @@ impl Arbitrary for ComputeSinkDesc<CollectionMetadata, mz_repr::Timestamp> {
             any::<bool>(),
             proptest::collection::vec(any::<mz_repr::Timestamp>(), 1..4),
             proptest::collection::vec(any::<usize>(), 0..4),
+            proptest::option::of(any::<RefreshSchedule>()),
         )
             .prop_map(
                 |(
@@ -51,6 +55,7 @@ impl Arbitrary for ComputeSinkDesc<CollectionMetadata, mz_repr::Timestamp> {
                     with_snapshot,
                     up_to_frontier,
                     non_null_assertions,
+                    refresh_schedule,
                 )| {
                     ComputeSinkDesc {
                         from,
@@ -59,6 +64,7 @@ impl Arbitrary for ComputeSinkDesc<CollectionMetadata, mz_repr::Timestamp> {
                         with_snapshot,
                         up_to: Antichain::from(up_to_frontier),
                         non_null_assertions,
+                        refresh_schedule,
                     }
                 },
             )
@@ -75,6 +81,7 @@ impl RustType<ProtoComputeSinkDesc> for ComputeSinkDesc<CollectionMetadata, mz_r
             with_snapshot: self.with_snapshot,
             up_to: Some(self.up_to.into_proto()),
             non_null_assertions: self.non_null_assertions.into_proto(),
+            refresh_schedule: self.refresh_schedule.into_proto(),
         }
     }
 
@@ -92,6 +99,7 @@ impl RustType<ProtoComputeSinkDesc> for ComputeSinkDesc<CollectionMetadata, mz_r
                 .up_to
                 .into_rust_if_some("ProtoComputeSinkDesc::up_to")?,
             non_null_assertions: proto.non_null_assertions.into_rust()?,
+            refresh_schedule: proto.refresh_schedule.into_rust()?,
         })
     }
 }
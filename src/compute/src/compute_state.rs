@@ -36,7 +36,7 @@ use mz_persist_client::read::ReadHandle;
 use mz_persist_client::Diagnostics;
 use mz_persist_types::codec_impls::UnitSchema;
 use mz_repr::fixed_length::{FromRowByTypes, IntoRowByTypes};
-use mz_repr::{ColumnType, DatumVec, Diff, GlobalId, Row, RowArena, Timestamp};
+use mz_repr::{ColumnType, Datum, DatumVec, Diff, GlobalId, Row, RowArena, Timestamp};
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::sources::SourceData;
 use mz_storage_types::stats::StatsCursor;
@@ -45,7 +45,7 @@ use timely::container::columnation::Columnation;
 use timely::order::PartialOrder;
 use timely::progress::frontier::Antichain;
 use timely::worker::Worker as TimelyWorker;
-use tokio::sync::oneshot;
+use tokio::sync::mpsc;
 use tracing::{debug, error, info, span, warn, Level};
 use uuid::Uuid;
 
@@ -81,6 +81,9 @@ pub struct ComputeState {
     pub subscribe_response_buffer: Rc<RefCell<Vec<(GlobalId, SubscribeResponse)>>>,
     /// Peek commands that are awaiting fulfillment.
     pub pending_peeks: BTreeMap<Uuid, PendingPeek>,
+    /// Streaming peeks that have sent a batch of their result and wait for a `ContinuePeek`
+    /// command before they send the next response.
+    pub parked_peeks: BTreeMap<Uuid, PendingPeek>,
     /// The logger, from Timely's logging framework, if logs are enabled.
     pub compute_logger: Option<logging::compute::Logger>,
     /// A process-global cache of (blob_uri, consensus_uri) -> PersistClient.
@@ -122,6 +125,7 @@ impl ComputeState {
             traces,
             subscribe_response_buffer: Default::default(),
             pending_peeks: Default::default(),
+            parked_peeks: Default::default(),
             compute_logger: None,
             persist_clients,
             command_history,
@@ -197,6 +201,7 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
                 self.handle_peek(peek)
             }
             CancelPeek { uuid } => self.handle_cancel_peek(uuid),
+            ContinuePeek { uuid } => self.handle_continue_peek(uuid),
         }
     }
 
@@ -385,11 +390,23 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
     }
 
     fn handle_cancel_peek(&mut self, uuid: Uuid) {
-        if let Some(peek) = self.compute_state.pending_peeks.remove(&uuid) {
+        let peek = match self.compute_state.pending_peeks.remove(&uuid) {
+            Some(peek) => Some(peek),
+            None => self.compute_state.parked_peeks.remove(&uuid),
+        };
+        if let Some(peek) = peek {
             self.send_peek_response(peek, PeekResponse::Canceled);
         }
     }
 
+    fn handle_continue_peek(&mut self, uuid: Uuid) {
+        // The peek might have concluded on this worker already, in which case there is nothing
+        // left to continue.
+        if let Some(peek) = self.compute_state.parked_peeks.remove(&uuid) {
+            self.process_peek(&mut Antichain::new(), peek);
+        }
+    }
+
     fn drop_collection(&mut self, id: GlobalId) {
         let collection = self
             .compute_state
@@ -548,18 +565,31 @@ impl<'a, A: Allocate + 'static> ActiveComputeState<'a, A> {
             PendingPeek::Index(peek) => {
                 peek.seek_fulfillment(upper, self.compute_state.max_result_size)
             }
-            PendingPeek::Persist(peek) => peek.result.try_recv().ok().map(|(result, duration)| {
-                self.compute_state
-                    .metrics
-                    .persist_peek_seconds
-                    .observe(duration.as_secs_f64());
+            PendingPeek::Persist(peek) => peek.result.try_recv().ok().map(|result| {
+                if result.is_terminal() {
+                    self.compute_state
+                        .metrics
+                        .persist_peek_seconds
+                        .observe(peek.started.elapsed().as_secs_f64());
+                }
                 result
             }),
         };
 
         if let Some(response) = response {
             let _span = span!(parent: peek.span(), Level::DEBUG, "process_peek").entered();
-            self.send_peek_response(peek, response)
+            if response.is_terminal() {
+                self.send_peek_response(peek, response)
+            } else {
+                // Hand out the batch, and wait to be asked for the next one.
+                let uuid = peek.peek().uuid;
+                self.send_compute_response(ComputeResponse::PeekResponse(
+                    uuid,
+                    response,
+                    OpenTelemetryContext::obtain(),
+                ));
+                self.compute_state.parked_peeks.insert(uuid, peek);
+            }
         } else {
             let uuid = peek.peek().uuid;
             self.compute_state.pending_peeks.insert(uuid, peek);
@@ -698,6 +728,7 @@ impl PendingPeek {
         PendingPeek::Index(IndexPeek {
             peek,
             trace_bundle,
+            resume: None,
             span: tracing::Span::current(),
         })
     }
@@ -708,13 +739,15 @@ impl PendingPeek {
         metadata: CollectionMetadata,
         active_worker: bool,
     ) -> Self {
-        let (result_tx, result_rx) = oneshot::channel();
+        // A capacity of one lets the background task prepare the next batch of a streaming peek
+        // while the current one is handed out, but no more.
+        let (result_tx, result_rx) = mpsc::channel(1);
         let timestamp = peek.timestamp;
         let mfp_plan = peek.map_filter_project.clone();
         let max_results_needed = peek.finishing.limit.unwrap_or(usize::MAX) + peek.finishing.offset;
+        let streaming = peek.finishing.order_by.is_empty();
 
         let task_handle = mz_ore::task::spawn(|| "persist::peek", async move {
-            let start = Instant::now();
             let result = if active_worker {
                 PersistPeek::do_peek(
                     &persist_clients,
@@ -722,6 +755,7 @@ impl PendingPeek {
                     timestamp,
                     mfp_plan,
                     max_results_needed,
+                    streaming.then_some(&result_tx),
                 )
                 .await
             } else {
@@ -731,12 +765,13 @@ impl PendingPeek {
                 Ok(rows) => PeekResponse::Rows(rows),
                 Err(e) => PeekResponse::Error(e.to_string()),
             };
-            let _ = result_tx.send((result, start.elapsed()));
+            let _ = result_tx.send(result).await;
         });
         PendingPeek::Persist(PersistPeek {
             peek,
             _abort_handle: task_handle.abort_handle().abort_on_drop(),
             result: result_rx,
+            started: Instant::now(),
             span: tracing::Span::current(),
         })
     }
@@ -765,8 +800,13 @@ pub struct PersistPeek {
     /// A background task that's responsible for producing the peek results.
     /// If we're no longer interested in the results, we abort the task.
     _abort_handle: AbortOnDropAbortHandle,
-    /// The result of the background task, eventually.
-    result: oneshot::Receiver<(PeekResponse, Duration)>,
+    /// The responses of the background task, eventually.
+    ///
+    /// Streaming peeks receive any number of [`PeekResponse::Batch`]es before the terminal
+    /// response.
+    result: mpsc::Receiver<PeekResponse>,
+    /// The time at which the peek was received.
+    started: Instant,
     /// The `tracing::Span` tracking this peek's operation
     span: tracing::Span,
 }
@@ -778,16 +818,17 @@ impl PersistPeek {
         as_of: Timestamp,
         mfp_plan: SafeMfpPlan,
         mut limit_remaining: usize,
+        batch_tx: Option<&mpsc::Sender<PeekResponse>>,
     ) -> Result<Vec<(Row, NonZeroUsize)>, String> {
         let client = persist_clients
-            .open(metadata.persist_location)
+            .open(metadata.persist_location.clone())
             .await
             .map_err(|e| e.to_string())?;
 
         let mut reader: ReadHandle<SourceData, (), Timestamp, Diff> = client
             .open_leased_reader(
                 metadata.data_shard,
-                Arc::new(metadata.relation_desc.clone()),
+                Arc::new(metadata.stored_desc().clone()),
                 Arc::new(UnitSchema),
                 Diagnostics::from_purpose("persist::peek"),
             )
@@ -795,11 +836,12 @@ impl PersistPeek {
             .map_err(|e| e.to_string())?;
 
         let metrics = client.metrics();
+        let arity = metadata.relation_desc.arity();
 
         let mut cursor = StatsCursor::new(
             &mut reader,
             metrics,
-            &metadata.relation_desc,
+            metadata.stored_desc(),
             Antichain::from_elem(as_of),
         )
         .await
@@ -809,6 +851,7 @@ impl PersistPeek {
 
         // Re-used state for processing and building rows.
         let mut result = vec![];
+        let mut result_size: usize = 0;
         let mut datum_vec = DatumVec::new();
         let mut row_builder = Row::default();
         let arena = RowArena::new();
@@ -829,10 +872,19 @@ impl PersistPeek {
                     continue;
                 };
                 let mut datum_local = datum_vec.borrow_with(&row);
+                // Rows stored in the shard of a table whose columns have been
+                // altered differ from the table's columns.
+                match &metadata.table_layout {
+                    Some(layout) => layout.read_datums(&mut datum_local),
+                    None => datum_local.resize(arity, Datum::Null),
+                }
                 let eval_result = mfp_plan
                     .evaluate_into(&mut datum_local, &arena, &mut row_builder)
                     .map_err(|e| e.to_string())?;
                 if let Some(row) = eval_result {
+                    result_size = result_size
+                        .saturating_add(row.byte_len())
+                        .saturating_add(std::mem::size_of::<NonZeroUsize>());
                     result.push((row, count));
                     limit_remaining = limit_remaining.saturating_sub(count.get());
                     if limit_remaining == 0 {
@@ -840,21 +892,51 @@ impl PersistPeek {
                     }
                 }
             }
+
+            // Hand out the rows collected so far, if the result is streamed. Sending waits
+            // until the worker has room for another batch.
+            if let Some(batch_tx) = batch_tx {
+                if limit_remaining > 0 && result_size >= PEEK_RESPONSE_BATCH_BYTES {
+                    result_size = 0;
+                    let batch = PeekResponse::Batch(std::mem::take(&mut result));
+                    batch_tx
+                        .send(batch)
+                        .await
+                        .map_err(|_| "peek was dropped".to_string())?;
+                }
+            }
         }
 
         Ok(result)
     }
 }
 
+/// The size in bytes above which a worker hands out the rows a streaming peek has collected so
+/// far as a [`PeekResponse::Batch`].
+///
+/// Peeks stream their result if the result does not need to be ordered.
+const PEEK_RESPONSE_BATCH_BYTES: usize = 1 << 20;
+
 /// An in-progress index-backed peek, and data to eventually fulfill it.
 pub struct IndexPeek {
     peek: Peek,
     /// The data from which the trace derives.
     trace_bundle: TraceBundle,
+    /// The position from which to continue reading, if the peek has already handed out a batch.
+    resume: Option<IndexPeekResume>,
     /// The `tracing::Span` tracking this peek's operation
     span: tracing::Span,
 }
 
+/// The position from which a streaming index peek continues reading its arrangement.
+#[derive(Debug)]
+enum IndexPeekResume {
+    /// Continue with the first key that is not less than the given one.
+    Key(Row),
+    /// Continue with the literal constraint at the given position, in sorted order.
+    Literal(usize),
+}
+
 impl IndexPeek {
     /// Attempts to fulfill the peek and reports success.
     ///
@@ -893,6 +975,7 @@ impl IndexPeek {
         }
 
         let response = match self.collect_finished_data(max_result_size) {
+            Ok(rows) if self.resume.is_some() => PeekResponse::Batch(rows),
             Ok(rows) => PeekResponse::Rows(rows),
             Err(text) => PeekResponse::Error(text),
         };
@@ -900,14 +983,17 @@ impl IndexPeek {
     }
 
     /// Collects data for a known-complete peek from the ok stream.
+    ///
+    /// If the peek streams its result, this collects only the next batch of it and leaves the
+    /// position to continue from in `self.resume`.
     fn collect_finished_data(
         &mut self,
         max_result_size: u32,
     ) -> Result<Vec<(Row, NonZeroUsize)>, String> {
         // Check if there exist any errors and, if so, return whatever one we
-        // find first.
+        // find first. A peek that has already handed out a batch has done so before.
         let (mut cursor, storage) = self.trace_bundle.errs_mut().cursor();
-        while cursor.key_valid(&storage) {
+        while self.resume.is_none() && cursor.key_valid(&storage) {
             let mut copies = 0;
             cursor.map_times(&storage, |time, diff| {
                 if time.less_equal(&self.peek.timestamp) {
@@ -937,24 +1023,39 @@ impl IndexPeek {
         max_result_size: u32,
     ) -> Result<Vec<(Row, NonZeroUsize)>, String> {
         let peek = &mut self.peek;
+        let resume = &mut self.resume;
         let oks = self.trace_bundle.oks_mut();
         match oks {
-            SpecializedTraceHandle::RowUnit(oks_handle) => {
-                Self::collect_ok_finished_data(peek, oks_handle, None, Some(&[]), max_result_size)
-            }
-            SpecializedTraceHandle::RowRow(oks_handle) => {
-                Self::collect_ok_finished_data(peek, oks_handle, None, None, max_result_size)
-            }
+            SpecializedTraceHandle::RowUnit(oks_handle) => Self::collect_ok_finished_data(
+                peek,
+                oks_handle,
+                None,
+                Some(&[]),
+                max_result_size,
+                resume,
+            ),
+            SpecializedTraceHandle::RowRow(oks_handle) => Self::collect_ok_finished_data(
+                peek,
+                oks_handle,
+                None,
+                None,
+                max_result_size,
+                resume,
+            ),
         }
     }
 
     /// Collects data for a known-complete peek from the ok stream.
+    ///
+    /// Starts reading at `resume`, if set. If the peek streams its result and more data remains
+    /// after collecting a batch, sets `resume` to the position to continue from.
     fn collect_ok_finished_data<Tr, K, V>(
         peek: &mut Peek<Timestamp>,
         oks_handle: &mut TraceAgent<Tr>,
         key_types: Option<&[ColumnType]>,
         val_types: Option<&[ColumnType]>,
         max_result_size: u32,
+        resume: &mut Option<IndexPeekResume>,
     ) -> Result<Vec<(Row, NonZeroUsize)>, String>
     where
         Tr: TraceReader<Key = K, Val = V, Time = Timestamp, Diff = Diff>,
@@ -977,6 +1078,10 @@ impl IndexPeek {
         // just at least those results that would have been returned.
         let max_results = peek.finishing.limit.map(|l| l + peek.finishing.offset);
 
+        // Results that need not be ordered are streamed in bounded batches.
+        let streaming = peek.finishing.order_by.is_empty();
+        let start = resume.take();
+
         use mz_ore::result::ResultExt;
 
         let mut row_builder = Row::default();
@@ -990,9 +1095,23 @@ impl IndexPeek {
             .iter_mut()
             .for_each(|vec| vec.sort());
         let has_literal_constraints = peek.literal_constraints.is_some();
-        let mut literals = peek.literal_constraints.iter().flat_map(|l| l);
+        let mut literals_done = match &start {
+            Some(IndexPeekResume::Literal(position)) => *position,
+            _ => 0,
+        };
+        let mut literals = peek
+            .literal_constraints
+            .iter()
+            .flat_map(|l| l)
+            .skip(literals_done)
+            .peekable();
         let mut current_literal = None;
 
+        if let Some(IndexPeekResume::Key(key)) = start {
+            let key = key_buf.from_row(key, key_types);
+            cursor.seek_key(&storage, &key);
+        }
+
         while cursor.key_valid(&storage) {
             if has_literal_constraints {
                 loop {
@@ -1002,6 +1121,7 @@ impl IndexPeek {
                     match current_literal {
                         None => return Ok(results),
                         Some(current_literal) => {
+                            literals_done += 1;
                             // NOTE(vmarcos): We expect the extra allocations below to be manageable
                             // since we only perform as many of them as there are literals.
                             let current_literal =
@@ -1129,6 +1249,22 @@ impl IndexPeek {
                 // We are simply stepping through all the keys that the index has.
                 cursor.step_key(&storage);
             }
+
+            // Hand out the results collected so far, if the result is streamed and there is more
+            // to read.
+            if streaming && total_size >= PEEK_RESPONSE_BATCH_BYTES {
+                if has_literal_constraints {
+                    if literals.peek().is_some() {
+                        *resume = Some(IndexPeekResume::Literal(literals_done));
+                        return Ok(results);
+                    }
+                } else if cursor.key_valid(&storage) {
+                    let mut key_row = Row::default();
+                    let key = cursor.key(&storage).into_row(&mut key_row, key_types);
+                    *resume = Some(IndexPeekResume::Key(key.clone()));
+                    return Ok(results);
+                }
+            }
         }
 
         Ok(results)
@@ -16,6 +16,7 @@ use differential_dataflow::operators::arrange::{Arrange, Arranged, TraceAgent};
 use differential_dataflow::trace::{Batch, Batcher, Builder, Trace, TraceReader};
 use differential_dataflow::{Collection, Data, ExchangeData, Hashable};
 use mz_ore::num::Overflowing;
+use mz_ore::region::measure_disk_backed;
 use timely::container::columnation::Columnation;
 use timely::dataflow::channels::pact::{ParallelizationContract, Pipeline};
 use timely::dataflow::operators::Operator;
@@ -346,6 +347,9 @@ pub trait ArrangementSize {
 /// * `arranged`: The arrangement to inspect.
 /// * `logic`: Closure that calculates the heap size/capacity/allocations for a trace. The return
 ///    value are size and capacity in bytes, and number of allocations, all in absolute values.
+///
+/// The operator additionally logs how many bytes of the size are held in disk-backed regions,
+/// as reported by [`measure_disk_backed`].
 fn log_arrangement_size_inner<G, Tr, L>(
     arranged: Arranged<G, TraceAgent<Tr>>,
     mut logic: L,
@@ -368,6 +372,7 @@ where
     let trace = Rc::downgrade(&arranged.trace.trace_box_unstable());
 
     let (mut old_size, mut old_capacity, mut old_allocations) = (0isize, 0isize, 0isize);
+    let mut old_spilled = 0isize;
 
     let stream = arranged
         .stream
@@ -384,7 +389,8 @@ where
                     return;
                 };
 
-                let (size, capacity, allocations) = logic(&trace.borrow().trace);
+                let ((size, capacity, allocations), spilled) =
+                    measure_disk_backed(|| logic(&trace.borrow().trace));
 
                 let size = size.try_into().expect("must fit");
                 if size != old_size {
@@ -410,9 +416,18 @@ where
                     });
                 }
 
+                let spilled = spilled.try_into().expect("must fit");
+                if spilled != old_spilled {
+                    logger.log(ComputeEvent::ArrangementHeapSpilled {
+                        operator,
+                        delta_spilled: spilled - old_spilled,
+                    });
+                }
+
                 old_size = size;
                 old_capacity = capacity;
                 old_allocations = allocations;
+                old_spilled = spilled;
             }
         });
     Arranged {
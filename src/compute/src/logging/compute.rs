@@ -97,6 +97,14 @@ pub enum ComputeEvent {
         /// Delta of distinct heap allocations backing the arrangement.
         delta_allocations: isize,
     },
+    /// Arrangement spilled size update
+    ArrangementHeapSpilled {
+        /// Operator index
+        operator: usize,
+        /// Delta of the bytes of the arrangement's heap size that are held in disk-backed
+        /// allocations.
+        delta_spilled: isize,
+    },
     /// Arrangement size operator address
     ArrangementHeapSizeOperator {
         /// Operator index
@@ -189,6 +197,7 @@ pub(super) fn construct<A: Allocate + 'static>(
         let (mut arrangement_heap_capacity_out, arrangement_heap_capacity) = demux.new_output();
         let (mut arrangement_heap_allocations_out, arrangement_heap_allocations) =
             demux.new_output();
+        let (mut arrangement_heap_spilled_out, arrangement_heap_spilled) = demux.new_output();
         let (mut error_count_out, error_count) = demux.new_output();
 
         let mut demux_state = DemuxState::new(worker2, metrics);
@@ -205,6 +214,7 @@ pub(super) fn construct<A: Allocate + 'static>(
                 let mut arrangement_heap_size = arrangement_heap_size_out.activate();
                 let mut arrangement_heap_capacity = arrangement_heap_capacity_out.activate();
                 let mut arrangement_heap_allocations = arrangement_heap_allocations_out.activate();
+                let mut arrangement_heap_spilled = arrangement_heap_spilled_out.activate();
                 let mut error_count = error_count_out.activate();
 
                 input.for_each(|cap, data| {
@@ -221,6 +231,7 @@ pub(super) fn construct<A: Allocate + 'static>(
                         arrangement_heap_size: arrangement_heap_size.session(&cap),
                         arrangement_heap_capacity: arrangement_heap_capacity.session(&cap),
                         arrangement_heap_allocations: arrangement_heap_allocations.session(&cap),
+                        arrangement_heap_spilled: arrangement_heap_spilled.session(&cap),
                         error_count: error_count.session(&cap),
                     };
 
@@ -342,6 +353,11 @@ pub(super) fn construct<A: Allocate + 'static>(
             .as_collection()
             .map(move |d| arrangement_heap_datum_to_row(&mut packer, d));
 
+        let mut packer = PermutedRowPacker::new(ComputeLog::ArrangementHeapSpilled);
+        let arrangement_heap_spilled = arrangement_heap_spilled
+            .as_collection()
+            .map(move |d| arrangement_heap_datum_to_row(&mut packer, d));
+
         let mut packer = PermutedRowPacker::new(ComputeLog::ErrorCount);
         let error_count = error_count.as_collection().map({
             let mut scratch = String::new();
@@ -366,6 +382,7 @@ pub(super) fn construct<A: Allocate + 'static>(
             (ArrangementHeapSize, arrangement_heap_size),
             (ArrangementHeapCapacity, arrangement_heap_capacity),
             (ArrangementHeapAllocations, arrangement_heap_allocations),
+            (ArrangementHeapSpilled, arrangement_heap_spilled),
             (ErrorCount, error_count),
         ];
 
@@ -478,6 +495,7 @@ struct ArrangementSizeState {
     size: isize,
     capacity: isize,
     count: isize,
+    spilled: isize,
 }
 
 /// State maintained in support of the `delayed_time_seconds_total` metric.
@@ -546,6 +564,7 @@ struct DemuxOutput<'a> {
     arrangement_heap_size: OutputSession<'a, ArrangementHeapDatum>,
     arrangement_heap_capacity: OutputSession<'a, ArrangementHeapDatum>,
     arrangement_heap_allocations: OutputSession<'a, ArrangementHeapDatum>,
+    arrangement_heap_spilled: OutputSession<'a, ArrangementHeapDatum>,
     error_count: OutputSession<'a, ErrorCountDatum>,
 }
 
@@ -641,6 +660,10 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
                 operator,
                 delta_allocations: allocations,
             } => self.handle_arrangement_heap_allocations(operator, allocations),
+            ArrangementHeapSpilled {
+                operator,
+                delta_spilled: spilled,
+            } => self.handle_arrangement_heap_spilled(operator, spilled),
             ArrangementHeapSizeOperator { operator, address } => {
                 self.handle_arrangement_heap_size_operator(operator, address)
             }
@@ -948,6 +971,21 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
         state.count += count;
     }
 
+    /// Update the disk-backed size for an arrangement.
+    fn handle_arrangement_heap_spilled(&mut self, operator_id: usize, spilled: isize) {
+        let ts = self.ts();
+        let Some(state) = self.state.arrangement_size.get_mut(&operator_id) else {
+            return;
+        };
+
+        let datum = ArrangementHeapDatum { operator_id };
+        self.output
+            .arrangement_heap_spilled
+            .give((datum, ts, Diff::cast_from(spilled)));
+
+        state.spilled += spilled;
+    }
+
     /// Indicate that a new arrangement exists, start maintaining the heap size state.
     fn handle_arrangement_heap_size_operator(&mut self, operator_id: usize, address: Vec<usize>) {
         let activator = self.state.worker.activator_for(&address);
@@ -975,10 +1013,13 @@ impl<A: Allocate> DemuxHandler<'_, '_, A> {
                 -Diff::cast_from(state.capacity),
             ));
             self.output.arrangement_heap_allocations.give((
-                datum,
+                datum.clone(),
                 ts,
                 -Diff::cast_from(state.count),
             ));
+            self.output
+                .arrangement_heap_spilled
+                .give((datum, ts, -Diff::cast_from(state.spilled)));
         }
         self.shared_state
             .arrangement_size_activators
@@ -13,15 +13,18 @@ use std::any::Any;
 use std::collections::{BTreeMap, BTreeSet};
 use std::rc::Rc;
 
-use differential_dataflow::Collection;
+use differential_dataflow::{AsCollection, Collection, Data};
 use mz_compute_types::sinks::{ComputeSinkConnection, ComputeSinkDesc};
 use mz_expr::{permutation_for_arrangement, EvalError, MapFilterProject};
 use mz_ore::soft_assert;
 use mz_ore::vec::PartialOrdVecExt;
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::{Diff, GlobalId, Row};
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
+use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
 use mz_timely_util::operator::CollectionExt;
+use timely::dataflow::channels::pact::Pipeline;
 use timely::dataflow::scopes::Child;
 use timely::dataflow::Scope;
 use timely::progress::Antichain;
@@ -107,6 +110,21 @@ where
             err_collection = err_collection.concat(&null_errs);
         }
 
+        if let Some(refresh_schedule) = &sink.refresh_schedule {
+            // The view is also refreshed at the `as_of`, so that it is readable
+            // right away rather than only after its first scheduled refresh.
+            // When the dataflow is restarted, its `as_of` is typically at or
+            // before the write frontier of the view, which already sits at a
+            // scheduled refresh, so this doesn't change the contents of the
+            // view.
+            let mut refresh_schedule = refresh_schedule.clone();
+            refresh_schedule
+                .ats
+                .extend(self.as_of_frontier.as_option().copied());
+            ok_collection = apply_refresh(ok_collection, refresh_schedule.clone());
+            err_collection = apply_refresh(err_collection, refresh_schedule);
+        }
+
         let region_name = match sink.connection {
             ComputeSinkConnection::Subscribe(_) => format!("SubscribeSink({:?})", sink_id),
             ComputeSinkConnection::Persist(_) => format!("PersistSink({:?})", sink_id),
@@ -136,6 +154,65 @@ where
     }
 }
 
+/// Rounds the times of all updates in `coll` up to the next refresh time of
+/// `refresh_schedule`, so that the collection only changes at refresh times.
+/// Updates that have no refresh at or after their time are dropped.
+///
+/// The input is disconnected from the output: the operator instead holds its
+/// output capability at the next refresh time at or after the input frontier.
+/// This makes the output frontier jump directly from one refresh to the next,
+/// and close once the schedule has no more refreshes.
+fn apply_refresh<G, D>(
+    coll: Collection<G, D, Diff>,
+    refresh_schedule: RefreshSchedule,
+) -> Collection<G, D, Diff>
+where
+    G: Scope<Timestamp = mz_repr::Timestamp>,
+    D: Data,
+{
+    let mut builder = AsyncOperatorBuilder::new("ApplyRefresh".to_string(), coll.scope());
+    let (mut output, output_stream) = builder.new_output();
+    let mut input = builder.new_input_connection(&coll.inner, Pipeline, vec![Antichain::new()]);
+
+    builder.build(move |capabilities| async move {
+        let mut capability = capabilities.into_iter().next();
+        while let Some(event) = input.next_mut().await {
+            match event {
+                Event::Data(_cap, data) => {
+                    // The held capability is at the next refresh time at or
+                    // after the input frontier, so it is not beyond the
+                    // rounded-up time of any update we can still receive.
+                    let Some(capability) = &capability else {
+                        continue;
+                    };
+                    for (d, time, diff) in data.drain(..) {
+                        if let Some(time) = refresh_schedule.round_up_timestamp(time) {
+                            output
+                                .give(&capability.delayed(&time), (d, time, diff))
+                                .await;
+                        }
+                    }
+                }
+                Event::Progress(frontier) => {
+                    let next_refresh = frontier
+                        .as_option()
+                        .and_then(|time| refresh_schedule.round_up_timestamp(*time));
+                    match next_refresh {
+                        Some(time) => {
+                            if let Some(capability) = &mut capability {
+                                capability.downgrade(&time);
+                            }
+                        }
+                        None => capability = None,
+                    }
+                }
+            }
+        }
+    });
+
+    output_stream.as_collection()
+}
+
 /// A type that can be rendered as a dataflow sink.
 pub(crate) trait SinkRender<G>
 where
@@ -637,8 +637,10 @@ impl<'w, A: Allocate + 'static> Worker<'w, A> {
             // All re-used dataflows should roll back any believed communicated information (e.g. frontiers)
             // so that they recommunicate that information as if from scratch.
 
-            // Remove all pending peeks.
-            for (_, peek) in std::mem::take(&mut compute_state.pending_peeks) {
+            // Remove all pending and parked peeks.
+            let parked_peeks = std::mem::take(&mut compute_state.parked_peeks);
+            let pending_peeks = std::mem::take(&mut compute_state.pending_peeks);
+            for (_, peek) in pending_peeks.into_iter().chain(parked_peeks) {
                 // Log dropping the peek request.
                 if let Some(logger) = compute_state.compute_logger.as_mut() {
                     logger.log(ComputeEvent::Peek(peek.as_log_event(), false));
@@ -99,7 +99,9 @@ use mz_build_info::BuildInfo;
 use mz_cloud_resources::{AwsExternalIdPrefix, CloudResourceController};
 use mz_controller::ControllerConfig;
 use mz_environmentd::{CatalogConfig, Listeners, ListenersConfig, BUILD_INFO};
-use mz_frontegg_auth::{Authentication, FronteggCliArgs};
+use mz_frontegg_auth::{
+    Authentication, AuthenticationProvider, FronteggCliArgs, OidcAuthentication, OidcCliArgs,
+};
 use mz_orchestrator::Orchestrator;
 use mz_orchestrator_kubernetes::{
     KubernetesImagePullPolicy, KubernetesOrchestrator, KubernetesOrchestratorConfig,
@@ -121,6 +123,7 @@ use mz_persist_client::rpc::{
 };
 use mz_persist_client::PersistLocation;
 use mz_secrets::SecretsController;
+use mz_server_core::client_cert::ClientCertAuthCliArgs;
 use mz_server_core::TlsCliArgs;
 use mz_service::emit_boot_diagnostics;
 use mz_service::secrets::{SecretsControllerKind, SecretsReaderCliArgs};
@@ -249,6 +252,12 @@ pub struct Args {
         default_value = "127.0.0.1:6881"
     )]
     balancer_http_listen_addr: SocketAddr,
+    /// Expect SQL connections to the balancer SQL port to begin with a PROXY
+    /// protocol version 2 header that carries the address of the client.
+    ///
+    /// Must match balancerd's `--proxy-protocol` option.
+    #[clap(long, env = "BALANCER_PROXY_PROTOCOL")]
+    balancer_proxy_protocol: bool,
     /// Enable cross-origin resource sharing (CORS) for HTTP requests from the
     /// specified origin.
     ///
@@ -262,7 +271,22 @@ pub struct Args {
     #[clap(flatten)]
     tls: TlsCliArgs,
     #[clap(flatten)]
+    tls_client_auth: ClientCertAuthCliArgs,
+    #[clap(flatten)]
     frontegg: FronteggCliArgs,
+    #[clap(flatten)]
+    oidc: OidcCliArgs,
+    /// Requires users to authenticate with the passwords of their roles, as
+    /// set by `CREATE ROLE ... PASSWORD`.
+    ///
+    /// SQL connections authenticate with SCRAM-SHA-256, while HTTP and
+    /// WebSocket connections authenticate with HTTP basic authentication.
+    #[clap(
+        long,
+        env = "PASSWORD_AUTH",
+        conflicts_with_all = &["frontegg-tenant", "oidc-issuer"]
+    )]
+    password_auth: bool,
 
     // === Orchestrator options. ===
     /// The service orchestrator implementation to use.
@@ -401,12 +425,23 @@ pub struct Args {
 
     // === Storage options. ===
     /// Where the persist library should store its blob data.
+    ///
+    /// If the URL has an `encryption_key_file` query parameter, blob data is
+    /// encrypted at rest with the keys in that file.
     #[clap(long, env = "PERSIST_BLOB_URL")]
     persist_blob_url: Url,
     /// Where the persist library should perform consensus.
+    ///
+    /// A `file://` URL keeps consensus in a local directory, which is only
+    /// suitable for single-node deployments. It must be paired with a
+    /// `file://` blob URL and `--catalog-store=persist`, so that the catalog
+    /// is stored alongside it.
     #[clap(long, env = "PERSIST_CONSENSUS_URL")]
     persist_consensus_url: Url,
     /// The PostgreSQL URL for the storage stash.
+    ///
+    /// The storage stash has no file-backed implementation, so this is
+    /// required even when persist and the catalog are file-backed.
     #[clap(long, env = "STORAGE_STASH_URL", value_name = "POSTGRES_URL")]
     storage_stash_url: String,
     /// The Persist PubSub URL.
@@ -447,6 +482,62 @@ pub struct Args {
     #[clap(long, env = "TIMESTAMP_ORACLE_URL", value_name = "POSTGRES_URL")]
     timestamp_oracle_url: Option<String>,
 
+    // === Recovery options. ===
+    /// Before booting, recover this environment from the state that another
+    /// one had at the given time, in milliseconds since the Unix epoch.
+    ///
+    /// The other environment's storage stash, adapter stash (unless the catalog
+    /// is stored in persist), and persist shards are all restored as of that
+    /// time into this environment's own, which must be empty. How far back
+    /// this can go is bounded by the `persist_state_history_retention` of the
+    /// other environment. Drop the recovery options once the environment has
+    /// booted, as recovering into an environment that has state fails.
+    #[clap(
+        long,
+        env = "RECOVER_AS_OF",
+        value_name = "MILLIS",
+        requires_all = &[
+            "recover-from-persist-blob-url",
+            "recover-from-persist-consensus-url",
+            "recover-from-storage-stash-url",
+        ]
+    )]
+    recover_as_of: Option<u64>,
+    /// Where the persist library of the environment to recover from stores its
+    /// blob data.
+    #[clap(
+        long,
+        env = "RECOVER_FROM_PERSIST_BLOB_URL",
+        requires = "recover-as-of"
+    )]
+    recover_from_persist_blob_url: Option<Url>,
+    /// Where the persist library of the environment to recover from performs
+    /// consensus.
+    #[clap(
+        long,
+        env = "RECOVER_FROM_PERSIST_CONSENSUS_URL",
+        requires = "recover-as-of"
+    )]
+    recover_from_persist_consensus_url: Option<Url>,
+    /// The PostgreSQL URL for the storage stash of the environment to recover
+    /// from.
+    #[clap(
+        long,
+        env = "RECOVER_FROM_STORAGE_STASH_URL",
+        value_name = "POSTGRES_URL",
+        requires = "recover-as-of"
+    )]
+    recover_from_storage_stash_url: Option<String>,
+    /// The PostgreSQL URL for the adapter stash of the environment to recover
+    /// from. Required unless the catalog is stored in persist.
+    #[clap(
+        long,
+        env = "RECOVER_FROM_ADAPTER_STASH_URL",
+        value_name = "POSTGRES_URL",
+        requires = "recover-as-of"
+    )]
+    recover_from_adapter_stash_url: Option<String>,
+
     // === Bootstrap options. ===
     #[clap(
         long,
@@ -609,6 +700,59 @@ enum CatalogKind {
     Shadow,
 }
 
+/// Restores the environment named by the `--recover-from-*` options as of
+/// `as_of` into the one this process is configured with.
+async fn recover_environment(
+    args: &Args,
+    as_of: u64,
+    metrics_registry: &MetricsRegistry,
+) -> Result<(), anyhow::Error> {
+    /// The number of concurrent shard restores, and of blob copies per shard.
+    const CONCURRENCY: usize = 16;
+
+    info!("recovering environment as of {}", as_of);
+    let mut stashes = vec![(
+        args.recover_from_storage_stash_url
+            .as_deref()
+            .expect("clap enforced"),
+        args.storage_stash_url.as_str(),
+    )];
+    if !matches!(args.catalog_store, CatalogKind::Persist) {
+        let Some(from) = args.recover_from_adapter_stash_url.as_deref() else {
+            bail!("--recover-from-adapter-stash-url is required unless the catalog is stored in persist");
+        };
+        let to = args.adapter_stash_url.as_deref().expect("clap enforced");
+        stashes.push((from, to));
+    }
+    for (from, to) in stashes {
+        let tls = mz_tls_util::make_tls(&to.parse::<tokio_postgres::Config>()?)?;
+        mz_stash::Stash::restore_as_of(from, None, to, None, tls, as_of)
+            .await
+            .context("restoring stash")?;
+    }
+
+    let restored = mz_persist_client::cli::admin::restore_environment_as_of(
+        metrics_registry,
+        args.recover_from_persist_consensus_url
+            .as_ref()
+            .expect("clap enforced")
+            .as_str(),
+        args.recover_from_persist_blob_url
+            .as_ref()
+            .expect("clap enforced")
+            .as_str(),
+        args.persist_consensus_url.as_str(),
+        args.persist_blob_url.as_str(),
+        as_of,
+        CONCURRENCY,
+        true,
+    )
+    .await
+    .context("restoring persist shards")?;
+    info!("recovered {} shards as of {}", restored.len(), as_of);
+    Ok(())
+}
+
 // TODO [Alex Hunt] move this to a shared function that can be imported by the
 // region-controller.
 fn aws_secrets_controller_prefix(env_id: &EnvironmentId) -> String {
@@ -640,6 +784,21 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     sys::enable_sigusr2_coverage_dump()?;
     sys::enable_termination_signal_cleanup()?;
 
+    // A file-backed consensus only makes sense when everything else that
+    // persist-backed state depends on lives on the same node, so insist that
+    // the blob and the catalog do too rather than silently writing the catalog
+    // to the adapter stash.
+    if args.persist_consensus_url.scheme() == "file" {
+        if args.persist_blob_url.scheme() != "file" {
+            bail!(
+                "--persist-consensus-url with a file:// URL requires a file:// --persist-blob-url"
+            );
+        }
+        if !matches!(args.catalog_store, CatalogKind::Persist) {
+            bail!("--persist-consensus-url with a file:// URL requires --catalog-store=persist");
+        }
+    }
+
     // Start Tokio runtime.
 
     let ncpus_useful = usize::max(1, cmp::min(num_cpus::get(), num_cpus::get_physical()));
@@ -685,9 +844,22 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     // Initialize fail crate for failpoint support
     let _failpoint_scenario = FailScenario::setup();
 
+    if let Some(as_of) = args.recover_as_of {
+        runtime.block_on(recover_environment(&args, as_of, &metrics_registry))?;
+    }
+
     // Configure connections.
     let tls = args.tls.into_config()?;
-    let frontegg = Authentication::from_args(args.frontegg, &metrics_registry)?;
+    let tls_client_auth = args.tls_client_auth.into_config()?;
+    let auth_provider: Option<Arc<dyn AuthenticationProvider>> = match (
+        Authentication::from_args(args.frontegg, &metrics_registry)?,
+        OidcAuthentication::from_args(args.oidc)?,
+    ) {
+        (None, None) => None,
+        (Some(frontegg), None) => Some(Arc::new(frontegg)),
+        (None, Some(oidc)) => Some(Arc::new(oidc)),
+        (Some(_), Some(_)) => unreachable!("clap enforced"),
+    };
 
     // Configure CORS.
     let allowed_origins = if !args.cors_allowed_origin.is_empty() {
@@ -964,7 +1136,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         listeners
             .serve(mz_environmentd::Config {
                 tls,
-                frontegg,
+                auth_provider,
+                password_auth: args.password_auth,
+                tls_client_auth,
+                balancer_proxy_protocol: args.balancer_proxy_protocol,
                 cors_allowed_origin,
                 catalog_config,
                 timestamp_oracle_url: args.timestamp_oracle_url,
@@ -37,15 +37,12 @@ use http::{Method, Request, StatusCode};
 use hyper_openssl::MaybeHttpsStream;
 use mz_adapter::session::Session;
 use mz_adapter::{AdapterError, AdapterNotice, Client, SessionClient};
-use mz_frontegg_auth::{
-    Authentication as FronteggAuthentication, Error as FronteggError,
-    ExchangePasswordForTokenResponse,
-};
+use mz_frontegg_auth::{AuthenticatedUser, AuthenticationProvider, Error as AuthProviderError};
 use mz_http_util::DynamicFilterTarget;
 use mz_ore::cast::u64_to_usize;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::str::StrExt;
-use mz_repr::user::ExternalUserMetadata;
+use mz_server_core::client_cert::{self, IdentMap};
 use mz_server_core::{ConnectionHandler, Server};
 use mz_sql::session::user::{
     User, HTTP_DEFAULT_USER, SUPPORT_USER, SUPPORT_USER_NAME, SYSTEM_USER, SYSTEM_USER_NAME,
@@ -84,7 +81,9 @@ pub const MAX_REQUEST_SIZE: usize = u64_to_usize(2 * bytesize::MB);
 #[derive(Debug, Clone)]
 pub struct HttpConfig {
     pub tls: Option<TlsConfig>,
-    pub frontegg: Option<FronteggAuthentication>,
+    pub auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    pub password_auth: bool,
+    pub cert_ident_map: Option<Arc<IdentMap>>,
     pub adapter_client: mz_adapter::Client,
     pub allowed_origin: AllowOrigin,
     pub active_connection_count: Arc<Mutex<ConnectionCounter>>,
@@ -106,7 +105,9 @@ pub enum TlsMode {
 
 #[derive(Clone)]
 pub struct WsState {
-    frontegg: Arc<Option<FronteggAuthentication>>,
+    auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    password_auth: bool,
+    cert_ident_map: Option<Arc<IdentMap>>,
     adapter_client_rx: Delayed<mz_adapter::Client>,
     active_connection_count: SharedConnectionCounter,
 }
@@ -121,7 +122,9 @@ impl HttpServer {
     pub fn new(
         HttpConfig {
             tls,
-            frontegg,
+            auth_provider,
+            password_auth,
+            cert_ident_map,
             adapter_client,
             allowed_origin,
             active_connection_count,
@@ -130,17 +133,30 @@ impl HttpServer {
         }: HttpConfig,
     ) -> HttpServer {
         let tls_mode = tls.as_ref().map(|tls| tls.mode).unwrap_or(TlsMode::Disable);
-        let frontegg = Arc::new(frontegg);
-        let base_frontegg = Arc::clone(&frontegg);
+        let base_auth_provider = auth_provider.clone();
         let (adapter_client_tx, adapter_client_rx) = oneshot::channel();
         adapter_client_tx
             .send(adapter_client.clone())
             .expect("rx known to be live");
         let adapter_client_rx = adapter_client_rx.shared();
+        let password_auth_client = password_auth.then(|| adapter_client.clone());
+        let base_cert_ident_map = cert_ident_map.clone();
         let base_router = base_router(BaseRouterConfig { profiling: false })
             .layer(middleware::from_fn(move |req, next| {
-                let base_frontegg = Arc::clone(&base_frontegg);
-                async move { http_auth(req, next, tls_mode, base_frontegg.as_ref().as_ref()).await }
+                let base_auth_provider = base_auth_provider.clone();
+                let password_auth_client = password_auth_client.clone();
+                let base_cert_ident_map = base_cert_ident_map.clone();
+                async move {
+                    http_auth(
+                        req,
+                        next,
+                        tls_mode,
+                        base_auth_provider.as_deref(),
+                        password_auth_client.as_ref(),
+                        base_cert_ident_map.as_deref(),
+                    )
+                    .await
+                }
             }))
             .layer(Extension(adapter_client_rx.clone()))
             .layer(Extension(Arc::clone(&active_connection_count)))
@@ -150,6 +166,7 @@ impl HttpServer {
                     .allow_headers([
                         AUTHORIZATION,
                         CONTENT_TYPE,
+                        HeaderName::from_static("last-event-id"),
                         HeaderName::from_static("x-materialize-version"),
                     ])
                     .allow_methods(Any)
@@ -160,7 +177,9 @@ impl HttpServer {
         let ws_router = Router::new()
             .route("/api/experimental/sql", routing::get(sql::handle_sql_ws))
             .with_state(WsState {
-                frontegg,
+                auth_provider,
+                password_auth,
+                cert_ident_map,
                 adapter_client_rx,
                 active_connection_count,
             });
@@ -214,7 +233,14 @@ impl Server for HttpServer {
                         let _ = ssl_stream.get_mut().shutdown().await;
                         return Err(e.into());
                     }
-                    (MaybeHttpsStream::Https(ssl_stream), ConnProtocol::Https)
+                    let cert_identities = ssl_stream
+                        .ssl()
+                        .peer_certificate()
+                        .map(|cert| client_cert::cert_identities(&cert));
+                    (
+                        MaybeHttpsStream::Https(ssl_stream),
+                        ConnProtocol::Https { cert_identities },
+                    )
                 }
                 _ => (MaybeHttpsStream::Http(conn), ConnProtocol::Http),
             };
@@ -454,7 +480,9 @@ impl InternalHttpServer {
             // attach the x-materialize-user header to all requests it proxies to this api.
             .layer(middleware::from_fn(internal_http_auth))
             .with_state(WsState {
-                frontegg: Arc::new(None),
+                auth_provider: None,
+                password_auth: false,
+                cert_ident_map: None,
                 adapter_client_rx,
                 active_connection_count,
             });
@@ -515,10 +543,15 @@ type Delayed<T> = Shared<oneshot::Receiver<T>>;
 
 type SharedConnectionCounter = Arc<Mutex<ConnectionCounter>>;
 
+/// The protocol of an HTTP connection.
 #[derive(Clone)]
-enum ConnProtocol {
+pub enum ConnProtocol {
     Http,
-    Https,
+    Https {
+        /// The identities of the client certificate, if the client presented
+        /// one. The certificate has already been verified.
+        cert_identities: Option<Vec<String>>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -640,13 +673,17 @@ enum AuthError {
     #[error("invalid username in client certificate")]
     InvalidLogin(String),
     #[error("{0}")]
-    Frontegg(#[from] FronteggError),
+    AuthProvider(#[from] AuthProviderError),
     #[error("missing authorization header")]
     MissingHttpAuthentication,
     #[error("{0}")]
     MismatchedUser(String),
     #[error("unexpected credentials")]
     UnexpectedCredentials,
+    #[error("password authentication failed for user {0}")]
+    InvalidPassword(String),
+    #[error("certificate authentication failed")]
+    InvalidCertificate,
 }
 
 impl IntoResponse for AuthError {
@@ -671,7 +708,9 @@ async fn http_auth<B>(
     mut req: Request<B>,
     next: Next<B>,
     tls_mode: TlsMode,
-    frontegg: Option<&FronteggAuthentication>,
+    auth_provider: Option<&dyn AuthenticationProvider>,
+    password_auth: Option<&mz_adapter::Client>,
+    cert_ident_map: Option<&IdentMap>,
 ) -> impl IntoResponse {
     // First, extract the username from the certificate, validating that the
     // connection matches the TLS configuration along the way.
@@ -682,10 +721,32 @@ async fn http_auth<B>(
         (TlsMode::Require, ConnProtocol::Http) => return Err(AuthError::HttpsRequired),
         (TlsMode::Require, ConnProtocol::Https { .. }) => {}
     }
-    let creds = match frontegg {
-        // If no Frontegg authentication, use the default HTTP user.
-        None => Credentials::DefaultUser,
-        Some(_) => {
+
+    // A client certificate that maps to a role authenticates the request on
+    // its own. Otherwise, fall back to the other authentication methods, if
+    // there are any.
+    if let Some(cert_ident_map) = cert_ident_map {
+        let requested_user = req
+            .headers()
+            .typed_get::<Authorization<Basic>>()
+            .map(|basic| basic.username().to_string());
+        match cert_auth(cert_ident_map, conn_protocol, requested_user.as_deref()) {
+            Some(user) => {
+                req.extensions_mut().insert(user);
+                return Ok(next.run(req).await);
+            }
+            None if auth_provider.is_none() && password_auth.is_none() => {
+                return Err(AuthError::InvalidCertificate)
+            }
+            None => {}
+        }
+    }
+
+    let creds = match (auth_provider, password_auth) {
+        // If no external or password authentication, use the default HTTP
+        // user.
+        (None, None) => Credentials::DefaultUser,
+        _ => {
             if let Some(basic) = req.headers().typed_get::<Authorization<Basic>>() {
                 Credentials::Password {
                     username: basic.username().to_string(),
@@ -701,7 +762,7 @@ async fn http_auth<B>(
         }
     };
 
-    let user = auth(frontegg, creds).await?;
+    let user = auth(auth_provider, password_auth, creds).await?;
 
     // Add the authenticated user as an extension so downstream handlers can
     // inspect it if necessary.
@@ -711,13 +772,47 @@ async fn http_auth<B>(
     Ok(next.run(req).await)
 }
 
+/// Authenticates a client by its TLS client certificate, if the certificate
+/// maps to a role.
+///
+/// If the client requested a specific user, the certificate must map to that
+/// user. Otherwise the first role that the certificate maps to is used.
+fn cert_auth(
+    cert_ident_map: &IdentMap,
+    conn_protocol: &ConnProtocol,
+    requested_user: Option<&str>,
+) -> Option<AuthedUser> {
+    let ConnProtocol::Https {
+        cert_identities: Some(identities),
+    } = conn_protocol
+    else {
+        return None;
+    };
+    let name = match requested_user {
+        Some(user) => cert_ident_map
+            .authorizes(identities, user)
+            .then(|| user.to_string())?,
+        None => cert_ident_map.roles(identities).into_iter().next()?,
+    };
+    if mz_adapter::catalog::is_reserved_role_name(&name) {
+        return None;
+    }
+    Some(AuthedUser(User {
+        name,
+        external_metadata: None,
+    }))
+}
+
 async fn init_ws(
     WsState {
-        frontegg,
+        auth_provider,
+        password_auth,
+        cert_ident_map,
         adapter_client_rx,
         active_connection_count,
     }: &WsState,
     existing_user: Option<AuthedUser>,
+    conn_protocol: Option<ConnProtocol>,
     ws: &mut WebSocket,
 ) -> Result<AuthedClient, anyhow::Error> {
     // TODO: Add a timeout here to prevent resource leaks by clients that
@@ -739,8 +834,28 @@ async fn init_ws(
             }
         }
     };
-    let (user, options) = match (frontegg.as_ref(), existing_user, ws_auth) {
-        (Some(frontegg), None, ws_auth) => {
+    // A client certificate that maps to the requested user, or to any role if
+    // no user is requested, authenticates the connection on its own.
+    let cert_user = match (cert_ident_map, &existing_user, &conn_protocol) {
+        (Some(cert_ident_map), None, Some(conn_protocol)) => {
+            let requested_user = match &ws_auth {
+                WebSocketAuth::Basic { user, .. } => Some(user.as_str()),
+                WebSocketAuth::Bearer { .. } | WebSocketAuth::OptionsOnly { .. } => None,
+            };
+            cert_auth(cert_ident_map, conn_protocol, requested_user)
+        }
+        _ => None,
+    };
+    let (user, options) = match (auth_provider.as_deref(), existing_user, ws_auth) {
+        (_, None, ws_auth) if cert_user.is_some() => {
+            let options = match ws_auth {
+                WebSocketAuth::Basic { options, .. }
+                | WebSocketAuth::Bearer { options, .. }
+                | WebSocketAuth::OptionsOnly { options } => options,
+            };
+            (cert_user.expect("known to be present"), options)
+        }
+        (Some(auth_provider), None, ws_auth) => {
             let (creds, options) = match ws_auth {
                 WebSocketAuth::Basic {
                     user,
@@ -761,7 +876,26 @@ async fn init_ws(
                     anyhow::bail!("expected auth information");
                 }
             };
-            (auth(Some(frontegg), creds).await?, options)
+            (auth(Some(auth_provider), None, creds).await?, options)
+        }
+        // No external authentication, password authentication enabled, we
+        // check the basic
+        // auth credentials against the catalog.
+        (
+            None,
+            None,
+            WebSocketAuth::Basic {
+                user,
+                password,
+                options,
+            },
+        ) if *password_auth => {
+            let adapter_client = adapter_client_rx.clone().await?;
+            let creds = Credentials::Password {
+                username: user,
+                password,
+            };
+            (auth(None, Some(&adapter_client), creds).await?, options)
         }
         (
             None,
@@ -771,21 +905,32 @@ async fn init_ws(
                 password: _,
                 options,
             },
-        ) => (auth(None, Credentials::User(user)).await?, options),
-        // No frontegg, specified existing user, we only accept options only.
+        ) if cert_ident_map.is_none() => {
+            (auth(None, None, Credentials::User(user)).await?, options)
+        }
+        // Client certificate authentication is the only authentication
+        // method, and the certificate did not match.
+        (None, None, WebSocketAuth::Basic { .. }) => {
+            anyhow::bail!("certificate authentication failed")
+        }
+        // No external authentication, specified existing user, we only accept
+        // options only.
         (None, Some(existing_user), WebSocketAuth::OptionsOnly { options }) => {
             (existing_user, options)
         }
-        // No frontegg, specified existing user, we do not expect basic or bearer auth.
+        // No external authentication, specified existing user, we do not
+        // expect basic or bearer auth.
         (None, Some(_), WebSocketAuth::Basic { .. } | WebSocketAuth::Bearer { .. }) => {
             warn!("Unexpected bearer or basic auth provided when using user header");
             anyhow::bail!("unexpected")
         }
-        // Specifying both frontegg and an existing user should not be possible.
+        // Specifying both external authentication and an existing user should
+        // not be possible.
         (Some(_), Some(_), _) => anyhow::bail!("unexpected"),
-        // No frontegg, no existing user, and no passed username.
+        // No external authentication, no existing user, and no passed
+        // username.
         (None, None, WebSocketAuth::Bearer { .. } | WebSocketAuth::OptionsOnly { .. }) => {
-            warn!("Unexpected auth type when not using frontegg or user header");
+            warn!("Unexpected auth type when not using external auth or user header");
             anyhow::bail!("unexpected")
         }
     };
@@ -810,7 +955,8 @@ enum Credentials {
 }
 
 async fn auth(
-    frontegg: Option<&FronteggAuthentication>,
+    auth_provider: Option<&dyn AuthenticationProvider>,
+    password_auth: Option<&mz_adapter::Client>,
     creds: Credentials,
 ) -> Result<AuthedUser, AuthError> {
     // There are three places a username may be specified:
@@ -822,47 +968,58 @@ async fn auth(
     // We verify that if any of these are present, they must match any other
     // that is also present.
 
-    // Then, handle Frontegg authentication if required.
-    let user = match (frontegg, creds) {
-        // If no Frontegg authentication, allow the default user.
-        (None, Credentials::DefaultUser) => User {
+    // Then, handle external or password authentication if required.
+    let user = match (auth_provider, password_auth, creds) {
+        // If password authentication is enabled, the password must match the
+        // one stored for the role in the catalog.
+        (None, Some(adapter_client), Credentials::Password { username, password }) => {
+            let verified = adapter_client
+                .role_password_verifier(&username)
+                .await
+                .map_or(false, |verifier| verifier.verify_password(&password));
+            if !verified {
+                return Err(AuthError::InvalidPassword(username));
+            }
+            User {
+                name: username,
+                external_metadata: None,
+            }
+        }
+        // Password authentication requires HTTP basic authentication.
+        (None, Some(_), _) => return Err(AuthError::MissingHttpAuthentication),
+        // If no external authentication, allow the default user.
+        (None, None, Credentials::DefaultUser) => User {
             name: HTTP_DEFAULT_USER.name.to_string(),
             external_metadata: None,
         },
-        // If no Frontegg authentication, allow a protocol-specified user.
-        (None, Credentials::User(name)) => User {
+        // If no external authentication, allow a protocol-specified user.
+        (None, None, Credentials::User(name)) => User {
             name,
             external_metadata: None,
         },
-        // With frontegg disabled, specifying credentials is an error.
-        (None, _) => return Err(AuthError::UnexpectedCredentials),
-        // If we require Frontegg auth, fetch credentials from the HTTP auth
+        // With external authentication disabled, specifying credentials is an
+        // error.
+        (None, None, _) => return Err(AuthError::UnexpectedCredentials),
+        // If we require external auth, fetch credentials from the HTTP auth
         // header. Basic auth comes with a username/password, where the password
-        // is the client+secret pair. Bearer auth is an existing JWT that must
-        // be validated. In either case, if a username was specified in the
-        // client cert, it must match that of the JWT.
-        (Some(frontegg), creds) => {
-            let claims = match creds {
+        // is, e.g., the client+secret pair for Frontegg. Bearer auth is an
+        // existing JWT that must be validated. In either case, if a username
+        // was specified in the client cert, it must match that of the JWT.
+        (Some(auth_provider), _, creds) => {
+            let AuthenticatedUser { name, metadata, .. } = match creds {
                 Credentials::Password { username, password } => {
-                    let ExchangePasswordForTokenResponse { claims, .. } = frontegg
-                        .exchange_password_for_token(&password, username)
-                        .await?;
-                    claims
-                }
-                Credentials::Token { token } => {
-                    let claims = frontegg.validate_access_token(&token, None)?;
-                    claims
+                    auth_provider
+                        .authenticate_password(username, &password)
+                        .await?
                 }
+                Credentials::Token { token } => auth_provider.authenticate_token(&token).await?,
                 Credentials::DefaultUser | Credentials::User(_) => {
                     return Err(AuthError::MissingHttpAuthentication)
                 }
             };
             User {
-                external_metadata: Some(ExternalUserMetadata {
-                    user_id: claims.user_id,
-                    admin: claims.is_admin,
-                }),
-                name: claims.email,
+                name,
+                external_metadata: Some(metadata),
             }
         }
     };
@@ -890,6 +1047,10 @@ fn base_router(BaseRouterConfig { profiling }: BaseRouterConfig) -> Router {
             routing::get(move || async move { root::handle_home(profiling).await }),
         )
         .route("/api/sql", routing::post(sql::handle_sql))
+        .route(
+            "/api/experimental/subscribe",
+            routing::get(sql::handle_sql_sse),
+        )
         .route("/memory", routing::get(memory::handle_memory))
         .route(
             "/hierarchical-memory",
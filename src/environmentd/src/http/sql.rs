@@ -9,18 +9,23 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::convert::Infallible;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use axum::body::StreamBody;
 use axum::extract::ws::{CloseFrame, Message, WebSocket};
-use axum::extract::{State, WebSocketUpgrade};
+use axum::extract::{Query, State, WebSocketUpgrade};
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::IntoResponse;
 use axum::{Extension, Json};
+use bytes::Bytes;
 use futures::future::BoxFuture;
-use futures::Future;
-use http::StatusCode;
+use futures::{Future, StreamExt};
+use http::header::CONTENT_TYPE;
+use http::{HeaderMap, StatusCode};
 use itertools::izip;
 use mz_adapter::client::RecordFirstRowStream;
 use mz_adapter::session::{EndTransactionAction, TransactionStatus};
@@ -33,33 +38,49 @@ use mz_interchange::encode::TypedDatum;
 use mz_interchange::json::{JsonNumberPolicy, ToJson};
 use mz_ore::cast::CastFrom;
 use mz_ore::result::ResultExt;
-use mz_repr::{Datum, RelationDesc, RowArena};
+use mz_repr::{ColumnType, Datum, DatumVec, RelationDesc, Row, RowArena, Timestamp};
 use mz_sql::ast::display::AstDisplay;
-use mz_sql::ast::{Raw, Statement, StatementKind};
+use mz_sql::ast::{
+    AsOf, Expr, Raw, Statement, StatementKind, SubscribeOption, SubscribeOptionName,
+    SubscribeStatement, Value, WithOptionValue,
+};
 use mz_sql::parse::StatementParseResult;
 use mz_sql::plan::Plan;
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 use tokio::{select, time};
 use tokio_postgres::error::SqlState;
-use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
 use tracing::debug;
 use tungstenite::protocol::frame::coding::CloseCode;
 
-use crate::http::{init_ws, AuthedClient, AuthedUser, WsState, MAX_REQUEST_SIZE};
+use crate::http::{init_ws, AuthedClient, AuthedUser, ConnProtocol, WsState, MAX_REQUEST_SIZE};
 
 pub async fn handle_sql(
     mut client: AuthedClient,
     Json(request): Json<SqlRequest>,
-) -> impl IntoResponse {
-    let mut res = SqlResponse {
-        results: Vec::new(),
-    };
+) -> Result<impl IntoResponse, (StatusCode, String)> {
     // Don't need to worry about timeouts or resetting cancel here because there is always exactly 1
     // request.
-    match execute_request(&mut client, request, &mut res).await {
-        Ok(()) => Ok(Json(res)),
-        Err(e) => Err((StatusCode::BAD_REQUEST, e.to_string())),
-    }
+    let (tx, mut rx) = mpsc::channel(1);
+    mz_ore::task::spawn(|| "sql_http", async move {
+        let mut sender = HttpResponseSender { tx, started: false };
+        let res = execute_request(&mut client, request, &mut sender).await;
+        sender.finish(res).await;
+    });
+    // The status of the response is determined by whether the request fails
+    // before it produces any results. Once results have been streamed, a
+    // failure can only be reported by aborting the body.
+    let first = match rx.recv().await {
+        Some(Ok(chunk)) => chunk,
+        Some(Err(e)) => return Err((StatusCode::BAD_REQUEST, e.to_string())),
+        None => {
+            let msg = "request terminated unexpectedly";
+            return Err((StatusCode::INTERNAL_SERVER_ERROR, msg.into()));
+        }
+    };
+    let body = futures::stream::once(async { Ok(first) }).chain(ReceiverStream::new(rx));
+    Ok(([(CONTENT_TYPE, "application/json")], StreamBody::new(body)))
 }
 
 #[derive(Serialize)]
@@ -70,12 +91,104 @@ struct ErrorResponse {
 pub async fn handle_sql_ws(
     State(state): State<WsState>,
     existing_user: Option<Extension<AuthedUser>>,
+    conn_protocol: Option<Extension<ConnProtocol>>,
     ws: WebSocketUpgrade,
 ) -> impl IntoResponse {
     // An upstream middleware may have already provided the user for us
     let user = existing_user.and_then(|Extension(user)| Some(user));
+    let conn_protocol = conn_protocol.map(|Extension(conn_protocol)| conn_protocol);
     ws.max_message_size(MAX_REQUEST_SIZE)
-        .on_upgrade(|ws| async move { run_ws(&state, user, ws).await })
+        .on_upgrade(|ws| async move { run_ws(&state, user, conn_protocol, ws).await })
+}
+
+/// A request to stream the results of a `SUBSCRIBE` as server-sent events.
+#[derive(Debug, Deserialize)]
+pub struct SseRequest {
+    /// A query string containing exactly one `SUBSCRIBE` statement.
+    query: String,
+}
+
+/// Runs a `SUBSCRIBE` and streams its results as server-sent events.
+///
+/// The subscription always reports progress. Each progress event, other than
+/// the first, has as its ID the greatest timestamp whose updates have all been
+/// streamed. A client that reconnects with that ID in the `Last-Event-ID`
+/// header resumes the subscription `AS OF` that timestamp, without a snapshot.
+pub async fn handle_sql_sse(
+    headers: HeaderMap,
+    Query(SseRequest { query }): Query<SseRequest>,
+    mut client: AuthedClient,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let resume_from = match headers.get("last-event-id") {
+        None => None,
+        Some(id) => match id.to_str().ok().and_then(|id| id.parse().ok()) {
+            Some(ts) => Some(ts),
+            None => {
+                let msg = format!("invalid Last-Event-ID: {:?}", id);
+                return Err((StatusCode::BAD_REQUEST, msg));
+            }
+        },
+    };
+
+    let mut stmts = match client.client.parse(&query) {
+        Ok(Ok(stmts)) => stmts,
+        Ok(Err(e)) => return Err((StatusCode::BAD_REQUEST, e.error.to_string())),
+        Err(e) => return Err((StatusCode::BAD_REQUEST, e)),
+    };
+    let mut stmt = match (stmts.pop(), stmts.is_empty()) {
+        (
+            Some(StatementParseResult {
+                ast: Statement::Subscribe(stmt),
+                ..
+            }),
+            true,
+        ) => stmt,
+        _ => {
+            let msg = "query must contain exactly one SUBSCRIBE statement";
+            return Err((StatusCode::BAD_REQUEST, msg.into()));
+        }
+    };
+    plan_sse_subscribe(&mut stmt, resume_from);
+    let request = SqlRequest::Simple {
+        query: Statement::Subscribe(stmt).to_ast_string(),
+    };
+
+    // Like the WebSocket endpoint, stream results only as fast as the client
+    // consumes them, rather than buffering them without bound.
+    let (tx, rx) = mpsc::channel(1);
+    mz_ore::task::spawn(|| "sql_sse", async move {
+        let mut sender = SseSender { tx };
+        if let Err(e) = execute_request(&mut client, request, &mut sender).await {
+            // The client may already have gone away, in which case there is
+            // no one left to tell.
+            let _ = sender.send("error", &SqlError::from(e)).await;
+        }
+    });
+    let events = ReceiverStream::new(rx).map(Ok::<_, Infallible>);
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Rewrites a `SUBSCRIBE` so that its results can be streamed as server-sent
+/// events, resuming after `resume_from` if specified.
+fn plan_sse_subscribe(stmt: &mut SubscribeStatement<Raw>, resume_from: Option<Timestamp>) {
+    stmt.options.retain(|option| match option.name {
+        SubscribeOptionName::Progress => false,
+        SubscribeOptionName::Snapshot => resume_from.is_none(),
+    });
+    stmt.options.push(SubscribeOption {
+        name: SubscribeOptionName::Progress,
+        value: Some(WithOptionValue::Value(Value::Boolean(true))),
+    });
+    if let Some(resume_from) = resume_from {
+        // All updates at or before `resume_from` have been delivered.
+        stmt.options.push(SubscribeOption {
+            name: SubscribeOptionName::Snapshot,
+            value: Some(WithOptionValue::Value(Value::Boolean(false))),
+        });
+        stmt.as_of = Some(AsOf::At(Expr::Value(Value::Number(
+            resume_from.to_string(),
+        ))));
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -98,8 +211,13 @@ pub enum WebSocketAuth {
     },
 }
 
-async fn run_ws(state: &WsState, user: Option<AuthedUser>, mut ws: WebSocket) {
-    let mut client = match init_ws(state, user, &mut ws).await {
+async fn run_ws(
+    state: &WsState,
+    user: Option<AuthedUser>,
+    conn_protocol: Option<ConnProtocol>,
+    mut ws: WebSocket,
+) {
+    let mut client = match init_ws(state, user, conn_protocol, &mut ws).await {
         Ok(client) => client,
         Err(e) => {
             // We omit most detail from the error message we send to the client, to
@@ -311,6 +429,12 @@ pub struct SqlResponse {
 
 enum StatementResult {
     SqlResult(SqlResult),
+    /// The rows of a peek, which are sent to the client as they arrive.
+    Rows {
+        desc: RelationDesc,
+        rows: RecordFirstRowStream,
+        notices: Vec<Notice>,
+    },
     Subscribe {
         desc: RelationDesc,
         tag: String,
@@ -330,13 +454,17 @@ impl From<SqlResult> for StatementResult {
 #[serde(untagged)]
 pub enum SqlResult {
     /// The query returned rows.
+    ///
+    /// The fields are serialized in the same order in which
+    /// [`HttpResponseSender`] streams them, as the tag is only known once all
+    /// rows have been sent.
     Rows {
-        /// The command complete tag.
-        tag: String,
-        /// The result rows.
-        rows: Vec<Vec<serde_json::Value>>,
         /// Information about each column.
         desc: Description,
+        /// The result rows.
+        rows: Vec<Vec<serde_json::Value>>,
+        /// The command complete tag.
+        tag: String,
         // Any notices generated during execution of the query.
         notices: Vec<Notice>,
     },
@@ -515,8 +643,8 @@ pub struct CommandStarting {
 }
 
 /// Trait describing how to transmit a response to a client. HTTP clients
-/// accumulate into a Vec and send all at once. WebSocket clients send each
-/// message as they occur.
+/// stream the JSON serialization of a [`SqlResponse`] into the response body.
+/// WebSocket clients send each message as they occur.
 #[async_trait]
 trait ResultSender: Send {
     /// Adds a result to the client. `canceled` is a function that returns a future that resolves if
@@ -556,8 +684,126 @@ trait ResultSender: Send {
     }
 }
 
+/// Streams the results of a [`SqlRequest`] into the body of an HTTP response.
+///
+/// The body is the JSON serialization of a [`SqlResponse`], written as the
+/// results are produced, so that the rows of a large result are never held in
+/// memory in full.
+struct HttpResponseSender {
+    tx: mpsc::Sender<Result<Bytes, anyhow::Error>>,
+    /// Whether the start of the [`SqlResponse`] has been written.
+    started: bool,
+}
+
+impl HttpResponseSender {
+    /// Writes `chunk` to the response body. Fails if the client has gone away.
+    async fn send(&mut self, chunk: Vec<u8>) -> Result<(), anyhow::Error> {
+        self.tx
+            .send(Ok(Bytes::from(chunk)))
+            .await
+            .map_err(|_| anyhow!("client disconnected"))
+    }
+
+    /// Writes whatever must precede the next result in the response body.
+    async fn start_result(&mut self) -> Result<(), anyhow::Error> {
+        let prefix = if self.started {
+            ","
+        } else {
+            self.started = true;
+            r#"{"results":["#
+        };
+        self.send(prefix.into()).await
+    }
+
+    /// Streams the rows in `rows` as a [`SqlResult::Rows`], or as a
+    /// [`SqlResult::Err`] if the peek fails after some rows have been sent.
+    async fn send_rows<C, F>(
+        &mut self,
+        canceled: C,
+        desc: RelationDesc,
+        mut rows: RecordFirstRowStream,
+        notices: Vec<Notice>,
+    ) -> Result<Result<(), ()>, anyhow::Error>
+    where
+        C: Fn() -> F + Send + Sync,
+        F: Future<Output = ()> + Send,
+    {
+        let mut chunk = br#"{"desc":"#.to_vec();
+        serde_json::to_writer(&mut chunk, &Description::from(&desc)).expect("must serialize");
+        chunk.extend(br#","rows":["#);
+        self.send(chunk).await?;
+
+        let types = &desc.typ().column_types;
+        let mut datum_vec = DatumVec::new();
+        let mut rows_returned = 0;
+        let (res, mut chunk) = loop {
+            match self.await_rows(canceled(), rows.recv()).await? {
+                Some(PeekResponseUnary::Rows(batch)) => {
+                    let mut chunk = vec![];
+                    for row in batch {
+                        if rows_returned > 0 {
+                            chunk.push(b',');
+                        }
+                        rows_returned += 1;
+                        let row = row_to_json(&mut datum_vec, &row, types);
+                        serde_json::to_writer(&mut chunk, &row).expect("must serialize");
+                    }
+                    self.send(chunk).await?;
+                }
+                Some(PeekResponseUnary::Error(error)) => {
+                    let mut chunk = br#"],"error":"#.to_vec();
+                    serde_json::to_writer(&mut chunk, &SqlError::from(error))
+                        .expect("must serialize");
+                    break (Err(()), chunk);
+                }
+                Some(PeekResponseUnary::Canceled) => {
+                    let mut chunk = br#"],"error":"#.to_vec();
+                    let error = SqlError::from("statement canceled due to user request");
+                    serde_json::to_writer(&mut chunk, &error).expect("must serialize");
+                    break (Err(()), chunk);
+                }
+                None => {
+                    let mut chunk = br#"],"tag":"#.to_vec();
+                    let tag = format!("SELECT {rows_returned}");
+                    serde_json::to_writer(&mut chunk, &tag).expect("must serialize");
+                    break (Ok(()), chunk);
+                }
+            }
+        };
+        chunk.extend(br#","notices":"#);
+        serde_json::to_writer(&mut chunk, &notices).expect("must serialize");
+        chunk.push(b'}');
+        self.send(chunk).await?;
+        Ok(res)
+    }
+
+    /// Completes the response body after the request has been executed.
+    ///
+    /// A request that fails before producing any results is answered with an
+    /// error status. A request that fails later aborts the body.
+    async fn finish(mut self, res: Result<(), anyhow::Error>) {
+        match res {
+            Ok(()) => {
+                let end = if self.started {
+                    "]}"
+                } else {
+                    r#"{"results":[]}"#
+                };
+                if let Err(e) = self.send(end.into()).await {
+                    debug!("failed to finish SQL response: {e}");
+                }
+            }
+            Err(e) => {
+                // The client may already have gone away, in which case there
+                // is no one left to tell.
+                let _ = self.tx.send(Err(e)).await;
+            }
+        }
+    }
+}
+
 #[async_trait]
-impl ResultSender for SqlResponse {
+impl ResultSender for HttpResponseSender {
     // The first component of the return value is
     // Err if sending to the client
     // produced an error and the server should disconnect. It is Ok(Err) if the statement
@@ -567,7 +813,7 @@ impl ResultSender for SqlResponse {
     // needs to be retired for statement logging purposes.
     async fn add_result<C, F>(
         &mut self,
-        _canceled: C,
+        canceled: C,
         res: StatementResult,
     ) -> (
         Result<Result<(), ()>, anyhow::Error>,
@@ -577,21 +823,30 @@ impl ResultSender for SqlResponse {
         C: Fn() -> F + Send + Sync,
         F: Future<Output = ()> + Send,
     {
+        if let Err(e) = self.start_result().await {
+            return (Err(e), None);
+        }
         let (res, stmt_logging) = match res {
             StatementResult::SqlResult(res) => {
                 let is_err = matches!(res, SqlResult::Err { .. });
-                self.results.push(res);
+                let chunk = serde_json::to_vec(&res).expect("must serialize");
                 let res = if is_err { Err(()) } else { Ok(()) };
-                (res, None)
+                (self.send(chunk).await.map(|()| res), None)
             }
+            StatementResult::Rows {
+                desc,
+                rows,
+                notices,
+            } => (self.send_rows(canceled, desc, rows, notices).await, None),
             StatementResult::Subscribe { ctx_extra, .. } => {
                 let message = "SUBSCRIBE only supported over websocket";
-                self.results.push(SqlResult::Err {
+                let res = SqlResult::Err {
                     error: message.into(),
                     notices: Vec::new(),
-                });
+                };
+                let chunk = serde_json::to_vec(&res).expect("must serialize");
                 (
-                    Err(()),
+                    self.send(chunk).await.map(|()| Err(())),
                     Some((
                         StatementEndedExecutionReason::Errored {
                             error: message.into(),
@@ -601,11 +856,14 @@ impl ResultSender for SqlResponse {
                 )
             }
         };
-        (Ok(res), stmt_logging)
+        (res, stmt_logging)
     }
 
     fn connection_error(&mut self) -> BoxFuture<anyhow::Error> {
-        Box::pin(futures::future::pending())
+        Box::pin(async move {
+            self.tx.closed().await;
+            anyhow!("client disconnected")
+        })
     }
 
     fn allow_subscribe(&self) -> bool {
@@ -643,6 +901,7 @@ impl ResultSender for WebSocket {
             StatementResult::SqlResult(SqlResult::Err { .. }) => (false, false),
             StatementResult::SqlResult(SqlResult::Ok { .. }) => (false, false),
             StatementResult::SqlResult(SqlResult::Rows { .. }) => (true, false),
+            StatementResult::Rows { .. } => (true, false),
             StatementResult::Subscribe { .. } => (true, true),
         };
         if let Err(e) = send(
@@ -689,6 +948,49 @@ impl ResultSender for WebSocket {
                 msgs.extend(notices.into_iter().map(WebSocketResponse::Notice));
                 (true, msgs, None)
             }
+            StatementResult::Rows {
+                desc,
+                mut rows,
+                notices,
+            } => {
+                if let Err(e) = send(self, WebSocketResponse::Rows((&desc).into())).await {
+                    return (Err(e), None);
+                }
+
+                let types = &desc.typ().column_types;
+                let mut datum_vec = DatumVec::new();
+                let mut rows_returned = 0;
+                let (is_err, mut msgs) = loop {
+                    let res = match self.await_rows(canceled(), rows.recv()).await {
+                        Ok(res) => res,
+                        Err(e) => return (Err(e), None),
+                    };
+                    match res {
+                        Some(PeekResponseUnary::Rows(batch)) => {
+                            rows_returned += batch.len();
+                            for row in batch {
+                                let row = row_to_json(&mut datum_vec, &row, types);
+                                if let Err(e) = send(self, WebSocketResponse::Row(row)).await {
+                                    return (Err(e), None);
+                                }
+                            }
+                        }
+                        Some(PeekResponseUnary::Error(error)) => {
+                            break (true, vec![WebSocketResponse::Error(error.into())]);
+                        }
+                        Some(PeekResponseUnary::Canceled) => {
+                            let error = "statement canceled due to user request";
+                            break (true, vec![WebSocketResponse::Error(error.into())]);
+                        }
+                        None => {
+                            let tag = format!("SELECT {rows_returned}");
+                            break (false, vec![WebSocketResponse::CommandComplete(tag)]);
+                        }
+                    }
+                };
+                msgs.extend(notices.into_iter().map(WebSocketResponse::Notice));
+                (is_err, msgs, None)
+            }
             StatementResult::Subscribe {
                 ref desc,
                 tag,
@@ -814,6 +1116,276 @@ impl ResultSender for WebSocket {
     }
 }
 
+/// Streams results to an HTTP client as server-sent events.
+struct SseSender {
+    tx: mpsc::Sender<Event>,
+}
+
+impl SseSender {
+    /// Sends an event of the specified type whose data is `data`, as JSON.
+    async fn send(&self, event: &str, data: &impl Serialize) -> Result<(), anyhow::Error> {
+        let data = serde_json::to_string(data).expect("must serialize");
+        self.send_event(Event::default().event(event).data(data))
+            .await
+    }
+
+    /// Sends `event`, waiting for the client to make room for it. Fails if
+    /// the client has gone away.
+    async fn send_event(&self, event: Event) -> Result<(), anyhow::Error> {
+        self.tx
+            .send(event)
+            .await
+            .map_err(|_| anyhow!("client disconnected"))
+    }
+
+    async fn send_notices(&self, notices: Vec<Notice>) -> Result<(), anyhow::Error> {
+        for notice in notices {
+            self.send("notice", &notice).await?;
+        }
+        Ok(())
+    }
+
+    /// Sends the events for a statement that completed with `tag`.
+    async fn send_complete(
+        &self,
+        tag: &impl Serialize,
+        notices: Vec<Notice>,
+    ) -> Result<(), anyhow::Error> {
+        self.send("complete", tag).await?;
+        self.send_notices(notices).await
+    }
+}
+
+#[async_trait]
+impl ResultSender for SseSender {
+    // The first component of the return value is
+    // Err if sending to the client
+    // produced an error and the server should disconnect. It is Ok(Err) if the statement
+    // produced an error and should error the transaction, but remain connected. It is Ok(Ok(()))
+    // if the statement succeeded.
+    // The second component of the return value is `Some` if execution still
+    // needs to be retired for statement logging purposes.
+    async fn add_result<C, F>(
+        &mut self,
+        canceled: C,
+        res: StatementResult,
+    ) -> (
+        Result<Result<(), ()>, anyhow::Error>,
+        Option<(StatementEndedExecutionReason, ExecuteContextExtra)>,
+    )
+    where
+        C: Fn() -> F + Send + Sync,
+        F: Future<Output = ()> + Send,
+    {
+        let (desc, tag, mut rx, ctx_extra) = match res {
+            StatementResult::SqlResult(SqlResult::Rows {
+                tag,
+                rows,
+                desc,
+                notices,
+            }) => {
+                let res = async {
+                    self.send("description", &desc).await?;
+                    self.send("updates", &rows).await?;
+                    self.send_complete(&tag, notices).await
+                }
+                .await;
+                return (res.map(Ok), None);
+            }
+            StatementResult::SqlResult(SqlResult::Ok { ok, notices, .. }) => {
+                let res = self.send_complete(&ok, notices).await;
+                return (res.map(Ok), None);
+            }
+            StatementResult::Rows {
+                desc,
+                mut rows,
+                notices,
+            } => {
+                if let Err(e) = self.send("description", &Description::from(&desc)).await {
+                    return (Err(e), None);
+                }
+                let types = &desc.typ().column_types;
+                let mut datum_vec = DatumVec::new();
+                let mut rows_returned = 0;
+                let res = loop {
+                    let res = match self.await_rows(canceled(), rows.recv()).await {
+                        Ok(res) => res,
+                        Err(e) => return (Err(e), None),
+                    };
+                    let res = match res {
+                        Some(PeekResponseUnary::Rows(batch)) => {
+                            rows_returned += batch.len();
+                            let batch: Vec<_> = batch
+                                .iter()
+                                .map(|row| row_to_json(&mut datum_vec, row, types))
+                                .collect();
+                            match self.send("updates", &batch).await {
+                                Ok(()) => continue,
+                                Err(e) => Err(e),
+                            }
+                        }
+                        Some(PeekResponseUnary::Error(error)) => {
+                            self.send("error", &SqlError::from(error)).await.map(Err)
+                        }
+                        Some(PeekResponseUnary::Canceled) => {
+                            let error = SqlError::from("statement canceled due to user request");
+                            self.send("error", &error).await.map(Err)
+                        }
+                        None => {
+                            let tag = format!("SELECT {rows_returned}");
+                            self.send("complete", &tag).await.map(Ok)
+                        }
+                    };
+                    break res;
+                };
+                let res = match res {
+                    Ok(res) => self.send_notices(notices).await.map(|()| res),
+                    Err(e) => Err(e),
+                };
+                return (res, None);
+            }
+            StatementResult::SqlResult(SqlResult::Err { error, notices }) => {
+                let res = async {
+                    self.send("error", &error).await?;
+                    self.send_notices(notices).await
+                }
+                .await;
+                return (res.map(Err), None);
+            }
+            StatementResult::Subscribe {
+                desc,
+                tag,
+                rx,
+                ctx_extra,
+            } => (desc, tag, rx, ctx_extra),
+        };
+
+        if let Err(e) = self.send("description", &Description::from(&desc)).await {
+            // We consider the remote breaking the connection to be a cancellation,
+            // matching the behavior for pgwire
+            return (
+                Err(e),
+                Some((StatementEndedExecutionReason::Canceled, ctx_extra)),
+            );
+        }
+
+        let types = &desc.typ().column_types;
+        let mut datum_vec = mz_repr::DatumVec::new();
+        let mut updates = Vec::new();
+        let mut rows_returned = 0;
+        let mut initial_progress = true;
+        let (is_err, stmt_logging) = loop {
+            let res = match self.await_rows(canceled(), rx.recv()).await {
+                Ok(res) => res,
+                Err(e) => {
+                    // We consider the remote breaking the connection to be a cancellation,
+                    // matching the behavior for pgwire
+                    return (
+                        Err(e),
+                        Some((StatementEndedExecutionReason::Canceled, ctx_extra)),
+                    );
+                }
+            };
+            let sent = match res {
+                Some(PeekResponseUnary::Rows(rows)) => {
+                    let mut sent = Ok(());
+                    for row in rows {
+                        let datums = datum_vec.borrow_with(&row);
+                        // With `PROGRESS`, the second column indicates whether
+                        // the row is a progress message.
+                        if datums[1] != Datum::True {
+                            rows_returned += 1;
+                            updates.push(
+                                datums
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, d)| {
+                                        TypedDatum::new(*d, &types[i])
+                                            .json(&JsonNumberPolicy::ConvertNumberToString)
+                                    })
+                                    .collect::<Vec<_>>(),
+                            );
+                            continue;
+                        }
+
+                        // Flush the updates that the progress message
+                        // completes, then the progress message itself.
+                        let upper = Timestamp::try_from(datums[0].unwrap_numeric().0)
+                            .expect("subscribe timestamps are valid");
+                        if !updates.is_empty() {
+                            sent = self.send("updates", &updates).await;
+                            updates.clear();
+                        }
+                        let data = serde_json::json!({ "timestamp": upper.to_string() });
+                        let mut event = Event::default().event("progress").data(data.to_string());
+                        // The initial progress message precedes the snapshot,
+                        // so it is not a valid point from which to resume.
+                        if !initial_progress {
+                            let complete = upper.step_back().expect("upper is after as_of");
+                            event = event.id(complete.to_string());
+                        }
+                        initial_progress = false;
+                        if sent.is_ok() {
+                            sent = self.send_event(event).await;
+                        }
+                        if sent.is_err() {
+                            break;
+                        }
+                    }
+                    sent
+                }
+                Some(PeekResponseUnary::Error(error)) => {
+                    let _ = self.send("error", &SqlError::from(error.clone())).await;
+                    break (
+                        true,
+                        Some((StatementEndedExecutionReason::Errored { error }, ctx_extra)),
+                    );
+                }
+                Some(PeekResponseUnary::Canceled) => {
+                    let _ = self.send("error", &SqlError::from("query canceled")).await;
+                    break (
+                        true,
+                        Some((StatementEndedExecutionReason::Canceled, ctx_extra)),
+                    );
+                }
+                None => {
+                    let _ = self.send("complete", &tag).await;
+                    break (
+                        false,
+                        Some((
+                            StatementEndedExecutionReason::Success {
+                                rows_returned: Some(u64::cast_from(rows_returned)),
+                                execution_strategy: Some(StatementExecutionStrategy::Standard),
+                            },
+                            ctx_extra,
+                        )),
+                    );
+                }
+            };
+            if let Err(e) = sent {
+                // We consider the remote breaking the connection to be a cancellation,
+                // matching the behavior for pgwire
+                return (
+                    Err(e),
+                    Some((StatementEndedExecutionReason::Canceled, ctx_extra)),
+                );
+            }
+        };
+        (Ok(if is_err { Err(()) } else { Ok(()) }), stmt_logging)
+    }
+
+    fn connection_error(&mut self) -> BoxFuture<anyhow::Error> {
+        Box::pin(async move {
+            self.tx.closed().await;
+            anyhow!("client disconnected")
+        })
+    }
+
+    fn allow_subscribe(&self) -> bool {
+        true
+    }
+}
+
 async fn send_and_retire<S: ResultSender>(
     res: StatementResult,
     client: &mut SessionClient,
@@ -855,7 +1427,7 @@ async fn execute_stmt_group<S: ResultSender>(
             let _ = send_and_retire(err.into(), client, sender).await?;
             return Ok(Err(()));
         }
-        let res = execute_stmt(client, sender, stmt, sql, params).await?;
+        let res = execute_stmt(client, stmt, sql, params).await?;
         let is_err = send_and_retire(res, client, sender).await?;
 
         if is_err.is_err() {
@@ -916,7 +1488,8 @@ async fn execute_request<S: ResultSender>(
                 | ExecuteResponseKind::Subscribing
                 | ExecuteResponseKind::CopyFrom
                 | ExecuteResponseKind::DeclaredCursor
-                | ExecuteResponseKind::ClosedCursor => true,
+                | ExecuteResponseKind::ClosedCursor
+                | ExecuteResponseKind::Listening => true,
                 // Various statements generate `PeekPlan` (`SELECT`, `COPY`,
                 // `EXPLAIN`, `SHOW`) which has both `SendRows` and `CopyTo` as its
                 // possible response types. but `COPY` needs be picked out because
@@ -991,9 +1564,8 @@ async fn execute_request<S: ResultSender>(
 }
 
 /// Executes a single statement in a [`SqlRequest`].
-async fn execute_stmt<S: ResultSender>(
+async fn execute_stmt(
     client: &mut SessionClient,
-    sender: &mut S,
     stmt: Statement<Raw>,
     sql: String,
     raw_params: Vec<Option<String>>,
@@ -1111,6 +1683,9 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedViews { .. }
         | ExecuteResponse::CreatedMaterializedView { .. }
         | ExecuteResponse::CreatedType
+        | ExecuteResponse::CreatedFunction
+        | ExecuteResponse::CreatedPolicy
+        | ExecuteResponse::CreatedSubscription
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
@@ -1127,6 +1702,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::AlteredDefaultPrivileges
         | ExecuteResponse::RevokedRole
         | ExecuteResponse::StartedTransaction { .. }
+        | ExecuteResponse::Unlistened { .. }
         | ExecuteResponse::Updated(_)
         | ExecuteResponse::AlteredObject(_)
         | ExecuteResponse::AlteredIndexLogicalCompaction
@@ -1156,42 +1732,16 @@ async fn execute_stmt<S: ResultSender>(
             };
             SqlResult::ok(client, tag.expect("ok only called on tag-generating results"), params).into()
         }
-        ExecuteResponse::SendingRows {
-            future: rows,
-            span: _,
-        } => {
-            let rows = match sender.await_rows(client.canceled(), rows).await? {
-                PeekResponseUnary::Rows(rows) => {
-                    RecordFirstRowStream::record(execute_started, client);
-                    rows
-                },
-                PeekResponseUnary::Error(e) => {
-                    return Ok(SqlResult::err(client, e).into());
-                }
-                PeekResponseUnary::Canceled => {
-                    return Ok(SqlResult::err(client, "statement canceled due to user request").into());
-                }
-            };
-            let mut sql_rows: Vec<Vec<serde_json::Value>> = vec![];
-            let mut datum_vec = mz_repr::DatumVec::new();
-            let desc = desc.relation_desc.expect("RelationDesc must exist");
-            let types = &desc.typ().column_types;
-            for row in rows {
-                let datums = datum_vec.borrow_with(&row);
-                sql_rows.push(datums.iter().enumerate().map(|(i, d)| TypedDatum::new(*d, &types[i]).json(&JsonNumberPolicy::ConvertNumberToString)).collect());
-            }
-            let tag = format!("SELECT {}", sql_rows.len());
-            SqlResult::rows(client, tag, sql_rows, desc).into()
-        }
+        ExecuteResponse::SendingRows { rows, span: _ } => StatementResult::Rows {
+            desc: desc.relation_desc.expect("RelationDesc must exist"),
+            rows: RecordFirstRowStream::new(rows, execute_started, client),
+            notices: make_notices(client),
+        },
         ExecuteResponse::SendingRowsImmediate { rows, span: _} => {
-            let mut sql_rows: Vec<Vec<serde_json::Value>> = vec![];
-            let mut datum_vec = mz_repr::DatumVec::new();
+            let mut datum_vec = DatumVec::new();
             let desc = desc.relation_desc.expect("RelationDesc must exist");
             let types = &desc.typ().column_types;
-            for row in rows {
-                let datums = datum_vec.borrow_with(&row);
-                sql_rows.push(datums.iter().enumerate().map(|(i, d)| TypedDatum::new(*d, &types[i]).json(&JsonNumberPolicy::ConvertNumberToString)).collect());
-            }
+            let sql_rows: Vec<_> = rows.iter().map(|row| row_to_json(&mut datum_vec, row, types)).collect();
             let tag = format!("SELECT {}", sql_rows.len());
             SqlResult::rows(client, tag, sql_rows, desc).into()
         }
@@ -1207,7 +1757,8 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CopyTo { .. }
         | ExecuteResponse::CopyFrom { .. }
         | ExecuteResponse::DeclaredCursor
-        | ExecuteResponse::ClosedCursor) => {
+        | ExecuteResponse::ClosedCursor
+        | ExecuteResponse::Listening { .. }) => {
             SqlResult::err(
                 client,
                 format!("internal error: encountered prohibited ExecuteResponse {:?}.\n\n
@@ -1232,6 +1783,20 @@ fn make_notices(client: &mut SessionClient) -> Vec<Notice> {
         .collect()
 }
 
+/// Converts `row`, whose columns have the types `types`, into JSON values.
+fn row_to_json(
+    datum_vec: &mut DatumVec,
+    row: &Row,
+    types: &[ColumnType],
+) -> Vec<serde_json::Value> {
+    let datums = datum_vec.borrow_with(row);
+    datums
+        .iter()
+        .zip(types)
+        .map(|(d, typ)| TypedDatum::new(*d, typ).json(&JsonNumberPolicy::ConvertNumberToString))
+        .collect()
+}
+
 // Duplicated from protocol.rs.
 // See postgres' backend/tcop/postgres.c IsTransactionExitStmt.
 fn is_txn_exit_stmt(stmt: &Statement<Raw>) -> bool {
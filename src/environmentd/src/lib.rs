@@ -98,7 +98,7 @@ use mz_build_info::{build_info, BuildInfo};
 use mz_catalog::durable::{BootstrapArgs, OpenableDurableCatalogState, StashConfig};
 use mz_cloud_resources::CloudResourceController;
 use mz_controller::ControllerConfig;
-use mz_frontegg_auth::Authentication as FronteggAuthentication;
+use mz_frontegg_auth::AuthenticationProvider;
 use mz_ore::future::OreFutureExt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::NowFn;
@@ -107,6 +107,7 @@ use mz_ore::tracing::TracingHandle;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::usage::StorageUsageClient;
 use mz_secrets::SecretsController;
+use mz_server_core::client_cert::ClientCertAuthConfig;
 use mz_server_core::{ConnectionStream, ListenerHandle, TlsCertConfig};
 use mz_sql::catalog::EnvironmentId;
 use mz_sql::session::vars::ConnectionCounter;
@@ -143,8 +144,17 @@ pub struct Config {
     pub cors_allowed_origin: AllowOrigin,
     /// TLS encryption and authentication configuration.
     pub tls: Option<TlsCertConfig>,
-    /// Frontegg JWT authentication configuration.
-    pub frontegg: Option<FronteggAuthentication>,
+    /// External authentication configuration, like Frontegg or an OpenID
+    /// Connect provider.
+    pub auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    /// Whether to authenticate users against the passwords of their roles, as
+    /// stored in the catalog. Ignored if `auth_provider` is present.
+    pub password_auth: bool,
+    /// TLS client certificate authentication configuration. Requires `tls`.
+    pub tls_client_auth: Option<ClientCertAuthConfig>,
+    /// Whether connections to the balancer SQL port begin with a PROXY
+    /// protocol version 2 header that carries the address of the client.
+    pub balancer_proxy_protocol: bool,
 
     // === Controller options. ===
     /// Storage and compute controller configuration.
@@ -346,9 +356,17 @@ impl Listeners {
 
         // Validate TLS configuration, if present.
         let (pgwire_tls, http_tls) = match &config.tls {
-            None => (None, None),
+            None => {
+                if config.tls_client_auth.is_some() {
+                    bail!("TLS client certificate authentication requires TLS");
+                }
+                (None, None)
+            }
             Some(tls_config) => {
-                let context = tls_config.context()?;
+                let context = match &config.tls_client_auth {
+                    Some(client_auth) => tls_config.context_with_client_auth(client_auth)?,
+                    None => tls_config.context()?,
+                };
                 let pgwire_tls = mz_server_core::TlsConfig {
                     context: context.clone(),
                     mode: mz_server_core::TlsMode::Require,
@@ -361,6 +379,11 @@ impl Listeners {
             }
         };
 
+        let cert_ident_map = config
+            .tls_client_auth
+            .as_ref()
+            .map(|client_auth| Arc::clone(&client_auth.ident_map));
+
         let active_connection_count = Arc::new(Mutex::new(ConnectionCounter::new(0)));
 
         let (ready_to_promote_tx, ready_to_promote_rx) = oneshot::channel();
@@ -577,10 +600,13 @@ impl Listeners {
             let sql_server = mz_pgwire::Server::new(mz_pgwire::Config {
                 tls: pgwire_tls.clone(),
                 adapter_client: adapter_client.clone(),
-                frontegg: config.frontegg.clone(),
+                auth_provider: config.auth_provider.clone(),
+                password_auth: config.password_auth,
+                cert_ident_map: cert_ident_map.clone(),
                 metrics: metrics.clone(),
                 internal: false,
                 active_connection_count: Arc::clone(&active_connection_count),
+                proxy_protocol: false,
             });
             mz_server_core::serve(sql_conns, sql_server)
         });
@@ -599,10 +625,13 @@ impl Listeners {
                     pgwire_tls
                 }),
                 adapter_client: adapter_client.clone(),
-                frontegg: None,
+                auth_provider: None,
+                password_auth: false,
+                cert_ident_map: None,
                 metrics: metrics.clone(),
                 internal: true,
                 active_connection_count: Arc::clone(&active_connection_count),
+                proxy_protocol: false,
             });
             mz_server_core::serve(internal_sql_conns, internal_sql_server)
         });
@@ -612,7 +641,9 @@ impl Listeners {
         task::spawn(|| "http_server", {
             let http_server = HttpServer::new(HttpConfig {
                 tls: http_tls,
-                frontegg: config.frontegg.clone(),
+                auth_provider: config.auth_provider.clone(),
+                password_auth: config.password_auth,
+                cert_ident_map: cert_ident_map.clone(),
                 adapter_client: adapter_client.clone(),
                 allowed_origin: config.cors_allowed_origin.clone(),
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -627,7 +658,9 @@ impl Listeners {
             let balancer_http_server = HttpServer::new(HttpConfig {
                 // TODO(Alex): implement self-signed TLS for all internal connections
                 tls: None,
-                frontegg: config.frontegg.clone(),
+                auth_provider: config.auth_provider.clone(),
+                password_auth: config.password_auth,
+                cert_ident_map: cert_ident_map.clone(),
                 adapter_client: adapter_client.clone(),
                 allowed_origin: config.cors_allowed_origin,
                 active_connection_count: Arc::clone(&active_connection_count),
@@ -642,10 +675,13 @@ impl Listeners {
             let balancer_sql_server = mz_pgwire::Server::new(mz_pgwire::Config {
                 tls: None,
                 adapter_client: adapter_client.clone(),
-                frontegg: config.frontegg.clone(),
+                auth_provider: config.auth_provider.clone(),
+                password_auth: config.password_auth,
+                cert_ident_map,
                 metrics,
                 internal: false,
                 active_connection_count: Arc::clone(&active_connection_count),
+                proxy_protocol: config.balancer_proxy_protocol,
             });
             mz_server_core::serve(balancer_sql_conns, balancer_sql_server)
         });
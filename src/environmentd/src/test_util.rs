@@ -92,6 +92,9 @@ use futures::Future;
 use headers::{Header, HeaderMapExt};
 use hyper::http::header::HeaderMap;
 use mz_controller::ControllerConfig;
+use mz_frontegg_auth::{
+    Authentication as FronteggAuthentication, AuthenticationProvider, OidcAuthentication,
+};
 use mz_orchestrator_process::{ProcessOrchestrator, ProcessOrchestratorConfig};
 use mz_orchestrator_tracing::{TracingCliArgs, TracingOrchestrator};
 use mz_ore::metrics::MetricsRegistry;
@@ -107,6 +110,7 @@ use mz_persist_client::cfg::{PersistConfig, PersistParameters};
 use mz_persist_client::rpc::PersistGrpcPubSubServer;
 use mz_persist_client::PersistLocation;
 use mz_secrets::SecretsController;
+use mz_server_core::client_cert::{ClientCertAuthConfig, IdentMap};
 use mz_server_core::TlsCertConfig;
 use mz_sql::catalog::EnvironmentId;
 use mz_stash_types::metrics::Metrics as StashMetrics;
@@ -141,7 +145,7 @@ use tungstenite::stream::MaybeTlsStream;
 use tungstenite::{Message, WebSocket};
 use url::Url;
 
-use crate::{CatalogConfig, FronteggAuthentication, WebSocketAuth, WebSocketResponse};
+use crate::{CatalogConfig, WebSocketAuth, WebSocketResponse};
 
 pub static KAFKA_ADDRS: Lazy<String> =
     Lazy::new(|| env::var("KAFKA_ADDRS").unwrap_or_else(|_| "localhost:9092".into()));
@@ -151,7 +155,10 @@ pub static KAFKA_ADDRS: Lazy<String> =
 pub struct TestHarness {
     data_directory: Option<PathBuf>,
     tls: Option<TlsCertConfig>,
-    frontegg: Option<FronteggAuthentication>,
+    auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    password_auth: bool,
+    tls_client_auth: Option<ClientCertAuthConfig>,
+    balancer_proxy_protocol: bool,
     unsafe_mode: bool,
     workers: usize,
     now: NowFn,
@@ -178,7 +185,10 @@ impl Default for TestHarness {
         TestHarness {
             data_directory: None,
             tls: None,
-            frontegg: None,
+            auth_provider: None,
+            password_auth: false,
+            tls_client_auth: None,
+            balancer_proxy_protocol: false,
             unsafe_mode: false,
             workers: 1,
             now: SYSTEM_TIME.clone(),
@@ -255,7 +265,34 @@ impl TestHarness {
     }
 
     pub fn with_frontegg(mut self, frontegg: &FronteggAuthentication) -> Self {
-        self.frontegg = Some(frontegg.clone());
+        self.auth_provider = Some(Arc::new(frontegg.clone()));
+        self
+    }
+
+    pub fn with_oidc(mut self, oidc: &OidcAuthentication) -> Self {
+        self.auth_provider = Some(Arc::new(oidc.clone()));
+        self
+    }
+
+    pub fn with_password_auth(mut self) -> Self {
+        self.password_auth = true;
+        self
+    }
+
+    pub fn with_balancer_proxy_protocol(mut self) -> Self {
+        self.balancer_proxy_protocol = true;
+        self
+    }
+
+    pub fn with_tls_client_auth(
+        mut self,
+        ca_path: impl Into<PathBuf>,
+        ident_map: IdentMap,
+    ) -> Self {
+        self.tls_client_auth = Some(ClientCertAuthConfig {
+            ca: ca_path.into(),
+            ident_map: Arc::new(ident_map),
+        });
         self
     }
 
@@ -505,7 +542,10 @@ impl Listeners {
                 secrets_controller,
                 cloud_resource_controller: None,
                 tls: config.tls,
-                frontegg: config.frontegg,
+                auth_provider: config.auth_provider,
+                password_auth: config.password_auth,
+                tls_client_auth: config.tls_client_auth,
+                balancer_proxy_protocol: config.balancer_proxy_protocol,
                 unsafe_mode: config.unsafe_mode,
                 all_features: false,
                 metrics_registry: metrics_registry.clone(),
@@ -82,6 +82,7 @@ use std::fs::{self, File};
 use std::future::IntoFuture;
 use std::io::{Read, Write};
 use std::net::{IpAddr, Ipv4Addr, TcpStream};
+use std::path::PathBuf;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -97,16 +98,20 @@ use mz_environmentd::test_util::{self, make_header, make_pg_tls, Ca};
 use mz_environmentd::{WebSocketAuth, WebSocketResponse};
 use mz_frontegg_auth::{
     Authentication as FronteggAuthentication, AuthenticationConfig as FronteggConfig, Claims,
+    JwksSource, OidcAuthentication, OidcConfig,
 };
 use mz_frontegg_mock::FronteggMockServer;
 use mz_ore::assert_contains;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{NowFn, SYSTEM_TIME};
 use mz_ore::retry::Retry;
+use mz_server_core::client_cert::IdentMap;
 use mz_sql::names::PUBLIC_ROLE_NAME;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SYSTEM_USER};
 use openssl::error::ErrorStack;
-use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions, SslVerifyMode};
+use openssl::ssl::{
+    SslConnector, SslConnectorBuilder, SslFiletype, SslMethod, SslOptions, SslVerifyMode,
+};
 use postgres::config::SslMode;
 use postgres::error::SqlState;
 use serde::Deserialize;
@@ -1251,6 +1256,510 @@ async fn test_auth_base_require_tls() {
     .await;
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_password() {
+    let server = test_util::TestHarness::default()
+        .with_password_auth()
+        .start()
+        .await;
+
+    let sys_client = server
+        .connect()
+        .internal()
+        .user(&SYSTEM_USER.name)
+        .await
+        .unwrap();
+    sys_client
+        .batch_execute("CREATE ROLE alice PASSWORD 'hunter2'; CREATE ROLE bob")
+        .await
+        .unwrap();
+
+    let alice_header = make_header(Authorization::basic("alice", "hunter2"));
+    let alice_header_wrong = make_header(Authorization::basic("alice", "hunter3"));
+    let bob_header = make_header(Authorization::basic("bob", ""));
+    let no_headers = HeaderMap::new();
+
+    run_tests(
+        "PasswordAuth",
+        &server,
+        &[
+            // The correct password should succeed.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("hunter2"),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            // An incorrect password should fail.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("hunter3"),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                    assert_eq!(
+                        err.message(),
+                        "password authentication failed for user \"alice\""
+                    );
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_header_wrong,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Roles without a password cannot log in.
+            TestCase::Pgwire {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                password: Some(""),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                scheme: Scheme::HTTP,
+                headers: &bob_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Neither can roles that do not exist.
+            TestCase::Pgwire {
+                user_to_auth_as: "carol",
+                user_reported_by_system: "carol",
+                password: Some("hunter2"),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+            // HTTP requests must supply credentials.
+            TestCase::Http {
+                user_to_auth_as: &*HTTP_DEFAULT_USER.name,
+                user_reported_by_system: &*HTTP_DEFAULT_USER.name,
+                scheme: Scheme::HTTP,
+                headers: &no_headers,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    )
+    .await;
+
+    // Changing the password takes effect for new connections.
+    sys_client
+        .batch_execute("ALTER ROLE alice PASSWORD 'hunter3'")
+        .await
+        .unwrap();
+    run_tests(
+        "PasswordAuth after ALTER ROLE",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("hunter3"),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some("hunter2"),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+        ],
+    )
+    .await;
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_client_cert() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let (billing_cert, billing_key) = ca.request_client_cert("billing.internal").unwrap();
+    let (other_cert, other_key) = ca.request_client_cert("other.internal").unwrap();
+
+    let ident_map = IdentMap::parse(r"/^(billing)\.internal$ \1").unwrap();
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_tls_client_auth(ca.ca_cert_path(), ident_map)
+        .start()
+        .await;
+
+    let with_cert = |cert: &PathBuf, key: &PathBuf| {
+        let cert = cert.clone();
+        let key = key.clone();
+        Box::new(move |b: &mut SslConnectorBuilder| {
+            b.set_verify(SslVerifyMode::NONE);
+            b.set_certificate_file(&cert, SslFiletype::PEM)?;
+            b.set_private_key_file(&key, SslFiletype::PEM)
+        })
+    };
+    let billing_header = make_header(Authorization::basic("billing", ""));
+    let other_user_header = make_header(Authorization::basic("materialize", ""));
+    let no_headers = HeaderMap::new();
+
+    run_tests(
+        "ClientCertAuth",
+        &server,
+        &[
+            // A certificate that maps to the requested user should succeed.
+            TestCase::Pgwire {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: with_cert(&billing_cert, &billing_key),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                scheme: Scheme::HTTPS,
+                headers: &billing_header,
+                configure: with_cert(&billing_cert, &billing_key),
+                assert: Assert::Success,
+            },
+            // Without a requested user, HTTP uses the role the certificate
+            // maps to.
+            TestCase::Http {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_cert(&billing_cert, &billing_key),
+                assert: Assert::Success,
+            },
+            // A certificate does not authenticate other users.
+            TestCase::Pgwire {
+                user_to_auth_as: "materialize",
+                user_reported_by_system: "materialize",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: with_cert(&billing_cert, &billing_key),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                    assert_eq!(
+                        err.message(),
+                        "certificate authentication failed for user \"materialize\""
+                    );
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "materialize",
+                user_reported_by_system: "materialize",
+                scheme: Scheme::HTTPS,
+                headers: &other_user_header,
+                configure: with_cert(&billing_cert, &billing_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Neither do certificates that do not map to any role.
+            TestCase::Pgwire {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: with_cert(&other_cert, &other_key),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: with_cert(&other_cert, &other_key),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // Clients without a certificate have no other way to authenticate.
+            TestCase::Pgwire {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "billing",
+                user_reported_by_system: "billing",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    )
+    .await;
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_oidc() {
+    let ca = Ca::new_root("test ca").unwrap();
+    let issuer = "https://idp.example.com/realms/materialize";
+
+    // Publish the CA's key as a JSON Web Key Set, as an OpenID Connect
+    // provider would.
+    let rsa = ca.pkey.rsa().unwrap();
+    let jwks = json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": "test-key",
+            "n": base64::encode_config(rsa.n().to_vec(), base64::URL_SAFE_NO_PAD),
+            "e": base64::encode_config(rsa.e().to_vec(), base64::URL_SAFE_NO_PAD),
+        }]
+    });
+    let oidc_auth = OidcAuthentication::new(OidcConfig {
+        issuer: issuer.into(),
+        jwks: JwksSource::Static(serde_json::from_value(jwks).unwrap()),
+        audience: "materialize".into(),
+        role_claim: "preferred_username".into(),
+        groups_claim: Some("groups".into()),
+        admin_group: Some("mzadmin".into()),
+        now: SYSTEM_TIME.clone(),
+    });
+
+    let encoding_key =
+        EncodingKey::from_rsa_pem(&ca.pkey.private_key_to_pem_pkcs8().unwrap()).unwrap();
+    let make_jwt = |claims: serde_json::Value| {
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = Some("test-key".into());
+        jsonwebtoken::encode(&header, &claims, &encoding_key).unwrap()
+    };
+    let exp = SYSTEM_TIME.as_secs() + 600;
+    let alice_jwt = make_jwt(json!({
+        "iss": issuer,
+        "aud": "materialize",
+        "exp": exp,
+        "sub": Uuid::new_v4().to_string(),
+        "preferred_username": "alice",
+        "groups": ["analysts"],
+    }));
+    let admin_jwt = make_jwt(json!({
+        "iss": issuer,
+        "aud": "materialize",
+        "exp": exp,
+        "sub": Uuid::new_v4().to_string(),
+        "preferred_username": "carol",
+        "groups": ["mzadmin"],
+    }));
+    let expired_jwt = make_jwt(json!({
+        "iss": issuer,
+        "aud": "materialize",
+        "exp": 0,
+        "preferred_username": "alice",
+    }));
+    let wrong_issuer_jwt = make_jwt(json!({
+        "iss": "https://evil.example.com",
+        "aud": "materialize",
+        "exp": exp,
+        "preferred_username": "alice",
+    }));
+
+    let server = test_util::TestHarness::default()
+        .with_oidc(&oidc_auth)
+        .start()
+        .await;
+
+    let sys_client = server
+        .connect()
+        .internal()
+        .user(&SYSTEM_USER.name)
+        .await
+        .unwrap();
+    sys_client
+        .batch_execute("CREATE ROLE analysts; CREATE ROLE auditors")
+        .await
+        .unwrap();
+
+    let alice_basic_header = make_header(Authorization::basic("alice", &alice_jwt));
+    let alice_bearer_header = make_header(Authorization::bearer(&alice_jwt).unwrap());
+    let expired_header = make_header(Authorization::bearer(&expired_jwt).unwrap());
+    let wrong_issuer_header = make_header(Authorization::bearer(&wrong_issuer_jwt).unwrap());
+    let no_headers = HeaderMap::new();
+
+    run_tests(
+        "OIDC",
+        &server,
+        &[
+            // A valid token for the requested user should succeed.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some(&alice_jwt),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::SuccessSuperuserCheck(false),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_basic_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_bearer_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            // Members of the admin group are superusers.
+            TestCase::Pgwire {
+                user_to_auth_as: "carol",
+                user_reported_by_system: "carol",
+                password: Some(&admin_jwt),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::SuccessSuperuserCheck(true),
+            },
+            // A token for another user should fail.
+            TestCase::Pgwire {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                password: Some(&alice_jwt),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                    assert_eq!(err.message(), "invalid password");
+                })),
+            },
+            // Expired tokens and tokens from other issuers should fail.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some(&expired_jwt),
+                ssl_mode: SslMode::Disable,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &expired_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &wrong_issuer_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            // A token is required.
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &no_headers,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    )
+    .await;
+
+    // Groups are synchronized to role memberships at login.
+    let membership_query = "SELECT r.name FROM mz_role_members m \
+        JOIN mz_roles r ON m.role_id = r.id \
+        JOIN mz_roles u ON m.member = u.id \
+        WHERE u.name = 'alice' ORDER BY r.name";
+    let memberships = |rows: Vec<tokio_postgres::Row>| {
+        rows.into_iter()
+            .map(|row| row.get::<_, String>(0))
+            .collect::<Vec<_>>()
+    };
+    let rows = sys_client.query(membership_query, &[]).await.unwrap();
+    assert_eq!(memberships(rows), vec!["analysts"]);
+
+    // Memberships in roles that the user's groups no longer name are revoked.
+    sys_client
+        .batch_execute("GRANT auditors TO alice")
+        .await
+        .unwrap();
+    let alice_client = server
+        .connect()
+        .user("alice")
+        .password(&alice_jwt)
+        .await
+        .unwrap();
+    let rows = sys_client.query(membership_query, &[]).await.unwrap();
+    assert_eq!(memberships(rows), vec!["analysts"]);
+    drop(alice_client);
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
 async fn test_auth_intermediate_ca_no_intermediary() {
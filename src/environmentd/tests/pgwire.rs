@@ -84,6 +84,7 @@ use std::time::Duration;
 
 use bytes::BytesMut;
 use fallible_iterator::FallibleIterator;
+use futures::StreamExt;
 use mz_adapter::session::DEFAULT_DATABASE_NAME;
 use mz_environmentd::test_util::{self, PostgresErrorExt};
 use mz_ore::collections::CollectionExt;
@@ -95,6 +96,7 @@ use postgres::types::Type;
 use postgres::SimpleQueryMessage;
 use postgres_array::{Array, Dimension};
 use tokio::sync::mpsc;
+use tokio_postgres::AsyncMessage;
 
 #[mz_ore::test]
 #[ignore]
@@ -206,6 +208,61 @@ fn test_read_many_rows() {
     assert_eq!(rows.len(), 3, "row len should be all values");
 }
 
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_read_large_result() {
+    let server = test_util::TestHarness::default().start_blocking();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    let mut observer = server.connect(postgres::NoTls).unwrap();
+    client.batch_execute("CREATE TABLE t (s text)").unwrap();
+    client
+        .batch_execute("INSERT INTO t SELECT repeat('x', 1024) FROM generate_series(1, 16384)")
+        .unwrap();
+
+    // The result is four times larger than the maximum result size, which it
+    // can only exceed by being streamed in bounded batches.
+    client
+        .batch_execute("SET max_query_result_size = '4MB'")
+        .unwrap();
+    let rows = client.query("SELECT s FROM t", &[]).unwrap();
+    assert_eq!(rows.len(), 16384);
+    assert!(rows.iter().all(|row| row.get::<_, String>(0).len() == 1024));
+
+    // A portal that has only returned its first row has not had the rest of
+    // its result produced, so its peek is still active.
+    let active_peeks = |observer: &mut postgres::Client| -> i64 {
+        observer
+            .query_one(
+                "SELECT count(*) FROM mz_internal.mz_active_peeks WHERE index_id LIKE 't%'",
+                &[],
+            )
+            .unwrap()
+            .get(0)
+    };
+    observer
+        .batch_execute("SET auto_route_introspection_queries = false")
+        .unwrap();
+    let mut trans = client.transaction().unwrap();
+    let portal = trans.bind("SELECT s FROM t", &[]).unwrap();
+    let rows = trans.query_portal(&portal, 1).unwrap();
+    assert_eq!(rows.len(), 1);
+    Retry::default()
+        .retry(|_| match active_peeks(&mut observer) {
+            1 => Ok(()),
+            n => Err(format!("expected 1 active peek, found {n}")),
+        })
+        .unwrap();
+
+    // Finishing the transaction stops the peek.
+    trans.commit().unwrap();
+    Retry::default()
+        .retry(|_| match active_peeks(&mut observer) {
+            0 => Ok(()),
+            n => Err(format!("expected no active peeks, found {n}")),
+        })
+        .unwrap();
+}
+
 #[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
 async fn test_conn_startup() {
     let server = test_util::TestHarness::default().start().await;
@@ -539,6 +596,110 @@ fn test_record_types() {
     assert_eq!(rows.len(), 2);
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+async fn test_listen() {
+    let server = test_util::TestHarness::default().start().await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a int, b text)")
+        .await
+        .unwrap();
+    client
+        .batch_execute("INSERT INTO t VALUES (1, 'old')")
+        .await
+        .unwrap();
+
+    // Drive the listening connection ourselves so that we observe its
+    // asynchronous messages.
+    let mut pg_config = server.connect().as_pg_config().clone();
+    pg_config.port(server.inner.sql_local_addr().port());
+    let (listener, mut conn) = pg_config.connect(postgres::NoTls).await.unwrap();
+    let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+    let (notice_tx, mut notice_rx) = mpsc::unbounded_channel();
+    mz_ore::task::spawn(|| "test_listen", async move {
+        let mut messages = futures::stream::poll_fn(|cx| conn.poll_message(cx));
+        while let Some(message) = messages.next().await {
+            match message.unwrap() {
+                AsyncMessage::Notification(n) => notification_tx.send(n).unwrap(),
+                AsyncMessage::Notice(notice) => notice_tx.send(notice).unwrap(),
+                _ => {}
+            }
+        }
+    });
+
+    listener.batch_execute("LISTEN t").await.unwrap();
+    client
+        .batch_execute("INSERT INTO t VALUES (2, 'new')")
+        .await
+        .unwrap();
+
+    // Only changes after `LISTEN` are delivered.
+    let n = notification_rx.recv().await.unwrap();
+    assert_eq!(n.channel(), "t");
+    let payload: serde_json::Value = serde_json::from_str(n.payload()).unwrap();
+    assert_eq!(payload["mz_diff"], 1);
+    assert_eq!(payload["a"], "2");
+    assert_eq!(payload["b"], "new");
+    assert!(payload["mz_timestamp"].is_string());
+
+    // Notifications are held back while the listener is in a transaction.
+    listener.batch_execute("BEGIN").await.unwrap();
+    client
+        .batch_execute("DELETE FROM t WHERE a = 2")
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(notification_rx.try_recv().is_err());
+    listener.batch_execute("COMMIT").await.unwrap();
+    let n = notification_rx.recv().await.unwrap();
+    let payload: serde_json::Value = serde_json::from_str(n.payload()).unwrap();
+    assert_eq!(payload["mz_diff"], -1);
+    assert_eq!(payload["a"], "2");
+
+    // Notifications whose payload is too long are discarded with a notice.
+    client
+        .batch_execute("INSERT INTO t VALUES (4, repeat('x', 8000))")
+        .await
+        .unwrap();
+    let notice = notice_rx.recv().await.unwrap();
+    assert_eq!(
+        notice.message(),
+        "discarded 1 notifications on channel t because their payload exceeded 8000 bytes"
+    );
+    assert!(notification_rx.try_recv().is_err());
+
+    // Notifications that pile up beyond the limit are discarded with a notice
+    // once the listener catches up.
+    listener.batch_execute("BEGIN").await.unwrap();
+    client
+        .batch_execute("INSERT INTO t SELECT 5, 'many' FROM generate_series(1, 10010)")
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    listener.batch_execute("COMMIT").await.unwrap();
+    for _ in 0..10_000 {
+        let n = notification_rx.recv().await.unwrap();
+        let payload: serde_json::Value = serde_json::from_str(n.payload()).unwrap();
+        assert_eq!(payload["a"], "5");
+    }
+    let notice = notice_rx.recv().await.unwrap();
+    assert_eq!(
+        notice.message(),
+        "discarded 10 notifications on channel t because the client did not keep up"
+    );
+    assert!(notification_rx.try_recv().is_err());
+
+    // No more notifications are delivered after `UNLISTEN`.
+    listener.batch_execute("UNLISTEN t").await.unwrap();
+    client
+        .batch_execute("INSERT INTO t VALUES (3, 'ignored')")
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    listener.batch_execute("SELECT 1").await.unwrap();
+    assert!(notification_rx.try_recv().is_err());
+}
+
 fn pg_test_inner(dir: PathBuf, flags: &[&'static str]) {
     // We want a new server per file, so we can't use pgtest::walk.
     datadriven::walk(dir.to_str().unwrap(), |tf| {
@@ -79,7 +79,7 @@
 
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::fmt::Write;
-use std::io::Write as _;
+use std::io::{Read, Write as _};
 use std::net::Ipv4Addr;
 use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicBool, AtomicUsize};
@@ -111,7 +111,8 @@ use mz_sql_parser::ast::display::AstDisplay;
 use postgres_array::Array;
 use rand::RngCore;
 use reqwest::blocking::Client;
-use reqwest::{header::CONTENT_TYPE, Url};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, TRANSFER_ENCODING};
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use tempfile::TempDir;
 use tokio_postgres::error::SqlState;
@@ -1522,6 +1523,57 @@ fn test_default_cluster_sizes() {
     assert_eq!(builtin_size, "2");
 }
 
+#[mz_ore::test]
+#[cfg_attr(miri, ignore)] // too slow
+fn test_http_large_result() {
+    let server = test_util::TestHarness::default().start_blocking();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    client.batch_execute("CREATE TABLE t (s text)").unwrap();
+    client
+        .batch_execute("INSERT INTO t SELECT repeat('x', 1024) FROM generate_series(1, 16384)")
+        .unwrap();
+
+    let http_url = Url::parse(&format!(
+        "http://{}/api/sql",
+        server.inner().http_local_addr()
+    ))
+    .unwrap();
+    // The result is four times larger than the maximum result size, which it
+    // can only exceed by being streamed in bounded batches.
+    let json = serde_json::json!({
+        "queries": [
+            {"query": "SET max_query_result_size = '4MB'"},
+            {"query": "SELECT s FROM t"},
+        ]
+    });
+    let mut res = Client::new().post(http_url).json(&json).send().unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    // The response is streamed as it is produced, so its length is not known
+    // up front.
+    assert_eq!(
+        res.headers().get(TRANSFER_ENCODING).unwrap(),
+        "chunked",
+        "{res:?}"
+    );
+    assert!(res.headers().get(CONTENT_LENGTH).is_none(), "{res:?}");
+    let mut first = [0; 12];
+    res.read_exact(&mut first).unwrap();
+    assert_eq!(&first, br#"{"results":["#);
+
+    let mut body = first.to_vec();
+    res.read_to_end(&mut body).unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[1]["tag"], "SELECT 16384");
+    let rows = results[1]["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 16384);
+    assert!(rows
+        .iter()
+        .all(|row| row[0].as_str().map(|s| s.len()) == Some(1024)));
+}
+
 #[mz_ore::test]
 fn test_max_request_size() {
     let statement = "SELECT $1::text";
@@ -1873,6 +1925,100 @@ fn test_http_options_param() {
         .contains(r#"startup setting not_a_session_var not set"#));
 }
 
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // too slow
+async fn test_http_subscribe_sse() {
+    /// A server-sent event.
+    #[derive(Debug, Default)]
+    struct Event {
+        event: String,
+        id: Option<String>,
+        data: String,
+    }
+
+    /// Reads events from `resp` until one of type `event` is received.
+    async fn next_event(resp: &mut reqwest::Response, buf: &mut String, event: &str) -> Event {
+        loop {
+            while let Some(end) = buf.find("\n\n") {
+                let raw: String = buf.drain(..end + 2).collect();
+                let mut next = Event::default();
+                for line in raw.lines() {
+                    match line.split_once(':') {
+                        Some(("event", value)) => next.event = value.trim_start().into(),
+                        Some(("id", value)) => next.id = Some(value.trim_start().into()),
+                        Some(("data", value)) => next.data = value.trim_start().into(),
+                        _ => {}
+                    }
+                }
+                if next.event == event {
+                    return next;
+                }
+            }
+            let chunk = resp.chunk().await.unwrap().expect("stream ended");
+            buf.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    let server = test_util::TestHarness::default().start().await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute("CREATE TABLE t (a int); INSERT INTO t VALUES (1)")
+        .await
+        .unwrap();
+
+    let url = Url::parse(&format!(
+        "http://{}/api/experimental/subscribe",
+        server.inner.http_local_addr()
+    ))
+    .unwrap();
+    let http_client = reqwest::Client::new();
+
+    // Only a single `SUBSCRIBE` is supported.
+    let resp = http_client
+        .get(url.clone())
+        .query(&[("query", "SELECT 1")])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // The snapshot is delivered, followed by a progress event with an ID.
+    let mut resp = http_client
+        .get(url.clone())
+        .query(&[("query", "SUBSCRIBE t")])
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+    let mut buf = String::new();
+    let updates = next_event(&mut resp, &mut buf, "updates").await;
+    let updates: Vec<Vec<serde_json::Value>> = serde_json::from_str(&updates.data).unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].last().unwrap(), "1");
+    let progress = next_event(&mut resp, &mut buf, "progress").await;
+    let id = progress.id.expect("progress after snapshot has an ID");
+    drop(resp);
+
+    // Resuming from the ID only delivers updates that happened since.
+    client
+        .batch_execute("INSERT INTO t VALUES (2)")
+        .await
+        .unwrap();
+    let mut resp = http_client
+        .get(url)
+        .query(&[("query", "SUBSCRIBE t")])
+        .header("Last-Event-ID", id)
+        .send()
+        .await
+        .unwrap();
+    assert!(resp.status().is_success());
+    let mut buf = String::new();
+    let updates = next_event(&mut resp, &mut buf, "updates").await;
+    let updates: Vec<Vec<serde_json::Value>> = serde_json::from_str(&updates.data).unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].last().unwrap(), "2");
+}
+
 #[mz_ore::test]
 #[cfg_attr(miri, ignore)] // too slow
 fn test_max_connections_on_all_interfaces() {
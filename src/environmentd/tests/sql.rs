@@ -2756,10 +2756,17 @@ fn test_mz_sessions() {
         1,
     );
     let foo_session_row = foo_client
-        .query_one("SELECT id::int8, role_id FROM mz_internal.mz_sessions", &[])
+        .query_one(
+            "SELECT id::int8, role_id, client_ip FROM mz_internal.mz_sessions",
+            &[],
+        )
         .unwrap();
     let foo_conn_id = foo_session_row.get::<_, i64>("id");
     let foo_role_id = foo_session_row.get::<_, String>("role_id");
+    assert_eq!(
+        foo_session_row.get::<_, Option<String>>("client_ip"),
+        Some("127.0.0.1".into()),
+    );
     assert_eq!(
         foo_client
             .query_one("SELECT name FROM mz_roles WHERE id = $1", &[&foo_role_id])
@@ -90,6 +90,7 @@ pub enum UnmaterializableFunc {
     CurrentTimestamp,
     CurrentUser,
     IsRbacEnabled,
+    MzBypassesRls,
     MzEnvironmentId,
     MzIsSuperuser,
     MzNow,
@@ -128,6 +129,7 @@ impl UnmaterializableFunc {
             }
             UnmaterializableFunc::CurrentUser => ScalarType::String.nullable(false),
             UnmaterializableFunc::IsRbacEnabled => ScalarType::Bool.nullable(false),
+            UnmaterializableFunc::MzBypassesRls => ScalarType::Bool.nullable(false),
             UnmaterializableFunc::MzEnvironmentId => ScalarType::String.nullable(false),
             UnmaterializableFunc::MzIsSuperuser => ScalarType::Bool.nullable(false),
             UnmaterializableFunc::MzNow => ScalarType::MzTimestamp.nullable(false),
@@ -167,6 +169,7 @@ impl fmt::Display for UnmaterializableFunc {
             UnmaterializableFunc::CurrentTimestamp => f.write_str("current_timestamp"),
             UnmaterializableFunc::CurrentUser => f.write_str("current_user"),
             UnmaterializableFunc::IsRbacEnabled => f.write_str("is_rbac_enabled"),
+            UnmaterializableFunc::MzBypassesRls => f.write_str("mz_bypasses_rls"),
             UnmaterializableFunc::MzEnvironmentId => f.write_str("mz_environment_id"),
             UnmaterializableFunc::MzIsSuperuser => f.write_str("mz_is_superuser"),
             UnmaterializableFunc::MzNow => f.write_str("mz_now"),
@@ -196,6 +199,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
             UnmaterializableFunc::CurrentTimestamp => CurrentTimestamp(()),
             UnmaterializableFunc::CurrentUser => CurrentUser(()),
             UnmaterializableFunc::IsRbacEnabled => IsRbacEnabled(()),
+            UnmaterializableFunc::MzBypassesRls => MzBypassesRls(()),
             UnmaterializableFunc::MzEnvironmentId => MzEnvironmentId(()),
             UnmaterializableFunc::MzIsSuperuser => MzIsSuperuser(()),
             UnmaterializableFunc::MzNow => MzNow(()),
@@ -226,6 +230,7 @@ impl RustType<ProtoUnmaterializableFunc> for UnmaterializableFunc {
                 CurrentSetting(()) => Ok(UnmaterializableFunc::ViewableVariables),
                 CurrentUser(()) => Ok(UnmaterializableFunc::CurrentUser),
                 IsRbacEnabled(()) => Ok(UnmaterializableFunc::IsRbacEnabled),
+                MzBypassesRls(()) => Ok(UnmaterializableFunc::MzBypassesRls),
                 MzEnvironmentId(()) => Ok(UnmaterializableFunc::MzEnvironmentId),
                 MzIsSuperuser(()) => Ok(UnmaterializableFunc::MzIsSuperuser),
                 MzNow(()) => Ok(UnmaterializableFunc::MzNow),
@@ -1983,6 +1988,27 @@ fn mz_acl_item_contains_privilege(a: Datum<'_>, b: Datum<'_>) -> Result<Datum<'s
     Ok(contains.into())
 }
 
+fn inet_subnet<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    b.unwrap_inet().contains(&a.unwrap_inet()).into()
+}
+
+fn inet_subnet_eq<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    b.unwrap_inet().contains_or_equals(&a.unwrap_inet()).into()
+}
+
+fn inet_overlaps<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    a.unwrap_inet().overlaps(&b.unwrap_inet()).into()
+}
+
+fn inet_set_masklen<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let inet = a.unwrap_inet();
+    let masklen = b.unwrap_int32();
+    let invalid = || EvalError::InvalidParameterValue(format!("invalid mask length: {}", masklen));
+    let masklen = u8::try_from(masklen).map_err(|_| invalid())?;
+    let inet = inet.with_masklen(masklen).map_err(|_| invalid())?;
+    Ok(Datum::Inet(inet))
+}
+
 // transliterated from postgres/src/backend/utils/adt/misc.c
 fn parse_ident<'a>(
     a: Datum<'a>,
@@ -2339,6 +2365,12 @@ pub enum BinaryFunc {
     MzAclItemContainsPrivilege,
     ParseIdent,
     PrettySql,
+    InetSubnet,
+    InetSubnetEq,
+    InetSupernet,
+    InetSupernetEq,
+    InetOverlaps,
+    InetSetMasklen,
 }
 
 impl BinaryFunc {
@@ -2604,6 +2636,12 @@ impl BinaryFunc {
             BinaryFunc::MzAclItemContainsPrivilege => mz_acl_item_contains_privilege(a, b),
             BinaryFunc::ParseIdent => parse_ident(a, b, temp_storage),
             BinaryFunc::PrettySql => pretty_sql(a, b, temp_storage),
+            BinaryFunc::InetSubnet => Ok(inet_subnet(a, b)),
+            BinaryFunc::InetSubnetEq => Ok(inet_subnet_eq(a, b)),
+            BinaryFunc::InetSupernet => Ok(inet_subnet(b, a)),
+            BinaryFunc::InetSupernetEq => Ok(inet_subnet_eq(b, a)),
+            BinaryFunc::InetOverlaps => Ok(inet_overlaps(a, b)),
+            BinaryFunc::InetSetMasklen => inet_set_masklen(a, b),
         }
     }
 
@@ -2792,6 +2830,11 @@ impl BinaryFunc {
 
             ParseIdent => ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable),
             PrettySql => ScalarType::String.nullable(in_nullable),
+
+            InetSubnet | InetSubnetEq | InetSupernet | InetSupernetEq | InetOverlaps => {
+                ScalarType::Bool.nullable(in_nullable)
+            }
+            InetSetMasklen => ScalarType::Inet.nullable(in_nullable),
         }
     }
 
@@ -2992,7 +3035,13 @@ impl BinaryFunc {
             | UuidGenerateV5
             | MzAclItemContainsPrivilege
             | ParseIdent
-            | PrettySql => false,
+            | PrettySql
+            | InetSubnet
+            | InetSubnetEq
+            | InetSupernet
+            | InetSupernetEq
+            | InetOverlaps
+            | InetSetMasklen => false,
 
             JsonbGetInt64 { .. }
             | JsonbGetString { .. }
@@ -3143,7 +3192,12 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | InetSubnet
+            | InetSubnetEq
+            | InetSupernet
+            | InetSupernetEq
+            | InetOverlaps => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | AgeTimestamp
@@ -3196,7 +3250,8 @@ impl BinaryFunc {
             | ConstantTimeEqBytes
             | ConstantTimeEqString
             | ParseIdent
-            | PrettySql => false,
+            | PrettySql
+            | InetSetMasklen => false,
         }
     }
 
@@ -3420,6 +3475,12 @@ impl BinaryFunc {
             BinaryFunc::ParseIdent => (false, false),
             BinaryFunc::ConstantTimeEqBytes | BinaryFunc::ConstantTimeEqString => (false, false),
             BinaryFunc::PrettySql => (false, false),
+            BinaryFunc::InetSubnet
+            | BinaryFunc::InetSubnetEq
+            | BinaryFunc::InetSupernet
+            | BinaryFunc::InetSupernetEq
+            | BinaryFunc::InetOverlaps
+            | BinaryFunc::InetSetMasklen => (false, false),
         }
     }
 }
@@ -3627,6 +3688,12 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::MzAclItemContainsPrivilege => f.write_str("mz_aclitem_contains_privilege"),
             BinaryFunc::ParseIdent => f.write_str("parse_ident"),
             BinaryFunc::PrettySql => f.write_str("pretty_sql"),
+            BinaryFunc::InetSubnet => f.write_str("<<"),
+            BinaryFunc::InetSubnetEq => f.write_str("<<="),
+            BinaryFunc::InetSupernet => f.write_str(">>"),
+            BinaryFunc::InetSupernetEq => f.write_str(">>="),
+            BinaryFunc::InetOverlaps => f.write_str("&&"),
+            BinaryFunc::InetSetMasklen => f.write_str("set_masklen"),
         }
     }
 }
@@ -3840,6 +3907,12 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::RangeIntersection).boxed(),
             Just(BinaryFunc::RangeDifference).boxed(),
             Just(BinaryFunc::ParseIdent).boxed(),
+            Just(BinaryFunc::InetSubnet).boxed(),
+            Just(BinaryFunc::InetSubnetEq).boxed(),
+            Just(BinaryFunc::InetSupernet).boxed(),
+            Just(BinaryFunc::InetSupernetEq).boxed(),
+            Just(BinaryFunc::InetOverlaps).boxed(),
+            Just(BinaryFunc::InetSetMasklen).boxed(),
         ])
     }
 }
@@ -4039,6 +4112,12 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::ConstantTimeEqBytes => ConstantTimeEqBytes(()),
             BinaryFunc::ConstantTimeEqString => ConstantTimeEqString(()),
             BinaryFunc::PrettySql => PrettySql(()),
+            BinaryFunc::InetSubnet => InetSubnet(()),
+            BinaryFunc::InetSubnetEq => InetSubnetEq(()),
+            BinaryFunc::InetSupernet => InetSupernet(()),
+            BinaryFunc::InetSupernetEq => InetSupernetEq(()),
+            BinaryFunc::InetOverlaps => InetOverlaps(()),
+            BinaryFunc::InetSetMasklen => InetSetMasklen(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -4242,6 +4321,12 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 ConstantTimeEqBytes(()) => Ok(BinaryFunc::ConstantTimeEqBytes),
                 ConstantTimeEqString(()) => Ok(BinaryFunc::ConstantTimeEqString),
                 PrettySql(()) => Ok(BinaryFunc::PrettySql),
+                InetSubnet(()) => Ok(BinaryFunc::InetSubnet),
+                InetSubnetEq(()) => Ok(BinaryFunc::InetSubnetEq),
+                InetSupernet(()) => Ok(BinaryFunc::InetSupernet),
+                InetSupernetEq(()) => Ok(BinaryFunc::InetSupernetEq),
+                InetOverlaps(()) => Ok(BinaryFunc::InetOverlaps),
+                InetSetMasklen(()) => Ok(BinaryFunc::InetSetMasklen),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -4707,7 +4792,24 @@ derive_unary!(
     AclItemPrivileges,
     QuoteIdent,
     TryParseMonotonicIso8601Timestamp,
-    RegexpSplitToArray
+    RegexpSplitToArray,
+    CastStringToInet,
+    CastStringToCidr,
+    CastStringToMacAddr,
+    CastInetToString,
+    CastCidrToString,
+    CastMacAddrToString,
+    CastInetToCidr,
+    CastCidrToInet,
+    InetHost,
+    InetMasklen,
+    InetFamily,
+    InetNetmask,
+    InetBroadcast,
+    InetNetwork,
+    MacAddrTrunc,
+    CastStringToEnum,
+    CastEnumToString
 );
 
 impl UnaryFunc {
@@ -5119,6 +5221,23 @@ impl Arbitrary for UnaryFunc {
             AclItemGrantee::arbitrary().prop_map_into().boxed(),
             AclItemPrivileges::arbitrary().prop_map_into().boxed(),
             QuoteIdent::arbitrary().prop_map_into().boxed(),
+            CastStringToInet::arbitrary().prop_map_into().boxed(),
+            CastStringToCidr::arbitrary().prop_map_into().boxed(),
+            CastStringToMacAddr::arbitrary().prop_map_into().boxed(),
+            CastInetToString::arbitrary().prop_map_into().boxed(),
+            CastCidrToString::arbitrary().prop_map_into().boxed(),
+            CastMacAddrToString::arbitrary().prop_map_into().boxed(),
+            CastInetToCidr::arbitrary().prop_map_into().boxed(),
+            CastCidrToInet::arbitrary().prop_map_into().boxed(),
+            InetHost::arbitrary().prop_map_into().boxed(),
+            InetMasklen::arbitrary().prop_map_into().boxed(),
+            InetFamily::arbitrary().prop_map_into().boxed(),
+            InetNetmask::arbitrary().prop_map_into().boxed(),
+            InetBroadcast::arbitrary().prop_map_into().boxed(),
+            InetNetwork::arbitrary().prop_map_into().boxed(),
+            MacAddrTrunc::arbitrary().prop_map_into().boxed(),
+            CastStringToEnum::arbitrary().prop_map_into().boxed(),
+            CastEnumToString::arbitrary().prop_map_into().boxed(),
         ])
     }
 }
@@ -5507,6 +5626,23 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::TryParseMonotonicIso8601Timestamp(_) => {
                 TryParseMonotonicIso8601Timestamp(())
             }
+            UnaryFunc::CastStringToInet(_) => CastStringToInet(()),
+            UnaryFunc::CastStringToCidr(_) => CastStringToCidr(()),
+            UnaryFunc::CastStringToMacAddr(_) => CastStringToMacAddr(()),
+            UnaryFunc::CastInetToString(_) => CastInetToString(()),
+            UnaryFunc::CastCidrToString(_) => CastCidrToString(()),
+            UnaryFunc::CastMacAddrToString(_) => CastMacAddrToString(()),
+            UnaryFunc::CastInetToCidr(_) => CastInetToCidr(()),
+            UnaryFunc::CastCidrToInet(_) => CastCidrToInet(()),
+            UnaryFunc::InetHost(_) => InetHost(()),
+            UnaryFunc::InetMasklen(_) => InetMasklen(()),
+            UnaryFunc::InetFamily(_) => InetFamily(()),
+            UnaryFunc::InetNetmask(_) => InetNetmask(()),
+            UnaryFunc::InetBroadcast(_) => InetBroadcast(()),
+            UnaryFunc::InetNetwork(_) => InetNetwork(()),
+            UnaryFunc::MacAddrTrunc(_) => MacAddrTrunc(()),
+            UnaryFunc::CastStringToEnum(func) => CastStringToEnum(func.return_ty.into_proto()),
+            UnaryFunc::CastEnumToString(func) => CastEnumToString(func.ty.into_proto()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -5967,6 +6103,29 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 TryParseMonotonicIso8601Timestamp(_) => {
                     Ok(impls::TryParseMonotonicIso8601Timestamp.into())
                 }
+                CastStringToInet(_) => Ok(impls::CastStringToInet.into()),
+                CastStringToCidr(_) => Ok(impls::CastStringToCidr.into()),
+                CastStringToMacAddr(_) => Ok(impls::CastStringToMacAddr.into()),
+                CastInetToString(_) => Ok(impls::CastInetToString.into()),
+                CastCidrToString(_) => Ok(impls::CastCidrToString.into()),
+                CastMacAddrToString(_) => Ok(impls::CastMacAddrToString.into()),
+                CastInetToCidr(_) => Ok(impls::CastInetToCidr.into()),
+                CastCidrToInet(_) => Ok(impls::CastCidrToInet.into()),
+                InetHost(_) => Ok(impls::InetHost.into()),
+                InetMasklen(_) => Ok(impls::InetMasklen.into()),
+                InetFamily(_) => Ok(impls::InetFamily.into()),
+                InetNetmask(_) => Ok(impls::InetNetmask.into()),
+                InetBroadcast(_) => Ok(impls::InetBroadcast.into()),
+                InetNetwork(_) => Ok(impls::InetNetwork.into()),
+                MacAddrTrunc(_) => Ok(impls::MacAddrTrunc.into()),
+                CastStringToEnum(return_ty) => Ok(impls::CastStringToEnum {
+                    return_ty: return_ty.into_rust()?,
+                }
+                .into()),
+                CastEnumToString(ty) => Ok(impls::CastEnumToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
@@ -6616,6 +6775,13 @@ where
             None => Ok::<_, EvalError>(buf.write_null()),
         }),
         MzAclItem => Ok(strconv::format_mz_acl_item(buf, d.unwrap_mz_acl_item())),
+        Inet => Ok(strconv::format_inet(buf, d.unwrap_inet())),
+        Cidr => Ok(strconv::format_cidr(buf, d.unwrap_inet())),
+        MacAddr => Ok(strconv::format_mac_addr(buf, d.unwrap_mac_addr())),
+        Enum { labels, .. } => Ok(strconv::format_string(
+            buf,
+            &labels[usize::cast_from(d.unwrap_uint32())],
+        )),
     }
 }
 
@@ -13,8 +13,10 @@ mod byte;
 mod char;
 mod date;
 mod datum;
+mod enum_type;
 mod float32;
 mod float64;
+mod inet;
 mod int16;
 mod int2vector;
 mod int32;
@@ -46,8 +48,10 @@ pub use crate::scalar::func::impls::byte::*;
 pub use crate::scalar::func::impls::char::*;
 pub use crate::scalar::func::impls::date::*;
 pub use crate::scalar::func::impls::datum::*;
+pub use crate::scalar::func::impls::enum_type::*;
 pub use crate::scalar::func::impls::float32::*;
 pub use crate::scalar::func::impls::float64::*;
+pub use crate::scalar::func::impls::inet::*;
 pub use crate::scalar::func::impls::int16::*;
 pub use crate::scalar::func::impls::int2vector::*;
 pub use crate::scalar::func::impls::int32::*;
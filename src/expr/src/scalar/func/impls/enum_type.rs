@@ -0,0 +1,101 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
+use mz_repr::{ColumnType, ScalarType};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::EagerUnaryFunc;
+use crate::EvalError;
+
+/// Returns the labels of a [`ScalarType::Enum`].
+fn enum_labels(ty: &ScalarType) -> &[String] {
+    match ty {
+        ScalarType::Enum { labels, .. } => labels,
+        _ => unreachable!("enum casts require an enum type, but got {:?}", ty),
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToEnum {
+    pub return_ty: ScalarType,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToEnum {
+    type Input = &'a str;
+    type Output = Result<u32, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<u32, EvalError> {
+        let ordinal = enum_labels(&self.return_ty)
+            .iter()
+            .position(|label| label == a)
+            .ok_or_else(|| {
+                EvalError::InvalidParameterValue(format!("invalid input value for enum: \"{}\"", a))
+            })?;
+        Ok(u32::try_from(ordinal).expect("enum ordinal fits in a u32"))
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        self.return_ty.clone().nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastEnumToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastStringToEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("text_to_enum")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastEnumToString {
+    pub ty: ScalarType,
+}
+
+impl<'a> EagerUnaryFunc<'a> for CastEnumToString {
+    type Input = u32;
+    type Output = String;
+
+    fn call(&self, a: u32) -> String {
+        enum_labels(&self.ty)[usize::cast_from(a)].clone()
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastStringToEnum {
+            return_ty: self.ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastEnumToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("enum_to_text")
+    }
+}
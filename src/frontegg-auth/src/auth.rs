@@ -12,8 +12,9 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use anyhow::Context;
+use async_trait::async_trait;
 use derivative::Derivative;
-use futures::future::BoxFuture;
+use futures::future::{self, BoxFuture, FutureExt};
 use jsonwebtoken::{Algorithm, DecodingKey, Validation};
 use mz_ore::cast::{CastLossy, ReinterpretCast};
 use mz_ore::collections::HashMap;
@@ -25,7 +26,10 @@ use tokio::sync::{oneshot, watch};
 use uuid::Uuid;
 
 use crate::metrics::Metrics;
-use crate::{ApiTokenArgs, ApiTokenResponse, AppPassword, Client, Error, FronteggCliArgs};
+use crate::{
+    ApiTokenArgs, ApiTokenResponse, AppPassword, AuthenticatedUser, AuthenticationProvider, Client,
+    Error, FronteggCliArgs,
+};
 
 pub const REFRESH_SUFFIX: &str = "/token/refresh";
 
@@ -242,10 +246,7 @@ impl Authentication {
                         };
 
                         // Spawn a task to continuously refresh our token.
-                        let external_metadata = ExternalUserMetadata {
-                            user_id: claims.user_id,
-                            admin: claims.is_admin,
-                        };
+                        let external_metadata = claims.external_metadata();
                         let (refresh_tx, refresh_rx) = watch::channel(external_metadata);
                         let id = continuously_refresh(
                             &url,
@@ -318,6 +319,41 @@ impl Authentication {
     }
 }
 
+#[async_trait]
+impl AuthenticationProvider for Authentication {
+    async fn authenticate_password(
+        &self,
+        user: String,
+        password: &str,
+    ) -> Result<AuthenticatedUser, Error> {
+        let ExchangePasswordForTokenResponse {
+            claims,
+            refresh_updates,
+            valid_until_fut,
+        } = self.exchange_password_for_token(password, user).await?;
+        Ok(AuthenticatedUser {
+            metadata: claims.external_metadata(),
+            // It's important that the username come from the claims, as
+            // Frontegg may return an email address with different casing than
+            // the user supplied. We want to use the Frontegg casing as
+            // canonical.
+            name: claims.email,
+            refresh_updates: Some(refresh_updates),
+            valid_until_fut,
+        })
+    }
+
+    async fn authenticate_token(&self, token: &str) -> Result<AuthenticatedUser, Error> {
+        let claims = self.validate_access_token(token, None)?;
+        Ok(AuthenticatedUser {
+            metadata: claims.external_metadata(),
+            name: claims.email,
+            refresh_updates: None,
+            valid_until_fut: future::pending().boxed(),
+        })
+    }
+}
+
 #[derive(Clone, Derivative)]
 #[derivative(Debug)]
 pub struct ValidationConfig {
@@ -440,10 +476,7 @@ fn continuously_refresh(
             let valid_for = valid_for(&validation_config.now, &claims);
 
             // Notify listeners that we've refreshed.
-            let metadata = ExternalUserMetadata {
-                user_id: claims.user_id,
-                admin: claims.is_admin,
-            };
+            let metadata = claims.external_metadata();
             if let Err(_) = refresh_tx.send(metadata) {
                 tracing::info!(?id, "All listeners disappeared");
                 break;
@@ -561,3 +594,14 @@ pub struct ValidatedClaims {
     // Prevent construction outside of `Authentication::validate_access_token`.
     _private: (),
 }
+
+impl ValidatedClaims {
+    /// Returns the [`ExternalUserMetadata`] described by these claims.
+    pub fn external_metadata(&self) -> ExternalUserMetadata {
+        ExternalUserMetadata {
+            user_id: self.user_id,
+            admin: self.is_admin,
+            groups: None,
+        }
+    }
+}
@@ -24,10 +24,18 @@ pub enum Error {
     MiddlewareError(Arc<anyhow::Error>),
     #[error("authentication token expired")]
     TokenExpired,
+    #[error("authentication token not yet valid")]
+    TokenNotYetValid,
     #[error("unauthorized organization")]
     UnauthorizedTenant,
     #[error("email in access token did not match the expected email")]
     WrongEmail,
+    #[error("user in access token did not match the expected user")]
+    WrongUser,
+    #[error("access token is missing claim {0} or it has an unexpected type")]
+    InvalidClaim(String),
+    #[error("access token is signed by an unknown key")]
+    UnknownSigningKey,
     #[error("request timeout")]
     Timeout(Arc<tokio::time::error::Elapsed>),
     #[error("internal error")]
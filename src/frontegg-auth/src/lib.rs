@@ -80,6 +80,8 @@ mod auth;
 mod client;
 mod error;
 mod metrics;
+mod oidc;
+mod provider;
 
 use std::path::PathBuf;
 
@@ -90,6 +92,8 @@ pub use auth::{
 pub use client::tokens::{ApiTokenArgs, ApiTokenResponse, RefreshToken};
 pub use client::Client;
 pub use error::Error;
+pub use oidc::{JwksSource, OidcAuthentication, OidcCliArgs, OidcConfig};
+pub use provider::{AuthenticatedUser, AuthenticationProvider};
 use uuid::Uuid;
 
 pub use crate::app_password::{AppPassword, AppPasswordParseError};
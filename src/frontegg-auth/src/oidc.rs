@@ -0,0 +1,515 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Authentication with JWTs issued by a generic OpenID Connect provider, like
+//! Keycloak.
+//!
+//! Unlike Frontegg, an OpenID Connect provider is not asked to exchange
+//! credentials for tokens. Clients obtain an access token from the provider
+//! themselves and present it to Materialize, either as the password or as an
+//! HTTP bearer token. Materialize validates the token's signature against the
+//! provider's JSON Web Key Set and reads the role name and, optionally, group
+//! memberships from its claims.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use futures::future::{self, FutureExt};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use mz_ore::now::NowFn;
+use mz_repr::user::ExternalUserMetadata;
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{AuthenticatedUser, AuthenticationProvider, Error};
+
+/// The signature algorithms accepted in JWTs.
+///
+/// Symmetric algorithms are deliberately excluded, as a JSON Web Key Set only
+/// publishes public keys.
+const ALLOWED_ALGORITHMS: &[Algorithm] = &[
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+    Algorithm::EdDSA,
+];
+
+/// The minimum time between fetches of a remote JSON Web Key Set, to avoid
+/// hammering the provider with tokens signed by unknown keys.
+const MIN_JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The tolerated clock skew, in seconds, between Materialize and the provider
+/// when validating the `exp` and `nbf` claims of JWTs.
+const CLOCK_SKEW_LEEWAY_SECS: i64 = 60;
+
+/// Command line arguments for OpenID Connect authentication.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct OidcCliArgs {
+    /// Enables authentication with JWTs issued by the specified OpenID Connect
+    /// issuer.
+    ///
+    /// Clients supply an access token from the issuer as their password, or
+    /// as a bearer token over HTTP. Requires `--oidc-audience`.
+    #[clap(
+        long,
+        env = "OIDC_ISSUER",
+        conflicts_with = "frontegg-tenant",
+        requires = "oidc-audience",
+        value_name = "URL"
+    )]
+    oidc_issuer: Option<String>,
+    /// URL of the JSON Web Key Set used to validate JWTs.
+    ///
+    /// The key set is refetched when a JWT is signed by an unknown key.
+    #[clap(
+        long,
+        env = "OIDC_JWKS_URL",
+        requires = "oidc-issuer",
+        conflicts_with = "oidc-jwks-file",
+        value_name = "URL"
+    )]
+    oidc_jwks_url: Option<String>,
+    /// Path to the JSON Web Key Set used to validate JWTs.
+    #[clap(
+        long,
+        env = "OIDC_JWKS_FILE",
+        requires = "oidc-issuer",
+        value_name = "PATH"
+    )]
+    oidc_jwks_file: Option<PathBuf>,
+    /// The audience that JWTs must be issued for.
+    ///
+    /// Providers issue tokens for all of their clients with the same keys, so
+    /// without an audience a token issued for any other application would be
+    /// accepted.
+    #[clap(long, env = "OIDC_AUDIENCE", requires = "oidc-issuer")]
+    oidc_audience: Option<String>,
+    /// The JWT claim that contains the name of the role to log in as.
+    #[clap(long, env = "OIDC_ROLE_CLAIM", default_value = "preferred_username")]
+    oidc_role_claim: String,
+    /// The JWT claim that lists the groups the user belongs to.
+    ///
+    /// If set, the user's role memberships are synchronized at login: the
+    /// user is granted membership in each existing role named by a group, and
+    /// loses membership in all other roles.
+    #[clap(long, env = "OIDC_GROUPS_CLAIM", requires = "oidc-issuer")]
+    oidc_groups_claim: Option<String>,
+    /// The group whose members are superusers.
+    #[clap(long, env = "OIDC_ADMIN_GROUP", requires = "oidc-groups-claim")]
+    oidc_admin_group: Option<String>,
+}
+
+/// The source of the keys used to validate JWTs.
+#[derive(Debug, Clone)]
+pub enum JwksSource {
+    /// A fixed key set, e.g., loaded from a file.
+    Static(JwkSet),
+    /// A key set served by the provider at the specified URL.
+    Url(String),
+}
+
+/// Configures [`OidcAuthentication`].
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    /// The issuer that JWTs must be issued by.
+    pub issuer: String,
+    /// The keys used to validate JWTs.
+    pub jwks: JwksSource,
+    /// The audience that JWTs must be issued for.
+    pub audience: String,
+    /// The claim that contains the name of the role to log in as.
+    pub role_claim: String,
+    /// The claim that lists the user's groups, if any.
+    pub groups_claim: Option<String>,
+    /// The group whose members are superusers, if any.
+    pub admin_group: Option<String>,
+    /// Function to provide system time to validate the exp (expires at) and
+    /// nbf (not before) fields of JWTs.
+    pub now: NowFn,
+}
+
+/// Authenticates users with JWTs issued by an OpenID Connect provider.
+#[derive(Debug, Clone)]
+pub struct OidcAuthentication {
+    config: Arc<OidcConfig>,
+    /// The most recently fetched key set and when it was fetched, if the key
+    /// set is remote.
+    remote_jwks: Arc<Mutex<Option<(Arc<JwkSet>, Instant)>>>,
+    client: reqwest::Client,
+}
+
+impl OidcAuthentication {
+    /// Creates a new OpenID Connect authenticator.
+    pub fn new(config: OidcConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("must build Client");
+        OidcAuthentication {
+            config: Arc::new(config),
+            remote_jwks: Arc::new(Mutex::new(None)),
+            client,
+        }
+    }
+
+    /// Creates an [`OidcAuthentication`] from [`OidcCliArgs`].
+    pub fn from_args(args: OidcCliArgs) -> Result<Option<Self>, Error> {
+        let Some(issuer) = args.oidc_issuer else {
+            return Ok(None);
+        };
+        let Some(audience) = args.oidc_audience else {
+            return Err(anyhow::anyhow!("--oidc-audience is required with --oidc-issuer").into());
+        };
+        let jwks = match (args.oidc_jwks_url, args.oidc_jwks_file) {
+            (Some(url), None) => JwksSource::Url(url),
+            (None, Some(path)) => {
+                let jwks = std::fs::read(&path)
+                    .with_context(|| format!("reading {path:?} for --oidc-jwks-file"))?;
+                let jwks = serde_json::from_slice(&jwks)
+                    .with_context(|| format!("parsing {path:?} for --oidc-jwks-file"))?;
+                JwksSource::Static(jwks)
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "expected exactly one of --oidc-jwks-url or --oidc-jwks-file"
+                )
+                .into())
+            }
+        };
+        Ok(Some(Self::new(OidcConfig {
+            issuer,
+            jwks,
+            audience,
+            role_claim: args.oidc_role_claim,
+            groups_claim: args.oidc_groups_claim,
+            admin_group: args.oidc_admin_group,
+            now: mz_ore::now::SYSTEM_TIME.clone(),
+        })))
+    }
+
+    /// Validates a JWT, returning the name of the role it authenticates and
+    /// the metadata described by its claims.
+    pub async fn validate_access_token(
+        &self,
+        token: &str,
+    ) -> Result<(String, ExternalUserMetadata), Error> {
+        let header = jsonwebtoken::decode_header(token)?;
+        if !ALLOWED_ALGORITHMS.contains(&header.alg) {
+            return Err(jsonwebtoken::errors::Error::from(ErrorKind::InvalidAlgorithm).into());
+        }
+        let decoding_key = self.decoding_key(header.kid.as_deref()).await?;
+
+        // We validate with our own now function.
+        let mut validation = Validation::new(header.alg);
+        validation.validate_exp = false;
+        validation.validate_nbf = false;
+        validation.set_issuer(&[&self.config.issuer]);
+        validation.set_audience(&[&self.config.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+
+        let msg = jsonwebtoken::decode::<OidcClaims>(token, &decoding_key, &validation)?;
+        let now = self.config.now.as_secs();
+        if msg.claims.exp < now - CLOCK_SKEW_LEEWAY_SECS {
+            return Err(Error::TokenExpired);
+        }
+        if msg
+            .claims
+            .nbf
+            .map_or(false, |nbf| nbf > now + CLOCK_SKEW_LEEWAY_SECS)
+        {
+            return Err(Error::TokenNotYetValid);
+        }
+        let claims = msg.claims.claims;
+
+        let name = match claims.get(&self.config.role_claim) {
+            Some(Value::String(name)) if !name.is_empty() => name.clone(),
+            _ => return Err(Error::InvalidClaim(self.config.role_claim.clone())),
+        };
+        let groups = match &self.config.groups_claim {
+            None => None,
+            Some(groups_claim) => match claims.get(groups_claim) {
+                // A user that belongs to no groups may be issued tokens
+                // without the claim.
+                None | Some(Value::Null) => Some(vec![]),
+                Some(Value::String(group)) => Some(vec![group.clone()]),
+                Some(Value::Array(groups)) => Some(
+                    groups
+                        .iter()
+                        .map(|group| match group {
+                            Value::String(group) => Ok(group.clone()),
+                            _ => Err(Error::InvalidClaim(groups_claim.clone())),
+                        })
+                        .collect::<Result<_, _>>()?,
+                ),
+                Some(_) => return Err(Error::InvalidClaim(groups_claim.clone())),
+            },
+        };
+        let admin = match (&self.config.admin_group, &groups) {
+            (Some(admin_group), Some(groups)) => groups.contains(admin_group),
+            _ => false,
+        };
+        // Most providers use UUIDs as subjects. For those that don't, derive a
+        // stable UUID from the issuer and subject.
+        let subject = match claims.get("sub") {
+            Some(Value::String(sub)) => sub.as_str(),
+            _ => name.as_str(),
+        };
+        let user_id = Uuid::parse_str(subject).unwrap_or_else(|_| {
+            Uuid::new_v5(
+                &Uuid::NAMESPACE_URL,
+                format!("{}#{}", self.config.issuer, subject).as_bytes(),
+            )
+        });
+
+        let metadata = ExternalUserMetadata {
+            user_id,
+            admin,
+            groups,
+        };
+        Ok((name, metadata))
+    }
+
+    /// Returns the key that signed a JWT with the specified key ID.
+    async fn decoding_key(&self, kid: Option<&str>) -> Result<DecodingKey, Error> {
+        let url = match &self.config.jwks {
+            JwksSource::Static(jwks) => return find_key(jwks, kid),
+            JwksSource::Url(url) => url,
+        };
+
+        // Keys are rotated by the provider, so a token signed by an unknown
+        // key prompts a refetch of the key set, unless we fetched it recently.
+        let cached = self.remote_jwks.lock().expect("lock poisoned").clone();
+        if let Some((jwks, fetched_at)) = cached {
+            match find_key(&jwks, kid) {
+                Ok(key) => return Ok(key),
+                Err(err) if fetched_at.elapsed() < MIN_JWKS_REFRESH_INTERVAL => return Err(err),
+                Err(_) => (),
+            }
+        }
+        let jwks: JwkSet = self
+            .client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        let jwks = Arc::new(jwks);
+        *self.remote_jwks.lock().expect("lock poisoned") =
+            Some((Arc::clone(&jwks), Instant::now()));
+        find_key(&jwks, kid)
+    }
+}
+
+/// Finds the key with the specified key ID in a key set. A token without a
+/// key ID may only be validated against a key set with a single key.
+fn find_key(jwks: &JwkSet, kid: Option<&str>) -> Result<DecodingKey, Error> {
+    let jwk = match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    };
+    let jwk = jwk.ok_or(Error::UnknownSigningKey)?;
+    Ok(DecodingKey::from_jwk(jwk)?)
+}
+
+#[async_trait]
+impl AuthenticationProvider for OidcAuthentication {
+    async fn authenticate_password(
+        &self,
+        user: String,
+        password: &str,
+    ) -> Result<AuthenticatedUser, Error> {
+        let (name, metadata) = self.validate_access_token(password).await?;
+        // Like Frontegg email addresses, role names are compared case
+        // insensitively, with the provider's casing being canonical.
+        if name.to_lowercase() != user.to_lowercase() {
+            return Err(Error::WrongUser);
+        }
+        Ok(AuthenticatedUser {
+            name,
+            metadata,
+            refresh_updates: None,
+            // The token is only validated when the client connects. Sessions
+            // outlive the token, as in PostgreSQL.
+            valid_until_fut: future::pending().boxed(),
+        })
+    }
+
+    async fn authenticate_token(&self, token: &str) -> Result<AuthenticatedUser, Error> {
+        let (name, metadata) = self.validate_access_token(token).await?;
+        Ok(AuthenticatedUser {
+            name,
+            metadata,
+            refresh_updates: None,
+            valid_until_fut: future::pending().boxed(),
+        })
+    }
+}
+
+/// The claims in a JWT issued by an OpenID Connect provider.
+#[derive(Debug, Deserialize)]
+struct OidcClaims {
+    exp: i64,
+    nbf: Option<i64>,
+    /// The remaining claims, as the role and groups claims are configurable.
+    #[serde(flatten)]
+    claims: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use jsonwebtoken::{EncodingKey, Header};
+    use serde_json::json;
+
+    use super::*;
+
+    // A 2048-bit RSA test key, in PEM and JWK form.
+    const PRIVATE_KEY: &str = include_str!("testdata/oidc_test_key.pem");
+    const JWKS: &str = include_str!("testdata/oidc_test_jwks.json");
+
+    fn authentication(groups_claim: Option<&str>) -> OidcAuthentication {
+        OidcAuthentication::new(OidcConfig {
+            issuer: "https://idp.example.com/realms/materialize".into(),
+            jwks: JwksSource::Static(serde_json::from_str(JWKS).unwrap()),
+            audience: "materialize".into(),
+            role_claim: "preferred_username".into(),
+            groups_claim: groups_claim.map(|claim| claim.into()),
+            admin_group: Some("admins".into()),
+            now: mz_ore::now::SYSTEM_TIME.clone(),
+        })
+    }
+
+    fn token(claims: Value) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some("test-key".into());
+        let key = EncodingKey::from_rsa_pem(PRIVATE_KEY.as_bytes()).unwrap();
+        jsonwebtoken::encode(&header, &claims, &key).unwrap()
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn exp() -> u64 {
+        now() + 300
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `GFp_bn_mul_mont` on OS `linux`
+    async fn test_oidc_validate() {
+        let auth = authentication(Some("groups"));
+        let (name, metadata) = auth
+            .validate_access_token(&token(json!({
+                "iss": "https://idp.example.com/realms/materialize",
+                "aud": "materialize",
+                "exp": exp(),
+                "sub": "4f6ad1e4-5b0d-4bd4-9c2c-2b7e4c4a11c1",
+                "preferred_username": "alice",
+                "groups": ["admins", "analysts"],
+            })))
+            .await
+            .unwrap();
+        assert_eq!(name, "alice");
+        assert_eq!(
+            metadata.user_id,
+            Uuid::parse_str("4f6ad1e4-5b0d-4bd4-9c2c-2b7e4c4a11c1").unwrap()
+        );
+        assert!(metadata.admin);
+        assert_eq!(
+            metadata.groups,
+            Some(vec!["admins".to_string(), "analysts".to_string()])
+        );
+
+        // Without a groups claim, groups are not reported.
+        let auth = authentication(None);
+        let (_, metadata) = auth
+            .validate_access_token(&token(json!({
+                "iss": "https://idp.example.com/realms/materialize",
+                "aud": "materialize",
+                "exp": exp(),
+                "sub": "alice",
+                "preferred_username": "alice",
+            })))
+            .await
+            .unwrap();
+        assert!(!metadata.admin);
+        assert_eq!(metadata.groups, None);
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `GFp_bn_mul_mont` on OS `linux`
+    async fn test_oidc_invalid() {
+        let auth = authentication(Some("groups"));
+        let valid = json!({
+            "iss": "https://idp.example.com/realms/materialize",
+            "aud": "materialize",
+            "exp": exp(),
+            "preferred_username": "alice",
+        });
+        let with = |key: &str, value: Value| {
+            let mut claims = valid.clone();
+            claims[key] = value;
+            token(claims)
+        };
+
+        for token in [
+            with("iss", json!("https://evil.example.com")),
+            with("aud", json!("other")),
+            with("exp", json!(1)),
+            with("exp", json!(now() - 120)),
+            with("nbf", json!(now() + 120)),
+            with("preferred_username", json!(42)),
+            with("groups", json!([1, 2])),
+        ] {
+            assert!(auth.validate_access_token(&token).await.is_err());
+        }
+
+        let mut no_audience = valid.clone();
+        no_audience.as_object_mut().unwrap().remove("aud");
+        assert!(auth
+            .validate_access_token(&token(no_audience))
+            .await
+            .is_err());
+
+        // Small clock skew between Materialize and the provider is tolerated.
+        auth.validate_access_token(&with("exp", json!(now() - 10)))
+            .await
+            .unwrap();
+        auth.validate_access_token(&with("nbf", json!(now() + 10)))
+            .await
+            .unwrap();
+
+        let err = auth
+            .authenticate_password("bob".into(), &token(valid.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::WrongUser));
+        auth.authenticate_password("Alice".into(), &token(valid))
+            .await
+            .unwrap();
+    }
+}
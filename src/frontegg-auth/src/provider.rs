@@ -0,0 +1,56 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! An abstraction over external authentication providers.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derivative::Derivative;
+use futures::future::BoxFuture;
+use mz_repr::user::ExternalUserMetadata;
+use tokio::sync::watch;
+
+use crate::Error;
+
+/// A service that authenticates users on behalf of Materialize, like Frontegg
+/// or an OpenID Connect identity provider.
+#[async_trait]
+pub trait AuthenticationProvider: fmt::Debug + Send + Sync {
+    /// Authenticates a client that connected as `user` with `password`, as
+    /// with pgwire or HTTP basic authentication.
+    async fn authenticate_password(
+        &self,
+        user: String,
+        password: &str,
+    ) -> Result<AuthenticatedUser, Error>;
+
+    /// Authenticates a client that presented an access token, as with HTTP
+    /// bearer authentication.
+    async fn authenticate_token(&self, token: &str) -> Result<AuthenticatedUser, Error>;
+}
+
+/// A user authenticated by an [`AuthenticationProvider`].
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct AuthenticatedUser {
+    /// The name of the role the user logs in as.
+    ///
+    /// The provider's spelling of the name is canonical, and may differ from
+    /// the name the client supplied, e.g., in casing.
+    pub name: String,
+    /// Metadata about the user in the provider.
+    pub metadata: ExternalUserMetadata,
+    /// Receives updates to `metadata`, if the provider refreshes the user's
+    /// authentication while the user is connected.
+    pub refresh_updates: Option<watch::Receiver<ExternalUserMetadata>>,
+    /// The authentication is valid until this future resolves.
+    #[derivative(Debug = "ignore")]
+    pub valid_until_fut: BoxFuture<'static, ()>,
+}
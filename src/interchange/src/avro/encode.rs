@@ -490,6 +490,12 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
                 ScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
                 ScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
+                ScalarType::Inet => Value::String(datum.unwrap_inet().to_string()),
+                ScalarType::Cidr => Value::String(datum.unwrap_inet().display_cidr().to_string()),
+                ScalarType::MacAddr => Value::String(datum.unwrap_mac_addr().to_string()),
+                ScalarType::Enum { labels, .. } => {
+                    Value::String(labels[usize::cast_from(datum.unwrap_uint32())].clone())
+                }
             };
             if typ.nullable {
                 val = Value::Union {
@@ -10,6 +10,7 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use mz_ore::cast::CastFrom;
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
@@ -241,6 +242,12 @@ impl ToJson for TypedDatum<'_> {
                 json!(datum.unwrap_range().to_string())
             }
             ScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
+            ScalarType::Inet => json!(datum.unwrap_inet().to_string()),
+            ScalarType::Cidr => json!(datum.unwrap_inet().display_cidr().to_string()),
+            ScalarType::MacAddr => json!(datum.unwrap_mac_addr().to_string()),
+            ScalarType::Enum { labels, .. } => {
+                json!(labels[usize::cast_from(datum.unwrap_uint32())])
+            }
         };
         // We don't need to recurse into map or object here because those already recursively call
         // .json() with the number policy to generate the member Values.
@@ -391,6 +398,8 @@ fn build_row_schema_field_type(
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
         ScalarType::Range { .. } => json!("string"),
         ScalarType::MzAclItem => json!("string"),
+        ScalarType::Inet | ScalarType::Cidr | ScalarType::MacAddr => json!("string"),
+        ScalarType::Enum { .. } => json!("string"),
     };
     if typ.nullable {
         // Should be revisited if we ever support a different kind of union scheme.
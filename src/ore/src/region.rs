@@ -15,8 +15,36 @@
 
 //! Region-allocated data utilities.
 
+use std::cell::Cell;
 use std::fmt::{Debug, Formatter};
 
+thread_local! {
+    /// The number of bytes that [`LgAllocRegion::heap_size`] reported for
+    /// disk-backed allocations while a [`measure_disk_backed`] scope is active on
+    /// this thread, or `None` if no scope is active.
+    static DISK_BACKED_SIZE: Cell<Option<usize>> = Cell::new(None);
+}
+
+/// Runs `f`, returning its result together with the number of bytes that calls
+/// to [`LgAllocRegion::heap_size`] made by `f` on this thread reported for
+/// disk-backed allocations.
+///
+/// Regions are backed by disk when lgalloc is enabled, and the operating
+/// system may page their contents out under memory pressure. The callbacks of
+/// `heap_size` cannot distinguish these allocations from heap allocations, so
+/// this function lets callers recover the split. Nested scopes also count
+/// toward their enclosing scope.
+pub fn measure_disk_backed<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let outer = DISK_BACKED_SIZE.with(|size| size.replace(Some(0)));
+    let result = f();
+    let size = DISK_BACKED_SIZE.with(|size| {
+        let inner = size.get().unwrap_or(0);
+        size.set(outer.map(|outer| outer + inner));
+        inner
+    });
+    (result, size)
+}
+
 /// A region allocator which holds items at stable memory locations.
 ///
 /// Items once inserted will not be moved, and their locations in memory
@@ -131,10 +159,17 @@ impl<T> LgAllocRegion<T> {
     }
 
     /// Visit contained allocations to determine their size and capacity.
+    ///
+    /// Disk-backed allocations are also reported to the enclosing
+    /// [`measure_disk_backed`] scope, if any.
     #[inline]
     pub fn heap_size(&self, mut callback: impl FnMut(usize, usize)) {
         // Calculate heap size for local, stash, and stash entries
         let size_of_t = std::mem::size_of::<T>();
+        Self::record_disk_backed(&self.local);
+        for stash in &self.stash {
+            Self::record_disk_backed(stash);
+        }
         callback(
             self.local.len() * size_of_t,
             self.local.capacity() * size_of_t,
@@ -147,4 +182,17 @@ impl<T> LgAllocRegion<T> {
             callback(stash.len() * size_of_t, stash.capacity() * size_of_t);
         }
     }
+
+    /// Adds the size of `region` to the enclosing [`measure_disk_backed`]
+    /// scope, if `region` is disk-backed.
+    fn record_disk_backed(region: &lgalloc::Region<T>) {
+        if let lgalloc::Region::MMap(_) = region {
+            let bytes = region.len() * std::mem::size_of::<T>();
+            DISK_BACKED_SIZE.with(|size| {
+                if let Some(current) = size.get() {
+                    size.set(Some(current + bytes));
+                }
+            });
+        }
+    }
 }
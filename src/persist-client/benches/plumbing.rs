@@ -18,7 +18,7 @@ use criterion::{Bencher, BenchmarkId, Criterion, Throughput};
 use differential_dataflow::trace::Description;
 use futures::stream::{FuturesUnordered, StreamExt};
 use mz_ore::task::RuntimeExt;
-use mz_persist::indexed::encoding::BlobTraceBatchPart;
+use mz_persist::indexed::encoding::{BlobTraceBatchPart, CompressionFormat};
 use mz_persist::location::{
     Atomicity, Blob, CaSResult, Consensus, ExternalError, SeqNo, VersionedData,
 };
@@ -206,7 +206,7 @@ pub fn bench_encode_batch(name: &str, throughput: bool, c: &mut Criterion, data:
         b.iter(|| {
             // Intentionally alloc a new buf each iter.
             let mut buf = Vec::new();
-            trace.encode(&mut buf);
+            trace.encode(&mut buf, CompressionFormat::None);
         })
     });
 }
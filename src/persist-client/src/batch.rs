@@ -22,10 +22,10 @@ use differential_dataflow::difference::Semigroup;
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::trace::Description;
 use futures_util::stream::{FuturesUnordered, StreamExt};
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 use mz_ore::task::JoinHandleExt;
 use mz_persist::indexed::columnar::{ColumnarRecords, ColumnarRecordsBuilder};
-use mz_persist::indexed::encoding::BlobTraceBatchPart;
+use mz_persist::indexed::encoding::{BlobTraceBatchPart, CompressionFormat};
 use mz_persist::location::{Atomicity, Blob};
 use mz_persist_types::stats::{trim_to_budget, truncate_bytes, TruncateBound, TRUNCATE_LEN};
 use mz_persist_types::{Codec, Codec64};
@@ -218,6 +218,7 @@ pub struct BatchBuilderConfig {
     pub(crate) blob_target_size: usize,
     pub(crate) batch_delete_enabled: bool,
     pub(crate) batch_builder_max_outstanding_parts: usize,
+    pub(crate) compression: CompressionFormat,
     pub(crate) stats_collection_enabled: bool,
     pub(crate) stats_budget: usize,
     pub(crate) stats_untrimmable_columns: Arc<UntrimmableColumns>,
@@ -236,6 +237,7 @@ impl BatchBuilderConfig {
             batch_builder_max_outstanding_parts: value
                 .dynamic
                 .batch_builder_max_outstanding_parts(),
+            compression: value.dynamic.blob_compression(),
             stats_collection_enabled: value.dynamic.stats_collection_enabled(),
             stats_budget: value.dynamic.stats_budget_bytes(),
             stats_untrimmable_columns: Arc::new(value.dynamic.stats_untrimmable_columns()),
@@ -812,6 +814,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
         let partial_key = PartialBatchKey::new(&self.cfg.writer_key, &PartId::new());
         let key = partial_key.complete(&self.shard_id);
         let index = u64::cast_from(self.finished_parts.len() + self.writing_parts.len());
+        let compression = self.cfg.compression;
         let stats_collection_enabled = self.cfg.stats_collection_enabled;
         let stats_budget = self.cfg.stats_budget;
         let schemas = schemas.clone();
@@ -853,7 +856,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
 
                         let encode_start = Instant::now();
                         let mut buf = Vec::new();
-                        batch.encode(&mut buf);
+                        batch.encode(&mut buf, compression);
 
                         // Drop batch as soon as we can to reclaim its memory.
                         drop(batch);
@@ -882,6 +885,12 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                 batch_metrics.seconds.inc_by(start.elapsed().as_secs_f64());
                 batch_metrics.bytes.inc_by(u64::cast_from(payload_len));
                 batch_metrics.goodbytes.inc_by(u64::cast_from(goodbytes));
+                if payload_len > 0 {
+                    batch_metrics
+                        .compression_ratio
+                        .with_label_values(&[compression.as_str()])
+                        .observe(f64::cast_lossy(goodbytes) / f64::cast_lossy(payload_len));
+                }
                 let stats = stats.map(|(stats, stats_step_timing, trimmed_bytes)| {
                     batch_metrics
                         .step_stats
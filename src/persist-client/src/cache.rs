@@ -21,6 +21,7 @@ use differential_dataflow::difference::Semigroup;
 use differential_dataflow::lattice::Lattice;
 use mz_ore::metrics::MetricsRegistry;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encryption::EncryptedBlob;
 use mz_persist::location::{
     Blob, Consensus, ExternalError, Tasked, VersionedData, BLOB_GET_LIVENESS_KEY,
     CONSENSUS_HEAD_LIVENESS_KEY,
@@ -29,10 +30,11 @@ use mz_persist_types::{Codec, Codec64};
 use timely::progress::Timestamp;
 use tokio::sync::{Mutex, OnceCell};
 use tokio::task::JoinHandle;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
+use crate::internal::disk_cache::DiskCacheBlob;
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -203,12 +205,16 @@ impl PersistClientCache {
             Entry::Vacant(x) => {
                 // Intentionally hold the lock, so we don't double connect under
                 // concurrency.
-                let blob = BlobConfig::try_from(
+                // Encryption goes on top of everything else, so that the
+                // layers below it (notably the disk cache) only ever see
+                // ciphertext.
+                let (blob, key_provider) = BlobConfig::try_from(
                     x.key(),
                     Box::new(self.cfg.clone()),
                     self.metrics.s3_blob.clone(),
                 )
-                .await?;
+                .await?
+                .split_encryption();
                 let blob = retry_external(&self.metrics.retries.external.blob_open, || {
                     blob.clone().open()
                 })
@@ -221,6 +227,35 @@ impl PersistClientCache {
                     Self::PROMETHEUS_SCRAPE_INTERVAL,
                 )
                 .await;
+                // The disk cache goes on top, so that hits don't show up in
+                // blob metrics as if they were reads from s3.
+                let blob: Arc<dyn Blob + Send + Sync> = match &self.cfg.blob_cache_disk_dir {
+                    Some(dir) => match DiskCacheBlob::open(
+                        dir.clone(),
+                        Arc::clone(&blob) as Arc<dyn Blob + Send + Sync>,
+                        Arc::clone(&self.cfg.dynamic),
+                        self.metrics.blob_cache_disk.clone(),
+                    )
+                    .await
+                    {
+                        Ok(cached) => Arc::new(cached),
+                        Err(err) => {
+                            // The cache is only an optimization, so don't
+                            // fail to start up without it.
+                            warn!(
+                                "failed to open persist blob disk cache at {}: {}",
+                                dir.display(),
+                                err
+                            );
+                            blob
+                        }
+                    },
+                    None => blob,
+                };
+                let blob: Arc<dyn Blob + Send + Sync> = match key_provider {
+                    Some(key_provider) => Arc::new(EncryptedBlob::new(blob, key_provider)),
+                    None => blob,
+                };
                 Arc::clone(&x.insert((RttLatencyTask(task), blob)).1)
             }
         };
@@ -675,10 +710,15 @@ mod tests {
     use std::ops::Deref;
     use std::sync::atomic::{AtomicBool, Ordering};
 
+    use bytes::Bytes;
     use futures::stream::{FuturesUnordered, StreamExt};
     use mz_build_info::DUMMY_BUILD_INFO;
     use mz_ore::now::SYSTEM_TIME;
     use mz_ore::task::spawn;
+    use mz_persist::location::Atomicity;
+
+    use crate::cfg::PersistParameters;
+    use crate::internal::paths::{PartId, PartialBatchKey, WriterKey};
 
     use super::*;
 
@@ -750,6 +790,77 @@ mod tests {
         assert_eq!(cache.consensus_by_uri.lock().await.len(), 4);
     }
 
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn blob_disk_cache_encrypted() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let (blob_dir, cache_dir) = (dir.path().join("blob"), dir.path().join("cache"));
+        std::fs::create_dir(&cache_dir).expect("create cache dir");
+        let key_file = dir.path().join("keys");
+        std::fs::write(
+            &key_file,
+            "k1: AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=\n",
+        )
+        .expect("write key file");
+
+        let mut cfg = PersistConfig::new_for_tests();
+        cfg.blob_cache_disk_dir = Some(cache_dir.clone());
+        PersistParameters {
+            blob_cache_disk_limit_bytes: Some(1 << 20),
+            ..Default::default()
+        }
+        .apply(&cfg);
+        let cache = PersistClientCache::new(cfg, &MetricsRegistry::new(), |_, _| {
+            PubSubClientConnection::noop()
+        });
+        let blob = cache
+            .open_blob(format!(
+                "file://{}?encryption_key_file={}",
+                blob_dir.display(),
+                key_file.display()
+            ))
+            .await
+            .expect("failed to open blob");
+
+        let writer_key = WriterKey::for_version(&semver::Version::new(0, 0, 0));
+        let key = PartialBatchKey::new(&writer_key, &PartId::new())
+            .complete(&ShardId::new())
+            .to_string();
+        let value = Bytes::from("some secret value");
+        blob.set(&key, value.clone(), Atomicity::RequireAtomic)
+            .await
+            .expect("set");
+        let read = blob
+            .get(&key)
+            .await
+            .expect("get")
+            .map(|x| x.into_contiguous());
+        assert_eq!(read.as_deref(), Some(&value[..]));
+
+        // The read populates the disk cache in the background, with the
+        // encrypted value.
+        let cached_path = cache_dir.join(key.replace('/', "_"));
+        let mut cached = None;
+        for _ in 0..1000 {
+            if let Ok(contents) = std::fs::read(&cached_path) {
+                cached = Some(contents);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        let cached = cached.expect("part was never cached");
+        assert!(cached.starts_with(b"MZE1"));
+        assert!(!cached.windows(value.len()).any(|w| w == &value[..]));
+
+        // Reads served from the cache are still decrypted.
+        let read = blob
+            .get(&key)
+            .await
+            .expect("get")
+            .map(|x| x.into_contiguous());
+        assert_eq!(read.as_deref(), Some(&value[..]));
+    }
+
     #[mz_ore::test(tokio::test)]
     #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
     async fn state_cache() {
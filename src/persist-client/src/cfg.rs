@@ -13,6 +13,7 @@
 
 use once_cell::sync::Lazy;
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::string::ToString;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
@@ -29,6 +30,8 @@ use proptest_derive::Arbitrary;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
+pub use mz_persist::indexed::encoding::CompressionFormat;
+
 include!(concat!(env!("OUT_DIR"), "/mz_persist_client.cfg.rs"));
 
 #[derive(Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Debug)]
@@ -159,6 +162,10 @@ pub struct PersistConfig {
     pub pubsub_state_cache_shard_ref_channel_size: usize,
     /// Backoff after an established connection to Persist PubSub service fails.
     pub pubsub_reconnect_backoff: Duration,
+    /// A local directory in which to cache batch parts read from blob, if
+    /// any. The size of the cache is controlled by
+    /// [DynamicConfig::blob_cache_disk_limit_bytes].
+    pub blob_cache_disk_dir: Option<PathBuf>,
 }
 
 impl PersistConfig {
@@ -175,6 +182,9 @@ impl PersistConfig {
                 blob_cache_mem_limit_bytes: AtomicUsize::new(
                     Self::DEFAULT_BLOB_CACHE_MEM_LIMIT_BYTES,
                 ),
+                blob_cache_disk_limit_bytes: AtomicUsize::new(
+                    Self::DEFAULT_BLOB_CACHE_DISK_LIMIT_BYTES,
+                ),
                 compaction_heuristic_min_inputs: AtomicUsize::new(8),
                 compaction_heuristic_min_parts: AtomicUsize::new(8),
                 compaction_heuristic_min_updates: AtomicUsize::new(1024),
@@ -187,6 +197,7 @@ impl PersistConfig {
                 consensus_connect_timeout: RwLock::new(Self::DEFAULT_CRDB_CONNECT_TIMEOUT),
                 consensus_tcp_user_timeout: RwLock::new(Self::DEFAULT_CRDB_TCP_USER_TIMEOUT),
                 reader_lease_duration: RwLock::new(Self::DEFAULT_READ_LEASE_DURATION),
+                state_history_retention: RwLock::new(Self::DEFAULT_STATE_HISTORY_RETENTION),
                 gc_blob_delete_concurrency_limit: AtomicUsize::new(32),
                 state_versions_recent_live_diffs_limit: AtomicUsize::new(
                     30 * Self::DEFAULT_ROLLUP_THRESHOLD,
@@ -209,6 +220,7 @@ impl PersistConfig {
                 stats_untrimmable_columns: RwLock::new(
                     Self::DEFAULT_STATS_UNTRIMMABLE_COLUMNS.clone(),
                 ),
+                blob_compression: RwLock::new(Self::DEFAULT_BLOB_COMPRESSION),
                 pubsub_client_enabled: AtomicBool::new(Self::DEFAULT_PUBSUB_CLIENT_ENABLED),
                 pubsub_push_diff_enabled: AtomicBool::new(Self::DEFAULT_PUBSUB_PUSH_DIFF_ENABLED),
                 rollup_threshold: AtomicUsize::new(Self::DEFAULT_ROLLUP_THRESHOLD),
@@ -236,6 +248,7 @@ impl PersistConfig {
             pubsub_server_connection_channel_size: 25,
             pubsub_state_cache_shard_ref_channel_size: 25,
             pubsub_reconnect_backoff: Duration::from_secs(5),
+            blob_cache_disk_dir: None,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -314,6 +327,8 @@ impl PersistConfig {
     pub const DEFAULT_PUBSUB_PUSH_DIFF_ENABLED: bool = true;
     /// Default value for [`DynamicConfig::rollup_threshold`].
     pub const DEFAULT_ROLLUP_THRESHOLD: usize = 128;
+    /// Default value for [`DynamicConfig::blob_compression`].
+    pub const DEFAULT_BLOB_COMPRESSION: CompressionFormat = CompressionFormat::None;
 
     pub const DEFAULT_STATS_UNTRIMMABLE_COLUMNS: Lazy<UntrimmableColumns> = Lazy::new(|| {
         UntrimmableColumns {
@@ -366,12 +381,24 @@ impl PersistConfig {
     /// unobjectionable enough to have it for the cases that it does help.
     pub const DEFAULT_BLOB_CACHE_MEM_LIMIT_BYTES: usize = 1024 * 1024;
 
+    /// Default value for [`DynamicConfig::blob_cache_disk_limit_bytes`].
+    ///
+    /// The disk cache competes for scratch space with everything else on the
+    /// replica, so it starts disabled and is turned on per-environment.
+    pub const DEFAULT_BLOB_CACHE_DISK_LIMIT_BYTES: usize = 0;
+
     // Move this to a PersistConfig field when we actually have read leases.
     //
     // MIGRATION: Remove this once we remove the ReaderState <->
     // ProtoReaderState migration.
     pub const DEFAULT_READ_LEASE_DURATION: Duration = Duration::from_secs(60 * 15);
 
+    /// Default value for [`DynamicConfig::state_history_retention`].
+    ///
+    /// Retaining history holds on to blobs that would otherwise be garbage
+    /// collected, so it's opt-in.
+    pub const DEFAULT_STATE_HISTORY_RETENTION: Duration = Duration::ZERO;
+
     // TODO: Get rid of this in favor of using PersistParameters at the
     // relevant callsites.
     pub fn set_state_versions_recent_live_diffs_limit(&self, val: usize) {
@@ -439,6 +466,7 @@ pub struct DynamicConfig {
     batch_builder_max_outstanding_parts: AtomicUsize,
     blob_target_size: AtomicUsize,
     blob_cache_mem_limit_bytes: AtomicUsize,
+    blob_cache_disk_limit_bytes: AtomicUsize,
     compaction_heuristic_min_inputs: AtomicUsize,
     compaction_heuristic_min_parts: AtomicUsize,
     compaction_heuristic_min_updates: AtomicUsize,
@@ -452,6 +480,7 @@ pub struct DynamicConfig {
     consensus_connection_pool_ttl: RwLock<Duration>,
     consensus_connection_pool_ttl_stagger: RwLock<Duration>,
     reader_lease_duration: RwLock<Duration>,
+    state_history_retention: RwLock<Duration>,
     sink_minimum_batch_updates: AtomicUsize,
     storage_sink_minimum_batch_updates: AtomicUsize,
     storage_source_decode_fuel: AtomicUsize,
@@ -460,6 +489,7 @@ pub struct DynamicConfig {
     stats_filter_enabled: AtomicBool,
     stats_budget_bytes: AtomicUsize,
     stats_untrimmable_columns: RwLock<UntrimmableColumns>,
+    blob_compression: RwLock<CompressionFormat>,
     pubsub_client_enabled: AtomicBool,
     pubsub_push_diff_enabled: AtomicBool,
     rollup_threshold: AtomicUsize,
@@ -552,6 +582,13 @@ impl DynamicConfig {
         self.blob_cache_mem_limit_bytes.load(Self::LOAD_ORDERING)
     }
 
+    /// Capacity of the on-disk blob cache in bytes, or 0 to disable it.
+    ///
+    /// Only takes effect if [PersistConfig::blob_cache_disk_dir] is set.
+    pub fn blob_cache_disk_limit_bytes(&self) -> usize {
+        self.blob_cache_disk_limit_bytes.load(Self::LOAD_ORDERING)
+    }
+
     /// In Compactor::compact_and_apply, we do the compaction (don't skip it)
     /// if the number of inputs is at least this many. Compaction is performed
     /// if any of the heuristic criteria are met (they are OR'd).
@@ -647,6 +684,14 @@ impl DynamicConfig {
         *self.reader_lease_duration.write().expect("lock poisoned") = d;
     }
 
+    /// Length of wall-clock time for which garbage collection retains every
+    /// version of a shard's state (and the blobs it references), so that the
+    /// shard can be recovered as of any point in that window. Zero disables
+    /// the retention.
+    pub fn state_history_retention(&self) -> Duration {
+        *self.state_history_retention.read().expect("lock poisoned")
+    }
+
     /// The maximum number of concurrent blob deletes during garbage collection.
     pub fn gc_blob_delete_concurrency_limit(&self) -> usize {
         self.gc_blob_delete_concurrency_limit
@@ -703,6 +748,14 @@ impl DynamicConfig {
             .clone()
     }
 
+    /// The compression applied to newly written batch parts.
+    ///
+    /// The codec is recorded in each part, so changing this only affects
+    /// parts written afterward and shards may freely mix codecs.
+    pub fn blob_compression(&self) -> CompressionFormat {
+        *self.blob_compression.read().expect("lock poisoned")
+    }
+
     /// Determines whether PubSub clients should connect to the PubSub server.
     pub fn pubsub_client_enabled(&self) -> bool {
         self.pubsub_client_enabled.load(Self::LOAD_ORDERING)
@@ -799,6 +852,8 @@ pub struct PersistParameters {
     pub blob_target_size: Option<usize>,
     /// Configures [`DynamicConfig::blob_cache_mem_limit_bytes`].
     pub blob_cache_mem_limit_bytes: Option<usize>,
+    /// Configures [`DynamicConfig::blob_cache_disk_limit_bytes`].
+    pub blob_cache_disk_limit_bytes: Option<usize>,
     /// Configures [`DynamicConfig::compaction_minimum_timeout`].
     pub compaction_minimum_timeout: Option<Duration>,
     /// Configures [`DynamicConfig::consensus_connect_timeout`].
@@ -815,6 +870,8 @@ pub struct PersistParameters {
     pub txns_data_shard_retryer: Option<RetryParameters>,
     /// Configures [`DynamicConfig::reader_lease_duration`].
     pub reader_lease_duration: Option<Duration>,
+    /// Configures [`DynamicConfig::state_history_retention`].
+    pub state_history_retention: Option<Duration>,
     /// Configures [`PersistConfig::sink_minimum_batch_updates`].
     pub sink_minimum_batch_updates: Option<usize>,
     /// Configures [`PersistConfig::storage_sink_minimum_batch_updates`].
@@ -831,6 +888,8 @@ pub struct PersistParameters {
     pub stats_budget_bytes: Option<usize>,
     /// Configures [`DynamicConfig::stats_untrimmable_columns`].
     pub stats_untrimmable_columns: Option<UntrimmableColumns>,
+    /// Configures [`DynamicConfig::blob_compression`].
+    pub blob_compression: Option<CompressionFormat>,
     /// Configures [`DynamicConfig::pubsub_client_enabled`]
     pub pubsub_client_enabled: Option<bool>,
     /// Configures [`DynamicConfig::pubsub_push_diff_enabled`]
@@ -849,12 +908,14 @@ impl PersistParameters {
         let Self {
             blob_target_size: self_blob_target_size,
             blob_cache_mem_limit_bytes: self_blob_cache_mem_limit_bytes,
+            blob_cache_disk_limit_bytes: self_blob_cache_disk_limit_bytes,
             compaction_minimum_timeout: self_compaction_minimum_timeout,
             consensus_connect_timeout: self_consensus_connect_timeout,
             consensus_tcp_user_timeout: self_consensus_tcp_user_timeout,
             consensus_connection_pool_ttl: self_consensus_connection_pool_ttl,
             consensus_connection_pool_ttl_stagger: self_consensus_connection_pool_ttl_stagger,
             reader_lease_duration: self_reader_lease_duration,
+            state_history_retention: self_state_history_retention,
             sink_minimum_batch_updates: self_sink_minimum_batch_updates,
             storage_sink_minimum_batch_updates: self_storage_sink_minimum_batch_updates,
             storage_source_decode_fuel: self_storage_source_decode_fuel,
@@ -865,6 +926,7 @@ impl PersistParameters {
             stats_filter_enabled: self_stats_filter_enabled,
             stats_budget_bytes: self_stats_budget_bytes,
             stats_untrimmable_columns: self_stats_untrimmable_columns,
+            blob_compression: self_blob_compression,
             pubsub_client_enabled: self_pubsub_client_enabled,
             pubsub_push_diff_enabled: self_pubsub_push_diff_enabled,
             rollup_threshold: self_rollup_threshold,
@@ -873,12 +935,14 @@ impl PersistParameters {
         let Self {
             blob_target_size: other_blob_target_size,
             blob_cache_mem_limit_bytes: other_blob_cache_mem_limit_bytes,
+            blob_cache_disk_limit_bytes: other_blob_cache_disk_limit_bytes,
             compaction_minimum_timeout: other_compaction_minimum_timeout,
             consensus_connect_timeout: other_consensus_connect_timeout,
             consensus_tcp_user_timeout: other_consensus_tcp_user_timeout,
             consensus_connection_pool_ttl: other_consensus_connection_pool_ttl,
             consensus_connection_pool_ttl_stagger: other_consensus_connection_pool_ttl_stagger,
             reader_lease_duration: other_reader_lease_duration,
+            state_history_retention: other_state_history_retention,
             sink_minimum_batch_updates: other_sink_minimum_batch_updates,
             storage_sink_minimum_batch_updates: other_storage_sink_minimum_batch_updates,
             storage_source_decode_fuel: other_storage_source_decode_fuel,
@@ -889,6 +953,7 @@ impl PersistParameters {
             stats_filter_enabled: other_stats_filter_enabled,
             stats_budget_bytes: other_stats_budget_bytes,
             stats_untrimmable_columns: other_stats_untrimmable_columns,
+            blob_compression: other_blob_compression,
             pubsub_client_enabled: other_pubsub_client_enabled,
             pubsub_push_diff_enabled: other_pubsub_push_diff_enabled,
             rollup_threshold: other_rollup_threshold,
@@ -900,6 +965,9 @@ impl PersistParameters {
         if let Some(v) = other_blob_cache_mem_limit_bytes {
             *self_blob_cache_mem_limit_bytes = Some(v);
         }
+        if let Some(v) = other_blob_cache_disk_limit_bytes {
+            *self_blob_cache_disk_limit_bytes = Some(v);
+        }
         if let Some(v) = other_compaction_minimum_timeout {
             *self_compaction_minimum_timeout = Some(v);
         }
@@ -918,6 +986,9 @@ impl PersistParameters {
         if let Some(v) = other_reader_lease_duration {
             *self_reader_lease_duration = Some(v);
         }
+        if let Some(v) = other_state_history_retention {
+            *self_state_history_retention = Some(v);
+        }
         if let Some(v) = other_sink_minimum_batch_updates {
             *self_sink_minimum_batch_updates = Some(v);
         }
@@ -948,6 +1019,9 @@ impl PersistParameters {
         if let Some(v) = other_stats_untrimmable_columns {
             *self_stats_untrimmable_columns = Some(v)
         }
+        if let Some(v) = other_blob_compression {
+            *self_blob_compression = Some(v)
+        }
         if let Some(v) = other_pubsub_client_enabled {
             *self_pubsub_client_enabled = Some(v)
         }
@@ -969,12 +1043,14 @@ impl PersistParameters {
         let Self {
             blob_target_size,
             blob_cache_mem_limit_bytes,
+            blob_cache_disk_limit_bytes,
             compaction_minimum_timeout,
             consensus_connect_timeout,
             consensus_tcp_user_timeout,
             consensus_connection_pool_ttl,
             consensus_connection_pool_ttl_stagger,
             reader_lease_duration,
+            state_history_retention,
             sink_minimum_batch_updates,
             storage_sink_minimum_batch_updates,
             storage_source_decode_fuel,
@@ -985,6 +1061,7 @@ impl PersistParameters {
             stats_filter_enabled,
             stats_budget_bytes,
             stats_untrimmable_columns,
+            blob_compression,
             pubsub_client_enabled,
             pubsub_push_diff_enabled,
             rollup_threshold,
@@ -992,12 +1069,14 @@ impl PersistParameters {
         } = self;
         blob_target_size.is_none()
             && blob_cache_mem_limit_bytes.is_none()
+            && blob_cache_disk_limit_bytes.is_none()
             && compaction_minimum_timeout.is_none()
             && consensus_connect_timeout.is_none()
             && consensus_tcp_user_timeout.is_none()
             && consensus_connection_pool_ttl.is_none()
             && consensus_connection_pool_ttl_stagger.is_none()
             && reader_lease_duration.is_none()
+            && state_history_retention.is_none()
             && sink_minimum_batch_updates.is_none()
             && storage_sink_minimum_batch_updates.is_none()
             && storage_source_decode_fuel.is_none()
@@ -1008,6 +1087,7 @@ impl PersistParameters {
             && stats_filter_enabled.is_none()
             && stats_budget_bytes.is_none()
             && stats_untrimmable_columns.is_none()
+            && blob_compression.is_none()
             && pubsub_client_enabled.is_none()
             && pubsub_push_diff_enabled.is_none()
             && rollup_threshold.is_none()
@@ -1024,12 +1104,14 @@ impl PersistParameters {
         let Self {
             blob_target_size,
             blob_cache_mem_limit_bytes,
+            blob_cache_disk_limit_bytes,
             compaction_minimum_timeout,
             consensus_connect_timeout,
             consensus_tcp_user_timeout,
             consensus_connection_pool_ttl,
             consensus_connection_pool_ttl_stagger,
             reader_lease_duration,
+            state_history_retention,
             sink_minimum_batch_updates,
             storage_sink_minimum_batch_updates,
             storage_source_decode_fuel,
@@ -1040,6 +1122,7 @@ impl PersistParameters {
             stats_filter_enabled,
             stats_budget_bytes,
             stats_untrimmable_columns,
+            blob_compression,
             pubsub_client_enabled,
             pubsub_push_diff_enabled,
             rollup_threshold,
@@ -1055,6 +1138,11 @@ impl PersistParameters {
                 .blob_cache_mem_limit_bytes
                 .store(*blob_cache_mem_limit_bytes, DynamicConfig::STORE_ORDERING);
         }
+        if let Some(blob_cache_disk_limit_bytes) = blob_cache_disk_limit_bytes {
+            cfg.dynamic
+                .blob_cache_disk_limit_bytes
+                .store(*blob_cache_disk_limit_bytes, DynamicConfig::STORE_ORDERING);
+        }
         if let Some(compaction_minimum_timeout) = compaction_minimum_timeout {
             let mut timeout = cfg
                 .dynamic
@@ -1099,6 +1187,14 @@ impl PersistParameters {
             cfg.dynamic
                 .set_reader_lease_duration(*reader_lease_duration);
         }
+        if let Some(state_history_retention) = state_history_retention {
+            let mut retention = cfg
+                .dynamic
+                .state_history_retention
+                .write()
+                .expect("lock poisoned");
+            *retention = *state_history_retention;
+        }
         if let Some(sink_minimum_batch_updates) = sink_minimum_batch_updates {
             cfg.dynamic
                 .sink_minimum_batch_updates
@@ -1159,6 +1255,10 @@ impl PersistParameters {
                 .expect("lock poisoned");
             *columns = stats_untrimmable_columns.clone();
         }
+        if let Some(blob_compression) = blob_compression {
+            let mut compression = cfg.dynamic.blob_compression.write().expect("lock poisoned");
+            *compression = *blob_compression;
+        }
         if let Some(pubsub_client_enabled) = pubsub_client_enabled {
             cfg.dynamic
                 .pubsub_client_enabled
@@ -1187,6 +1287,7 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
         ProtoPersistParameters {
             blob_target_size: self.blob_target_size.into_proto(),
             blob_cache_mem_limit_bytes: self.blob_cache_mem_limit_bytes.into_proto(),
+            blob_cache_disk_limit_bytes: self.blob_cache_disk_limit_bytes.into_proto(),
             compaction_minimum_timeout: self.compaction_minimum_timeout.into_proto(),
             consensus_connect_timeout: self.consensus_connect_timeout.into_proto(),
             consensus_tcp_user_timeout: self.consensus_tcp_user_timeout.into_proto(),
@@ -1195,6 +1296,7 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
                 .consensus_connection_pool_ttl_stagger
                 .into_proto(),
             reader_lease_duration: self.reader_lease_duration.into_proto(),
+            state_history_retention: self.state_history_retention.into_proto(),
             sink_minimum_batch_updates: self.sink_minimum_batch_updates.into_proto(),
             storage_sink_minimum_batch_updates: self
                 .storage_sink_minimum_batch_updates
@@ -1207,6 +1309,7 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
             stats_filter_enabled: self.stats_filter_enabled.into_proto(),
             stats_budget_bytes: self.stats_budget_bytes.into_proto(),
             stats_untrimmable_columns: self.stats_untrimmable_columns.into_proto(),
+            blob_compression: self.blob_compression.map(|x| x.to_string()),
             pubsub_client_enabled: self.pubsub_client_enabled.into_proto(),
             pubsub_push_diff_enabled: self.pubsub_push_diff_enabled.into_proto(),
             rollup_threshold: self.rollup_threshold.into_proto(),
@@ -1218,6 +1321,7 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
         Ok(Self {
             blob_target_size: proto.blob_target_size.into_rust()?,
             blob_cache_mem_limit_bytes: proto.blob_cache_mem_limit_bytes.into_rust()?,
+            blob_cache_disk_limit_bytes: proto.blob_cache_disk_limit_bytes.into_rust()?,
             compaction_minimum_timeout: proto.compaction_minimum_timeout.into_rust()?,
             consensus_connect_timeout: proto.consensus_connect_timeout.into_rust()?,
             consensus_tcp_user_timeout: proto.consensus_tcp_user_timeout.into_rust()?,
@@ -1226,6 +1330,7 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
                 .consensus_connection_pool_ttl_stagger
                 .into_rust()?,
             reader_lease_duration: proto.reader_lease_duration.into_rust()?,
+            state_history_retention: proto.state_history_retention.into_rust()?,
 
             sink_minimum_batch_updates: proto.sink_minimum_batch_updates.into_rust()?,
             storage_sink_minimum_batch_updates: proto
@@ -1239,6 +1344,10 @@ impl RustType<ProtoPersistParameters> for PersistParameters {
             stats_filter_enabled: proto.stats_filter_enabled.into_rust()?,
             stats_budget_bytes: proto.stats_budget_bytes.into_rust()?,
             stats_untrimmable_columns: proto.stats_untrimmable_columns.into_rust()?,
+            blob_compression: proto
+                .blob_compression
+                .map(|x| x.parse().map_err(TryFromProtoError::UnknownEnumVariant))
+                .transpose()?,
             pubsub_client_enabled: proto.pubsub_client_enabled.into_rust()?,
             pubsub_push_diff_enabled: proto.pubsub_push_diff_enabled.into_rust()?,
             rollup_threshold: proto.rollup_threshold.into_rust()?,
@@ -10,31 +10,39 @@
 //! CLI introspection tools for persist
 
 use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
+use std::ops::ControlFlow::{Break, Continue};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::{anyhow, bail};
+use bytes::Bytes;
 use differential_dataflow::difference::Semigroup;
 use differential_dataflow::lattice::Lattice;
 use futures_util::{stream, StreamExt, TryStreamExt};
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
-use mz_persist::location::{Blob, Consensus, ExternalError};
+use mz_persist::location::{Atomicity, Blob, CaSResult, Consensus, ExternalError, SeqNo};
 use mz_persist_types::codec_impls::TodoSchema;
 use mz_persist_types::{Codec, Codec64};
+use mz_proto::RustType;
 use prometheus::proto::{MetricFamily, MetricType};
+use prost::Message;
 use timely::progress::Timestamp;
 use tracing::info;
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::StateCache;
-use crate::cli::args::{make_blob, make_consensus, StateArgs, StoreArgs};
+use crate::cli::args::{make_blob, make_consensus, StateArgs, StoreArgs, READ_ALL_BUILD_INFO};
 use crate::internal::compact::{CompactConfig, CompactReq, Compactor};
-use crate::internal::encoding::Schemas;
+use crate::internal::encoding::{Rollup, Schemas, UntypedState};
 use crate::internal::gc::{GarbageCollector, GcReq};
 use crate::internal::machine::Machine;
+use crate::internal::paths::{PartialBatchKey, PartialRollupKey, RollupId};
+use crate::internal::state::{HollowRollup, NoOpStateTransition, TypedState};
+use crate::internal::state_diff::StateDiff;
 use crate::internal::trace::{ApplyMergeResult, FueledMergeRes};
 use crate::rpc::NoopPubSubSender;
 use crate::write::WriterId;
@@ -61,6 +69,14 @@ pub(crate) enum Command {
     /// Attempt to ensure that all the files referenced by consensus are available
     /// in Blob.
     RestoreBlob(RestoreBlobArgs),
+    /// Writes a consistent snapshot of a shard to another blob location, from
+    /// which it can be restored with `import-shard`.
+    ExportShard(ExportShardArgs),
+    /// Restores a snapshot written by `export-shard` as a new shard.
+    ImportShard(ImportShardArgs),
+    /// Materializes a new environment from the state that every shard of an
+    /// existing one had as of some past time.
+    RestoreEnvironment(RestoreEnvironmentArgs),
 }
 
 /// Manually completes all fueled compactions in a shard.
@@ -92,6 +108,96 @@ pub(crate) struct RestoreBlobArgs {
     concurrency: usize,
 }
 
+/// Writes a consistent snapshot of a shard to another blob location.
+///
+/// Only the export location is written to (and only with `--commit`); the
+/// shard itself is never modified.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ExportShardArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// Blob to write the snapshot to, e.g. `file:///path/to/backups` or an s3
+    /// URI. Repeated exports of a shard to the same location only copy the
+    /// parts that are new since the last one.
+    #[clap(long)]
+    export_blob_uri: String,
+
+    /// The number of concurrent part copies to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
+/// Restores a snapshot written by `export-shard` as a new shard.
+///
+/// The new shard has the data, since, and upper of the exported one, but none
+/// of its readers or writers. Use a persistcli built at the same version as
+/// the environment being restored into.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ImportShardArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    /// Blob that `export-shard` wrote the snapshot to.
+    #[clap(long)]
+    export_blob_uri: String,
+
+    /// The id of the exported shard.
+    #[clap(long)]
+    export_shard_id: String,
+
+    /// The seqno of the export to restore, if there is more than one. Defaults
+    /// to the most recent.
+    #[clap(long)]
+    export_seqno: Option<u64>,
+
+    /// The id of the shard to create. Defaults to a new, random id.
+    #[clap(long)]
+    shard_id: Option<String>,
+
+    /// The number of concurrent part copies to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
+/// Materializes a new environment from the state that every shard of an
+/// existing one had as of some past time.
+///
+/// Each shard is cut off at the last version of its state written at or before
+/// `--as-of`. That's exactly what durable state would look like had the whole
+/// environment crashed at that moment, so the restored shards are consistent
+/// with each other (up to clock skew between the processes that wrote them).
+/// This can only go as far back as the history kept around by
+/// `persist_state_history_retention`.
+///
+/// This only restores persist. The catalog and storage stashes live outside of
+/// it, so to roll back a whole environment, boot the new one with
+/// environmentd's `--recover-as-of` instead, which restores the stashes as of
+/// the same time and then runs this same restore.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct RestoreEnvironmentArgs {
+    #[clap(flatten)]
+    state: StoreArgs,
+
+    /// Consensus of the new environment. None of the restored shards may
+    /// already exist in it.
+    #[clap(long)]
+    to_consensus_uri: String,
+
+    /// Blob of the new environment.
+    #[clap(long)]
+    to_blob_uri: String,
+
+    /// The time to restore to, in milliseconds since the unix epoch.
+    #[clap(long)]
+    as_of: u64,
+
+    /// The number of concurrent shard restores, and of blob copies per shard,
+    /// to run at once.
+    #[clap(long, default_value_t = 16)]
+    concurrency: usize,
+}
+
 /// Runs the given read-write admin command.
 pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
     match command.command {
@@ -190,6 +296,100 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                 bail!("referenced blobs were not restored: {not_restored:#?}")
             }
         }
+        Command::ExportShard(args) => {
+            let ExportShardArgs {
+                state,
+                export_blob_uri,
+                concurrency,
+            } = args;
+            let shard_id = state.shard_id();
+            // The shard is only read, so there's no need to match its version.
+            let cfg = PersistConfig::new(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let consensus =
+                make_consensus(&cfg, &state.consensus_uri, false, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &state.blob_uri, false, Arc::clone(&metrics)).await?;
+            let dest =
+                make_blob(&cfg, &export_blob_uri, command.commit, Arc::clone(&metrics)).await?;
+            let versions = StateVersions::new(cfg, consensus, blob, metrics);
+            let seqno = export_shard(&versions, dest.as_ref(), shard_id, concurrency).await?;
+            info!("exported shard {shard_id} at {seqno} to {export_blob_uri}");
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::ImportShard(args) => {
+            let ImportShardArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                export_blob_uri,
+                export_shard_id,
+                export_seqno,
+                shard_id,
+                concurrency,
+            } = args;
+            let export_shard_id = ShardId::from_str(&export_shard_id)
+                .map_err(|err| anyhow!("invalid export shard id: {err}"))?;
+            let shard_id = match shard_id {
+                Some(shard_id) => ShardId::from_str(&shard_id)
+                    .map_err(|err| anyhow!("invalid shard id: {err}"))?,
+                None => ShardId::new(),
+            };
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, command.commit, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, command.commit, Arc::clone(&metrics)).await?;
+            let src = make_blob(&cfg, &export_blob_uri, false, Arc::clone(&metrics)).await?;
+            let versions = StateVersions::new(cfg, consensus, blob, Arc::clone(&metrics));
+            let seqno = import_shard(
+                &versions,
+                &metrics,
+                src.as_ref(),
+                export_shard_id,
+                export_seqno.map(SeqNo),
+                shard_id,
+                concurrency,
+            )
+            .await?;
+            info!("imported shard {export_shard_id} at {seqno} as {shard_id}");
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
+        Command::RestoreEnvironment(args) => {
+            let RestoreEnvironmentArgs {
+                state:
+                    StoreArgs {
+                        consensus_uri,
+                        blob_uri,
+                    },
+                to_consensus_uri,
+                to_blob_uri,
+                as_of,
+                concurrency,
+            } = args;
+            let metrics_registry = MetricsRegistry::new();
+            let restored = restore_environment_as_of(
+                &metrics_registry,
+                &consensus_uri,
+                &blob_uri,
+                &to_consensus_uri,
+                &to_blob_uri,
+                as_of,
+                concurrency,
+                command.commit,
+            )
+            .await?;
+            info!(
+                "restored {} shards as of {} to {}",
+                restored.len(),
+                as_of,
+                to_consensus_uri
+            );
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
     }
     Ok(())
 }
@@ -456,3 +656,480 @@ async fn force_gc(
 
     Ok(Box::new(machine))
 }
+
+/// The name under which an exported state is written, relative to its shard
+/// and followed by its [SeqNo].
+const EXPORTED_STATE_PREFIX: &str = "state-";
+
+/// The key of the state of `shard_id` exported at `seqno`.
+fn exported_state_key(shard_id: &ShardId, seqno: SeqNo) -> String {
+    format!("{}/{}{}", shard_id, EXPORTED_STATE_PREFIX, seqno)
+}
+
+/// Copies a consistent snapshot of `shard_id` into `dest`, returning the
+/// [SeqNo] of the state that was exported.
+///
+/// The snapshot consists of every batch part referenced by the current state,
+/// stored under the same keys as in the source blob, and then the state itself.
+/// The state is written last, so its presence means the export is complete.
+/// Parts are immutable, so those already present in `dest` from an earlier
+/// export of the same shard are not copied again.
+///
+/// This is read-only with respect to the source shard: instead of holding back
+/// compaction and GC with a reader, an export that races with the deletion of
+/// one of its parts simply retries against the new state.
+async fn export_shard(
+    versions: &StateVersions,
+    dest: &(dyn Blob + Send + Sync),
+    shard_id: ShardId,
+    concurrency: usize,
+) -> anyhow::Result<SeqNo> {
+    const MAX_ATTEMPTS: usize = 10;
+    let blob = versions.blob.as_ref();
+    let build_version = &versions.cfg.build_version;
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        let diffs = versions.fetch_recent_live_diffs::<u64>(&shard_id).await;
+        if diffs.0.is_empty() {
+            bail!("shard {shard_id} is not initialized");
+        }
+        let state = versions
+            .fetch_current_state::<u64>(&shard_id, diffs.0)
+            .await;
+        let seqno = state.seqno();
+        let mut buf = Vec::new();
+        Rollup::from_untyped_state_without_diffs(state)
+            .into_proto()
+            .encode(&mut buf)
+            .expect("no required fields means no initialization errors");
+        let buf = Bytes::from(buf);
+        let state = UntypedState::<u64>::decode(build_version, Bytes::clone(&buf))
+            .check_ts_codec(&shard_id)?;
+
+        let mut existing = BTreeSet::new();
+        dest.list_keys_and_metadata(&format!("{}/", shard_id), &mut |x| {
+            existing.insert(x.key.to_owned());
+        })
+        .await?;
+        let parts: Vec<_> = state
+            .collections
+            .trace
+            .batches()
+            .into_iter()
+            .flat_map(|b| b.parts.iter().map(|p| p.key.complete(&shard_id)))
+            .filter(|key| !existing.contains(key.as_str()))
+            .collect();
+        info!(
+            "attempt {}: exporting shard {} at {}, copying {} parts ({} already exported)",
+            attempt,
+            shard_id,
+            seqno,
+            parts.len(),
+            existing.len()
+        );
+
+        let copied: Vec<bool> = stream::iter(parts)
+            .map(|key| async move {
+                let Some(value) = blob.get(&key).await? else {
+                    return Ok::<_, anyhow::Error>(false);
+                };
+                let value = Bytes::from(value.into_contiguous());
+                dest.set(&key, value, Atomicity::RequireAtomic).await?;
+                Ok(true)
+            })
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await?;
+        if copied.contains(&false) {
+            info!(
+                "attempt {}: parts of shard {} at {} were deleted during export, retrying",
+                attempt, shard_id, seqno
+            );
+            continue;
+        }
+
+        dest.set(
+            &exported_state_key(&shard_id, seqno),
+            buf,
+            Atomicity::RequireAtomic,
+        )
+        .await?;
+        return Ok(seqno);
+    }
+    Err(anyhow!(
+        "could not export a consistent snapshot of shard {shard_id} in {MAX_ATTEMPTS} attempts"
+    ))
+}
+
+/// Restores the export of `src_shard_id` at `seqno` (or the latest export, if
+/// None) in `src` as the new shard `shard_id`, returning the [SeqNo] of the
+/// export that was restored.
+///
+/// The restored shard has the contents, since, and upper of the exported one,
+/// but none of its readers or writers. It refuses to overwrite a shard that
+/// already exists.
+async fn import_shard(
+    versions: &StateVersions,
+    metrics: &Metrics,
+    src: &(dyn Blob + Send + Sync),
+    src_shard_id: ShardId,
+    seqno: Option<SeqNo>,
+    shard_id: ShardId,
+    concurrency: usize,
+) -> anyhow::Result<SeqNo> {
+    let cfg = &versions.cfg;
+    let blob = versions.blob.as_ref();
+
+    let prefix = format!("{}/{}", src_shard_id, EXPORTED_STATE_PREFIX);
+    let mut exported = BTreeSet::new();
+    src.list_keys_and_metadata(&prefix, &mut |x| {
+        if let Ok(seqno) = SeqNo::from_str(&x.key[prefix.len()..]) {
+            exported.insert(seqno);
+        }
+    })
+    .await?;
+    let seqno = match seqno {
+        Some(seqno) if exported.contains(&seqno) => seqno,
+        Some(seqno) => bail!("no export of shard {src_shard_id} at {seqno}: {exported:?}"),
+        None => *exported
+            .last()
+            .ok_or_else(|| anyhow!("no export of shard {src_shard_id} found"))?,
+    };
+    let buf = src
+        .get(&exported_state_key(&src_shard_id, seqno))
+        .await?
+        .ok_or_else(|| anyhow!("export of shard {src_shard_id} at {seqno} disappeared"))?;
+    let buf = Bytes::from(buf.into_contiguous());
+
+    // Rebind the codec names of the placeholder K and V types, as in
+    // `persistcli inspect`, so the new state records the original codecs.
+    let mut rebound = false;
+    let exported = loop {
+        let state = UntypedState::<u64>::decode(&cfg.build_version, Bytes::clone(&buf));
+        match state
+            .check_codecs::<crate::cli::inspect::K, crate::cli::inspect::V, i64>(&src_shard_id)
+        {
+            Ok(state) => break state,
+            Err(codec) if !rebound => {
+                *crate::cli::inspect::KVTD_CODECS.lock().expect("lockable") = codec.actual;
+                rebound = true;
+            }
+            Err(codec) => bail!("unsupported codecs in export of shard {src_shard_id}: {codec}"),
+        }
+    };
+
+    if !versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await
+        .0
+        .is_empty()
+    {
+        bail!("shard {shard_id} already exists");
+    }
+
+    let parts: Vec<PartialBatchKey> = exported
+        .collections
+        .trace
+        .batches()
+        .into_iter()
+        .flat_map(|b| b.parts.iter().map(|p| p.key.clone()))
+        .collect();
+    info!(
+        "importing shard {} at {} as {}, copying {} parts",
+        src_shard_id,
+        seqno,
+        shard_id,
+        parts.len()
+    );
+    // Part keys in state are relative to the shard, so the copies are
+    // referenced by the restored state as-is.
+    let () = stream::iter(parts)
+        .map(|key| async move {
+            let value = src
+                .get(&key.complete(&src_shard_id))
+                .await?
+                .ok_or_else(|| anyhow!("export of shard {src_shard_id} is missing part {key}"))?;
+            let value = Bytes::from(value.into_contiguous());
+            blob.set(&key.complete(&shard_id), value, Atomicity::RequireAtomic)
+                .await?;
+            Ok::<_, anyhow::Error>(())
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
+
+    // Mirrors initializing a new shard, except that the initial state starts
+    // out with the exported trace.
+    let shard_metrics = metrics.shards.shard(&shard_id, "import");
+    let empty_state = TypedState::<crate::cli::inspect::K, crate::cli::inspect::V, u64, i64>::new(
+        cfg.build_version.clone(),
+        shard_id,
+        cfg.hostname.clone(),
+        (cfg.now)(),
+    );
+    let rollup_seqno = empty_state.seqno.next();
+    let rollup = HollowRollup {
+        key: PartialRollupKey::new(rollup_seqno, &RollupId::new()),
+        encoded_size_bytes: None,
+    };
+    let trace = &exported.collections.trace;
+    let (_, initial_state) = match empty_state.clone_apply(cfg, &mut |_, _, state| {
+        state.trace = trace.clone();
+        state.add_rollup((rollup_seqno, &rollup))
+    }) {
+        Continue(x) => x,
+        Break(NoOpStateTransition(_)) => {
+            panic!("initial state transition should not be a no-op")
+        }
+    };
+    let rollup = versions.encode_rollup_blob(
+        &shard_metrics,
+        initial_state.clone_for_rollup(),
+        vec![],
+        rollup.key,
+    );
+    let () = versions.write_rollup_blob(&rollup).await;
+    let diff = StateDiff::from_diff(&empty_state.state, &initial_state.state);
+    let (cas_res, _) = versions
+        .try_compare_and_set_current("import_shard", &shard_metrics, None, &initial_state, &diff)
+        .await?;
+    match cas_res {
+        CaSResult::Committed => Ok(seqno),
+        CaSResult::ExpectationMismatch => {
+            bail!("shard {shard_id} was concurrently initialized by someone else")
+        }
+    }
+}
+
+/// Restores every shard of the environment at `consensus_uri` and `blob_uri`
+/// as of `as_of_walltime_ms` into `to_consensus_uri` and `to_blob_uri`,
+/// returning the [SeqNo] each was restored at.
+///
+/// Nothing is written (besides undeleting blobs) unless `commit` is set. See
+/// `persistcli admin restore-environment` for what exactly is restored.
+#[allow(clippy::too_many_arguments)]
+pub async fn restore_environment_as_of(
+    metrics_registry: &MetricsRegistry,
+    consensus_uri: &str,
+    blob_uri: &str,
+    to_consensus_uri: &str,
+    to_blob_uri: &str,
+    as_of_walltime_ms: u64,
+    concurrency: usize,
+    commit: bool,
+) -> anyhow::Result<BTreeMap<ShardId, SeqNo>> {
+    // The environment is only read (save for undeleting blobs), so there's no
+    // need to match its version.
+    let cfg = PersistConfig::new(&READ_ALL_BUILD_INFO, SYSTEM_TIME.clone());
+    let metrics = Arc::new(Metrics::new(&cfg, metrics_registry));
+    let consensus = make_consensus(&cfg, consensus_uri, false, Arc::clone(&metrics)).await?;
+    let blob = make_blob(&cfg, blob_uri, commit, Arc::clone(&metrics)).await?;
+    let dest_consensus =
+        make_consensus(&cfg, to_consensus_uri, commit, Arc::clone(&metrics)).await?;
+    let dest_blob = make_blob(&cfg, to_blob_uri, commit, Arc::clone(&metrics)).await?;
+    let versions = StateVersions::new(cfg, consensus, blob, metrics);
+    restore_environment(
+        &versions,
+        dest_consensus.as_ref(),
+        dest_blob.as_ref(),
+        as_of_walltime_ms,
+        concurrency,
+    )
+    .await
+}
+
+/// Restores every shard in `versions` as of `as_of_walltime_ms` into
+/// `dest_consensus` and `dest_blob`, returning the [SeqNo] each was restored
+/// at. Shards that didn't exist yet at that time are skipped.
+async fn restore_environment(
+    versions: &StateVersions,
+    dest_consensus: &(dyn Consensus + Send + Sync),
+    dest_blob: &(dyn Blob + Send + Sync),
+    as_of_walltime_ms: u64,
+    concurrency: usize,
+) -> anyhow::Result<BTreeMap<ShardId, SeqNo>> {
+    let shards: Vec<String> = versions.consensus.list_keys().try_collect().await?;
+    info!(
+        "restoring {} shards as of {}",
+        shards.len(),
+        as_of_walltime_ms
+    );
+    let restored: Vec<(ShardId, Option<SeqNo>)> = stream::iter(shards)
+        .map(|shard_id| async move {
+            let shard_id = ShardId::from_str(&shard_id)
+                .map_err(|err| anyhow!("invalid shard id in consensus: {err}"))?;
+            let seqno = crate::internal::restore::restore_shard_as_of(
+                versions,
+                dest_consensus,
+                dest_blob,
+                shard_id,
+                as_of_walltime_ms,
+                concurrency,
+            )
+            .await?;
+            Ok::<_, anyhow::Error>((shard_id, seqno))
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
+    Ok(restored
+        .into_iter()
+        .filter_map(|(shard_id, seqno)| seqno.map(|seqno| (shard_id, seqno)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use mz_persist::mem::{MemBlob, MemBlobConfig, MemConsensus};
+    use timely::progress::Antichain;
+
+    use crate::cfg::PersistParameters;
+    use crate::tests::{all_ok, new_test_client};
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn export_import_shard() {
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+        ];
+
+        let client = new_test_client().await;
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write
+            .expect_append(&data, write.upper().clone(), vec![4])
+            .await;
+
+        let versions = StateVersions::new(
+            client.cfg.clone(),
+            Arc::clone(&client.consensus),
+            Arc::clone(&client.blob),
+            Arc::clone(&client.metrics),
+        );
+        let backup = MemBlob::open(MemBlobConfig::default());
+        let seqno = export_shard(&versions, &backup, shard_id, 2)
+            .await
+            .expect("export succeeds");
+
+        let new_shard_id = ShardId::new();
+        let imported = import_shard(
+            &versions,
+            &client.metrics,
+            &backup,
+            shard_id,
+            None,
+            new_shard_id,
+            2,
+        )
+        .await
+        .expect("import succeeds");
+        assert_eq!(imported, seqno);
+
+        // The restored shard has the same contents and frontiers, and is
+        // independent of the original.
+        let (new_write, mut new_read) = client
+            .expect_open::<String, String, u64, i64>(new_shard_id)
+            .await;
+        assert_eq!(new_write.upper(), &Antichain::from_elem(4));
+        assert_eq!(
+            new_read.expect_snapshot_and_fetch(3).await,
+            all_ok(&data, 3)
+        );
+        write
+            .expect_append(&data[..1], write.upper().clone(), vec![5])
+            .await;
+        assert_eq!(new_write.upper(), &Antichain::from_elem(4));
+
+        // Imports never clobber an existing shard.
+        let res = import_shard(
+            &versions,
+            &client.metrics,
+            &backup,
+            shard_id,
+            Some(seqno),
+            new_shard_id,
+            2,
+        )
+        .await;
+        assert!(res.is_err());
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn restore_environment_as_of() {
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+        ];
+
+        let client = new_test_client().await;
+        PersistParameters {
+            state_history_retention: Some(Duration::from_secs(60 * 60)),
+            ..Default::default()
+        }
+        .apply(&client.cfg);
+        let shard_id = ShardId::new();
+        let (mut write, _read) = client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        write
+            .expect_append(&data[..1], write.upper().clone(), vec![2])
+            .await;
+
+        // Make sure every version of state is on one side of `as_of` or the
+        // other.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let as_of = (client.cfg.now)();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        write
+            .expect_append(&data[1..], write.upper().clone(), vec![3])
+            .await;
+        let later_shard_id = ShardId::new();
+        let _later = client
+            .expect_open::<String, String, u64, i64>(later_shard_id)
+            .await;
+
+        let versions = StateVersions::new(
+            client.cfg.clone(),
+            Arc::clone(&client.consensus),
+            Arc::clone(&client.blob),
+            Arc::clone(&client.metrics),
+        );
+        let dest_consensus = Arc::new(MemConsensus::default());
+        let dest_blob = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let restored = restore_environment(
+            &versions,
+            dest_consensus.as_ref(),
+            dest_blob.as_ref(),
+            as_of,
+            2,
+        )
+        .await
+        .expect("restore succeeds");
+        // The shard created after `as_of` doesn't exist in the new environment.
+        assert_eq!(restored.keys().collect::<Vec<_>>(), vec![&shard_id]);
+
+        // The restored shard is the original as of `as_of`.
+        let mut new_client = client.clone();
+        new_client.consensus = dest_consensus;
+        new_client.blob = dest_blob;
+        new_client.shared_states = Arc::new(StateCache::new_no_metrics());
+        let (new_write, mut new_read) = new_client
+            .expect_open::<String, String, u64, i64>(shard_id)
+            .await;
+        assert_eq!(new_write.upper(), &Antichain::from_elem(2));
+        assert_eq!(
+            new_read.expect_snapshot_and_fetch(1).await,
+            all_ok(&data[..1], 1)
+        );
+    }
+}
@@ -0,0 +1,501 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A size-bounded, on-disk LRU cache of batch parts beneath [Blob].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::bytes::SegmentedBytes;
+use mz_ore::cast::CastFrom;
+use mz_persist::location::{Atomicity, Blob, BlobMetadata, ExternalError};
+use tracing::{debug, warn};
+
+use crate::cfg::DynamicConfig;
+use crate::internal::metrics::BlobCacheMetrics;
+use crate::internal::paths::{BlobKey, PartialBlobKey};
+
+/// An implementation of [Blob] that keeps a local copy of batch parts read
+/// from an underlying [Blob].
+///
+/// Batch parts are immutable once written and their keys are globally unique,
+/// so a cached copy never goes stale and the cache can outlive the process
+/// (e.g. across replica restarts) and be shared by every reader of every shard
+/// in it. Everything else (rollups, listing, writes) passes straight through.
+///
+/// The cache is bounded by [DynamicConfig::blob_cache_disk_limit_bytes],
+/// evicting the least recently read parts first. A limit of 0 disables it.
+/// Cache failures are never surfaced to callers: a part that can't be read
+/// from disk is simply fetched again.
+#[derive(Debug)]
+pub struct DiskCacheBlob {
+    blob: Arc<dyn Blob + Send + Sync>,
+    cache: Arc<DiskCache>,
+}
+
+impl DiskCacheBlob {
+    /// Returns a new [DiskCacheBlob] in front of `blob`, storing parts in
+    /// `dir`.
+    ///
+    /// Parts left in `dir` by a previous process are adopted, ordered by
+    /// modification time. Nothing is evicted until the next insert, so that
+    /// opening with the default config (before any dynamic config has been
+    /// applied) doesn't throw away a warm cache.
+    pub async fn open(
+        dir: PathBuf,
+        blob: Arc<dyn Blob + Send + Sync>,
+        cfg: Arc<DynamicConfig>,
+        metrics: BlobCacheMetrics,
+    ) -> Result<Self, ExternalError> {
+        let scan_dir = dir.clone();
+        let existing = mz_ore::task::spawn_blocking(
+            || "persist::disk_cache::open",
+            move || DiskCache::scan(&scan_dir),
+        )
+        .await??;
+
+        let mut state = DiskCacheState::default();
+        for (name, size) in existing {
+            state.insert(name, size);
+        }
+        debug!(
+            "opened persist disk cache at {} with {} parts ({} bytes)",
+            dir.display(),
+            state.entries.len(),
+            state.size_bytes
+        );
+        let cache = DiskCache {
+            dir,
+            cfg,
+            metrics,
+            state: Mutex::new(state),
+        };
+        cache.update_gauges(&cache.state.lock().expect("lock poisoned"));
+        Ok(DiskCacheBlob {
+            blob,
+            cache: Arc::new(cache),
+        })
+    }
+
+    /// Returns the name of the cache file for `key`, or None if `key` isn't
+    /// cacheable.
+    fn cache_name(key: &str) -> Option<String> {
+        match BlobKey::parse_ids(key) {
+            // Only batch parts are cached: rollups are read once on startup
+            // and then only by the occasional stale reader.
+            Ok((_, PartialBlobKey::Batch(_, _))) => Some(key.replace('/', "_")),
+            Ok((_, PartialBlobKey::Rollup(_, _))) | Err(_) => None,
+        }
+    }
+}
+
+#[async_trait]
+impl Blob for DiskCacheBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let name = match Self::cache_name(key) {
+            Some(name) if self.cache.cfg.blob_cache_disk_limit_bytes() > 0 => name,
+            _ => return self.blob.get(key).await,
+        };
+
+        if let Some(value) = self.cache.get(&name).await {
+            return Ok(Some(value));
+        }
+        let value = self.blob.get(key).await?;
+        if let Some(value) = value.as_ref() {
+            self.cache.insert_background(name, value.clone());
+        }
+        Ok(value)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes, atomic: Atomicity) -> Result<(), ExternalError> {
+        // Keys are never rewritten with different contents, but be defensive
+        // and make sure we don't serve a previous value.
+        if let Some(name) = Self::cache_name(key) {
+            self.cache.remove(&name).await;
+        }
+        self.blob.set(key, value, atomic).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        if let Some(name) = Self::cache_name(key) {
+            self.cache.remove(&name).await;
+        }
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+#[derive(Debug)]
+struct DiskCache {
+    dir: PathBuf,
+    cfg: Arc<DynamicConfig>,
+    metrics: BlobCacheMetrics,
+    state: Mutex<DiskCacheState>,
+}
+
+impl DiskCache {
+    const TMP_EXTENSION: &'static str = "tmp";
+
+    /// Returns the name and size of every part in `dir`, least recently
+    /// modified first, cleaning up any partial writes.
+    fn scan(dir: &Path) -> Result<Vec<(String, usize)>, std::io::Error> {
+        std::fs::create_dir_all(dir)?;
+        let mut parts = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+            if path.extension().and_then(|x| x.to_str()) == Some(Self::TMP_EXTENSION) {
+                let _ = std::fs::remove_file(&path);
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|x| x.to_str()) else {
+                continue;
+            };
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            parts.push((modified, name.to_owned(), usize::cast_from(metadata.len())));
+        }
+        parts.sort();
+        Ok(parts
+            .into_iter()
+            .map(|(_, name, size)| (name, size))
+            .collect())
+    }
+
+    async fn get(&self, name: &str) -> Option<SegmentedBytes> {
+        if !self.state.lock().expect("lock poisoned").touch(name) {
+            return None;
+        }
+        let path = self.dir.join(name);
+        let res = mz_ore::task::spawn_blocking(
+            || "persist::disk_cache::get",
+            move || std::fs::read(path),
+        )
+        .await;
+        match res {
+            Ok(Ok(buf)) => {
+                self.metrics.hits_blobs.inc();
+                self.metrics.hits_bytes.inc_by(u64::cast_from(buf.len()));
+                Some(SegmentedBytes::from(buf))
+            }
+            Ok(Err(err)) if err.kind() == ErrorKind::NotFound => {
+                // Raced with an eviction.
+                None
+            }
+            Ok(Err(err)) => {
+                warn!("failed to read {} from persist disk cache: {}", name, err);
+                self.remove(name).await;
+                None
+            }
+            Err(err) => {
+                warn!("failed to read {} from persist disk cache: {}", name, err);
+                None
+            }
+        }
+    }
+
+    /// Writes `value` to the cache without blocking the caller, evicting as
+    /// necessary to stay under the limit.
+    fn insert_background(self: &Arc<Self>, name: String, value: SegmentedBytes) {
+        let limit = self.cfg.blob_cache_disk_limit_bytes();
+        if value.len() > limit {
+            return;
+        }
+        {
+            let mut state = self.state.lock().expect("lock poisoned");
+            // Another reader may have beaten us to it.
+            if state.entries.contains_key(&name) || !state.pending.insert(name.clone()) {
+                return;
+            }
+        }
+
+        let cache = Arc::clone(self);
+        mz_ore::task::spawn_blocking(
+            || "persist::disk_cache::insert",
+            move || {
+                let size = value.len();
+                let path = cache.dir.join(&name);
+                if let Err(err) = Self::write(&path, value) {
+                    warn!("failed to write {} to persist disk cache: {}", name, err);
+                    cache
+                        .state
+                        .lock()
+                        .expect("lock poisoned")
+                        .pending
+                        .remove(&name);
+                    return;
+                }
+
+                let evicted = {
+                    let mut state = cache.state.lock().expect("lock poisoned");
+                    if !state.pending.remove(&name) {
+                        // The blob was deleted while we were writing it.
+                        drop(state);
+                        let _ = std::fs::remove_file(&path);
+                        return;
+                    }
+                    state.insert(name, size);
+                    let evicted = state.evict(cache.cfg.blob_cache_disk_limit_bytes());
+                    cache.update_gauges(&state);
+                    evicted
+                };
+                cache
+                    .metrics
+                    .evictions
+                    .inc_by(u64::cast_from(evicted.len()));
+                for name in evicted {
+                    if let Err(err) = std::fs::remove_file(cache.dir.join(&name)) {
+                        if err.kind() != ErrorKind::NotFound {
+                            warn!("failed to evict {} from persist disk cache: {}", name, err);
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    /// Writes `value` to `path` such that a crash never leaves a partial part
+    /// behind under its real name.
+    fn write(path: &Path, value: SegmentedBytes) -> Result<(), std::io::Error> {
+        use std::io::Write;
+
+        let tmp_path = path.with_extension(Self::TMP_EXTENSION);
+        let mut file = std::fs::File::create(&tmp_path)?;
+        for segment in value.into_segments() {
+            file.write_all(&segment)?;
+        }
+        drop(file);
+        std::fs::rename(&tmp_path, path)
+    }
+
+    async fn remove(&self, name: &str) {
+        let removed = {
+            let mut state = self.state.lock().expect("lock poisoned");
+            state.pending.remove(name);
+            let removed = state.remove(name);
+            if removed {
+                self.update_gauges(&state);
+            }
+            removed
+        };
+        if removed {
+            let path = self.dir.join(name);
+            let _ = mz_ore::task::spawn_blocking(
+                || "persist::disk_cache::remove",
+                move || std::fs::remove_file(path),
+            )
+            .await;
+        }
+    }
+
+    fn update_gauges(&self, state: &DiskCacheState) {
+        self.metrics
+            .size_blobs
+            .set(u64::cast_from(state.entries.len()));
+        self.metrics
+            .size_bytes
+            .set(u64::cast_from(state.size_bytes));
+    }
+}
+
+/// The in-memory index of what's in a [DiskCache].
+#[derive(Debug, Default)]
+struct DiskCacheState {
+    /// Cached parts by file name, with their size and last access.
+    entries: BTreeMap<String, (usize, u64)>,
+    /// Cached parts by last access, for eviction.
+    lru: BTreeMap<u64, String>,
+    /// Parts currently being written.
+    pending: BTreeSet<String>,
+    next_access: u64,
+    size_bytes: usize,
+}
+
+impl DiskCacheState {
+    fn next_access(&mut self) -> u64 {
+        let access = self.next_access;
+        self.next_access += 1;
+        access
+    }
+
+    /// Marks `name` as most recently used, returning whether it's cached.
+    fn touch(&mut self, name: &str) -> bool {
+        let access = self.next_access();
+        let Some((_, prev)) = self.entries.get_mut(name) else {
+            return false;
+        };
+        let name = self.lru.remove(prev).expect("entries and lru in sync");
+        *prev = access;
+        self.lru.insert(access, name);
+        true
+    }
+
+    fn insert(&mut self, name: String, size: usize) {
+        self.remove(&name);
+        let access = self.next_access();
+        self.entries.insert(name.clone(), (size, access));
+        self.lru.insert(access, name);
+        self.size_bytes += size;
+    }
+
+    fn remove(&mut self, name: &str) -> bool {
+        let Some((size, access)) = self.entries.remove(name) else {
+            return false;
+        };
+        self.lru.remove(&access);
+        self.size_bytes -= size;
+        true
+    }
+
+    /// Drops least recently used parts until the cache fits in `limit` bytes,
+    /// returning the names of the dropped parts.
+    fn evict(&mut self, limit: usize) -> Vec<String> {
+        let mut evicted = Vec::new();
+        while self.size_bytes > limit {
+            let Some((_, name)) = self.lru.pop_first() else {
+                break;
+            };
+            let (size, _) = self.entries.remove(&name).expect("entries and lru in sync");
+            self.size_bytes -= size;
+            evicted.push(name);
+        }
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
+
+    use crate::cfg::PersistParameters;
+    use crate::internal::metrics::Metrics;
+    use crate::internal::paths::{PartId, PartialBatchKey, WriterKey};
+    use crate::{PersistConfig, ShardId};
+
+    use super::*;
+
+    fn batch_key(shard_id: &ShardId) -> String {
+        let writer_key = WriterKey::for_version(&semver::Version::new(0, 0, 0));
+        PartialBatchKey::new(&writer_key, &PartId::new())
+            .complete(shard_id)
+            .to_string()
+    }
+
+    async fn open(
+        dir: &Path,
+        blob: &Arc<dyn Blob + Send + Sync>,
+        cfg: &PersistConfig,
+    ) -> DiskCacheBlob {
+        let metrics = Metrics::new(cfg, &MetricsRegistry::new());
+        DiskCacheBlob::open(
+            dir.to_owned(),
+            Arc::clone(blob),
+            Arc::clone(&cfg.dynamic),
+            metrics.blob_cache_disk,
+        )
+        .await
+        .expect("cache opens")
+    }
+
+    /// Waits for background inserts to land.
+    async fn wait_for_size(cache: &DiskCacheBlob, expected: usize) {
+        for _ in 0..1000 {
+            let state = cache.cache.state.lock().expect("lock poisoned");
+            if state.pending.is_empty() && state.entries.len() == expected {
+                return;
+            }
+            drop(state);
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+        }
+        panic!("cache never reached {} entries", expected);
+    }
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn disk_cache() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cfg = PersistConfig::new_for_tests();
+        PersistParameters {
+            blob_cache_disk_limit_bytes: Some(10),
+            ..Default::default()
+        }
+        .apply(&cfg);
+        let blob: Arc<dyn Blob + Send + Sync> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let cache = open(dir.path(), &blob, &cfg).await;
+
+        let shard_id = ShardId::new();
+        let (k1, k2, k3) = (
+            batch_key(&shard_id),
+            batch_key(&shard_id),
+            batch_key(&shard_id),
+        );
+        for key in [&k1, &k2, &k3] {
+            cache
+                .set(key, Bytes::from(vec![1u8; 4]), Atomicity::RequireAtomic)
+                .await
+                .expect("set");
+        }
+
+        // Reads go through to blob and populate the cache, evicting the least
+        // recently read part once over the limit.
+        for key in [&k1, &k2] {
+            assert_eq!(cache.get(key).await.expect("get").map(|x| x.len()), Some(4));
+        }
+        wait_for_size(&cache, 2).await;
+        let _ = cache.get(&k1).await.expect("get");
+        let _ = cache.get(&k3).await.expect("get");
+        wait_for_size(&cache, 2).await;
+        {
+            let state = cache.cache.state.lock().expect("lock poisoned");
+            assert!(state
+                .entries
+                .contains_key(&DiskCacheBlob::cache_name(&k1).unwrap()));
+            assert!(!state
+                .entries
+                .contains_key(&DiskCacheBlob::cache_name(&k2).unwrap()));
+            assert!(state
+                .entries
+                .contains_key(&DiskCacheBlob::cache_name(&k3).unwrap()));
+        }
+
+        // Once cached, reads are served even if the underlying blob is gone.
+        blob.delete(&k1).await.expect("delete");
+        assert_eq!(cache.get(&k1).await.expect("get").map(|x| x.len()), Some(4));
+
+        // A restarted cache picks up where the last one left off.
+        drop(cache);
+        let cache = open(dir.path(), &blob, &cfg).await;
+        assert_eq!(cache.get(&k1).await.expect("get").map(|x| x.len()), Some(4));
+
+        // Deleting through the cache removes the cached copy.
+        assert_eq!(cache.delete(&k3).await.expect("delete"), Some(4));
+        assert!(cache.get(&k3).await.expect("get").is_none());
+        wait_for_size(&cache, 1).await;
+    }
+}
@@ -13,7 +13,7 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::mem;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use differential_dataflow::difference::Semigroup;
 use differential_dataflow::lattice::Lattice;
@@ -36,6 +36,7 @@ use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{GcStepTimings, RetryMetrics};
 use crate::internal::paths::{BlobKey, PartialBatchKey, PartialBlobKey, PartialRollupKey};
 use crate::internal::state::HollowBlobRef;
+use crate::internal::state_diff::StateDiff;
 use crate::internal::state_versions::{InspectDiff, StateVersionsIter};
 use crate::ShardId;
 
@@ -246,8 +247,14 @@ where
 
         // First, check the latest known state to this process to see
         // if there's relevant GC work for this seqno_since
-        let gc_rollups =
+        let mut gc_rollups =
             GcRollups::new(machine.applier.rollups_lte_seqno(req.new_seqno_since), &req);
+        let history_retention = machine.applier.cfg.dynamic.state_history_retention();
+        if history_retention > Duration::ZERO
+            && !gc_rollups.rollups_to_remove_from_state().is_empty()
+        {
+            gc_rollups = Self::retain_history(machine, gc_rollups, &req, history_retention).await;
+        }
         let rollups_to_remove_from_state = gc_rollups.rollups_to_remove_from_state();
         report_step_timing(&machine.applier.metrics.gc.steps.find_removable_rollups);
 
@@ -389,6 +396,61 @@ where
         (maintenance, gc_results)
     }
 
+    /// Filters `gc_rollups` down to the ones we may truncate to without losing
+    /// any version of state written within `retention` of now.
+    ///
+    /// Truncating to a rollup discards every version before it, so a rollup is
+    /// only eligible if it was itself written before the retention cutoff. This
+    /// keeps every version in the window, plus the one that was current as of
+    /// the cutoff, which is what's needed to recover the shard as of any time
+    /// in the window.
+    ///
+    /// Walltimes strictly increase with seqno, so the eligible rollups are a
+    /// prefix of the rollups `<=` seqno_since. We binary search for its end,
+    /// which only fetches the handful of diffs we probe, rather than scanning
+    /// the (by design, long) history of the shard on every run.
+    async fn retain_history(
+        machine: &Machine<K, V, T, D>,
+        gc_rollups: GcRollups,
+        req: &GcReq,
+        retention: Duration,
+    ) -> GcRollups {
+        let retention_ms = u64::try_from(retention.as_millis()).unwrap_or(u64::MAX);
+        let cutoff_ms = (machine.applier.cfg.now)().saturating_sub(retention_ms);
+        let mut rollups = gc_rollups.rollups_lte_seqno_since;
+        let (mut lo, mut hi) = (0, rollups.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (seqno, _rollup) = &rollups[mid];
+            let diff = machine
+                .applier
+                .state_versions
+                .fetch_live_diff_at_seqno(&req.shard_id, *seqno)
+                .await;
+            let eligible = match diff {
+                Some(diff) => {
+                    let diff =
+                        StateDiff::<T>::decode(&machine.applier.cfg.build_version, diff.data);
+                    diff.walltime_ms <= cutoff_ms
+                }
+                // Rollups before the earliest live diff have already been
+                // truncated past, so they can't hold anything back.
+                None => true,
+            };
+            if eligible {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        rollups.truncate(lo);
+        debug!(
+            "gc seqno_since: ({}) retaining history newer than walltime {}",
+            req.new_seqno_since, cutoff_ms
+        );
+        GcRollups::new(rollups, req)
+    }
+
     /// Physically deletes all blobs from Blob and live diffs from Consensus that
     /// are safe to delete, given the `seqno_since`, ensuring that the earliest
     /// live diff in Consensus has a rollup of seqno `<= seqno_since`.
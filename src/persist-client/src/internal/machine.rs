@@ -2086,6 +2086,7 @@ pub mod datadriven {
 #[cfg(test)]
 pub mod tests {
     use std::sync::Arc;
+    use std::time::Duration;
 
     use crate::cache::StateCache;
     use mz_ore::cast::CastFrom;
@@ -2094,9 +2095,11 @@ pub mod tests {
     use mz_persist::location::SeqNo;
     use timely::progress::Antichain;
 
+    use crate::cfg::PersistParameters;
     use crate::internal::gc::{GarbageCollector, GcReq};
     use crate::internal::state::HandleDebugState;
     use crate::tests::new_test_client;
+    use crate::write::WriteHandle;
     use crate::ShardId;
 
     #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
@@ -2153,6 +2156,86 @@ pub mod tests {
         );
     }
 
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // error: unsupported operation: integer-to-pointer casts and `ptr::from_exposed_addr` are not supported with `-Zmiri-strict-provenance`
+    async fn gc_state_history_retention() {
+        mz_ore::test::init_logging();
+
+        let client = new_test_client().await;
+        client.cfg.dynamic.set_rollup_threshold(5);
+        PersistParameters {
+            state_history_retention: Some(Duration::from_secs(60 * 60)),
+            ..Default::default()
+        }
+        .apply(&client.cfg);
+        let (mut write, _) = client
+            .expect_open::<String, (), u64, i64>(ShardId::new())
+            .await;
+
+        async fn write_batches(
+            write: &mut WriteHandle<String, (), u64, i64>,
+            lower: u64,
+            upper: u64,
+        ) {
+            for idx in lower..upper {
+                let batch = write
+                    .expect_batch(&[((idx.to_string(), ()), idx, 1)], idx, idx + 1)
+                    .await;
+                let (_, writer_maintenance) = write
+                    .machine
+                    .compare_and_append(
+                        &batch.into_hollow_batch(),
+                        &write.writer_id,
+                        &HandleDebugState::default(),
+                        (write.cfg.now)(),
+                    )
+                    .await
+                    .expect("invalid usage")
+                    .expect("unexpected upper");
+                writer_maintenance
+                    .perform(&write.machine, &write.gc, write.compact.as_ref())
+                    .await;
+            }
+        }
+
+        // Everything was written within the retention window, so GC must not
+        // have truncated any versions of state.
+        const NUM_BATCHES: u64 = 100;
+        write_batches(&mut write, 0, NUM_BATCHES).await;
+        let live_diffs = write
+            .machine
+            .applier
+            .state_versions
+            .fetch_all_live_diffs(&write.machine.shard_id())
+            .await;
+        assert!(
+            live_diffs.0.len() > usize::cast_from(NUM_BATCHES),
+            "{} vs {}",
+            live_diffs.0.len(),
+            NUM_BATCHES
+        );
+
+        // Once retention is turned off, GC catches back up.
+        PersistParameters {
+            state_history_retention: Some(Duration::ZERO),
+            ..Default::default()
+        }
+        .apply(&client.cfg);
+        write_batches(&mut write, NUM_BATCHES, NUM_BATCHES + 10).await;
+        let live_diffs = write
+            .machine
+            .applier
+            .state_versions
+            .fetch_all_live_diffs(&write.machine.shard_id())
+            .await;
+        assert!(
+            live_diffs.0.len() < usize::cast_from(NUM_BATCHES),
+            "{} vs {}",
+            live_diffs.0.len(),
+            NUM_BATCHES
+        );
+    }
+
     // A regression test for #14719, where a bug in gc led to an incremental
     // state invariant being violated which resulted in gc being permanently
     // wedged for the shard.
@@ -89,8 +89,10 @@ pub struct Metrics {
     pub pushdown: PushdownMetrics,
     /// Metrics for consolidation.
     pub consolidation: ConsolidationMetrics,
-    /// Metrics for blob caching.
-    pub blob_cache_mem: BlobMemCache,
+    /// Metrics for the in-memory blob cache.
+    pub blob_cache_mem: BlobCacheMetrics,
+    /// Metrics for the on-disk blob cache.
+    pub blob_cache_disk: BlobCacheMetrics,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
 
@@ -144,7 +146,8 @@ impl Metrics {
             pubsub_client: PubSubClientMetrics::new(registry),
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
-            blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_mem: BlobCacheMetrics::new(registry, "mem"),
+            blob_cache_disk: BlobCacheMetrics::new(registry, "disk"),
             tasks: TasksMetrics::new(registry),
             sink: SinkMetrics::new(registry),
             s3_blob: S3BlobMetrics::new(registry),
@@ -670,6 +673,7 @@ pub struct ReadMetrics {
 pub struct BatchWriteMetrics {
     pub(crate) bytes: IntCounter,
     pub(crate) goodbytes: IntCounter,
+    pub(crate) compression_ratio: HistogramVec,
     pub(crate) seconds: Counter,
     pub(crate) write_stalls: IntCounter,
 
@@ -690,6 +694,15 @@ impl BatchWriteMetrics {
                 name: format!("mz_persist_{}_goodbytes", name),
                 help: format!("total logical size of {} batches written", name),
             )),
+            compression_ratio: registry.register(metric!(
+                name: format!("mz_persist_{}_compression_ratio", name),
+                help: format!(
+                    "histogram of the ratio of logical to encoded size of {} batch parts written",
+                    name
+                ),
+                var_labels: ["compression"],
+                buckets: vec![0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 32.0],
+            )),
             seconds: registry.register(metric!(
                 name: format!("mz_persist_{}_write_batch_part_seconds", name),
                 help: format!("time spent writing {} batches", name),
@@ -2061,9 +2074,8 @@ impl ConsolidationMetrics {
     }
 }
 
-#[derive(Debug)]
-#[allow(dead_code)] // TODO: Remove this when we reintroduce the cache.
-pub struct BlobMemCache {
+#[derive(Debug, Clone)]
+pub struct BlobCacheMetrics {
     pub(crate) size_blobs: UIntGauge,
     pub(crate) size_bytes: UIntGauge,
     pub(crate) hits_blobs: IntCounter,
@@ -2071,33 +2083,33 @@ pub struct BlobMemCache {
     pub(crate) evictions: IntCounter,
 }
 
-impl BlobMemCache {
-    fn new(registry: &MetricsRegistry) -> Self {
-        BlobMemCache {
+impl BlobCacheMetrics {
+    fn new(registry: &MetricsRegistry, cache: &str) -> Self {
+        BlobCacheMetrics {
             size_blobs: registry.register(metric!(
                 name: "mz_persist_blob_cache_size_blobs",
                 help: "count of blobs in the cache",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             size_bytes: registry.register(metric!(
                 name: "mz_persist_blob_cache_size_bytes",
                 help: "total size of blobs in the cache",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             hits_blobs: registry.register(metric!(
                 name: "mz_persist_blob_cache_hits_blobs",
                 help: "count of blobs served via cache instead of s3",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             hits_bytes: registry.register(metric!(
                 name: "mz_persist_blob_cache_hits_bytes",
                 help: "total size of blobs served via cache instead of s3",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
             evictions: registry.register(metric!(
                 name: "mz_persist_blob_cache_evictions",
                 help: "count of capacity-based cache evictions",
-                const_labels: {"cache" => "mem"},
+                const_labels: {"cache" => cache},
             )),
         }
     }
@@ -7,7 +7,9 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! See documentation on [[restore_blob]].
+//! See documentation on [[restore_blob]] and [[restore_shard_as_of]].
+
+use std::collections::BTreeSet;
 
 use crate::internal::encoding::UntypedState;
 use crate::internal::paths::BlobKey;
@@ -15,8 +17,10 @@ use crate::internal::state::State;
 use crate::internal::state_diff::{StateDiff, StateFieldValDiff};
 use crate::internal::state_versions::StateVersions;
 use crate::ShardId;
-use anyhow::anyhow;
-use mz_persist::location::Blob;
+use anyhow::{anyhow, bail};
+use bytes::Bytes;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use mz_persist::location::{Atomicity, Blob, CaSResult, Consensus, SeqNo, VersionedData};
 use tracing::info;
 
 /// Attempt to restore all the blobs referenced by the current state in consensus.
@@ -102,3 +106,131 @@ pub(crate) async fn restore_blob(
     }
     Ok(not_restored)
 }
+
+/// Copies `shard_id` as of `as_of_walltime_ms` from `versions` into
+/// `dest_consensus` and `dest_blob`, returning the [SeqNo] of the restored
+/// state, or None if the shard did not exist yet at that time.
+///
+/// The restored state is the last one written at or before
+/// `as_of_walltime_ms`. The copy keeps the shard id, and its history in
+/// `dest_consensus` is the original's, verbatim, cut off after the restored
+/// state: since, upper, readers, and writers are all exactly as they were, as
+/// if every process had crashed at that moment. Only the blobs referenced by
+/// the restored state are copied, restoring any that were since deleted (and
+/// are still recoverable) along the way.
+///
+/// How far back this can go is bounded by the history that garbage collection
+/// has kept around, see [crate::cfg::DynamicConfig::state_history_retention].
+pub(crate) async fn restore_shard_as_of(
+    versions: &StateVersions,
+    dest_consensus: &(dyn Consensus + Send + Sync),
+    dest_blob: &(dyn Blob + Send + Sync),
+    shard_id: ShardId,
+    as_of_walltime_ms: u64,
+    concurrency: usize,
+) -> anyhow::Result<Option<SeqNo>> {
+    let blob = versions.blob.as_ref();
+    let build_version = &versions.cfg.build_version;
+
+    // Walltimes are strictly increasing with seqno, so the version we're
+    // looking for is the last one in the prefix written by `as_of_walltime_ms`.
+    let diffs = versions.fetch_all_live_diffs(&shard_id).await;
+    let mut as_of = None;
+    for diff in diffs.0.iter() {
+        let diff: StateDiff<u64> = StateDiff::decode(build_version, diff.data.clone());
+        if diff.walltime_ms > as_of_walltime_ms {
+            // The diff that initializes a shard is the only one from the
+            // minimum seqno.
+            if as_of.is_none() && diff.seqno_from != SeqNo::minimum() {
+                bail!(
+                    "history of shard {} only goes back to {} (was state history retained?)",
+                    shard_id,
+                    diff.walltime_ms
+                );
+            }
+            break;
+        }
+        as_of = Some(diff.seqno_to);
+    }
+    let Some(as_of) = as_of else {
+        return Ok(None);
+    };
+
+    let mut states = versions
+        .fetch_all_live_states::<u64>(shard_id)
+        .await
+        .ok_or_else(|| anyhow!("shard {shard_id} is not initialized"))?
+        .check_ts_codec()?;
+    while states.state().seqno < as_of {
+        if states.next(|_| {}).is_none() {
+            bail!("live diffs of shard {shard_id} end before {as_of}");
+        }
+    }
+    let state = states.state();
+    if state.seqno != as_of {
+        bail!("history of shard {shard_id} was concurrently truncated past {as_of}");
+    }
+
+    // The earliest live diff must always have a rollup, so the restored history
+    // starts at the latest rollup of the restored state.
+    let earliest = *state.latest_rollup().0;
+    let diffs: Vec<VersionedData> = diffs
+        .0
+        .into_iter()
+        .filter(|x| earliest <= x.seqno && x.seqno <= as_of)
+        .collect();
+    if diffs.first().map(|x| x.seqno) != Some(earliest) {
+        bail!("history of shard {shard_id} was concurrently truncated past {earliest}");
+    }
+
+    let mut keys = BTreeSet::new();
+    for (_, rollup) in state.collections.rollups.range(earliest..) {
+        keys.insert(rollup.key.complete(&shard_id));
+    }
+    for batch in state.collections.trace.batches() {
+        for part in &batch.parts {
+            keys.insert(part.key.complete(&shard_id));
+        }
+    }
+    info!(
+        "restoring shard {} at {} ({} diffs, {} blobs)",
+        shard_id,
+        as_of,
+        diffs.len(),
+        keys.len()
+    );
+
+    // Copy the blobs first, so that the restored state never references a
+    // blob that isn't there.
+    let () = stream::iter(keys)
+        .map(|key| async move {
+            let value = match blob.get(&key).await? {
+                Some(value) => value,
+                None => {
+                    blob.restore(&key).await?;
+                    blob.get(&key).await?.ok_or_else(|| {
+                        anyhow!("blob {key} of shard {shard_id} at {as_of} is gone")
+                    })?
+                }
+            };
+            let value = Bytes::from(value.into_contiguous());
+            dest_blob.set(&key, value, Atomicity::RequireAtomic).await?;
+            Ok::<_, anyhow::Error>(())
+        })
+        .buffer_unordered(concurrency)
+        .try_collect()
+        .await?;
+
+    let key = shard_id.to_string();
+    let mut expected = None;
+    for diff in diffs {
+        let seqno = diff.seqno;
+        match dest_consensus.compare_and_set(&key, expected, diff).await? {
+            CaSResult::Committed => expected = Some(seqno),
+            CaSResult::ExpectationMismatch => {
+                bail!("shard {shard_id} already exists in the destination")
+            }
+        }
+    }
+    Ok(Some(as_of))
+}
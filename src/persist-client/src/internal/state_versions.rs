@@ -556,6 +556,22 @@ impl StateVersions {
         }
     }
 
+    /// Fetches the live diff with the given SeqNo, or None if it has already
+    /// been truncated.
+    pub async fn fetch_live_diff_at_seqno(
+        &self,
+        shard_id: &ShardId,
+        seqno: SeqNo,
+    ) -> Option<VersionedData> {
+        let path = shard_id.to_string();
+        let diffs = retry_external(&self.metrics.retries.external.fetch_state_scan, || async {
+            self.consensus.scan(&path, seqno, 1).await
+        })
+        .instrument(debug_span!("fetch_state::scan"))
+        .await;
+        diffs.into_iter().next().filter(|diff| diff.seqno == seqno)
+    }
+
     /// Fetches all live diffs greater than the given SeqNo.
     ///
     /// TODO: Apply a limit to this scan. This could additionally be used as an internal
@@ -152,6 +152,7 @@ pub mod write;
 mod internal {
     pub mod apply;
     pub mod compact;
+    pub mod disk_cache;
     pub mod encoding;
     pub mod gc;
     pub mod machine;
@@ -284,6 +284,14 @@ where
         Ok(())
     }
 
+    /// Replaces the [WriteHandle] used for a registered data shard, e.g. to
+    /// write with a new schema.
+    ///
+    /// The data shard's registration is unaffected.
+    pub fn update_write(&mut self, data_write: WriteHandle<K, V, T, D>) {
+        self.datas.put_write(data_write);
+    }
+
     /// Removes data shards from use with this txn set.
     ///
     /// The registration entry written to the txn shard is retracted. If it is
@@ -20,7 +20,8 @@ use url::Url;
 use mz_postgres_client::metrics::PostgresClientMetrics;
 use mz_postgres_client::PostgresClientKnobs;
 
-use crate::file::{FileBlob, FileBlobConfig};
+use crate::encryption::{EncryptedBlob, EncryptedBlobConfig, KeyFileProvider, KeyProvider};
+use crate::file::{FileBlob, FileBlobConfig, FileConsensus, FileConsensusConfig};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
 use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::S3BlobMetrics;
@@ -37,6 +38,8 @@ pub enum BlobConfig {
     /// Config for [MemBlob], only available in testing to prevent
     /// footguns.
     Mem(bool),
+    /// Config for [EncryptedBlob].
+    Encrypted(EncryptedBlobConfig),
 }
 
 /// Configuration knobs for [Blob].
@@ -54,12 +57,45 @@ pub trait BlobKnobs: std::fmt::Debug + Send + Sync {
 impl BlobConfig {
     /// Opens the associated implementation of [Blob].
     pub async fn open(self) -> Result<Arc<dyn Blob + Send + Sync>, ExternalError> {
+        match self {
+            BlobConfig::Encrypted(config) => {
+                let EncryptedBlobConfig {
+                    inner,
+                    key_provider,
+                } = config;
+                let blob = inner.open_unencrypted().await?;
+                Ok(Arc::new(EncryptedBlob::new(blob, key_provider)))
+            }
+            config => config.open_unencrypted().await,
+        }
+    }
+
+    /// Splits off the encryption of this config, if any, returning the config
+    /// of the underlying blob and the [KeyProvider] to encrypt it with.
+    ///
+    /// This allows layering other implementations of [Blob] (e.g. caches)
+    /// between the encryption and the underlying blob, so that they only ever
+    /// see ciphertext.
+    pub fn split_encryption(self) -> (BlobConfig, Option<Arc<dyn KeyProvider>>) {
+        match self {
+            BlobConfig::Encrypted(EncryptedBlobConfig {
+                inner,
+                key_provider,
+            }) => (*inner, Some(key_provider)),
+            config => (config, None),
+        }
+    }
+
+    async fn open_unencrypted(self) -> Result<Arc<dyn Blob + Send + Sync>, ExternalError> {
         match self {
             BlobConfig::File(config) => Ok(Arc::new(FileBlob::open(config).await?)),
             BlobConfig::S3(config) => Ok(Arc::new(S3Blob::open(config).await?)),
             BlobConfig::Mem(tombstone) => {
                 Ok(Arc::new(MemBlob::open(MemBlobConfig::new(tombstone))))
             }
+            BlobConfig::Encrypted(_) => Err(Determinate::new(anyhow!(
+                "encrypted blobs cannot be nested"
+            )))?,
         }
     }
 
@@ -72,6 +108,9 @@ impl BlobConfig {
         let url = Url::parse(value)
             .map_err(|err| anyhow!("failed to parse blob location {} as a url: {}", &value, err))?;
         let mut query_params = url.query_pairs().collect::<BTreeMap<_, _>>();
+        let encryption_key_file = query_params
+            .remove("encryption_key_file")
+            .map(|x| x.into_owned());
 
         let config = match url.scheme() {
             "file" => {
@@ -147,6 +186,14 @@ impl BlobConfig {
             )));
         }
 
+        let config = match encryption_key_file {
+            Some(path) => BlobConfig::Encrypted(EncryptedBlobConfig {
+                inner: Box::new(config),
+                key_provider: Arc::new(KeyFileProvider::open(path).await?),
+            }),
+            None => config,
+        };
+
         Ok(config)
     }
 }
@@ -154,6 +201,8 @@ impl BlobConfig {
 /// Config for an implementation of [Consensus].
 #[derive(Debug, Clone)]
 pub enum ConsensusConfig {
+    /// Config for [FileConsensus].
+    File(FileConsensusConfig),
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
@@ -164,6 +213,7 @@ impl ConsensusConfig {
     /// Opens the associated implementation of [Consensus].
     pub async fn open(self) -> Result<Arc<dyn Consensus + Send + Sync>, ExternalError> {
         match self {
+            ConsensusConfig::File(config) => Ok(Arc::new(FileConsensus::open(config).await?)),
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
@@ -186,6 +236,7 @@ impl ConsensusConfig {
         })?;
 
         let config = match url.scheme() {
+            "file" => Ok(ConsensusConfig::File(FileConsensusConfig::from(url.path()))),
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(value, knobs, metrics)?,
             )),
@@ -0,0 +1,389 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side encryption of [Blob] data at rest.
+//!
+//! [EncryptedBlob] encrypts every value written to the [Blob] it wraps with AES-256-GCM, using a
+//! fresh data key for each value. The data key is itself encrypted ("wrapped") by a key
+//! encryption key obtained from a pluggable [KeyProvider], and stored alongside the ciphertext:
+//!
+//! ```text
+//! magic (4 bytes) | key id len (1 byte) | key id | wrapped data key len (2 bytes, LE) |
+//! wrapped data key | nonce (12 bytes) | ciphertext | tag (16 bytes)
+//! ```
+//!
+//! The blob key is authenticated along with the ciphertext, so a value can't be moved to a
+//! different key without detection. Because the id of the key encryption key is recorded in
+//! every value, key encryption keys can be rotated without rewriting existing data, as long as
+//! the [KeyProvider] still knows the retired keys.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::bytes::SegmentedBytes;
+use openssl::rand::rand_bytes;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+
+use crate::cfg::BlobConfig;
+use crate::location::{Atomicity, Blob, BlobMetadata, Determinate, ExternalError};
+
+const MAGIC: &[u8; 4] = b"MZE1";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// A source of key encryption keys for [EncryptedBlob].
+///
+/// Implementations can keep key material local, like [KeyFileProvider], or delegate wrapping
+/// and unwrapping to an external key management service.
+#[async_trait]
+pub trait KeyProvider: std::fmt::Debug + Send + Sync {
+    /// Wraps `data_key` with the current key encryption key, and returns the id of that key
+    /// along with the wrapped data key.
+    async fn wrap_data_key(&self, data_key: &[u8]) -> Result<(String, Vec<u8>), ExternalError>;
+
+    /// Unwraps a data key that was wrapped with the key encryption key identified by `key_id`.
+    async fn unwrap_data_key(&self, key_id: &str, wrapped: &[u8])
+        -> Result<Vec<u8>, ExternalError>;
+}
+
+/// A [KeyProvider] that reads AES-256 key encryption keys from a local file.
+///
+/// Each non-empty line of the file that does not start with `#` holds one key, as an id and
+/// the base64-encoded 32 byte key separated by a colon. The last key in the file is used to
+/// wrap new data keys, and all keys can unwrap existing ones, so a key is rotated by appending
+/// a new one to the file.
+#[derive(Clone)]
+pub struct KeyFileProvider {
+    path: PathBuf,
+    current: String,
+    keys: BTreeMap<String, [u8; KEY_LEN]>,
+}
+
+impl std::fmt::Debug for KeyFileProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Don't leak key material into logs.
+        f.debug_struct("KeyFileProvider")
+            .field("path", &self.path)
+            .field("current", &self.current)
+            .field("keys", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl KeyFileProvider {
+    /// Loads the keys in the file at `path`.
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self, ExternalError> {
+        let path = path.as_ref().to_path_buf();
+        let contents = tokio::fs::read_to_string(&path).await?;
+        Self::parse(path, &contents)
+    }
+
+    fn parse(path: PathBuf, contents: &str) -> Result<Self, ExternalError> {
+        let mut keys = BTreeMap::new();
+        let mut current = None;
+        for line in contents.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (id, key) = line.split_once(':').ok_or_else(|| {
+                Determinate::new(anyhow!(
+                    "invalid line in encryption key file {}: expected <id>:<base64 key>",
+                    path.display()
+                ))
+            })?;
+            let (id, key) = (id.trim(), key.trim());
+            if id.is_empty() || id.len() > usize::from(u8::MAX) {
+                return Err(Determinate::new(anyhow!(
+                    "invalid key id in encryption key file {}: {id:?}",
+                    path.display()
+                ))
+                .into());
+            }
+            let key = base64::decode(key)
+                .ok()
+                .and_then(|key| <[u8; KEY_LEN]>::try_from(key).ok())
+                .ok_or_else(|| {
+                    Determinate::new(anyhow!(
+                        "key {id} in encryption key file {} is not a base64-encoded {KEY_LEN} byte key",
+                        path.display()
+                    ))
+                })?;
+            if keys.insert(id.to_owned(), key).is_some() {
+                return Err(Determinate::new(anyhow!(
+                    "duplicate key id in encryption key file {}: {id}",
+                    path.display()
+                ))
+                .into());
+            }
+            current = Some(id.to_owned());
+        }
+        let current = current.ok_or_else(|| {
+            Determinate::new(anyhow!(
+                "encryption key file {} has no keys",
+                path.display()
+            ))
+        })?;
+        Ok(KeyFileProvider {
+            path,
+            current,
+            keys,
+        })
+    }
+}
+
+#[async_trait]
+impl KeyProvider for KeyFileProvider {
+    async fn wrap_data_key(&self, data_key: &[u8]) -> Result<(String, Vec<u8>), ExternalError> {
+        let key = &self.keys[&self.current];
+        let wrapped = seal(key, self.current.as_bytes(), data_key)?;
+        Ok((self.current.clone(), wrapped))
+    }
+
+    async fn unwrap_data_key(
+        &self,
+        key_id: &str,
+        wrapped: &[u8],
+    ) -> Result<Vec<u8>, ExternalError> {
+        let key = self.keys.get(key_id).ok_or_else(|| {
+            Determinate::new(anyhow!(
+                "unknown key id {key_id} in encryption key file {}",
+                self.path.display()
+            ))
+        })?;
+        unseal(key, key_id.as_bytes(), wrapped)
+    }
+}
+
+/// Encrypts `plaintext` with a fresh nonce, and returns the nonce, ciphertext and tag.
+fn seal(key: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    let mut nonce = [0; NONCE_LEN];
+    rand_bytes(&mut nonce).map_err(|err| anyhow!(err))?;
+    let mut tag = [0; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        aad,
+        plaintext,
+        &mut tag,
+    )
+    .map_err(|err| anyhow!(err))?;
+    let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len() + TAG_LEN);
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    sealed.extend_from_slice(&tag);
+    Ok(sealed)
+}
+
+/// The inverse of [seal]: decrypts and authenticates the output of [seal].
+fn unseal(key: &[u8], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        return Err(Determinate::new(anyhow!("encrypted data is truncated")).into());
+    }
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        aad,
+        ciphertext,
+        tag,
+    )
+    .map_err(|_| {
+        ExternalError::from(Determinate::new(anyhow!(
+            "failed to decrypt data: wrong key or corrupted data"
+        )))
+    })
+}
+
+/// Config for [EncryptedBlob].
+#[derive(Debug, Clone)]
+pub struct EncryptedBlobConfig {
+    /// The config of the blob to encrypt the values of.
+    pub inner: Box<BlobConfig>,
+    /// The provider of the keys that wrap data keys.
+    pub key_provider: Arc<dyn KeyProvider>,
+}
+
+/// An implementation of [Blob] that encrypts the values of another [Blob].
+///
+/// Keys and value sizes are not encrypted. The sizes reported by
+/// [Blob::list_keys_and_metadata] and [Blob::delete] are those of the encrypted values.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob + Send + Sync>,
+    key_provider: Arc<dyn KeyProvider>,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob] that encrypts the values of `blob` with data keys wrapped
+    /// by `key_provider`.
+    pub fn new(blob: Arc<dyn Blob + Send + Sync>, key_provider: Arc<dyn KeyProvider>) -> Self {
+        EncryptedBlob { blob, key_provider }
+    }
+
+    async fn encrypt(&self, key: &str, value: Bytes) -> Result<Bytes, ExternalError> {
+        let mut data_key = [0; KEY_LEN];
+        rand_bytes(&mut data_key).map_err(|err| anyhow!(err))?;
+        let (key_id, wrapped) = self.key_provider.wrap_data_key(&data_key).await?;
+        let key_id_len =
+            u8::try_from(key_id.len()).map_err(|_| anyhow!("key id is too long: {key_id}"))?;
+        let wrapped_len = u16::try_from(wrapped.len())
+            .map_err(|_| anyhow!("wrapped data key is too long: {} bytes", wrapped.len()))?;
+
+        // Encrypting large parts takes a while, so keep it off the async runtime.
+        let aad = key.as_bytes().to_vec();
+        let sealed = mz_ore::task::spawn_blocking(
+            || "persist::encrypt",
+            move || seal(&data_key, &aad, &value),
+        )
+        .await??;
+
+        let mut buf =
+            Vec::with_capacity(MAGIC.len() + 1 + key_id.len() + 2 + wrapped.len() + sealed.len());
+        buf.extend_from_slice(MAGIC);
+        buf.push(key_id_len);
+        buf.extend_from_slice(key_id.as_bytes());
+        buf.extend_from_slice(&wrapped_len.to_le_bytes());
+        buf.extend_from_slice(&wrapped);
+        buf.extend_from_slice(&sealed);
+        Ok(Bytes::from(buf))
+    }
+
+    async fn decrypt(&self, key: &str, value: SegmentedBytes) -> Result<Vec<u8>, ExternalError> {
+        let value = value.into_contiguous();
+        let invalid = || {
+            ExternalError::from(Determinate::new(anyhow!(
+                "blob {key} is not encrypted or its header is corrupted"
+            )))
+        };
+
+        let rest = value.strip_prefix(MAGIC).ok_or_else(invalid)?;
+        let (&key_id_len, rest) = rest.split_first().ok_or_else(invalid)?;
+        let (key_id, rest) = split_at_checked(rest, usize::from(key_id_len)).ok_or_else(invalid)?;
+        let key_id = std::str::from_utf8(key_id).map_err(|_| invalid())?;
+        let (wrapped_len, rest) = split_at_checked(rest, 2).ok_or_else(invalid)?;
+        let wrapped_len = u16::from_le_bytes([wrapped_len[0], wrapped_len[1]]);
+        let (wrapped, _) = split_at_checked(rest, usize::from(wrapped_len)).ok_or_else(invalid)?;
+
+        let data_key = self.key_provider.unwrap_data_key(key_id, wrapped).await?;
+        let offset = value.len() - rest.len() + usize::from(wrapped_len);
+        let aad = key.as_bytes().to_vec();
+        mz_ore::task::spawn_blocking(
+            || "persist::decrypt",
+            move || unseal(&data_key, &aad, &value[offset..]),
+        )
+        .await?
+    }
+}
+
+fn split_at_checked(buf: &[u8], mid: usize) -> Option<(&[u8], &[u8])> {
+    (mid <= buf.len()).then(|| buf.split_at(mid))
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.blob.get(key).await? else {
+            return Ok(None);
+        };
+        let value = self.decrypt(key, value).await?;
+        Ok(Some(SegmentedBytes::from(value)))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes, atomic: Atomicity) -> Result<(), ExternalError> {
+        let value = self.encrypt(key, value).await?;
+        self.blob.set(key, value, atomic).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mem::MemMultiRegistry;
+
+    use super::*;
+
+    const KEY_FILE: &str = "
+# Retired key.
+k1: AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=
+k2: ICEiIyQlJicoKSorLC0uLzAxMjM0NTY3ODk6Ozw9Pj8=
+";
+
+    fn key_provider(contents: &str) -> Arc<dyn KeyProvider> {
+        Arc::new(KeyFileProvider::parse("keyfile".into(), contents).expect("valid key file"))
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let mut registry = MemMultiRegistry::new(false);
+        let plaintext: Arc<dyn Blob + Send + Sync> = Arc::new(registry.blob("path"));
+        let value = Bytes::from("some secret value");
+
+        // Write with the retired key only.
+        let retired = KEY_FILE.split("k2").next().expect("retired key");
+        let old = EncryptedBlob::new(Arc::clone(&plaintext), key_provider(retired));
+        assert_eq!(old.get("k").await?, None);
+        old.set("k", value.clone(), Atomicity::RequireAtomic)
+            .await?;
+        let read = old.get("k").await?.map(|v| v.into_contiguous());
+        assert_eq!(read.as_deref(), Some(&value[..]));
+
+        // The value is not stored in plaintext.
+        let stored = plaintext.get("k").await?.expect("value").into_contiguous();
+        assert!(stored.starts_with(MAGIC));
+        assert!(!stored.windows(value.len()).any(|w| w == &value[..]));
+
+        // A rotated key file can still read values written with the retired key.
+        let new = EncryptedBlob::new(Arc::clone(&plaintext), key_provider(KEY_FILE));
+        let read = new.get("k").await?.map(|v| v.into_contiguous());
+        assert_eq!(read.as_deref(), Some(&value[..]));
+
+        // A value can't be read under a different key.
+        plaintext
+            .set("other", Bytes::from(stored), Atomicity::RequireAtomic)
+            .await?;
+        assert!(new.get("other").await.is_err());
+
+        // Unencrypted values are rejected.
+        plaintext
+            .set("plain", value.clone(), Atomicity::RequireAtomic)
+            .await?;
+        assert!(new.get("plain").await.is_err());
+
+        // Unknown keys are rejected.
+        let other = key_provider("k3: QEFCQ0RFRkdISUpLTE1OT1BRUlNUVVZXWFlaW1xdXl8=");
+        let other = EncryptedBlob::new(Arc::clone(&plaintext), other);
+        assert!(other.get("k").await.is_err());
+
+        Ok(())
+    }
+}
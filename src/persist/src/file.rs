@@ -7,22 +7,31 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! File backed implementations for testing and benchmarking.
+//! File backed implementations for testing, benchmarking, and single-node deployments.
 
-use std::io::ErrorKind;
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::os::unix::fs::{FileExt, MetadataExt};
+use std::os::unix::io::AsRawFd;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::anyhow;
+use async_stream::try_stream;
 use async_trait::async_trait;
 use bytes::Bytes;
 use fail::fail_point;
+use md5::{Digest, Md5};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use tokio::fs::{self, File, OpenOptions};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use crate::error::Error;
-use crate::location::{Atomicity, Blob, BlobMetadata, Determinate, ExternalError};
+use crate::location::{
+    Atomicity, Blob, BlobMetadata, CaSResult, Consensus, Determinate, ExternalError, ResultStream,
+    SeqNo, VersionedData,
+};
 
 /// Configuration for opening a [FileBlob].
 #[derive(Debug, Clone)]
@@ -287,9 +296,449 @@ impl Blob for FileBlob {
     }
 }
 
+/// Configuration for opening a [FileConsensus].
+#[derive(Debug, Clone)]
+pub struct FileConsensusConfig {
+    base_dir: PathBuf,
+}
+
+impl<P: AsRef<Path>> From<P> for FileConsensusConfig {
+    fn from(base_dir: P) -> Self {
+        FileConsensusConfig {
+            base_dir: base_dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+/// Implementation of [Consensus] backed by files.
+///
+/// Each key is stored as an append-only log of checksummed [VersionedData] records in its own
+/// file. Every operation on a key holds an advisory lock on a sibling lock file, so the log may
+/// be shared by any number of processes on the same machine, e.g., an `environmentd` and the
+/// `clusterd` processes it spawned. Appends are fsync'd before a compare-and-set reports success,
+/// and a record torn by a crash is discarded on the next write.
+///
+/// Each instance keeps an index of the records in every log it has touched, so an operation
+/// only reads the records it returns and whatever was appended by other processes since the
+/// last one. Truncation rewrites the log without the truncated records, which keeps both the
+/// file and the index bounded by the live range of seqnos.
+///
+/// This is intended for single-node deployments that pair it with a [FileBlob]; it is not safe
+/// to use on network filesystems that don't implement `flock`.
+#[derive(Debug)]
+pub struct FileConsensus {
+    base_dir: PathBuf,
+    indexes: Mutex<BTreeMap<String, Arc<Mutex<LogIndex>>>>,
+}
+
+impl FileConsensus {
+    const LOG_EXTENSION: &'static str = "log";
+    const LOCK_EXTENSION: &'static str = "lock";
+    const TMP_EXTENSION: &'static str = "tmp";
+
+    /// The size of a record's header: its seqno and the length of its data.
+    const HEADER_LEN: usize = 16;
+    /// The size of a record's trailing checksum.
+    const CHECKSUM_LEN: usize = 16;
+
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let FileConsensusConfig { base_dir } = config;
+        fs::create_dir_all(&base_dir).await.map_err(Error::from)?;
+        Ok(FileConsensus {
+            base_dir,
+            indexes: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    fn key_path(&self, key: &str, extension: &str) -> PathBuf {
+        let mut file_name = FileBlob::replace_forward_slashes(key);
+        file_name.push('.');
+        file_name.push_str(extension);
+        self.base_dir.join(file_name)
+    }
+
+    /// Runs `f` on a blocking thread while holding the lock for `key`, passing it the index of
+    /// the log brought up to date with the log's contents.
+    async fn with_key_lock<R, F>(
+        &self,
+        key: &str,
+        exclusive: bool,
+        f: F,
+    ) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&KeyFiles, &mut LogIndex) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let files = KeyFiles {
+            base_dir: self.base_dir.clone(),
+            log: self.key_path(key, Self::LOG_EXTENSION),
+            tmp: self.key_path(key, Self::TMP_EXTENSION),
+        };
+        let lock = self.key_path(key, Self::LOCK_EXTENSION);
+        let index = Arc::clone(
+            self.indexes
+                .lock()
+                .expect("lock poisoned")
+                .entry(key.to_owned())
+                .or_default(),
+        );
+        mz_ore::task::spawn_blocking(
+            || "persist::file_consensus",
+            move || {
+                let lock = std::fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create(true)
+                    .open(lock)?;
+                let operation = if exclusive {
+                    libc::LOCK_EX
+                } else {
+                    libc::LOCK_SH
+                };
+                // SAFETY: `lock` is an open file for the duration of the call. The lock is
+                // released when `lock` is closed at the end of this closure.
+                if unsafe { libc::flock(lock.as_raw_fd(), operation) } != 0 {
+                    return Err(std::io::Error::last_os_error().into());
+                }
+                // NB: Always taken after the file lock, so that tasks waiting on another
+                // process don't hold up the ones in this process that could proceed.
+                let mut index = index.lock().expect("lock poisoned");
+                files.refresh(&mut index)?;
+                f(&files, &mut index)
+            },
+        )
+        .await?
+    }
+
+    /// Encodes `data` as a log record.
+    fn encode_record(data: &VersionedData, buf: &mut Vec<u8>) {
+        let start = buf.len();
+        buf.extend_from_slice(&data.seqno.0.to_le_bytes());
+        buf.extend_from_slice(&u64::cast_from(data.data.len()).to_le_bytes());
+        buf.extend_from_slice(&data.data);
+        let checksum = Md5::digest(&buf[start..]);
+        buf.extend_from_slice(&checksum);
+    }
+
+    /// Decodes the locations of all complete records in `buf`, relative to its start, and
+    /// returns them along with the number of bytes they occupy.
+    ///
+    /// Writers truncate the log to its complete records before appending to it, so any
+    /// remaining bytes can only be a record that was torn by a crash.
+    fn decode_records(buf: &[u8]) -> (Vec<RecordLocation>, usize) {
+        let mut records = Vec::new();
+        let mut offset = 0;
+        while let Some(header) = buf.get(offset..offset + Self::HEADER_LEN) {
+            let (seqno, len) = header.split_at(8);
+            let seqno = u64::from_le_bytes(seqno.try_into().expect("8 bytes"));
+            let len = u64::from_le_bytes(len.try_into().expect("8 bytes"));
+            let Some((len, end)) = usize::try_from(len).ok().and_then(|len| {
+                let end = (offset + Self::HEADER_LEN)
+                    .checked_add(len)?
+                    .checked_add(Self::CHECKSUM_LEN)?;
+                Some((len, end))
+            }) else {
+                break;
+            };
+            let Some(record) = buf.get(offset..end) else {
+                break;
+            };
+            let (contents, checksum) = record.split_at(record.len() - Self::CHECKSUM_LEN);
+            if Md5::digest(contents).as_slice() != checksum {
+                break;
+            }
+            records.push(RecordLocation {
+                seqno: SeqNo(seqno),
+                offset: u64::cast_from(offset),
+                len,
+            });
+            offset = end;
+        }
+        (records, offset)
+    }
+}
+
+/// The location of a record in a [FileConsensus] log.
+#[derive(Debug, Clone, Copy)]
+struct RecordLocation {
+    seqno: SeqNo,
+    /// The offset of the record's header in the log.
+    offset: u64,
+    /// The length of the record's data.
+    len: usize,
+}
+
+impl RecordLocation {
+    /// The total number of bytes the record occupies in the log.
+    fn encoded_len(&self) -> usize {
+        FileConsensus::HEADER_LEN + self.len + FileConsensus::CHECKSUM_LEN
+    }
+}
+
+/// An index of the complete records in a [FileConsensus] log.
+#[derive(Debug, Default)]
+struct LogIndex {
+    /// The log file the index describes, or `None` if the log doesn't exist.
+    ///
+    /// Holding the file open guarantees that its inode isn't reused, so comparing it against the
+    /// file currently at the log's path reliably detects that another process rewrote the log.
+    file: Option<std::fs::File>,
+    /// The locations of the complete records in the log, in seqno order.
+    records: Vec<RecordLocation>,
+    /// The number of bytes the complete records occupy.
+    valid_len: u64,
+}
+
+impl LogIndex {
+    /// Reads the record at `location`.
+    fn read(&self, location: &RecordLocation) -> Result<VersionedData, ExternalError> {
+        let file = self.file.as_ref().expect("log with records exists");
+        let mut buf = vec![0; location.encoded_len()];
+        file.read_exact_at(&mut buf, location.offset)?;
+        match FileConsensus::decode_records(&buf).0.as_slice() {
+            [record] if record.seqno == location.seqno => Ok(VersionedData {
+                seqno: location.seqno,
+                data: Bytes::copy_from_slice(
+                    &buf[FileConsensus::HEADER_LEN..FileConsensus::HEADER_LEN + location.len],
+                ),
+            }),
+            _ => Err(ExternalError::from(anyhow!(
+                "corrupt record for {:?} at offset {}",
+                location.seqno,
+                location.offset
+            ))),
+        }
+    }
+}
+
+/// The files that store the log of a [FileConsensus] key.
+struct KeyFiles {
+    base_dir: PathBuf,
+    log: PathBuf,
+    tmp: PathBuf,
+}
+
+impl KeyFiles {
+    /// Brings `index` up to date with the log.
+    ///
+    /// Only the bytes appended since the last refresh are read, unless the log was rewritten by
+    /// another process in the meantime, in which case the index is rebuilt from scratch.
+    fn refresh(&self, index: &mut LogIndex) -> Result<(), ExternalError> {
+        let file = match std::fs::File::open(&self.log) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                *index = LogIndex::default();
+                return Ok(());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let metadata = file.metadata()?;
+        let rewritten = match &index.file {
+            Some(indexed) => {
+                let indexed = indexed.metadata()?;
+                (indexed.dev(), indexed.ino()) != (metadata.dev(), metadata.ino())
+                    || metadata.len() < index.valid_len
+            }
+            None => true,
+        };
+        if rewritten {
+            *index = LogIndex {
+                file: Some(file),
+                records: Vec::new(),
+                valid_len: 0,
+            };
+        }
+        if metadata.len() > index.valid_len {
+            let mut file = index.file.as_ref().expect("log exists");
+            file.seek(SeekFrom::Start(index.valid_len))?;
+            let mut buf = Vec::new();
+            file.read_to_end(&mut buf)?;
+            let (records, len) = FileConsensus::decode_records(&buf);
+            let start = index.valid_len;
+            index.records.extend(records.into_iter().map(|mut record| {
+                record.offset += start;
+                record
+            }));
+            index.valid_len += u64::cast_from(len);
+        }
+        Ok(())
+    }
+
+    /// Appends a record to the log, discarding any torn record after its complete ones.
+    fn append(&self, index: &mut LogIndex, new: &VersionedData) -> Result<(), ExternalError> {
+        let created = index.file.is_none();
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .open(&self.log)?;
+        if file.metadata()?.len() != index.valid_len {
+            file.set_len(index.valid_len)?;
+        }
+        let mut buf = Vec::new();
+        FileConsensus::encode_record(new, &mut buf);
+        file.write_all_at(&buf, index.valid_len)?;
+
+        fail_point!("fileconsensus_append_sync", |_| {
+            Err(ExternalError::from(anyhow!(
+                "FileConsensus::append_sync fail point reached for file {:?}",
+                self.log
+            )))
+        });
+
+        file.sync_all()?;
+        if created {
+            // fsync the directory so it is guaranteed to see the new log
+            std::fs::File::open(&self.base_dir)?.sync_all()?;
+        }
+        index.records.push(RecordLocation {
+            seqno: new.seqno,
+            offset: index.valid_len,
+            len: new.data.len(),
+        });
+        index.valid_len += u64::cast_from(buf.len());
+        index.file = Some(file);
+        Ok(())
+    }
+
+    /// Atomically replaces the log with one that starts at the `retain_from`th record.
+    fn truncate(&self, index: &mut LogIndex, retain_from: usize) -> Result<(), ExternalError> {
+        let start = index.records[retain_from].offset;
+        let mut buf = vec![0; usize::cast_from(index.valid_len - start)];
+        index
+            .file
+            .as_ref()
+            .expect("log with records exists")
+            .read_exact_at(&mut buf, start)?;
+        // NB: If we have a partial tmp file from a previous crash, it will just get
+        // overwritten (which is safe).
+        let mut file = std::fs::File::create(&self.tmp)?;
+        file.write_all(&buf)?;
+        file.sync_all()?;
+        std::fs::rename(&self.tmp, &self.log)?;
+        // fsync the directory to guarantee it can see the renamed file
+        std::fs::File::open(&self.base_dir)?.sync_all()?;
+
+        index.file = Some(std::fs::File::open(&self.log)?);
+        index.records.drain(..retain_from);
+        for record in index.records.iter_mut() {
+            record.offset -= start;
+        }
+        index.valid_len -= start;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Consensus for FileConsensus {
+    fn list_keys(&self) -> ResultStream<String> {
+        Box::pin(try_stream! {
+            let mut entries = fs::read_dir(&self.base_dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                if path.extension().and_then(|os| os.to_str()) != Some(Self::LOG_EXTENSION) {
+                    continue;
+                }
+                if let Some(name) = path.file_stem().and_then(|os| os.to_str()) {
+                    yield FileBlob::restore_forward_slashes(name);
+                }
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        self.with_key_lock(key, false, |_files, index| {
+            index
+                .records
+                .last()
+                .map(|location| index.read(location))
+                .transpose()
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(ExternalError::from(
+                        anyhow!("new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                                 new.seqno, expected)));
+            }
+        }
+
+        if new.seqno.0 > i64::MAX.try_into().expect("i64::MAX known to fit in u64") {
+            return Err(ExternalError::from(anyhow!(
+                "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+                new.seqno
+            )));
+        }
+
+        self.with_key_lock(key, true, move |files, index| {
+            let seqno = index.records.last().map(|location| location.seqno);
+            if seqno != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+            files.append(index, &new)?;
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        self.with_key_lock(key, false, move |_files, index| {
+            let start = index
+                .records
+                .partition_point(|location| location.seqno < from);
+            index.records[start..]
+                .iter()
+                .take(limit)
+                .map(|location| index.read(location))
+                .collect()
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<usize, ExternalError> {
+        self.with_key_lock(key, true, move |files, index| {
+            if index
+                .records
+                .last()
+                .map_or(true, |location| location.seqno < seqno)
+            {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+
+            let deleted = index
+                .records
+                .partition_point(|location| location.seqno < seqno);
+            if deleted > 0 {
+                files.truncate(index, deleted)?;
+            }
+            Ok(deleted)
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::location::tests::blob_impl_test;
+    use futures_util::StreamExt;
+
+    use crate::location::tests::{blob_impl_test, consensus_impl_test};
 
     use super::*;
 
@@ -314,4 +763,109 @@ mod tests {
 
         Ok(())
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        consensus_impl_test(|| FileConsensus::open(temp_dir.path().into())).await
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_torn_write() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        let key = "s/torn";
+        let v1 = VersionedData {
+            seqno: SeqNo(1),
+            data: Bytes::from("abc"),
+        };
+        let v2 = VersionedData {
+            seqno: SeqNo(2),
+            data: Bytes::from("def"),
+        };
+        assert_eq!(
+            consensus.compare_and_set(key, None, v1.clone()).await,
+            Ok(CaSResult::Committed)
+        );
+
+        // Simulate a crash in the middle of appending a record.
+        let log = consensus.key_path(key, FileConsensus::LOG_EXTENSION);
+        let mut torn = Vec::new();
+        FileConsensus::encode_record(&v2, &mut torn);
+        torn.truncate(torn.len() - 1);
+        let mut file = OpenOptions::new().append(true).open(&log).await?;
+        file.write_all(&torn).await?;
+        file.sync_all().await?;
+
+        // The torn record is ignored, and overwritten by the next append.
+        assert_eq!(consensus.head(key).await, Ok(Some(v1.clone())));
+        assert_eq!(
+            consensus
+                .compare_and_set(key, Some(SeqNo(1)), v2.clone())
+                .await,
+            Ok(CaSResult::Committed)
+        );
+
+        // A new instance sees the same state, as after a restart.
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(
+            consensus.scan(key, SeqNo(0), usize::MAX).await,
+            Ok(vec![v1, v2])
+        );
+        let keys: Vec<_> = consensus.list_keys().collect().await;
+        assert_eq!(keys, vec![Ok(key.to_owned())]);
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock` on OS `linux`
+    async fn file_consensus_shared() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let a = FileConsensus::open(temp_dir.path().into()).await?;
+        let b = FileConsensus::open(temp_dir.path().into()).await?;
+        let key = "s/shared";
+        let data = |seqno| VersionedData {
+            seqno: SeqNo(seqno),
+            data: Bytes::from(format!("data{}", seqno)),
+        };
+
+        // Each instance picks up the records appended by the other.
+        assert_eq!(
+            a.compare_and_set(key, None, data(1)).await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(b.head(key).await, Ok(Some(data(1))));
+        assert_eq!(
+            b.compare_and_set(key, Some(SeqNo(1)), data(2)).await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(
+            a.compare_and_set(key, Some(SeqNo(1)), data(3)).await,
+            Ok(CaSResult::ExpectationMismatch)
+        );
+        assert_eq!(
+            a.compare_and_set(key, Some(SeqNo(2)), data(3)).await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(b.scan(key, SeqNo(2), 1).await, Ok(vec![data(2)]));
+
+        // Each instance notices when the other rewrites the log.
+        assert_eq!(b.truncate(key, SeqNo(2)).await, Ok(1));
+        assert_eq!(
+            a.scan(key, SeqNo(0), usize::MAX).await,
+            Ok(vec![data(2), data(3)])
+        );
+        assert_eq!(
+            a.compare_and_set(key, Some(SeqNo(3)), data(4)).await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(a.truncate(key, SeqNo(4)).await, Ok(2));
+        assert_eq!(b.head(key).await, Ok(Some(data(4))));
+        assert_eq!(b.scan(key, SeqNo(0), usize::MAX).await, Ok(vec![data(4)]));
+
+        Ok(())
+    }
 }
@@ -28,7 +28,7 @@ use crate::error::Error;
 use crate::gen::persist::ProtoBatchFormat;
 use crate::indexed::columnar::ColumnarRecords;
 use crate::indexed::encoding::{
-    decode_trace_inline_meta, encode_trace_inline_meta, BlobTraceBatchPart,
+    decode_trace_inline_meta, encode_trace_inline_meta, BlobTraceBatchPart, CompressionFormat,
 };
 
 /// The Arrow schema we use to encode ((K, V), T, D) tuples.
@@ -88,7 +88,7 @@ pub fn encode_trace_arrow<W: Write, T: Timestamp + Codec64>(
     let mut metadata = BTreeMap::new();
     metadata.insert(
         INLINE_METADATA_KEY.into(),
-        encode_trace_inline_meta(batch, ProtoBatchFormat::ArrowKvtd),
+        encode_trace_inline_meta(batch, ProtoBatchFormat::ArrowKvtd, CompressionFormat::None),
     );
     let schema = Schema::from(SCHEMA_ARROW_KVTD.fields.clone()).with_metadata(metadata);
     let options = WriteOptions { compression: None };
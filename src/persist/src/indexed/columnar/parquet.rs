@@ -26,7 +26,7 @@ use crate::indexed::columnar::arrow::{
 };
 use crate::indexed::columnar::ColumnarRecords;
 use crate::indexed::encoding::{
-    decode_trace_inline_meta, encode_trace_inline_meta, BlobTraceBatchPart,
+    decode_trace_inline_meta, encode_trace_inline_meta, BlobTraceBatchPart, CompressionFormat,
 };
 
 const INLINE_METADATA_KEY: &str = "MZ:inline";
@@ -35,13 +35,15 @@ const INLINE_METADATA_KEY: &str = "MZ:inline";
 pub fn encode_trace_parquet<W: Write, T: Timestamp + Codec64>(
     w: &mut W,
     batch: &BlobTraceBatchPart<T>,
+    compression: CompressionFormat,
 ) -> Result<(), Error> {
     // Better to error now than write out an invalid batch.
     batch.validate()?;
     encode_parquet_kvtd(
         w,
-        encode_trace_inline_meta(batch, ProtoBatchFormat::ParquetKvtd),
+        encode_trace_inline_meta(batch, ProtoBatchFormat::ParquetKvtd, compression),
         &batch.updates,
+        compression,
     )
 }
 
@@ -86,12 +88,20 @@ fn encode_parquet_kvtd<W: Write>(
     w: &mut W,
     inline_base64: String,
     iter: &[ColumnarRecords],
+    compression: CompressionFormat,
 ) -> Result<(), Error> {
     let iter = iter.into_iter().map(|x| Ok(encode_arrow_batch_kvtd(x)));
 
+    // The compression codec is recorded in the metadata of each column chunk,
+    // which is what the reader goes by.
+    let compression = match compression {
+        CompressionFormat::None => CompressionOptions::Uncompressed,
+        CompressionFormat::Lz4 => CompressionOptions::Lz4Raw,
+        CompressionFormat::Zstd => CompressionOptions::Zstd(None),
+    };
     let options = WriteOptions {
         write_statistics: false,
-        compression: CompressionOptions::Uncompressed,
+        compression,
         version: Version::V2,
         data_pagesize_limit: None, // use default limit
     };
@@ -86,6 +86,7 @@
 )]
 
 pub mod cfg;
+pub mod encryption;
 pub mod error;
 pub mod file;
 pub mod gen;
@@ -25,6 +25,8 @@ pub const TYPE_BPCHAR_ARRAY_OID: u32 = 1014;
 pub const TYPE_BPCHAR_OID: u32 = 1042;
 pub const TYPE_BYTEA_ARRAY_OID: u32 = 1001;
 pub const TYPE_BYTEA_OID: u32 = 17;
+pub const TYPE_CIDR_ARRAY_OID: u32 = 651;
+pub const TYPE_CIDR_OID: u32 = 650;
 pub const TYPE_CHAR_ARRAY_OID: u32 = 1002;
 pub const TYPE_CHAR_OID: u32 = 18;
 pub const TYPE_DATE_ARRAY_OID: u32 = 1182;
@@ -33,6 +35,8 @@ pub const TYPE_FLOAT4_ARRAY_OID: u32 = 1021;
 pub const TYPE_FLOAT4_OID: u32 = 700;
 pub const TYPE_FLOAT8_ARRAY_OID: u32 = 1022;
 pub const TYPE_FLOAT8_OID: u32 = 701;
+pub const TYPE_INET_ARRAY_OID: u32 = 1041;
+pub const TYPE_INET_OID: u32 = 869;
 pub const TYPE_INT2_ARRAY_OID: u32 = 1005;
 pub const TYPE_INT2_OID: u32 = 21;
 pub const TYPE_INT2_VECTOR_ARRAY_OID: u32 = 1006;
@@ -46,6 +50,8 @@ pub const TYPE_INTERVAL_OID: u32 = 1186;
 pub const TYPE_JSONB_ARRAY_OID: u32 = 3807;
 pub const TYPE_JSONB_OID: u32 = 3802;
 pub const TYPE_LIST_OID_OID: u32 = 16_384;
+pub const TYPE_MACADDR_ARRAY_OID: u32 = 1040;
+pub const TYPE_MACADDR_OID: u32 = 829;
 pub const TYPE_NAME_ARRAY_OID: u32 = 1003;
 pub const TYPE_NAME_OID: u32 = 19;
 pub const TYPE_NUMERIC_ARRAY_OID: u32 = 1231;
@@ -76,6 +82,7 @@ pub const TYPE_INT4RANGE_OID: u32 = 3904;
 pub const TYPE_INT4RANGE_ARRAY_OID: u32 = 3905;
 pub const TYPE_ANYRANGE_OID: u32 = 3831;
 pub const TYPE_ANYCOMPATIBLERANGE_OID: u32 = 5080;
+pub const TYPE_ANYENUM_OID: u32 = 3500;
 pub const TYPE_INT8RANGE_OID: u32 = 3926;
 pub const TYPE_INT8RANGE_ARRAY_OID: u32 = 3927;
 pub const TYPE_DATERANGE_OID: u32 = 3912;
@@ -368,3 +375,4 @@ pub const FUNC_CONSTANT_TIME_EQ_STRING_OID: u32 = 16_645;
 pub const FUNC_TIMEZONE_OFFSET: u32 = 16_646;
 pub const FUNC_PRETTY_SQL: u32 = 16_647;
 pub const FUNC_PRETTY_SQL_NOWIDTH: u32 = 16_648;
+pub const FUNC_MZ_BYPASSES_RLS_OID: u32 = 16_649;
@@ -95,6 +95,7 @@ pub mod oid;
 pub use types::{
     Type, TypeConversionError, TypeFromOidError, ANYCOMPATIBLELIST, ANYCOMPATIBLEMAP, LIST, MAP,
 };
+pub use value::inet::{Inet, MacAddr};
 pub use value::interval::Interval;
 pub use value::jsonb::Jsonb;
 pub use value::numeric::Numeric;
@@ -12,6 +12,7 @@ use std::fmt;
 use std::mem::size_of;
 
 use mz_repr::adt::char::{CharLength as AdtCharLength, InvalidCharLengthError};
+use mz_repr::adt::inet::MacAddr;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::numeric::{
     InvalidNumericMaxScaleError, NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION,
@@ -163,6 +164,19 @@ pub enum Type {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem,
+    /// An IPv4 or IPv6 host address and optional netmask.
+    Inet,
+    /// An IPv4 or IPv6 network address.
+    Cidr,
+    /// A MAC address.
+    MacAddr,
+    /// A user-defined enumerated type.
+    Enum {
+        /// The OID of the type.
+        oid: u32,
+        /// The labels of the type, in declaration order.
+        labels: Vec<String>,
+    },
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -557,6 +571,9 @@ impl Type {
             postgres_types::Type::TIMESTAMP => Type::Timestamp { precision: None },
             postgres_types::Type::TIMESTAMPTZ => Type::TimestampTz { precision: None },
             postgres_types::Type::UUID => Type::Uuid,
+            postgres_types::Type::INET => Type::Inet,
+            postgres_types::Type::CIDR => Type::Cidr,
+            postgres_types::Type::MACADDR => Type::MacAddr,
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
@@ -594,6 +611,9 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::INET_ARRAY => Type::Array(Box::new(Type::Inet)),
+            postgres_types::Type::CIDR_ARRAY => Type::Array(Box::new(Type::Cidr)),
+            postgres_types::Type::MACADDR_ARRAY => Type::Array(Box::new(Type::MacAddr)),
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -734,6 +754,10 @@ impl Type {
                     _ => unreachable!(),
                 },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
+                Type::Inet => &postgres_types::Type::INET_ARRAY,
+                Type::Cidr => &postgres_types::Type::CIDR_ARRAY,
+                Type::MacAddr => &postgres_types::Type::MACADDR_ARRAY,
+                Type::Enum { .. } => &postgres_types::Type::TEXT_ARRAY,
             },
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
@@ -779,6 +803,11 @@ impl Type {
                 t => unreachable!("{t:?} is not a range element type"),
             },
             Type::MzAclItem => &MZ_ACL_ITEM,
+            Type::Inet => &postgres_types::Type::INET,
+            Type::Cidr => &postgres_types::Type::CIDR,
+            Type::MacAddr => &postgres_types::Type::MACADDR,
+            // Enum values are transmitted as the text of their label.
+            Type::Enum { .. } => &postgres_types::Type::TEXT,
         }
     }
 
@@ -843,7 +872,10 @@ impl Type {
     ///
     /// [OID]: https://www.postgresql.org/docs/current/datatype-oid.html
     pub fn oid(&self) -> u32 {
-        self.inner().oid()
+        match self {
+            Type::Enum { oid, .. } => *oid,
+            _ => self.inner().oid(),
+        }
     }
 
     /// Returns the constraint on the type, if any.
@@ -910,7 +942,11 @@ impl Type {
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
-            | Type::MzAclItem => None,
+            | Type::MzAclItem
+            | Type::Inet
+            | Type::Cidr
+            | Type::MacAddr
+            | Type::Enum { .. } => None,
         }
     }
 
@@ -960,6 +996,10 @@ impl Type {
             Type::Range { .. } => -1,
             Type::MzAclItem => MzAclItem::binary_size().try_into().expect("must fit"),
             Type::AclItem => AclItem::binary_size().try_into().expect("must fit"),
+            Type::Inet => -1,
+            Type::Cidr => -1,
+            Type::MacAddr => MacAddr::binary_size().try_into().expect("must fit"),
+            Type::Enum { .. } => 4,
         }
     }
 
@@ -1092,6 +1132,10 @@ impl TryFrom<&Type> for ScalarType {
                 element_type: Box::new(TryFrom::try_from(&**element_type)?),
             }),
             Type::MzAclItem => Ok(ScalarType::MzAclItem),
+            Type::Inet => Ok(ScalarType::Inet),
+            Type::Cidr => Ok(ScalarType::Cidr),
+            Type::MacAddr => Ok(ScalarType::MacAddr),
+            Type::Enum { .. } => Err(TypeConversionError::UnsupportedType(typ.clone())),
         }
     }
 }
@@ -1260,6 +1304,15 @@ impl From<&ScalarType> for Type {
                 element_type: Box::new(From::from(&**element_type)),
             },
             ScalarType::MzAclItem => Type::MzAclItem,
+            ScalarType::Inet => Type::Inet,
+            ScalarType::Cidr => Type::Cidr,
+            ScalarType::MacAddr => Type::MacAddr,
+            ScalarType::Enum {
+                labels, custom_oid, ..
+            } => Type::Enum {
+                oid: *custom_oid,
+                labels: labels.clone(),
+            },
         }
     }
 }
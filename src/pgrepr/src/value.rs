@@ -13,7 +13,7 @@ use std::{io, str};
 
 use bytes::{BufMut, BytesMut};
 use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
-use mz_ore::cast::ReinterpretCast;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
 use mz_pgwire_common::Format;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
@@ -29,8 +29,9 @@ use postgres_types::{FromSql, IsNull, ToSql, Type as PgType};
 use uuid::Uuid;
 
 use crate::types::{UINT2, UINT4, UINT8};
-use crate::{Interval, Jsonb, Numeric, Type, UInt2, UInt4, UInt8};
+use crate::{Inet, Interval, Jsonb, MacAddr, Numeric, Type, UInt2, UInt4, UInt8};
 
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
 pub mod numeric;
@@ -116,6 +117,14 @@ pub enum Value {
     /// A list of privileges granted to a user that uses [`mz_repr::adt::system::Oid`]s for role
     /// references. This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// An IPv4 or IPv6 host address and optional netmask.
+    Inet(Inet),
+    /// An IPv4 or IPv6 network address.
+    Cidr(Inet),
+    /// A MAC address.
+    MacAddr(MacAddr),
+    /// A label of a user-defined enumerated type.
+    Enum(String),
 }
 
 impl Value {
@@ -145,6 +154,12 @@ impl Value {
             (Datum::MzTimestamp(t), ScalarType::MzTimestamp) => Some(Value::MzTimestamp(t)),
             (Datum::MzAclItem(mai), ScalarType::MzAclItem) => Some(Value::MzAclItem(mai)),
             (Datum::AclItem(ai), ScalarType::AclItem) => Some(Value::AclItem(ai)),
+            (Datum::Inet(i), ScalarType::Inet) => Some(Value::Inet(Inet(i))),
+            (Datum::Inet(i), ScalarType::Cidr) => Some(Value::Cidr(Inet(i))),
+            (Datum::MacAddr(m), ScalarType::MacAddr) => Some(Value::MacAddr(MacAddr(m))),
+            (Datum::UInt32(i), ScalarType::Enum { labels, .. }) => {
+                Some(Value::Enum(labels[usize::cast_from(i)].clone()))
+            }
             (Datum::Date(d), ScalarType::Date) => Some(Value::Date(d)),
             (Datum::Time(t), ScalarType::Time) => Some(Value::Time(t)),
             (Datum::Timestamp(ts), ScalarType::Timestamp { .. }) => Some(Value::Timestamp(ts)),
@@ -313,6 +328,19 @@ impl Value {
             }
             Value::MzAclItem(mz_acl_item) => Datum::MzAclItem(mz_acl_item),
             Value::AclItem(acl_item) => Datum::AclItem(acl_item),
+            Value::Inet(inet) | Value::Cidr(inet) => Datum::Inet(inet.0),
+            Value::MacAddr(mac_addr) => Datum::MacAddr(mac_addr.0),
+            Value::Enum(label) => {
+                let labels = match typ {
+                    Type::Enum { labels, .. } => labels,
+                    _ => panic!("Value::Enum should have type Type::Enum. Found {:?}", typ),
+                };
+                let ordinal = labels
+                    .iter()
+                    .position(|l| *l == label)
+                    .expect("Value::decode validates enum labels");
+                Datum::UInt32(u32::try_from(ordinal).expect("enum ordinal fits in a u32"))
+            }
         }
     }
 
@@ -397,6 +425,10 @@ impl Value {
             .expect("provided closure never fails"),
             Value::MzAclItem(mz_acl_item) => strconv::format_mz_acl_item(buf, *mz_acl_item),
             Value::AclItem(acl_item) => strconv::format_acl_item(buf, *acl_item),
+            Value::Inet(inet) => strconv::format_inet(buf, inet.0),
+            Value::Cidr(inet) => strconv::format_cidr(buf, inet.0),
+            Value::MacAddr(mac_addr) => strconv::format_mac_addr(buf, mac_addr.0),
+            Value::Enum(label) => strconv::format_string(buf, label),
         }
     }
 
@@ -535,6 +567,11 @@ impl Value {
                 Ok(postgres_types::IsNull::No)
             }
             Value::AclItem(_) => Err("aclitem has no binary encoding".into()),
+            Value::Inet(inet) => inet.to_sql(&PgType::INET, buf),
+            Value::Cidr(inet) => inet.to_sql(&PgType::CIDR, buf),
+            Value::MacAddr(mac_addr) => mac_addr.to_sql(&PgType::MACADDR, buf),
+            // The binary encoding of an enum value is the text of its label.
+            Value::Enum(label) => label.to_sql(&PgType::TEXT, buf),
         }
         .expect("encode_binary should never trigger a to_sql failure");
         if let IsNull::Yes = is_null {
@@ -627,6 +664,10 @@ impl Value {
             })?),
             Type::MzAclItem => Value::MzAclItem(strconv::parse_mz_acl_item(s)?),
             Type::AclItem => Value::AclItem(strconv::parse_acl_item(s)?),
+            Type::Inet => Value::Inet(Inet(strconv::parse_inet(s)?)),
+            Type::Cidr => Value::Cidr(Inet(strconv::parse_cidr(s)?)),
+            Type::MacAddr => Value::MacAddr(MacAddr(strconv::parse_mac_addr(s)?)),
+            Type::Enum { .. } => decode_enum(ty, s.to_owned())?,
         })
     }
 
@@ -697,10 +738,22 @@ impl Value {
                 Ok(Value::MzAclItem(mz_acl_item))
             }
             Type::AclItem => Err("aclitem has no binary encoding".into()),
+            Type::Inet => Inet::from_sql(ty.inner(), raw).map(Value::Inet),
+            Type::Cidr => Inet::from_sql(ty.inner(), raw).map(Value::Cidr),
+            Type::MacAddr => MacAddr::from_sql(ty.inner(), raw).map(Value::MacAddr),
+            Type::Enum { .. } => decode_enum(ty, String::from_sql(ty.inner(), raw)?),
         }
     }
 }
 
+/// Validates that `label` is one of the labels of the enum type `ty`.
+fn decode_enum(ty: &Type, label: String) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    match ty {
+        Type::Enum { labels, .. } if labels.contains(&label) => Ok(Value::Enum(label)),
+        _ => Err(format!("invalid input value for enum: \"{}\"", label).into()),
+    }
+}
+
 fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result<(), io::Error> {
     match elem {
         None => buf.put_i32(-1),
@@ -0,0 +1,152 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use bytes::{BufMut, BytesMut};
+use mz_repr::adt::inet::{Inet as ReprInet, MacAddr as ReprMacAddr, PGSQL_AF_INET, PGSQL_AF_INET6};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// A wrapper for the `repr` crate's [`Inet`](mz_repr::adt::inet::Inet) type
+/// that can be serialized to and deserialized from the PostgreSQL binary
+/// format of both `inet` and `cidr`.
+#[derive(Debug, Clone)]
+pub struct Inet(pub ReprInet);
+
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for Inet {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        // Postgres represents inets as the address family, the netmask length,
+        // a flag indicating whether the value is a cidr, the number of address
+        // bytes, and then the address bytes in network order.
+        //
+        // Postgres implementation: https://github.com/postgres/postgres/blob/REL_15_3/src/backend/utils/adt/network.c#L212-L248
+        out.put_u8(self.0.family_tag());
+        out.put_u8(self.0.masklen());
+        out.put_u8((*ty == Type::CIDR).into());
+        match self.0.addr() {
+            IpAddr::V4(a) => {
+                out.put_u8(4);
+                out.put_slice(&a.octets());
+            }
+            IpAddr::V6(a) => {
+                out.put_u8(16);
+                out.put_slice(&a.octets());
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Inet {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Inet, Box<dyn Error + Sync + Send>> {
+        let (family, masklen, nb, addr) = match raw {
+            [family, masklen, _is_cidr, nb, addr @ ..] => (*family, *masklen, *nb, addr),
+            _ => return Err("invalid length in external \"inet\" value".into()),
+        };
+        let addr = match (family, nb) {
+            (PGSQL_AF_INET, 4) => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(addr)?)),
+            (PGSQL_AF_INET6, 16) => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(addr)?)),
+            _ => return Err("invalid address family in external \"inet\" value".into()),
+        };
+        let inet = if *ty == Type::CIDR {
+            ReprInet::new_cidr(addr, masklen)?
+        } else {
+            ReprInet::new(addr, masklen)?
+        };
+        Ok(Inet(inet))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+}
+
+/// A wrapper for the `repr` crate's [`MacAddr`](mz_repr::adt::inet::MacAddr)
+/// type that can be serialized to and deserialized from the PostgreSQL binary
+/// format.
+#[derive(Debug, Clone)]
+pub struct MacAddr(pub ReprMacAddr);
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for MacAddr {
+    fn to_sql(
+        &self,
+        _: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        out.put_slice(&self.0.encode_binary());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::MACADDR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for MacAddr {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<MacAddr, Box<dyn Error + Sync + Send>> {
+        Ok(MacAddr(ReprMacAddr::decode_binary(raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::MACADDR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_inet_binary_roundtrip() {
+        for (s, ty) in [
+            ("192.168.1.5/24", Type::INET),
+            ("10.0.0.0/8", Type::CIDR),
+            ("2001:db8::1", Type::INET),
+        ] {
+            let inet = Inet(s.parse().unwrap());
+            let mut buf = BytesMut::new();
+            inet.to_sql(&ty, &mut buf).unwrap();
+            let decoded = Inet::from_sql(&ty, &buf).unwrap();
+            assert_eq!(inet.0, decoded.0);
+        }
+
+        // A cidr must not have bits set to the right of the netmask.
+        let mut buf = BytesMut::new();
+        Inet("192.168.1.5/24".parse().unwrap())
+            .to_sql(&Type::CIDR, &mut buf)
+            .unwrap();
+        assert!(Inet::from_sql(&Type::CIDR, &buf).is_err());
+    }
+}
@@ -12,6 +12,7 @@ use std::task::{Context, Poll};
 
 use async_trait::async_trait;
 use mz_ore::netio::AsyncReady;
+use mz_server_core::client_cert;
 use mz_server_core::TlsMode;
 use tokio::io::{self, AsyncRead, AsyncWrite, Interest, ReadBuf, Ready};
 use tokio_openssl::SslStream;
@@ -51,6 +52,20 @@ impl<A> Conn<A> {
 
         Ok(())
     }
+
+    /// Returns the identities of the client certificate presented during the
+    /// TLS handshake, if any.
+    ///
+    /// The certificate has already been verified during the handshake.
+    pub fn client_cert_identities(&self) -> Option<Vec<String>> {
+        match self {
+            Conn::Unencrypted(_) => None,
+            Conn::Ssl(stream) => {
+                let cert = stream.ssl().peer_certificate()?;
+                Some(client_cert::cert_identities(&cert))
+            }
+        }
+    }
 }
 
 impl<A> AsyncRead for Conn<A>
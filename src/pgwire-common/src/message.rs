@@ -182,6 +182,10 @@ pub enum FrontendMessage {
     Password {
         password: String,
     },
+
+    /// An authentication message, whose contents depend on the authentication
+    /// method in progress, e.g., a password or a SASL response.
+    RawAuthentication(Vec<u8>),
 }
 
 impl FrontendMessage {
@@ -202,6 +206,7 @@ impl FrontendMessage {
             FrontendMessage::CopyDone => "copy_done",
             FrontendMessage::CopyFail(_) => "copy_fail",
             FrontendMessage::Password { .. } => "password",
+            FrontendMessage::RawAuthentication(_) => "raw_authentication",
         }
     }
 }
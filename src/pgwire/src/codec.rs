@@ -28,6 +28,7 @@ use mz_pgwire_common::{
     input_err, parse_frame_len, Conn, Cursor, DecodeState, ErrorResponse, FrontendMessage,
     MAX_REQUEST_SIZE,
 };
+use mz_sql::session::scram::SCRAM_SHA_256;
 use tokio::io::{self, AsyncRead, AsyncWrite, Interest, Ready};
 use tokio::time::{self, Duration};
 use tokio_util::codec::{Decoder, Encoder, Framed};
@@ -221,6 +222,9 @@ impl Encoder<BackendMessage> for Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationSASL => b'R',
+            BackendMessage::AuthenticationSASLContinue(_) => b'R',
+            BackendMessage::AuthenticationSASLFinal(_) => b'R',
             BackendMessage::RowDescription(_) => b'T',
             BackendMessage::DataRow(_) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
@@ -245,6 +249,7 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::CopyOutResponse { .. } => b'H',
             BackendMessage::CopyData(_) => b'd',
             BackendMessage::CopyDone => b'c',
+            BackendMessage::NotificationResponse { .. } => b'A',
         };
         dst.put_u8(byte);
 
@@ -272,12 +277,34 @@ impl Encoder<BackendMessage> for Codec {
                 dst.put_slice(&data);
             }
             BackendMessage::CopyDone => (),
+            BackendMessage::NotificationResponse {
+                pid,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(pid);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::AuthenticationOk => {
                 dst.put_u32(0);
             }
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
+            BackendMessage::AuthenticationSASL => {
+                dst.put_u32(10);
+                dst.put_string(SCRAM_SHA_256);
+                dst.put_u8(b'\0');
+            }
+            BackendMessage::AuthenticationSASLContinue(data) => {
+                dst.put_u32(11);
+                dst.put_slice(&data);
+            }
+            BackendMessage::AuthenticationSASLFinal(data) => {
+                dst.put_u32(12);
+                dst.put_slice(&data);
+            }
             BackendMessage::RowDescription(fields) => {
                 dst.put_length_i16(fields.len())?;
                 for f in &fields {
@@ -468,7 +495,7 @@ impl Decoder for Codec {
                         b'X' => decode_terminate(buf)?,
 
                         // Authentication.
-                        b'p' => decode_password(buf)?,
+                        b'p' => decode_raw_authentication(buf, frame_len)?,
 
                         // Copy from flow.
                         b'f' => decode_copy_fail(buf)?,
@@ -497,10 +524,37 @@ fn decode_terminate(mut _buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Terminate)
 }
 
-fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
-    Ok(FrontendMessage::Password {
-        password: buf.read_cstr()?.to_owned(),
-    })
+fn decode_raw_authentication(
+    mut buf: Cursor,
+    frame_len: usize,
+) -> Result<FrontendMessage, io::Error> {
+    let mut data = Vec::with_capacity(frame_len);
+    for _ in 0..frame_len {
+        data.push(buf.read_byte()?);
+    }
+    Ok(FrontendMessage::RawAuthentication(data))
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] that
+/// responds to [`BackendMessage::AuthenticationCleartextPassword`].
+pub fn decode_password(data: &[u8]) -> Result<String, io::Error> {
+    let mut buf = Cursor::new(data);
+    Ok(buf.read_cstr()?.to_owned())
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] that
+/// responds to [`BackendMessage::AuthenticationSASL`], returning the selected
+/// mechanism and the initial client response.
+pub fn decode_sasl_initial_response(data: &[u8]) -> Result<(String, Vec<u8>), io::Error> {
+    let mut buf = Cursor::new(data);
+    let mechanism = buf.read_cstr()?.to_owned();
+    let mut initial_response = vec![];
+    // A length of -1 indicates that there is no initial response.
+    let len = buf.read_i32()?;
+    for _ in 0..len {
+        initial_response.push(buf.read_byte()?);
+    }
+    Ok((mechanism, initial_response))
 }
 
 fn decode_query(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
@@ -19,6 +19,10 @@ use mz_repr::{ColumnName, RelationDesc};
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    /// Requests SASL authentication with the SCRAM-SHA-256 mechanism.
+    AuthenticationSASL,
+    AuthenticationSASLContinue(Vec<u8>),
+    AuthenticationSASLFinal(Vec<u8>),
     CommandComplete {
         tag: String,
     },
@@ -48,6 +52,12 @@ pub enum BackendMessage {
     },
     CopyData(Vec<u8>),
     CopyDone,
+    NotificationResponse {
+        /// The ID of the connection that sent the notification.
+        pid: u32,
+        channel: String,
+        payload: String,
+    },
 }
 
 impl From<ErrorResponse> for BackendMessage {
@@ -10,48 +10,49 @@
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::future::Future;
+use std::net::IpAddr;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use std::{cmp, iter, mem};
 
 use byteorder::{ByteOrder, NetworkEndian};
-use futures::future::{pending, BoxFuture, FutureExt};
+use bytes::BytesMut;
+use futures::future::{pending, select_all, BoxFuture, FutureExt};
 use itertools::izip;
 use mz_adapter::client::RecordFirstRowStream;
 use mz_adapter::session::{
-    EndTransactionAction, InProgressRows, Portal, PortalState, TransactionStatus,
+    EndTransactionAction, InProgressRows, Portal, PortalState, RowBatchStream, TransactionStatus,
 };
 use mz_adapter::statement_logging::StatementEndedExecutionReason;
 use mz_adapter::{
     AdapterError, AdapterNotice, ExecuteContextExtra, ExecuteResponse, PeekResponseUnary,
-    RowsFuture,
-};
-use mz_frontegg_auth::{
-    Authentication as FronteggAuthentication, ExchangePasswordForTokenResponse,
 };
+use mz_frontegg_auth::{AuthenticatedUser, AuthenticationProvider};
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
+use mz_ore::task::{AbortOnDropHandle, JoinHandleExt};
 use mz_pgcopy::CopyFormatParams;
 use mz_pgwire_common::{ErrorResponse, Format, FrontendMessage, Severity, VERSIONS, VERSION_3};
-use mz_repr::user::ExternalUserMetadata;
 use mz_repr::{Datum, GlobalId, RelationDesc, RelationType, Row, RowArena, ScalarType};
+use mz_server_core::client_cert::IdentMap;
 use mz_server_core::TlsMode;
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{FetchDirection, Ident, Raw, Statement};
 use mz_sql::parse::StatementParseResult;
 use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
+use mz_sql::session::scram::{ScramError, ScramExchange, SCRAM_SHA_256};
 use mz_sql::session::user::{User, INTERNAL_USER_NAMES};
 use mz_sql::session::vars::{ConnectionCounter, DropConnection, Var, VarInput, MAX_COPY_FROM_SIZE};
 use postgres::error::SqlState;
 use tokio::io::{self, AsyncRead, AsyncWrite};
 use tokio::select;
-use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::time::{self};
-use tokio_stream::wrappers::UnboundedReceiverStream;
 use tracing::{debug, warn, Instrument};
 
-use crate::codec::FramedConn;
+use crate::codec::{decode_password, decode_sasl_initial_response, FramedConn};
 use crate::message::{self, BackendMessage};
 
 /// Reports whether the given stream begins with a pgwire handshake.
@@ -86,13 +87,21 @@ pub struct RunParams<'a, A> {
     pub version: i32,
     /// The parameters that the client provided in the startup message.
     pub params: BTreeMap<String, String>,
-    /// Frontegg authentication.
-    pub frontegg: Option<&'a FronteggAuthentication>,
+    /// The external authentication provider, like Frontegg.
+    pub auth_provider: Option<&'a dyn AuthenticationProvider>,
+    /// Whether to authenticate users with SCRAM-SHA-256 against the passwords
+    /// stored in the catalog, if there is no external authentication provider.
+    pub password_auth: bool,
+    /// Maps the identities of verified TLS client certificates to roles, if
+    /// client certificate authentication is enabled.
+    pub cert_ident_map: Option<&'a IdentMap>,
     /// Whether this is an internal server that permits access to restricted
     /// system resources.
     pub internal: bool,
     /// Global connection limit and count
     pub active_connection_count: Arc<Mutex<ConnectionCounter>>,
+    /// The IP address of the client, if known.
+    pub client_ip: Option<IpAddr>,
 }
 
 /// Runs a pgwire connection to completion.
@@ -112,9 +121,12 @@ pub async fn run<'a, A>(
         conn,
         version,
         mut params,
-        frontegg,
+        mut auth_provider,
+        mut password_auth,
+        cert_ident_map,
         internal,
         active_connection_count,
+        client_ip,
     }: RunParams<'a, A>,
 ) -> Result<(), io::Error>
 where
@@ -153,12 +165,39 @@ where
         return conn.send(err).await;
     }
 
-    let (mut session, is_expired) = if let Some(frontegg) = frontegg {
+    // A client certificate that maps to the requested user authenticates the
+    // connection on its own. Otherwise, fall back to the other authentication
+    // methods, if there are any.
+    if let Some(cert_ident_map) = cert_ident_map {
+        let cert_authenticated = conn
+            .inner()
+            .client_cert_identities()
+            .map_or(false, |identities| {
+                cert_ident_map.authorizes(&identities, &user)
+            });
+        if cert_authenticated {
+            auth_provider = None;
+            password_auth = false;
+        } else if auth_provider.is_none() && !password_auth {
+            warn!(%user, "pgwire connection failed certificate authentication");
+            return conn
+                .send(ErrorResponse::fatal(
+                    SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                    format!(
+                        "certificate authentication failed for user {}",
+                        user.quoted()
+                    ),
+                ))
+                .await;
+        }
+    }
+
+    let (mut session, is_expired) = if let Some(auth_provider) = auth_provider {
         conn.send(BackendMessage::AuthenticationCleartextPassword)
             .await?;
         conn.flush().await?;
         let password = match conn.recv().await? {
-            Some(FrontendMessage::Password { password }) => password,
+            Some(FrontendMessage::RawAuthentication(data)) => decode_password(&data)?,
             _ => {
                 return conn
                     .send(ErrorResponse::fatal(
@@ -169,46 +208,50 @@ where
             }
         };
 
-        let auth_response = frontegg.exchange_password_for_token(&password, user).await;
+        let auth_response = auth_provider.authenticate_password(user, &password).await;
         match auth_response {
-            Ok(result) => {
-                let ExchangePasswordForTokenResponse {
-                    claims,
-                    refresh_updates,
-                    valid_until_fut,
-                } = result;
-
-                // Create a session based on the validated claims.
-                //
-                // In particular, it's important that the username come from the claims, as
-                // Frontegg may return an email address with different casing than the user
-                // supplied via the pgwire username field. We want to use the Frontegg casing as
-                // canonical.
+            Ok(AuthenticatedUser {
+                name,
+                metadata,
+                refresh_updates,
+                valid_until_fut,
+            }) => {
+                // The provider may spell the user's name differently than the
+                // client did, so check again that the name is not reserved.
+                if mz_adapter::catalog::is_reserved_role_name(&name) {
+                    let msg = format!("unauthorized login to user '{name}'");
+                    return conn
+                        .send(ErrorResponse::fatal(SqlState::INSUFFICIENT_PRIVILEGE, msg))
+                        .await;
+                }
+
+                // Create a session based on the authenticated user. In
+                // particular, it's important that the username come from the
+                // provider, as the provider's spelling is canonical.
                 let mut session = adapter_client.new_session(
                     conn.conn_id().clone(),
                     User {
-                        name: claims.email.clone(),
-                        external_metadata: Some(ExternalUserMetadata {
-                            user_id: claims.user_id,
-                            admin: claims.is_admin,
-                        }),
+                        name,
+                        external_metadata: Some(metadata),
                     },
                 );
 
                 // Whenever refreshing a token, if the metadata has changed we'll update our
                 // session vars.
-                if session
-                    .register_external_metadata_transmitter(refresh_updates)
-                    .is_err()
-                {
-                    // TODO(parkmycar): Promote this to a panic as long as we don't see it in production.
-                    tracing::error!("Double register of external metadata transmitter!");
-                    return conn
-                        .send(ErrorResponse::fatal(
-                            SqlState::INTERNAL_ERROR,
-                            "resource already registered",
-                        ))
-                        .await;
+                if let Some(refresh_updates) = refresh_updates {
+                    if session
+                        .register_external_metadata_transmitter(refresh_updates)
+                        .is_err()
+                    {
+                        // TODO(parkmycar): Promote this to a panic as long as we don't see it in production.
+                        tracing::error!("Double register of external metadata transmitter!");
+                        return conn
+                            .send(ErrorResponse::fatal(
+                                SqlState::INTERNAL_ERROR,
+                                "resource already registered",
+                            ))
+                            .await;
+                    }
                 }
 
                 (session, valid_until_fut.left_future())
@@ -224,6 +267,12 @@ where
             }
         }
     } else {
+        if password_auth {
+            if let Err(err) = authenticate_scram(conn, &adapter_client, &user).await? {
+                warn!(?err, "pgwire connection failed authentication");
+                return conn.send(err).await;
+            }
+        }
         let session = adapter_client.new_session(
             conn.conn_id().clone(),
             User {
@@ -231,11 +280,15 @@ where
                 external_metadata: None,
             },
         );
-        // No frontegg check, so is_expired never resolves.
+        // No external authentication, so is_expired never resolves.
         let is_expired = pending().right_future();
         (session, is_expired)
     };
 
+    if let Some(client_ip) = client_ip {
+        session.set_client_ip(client_ip);
+    }
+
     for (name, value) in params {
         let settings = match name.as_str() {
             "options" => match parse_options(&value) {
@@ -309,6 +362,7 @@ where
     let machine = StateMachine {
         conn,
         adapter_client,
+        listeners: BTreeMap::new(),
     };
 
     select! {
@@ -341,6 +395,84 @@ where
 ///
 /// From Postgres, see pg_split_opts in postinit.c and process_postgres_switches
 /// in postgres.c.
+/// Authenticates `user` with SCRAM-SHA-256 against the password of their role.
+///
+/// Returns `Ok(Err(_))` with the error to send to the client if authentication
+/// fails. Unknown users and users without a password go through the full
+/// exchange and fail at the end, like users that supply the wrong password.
+async fn authenticate_scram<A>(
+    conn: &mut FramedConn<A>,
+    adapter_client: &mz_adapter::Client,
+    user: &str,
+) -> Result<Result<(), ErrorResponse>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
+{
+    fn scram_error(err: ScramError, user: &str) -> ErrorResponse {
+        match err {
+            ScramError::InvalidPassword => ErrorResponse::fatal(
+                SqlState::INVALID_PASSWORD,
+                format!("password authentication failed for user {}", user.quoted()),
+            ),
+            ScramError::Malformed | ScramError::ChannelBindingUnsupported => {
+                ErrorResponse::fatal(SqlState::PROTOCOL_VIOLATION, err.to_string())
+            }
+            ScramError::OpenSsl(_) => {
+                ErrorResponse::fatal(SqlState::INTERNAL_ERROR, err.to_string())
+            }
+        }
+    }
+
+    conn.send(BackendMessage::AuthenticationSASL).await?;
+    conn.flush().await?;
+    let (mechanism, client_first_message) = match conn.recv().await? {
+        Some(FrontendMessage::RawAuthentication(data)) => decode_sasl_initial_response(&data)?,
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "expected SASL response",
+            )))
+        }
+    };
+    if mechanism != SCRAM_SHA_256 {
+        return Ok(Err(ErrorResponse::fatal(
+            SqlState::PROTOCOL_VIOLATION,
+            "client selected an invalid SASL authentication mechanism",
+        )));
+    }
+
+    let verifier = adapter_client.role_password_verifier(user).await;
+    let exchange = match ScramExchange::start(user, verifier, &client_first_message) {
+        Ok(exchange) => exchange,
+        Err(err) => return Ok(Err(scram_error(err, user))),
+    };
+    conn.send(BackendMessage::AuthenticationSASLContinue(
+        exchange.server_first_message().as_bytes().to_vec(),
+    ))
+    .await?;
+    conn.flush().await?;
+
+    let client_final_message = match conn.recv().await? {
+        Some(FrontendMessage::RawAuthentication(data)) => data,
+        _ => {
+            return Ok(Err(ErrorResponse::fatal(
+                SqlState::PROTOCOL_VIOLATION,
+                "expected SASL response",
+            )))
+        }
+    };
+    match exchange.finish(&client_final_message) {
+        Ok(server_final_message) => {
+            conn.send(BackendMessage::AuthenticationSASLFinal(
+                server_final_message.into_bytes(),
+            ))
+            .await?;
+            Ok(Ok(()))
+        }
+        Err(err) => Ok(Err(scram_error(err, user))),
+    }
+}
+
 fn parse_options(value: &str) -> Result<Vec<(String, String)>, ()> {
     let opts = split_options(value);
     let mut pairs = Vec::with_capacity(opts.len());
@@ -428,6 +560,55 @@ enum State {
 struct StateMachine<'a, A> {
     conn: &'a mut FramedConn<A>,
     adapter_client: mz_adapter::SessionClient,
+    /// The channels on which the session is listening, by name.
+    listeners: BTreeMap<String, Listener>,
+}
+
+/// The maximum number of notifications that may be pending delivery on a
+/// channel. Notifications beyond this limit are discarded, and the client is
+/// told how many were discarded once it catches up.
+const MAX_PENDING_NOTIFICATIONS: usize = 10_000;
+
+/// The maximum length of the payload of a notification, as in PostgreSQL.
+/// Notifications with longer payloads are discarded.
+const MAX_NOTIFICATION_PAYLOAD_LEN: usize = 8000;
+
+/// A channel on which a session is listening.
+struct Listener {
+    /// The description of the relation whose updates are delivered on the
+    /// channel.
+    desc: RelationDesc,
+    /// The notifications pending delivery on the channel.
+    rx: mpsc::Receiver<Notification>,
+    /// The task that moves updates to the relation from the subscription into
+    /// `rx`.
+    _forwarder: AbortOnDropHandle<()>,
+}
+
+impl Listener {
+    fn new(desc: RelationDesc, updates: RowBatchStream) -> Listener {
+        let (tx, rx) = mpsc::channel(MAX_PENDING_NOTIFICATIONS);
+        let forwarder =
+            mz_ore::task::spawn(|| "pgwire::listener", forward_notifications(updates, tx));
+        Listener {
+            desc,
+            rx,
+            _forwarder: forwarder.abort_on_drop(),
+        }
+    }
+}
+
+/// A notification pending delivery on a channel.
+#[derive(Debug)]
+enum Notification {
+    /// An update to the relation.
+    Row(Row),
+    /// An error computing the updates to the relation, which might be
+    /// transient.
+    Error(String),
+    /// The number of notifications that were discarded because the client
+    /// didn't keep up.
+    Discarded(usize),
 }
 
 enum SendRowsEndedReason {
@@ -465,6 +646,12 @@ where
     }
 
     async fn advance_ready(&mut self) -> Result<State, io::Error> {
+        // Like PostgreSQL, only deliver notifications between transactions.
+        let notify = matches!(
+            self.adapter_client.session().transaction(),
+            TransactionStatus::Default
+        );
+
         // Handle timeouts first so we don't execute any statements when there's a pending timeout.
         let message = select! {
             biased;
@@ -490,6 +677,10 @@ where
             },
             // `recv()` is cancel-safe as per it's docs.
             message = self.conn.recv() => message?,
+            // `next_notification()` is cancel-safe as per its docs.
+            (channel, batch) = next_notification(&mut self.listeners), if notify => {
+                return self.notify(channel, batch).await;
+            },
         };
 
         self.adapter_client
@@ -577,7 +768,8 @@ where
             Some(FrontendMessage::CopyData(_))
             | Some(FrontendMessage::CopyDone)
             | Some(FrontendMessage::CopyFail(_))
-            | Some(FrontendMessage::Password { .. }) => State::Drain,
+            | Some(FrontendMessage::Password { .. })
+            | Some(FrontendMessage::RawAuthentication(_)) => State::Drain,
             None => State::Done,
         };
 
@@ -1323,40 +1515,6 @@ where
         self.flush().await
     }
 
-    // Converts a RowsFuture to a stream while also checking for connection close.
-    async fn row_future_to_stream<'s, 'p>(
-        &'s mut self,
-        parent: &'p tracing::Span,
-        mut rows: RowsFuture,
-    ) -> Result<UnboundedReceiver<PeekResponseUnary>, io::Error>
-    where
-        'p: 's,
-    {
-        // Do not include self.adapter_client.canceled() here because cancel messages
-        // will propagate through the PeekResponse. select is safe to use because if
-        // close finishes, rows is canceled, which is the intended behavior.
-        let span = tracing::debug_span!(parent: parent, "row_future_to_stream");
-        async move {
-            loop {
-                tokio::select! {
-                    err = self.conn.wait_closed() => return Err(err),
-                    rows = &mut rows => {
-                        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-                        tx.send(rows).expect("send must succeed");
-                        return Ok(rx);
-                    }
-                    notice = self.adapter_client.session().recv_notice() => {
-                        self.send(notice.into_response())
-                            .await?;
-                        self.conn.flush().await?;
-                    }
-                }
-            }
-        }
-        .instrument(span)
-        .await
-    }
-
     #[allow(clippy::too_many_arguments)]
     async fn send_execute_response(
         &mut self,
@@ -1420,18 +1578,17 @@ where
                 )
                 .await
             }
-            ExecuteResponse::SendingRows { future: rx, span } => {
+            ExecuteResponse::SendingRows { rows, span } => {
                 let row_desc =
                     row_desc.expect("missing row description for ExecuteResponse::SendingRows");
 
                 let span = tracing::debug_span!(parent: &span, "send_execute_response");
-                let rows = self.row_future_to_stream(&span, rx).await?;
 
                 self.send_rows(
                     row_desc,
                     portal_name,
                     InProgressRows::new(RecordFirstRowStream::new(
-                        Box::new(UnboundedReceiverStream::new(rows)),
+                        rows,
                         execute_started,
                         &self.adapter_client,
                     )),
@@ -1544,6 +1701,26 @@ where
                     .retire_execute(ctx_extra, statement_ended_execution_reason);
                 return result;
             }
+            ExecuteResponse::Listening { channel, updates } => {
+                if let Some((desc, updates)) = updates {
+                    self.listeners.insert(channel, Listener::new(desc, updates));
+                }
+                command_complete!()
+            }
+            ExecuteResponse::Unlistened { channel } => {
+                match channel {
+                    Some(channel) => {
+                        self.listeners.remove(&channel);
+                    }
+                    None => self.listeners.clear(),
+                }
+                command_complete!()
+            }
+            ExecuteResponse::DiscardedAll => {
+                // Discarding all session state includes `UNLISTEN *`.
+                self.listeners.clear();
+                command_complete!()
+            }
             ExecuteResponse::CopyTo { format, resp } => {
                 let row_desc =
                     row_desc.expect("missing row description for ExecuteResponse::CopyTo");
@@ -1584,11 +1761,7 @@ where
                             .retire_execute(ctx_extra, statement_ended_execution_reason);
                         return result;
                     }
-                    ExecuteResponse::SendingRows {
-                        future: rows_rx,
-                        span,
-                    } => {
-                        let rows = self.row_future_to_stream(&span, rows_rx).await?;
+                    ExecuteResponse::SendingRows { rows, span: _ } => {
                         // We don't need to finalize execution here;
                         // it was already done in the
                         // coordinator. Just extract the state and
@@ -1598,7 +1771,7 @@ where
                                 format,
                                 row_desc,
                                 RecordFirstRowStream::new(
-                                    Box::new(UnboundedReceiverStream::new(rows)),
+                                    rows,
                                     execute_started,
                                     &self.adapter_client,
                                 ),
@@ -1687,12 +1860,14 @@ where
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedPolicy
+            | ExecuteResponse::CreatedSubscription
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::Comment
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
-            | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
@@ -2183,6 +2358,90 @@ where
         Ok(State::Ready)
     }
 
+    /// Delivers the pending notifications on the specified channel, starting
+    /// with `first`.
+    async fn notify(
+        &mut self,
+        channel: String,
+        first: Option<Notification>,
+    ) -> Result<State, io::Error> {
+        let Some(first) = first else {
+            // The subscription ended, e.g., because the relation was dropped.
+            // The adapter has sent a notice explaining why.
+            self.listeners.remove(&channel);
+            self.send_pending_notices().await?;
+            return self.flush().await;
+        };
+        let pid = self.adapter_client.session().conn_id().unhandled();
+        let listener = self
+            .listeners
+            .get_mut(&channel)
+            .expect("notification for listened channel");
+        let mut messages = Vec::new();
+        let mut too_long = 0;
+        let mut next = Some(first);
+        while let Some(notification) = next {
+            match notification {
+                Notification::Row(row) => {
+                    let payload = notification_payload(&listener.desc, &row);
+                    if payload.len() >= MAX_NOTIFICATION_PAYLOAD_LEN {
+                        too_long += 1;
+                    } else {
+                        messages.push(BackendMessage::NotificationResponse {
+                            pid,
+                            channel: channel.clone(),
+                            payload,
+                        });
+                    }
+                }
+                Notification::Error(text) => {
+                    // The error might be transient, so keep listening.
+                    messages.push(
+                        ErrorResponse::notice(
+                            SqlState::WARNING,
+                            format!("error computing notifications for channel {channel}: {text}"),
+                        )
+                        .into(),
+                    );
+                }
+                Notification::Discarded(count) => {
+                    messages.push(
+                        ErrorResponse::notice(
+                            SqlState::WARNING,
+                            format!(
+                                "discarded {count} notifications on channel {channel} \
+                                 because the client did not keep up"
+                            ),
+                        )
+                        .into(),
+                    );
+                }
+            }
+            // Deliver everything that is already pending in one flush, but
+            // no more, so that a busy channel doesn't starve the client's
+            // requests.
+            next = if messages.len() < MAX_PENDING_NOTIFICATIONS {
+                listener.rx.try_recv().ok()
+            } else {
+                None
+            };
+        }
+        if too_long > 0 {
+            messages.push(
+                ErrorResponse::notice(
+                    SqlState::WARNING,
+                    format!(
+                        "discarded {too_long} notifications on channel {channel} \
+                         because their payload exceeded {MAX_NOTIFICATION_PAYLOAD_LEN} bytes"
+                    ),
+                )
+                .into(),
+            );
+        }
+        self.send_all(messages).await?;
+        self.flush().await
+    }
+
     async fn send_pending_notices(&mut self) -> Result<(), io::Error> {
         let notices = self
             .adapter_client
@@ -2246,6 +2505,132 @@ where
     }
 }
 
+/// Waits for the next notification on any of the channels in `listeners`.
+///
+/// Returns the name of the channel and the notification, or `None` if the
+/// channel's subscription has ended. Never returns if `listeners` is empty.
+///
+/// # Cancel safety
+///
+/// This method is cancel safe. If it is used in a `select!` and another branch
+/// completes first, no notification has been received.
+async fn next_notification(
+    listeners: &mut BTreeMap<String, Listener>,
+) -> (String, Option<Notification>) {
+    if listeners.is_empty() {
+        return pending().await;
+    }
+    // `Receiver::recv` is cancel safe.
+    let recvs = listeners.iter_mut().map(|(channel, listener)| {
+        Box::pin(async move { (channel.clone(), listener.rx.recv().await) })
+    });
+    let (next, _index, _rest) = select_all(recvs).await;
+    next
+}
+
+/// Moves the updates from a subscription into the bounded channel of a
+/// [`Listener`], until either the subscription ends or the listener is
+/// dropped.
+///
+/// The subscription's channel is unbounded, so it is drained even while the
+/// listener can't deliver notifications, e.g., because the session is in a
+/// transaction or the client is slow. Updates that don't fit are discarded
+/// and reported as a single [`Notification::Discarded`] once there is room.
+async fn forward_notifications(mut updates: RowBatchStream, tx: mpsc::Sender<Notification>) {
+    let mut discarded = 0;
+    loop {
+        select! {
+            // `Sender::reserve` is cancel safe.
+            permit = tx.reserve(), if discarded > 0 => match permit {
+                Ok(permit) => {
+                    permit.send(Notification::Discarded(discarded));
+                    discarded = 0;
+                }
+                Err(_) => return,
+            },
+            // `UnboundedReceiver::recv` is cancel safe.
+            batch = updates.recv() => {
+                let notifications: Vec<_> = match batch {
+                    Some(PeekResponseUnary::Rows(rows)) => {
+                        rows.into_iter().map(Notification::Row).collect()
+                    }
+                    Some(PeekResponseUnary::Error(text)) => vec![Notification::Error(text)],
+                    Some(PeekResponseUnary::Canceled) | None => break,
+                };
+                for notification in notifications {
+                    if !try_forward(&tx, notification, &mut discarded) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+    if discarded > 0 {
+        let _ = tx.send(Notification::Discarded(discarded)).await;
+    }
+}
+
+/// Sends a notification to a [`Listener`] if there is room for it, first
+/// reporting any previously discarded notifications. Returns whether the
+/// listener is still around.
+fn try_forward(
+    tx: &mpsc::Sender<Notification>,
+    notification: Notification,
+    discarded: &mut usize,
+) -> bool {
+    if *discarded > 0 {
+        match tx.try_send(Notification::Discarded(*discarded)) {
+            Ok(()) => *discarded = 0,
+            Err(TrySendError::Full(_)) => {
+                *discarded += 1;
+                return true;
+            }
+            Err(TrySendError::Closed(_)) => return false,
+        }
+    }
+    match tx.try_send(notification) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => {
+            *discarded += 1;
+            true
+        }
+        Err(TrySendError::Closed(_)) => false,
+    }
+}
+
+/// Encodes an update to a relation as the payload of a notification.
+///
+/// The payload is a JSON object that contains the `mz_timestamp` and `mz_diff`
+/// of the update, as in the output of `SUBSCRIBE`, and the text representation
+/// of each column of the updated row.
+fn notification_payload(desc: &RelationDesc, row: &Row) -> String {
+    let mut datums = row.iter();
+    let mut buf = BytesMut::new();
+    let mut text = |datum, typ: &ScalarType| match mz_pgrepr::Value::from_datum(datum, typ) {
+        Some(value) => {
+            buf.clear();
+            value.encode_text(&mut buf);
+            serde_json::Value::String(String::from_utf8_lossy(&buf).into_owned())
+        }
+        None => serde_json::Value::Null,
+    };
+
+    let mut payload = serde_json::Map::new();
+    let mz_timestamp = datums
+        .next()
+        .expect("subscribe rows begin with mz_timestamp");
+    payload.insert(
+        "mz_timestamp".into(),
+        text(mz_timestamp, &ScalarType::Numeric { max_scale: None }),
+    );
+    let mz_diff = datums.next().expect("subscribe rows contain mz_diff");
+    payload.insert("mz_diff".into(), mz_diff.unwrap_int64().into());
+    for ((name, typ), datum) in desc.iter().zip(datums) {
+        payload.insert(name.as_str().into(), text(datum, &typ.scalar_type));
+    }
+    serde_json::Value::Object(payload).to_string()
+}
+
 fn pad_formats(formats: Vec<Format>, n: usize) -> Result<Vec<Format>, String> {
     match (formats.len(), n) {
         (0, e) => Ok(vec![Format::Text; e]),
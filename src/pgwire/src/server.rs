@@ -9,15 +9,18 @@
 
 use std::fmt;
 use std::future::Future;
+use std::net::SocketAddr;
 use std::pin::Pin;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
-use mz_frontegg_auth::Authentication as FronteggAuthentication;
+use mz_frontegg_auth::AuthenticationProvider;
 use mz_ore::netio::AsyncReady;
 use mz_pgwire_common::{
     decode_startup, Conn, FrontendStartupMessage, ACCEPT_SSL_ENCRYPTION, REJECT_ENCRYPTION,
 };
+use mz_server_core::client_cert::IdentMap;
+use mz_server_core::proxy_protocol::ProxyHeader;
 use mz_server_core::{ConnectionHandler, TlsConfig};
 use mz_sql::session::vars::ConnectionCounter;
 use openssl::ssl::Ssl;
@@ -40,12 +43,24 @@ pub struct Config {
     /// If not present, then TLS is not enabled, and clients requests to
     /// negotiate TLS will be rejected.
     pub tls: Option<TlsConfig>,
-    /// The Frontegg authentication configuration.
+    /// The external authentication provider, like Frontegg or an OpenID
+    /// Connect provider.
     ///
-    /// If present, Frontegg authentication is enabled, and users may present
-    /// a valid Frontegg API token as a password to authenticate. Otherwise,
-    /// password authentication is disabled.
-    pub frontegg: Option<FronteggAuthentication>,
+    /// If present, users must present credentials that the provider accepts,
+    /// like a Frontegg API token or a JWT, as their password to authenticate.
+    /// Otherwise, password authentication is disabled.
+    pub auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    /// Whether to require users to authenticate with SCRAM-SHA-256 against
+    /// the password of their role, as stored in the catalog.
+    ///
+    /// Ignored if `auth_provider` is present.
+    pub password_auth: bool,
+    /// Maps the identities of verified TLS client certificates to roles.
+    ///
+    /// If present, clients whose certificate maps to the requested user are
+    /// authenticated without a password. If no other authentication method is
+    /// enabled, all other clients are rejected.
+    pub cert_ident_map: Option<Arc<IdentMap>>,
     /// The registry entries that the pgwire server uses to report metrics.
     pub metrics: MetricsConfig,
     /// Whether this is an internal server that permits access to restricted
@@ -53,16 +68,22 @@ pub struct Config {
     pub internal: bool,
     /// Global connection limit and count
     pub active_connection_count: Arc<Mutex<ConnectionCounter>>,
+    /// Whether connections begin with a PROXY protocol version 2 header, as
+    /// sent by balancerd, that carries the address of the client.
+    pub proxy_protocol: bool,
 }
 
 /// A server that communicates with clients via the pgwire protocol.
 pub struct Server {
     tls: Option<TlsConfig>,
     adapter_client: mz_adapter::Client,
-    frontegg: Option<FronteggAuthentication>,
+    auth_provider: Option<Arc<dyn AuthenticationProvider>>,
+    password_auth: bool,
+    cert_ident_map: Option<Arc<IdentMap>>,
     metrics: Metrics,
     internal: bool,
     active_connection_count: Arc<Mutex<ConnectionCounter>>,
+    proxy_protocol: bool,
 }
 
 #[async_trait]
@@ -70,10 +91,11 @@ impl mz_server_core::Server for Server {
     const NAME: &'static str = "pgwire";
 
     fn handle_connection(&self, conn: TcpStream) -> ConnectionHandler {
+        let peer_addr = conn.peer_addr().ok();
         // Using fully-qualified syntax means we won't accidentally call
         // ourselves (i.e., silently infinitely recurse) if the name or type of
         // `crate::Server::handle_connection` changes.
-        Box::pin(crate::Server::handle_connection(self, conn))
+        Box::pin(crate::Server::handle_connection(self, conn, peer_addr))
     }
 }
 
@@ -83,33 +105,49 @@ impl Server {
         Server {
             tls: config.tls,
             adapter_client: config.adapter_client,
-            frontegg: config.frontegg,
+            auth_provider: config.auth_provider,
+            password_auth: config.password_auth,
+            cert_ident_map: config.cert_ident_map,
             metrics: Metrics::new(config.metrics, config.internal),
             internal: config.internal,
             active_connection_count: config.active_connection_count,
+            proxy_protocol: config.proxy_protocol,
         }
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
     pub fn handle_connection<A>(
         &self,
-        conn: A,
+        mut conn: A,
+        peer_addr: Option<SocketAddr>,
     ) -> impl Future<Output = Result<(), anyhow::Error>> + 'static + Send
     where
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + fmt::Debug + 'static,
     {
         let mut adapter_client = self.adapter_client.clone();
-        let frontegg = self.frontegg.clone();
+        let auth_provider = self.auth_provider.clone();
+        let password_auth = self.password_auth;
+        let cert_ident_map = self.cert_ident_map.clone();
         let tls = self.tls.clone();
         let internal = self.internal;
         let metrics = self.metrics.clone();
         let active_connection_count = Arc::clone(&self.active_connection_count);
+        let proxy_protocol = self.proxy_protocol;
         // TODO(guswynn): remove this redundant_closure_call
         #[allow(clippy::redundant_closure_call)]
         async move {
             let result = (|| {
                 async move {
                     let conn_id = adapter_client.new_conn_id()?;
+                    let mut client_ip = peer_addr.map(|addr| addr.ip());
+                    if proxy_protocol {
+                        // The peer is a balancer, which reports the address of
+                        // the client it is proxying, if any.
+                        if let Some(header) = ProxyHeader::read(&mut conn).await? {
+                            trace!("cid={} recv=ProxyHeader({:?})", conn_id, header);
+                            client_ip = Some(header.source.ip());
+                        }
+                    }
                     let mut conn = Conn::Unencrypted(conn);
                     loop {
                         let message = decode_startup(&mut conn).await?;
@@ -133,9 +171,12 @@ impl Server {
                                     conn: &mut conn,
                                     version,
                                     params,
-                                    frontegg: frontegg.as_ref(),
+                                    auth_provider: auth_provider.as_deref(),
+                                    password_auth,
+                                    cert_ident_map: cert_ident_map.as_deref(),
                                     internal,
                                     active_connection_count,
+                                    client_ip,
                                 })
                                 .await?;
                                 conn.flush().await?;
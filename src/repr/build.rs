@@ -87,6 +87,7 @@ fn main() {
             &[
                 "repr/src/antichain.proto",
                 "repr/src/global_id.proto",
+                "repr/src/refresh_schedule.proto",
                 "repr/src/row.proto",
                 "repr/src/strconv.proto",
                 "repr/src/relation_and_scalar.proto",
@@ -96,6 +97,7 @@ fn main() {
                 "repr/src/adt/char.proto",
                 "repr/src/adt/date.proto",
                 "repr/src/adt/datetime.proto",
+                "repr/src/adt/inet.proto",
                 "repr/src/adt/interval.proto",
                 "repr/src/adt/mz_acl_item.proto",
                 "repr/src/adt/numeric.proto",
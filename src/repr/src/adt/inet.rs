@@ -0,0 +1,619 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Network address types.
+//!
+//! [`Inet`] backs both the PostgreSQL `inet` and `cidr` types. The two share a
+//! representation; a `cidr` is an `inet` whose bits to the right of the
+//! netmask are all zero. [`MacAddr`] backs the `macaddr` type.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+use columnation::{Columnation, CopyRegion};
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.inet.rs"));
+
+/// The address family tag PostgreSQL uses for IPv4 addresses in the binary
+/// encoding of `inet` and `cidr`.
+pub const PGSQL_AF_INET: u8 = 2;
+/// The address family tag PostgreSQL uses for IPv6 addresses in the binary
+/// encoding of `inet` and `cidr`.
+pub const PGSQL_AF_INET6: u8 = 3;
+
+/// An IPv4 or IPv6 host address and optional netmask.
+///
+/// See: <https://www.postgresql.org/docs/current/datatype-net-types.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Inet {
+    addr: IpAddr,
+    masklen: u8,
+}
+
+impl Inet {
+    /// Constructs a new `inet` value, validating that `masklen` fits the
+    /// address family of `addr`.
+    pub fn new(addr: IpAddr, masklen: u8) -> Result<Inet, Error> {
+        let max = max_masklen(&addr);
+        if masklen > max {
+            bail!("invalid mask length: {masklen}");
+        }
+        Ok(Inet { addr, masklen })
+    }
+
+    /// Constructs a new `cidr` value, additionally validating that `addr` has
+    /// no bits set to the right of the netmask.
+    pub fn new_cidr(addr: IpAddr, masklen: u8) -> Result<Inet, Error> {
+        let inet = Inet::new(addr, masklen)?;
+        if !inet.is_network() {
+            bail!("value has bits set to right of mask");
+        }
+        Ok(inet)
+    }
+
+    /// Constructs a host address with the maximum netmask for its family.
+    pub fn from_host(addr: IpAddr) -> Inet {
+        Inet {
+            addr,
+            masklen: max_masklen(&addr),
+        }
+    }
+
+    /// Returns the address, including any bits to the right of the netmask.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the length of the netmask in bits.
+    pub fn masklen(&self) -> u8 {
+        self.masklen
+    }
+
+    /// Returns 4 for IPv4 addresses and 6 for IPv6 addresses, like
+    /// PostgreSQL's `family` function.
+    pub fn family(&self) -> i32 {
+        match self.addr {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 6,
+        }
+    }
+
+    /// Returns the length of the netmask that covers exactly one address in
+    /// this value's family.
+    pub fn max_masklen(&self) -> u8 {
+        max_masklen(&self.addr)
+    }
+
+    /// Reports whether no bits to the right of the netmask are set, i.e.
+    /// whether this value is a valid `cidr`.
+    pub fn is_network(&self) -> bool {
+        self.bits() & !self.mask(self.masklen) == 0
+    }
+
+    /// Returns this value with all bits to the right of the netmask cleared.
+    pub fn network(&self) -> Inet {
+        Inet {
+            addr: self.with_bits(self.bits() & self.mask(self.masklen)),
+            masklen: self.masklen,
+        }
+    }
+
+    /// Returns the netmask for this value's network as a host address.
+    pub fn netmask(&self) -> Inet {
+        Inet::from_host(self.with_bits(self.mask(self.masklen)))
+    }
+
+    /// Returns the broadcast address for this value's network.
+    pub fn broadcast(&self) -> Inet {
+        let width_mask = self.mask(self.max_masklen());
+        Inet {
+            addr: self.with_bits((self.bits() | !self.mask(self.masklen)) & width_mask),
+            masklen: self.masklen,
+        }
+    }
+
+    /// Returns this value with its netmask length replaced by `masklen`.
+    ///
+    /// The address bits are left untouched, so the result may have bits set
+    /// to the right of the new netmask.
+    pub fn with_masklen(&self, masklen: u8) -> Result<Inet, Error> {
+        Inet::new(self.addr, masklen)
+    }
+
+    /// Reports whether `other` is strictly contained within this value's
+    /// network, i.e. `other << self`.
+    pub fn contains(&self, other: &Inet) -> bool {
+        other.masklen > self.masklen && self.contains_or_equals(other)
+    }
+
+    /// Reports whether `other` is contained within or equal to this value's
+    /// network, i.e. `other <<= self`.
+    pub fn contains_or_equals(&self, other: &Inet) -> bool {
+        self.family() == other.family()
+            && other.masklen >= self.masklen
+            && (other.bits() & self.mask(self.masklen)) == (self.bits() & self.mask(self.masklen))
+    }
+
+    /// Reports whether either value contains or equals the other, i.e.
+    /// `self && other`.
+    pub fn overlaps(&self, other: &Inet) -> bool {
+        self.contains_or_equals(other) || other.contains_or_equals(self)
+    }
+
+    /// Returns a value that formats this address as a `cidr`, i.e. always
+    /// including the netmask length.
+    pub fn display_cidr(&self) -> impl fmt::Display + '_ {
+        CidrDisplay(self)
+    }
+
+    /// The address as an integer, with IPv4 addresses zero-extended.
+    fn bits(&self) -> u128 {
+        match self.addr {
+            IpAddr::V4(a) => u128::from(u32::from(a)),
+            IpAddr::V6(a) => u128::from(a),
+        }
+    }
+
+    /// Constructs an address of this value's family from an integer produced
+    /// by [`Inet::bits`].
+    fn with_bits(&self, bits: u128) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(
+                u32::try_from(bits).expect("ipv4 fits in u32"),
+            )),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+        }
+    }
+
+    /// Returns a mask with the leading `masklen` bits of this value's family
+    /// set.
+    fn mask(&self, masklen: u8) -> u128 {
+        let width = u32::from(self.max_masklen());
+        let masklen = u32::from(masklen);
+        if masklen == 0 {
+            0
+        } else {
+            (u128::MAX >> (128 - masklen)) << (width - masklen)
+        }
+    }
+}
+
+// PostgreSQL orders network addresses first by family, then by the common
+// network prefix, then by netmask length, and finally by the full address.
+//
+// See: <https://github.com/postgres/postgres/blob/REL_15_3/src/backend/utils/adt/network.c#L395-L421>
+impl Ord for Inet {
+    fn cmp(&self, other: &Inet) -> Ordering {
+        self.family()
+            .cmp(&other.family())
+            .then_with(|| {
+                let prefix = self.mask(std::cmp::min(self.masklen, other.masklen));
+                (self.bits() & prefix).cmp(&(other.bits() & prefix))
+            })
+            .then_with(|| self.masklen.cmp(&other.masklen))
+            .then_with(|| self.bits().cmp(&other.bits()))
+    }
+}
+
+impl PartialOrd for Inet {
+    fn partial_cmp(&self, other: &Inet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// PostgreSQL's binary encoding for `inet` carries a flag describing whether the
+// value is a `cidr`. We have no use for that flag when packing a Datum into a
+// row, so we invent our own encoding: the address family, the netmask length,
+// and then the address bytes in network order.
+impl Inet {
+    pub fn encode_binary(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(self.binary_size());
+        match self.addr {
+            IpAddr::V4(a) => {
+                res.push(PGSQL_AF_INET);
+                res.push(self.masklen);
+                res.extend_from_slice(&a.octets());
+            }
+            IpAddr::V6(a) => {
+                res.push(PGSQL_AF_INET6);
+                res.push(self.masklen);
+                res.extend_from_slice(&a.octets());
+            }
+        }
+        res
+    }
+
+    pub fn decode_binary(raw: &[u8]) -> Result<Inet, Error> {
+        let (family, masklen, addr) = match raw {
+            [family, masklen, addr @ ..] => (*family, *masklen, addr),
+            _ => bail!("invalid binary size, found {}", raw.len()),
+        };
+        let addr = match family {
+            PGSQL_AF_INET => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(addr)?)),
+            PGSQL_AF_INET6 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(addr)?)),
+            _ => bail!("invalid address family {family}"),
+        };
+        Inet::new(addr, masklen)
+    }
+
+    /// Returns the length of the binary encoding of this value.
+    pub fn binary_size(&self) -> usize {
+        Self::binary_size_for_family(self.family_tag())
+    }
+
+    /// Returns the length of the binary encoding of a value whose encoding
+    /// begins with the address family tag `family`.
+    pub fn binary_size_for_family(family: u8) -> usize {
+        match family {
+            PGSQL_AF_INET => 2 + 4,
+            _ => 2 + 16,
+        }
+    }
+
+    /// Returns the PostgreSQL address family tag for this value.
+    pub fn family_tag(&self) -> u8 {
+        match self.addr {
+            IpAddr::V4(_) => PGSQL_AF_INET,
+            IpAddr::V6(_) => PGSQL_AF_INET6,
+        }
+    }
+}
+
+impl FromStr for Inet {
+    type Err = Error;
+
+    /// Parses an `inet` in the form `address[/masklen]`. The netmask length
+    /// defaults to the width of the address family.
+    ///
+    /// Unlike PostgreSQL, abbreviated IPv4 addresses (e.g. `10.1/16`) are not
+    /// accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.split_once('/') {
+            None => Ok(Inet::from_host(parse_addr(s)?)),
+            Some((addr, masklen)) => {
+                let addr = parse_addr(addr)?;
+                let masklen = masklen
+                    .parse()
+                    .map_err(|_| anyhow!("invalid mask length: {masklen}"))?;
+                Inet::new(addr, masklen)
+            }
+        }
+    }
+}
+
+fn parse_addr(s: &str) -> Result<IpAddr, Error> {
+    s.parse().map_err(|_| anyhow!("invalid address"))
+}
+
+fn max_masklen(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Formats an `inet`, omitting the netmask length if it covers a single host.
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.addr)?;
+        if self.masklen != self.max_masklen() {
+            write!(f, "/{}", self.masklen)?;
+        }
+        Ok(())
+    }
+}
+
+struct CidrDisplay<'a>(&'a Inet);
+
+impl fmt::Display for CidrDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.0.addr, self.0.masklen)
+    }
+}
+
+impl RustType<ProtoInet> for Inet {
+    fn into_proto(&self) -> ProtoInet {
+        let addr = match self.addr {
+            IpAddr::V4(a) => a.octets().to_vec(),
+            IpAddr::V6(a) => a.octets().to_vec(),
+        };
+        ProtoInet {
+            addr,
+            masklen: u32::from(self.masklen),
+        }
+    }
+
+    fn from_proto(proto: ProtoInet) -> Result<Self, TryFromProtoError> {
+        let addr = match <[u8; 4]>::try_from(proto.addr.as_slice()) {
+            Ok(octets) => IpAddr::V4(Ipv4Addr::from(octets)),
+            Err(_) => match <[u8; 16]>::try_from(proto.addr.as_slice()) {
+                Ok(octets) => IpAddr::V6(Ipv6Addr::from(octets)),
+                Err(_) => {
+                    return Err(TryFromProtoError::RowConversionError(format!(
+                        "invalid inet address length {}",
+                        proto.addr.len()
+                    )))
+                }
+            },
+        };
+        let masklen = u8::try_from(proto.masklen)?;
+        Inet::new(addr, masklen).map_err(|e| TryFromProtoError::RowConversionError(e.to_string()))
+    }
+}
+
+impl Columnation for Inet {
+    type InnerRegion = CopyRegion<Inet>;
+}
+
+impl Arbitrary for Inet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Inet>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::prop_oneof![
+            (any::<u32>(), 0..=32u8).prop_map(|(a, m)| Inet {
+                addr: IpAddr::V4(Ipv4Addr::from(a)),
+                masklen: m,
+            }),
+            (any::<u128>(), 0..=128u8).prop_map(|(a, m)| Inet {
+                addr: IpAddr::V6(Ipv6Addr::from(a)),
+                masklen: m,
+            }),
+        ]
+        .boxed()
+    }
+}
+
+/// A 48-bit IEEE 802 MAC address.
+///
+/// See: <https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-MACADDR>
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    proptest_derive::Arbitrary,
+)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// Returns the address with the last three bytes set to zero, like
+    /// PostgreSQL's `trunc(macaddr)`.
+    pub fn trunc(&self) -> MacAddr {
+        let [a, b, c, _, _, _] = self.0;
+        MacAddr([a, b, c, 0, 0, 0])
+    }
+
+    pub fn encode_binary(&self) -> [u8; 6] {
+        self.0
+    }
+
+    pub fn decode_binary(raw: &[u8]) -> Result<MacAddr, Error> {
+        Ok(MacAddr(raw.try_into().map_err(|_| {
+            anyhow!(
+                "invalid binary size, expecting {}, found {}",
+                MacAddr::binary_size(),
+                raw.len()
+            )
+        })?))
+    }
+
+    pub const fn binary_size() -> usize {
+        6
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = Error;
+
+    /// Accepts the same formats as PostgreSQL: six groups of two hex digits
+    /// separated by `:` or `-`, three groups of four separated by `.` or
+    /// `-`, two groups of six separated by `:` or `-`, or twelve unseparated
+    /// hex digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let groups: Vec<&str> = s.split(|c| matches!(c, ':' | '-' | '.')).collect();
+        let group_len = match groups.len() {
+            1 => 12,
+            2 => 6,
+            3 => 4,
+            6 => 2,
+            _ => bail!("invalid macaddr"),
+        };
+        if groups.iter().any(|g| g.len() != group_len) {
+            bail!("invalid macaddr");
+        }
+        let separators: Vec<char> = s.chars().filter(|c| !c.is_ascii_hexdigit()).collect();
+        if separators.windows(2).any(|w| w[0] != w[1]) {
+            bail!("invalid macaddr");
+        }
+        let digits = groups.concat();
+        let mut addr = [0; 6];
+        for (i, byte) in addr.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16)
+                .map_err(|_| anyhow!("invalid macaddr"))?;
+        }
+        Ok(MacAddr(addr))
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl RustType<ProtoMacAddr> for MacAddr {
+    fn into_proto(&self) -> ProtoMacAddr {
+        ProtoMacAddr {
+            addr: self.0.to_vec(),
+        }
+    }
+
+    fn from_proto(proto: ProtoMacAddr) -> Result<Self, TryFromProtoError> {
+        MacAddr::decode_binary(&proto.addr)
+            .map_err(|e| TryFromProtoError::RowConversionError(e.to_string()))
+    }
+}
+
+impl Columnation for MacAddr {
+    type InnerRegion = CopyRegion<MacAddr>;
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_inet_parse_and_display() {
+        let cases = [
+            ("192.168.1.5", "192.168.1.5", "192.168.1.5/32"),
+            ("192.168.1.5/24", "192.168.1.5/24", "192.168.1.5/24"),
+            ("10.0.0.0/8", "10.0.0.0/8", "10.0.0.0/8"),
+            ("::1", "::1", "::1/128"),
+            ("2001:db8::/32", "2001:db8::/32", "2001:db8::/32"),
+        ];
+        for (input, inet, cidr) in cases {
+            let parsed: Inet = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), inet);
+            assert_eq!(parsed.display_cidr().to_string(), cidr);
+        }
+
+        for input in ["", "1.2.3", "1.2.3.4/33", "::1/129", "1.2.3.4/x"] {
+            assert!(input.parse::<Inet>().is_err(), "{input} parsed");
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_inet_network_functions() {
+        let inet: Inet = "192.168.1.5/24".parse().unwrap();
+        assert_eq!(inet.network().to_string(), "192.168.1.0/24");
+        assert_eq!(inet.netmask().to_string(), "255.255.255.0");
+        assert_eq!(inet.broadcast().to_string(), "192.168.1.255/24");
+        assert!(!inet.is_network());
+        assert!(inet.network().is_network());
+        assert_eq!(inet.with_masklen(16).unwrap().to_string(), "192.168.1.5/16");
+        assert!(inet.with_masklen(33).is_err());
+
+        let v6: Inet = "2001:db8::1/64".parse().unwrap();
+        assert_eq!(v6.network().to_string(), "2001:db8::/64");
+        assert!(Inet::new_cidr(v6.addr(), v6.masklen()).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_inet_containment() {
+        let net: Inet = "192.168.1.0/24".parse().unwrap();
+        let host: Inet = "192.168.1.5".parse().unwrap();
+        let other: Inet = "192.168.2.5".parse().unwrap();
+        let v6: Inet = "::ffff:192.168.1.5".parse().unwrap();
+
+        assert!(net.contains(&host));
+        assert!(net.contains_or_equals(&host));
+        assert!(!net.contains(&net));
+        assert!(net.contains_or_equals(&net));
+        assert!(!net.contains(&other));
+        assert!(!net.contains(&v6));
+        assert!(!host.contains(&net));
+        assert!(net.overlaps(&host) && host.overlaps(&net));
+    }
+
+    #[mz_ore::test]
+    fn test_inet_ordering() {
+        let mut values: Vec<Inet> = [
+            "::1",
+            "10.0.0.1",
+            "10.0.0.0/8",
+            "9.255.255.255",
+            "10.0.0.0/16",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        values.sort();
+        let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            values,
+            [
+                "9.255.255.255",
+                "10.0.0.0/8",
+                "10.0.0.0/16",
+                "10.0.0.1",
+                "::1"
+            ]
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_macaddr_parse_and_display() {
+        for input in [
+            "08:00:2b:01:02:03",
+            "08-00-2b-01-02-03",
+            "08002b:010203",
+            "08002b-010203",
+            "0800.2b01.0203",
+            "0800-2b01-0203",
+            "08002b010203",
+            "08:00:2B:01:02:03",
+        ] {
+            let parsed: MacAddr = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), "08:00:2b:01:02:03", "{input}");
+        }
+
+        for input in [
+            "",
+            "08:00:2b:01:02",
+            "08:00-2b:01:02:03",
+            "0g:00:2b:01:02:03",
+        ] {
+            assert!(input.parse::<MacAddr>().is_err(), "{input} parsed");
+        }
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn proptest_inet_binary_encoding_roundtrip(inet: Inet) {
+            let encoded = inet.encode_binary();
+            prop_assert_eq!(encoded.len(), inet.binary_size());
+            let decoded = Inet::decode_binary(&encoded).unwrap();
+            prop_assert_eq!(inet, decoded);
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn proptest_inet_str_roundtrip(inet: Inet) {
+            let decoded: Inet = inet.to_string().parse().unwrap();
+            prop_assert_eq!(inet, decoded);
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn proptest_macaddr_str_roundtrip(mac: MacAddr) {
+            let decoded: MacAddr = mac.to_string().parse().unwrap();
+            prop_assert_eq!(mac, decoded);
+        }
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Schedules for materialized views that are refreshed at discrete times
+//! rather than continuously maintained.
+
+use std::time::Duration;
+
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamp;
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.refresh_schedule.rs"));
+
+/// The times at which a `REFRESH` materialized view brings its contents up to
+/// date.
+///
+/// Between two refresh times, the contents of the view do not change, and its
+/// write frontier sits at the next refresh time.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct RefreshSchedule {
+    /// Periodic refreshes, from `REFRESH EVERY`.
+    pub everies: Vec<RefreshEvery>,
+    /// One-off refreshes, from `REFRESH AT`.
+    pub ats: Vec<Timestamp>,
+}
+
+impl RefreshSchedule {
+    /// Returns the earliest refresh time that is not less than `timestamp`, or
+    /// `None` if there are no refreshes at or after `timestamp`.
+    pub fn round_up_timestamp(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        let everies = self
+            .everies
+            .iter()
+            .filter_map(|every| every.round_up_timestamp(timestamp));
+        let ats = self.ats.iter().copied().filter(|at| *at >= timestamp);
+        everies.chain(ats).min()
+    }
+
+    /// Returns the latest refresh time that is not greater than `timestamp`,
+    /// or `None` if there are no refreshes at or before `timestamp`.
+    pub fn round_down_timestamp(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        let everies = self
+            .everies
+            .iter()
+            .filter_map(|every| every.round_down_timestamp(timestamp));
+        let ats = self.ats.iter().copied().filter(|at| *at <= timestamp);
+        everies.chain(ats).max()
+    }
+
+    /// Returns the last refresh time of the schedule, or `None` if the
+    /// schedule refreshes forever.
+    pub fn last_refresh(&self) -> Option<Timestamp> {
+        if self.everies.is_empty() {
+            self.ats.iter().max().copied()
+        } else {
+            None
+        }
+    }
+}
+
+/// A periodic refresh: every `interval`, at times that are congruent to
+/// `aligned_to` modulo `interval`.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct RefreshEvery {
+    /// The time between two refreshes. Never zero.
+    pub interval: Duration,
+    /// A refresh time; all other refresh times are offset from it by a
+    /// multiple of `interval`.
+    pub aligned_to: Timestamp,
+}
+
+impl RefreshEvery {
+    fn interval_ms(&self) -> u64 {
+        u64::try_from(self.interval.as_millis())
+            .unwrap_or(u64::MAX)
+            .max(1)
+    }
+
+    /// Returns the earliest refresh time that is not less than `timestamp`.
+    pub fn round_up_timestamp(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        let interval = self.interval_ms();
+        let aligned_to = u64::from(self.aligned_to);
+        let timestamp = u64::from(timestamp);
+        let result = if timestamp <= aligned_to {
+            aligned_to - (aligned_to - timestamp) / interval * interval
+        } else {
+            let steps = (timestamp - aligned_to).div_ceil(interval);
+            steps
+                .checked_mul(interval)
+                .and_then(|offset| aligned_to.checked_add(offset))?
+        };
+        Some(Timestamp::new(result))
+    }
+
+    /// Returns the latest refresh time that is not greater than `timestamp`.
+    pub fn round_down_timestamp(&self, timestamp: Timestamp) -> Option<Timestamp> {
+        let interval = self.interval_ms();
+        let aligned_to = u64::from(self.aligned_to);
+        let timestamp = u64::from(timestamp);
+        let result = if timestamp >= aligned_to {
+            aligned_to + (timestamp - aligned_to) / interval * interval
+        } else {
+            let steps = (aligned_to - timestamp).div_ceil(interval);
+            steps
+                .checked_mul(interval)
+                .and_then(|offset| aligned_to.checked_sub(offset))?
+        };
+        Some(Timestamp::new(result))
+    }
+}
+
+impl RustType<ProtoRefreshSchedule> for RefreshSchedule {
+    fn into_proto(&self) -> ProtoRefreshSchedule {
+        ProtoRefreshSchedule {
+            everies: self.everies.into_proto(),
+            ats: self.ats.iter().map(u64::from).collect(),
+        }
+    }
+
+    fn from_proto(proto: ProtoRefreshSchedule) -> Result<Self, TryFromProtoError> {
+        Ok(RefreshSchedule {
+            everies: proto.everies.into_rust()?,
+            ats: proto.ats.into_iter().map(Timestamp::from).collect(),
+        })
+    }
+}
+
+impl RustType<ProtoRefreshEvery> for RefreshEvery {
+    fn into_proto(&self) -> ProtoRefreshEvery {
+        ProtoRefreshEvery {
+            interval: Some(self.interval.into_proto()),
+            aligned_to: self.aligned_to.into(),
+        }
+    }
+
+    fn from_proto(proto: ProtoRefreshEvery) -> Result<Self, TryFromProtoError> {
+        Ok(RefreshEvery {
+            interval: proto
+                .interval
+                .into_rust_if_some("ProtoRefreshEvery::interval")?,
+            aligned_to: proto.aligned_to.into(),
+        })
+    }
+}
+
+impl Arbitrary for RefreshSchedule {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<RefreshEvery>(), 0..4),
+            proptest::collection::vec(any::<Timestamp>(), 0..4),
+        )
+            .prop_map(|(everies, ats)| RefreshSchedule { everies, ats })
+            .boxed()
+    }
+}
+
+impl Arbitrary for RefreshEvery {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (1..u64::MAX, any::<Timestamp>())
+            .prop_map(|(interval_ms, aligned_to)| RefreshEvery {
+                interval: Duration::from_millis(interval_ms),
+                aligned_to,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn every(interval_ms: u64, aligned_to: u64) -> RefreshEvery {
+        RefreshEvery {
+            interval: Duration::from_millis(interval_ms),
+            aligned_to: aligned_to.into(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn round_every() {
+        let e = every(10, 3);
+        assert_eq!(e.round_up_timestamp(0.into()), Some(3.into()));
+        assert_eq!(e.round_up_timestamp(3.into()), Some(3.into()));
+        assert_eq!(e.round_up_timestamp(4.into()), Some(13.into()));
+        assert_eq!(e.round_down_timestamp(12.into()), Some(3.into()));
+        assert_eq!(e.round_down_timestamp(13.into()), Some(13.into()));
+        assert_eq!(e.round_down_timestamp(2.into()), None);
+
+        // Refresh times before `aligned_to` are also part of the schedule.
+        let e = every(10, 100);
+        assert_eq!(e.round_up_timestamp(75.into()), Some(80.into()));
+        assert_eq!(e.round_down_timestamp(75.into()), Some(70.into()));
+
+        // Times past the last representable refresh have no refresh.
+        let e = every(10, 0);
+        assert_eq!(e.round_up_timestamp(Timestamp::MAX), None);
+    }
+
+    #[mz_ore::test]
+    fn round_schedule() {
+        let schedule = RefreshSchedule {
+            everies: vec![every(100, 0)],
+            ats: vec![150.into()],
+        };
+        assert_eq!(schedule.round_up_timestamp(101.into()), Some(150.into()));
+        assert_eq!(schedule.round_up_timestamp(151.into()), Some(200.into()));
+        assert_eq!(schedule.round_down_timestamp(199.into()), Some(150.into()));
+        assert_eq!(schedule.last_refresh(), None);
+
+        let schedule = RefreshSchedule {
+            everies: vec![],
+            ats: vec![150.into(), 50.into()],
+        };
+        assert_eq!(schedule.round_up_timestamp(51.into()), Some(150.into()));
+        assert_eq!(schedule.round_up_timestamp(151.into()), None);
+        assert_eq!(schedule.round_down_timestamp(49.into()), None);
+        assert_eq!(schedule.last_refresh(), Some(150.into()));
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn refresh_schedule_protobuf_roundtrip(expect in any::<RefreshSchedule>()) {
+            let actual = protobuf_roundtrip::<_, ProtoRefreshSchedule>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // slow
+        fn round_up_is_a_refresh_time(schedule in any::<RefreshSchedule>(), ts in any::<Timestamp>()) {
+            if let Some(up) = schedule.round_up_timestamp(ts) {
+                prop_assert!(up >= ts);
+                prop_assert_eq!(schedule.round_down_timestamp(up), Some(up));
+            }
+        }
+    }
+}
@@ -33,6 +33,7 @@ use crate::adt::array::{
     Array, ArrayDimension, ArrayDimensions, InvalidArrayError, MAX_ARRAY_DIMENSIONS,
 };
 use crate::adt::date::Date;
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
 use crate::adt::numeric;
@@ -508,6 +509,9 @@ enum Tag {
     UInt64_48,
     UInt64_56,
     UInt64_64,
+
+    Inet,
+    MacAddr,
 }
 
 impl Tag {
@@ -1021,6 +1025,17 @@ unsafe fn read_datum<'a>(data: &'a [u8], offset: &mut usize) -> Datum<'a> {
                 .expect("invalid aclitem");
             Datum::AclItem(acl_item)
         }
+        Tag::Inet => {
+            // The address family determines the length of the encoding.
+            let len = Inet::binary_size_for_family(data[*offset]);
+            let inet = Inet::decode_binary(&data[*offset..*offset + len]).expect("invalid inet");
+            *offset += len;
+            Datum::Inet(inet)
+        }
+        Tag::MacAddr => {
+            const N: usize = MacAddr::binary_size();
+            Datum::MacAddr(MacAddr(read_byte_array::<N>(data, offset)))
+        }
     }
 }
 
@@ -1389,6 +1404,14 @@ where
             data.push(Tag::AclItem.into());
             data.extend_from_slice(&acl_item.encode_binary());
         }
+        Datum::Inet(inet) => {
+            data.push(Tag::Inet.into());
+            data.extend_from_slice(&inet.encode_binary());
+        }
+        Datum::MacAddr(mac_addr) => {
+            data.push(Tag::MacAddr.into());
+            data.extend_from_slice(&mac_addr.encode_binary());
+        }
     }
 }
 
@@ -1501,6 +1524,8 @@ pub fn datum_size(datum: &Datum) -> usize {
         }
         Datum::MzAclItem(_) => 1 + MzAclItem::binary_size(),
         Datum::AclItem(_) => 1 + AclItem::binary_size(),
+        Datum::Inet(inet) => 1 + inet.binary_size(),
+        Datum::MacAddr(_) => 1 + MacAddr::binary_size(),
     }
 }
 
@@ -101,8 +101,12 @@ impl ColumnType {
             (true, Int64) => Some(f.call::<Option<i64>>()),
             (false, UInt16) => Some(f.call::<u16>()),
             (true, UInt16) => Some(f.call::<Option<u16>>()),
-            (false, UInt32 | Oid | RegClass | RegProc | RegType) => Some(f.call::<u32>()),
-            (true, UInt32 | Oid | RegClass | RegProc | RegType) => Some(f.call::<Option<u32>>()),
+            (false, UInt32 | Oid | RegClass | RegProc | RegType | Enum { .. }) => {
+                Some(f.call::<u32>())
+            }
+            (true, UInt32 | Oid | RegClass | RegProc | RegType | Enum { .. }) => {
+                Some(f.call::<Option<u32>>())
+            }
             (false, UInt64) => Some(f.call::<u64>()),
             (true, UInt64) => Some(f.call::<Option<u64>>()),
             (false, Float32) => Some(f.call::<f32>()),
@@ -127,7 +131,15 @@ impl ColumnType {
             (true, MzTimestamp) => Some(f.call::<Option<crate::Timestamp>>()),
             (
                 _,
-                Numeric { .. } | Time | Timestamp { .. } | TimestampTz { .. } | Interval | Uuid,
+                Numeric { .. }
+                | Time
+                | Timestamp { .. }
+                | TimestampTz { .. }
+                | Interval
+                | Uuid
+                | Inet
+                | Cidr
+                | MacAddr,
             ) => {
                 if *nullable {
                     Some(f.call::<NullableProtoDatumToPersist>())
@@ -846,6 +858,8 @@ impl<'a> From<Datum<'a>> for ProtoDatum {
             })),
             Datum::MzAclItem(x) => DatumType::MzAclItem(x.into_proto()),
             Datum::AclItem(x) => DatumType::AclItem(x.into_proto()),
+            Datum::Inet(x) => DatumType::Inet(x.into_proto()),
+            Datum::MacAddr(x) => DatumType::MacAddr(x.into_proto()),
         };
         ProtoDatum {
             datum_type: Some(datum_type),
@@ -995,6 +1009,8 @@ impl RowPacker<'_> {
             }
             Some(DatumType::MzAclItem(x)) => self.push(Datum::MzAclItem(x.clone().into_rust()?)),
             Some(DatumType::AclItem(x)) => self.push(Datum::AclItem(x.clone().into_rust()?)),
+            Some(DatumType::Inet(x)) => self.push(Datum::Inet(x.clone().into_rust()?)),
+            Some(DatumType::MacAddr(x)) => self.push(Datum::MacAddr(x.clone().into_rust()?)),
             None => return Err("unknown datum type".into()),
         };
         Ok(())
@@ -1045,6 +1061,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::adt::array::ArrayDimension;
+    use crate::adt::inet::MacAddr;
     use crate::adt::interval::Interval;
     use crate::adt::numeric::Numeric;
     use crate::adt::timestamp::CheckedTimestamp;
@@ -1105,6 +1122,9 @@ mod tests {
             Datum::from(Numeric::nan()),
             Datum::JsonNull,
             Datum::Uuid(Uuid::from_u128(30)),
+            Datum::Inet("192.168.1.5/24".parse().unwrap()),
+            Datum::Inet("2001:db8::/32".parse().unwrap()),
+            Datum::MacAddr(MacAddr([8, 0, 0x2b, 1, 2, 3])),
             Datum::Dummy,
             Datum::Null,
         ]);
@@ -10,6 +10,7 @@
 use std::fmt::{self, Debug, Write};
 use std::hash::Hash;
 use std::iter;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::ops::Add;
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
@@ -28,6 +29,7 @@ use uuid::Uuid;
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, AclMode, MzAclItem};
@@ -129,6 +131,10 @@ pub enum Datum<'a> {
     /// A list of privileges granted to a user that uses [`Oid`]s for role references.
     /// This type is used primarily for compatibility with PostgreSQL.
     AclItem(AclItem),
+    /// An IPv4 or IPv6 network address, of type `inet` or `cidr`.
+    Inet(Inet),
+    /// A MAC address.
+    MacAddr(MacAddr),
     /// A placeholder value.
     ///
     /// Dummy values are never meant to be observed. Many operations on `Datum`
@@ -890,6 +896,32 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the inet value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Inet`].
+    #[track_caller]
+    pub fn unwrap_inet(&self) -> Inet {
+        match self {
+            Datum::Inet(inet) => *inet,
+            _ => panic!("Datum::unwrap_inet called on {:?}", self),
+        }
+    }
+
+    /// Unwraps the macaddr value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::MacAddr`].
+    #[track_caller]
+    pub fn unwrap_mac_addr(&self) -> MacAddr {
+        match self {
+            Datum::MacAddr(mac_addr) => *mac_addr,
+            _ => panic!("Datum::unwrap_mac_addr called on {:?}", self),
+        }
+    }
+
     /// Reports whether this datum is an instance of the specified column type.
     pub fn is_instance_of(self, column_type: &ColumnType) -> bool {
         fn is_instance_of_scalar(datum: Datum, scalar_type: &ScalarType) -> bool {
@@ -933,6 +965,9 @@ impl<'a> Datum<'a> {
                     (Datum::UInt32(_), ScalarType::RegProc) => true,
                     (Datum::UInt32(_), ScalarType::RegType) => true,
                     (Datum::UInt32(_), ScalarType::UInt32) => true,
+                    (Datum::UInt32(ordinal), ScalarType::Enum { labels, .. }) => {
+                        usize::try_from(*ordinal).map_or(false, |i| i < labels.len())
+                    }
                     (Datum::UInt32(_), _) => false,
                     (Datum::UInt64(_), ScalarType::UInt64) => true,
                     (Datum::UInt64(_), _) => false,
@@ -1010,6 +1045,11 @@ impl<'a> Datum<'a> {
                     (Datum::MzAclItem(_), _) => false,
                     (Datum::AclItem(_), ScalarType::AclItem) => true,
                     (Datum::AclItem(_), _) => false,
+                    (Datum::Inet(_), ScalarType::Inet) => true,
+                    (Datum::Inet(inet), ScalarType::Cidr) => inet.is_network(),
+                    (Datum::Inet(_), _) => false,
+                    (Datum::MacAddr(_), ScalarType::MacAddr) => true,
+                    (Datum::MacAddr(_), _) => false,
                 }
             }
         }
@@ -1235,6 +1275,20 @@ impl<'a> From<MzAclItem> for Datum<'a> {
     }
 }
 
+impl<'a> From<Inet> for Datum<'a> {
+    #[inline]
+    fn from(inet: Inet) -> Self {
+        Datum::Inet(inet)
+    }
+}
+
+impl<'a> From<MacAddr> for Datum<'a> {
+    #[inline]
+    fn from(mac_addr: MacAddr) -> Self {
+        Datum::MacAddr(mac_addr)
+    }
+}
+
 impl<'a, T> From<Option<T>> for Datum<'a>
 where
     Datum<'a>: From<T>,
@@ -1335,6 +1389,8 @@ impl fmt::Display for Datum<'_> {
             Datum::Range(i) => write!(f, "{}", i),
             Datum::MzAclItem(mz_acl_item) => write!(f, "{mz_acl_item}"),
             Datum::AclItem(acl_item) => write!(f, "{acl_item}"),
+            Datum::Inet(inet) => write!(f, "{inet}"),
+            Datum::MacAddr(mac_addr) => write!(f, "{mac_addr}"),
         }
     }
 }
@@ -1484,6 +1540,25 @@ pub enum ScalarType {
     MzAclItem,
     /// The type of [`Datum::AclItem`]
     AclItem,
+    /// The type of [`Datum::Inet`] values that represent hosts or networks.
+    Inet,
+    /// The type of [`Datum::Inet`] values that represent networks, i.e. have
+    /// no bits set to the right of the netmask.
+    Cidr,
+    /// The type of [`Datum::MacAddr`].
+    MacAddr,
+    /// A user-defined enumerated type.
+    ///
+    /// Values are represented as a [`Datum::UInt32`] holding the position of
+    /// the value's label in `labels`, so that values sort in the order in
+    /// which their labels were declared.
+    Enum {
+        /// The labels of the type, in declaration order.
+        labels: Vec<String>,
+        custom_id: GlobalId,
+        /// The OID of the type, which is reported to PostgreSQL clients.
+        custom_oid: u32,
+    },
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1576,6 +1651,18 @@ impl RustType<ProtoScalarType> for ScalarType {
                 })),
                 ScalarType::MzAclItem => MzAclItem(()),
                 ScalarType::AclItem => AclItem(()),
+                ScalarType::Inet => Inet(()),
+                ScalarType::Cidr => Cidr(()),
+                ScalarType::MacAddr => MacAddr(()),
+                ScalarType::Enum {
+                    labels,
+                    custom_id,
+                    custom_oid,
+                } => Enum(ProtoEnum {
+                    labels: labels.clone(),
+                    custom_id: Some(custom_id.into_proto()),
+                    custom_oid: *custom_oid,
+                }),
             }),
         }
     }
@@ -1662,6 +1749,14 @@ impl RustType<ProtoScalarType> for ScalarType {
             }),
             MzAclItem(()) => Ok(ScalarType::MzAclItem),
             AclItem(()) => Ok(ScalarType::AclItem),
+            Inet(()) => Ok(ScalarType::Inet),
+            Cidr(()) => Ok(ScalarType::Cidr),
+            MacAddr(()) => Ok(ScalarType::MacAddr),
+            Enum(x) => Ok(ScalarType::Enum {
+                labels: x.labels,
+                custom_id: x.custom_id.into_rust_if_some("ProtoEnum::custom_id")?,
+                custom_oid: x.custom_oid,
+            }),
         }
     }
 }
@@ -1779,6 +1874,7 @@ impl_datum_type_copy!(Uuid, Uuid);
 impl_datum_type_copy!('a, &'a str, String);
 impl_datum_type_copy!('a, &'a [u8], Bytes);
 impl_datum_type_copy!(crate::Timestamp, MzTimestamp);
+impl_datum_type_copy!(MacAddr, MacAddr);
 
 impl<'a, E> DatumType<'a, E> for Datum<'a> {
     fn nullable() -> bool {
@@ -2343,6 +2439,29 @@ impl<'a, E> DatumType<'a, E> for AclItem {
     }
 }
 
+impl AsColumnType for Inet {
+    fn as_column_type() -> ColumnType {
+        ScalarType::Inet.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for Inet {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::Inet(inet)) => Ok(inet),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, _temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::Inet(self))
+    }
+}
+
 impl AsColumnType for CheckedTimestamp<NaiveDateTime> {
     fn as_column_type() -> ColumnType {
         ScalarType::Timestamp { precision: None }.nullable(false)
@@ -2702,6 +2821,7 @@ impl ScalarType {
                         .map(|(_, t)| t)
                         .any(|t| t.scalar_type.is_custom_type())
             }
+            Enum { .. } => true,
             _ => false,
         }
     }
@@ -2775,6 +2895,14 @@ impl ScalarType {
                                 && a.1.scalar_type.eq_inner(&b.1.scalar_type, structure_only)
                         })
             }
+            (
+                Enum {
+                    custom_id: id_a, ..
+                },
+                Enum {
+                    custom_id: id_b, ..
+                },
+            ) => id_a == id_b || structure_only,
             (s, o) => ScalarBaseType::from(s) == ScalarBaseType::from(o),
         }
     }
@@ -3089,6 +3217,7 @@ impl ScalarType {
         static OID: Lazy<Row> =
             Lazy::new(|| Row::pack_slice(&[Datum::UInt32(u32::MIN), Datum::UInt32(u32::MAX)]));
         static MAP: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
+        static ENUM: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[Datum::UInt32(0)]));
         static INT2VECTOR: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
         static MZTIMESTAMP: Lazy<Row> = Lazy::new(|| {
             Row::pack_slice(&[
@@ -3133,6 +3262,31 @@ impl ScalarType {
         });
         // aclitem has no binary encoding so we can't test it here.
         static ACLITEM: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
+        static INET: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet(Inet::from_host(Ipv4Addr::UNSPECIFIED.into())),
+                Datum::Inet(Inet::from_host(Ipv4Addr::BROADCAST.into())),
+                Datum::Inet(Inet::new(Ipv4Addr::new(192, 168, 1, 5).into(), 24).unwrap()),
+                Datum::Inet(Inet::from_host(Ipv6Addr::UNSPECIFIED.into())),
+                Datum::Inet(Inet::from_host(Ipv6Addr::from(u128::MAX).into())),
+                Datum::Inet(Inet::new(Ipv6Addr::LOCALHOST.into(), 0).unwrap()),
+            ])
+        });
+        static CIDR: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet(Inet::new(Ipv4Addr::UNSPECIFIED.into(), 0).unwrap()),
+                Datum::Inet(Inet::from_host(Ipv4Addr::BROADCAST.into())),
+                Datum::Inet(Inet::new(Ipv4Addr::new(10, 0, 0, 0).into(), 8).unwrap()),
+                Datum::Inet(Inet::new(Ipv6Addr::UNSPECIFIED.into(), 0).unwrap()),
+                Datum::Inet(Inet::from_host(Ipv6Addr::from(u128::MAX).into())),
+            ])
+        });
+        static MACADDR: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::MacAddr(MacAddr([0; 6])),
+                Datum::MacAddr(MacAddr([u8::MAX; 6])),
+            ])
+        });
 
         match self {
             ScalarType::Bool => (*BOOL).iter(),
@@ -3171,6 +3325,10 @@ impl ScalarType {
             ScalarType::Range { .. } => (*RANGE).iter(),
             ScalarType::MzAclItem { .. } => (*MZACLITEM).iter(),
             ScalarType::AclItem { .. } => (*ACLITEM).iter(),
+            ScalarType::Inet => (*INET).iter(),
+            ScalarType::Cidr => (*CIDR).iter(),
+            ScalarType::MacAddr => (*MACADDR).iter(),
+            ScalarType::Enum { .. } => (*ENUM).iter(),
         }
     }
 
@@ -3227,6 +3385,9 @@ impl ScalarType {
             ScalarType::Int2Vector,
             ScalarType::MzTimestamp,
             ScalarType::MzAclItem,
+            ScalarType::Inet,
+            ScalarType::Cidr,
+            ScalarType::MacAddr,
             // TODO: Fill in some variants of these.
             /*
             ScalarType::AclItem,
@@ -3287,7 +3448,11 @@ impl ScalarType {
             | ScalarType::Int2Vector
             | ScalarType::MzTimestamp
             | ScalarType::Range { .. }
-            | ScalarType::MzAclItem { .. }) => Ok(t),
+            | ScalarType::MzAclItem { .. }
+            | ScalarType::Inet
+            | ScalarType::Cidr
+            | ScalarType::MacAddr
+            | ScalarType::Enum { .. }) => Ok(t),
 
             ScalarType::Array(elem) => Ok(elem.array_of_self_elem_type()?),
 
@@ -3343,6 +3508,9 @@ impl Arbitrary for ScalarType {
             Just(ScalarType::RegType).boxed(),
             Just(ScalarType::RegClass).boxed(),
             Just(ScalarType::Int2Vector).boxed(),
+            Just(ScalarType::Inet).boxed(),
+            Just(ScalarType::Cidr).boxed(),
+            Just(ScalarType::MacAddr).boxed(),
         ]);
 
         leaf.prop_recursive(
@@ -52,6 +52,7 @@ use uuid::Uuid;
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::inet::{Inet, MacAddr};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclItem, MzAclItem};
@@ -1727,6 +1728,53 @@ pub fn parse_acl_item(s: &str) -> Result<AclItem, ParseError> {
         .map_err(|e| ParseError::invalid_input_syntax("aclitem", s).with_details(e))
 }
 
+/// Writes an `inet` to `buf`.
+pub fn format_inet<F>(buf: &mut F, inet: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{inet}");
+    Nestable::Yes
+}
+
+/// Parses an `inet` from `s`.
+pub fn parse_inet(s: &str) -> Result<Inet, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("inet", s).with_details(e))
+}
+
+/// Writes a `cidr` to `buf`.
+pub fn format_cidr<F>(buf: &mut F, cidr: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{}", cidr.display_cidr());
+    Nestable::Yes
+}
+
+/// Parses a `cidr` from `s`, rejecting values with bits set to the right of
+/// the netmask.
+pub fn parse_cidr(s: &str) -> Result<Inet, ParseError> {
+    s.parse()
+        .and_then(|inet: Inet| Inet::new_cidr(inet.addr(), inet.masklen()))
+        .map_err(|e| ParseError::invalid_input_syntax("cidr", s).with_details(e))
+}
+
+/// Writes a `macaddr` to `buf`.
+pub fn format_mac_addr<F>(buf: &mut F, mac_addr: MacAddr) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{mac_addr}");
+    Nestable::Yes
+}
+
+/// Parses a `macaddr` from `s`.
+pub fn parse_mac_addr(s: &str) -> Result<MacAddr, ParseError> {
+    s.parse()
+        .map_err(|e| ParseError::invalid_input_syntax("macaddr", s).with_details(e))
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
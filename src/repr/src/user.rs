@@ -16,4 +16,10 @@ pub struct ExternalUserMetadata {
     pub user_id: Uuid,
     /// Indicates if the user is an admin in the external system.
     pub admin: bool,
+    /// The groups the user belongs to in the external system, if the system
+    /// reports them.
+    ///
+    /// When present, the user's role memberships are synchronized with these
+    /// groups when the user logs in.
+    pub groups: Option<Vec<String>>,
 }
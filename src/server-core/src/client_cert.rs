@@ -0,0 +1,240 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Authentication with TLS client certificates.
+//!
+//! A client that presents a certificate signed by a trusted CA is identified by
+//! the common name of the certificate's subject and by each URI, DNS name and
+//! email address in its subject alternative names. An [`IdentMap`] decides
+//! which roles those identities may log in as.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context};
+use openssl::nid::Nid;
+use openssl::x509::X509Ref;
+use regex::Regex;
+
+/// Configures authentication with TLS client certificates.
+#[derive(Debug, Clone)]
+pub struct ClientCertAuthConfig {
+    /// The path to the CA certificates used to verify client certificates.
+    pub ca: PathBuf,
+    /// Maps the identities of verified client certificates to roles.
+    pub ident_map: Arc<IdentMap>,
+}
+
+/// Command line arguments for authentication with TLS client certificates.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ClientCertAuthCliArgs {
+    /// CA certificates used to verify TLS client certificates.
+    ///
+    /// Clients that present a certificate signed by one of these CAs may log
+    /// in without a password as any role that `--tls-client-ident-map`
+    /// associates with the certificate. Requires TLS to be enabled.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CA",
+        requires_all = &["tls-cert", "tls-client-ident-map"],
+        value_name = "PATH"
+    )]
+    tls_client_ca: Option<PathBuf>,
+    /// File mapping the identities of TLS client certificates to roles.
+    ///
+    /// Each non-empty line that does not start with `#` contains an identity
+    /// and a role name separated by whitespace. An identity that starts with
+    /// a slash (`/`) is a regular expression, and `\1` in the role name is
+    /// replaced with its first capture group, as in PostgreSQL's
+    /// `pg_ident.conf`.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_IDENT_MAP",
+        requires = "tls-client-ca",
+        value_name = "PATH"
+    )]
+    tls_client_ident_map: Option<PathBuf>,
+}
+
+impl ClientCertAuthCliArgs {
+    /// Convert args into configuration.
+    pub fn into_config(self) -> Result<Option<ClientCertAuthConfig>, anyhow::Error> {
+        match (self.tls_client_ca, self.tls_client_ident_map) {
+            (Some(ca), Some(ident_map)) => Ok(Some(ClientCertAuthConfig {
+                ca,
+                ident_map: Arc::new(IdentMap::load(&ident_map)?),
+            })),
+            (None, None) => Ok(None),
+            _ => bail!("--tls-client-ca and --tls-client-ident-map must be specified together"),
+        }
+    }
+}
+
+/// Returns the identities of a client certificate.
+///
+/// These are the common names of the certificate's subject followed by the
+/// URIs, DNS names and email addresses in its subject alternative names.
+pub fn cert_identities(cert: &X509Ref) -> Vec<String> {
+    let mut identities: Vec<String> = cert
+        .subject_name()
+        .entries_by_nid(Nid::COMMONNAME)
+        .filter_map(|entry| entry.data().as_utf8().ok())
+        .map(|name| name.to_string())
+        .collect();
+    if let Some(alt_names) = cert.subject_alt_names() {
+        for alt_name in &alt_names {
+            if let Some(name) = alt_name
+                .uri()
+                .or_else(|| alt_name.dnsname())
+                .or_else(|| alt_name.email())
+            {
+                identities.push(name.to_string());
+            }
+        }
+    }
+    identities
+}
+
+/// Maps the identities of client certificates to the roles they may log in as.
+#[derive(Debug, Default)]
+pub struct IdentMap {
+    rules: Vec<IdentRule>,
+}
+
+#[derive(Debug)]
+struct IdentRule {
+    identity: IdentPattern,
+    role: String,
+}
+
+#[derive(Debug)]
+enum IdentPattern {
+    Exact(String),
+    Regex(Regex),
+}
+
+impl IdentMap {
+    /// Loads an identity map from the file at `path`.
+    pub fn load(path: &Path) -> Result<IdentMap, anyhow::Error> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("reading identity map {}", path.display()))?;
+        Self::parse(&contents).with_context(|| format!("parsing identity map {}", path.display()))
+    }
+
+    /// Parses an identity map in the format described on
+    /// [`ClientCertAuthCliArgs`].
+    pub fn parse(contents: &str) -> Result<IdentMap, anyhow::Error> {
+        let mut rules = vec![];
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let &[identity, role] = fields.as_slice() else {
+                bail!(
+                    "line {}: expected an identity and a role, found {} fields",
+                    i + 1,
+                    fields.len()
+                );
+            };
+            let identity = match identity.strip_prefix('/') {
+                Some(regex) => IdentPattern::Regex(
+                    Regex::new(regex).map_err(|e| anyhow!("line {}: {}", i + 1, e))?,
+                ),
+                None => IdentPattern::Exact(identity.to_string()),
+            };
+            rules.push(IdentRule {
+                identity,
+                role: role.to_string(),
+            });
+        }
+        Ok(IdentMap { rules })
+    }
+
+    /// Returns the roles that a client with the given certificate identities
+    /// may log in as, in the order of the rules that matched.
+    pub fn roles(&self, identities: &[String]) -> Vec<String> {
+        let mut roles = vec![];
+        for rule in &self.rules {
+            for identity in identities {
+                let role = match &rule.identity {
+                    IdentPattern::Exact(exact) if exact == identity => rule.role.clone(),
+                    IdentPattern::Exact(_) => continue,
+                    IdentPattern::Regex(regex) => match regex.captures(identity) {
+                        Some(captures) => match captures.get(1) {
+                            Some(capture) => rule.role.replace(r"\1", capture.as_str()),
+                            None => rule.role.clone(),
+                        },
+                        None => continue,
+                    },
+                };
+                if !roles.contains(&role) {
+                    roles.push(role);
+                }
+            }
+        }
+        roles
+    }
+
+    /// Reports whether a client with the given certificate identities may log
+    /// in as `role`.
+    pub fn authorizes(&self, identities: &[String], role: &str) -> bool {
+        self.roles(identities).iter().any(|r| r == role)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IdentMap;
+
+    #[mz_ore::test]
+    fn test_ident_map() {
+        let map = IdentMap::parse(
+            r"
+# Exact identities.
+billing.internal   billing
+/^spiffe://example\.org/ns/prod/sa/(.*)$   \1
+/^ops@example\.org$   admin
+",
+        )
+        .unwrap();
+
+        let identities = vec!["billing.internal".to_string()];
+        assert_eq!(map.roles(&identities), vec!["billing"]);
+        assert!(map.authorizes(&identities, "billing"));
+        assert!(!map.authorizes(&identities, "admin"));
+
+        let identities = vec![
+            "ignored".to_string(),
+            "spiffe://example.org/ns/prod/sa/ingest".to_string(),
+            "ops@example.org".to_string(),
+        ];
+        assert_eq!(map.roles(&identities), vec!["ingest", "admin"]);
+
+        let identities = vec!["spiffe://example.org/ns/dev/sa/ingest".to_string()];
+        assert!(map.roles(&identities).is_empty());
+    }
+
+    #[mz_ore::test]
+    fn test_ident_map_errors() {
+        let err = IdentMap::parse("billing").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: expected an identity and a role, found 1 fields"
+        );
+        let err = IdentMap::parse("\na b c").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected an identity and a role, found 3 fields"
+        );
+        assert!(IdentMap::parse("/( role").is_err());
+    }
+}
@@ -77,6 +77,9 @@
 
 //! Methods common to servers listening for TCP connections.
 
+pub mod client_cert;
+pub mod proxy_protocol;
+
 use std::future::Future;
 use std::io;
 use std::net::SocketAddr;
@@ -88,13 +91,18 @@ use anyhow::bail;
 use futures::stream::{Stream, StreamExt};
 use mz_ore::error::ErrorExt;
 use mz_ore::task;
-use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod};
+use openssl::ssl::{
+    SslAcceptor, SslAcceptorBuilder, SslContext, SslFiletype, SslMethod, SslVerifyMode,
+};
+use openssl::x509::X509Name;
 use socket2::{SockRef, TcpKeepalive};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::sync::oneshot;
 use tokio_stream::wrappers::TcpListenerStream;
 use tracing::{debug, error};
 
+use crate::client_cert::ClientCertAuthConfig;
+
 /// TCP keepalive settings. The idle time and interval match CockroachDB [0].
 /// The number of retries matches the Linux default.
 ///
@@ -234,6 +242,26 @@ pub struct TlsCertConfig {
 impl TlsCertConfig {
     /// Returns the SSL context to use in TlsConfigs.
     pub fn context(&self) -> Result<SslContext, anyhow::Error> {
+        Ok(self.acceptor_builder()?.build().into_context())
+    }
+
+    /// Like [`TlsCertConfig::context`], but additionally requests a certificate
+    /// from each client and verifies any that is presented against the CAs in
+    /// `client_auth`.
+    pub fn context_with_client_auth(
+        &self,
+        client_auth: &ClientCertAuthConfig,
+    ) -> Result<SslContext, anyhow::Error> {
+        let mut builder = self.acceptor_builder()?;
+        builder.set_ca_file(&client_auth.ca)?;
+        builder.set_client_ca_list(X509Name::load_client_ca_file(&client_auth.ca)?);
+        // Clients without a certificate can still authenticate by other means,
+        // so a certificate is requested but not required.
+        builder.set_verify(SslVerifyMode::PEER);
+        Ok(builder.build().into_context())
+    }
+
+    fn acceptor_builder(&self) -> Result<SslAcceptorBuilder, anyhow::Error> {
         // Mozilla publishes three presets: old, intermediate, and modern. They
         // recommend the intermediate preset for general purpose servers, which
         // is what we use, as it is compatible with nearly every client released
@@ -243,7 +271,7 @@ impl TlsCertConfig {
         let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         builder.set_certificate_chain_file(&self.cert)?;
         builder.set_private_key_file(&self.key, SslFiletype::PEM)?;
-        Ok(builder.build().into_context())
+        Ok(builder)
     }
 }
 
@@ -0,0 +1,202 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Version 2 of the PROXY protocol.
+//!
+//! A proxy that forwards a TCP connection prefixes the forwarded stream with a
+//! PROXY protocol header, which carries the addresses of the original
+//! connection. This lets the server learn the address of the client even
+//! though its TCP peer is the proxy.
+//!
+//! See: <https://www.haproxy.org/download/2.9/doc/proxy-protocol.txt>
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// The signature that begins every version 2 header.
+const SIGNATURE: [u8; 12] = [
+    0x0D, 0x0A, 0x0D, 0x0A, 0x00, 0x0D, 0x0A, 0x51, 0x55, 0x49, 0x54, 0x0A,
+];
+
+/// Protocol version 2, in the high nibble.
+const VERSION_2: u8 = 0x20;
+/// The connection was established on purpose by the proxy, e.g., for a health
+/// check, and carries no client addresses.
+const COMMAND_LOCAL: u8 = 0x00;
+/// The connection was established on behalf of a client.
+const COMMAND_PROXY: u8 = 0x01;
+
+/// TCP over IPv4.
+const FAMILY_TCP4: u8 = 0x11;
+/// TCP over IPv6.
+const FAMILY_TCP6: u8 = 0x21;
+
+/// The length of the addresses of the TCP over IPv4 family.
+const TCP4_LEN: usize = 4 + 4 + 2 + 2;
+/// The length of the addresses of the TCP over IPv6 family.
+const TCP6_LEN: usize = 16 + 16 + 2 + 2;
+
+/// The addresses of a proxied connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProxyHeader {
+    /// The address of the client.
+    pub source: SocketAddr,
+    /// The address to which the client connected, i.e., the proxy.
+    pub destination: SocketAddr,
+}
+
+impl ProxyHeader {
+    /// Encodes the header into `buf`.
+    ///
+    /// If exactly one of the addresses is an IPv6 address, the other is
+    /// encoded as an IPv4-mapped IPv6 address.
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&SIGNATURE);
+        buf.push(VERSION_2 | COMMAND_PROXY);
+        match (self.source.ip(), self.destination.ip()) {
+            (IpAddr::V4(src), IpAddr::V4(dst)) => {
+                buf.push(FAMILY_TCP4);
+                buf.extend_from_slice(&u16::try_from(TCP4_LEN).expect("fits").to_be_bytes());
+                buf.extend_from_slice(&src.octets());
+                buf.extend_from_slice(&dst.octets());
+            }
+            (src, dst) => {
+                let to_v6 = |ip| match ip {
+                    IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+                    IpAddr::V6(ip) => ip,
+                };
+                buf.push(FAMILY_TCP6);
+                buf.extend_from_slice(&u16::try_from(TCP6_LEN).expect("fits").to_be_bytes());
+                buf.extend_from_slice(&to_v6(src).octets());
+                buf.extend_from_slice(&to_v6(dst).octets());
+            }
+        }
+        buf.extend_from_slice(&self.source.port().to_be_bytes());
+        buf.extend_from_slice(&self.destination.port().to_be_bytes());
+    }
+
+    /// Reads a header from the beginning of `conn`.
+    ///
+    /// Reads exactly the bytes of the header, so that the connection can be
+    /// handed off to the server afterwards. Returns `None` if the header does
+    /// not carry TCP addresses, as with the `LOCAL` command. Returns an error
+    /// if `conn` does not begin with a valid version 2 header.
+    pub async fn read<A>(conn: &mut A) -> Result<Option<ProxyHeader>, io::Error>
+    where
+        A: AsyncRead + Unpin,
+    {
+        let mut prefix = [0; 16];
+        conn.read_exact(&mut prefix).await?;
+        if prefix[..12] != SIGNATURE {
+            return Err(invalid("missing PROXY protocol signature"));
+        }
+        if prefix[12] & 0xF0 != VERSION_2 {
+            return Err(invalid("unsupported PROXY protocol version"));
+        }
+        let command = prefix[12] & 0x0F;
+        let family = prefix[13];
+        let len = usize::from(u16::from_be_bytes([prefix[14], prefix[15]]));
+
+        // Always consume the rest of the header, including any TLVs, which we
+        // ignore.
+        let mut addrs = vec![0; len];
+        conn.read_exact(&mut addrs).await?;
+
+        match command {
+            COMMAND_LOCAL => return Ok(None),
+            COMMAND_PROXY => (),
+            _ => return Err(invalid("unsupported PROXY protocol command")),
+        }
+
+        let port = |b: &[u8]| u16::from_be_bytes([b[0], b[1]]);
+        match family {
+            FAMILY_TCP4 if len >= TCP4_LEN => {
+                let ip = |b: &[u8]| Ipv4Addr::new(b[0], b[1], b[2], b[3]);
+                Ok(Some(ProxyHeader {
+                    source: SocketAddr::new(ip(&addrs[0..4]).into(), port(&addrs[8..10])),
+                    destination: SocketAddr::new(ip(&addrs[4..8]).into(), port(&addrs[10..12])),
+                }))
+            }
+            FAMILY_TCP6 if len >= TCP6_LEN => {
+                let ip = |b: &[u8]| {
+                    let octets: [u8; 16] = b.try_into().expect("slice is 16 bytes");
+                    Ipv6Addr::from(octets)
+                };
+                Ok(Some(ProxyHeader {
+                    source: SocketAddr::new(ip(&addrs[0..16]).into(), port(&addrs[32..34])),
+                    destination: SocketAddr::new(ip(&addrs[16..32]).into(), port(&addrs[34..36])),
+                }))
+            }
+            FAMILY_TCP4 | FAMILY_TCP6 => Err(invalid("truncated PROXY protocol addresses")),
+            // Unspecified, UDP, or Unix socket addresses. None of them
+            // identify a TCP client.
+            _ => Ok(None),
+        }
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, SocketAddr};
+
+    use super::ProxyHeader;
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `epoll_wait`
+    async fn test_proxy_header_roundtrip() {
+        for (source, destination) in [
+            ("192.0.2.1:51234", "10.0.0.1:6875"),
+            ("[2001:db8::1]:51234", "[2001:db8::2]:6875"),
+            ("192.0.2.1:51234", "[2001:db8::2]:6875"),
+        ] {
+            let header = ProxyHeader {
+                source: source.parse().unwrap(),
+                destination: destination.parse().unwrap(),
+            };
+            let mut buf = vec![];
+            header.encode(&mut buf);
+            // The bytes after the header must be left unread.
+            buf.extend_from_slice(b"rest");
+
+            let mut conn = &buf[..];
+            let decoded = ProxyHeader::read(&mut conn).await.unwrap().unwrap();
+            // Mixed families are decoded as IPv4-mapped IPv6 addresses.
+            let to_canonical = |addr: SocketAddr| match addr.ip() {
+                IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                    Some(ip) => SocketAddr::new(ip.into(), addr.port()),
+                    None => addr,
+                },
+                IpAddr::V4(_) => addr,
+            };
+            assert_eq!(to_canonical(decoded.source), header.source);
+            assert_eq!(to_canonical(decoded.destination), header.destination);
+            assert_eq!(conn, b"rest");
+        }
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `epoll_wait`
+    async fn test_proxy_header_invalid() {
+        // A pgwire startup message rather than a PROXY header.
+        let mut conn = &[0, 0, 0, 8, 4, 210, 22, 47, 0, 0, 0, 0, 0, 0, 0, 0][..];
+        assert!(ProxyHeader::read(&mut conn).await.is_err());
+
+        // A LOCAL command carries no addresses.
+        let mut buf = super::SIGNATURE.to_vec();
+        buf.extend_from_slice(&[0x20, 0x00, 0x00, 0x00]);
+        let mut conn = &buf[..];
+        assert_eq!(ProxyHeader::read(&mut conn).await.unwrap(), None);
+    }
+}
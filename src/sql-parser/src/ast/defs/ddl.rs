@@ -30,12 +30,18 @@ use crate::ast::{AstInfo, Expr, Ident, OrderByExpr, UnresolvedItemName, WithOpti
 pub enum MaterializedViewOptionName {
     /// The `ASSERT NOT NULL [=] <ident>` option.
     AssertNotNull,
+    /// The `RETAIN HISTORY [=] FOR <interval>` option.
+    RetainHistory,
+    /// The `REFRESH [=] ...` option.
+    Refresh,
 }
 
 impl AstDisplay for MaterializedViewOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             MaterializedViewOptionName::AssertNotNull => f.write_str("ASSERT NOT NULL"),
+            MaterializedViewOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
+            MaterializedViewOptionName::Refresh => f.write_str("REFRESH"),
         }
     }
 }
@@ -1210,6 +1216,7 @@ pub enum CreateSourceOptionName {
     Size,
     Timeline,
     TimestampInterval,
+    RetainHistory,
 }
 
 impl AstDisplay for CreateSourceOptionName {
@@ -1219,6 +1226,7 @@ impl AstDisplay for CreateSourceOptionName {
             CreateSourceOptionName::Size => "SIZE",
             CreateSourceOptionName::Timeline => "TIMELINE",
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
+            CreateSourceOptionName::RetainHistory => "RETAIN HISTORY",
         })
     }
 }
@@ -54,7 +54,10 @@ pub enum Statement<T: AstInfo> {
     CreateMaterializedView(CreateMaterializedViewStatement<T>),
     CreateTable(CreateTableStatement<T>),
     CreateIndex(CreateIndexStatement<T>),
+    CreatePolicy(CreatePolicyStatement<T>),
+    CreateSubscription(CreateSubscriptionStatement<T>),
     CreateType(CreateTypeStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
@@ -65,9 +68,11 @@ pub enum Statement<T: AstInfo> {
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
+    AlterSubscription(AlterSubscriptionStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
+    AlterTable(AlterTableStatement<T>),
     AlterSystemSet(AlterSystemSetStatement),
     AlterSystemReset(AlterSystemResetStatement),
     AlterSystemResetAll(AlterSystemResetAllStatement),
@@ -84,6 +89,8 @@ pub enum Statement<T: AstInfo> {
     Commit(CommitStatement),
     Rollback(RollbackStatement),
     Subscribe(SubscribeStatement<T>),
+    Listen(ListenStatement),
+    Unlisten(UnlistenStatement),
     ExplainPlan(ExplainPlanStatement<T>),
     ExplainTimestamp(ExplainTimestampStatement<T>),
     ExplainSinkSchema(ExplainSinkSchemaStatement<T>),
@@ -123,9 +130,12 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateMaterializedView(stmt) => f.write_node(stmt),
             Statement::CreateTable(stmt) => f.write_node(stmt),
             Statement::CreateIndex(stmt) => f.write_node(stmt),
+            Statement::CreatePolicy(stmt) => f.write_node(stmt),
+            Statement::CreateSubscription(stmt) => f.write_node(stmt),
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
@@ -135,8 +145,10 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
+            Statement::AlterSubscription(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
+            Statement::AlterTable(stmt) => f.write_node(stmt),
             Statement::AlterSystemSet(stmt) => f.write_node(stmt),
             Statement::AlterSystemReset(stmt) => f.write_node(stmt),
             Statement::AlterSystemResetAll(stmt) => f.write_node(stmt),
@@ -153,6 +165,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Commit(stmt) => f.write_node(stmt),
             Statement::Rollback(stmt) => f.write_node(stmt),
             Statement::Subscribe(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
             Statement::ExplainPlan(stmt) => f.write_node(stmt),
             Statement::ExplainTimestamp(stmt) => f.write_node(stmt),
             Statement::ExplainSinkSchema(stmt) => f.write_node(stmt),
@@ -195,7 +209,10 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateMaterializedView => "create_materialized_view",
         StatementKind::CreateTable => "create_table",
         StatementKind::CreateIndex => "create_index",
+        StatementKind::CreatePolicy => "create_policy",
+        StatementKind::CreateSubscription => "create_subscription",
         StatementKind::CreateType => "create_type",
+        StatementKind::CreateFunction => "create_function",
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
@@ -206,9 +223,11 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterRole => "alter_role",
         StatementKind::AlterSecret => "alter_secret",
+        StatementKind::AlterSubscription => "alter_subscription",
         StatementKind::AlterSetCluster => "alter_set_cluster",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
+        StatementKind::AlterTable => "alter_table",
         StatementKind::AlterSystemSet => "alter_system_set",
         StatementKind::AlterSystemReset => "alter_system_reset",
         StatementKind::AlterSystemResetAll => "alter_system_reset_all",
@@ -225,6 +244,8 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Commit => "commit",
         StatementKind::Rollback => "rollback",
         StatementKind::Subscribe => "subscribe",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
         StatementKind::ExplainPlan => "explain_plan",
         StatementKind::ExplainTimestamp => "explain_timestamp",
         StatementKind::ExplainSinkSchema => "explain_sink_schema",
@@ -273,6 +294,8 @@ pub struct InsertStatement<T: AstInfo> {
     pub columns: Vec<Ident>,
     /// A SQL query that specifies what to insert.
     pub source: InsertSource<T>,
+    /// ON CONFLICT
+    pub on_conflict: Option<OnConflict<T>>,
     /// RETURNING
     pub returning: Vec<SelectItem<T>>,
 }
@@ -288,6 +311,10 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
         }
         f.write_str(" ");
         f.write_node(&self.source);
+        if let Some(on_conflict) = &self.on_conflict {
+            f.write_str(" ");
+            f.write_node(on_conflict);
+        }
         if !self.returning.is_empty() {
             f.write_str(" RETURNING ");
             f.write_node(&display::comma_separated(&self.returning));
@@ -296,6 +323,59 @@ impl<T: AstInfo> AstDisplay for InsertStatement<T> {
 }
 impl_display_t!(InsertStatement);
 
+/// `ON CONFLICT` clause of an `INSERT`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnConflict<T: AstInfo> {
+    /// The columns of the key that conflicts are detected on. Empty if no
+    /// conflict target was specified.
+    pub target: Vec<Ident>,
+    pub action: OnConflictAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for OnConflict<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ON CONFLICT ");
+        if !self.target.is_empty() {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(&self.target));
+            f.write_str(") ");
+        }
+        f.write_node(&self.action);
+    }
+}
+impl_display_t!(OnConflict);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OnConflictAction<T: AstInfo> {
+    /// `DO NOTHING`
+    DoNothing,
+    /// `DO UPDATE SET ... [WHERE ...]`
+    DoUpdate {
+        assignments: Vec<Assignment<T>>,
+        selection: Option<Expr<T>>,
+    },
+}
+
+impl<T: AstInfo> AstDisplay for OnConflictAction<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            OnConflictAction::DoNothing => f.write_str("DO NOTHING"),
+            OnConflictAction::DoUpdate {
+                assignments,
+                selection,
+            } => {
+                f.write_str("DO UPDATE SET ");
+                f.write_node(&display::comma_separated(assignments));
+                if let Some(selection) = selection {
+                    f.write_str(" WHERE ");
+                    f.write_node(selection);
+                }
+            }
+        }
+    }
+}
+impl_display_t!(OnConflictAction);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CopyRelation<T: AstInfo> {
     Table {
@@ -678,6 +758,7 @@ pub struct CreateWebhookSourceStatement<T: AstInfo> {
     pub include_headers: CreateWebhookSourceIncludeHeaders,
     pub validate_using: Option<CreateWebhookSourceCheck<T>>,
     pub in_cluster: T::ClusterName,
+    pub with_options: Vec<CreateSourceOption<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateWebhookSourceStatement<T> {
@@ -702,6 +783,12 @@ impl<T: AstInfo> AstDisplay for CreateWebhookSourceStatement<T> {
             f.write_str(" ");
             f.write_node(validate);
         }
+
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
     }
 }
 
@@ -1035,6 +1122,9 @@ impl_display_t!(ReferencedSubsources);
 pub enum CreateSubsourceOptionName {
     Progress,
     References,
+    /// The `RETAIN HISTORY [=] FOR <interval>` option, which purification
+    /// copies from the subsource's source.
+    RetainHistory,
 }
 
 impl AstDisplay for CreateSubsourceOptionName {
@@ -1046,6 +1136,9 @@ impl AstDisplay for CreateSubsourceOptionName {
             CreateSubsourceOptionName::References => {
                 f.write_str("REFERENCES");
             }
+            CreateSubsourceOptionName::RetainHistory => {
+                f.write_str("RETAIN HISTORY");
+            }
         }
     }
 }
@@ -1327,6 +1420,7 @@ pub struct CreateTableStatement<T: AstInfo> {
     pub constraints: Vec<TableConstraint<T>>,
     pub if_not_exists: bool,
     pub temporary: bool,
+    pub with_options: Vec<TableOption<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
@@ -1347,10 +1441,46 @@ impl<T: AstInfo> AstDisplay for CreateTableStatement<T> {
             f.write_node(&display::comma_separated(&self.constraints));
         }
         f.write_str(")");
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
     }
 }
 impl_display_t!(CreateTableStatement);
 
+/// An option in a `CREATE TABLE` statement.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TableOptionName {
+    /// The `RETAIN HISTORY [=] FOR <interval>` option.
+    RetainHistory,
+}
+
+impl AstDisplay for TableOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            TableOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TableOption<T: AstInfo> {
+    pub name: TableOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for TableOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
 /// `CREATE INDEX`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateIndexStatement<T: AstInfo> {
@@ -1401,6 +1531,93 @@ impl<T: AstInfo> AstDisplay for CreateIndexStatement<T> {
 }
 impl_display_t!(CreateIndexStatement);
 
+/// `CREATE POLICY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePolicyStatement<T: AstInfo> {
+    /// The policy name.
+    pub name: Ident,
+    /// `ON` table, view or materialized view name.
+    pub on_name: T::ItemName,
+    /// The `USING` expression, which determines the visible rows.
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE POLICY ");
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.on_name);
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreatePolicyStatement);
+
+/// `CREATE SUBSCRIPTION`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateSubscriptionStatement<T: AstInfo> {
+    /// The subscription name.
+    pub name: UnresolvedItemName,
+    /// `ON` table, source, view or materialized view name.
+    pub on_name: T::ItemName,
+    pub if_not_exists: bool,
+    pub with_options: Vec<SubscriptionOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateSubscriptionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SUBSCRIPTION ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.on_name);
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(CreateSubscriptionStatement);
+
+/// An option in a `CREATE SUBSCRIPTION` or `ALTER SUBSCRIPTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubscriptionOptionName {
+    /// The `ACKNOWLEDGED [=] <timestamp>` option.
+    Acknowledged,
+    /// The `RETAIN HISTORY [=] FOR <interval>` option.
+    RetainHistory,
+}
+
+impl AstDisplay for SubscriptionOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            SubscriptionOptionName::Acknowledged => f.write_str("ACKNOWLEDGED"),
+            SubscriptionOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubscriptionOption<T: AstInfo> {
+    pub name: SubscriptionOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for SubscriptionOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+
 /// An option in a `CREATE CLUSTER` statement.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IndexOptionName {
@@ -1463,6 +1680,13 @@ pub enum RoleAttribute {
     Inherit,
     /// The `NOINHERIT` option.
     NoInherit,
+    /// The `PASSWORD` option, or `PASSWORD NULL` to remove the role's
+    /// password.
+    Password(Option<String>),
+    /// The `BYPASSRLS` option.
+    BypassRls,
+    /// The `NOBYPASSRLS` option.
+    NoBypassRls,
     // The following are not supported, but included to give helpful error messages.
     Login,
     NoLogin,
@@ -1485,6 +1709,19 @@ impl AstDisplay for RoleAttribute {
             RoleAttribute::NoLogin => f.write_str("NOLOGIN"),
             RoleAttribute::Inherit => f.write_str("INHERIT"),
             RoleAttribute::NoInherit => f.write_str("NOINHERIT"),
+            RoleAttribute::Password(None) => f.write_str("PASSWORD NULL"),
+            RoleAttribute::Password(Some(password)) => {
+                f.write_str("PASSWORD ");
+                if f.redacted() {
+                    f.write_str("'<REDACTED>'");
+                } else {
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(password));
+                    f.write_str("'");
+                }
+            }
+            RoleAttribute::BypassRls => f.write_str("BYPASSRLS"),
+            RoleAttribute::NoBypassRls => f.write_str("NOBYPASSRLS"),
             RoleAttribute::CreateCluster => f.write_str("CREATECLUSTER"),
             RoleAttribute::NoCreateCluster => f.write_str("NOCREATECLUSTER"),
             RoleAttribute::CreateDB => f.write_str("CREATEDB"),
@@ -1559,6 +1796,17 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
         f.write_node(&self.name);
         f.write_str(" AS ");
         match &self.as_type {
+            CreateTypeAs::Enum { labels } => {
+                f.write_str(&self.as_type);
+                f.write_str("(");
+                let mut delim = "";
+                for label in labels {
+                    f.write_str(delim);
+                    f.write_node(&display::escaped_string_literal(label));
+                    delim = ", ";
+                }
+                f.write_str(")");
+            }
             CreateTypeAs::List { options } => {
                 f.write_str(&self.as_type);
                 f.write_str("(");
@@ -1587,6 +1835,128 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
 }
 impl_display_t!(CreateTypeStatement);
 
+/// `CREATE [OR REPLACE] FUNCTION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    /// Name of the created function.
+    pub name: UnresolvedItemName,
+    /// Whether to replace an existing function of the same name.
+    pub or_replace: bool,
+    /// The function's parameters.
+    pub params: Vec<FunctionParameter<T>>,
+    /// The function's return type.
+    pub returns: FunctionReturnType<T>,
+    /// The function's body.
+    pub body: FunctionBody<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE ");
+        if self.or_replace {
+            f.write_str("OR REPLACE ");
+        }
+        f.write_str("FUNCTION ");
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.params));
+        f.write_str(") RETURNS ");
+        f.write_node(&self.returns);
+        f.write_str(" LANGUAGE SQL ");
+        f.write_node(&self.body);
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// A parameter in a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    pub name: Option<Ident>,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if let Some(name) = &self.name {
+            f.write_node(name);
+            f.write_str(" ");
+        }
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// The `RETURNS` clause of a `CREATE FUNCTION` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionReturnType<T: AstInfo> {
+    /// `RETURNS <type>`
+    Scalar(T::DataType),
+    /// `RETURNS SETOF <type>`
+    SetOf(T::DataType),
+    /// `RETURNS TABLE (<name> <type>, ...)`
+    Table(Vec<FunctionReturnColumn<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturnType<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionReturnType::Scalar(ty) => f.write_node(ty),
+            FunctionReturnType::SetOf(ty) => {
+                f.write_str("SETOF ");
+                f.write_node(ty);
+            }
+            FunctionReturnType::Table(columns) => {
+                f.write_str("TABLE (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionReturnType);
+
+/// A column in a `RETURNS TABLE` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionReturnColumn<T: AstInfo> {
+    pub name: Ident,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturnColumn<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionReturnColumn);
+
+/// The body of a SQL-language function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionBody<T: AstInfo> {
+    /// `RETURN <expr>`
+    Return(Expr<T>),
+    /// `BEGIN ATOMIC <query>; END`
+    Atomic(Query<T>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionBody<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionBody::Return(expr) => {
+                f.write_str("RETURN ");
+                f.write_node(expr);
+            }
+            FunctionBody::Atomic(query) => {
+                f.write_str("BEGIN ATOMIC ");
+                f.write_node(query);
+                f.write_str("; END");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionBody);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
@@ -1821,6 +2191,9 @@ impl<T: AstInfo> AstDisplay for ReplicaOption<T> {
 /// `CREATE TYPE .. AS <TYPE>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateTypeAs<T: AstInfo> {
+    Enum {
+        labels: Vec<String>,
+    },
     List {
         options: Vec<CreateTypeListOption<T>>,
     },
@@ -1835,6 +2208,7 @@ pub enum CreateTypeAs<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            CreateTypeAs::Enum { .. } => f.write_str("ENUM "),
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
@@ -2163,6 +2537,61 @@ impl<T: AstInfo> AstDisplay for AlterSourceStatement<T> {
 
 impl_display_t!(AlterSourceStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTableAction<T: AstInfo> {
+    /// `ADD [COLUMN] [IF NOT EXISTS] <column_def>`
+    AddColumn {
+        if_col_not_exists: bool,
+        column: ColumnDef<T>,
+    },
+    /// `DROP [COLUMN] [IF EXISTS] <name>`
+    DropColumn { if_col_exists: bool, name: Ident },
+}
+
+/// `ALTER TABLE ... { ADD | DROP } COLUMN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableStatement<T: AstInfo> {
+    pub table_name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub action: AlterTableAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.table_name);
+        f.write_str(" ");
+
+        match &self.action {
+            AlterTableAction::AddColumn {
+                if_col_not_exists,
+                column,
+            } => {
+                f.write_str("ADD COLUMN ");
+                if *if_col_not_exists {
+                    f.write_str("IF NOT EXISTS ");
+                }
+                f.write_node(column);
+            }
+            AlterTableAction::DropColumn {
+                if_col_exists,
+                name,
+            } => {
+                f.write_str("DROP COLUMN ");
+                if *if_col_exists {
+                    f.write_str("IF EXISTS ");
+                }
+                f.write_node(name);
+            }
+        }
+    }
+}
+
+impl_display_t!(AlterTableStatement);
+
 /// `ALTER SECRET ... AS`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterSecretStatement<T: AstInfo> {
@@ -2185,6 +2614,29 @@ impl<T: AstInfo> AstDisplay for AlterSecretStatement<T> {
 
 impl_display_t!(AlterSecretStatement);
 
+/// `ALTER SUBSCRIPTION ... SET (...)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterSubscriptionStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub options: Vec<SubscriptionOption<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterSubscriptionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER SUBSCRIPTION ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" SET (");
+        f.write_node(&display::comma_separated(&self.options));
+        f.write_str(")");
+    }
+}
+
+impl_display_t!(AlterSubscriptionStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterConnectionAction<T: AstInfo> {
     RotateKeys,
@@ -3027,6 +3479,8 @@ pub enum ObjectType {
     Database,
     Schema,
     Func,
+    Policy,
+    Subscription,
     Subsource,
 }
 
@@ -3043,6 +3497,8 @@ impl ObjectType {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
+            | ObjectType::Policy
+            | ObjectType::Subscription
             | ObjectType::Subsource => true,
             ObjectType::Database
             | ObjectType::Schema
@@ -3071,6 +3527,8 @@ impl AstDisplay for ObjectType {
             ObjectType::Database => "DATABASE",
             ObjectType::Schema => "SCHEMA",
             ObjectType::Func => "FUNCTION",
+            ObjectType::Policy => "POLICY",
+            ObjectType::Subscription => "SUBSCRIPTION",
             ObjectType::Subsource => "SUBSOURCE",
         })
     }
@@ -3129,6 +3587,8 @@ pub enum WithOptionValue<T: AstInfo> {
     // Special cases.
     ClusterReplicas(Vec<ReplicaDefinition<T>>),
     ConnectionKafkaBroker(KafkaBroker<T>),
+    RetainHistoryFor(Value),
+    Refresh(RefreshOptionValue<T>),
 }
 
 impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
@@ -3137,13 +3597,16 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
             // When adding branches to this match statement, think about whether it is OK for us to collect
             // the value as part of our telemetry. Check the data management policy to be sure!
             match self {
-                WithOptionValue::Value(_) | WithOptionValue::Sequence(_) => {
+                WithOptionValue::Value(_)
+                | WithOptionValue::Sequence(_)
+                | WithOptionValue::RetainHistoryFor(_) => {
                     // These are redact-aware.
                 }
                 WithOptionValue::DataType(_)
                 | WithOptionValue::Item(_)
                 | WithOptionValue::UnresolvedItemName(_)
-                | WithOptionValue::ClusterReplicas(_) => {
+                | WithOptionValue::ClusterReplicas(_)
+                | WithOptionValue::Refresh(_) => {
                     // These do not need redaction.
                 }
                 WithOptionValue::Secret(_)
@@ -3179,11 +3642,68 @@ impl<T: AstInfo> AstDisplay for WithOptionValue<T> {
             WithOptionValue::ConnectionKafkaBroker(broker) => {
                 f.write_node(broker);
             }
+            WithOptionValue::RetainHistoryFor(value) => {
+                f.write_str("FOR ");
+                f.write_node(value);
+            }
+            WithOptionValue::Refresh(refresh) => f.write_node(refresh),
         }
     }
 }
 impl_display_t!(WithOptionValue);
 
+/// The value of a materialized view's `REFRESH` option.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RefreshOptionValue<T: AstInfo> {
+    /// `REFRESH ON COMMIT`: continuously maintain the view. This is the
+    /// default.
+    OnCommit,
+    /// `REFRESH AT <time>`: refresh the view once, at the given time.
+    At(RefreshAtOptionValue<T>),
+    /// `REFRESH EVERY <interval> [ALIGNED TO <time>]`: refresh the view
+    /// periodically.
+    Every(RefreshEveryOptionValue<T>),
+}
+
+impl<T: AstInfo> AstDisplay for RefreshOptionValue<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            RefreshOptionValue::OnCommit => f.write_str("ON COMMIT"),
+            RefreshOptionValue::At(RefreshAtOptionValue { time }) => {
+                f.write_str("AT ");
+                f.write_node(time);
+            }
+            RefreshOptionValue::Every(RefreshEveryOptionValue {
+                interval,
+                aligned_to,
+            }) => {
+                f.write_str("EVERY ");
+                f.write_node(interval);
+                if let Some(aligned_to) = aligned_to {
+                    f.write_str(" ALIGNED TO ");
+                    f.write_node(aligned_to);
+                }
+            }
+        }
+    }
+}
+impl_display_t!(RefreshOptionValue);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RefreshAtOptionValue<T: AstInfo> {
+    /// The time of the refresh. Must evaluate to a constant.
+    pub time: Expr<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RefreshEveryOptionValue<T: AstInfo> {
+    /// The time between two refreshes.
+    pub interval: Value,
+    /// A time at which a refresh happens. Defaults to the Unix epoch. Must
+    /// evaluate to a constant.
+    pub aligned_to: Option<Expr<T>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum TransactionMode {
     AccessMode(TransactionAccessMode),
@@ -3454,6 +3974,41 @@ impl AstDisplay for CloseStatement {
 }
 impl_display!(CloseStatement);
 
+/// `LISTEN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement {
+    /// The relation whose changes to listen for. Its name, as written, is
+    /// also the name of the channel.
+    pub name: UnresolvedItemName,
+}
+
+impl AstDisplay for ListenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.name);
+    }
+}
+impl_display!(ListenStatement);
+
+/// `UNLISTEN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` to stop listening on all
+    /// channels (`UNLISTEN *`).
+    pub name: Option<UnresolvedItemName>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.name {
+            Some(name) => f.write_node(name),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum FetchOptionName {
     Timeout,
@@ -3754,6 +4309,7 @@ pub enum Privilege {
     INSERT,
     UPDATE,
     DELETE,
+    EXECUTE,
     USAGE,
     CREATE,
     CREATEROLE,
@@ -3768,6 +4324,7 @@ impl AstDisplay for Privilege {
             Privilege::INSERT => "INSERT",
             Privilege::UPDATE => "UPDATE",
             Privilege::DELETE => "DELETE",
+            Privilege::EXECUTE => "EXECUTE",
             Privilege::CREATE => "CREATE",
             Privilege::USAGE => "USAGE",
             Privilege::CREATEROLE => "CREATEROLE",
@@ -3782,6 +4339,8 @@ impl_display!(Privilege);
 pub enum PrivilegeSpecification {
     All,
     Privileges(Vec<Privilege>),
+    /// Privileges on individual columns of a relation, e.g. `SELECT (a, b)`.
+    Columns(Vec<ColumnPrivilege>),
 }
 
 impl AstDisplay for PrivilegeSpecification {
@@ -3791,11 +4350,31 @@ impl AstDisplay for PrivilegeSpecification {
             PrivilegeSpecification::Privileges(privileges) => {
                 f.write_node(&display::comma_separated(privileges))
             }
+            PrivilegeSpecification::Columns(privileges) => {
+                f.write_node(&display::comma_separated(privileges))
+            }
         }
     }
 }
 impl_display!(PrivilegeSpecification);
 
+/// A privilege on a list of columns, e.g. `SELECT (a, b)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColumnPrivilege {
+    pub privilege: Privilege,
+    pub columns: Vec<Ident>,
+}
+
+impl AstDisplay for ColumnPrivilege {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.privilege);
+        f.write_str(" (");
+        f.write_node(&display::comma_separated(&self.columns));
+        f.write_str(")");
+    }
+}
+impl_display!(ColumnPrivilege);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GrantTargetSpecification<T: AstInfo> {
     Object {
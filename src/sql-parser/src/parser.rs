@@ -419,6 +419,12 @@ impl<'a> Parser<'a> {
                 Token::Keyword(SUBSCRIBE) => Ok(self
                     .parse_subscribe()
                     .map_parser_err(StatementKind::Subscribe)?),
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
                 Token::Keyword(EXPLAIN) => Ok(self.parse_explain()?),
                 Token::Keyword(DECLARE) => Ok(self.parse_declare()?),
                 Token::Keyword(FETCH) => {
@@ -1820,6 +1826,15 @@ impl<'a> Parser<'a> {
         } else if self.peek_keyword(TYPE) {
             self.parse_create_type()
                 .map_parser_err(StatementKind::CreateType)
+        } else if self.peek_keyword(FUNCTION) || self.peek_keywords(&[OR, REPLACE, FUNCTION]) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
+        } else if self.peek_keyword(POLICY) {
+            self.parse_create_policy()
+                .map_parser_err(StatementKind::CreatePolicy)
+        } else if self.peek_keyword(SUBSCRIPTION) {
+            self.parse_create_subscription()
+                .map_parser_err(StatementKind::CreateSubscription)
         } else if self.peek_keyword(ROLE) {
             self.parse_create_role()
                 .map_parser_err(StatementKind::CreateRole)
@@ -1878,7 +1893,7 @@ impl<'a> Parser<'a> {
             } else {
                 self.expected(
                     self.peek_pos(),
-                    "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
+                    "DATABASE, SCHEMA, ROLE, TYPE, FUNCTION, INDEX, POLICY, SINK, SOURCE, SUBSCRIPTION, TABLE, SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW after CREATE",
                     self.peek_token(),
                 ).map_no_statement_parser_err()
             }
@@ -2601,9 +2616,13 @@ impl<'a> Parser<'a> {
     fn parse_create_subsource_option_name(
         &mut self,
     ) -> Result<CreateSubsourceOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[PROGRESS, REFERENCES])? {
+        let name = match self.expect_one_of_keywords(&[PROGRESS, REFERENCES, RETAIN])? {
             PROGRESS => CreateSubsourceOptionName::Progress,
             REFERENCES => CreateSubsourceOptionName::References,
+            RETAIN => {
+                self.expect_keyword(HISTORY)?;
+                CreateSubsourceOptionName::RetainHistory
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -2611,10 +2630,12 @@ impl<'a> Parser<'a> {
 
     /// Parse a NAME = VALUE parameter for CREATE SINK
     fn parse_create_subsource_option(&mut self) -> Result<CreateSubsourceOption<Raw>, ParserError> {
-        Ok(CreateSubsourceOption {
-            name: self.parse_create_subsource_option_name()?,
-            value: self.parse_optional_option_value()?,
-        })
+        let name = self.parse_create_subsource_option_name()?;
+        let value = match name {
+            CreateSubsourceOptionName::RetainHistory => self.parse_retain_history_value()?,
+            _ => self.parse_optional_option_value()?,
+        };
+        Ok(CreateSubsourceOption { name, value })
     }
 
     fn parse_create_source(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -2764,29 +2785,35 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[IGNORE, SIZE, TIMELINE, TIMESTAMP])? {
-            IGNORE => {
-                self.expect_keyword(KEYS)?;
-                CreateSourceOptionName::IgnoreKeys
-            }
-            SIZE => CreateSourceOptionName::Size,
-            TIMELINE => CreateSourceOptionName::Timeline,
-            TIMESTAMP => {
-                self.expect_keyword(INTERVAL)?;
-                CreateSourceOptionName::TimestampInterval
-            }
-            _ => unreachable!(),
-        };
+        let name =
+            match self.expect_one_of_keywords(&[IGNORE, SIZE, TIMELINE, TIMESTAMP, RETAIN])? {
+                IGNORE => {
+                    self.expect_keyword(KEYS)?;
+                    CreateSourceOptionName::IgnoreKeys
+                }
+                SIZE => CreateSourceOptionName::Size,
+                TIMELINE => CreateSourceOptionName::Timeline,
+                TIMESTAMP => {
+                    self.expect_keyword(INTERVAL)?;
+                    CreateSourceOptionName::TimestampInterval
+                }
+                RETAIN => {
+                    self.expect_keyword(HISTORY)?;
+                    CreateSourceOptionName::RetainHistory
+                }
+                _ => unreachable!(),
+            };
         Ok(name)
     }
 
     /// Parses a single valid option in the WITH block of a create source
     fn parse_source_option(&mut self) -> Result<CreateSourceOption<Raw>, ParserError> {
         let name = self.parse_source_option_name()?;
-        Ok(CreateSourceOption {
-            name,
-            value: self.parse_optional_option_value()?,
-        })
+        let value = match name {
+            CreateSourceOptionName::RetainHistory => self.parse_retain_history_value()?,
+            _ => self.parse_optional_option_value()?,
+        };
+        Ok(CreateSourceOption { name, value })
     }
 
     fn parse_create_webhook_source(
@@ -2864,6 +2891,15 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_source_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
         Ok(Statement::CreateWebhookSource(
             CreateWebhookSourceStatement {
                 name,
@@ -2872,6 +2908,7 @@ impl<'a> Parser<'a> {
                 include_headers,
                 validate_using,
                 in_cluster,
+                with_options,
             },
         ))
     }
@@ -3245,18 +3282,71 @@ impl<'a> Parser<'a> {
     fn parse_materialized_view_option_name(
         &mut self,
     ) -> Result<MaterializedViewOptionName, ParserError> {
-        self.expect_keywords(&[ASSERT, NOT, NULL])?;
-        Ok(MaterializedViewOptionName::AssertNotNull)
+        let option = self.expect_one_of_keywords(&[ASSERT, RETAIN, REFRESH])?;
+        let name = match option {
+            ASSERT => {
+                self.expect_keywords(&[NOT, NULL])?;
+                MaterializedViewOptionName::AssertNotNull
+            }
+            RETAIN => {
+                self.expect_keyword(HISTORY)?;
+                MaterializedViewOptionName::RetainHistory
+            }
+            REFRESH => MaterializedViewOptionName::Refresh,
+            _ => unreachable!(),
+        };
+        Ok(name)
     }
 
     fn parse_materialized_view_option(
         &mut self,
     ) -> Result<MaterializedViewOption<Raw>, ParserError> {
         let name = self.parse_materialized_view_option_name()?;
-        let value = self.parse_optional_option_value()?;
+        let value = match name {
+            MaterializedViewOptionName::RetainHistory => self.parse_retain_history_value()?,
+            MaterializedViewOptionName::Refresh => self.parse_refresh_option_value()?,
+            MaterializedViewOptionName::AssertNotNull => self.parse_optional_option_value()?,
+        };
         Ok(MaterializedViewOption { name, value })
     }
 
+    /// Parses the value of a `REFRESH` option:
+    /// `[=] { ON COMMIT | AT <expr> | EVERY <interval> [ALIGNED TO <expr>] }`.
+    fn parse_refresh_option_value(&mut self) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        let refresh = match self.expect_one_of_keywords(&[ON, AT, EVERY])? {
+            ON => {
+                self.expect_keyword(COMMIT)?;
+                RefreshOptionValue::OnCommit
+            }
+            AT => RefreshOptionValue::At(RefreshAtOptionValue {
+                time: self.parse_expr()?,
+            }),
+            EVERY => {
+                let interval = self.parse_value()?;
+                let aligned_to = if self.parse_keywords(&[ALIGNED, TO]) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                RefreshOptionValue::Every(RefreshEveryOptionValue {
+                    interval,
+                    aligned_to,
+                })
+            }
+            _ => unreachable!(),
+        };
+        Ok(Some(WithOptionValue::Refresh(refresh)))
+    }
+
+    /// Parses the value of a `RETAIN HISTORY` option: `[=] FOR <interval>`.
+    fn parse_retain_history_value(&mut self) -> Result<Option<WithOptionValue<Raw>>, ParserError> {
+        let _ = self.consume_token(&Token::Eq);
+        self.expect_keyword(FOR)?;
+        let value = self.parse_value()?;
+        Ok(Some(WithOptionValue::RetainHistoryFor(value)))
+    }
+
     fn parse_create_index(&mut self) -> Result<Statement<Raw>, ParserError> {
         let default_index = self.parse_keyword(DEFAULT);
         self.expect_keyword(INDEX)?;
@@ -3356,11 +3446,11 @@ impl<'a> Parser<'a> {
         self.expect_keyword(ROLE)?;
         let name = self.parse_identifier()?;
         let _ = self.parse_keyword(WITH);
-        let options = self.parse_role_attributes();
+        let options = self.parse_role_attributes()?;
         Ok(Statement::CreateRole(CreateRoleStatement { name, options }))
     }
 
-    fn parse_role_attributes(&mut self) -> Vec<RoleAttribute> {
+    fn parse_role_attributes(&mut self) -> Result<Vec<RoleAttribute>, ParserError> {
         let mut options = vec![];
         loop {
             match self.parse_one_of_keywords(&[
@@ -3376,6 +3466,9 @@ impl<'a> Parser<'a> {
                 NOCREATEDB,
                 CREATEROLE,
                 NOCREATEROLE,
+                PASSWORD,
+                BYPASSRLS,
+                NOBYPASSRLS,
             ]) {
                 None => break,
                 Some(SUPERUSER) => options.push(RoleAttribute::SuperUser),
@@ -3390,10 +3483,79 @@ impl<'a> Parser<'a> {
                 Some(NOCREATEDB) => options.push(RoleAttribute::NoCreateDB),
                 Some(CREATEROLE) => options.push(RoleAttribute::CreateRole),
                 Some(NOCREATEROLE) => options.push(RoleAttribute::NoCreateRole),
+                Some(PASSWORD) => {
+                    let password = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::Password(password));
+                }
+                Some(BYPASSRLS) => options.push(RoleAttribute::BypassRls),
+                Some(NOBYPASSRLS) => options.push(RoleAttribute::NoBypassRls),
                 Some(_) => unreachable!(),
             }
         }
-        options
+        Ok(options)
+    }
+
+    fn parse_create_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(POLICY)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let on_name = self.parse_raw_name()?;
+        self.expect_keyword(USING)?;
+        self.expect_token(&Token::LParen)?;
+        let using = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::CreatePolicy(CreatePolicyStatement {
+            name,
+            on_name,
+            using,
+        }))
+    }
+
+    fn parse_create_subscription(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(SUBSCRIPTION)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        self.expect_keyword(ON)?;
+        let on_name = self.parse_raw_name()?;
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_subscription_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+        Ok(Statement::CreateSubscription(CreateSubscriptionStatement {
+            name,
+            on_name,
+            if_not_exists,
+            with_options,
+        }))
+    }
+
+    fn parse_subscription_option_name(&mut self) -> Result<SubscriptionOptionName, ParserError> {
+        let option = match self.expect_one_of_keywords(&[ACKNOWLEDGED, RETAIN])? {
+            ACKNOWLEDGED => SubscriptionOptionName::Acknowledged,
+            RETAIN => {
+                self.expect_keyword(HISTORY)?;
+                SubscriptionOptionName::RetainHistory
+            }
+            _ => unreachable!(),
+        };
+        Ok(option)
+    }
+
+    fn parse_subscription_option(&mut self) -> Result<SubscriptionOption<Raw>, ParserError> {
+        let name = self.parse_subscription_option_name()?;
+        let value = match name {
+            SubscriptionOptionName::Acknowledged => self.parse_optional_option_value()?,
+            SubscriptionOptionName::RetainHistory => self.parse_retain_history_value()?,
+        };
+        Ok(SubscriptionOption { name, value })
     }
 
     fn parse_create_secret(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3409,12 +3571,110 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let or_replace = self.parse_keywords(&[OR, REPLACE]);
+        self.expect_keyword(FUNCTION)?;
+        let name = self.parse_item_name()?;
+        self.expect_token(&Token::LParen)?;
+        let params = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let params = self.parse_comma_separated(Parser::parse_function_parameter)?;
+            self.expect_token(&Token::RParen)?;
+            params
+        };
+
+        self.expect_keyword(RETURNS)?;
+        let returns = if self.parse_keyword(SETOF) {
+            FunctionReturnType::SetOf(self.parse_data_type()?)
+        } else if self.parse_keyword(TABLE) {
+            self.expect_token(&Token::LParen)?;
+            let columns = self.parse_comma_separated(|parser| {
+                Ok(FunctionReturnColumn {
+                    name: parser.parse_identifier()?,
+                    data_type: parser.parse_data_type()?,
+                })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            FunctionReturnType::Table(columns)
+        } else {
+            FunctionReturnType::Scalar(self.parse_data_type()?)
+        };
+
+        if self.parse_keyword(LANGUAGE) {
+            if !self.parse_keyword(SQL) {
+                return parser_err!(
+                    self,
+                    self.peek_pos(),
+                    "only LANGUAGE SQL functions are supported"
+                );
+            }
+        }
+
+        let body = match self.expect_one_of_keywords(&[RETURN, BEGIN])? {
+            RETURN => FunctionBody::Return(self.parse_expr()?),
+            BEGIN => {
+                self.expect_keyword(ATOMIC)?;
+                let query = self.parse_query()?;
+                self.expect_token(&Token::Semicolon)?;
+                self.expect_keyword(END)?;
+                FunctionBody::Atomic(query)
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            or_replace,
+            params,
+            returns,
+            body,
+        }))
+    }
+
+    fn parse_function_parameter(&mut self) -> Result<FunctionParameter<Raw>, ParserError> {
+        // A parameter is either `<type>` or `<name> <type>`. Try the unnamed
+        // form first so that multi-word types like `double precision` are not
+        // mistaken for a name followed by a type.
+        let unnamed = self.maybe_parse(|parser| {
+            let data_type = parser.parse_data_type()?;
+            match parser.peek_token() {
+                Some(Token::Comma) | Some(Token::RParen) => Ok(data_type),
+                _ => parser.expected(parser.peek_pos(), "',' or ')'", parser.peek_token()),
+            }
+        });
+        match unnamed {
+            Some(data_type) => Ok(FunctionParameter {
+                name: None,
+                data_type,
+            }),
+            None => Ok(FunctionParameter {
+                name: Some(self.parse_identifier()?),
+                data_type: self.parse_data_type()?,
+            }),
+        }
+    }
+
     fn parse_create_type(&mut self) -> Result<Statement<Raw>, ParserError> {
         self.expect_keyword(TYPE)?;
         let name = self.parse_item_name()?;
         self.expect_keyword(AS)?;
 
-        match self.parse_one_of_keywords(&[LIST, MAP]) {
+        match self.parse_one_of_keywords(&[ENUM, LIST, MAP]) {
+            Some(ENUM) => {
+                self.expect_token(&Token::LParen)?;
+                let labels = if self.consume_token(&Token::RParen) {
+                    vec![]
+                } else {
+                    let labels = self.parse_comma_separated(Parser::parse_literal_string)?;
+                    self.expect_token(&Token::RParen)?;
+                    labels
+                };
+                Ok(Statement::CreateType(CreateTypeStatement {
+                    name,
+                    as_type: CreateTypeAs::Enum { labels },
+                }))
+            }
             Some(LIST) => {
                 self.expect_token(&Token::LParen)?;
                 let options = self.parse_comma_separated(Parser::parse_create_type_list_option)?;
@@ -3775,7 +4035,10 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::Type
             | ObjectType::Secret
-            | ObjectType::Connection => {
+            | ObjectType::Connection
+            | ObjectType::Func
+            | ObjectType::Policy
+            | ObjectType::Subscription => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
                 })?;
@@ -3790,7 +4053,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -3859,15 +4122,38 @@ impl<'a> Parser<'a> {
         // parse optional column list (schema)
         let (columns, constraints) = self.parse_columns(Mandatory)?;
 
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_table_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
         Ok(Statement::CreateTable(CreateTableStatement {
             name: table_name,
             columns,
             constraints,
             if_not_exists,
             temporary,
+            with_options,
         }))
     }
 
+    fn parse_table_option_name(&mut self) -> Result<TableOptionName, ParserError> {
+        self.expect_keywords(&[RETAIN, HISTORY])?;
+        Ok(TableOptionName::RetainHistory)
+    }
+
+    fn parse_table_option(&mut self) -> Result<TableOption<Raw>, ParserError> {
+        let name = self.parse_table_option_name()?;
+        let value = match name {
+            TableOptionName::RetainHistory => self.parse_retain_history_value()?,
+        };
+        Ok(TableOption { name, value })
+    }
+
     fn parse_columns(
         &mut self,
         optional: IsOptional,
@@ -3895,26 +4181,7 @@ impl<'a> Parser<'a> {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Some(column_name) = self.consume_identifier() {
-                let data_type = self.parse_data_type()?;
-                let collation = if self.parse_keyword(COLLATE) {
-                    Some(self.parse_item_name()?)
-                } else {
-                    None
-                };
-                let mut options = vec![];
-                loop {
-                    match self.peek_token() {
-                        None | Some(Token::Comma) | Some(Token::RParen) => break,
-                        _ => options.push(self.parse_column_option_def()?),
-                    }
-                }
-
-                columns.push(ColumnDef {
-                    name: column_name,
-                    data_type,
-                    collation,
-                    options,
-                });
+                columns.push(self.parse_column_def(column_name)?);
             } else {
                 return self.expected(
                     self.peek_pos(),
@@ -3938,6 +4205,30 @@ impl<'a> Parser<'a> {
         Ok((columns, constraints))
     }
 
+    /// Parses the remainder of a column definition, after its name.
+    fn parse_column_def(&mut self, name: Ident) -> Result<ColumnDef<Raw>, ParserError> {
+        let data_type = self.parse_data_type()?;
+        let collation = if self.parse_keyword(COLLATE) {
+            Some(self.parse_item_name()?)
+        } else {
+            None
+        };
+        let mut options = vec![];
+        loop {
+            match self.peek_token() {
+                None | Some(Token::Comma) | Some(Token::RParen) | Some(Token::Semicolon) => break,
+                _ => options.push(self.parse_column_option_def()?),
+            }
+        }
+
+        Ok(ColumnDef {
+            name,
+            data_type,
+            collation,
+            options,
+        })
+    }
+
     fn parse_column_option_def(&mut self) -> Result<ColumnOptionDef<Raw>, ParserError> {
         let name = if self.parse_keyword(CONSTRAINT) {
             Some(self.parse_identifier()?)
@@ -4138,6 +4429,7 @@ impl<'a> Parser<'a> {
             ObjectType::Index => self.parse_alter_index(),
             ObjectType::Secret => self.parse_alter_secret(),
             ObjectType::Connection => self.parse_alter_connection(),
+            ObjectType::Subscription => self.parse_alter_subscription(),
             ObjectType::View | ObjectType::MaterializedView | ObjectType::Table => {
                 self.parse_alter_views(object_type)
             }
@@ -4219,7 +4511,7 @@ impl<'a> Parser<'a> {
                 }))
             }
             ObjectType::Schema => self.parse_alter_schema(object_type),
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Func | ObjectType::Policy | ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported ALTER on {object_type}")
@@ -4601,6 +4893,62 @@ impl<'a> Parser<'a> {
         )
     }
 
+    fn parse_alter_subscription(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
+        let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
+        let name = self.parse_item_name().map_no_statement_parser_err()?;
+
+        Ok(
+            match self
+                .expect_one_of_keywords(&[SET, RENAME, OWNER])
+                .map_no_statement_parser_err()?
+            {
+                SET => {
+                    self.expect_token(&Token::LParen)
+                        .map_parser_err(StatementKind::AlterSubscription)?;
+                    let options = self
+                        .parse_comma_separated(Parser::parse_subscription_option)
+                        .map_parser_err(StatementKind::AlterSubscription)?;
+                    self.expect_token(&Token::RParen)
+                        .map_parser_err(StatementKind::AlterSubscription)?;
+                    Statement::AlterSubscription(AlterSubscriptionStatement {
+                        name,
+                        if_exists,
+                        options,
+                    })
+                }
+                RENAME => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterObjectRename)?;
+                    let to_item_name = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterObjectRename)?;
+
+                    Statement::AlterObjectRename(AlterObjectRenameStatement {
+                        object_type: ObjectType::Subscription,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        to_item_name,
+                    })
+                }
+                OWNER => {
+                    self.expect_keyword(TO)
+                        .map_parser_err(StatementKind::AlterOwner)?;
+                    let new_owner = self
+                        .parse_identifier()
+                        .map_parser_err(StatementKind::AlterOwner)?;
+
+                    Statement::AlterOwner(AlterOwnerStatement {
+                        object_type: ObjectType::Subscription,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        new_owner,
+                    })
+                }
+                _ => unreachable!(),
+            },
+        )
+    }
+
     /// Parse an ALTER SINK statement.
     fn parse_alter_sink(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
@@ -4838,7 +5186,7 @@ impl<'a> Parser<'a> {
             }
             Some(WITH) | None => {
                 let _ = self.parse_keyword(WITH);
-                let attrs = self.parse_role_attributes();
+                let attrs = self.parse_role_attributes()?;
                 AlterRoleOption::Attributes(attrs)
             }
             Some(k) => unreachable!("unmatched keyword: {k}"),
@@ -4873,7 +5221,7 @@ impl<'a> Parser<'a> {
             GrantTargetAllSpecification::All
         };
         let is_grant = self.expect_one_of_keywords(&[GRANT, REVOKE])? == GRANT;
-        let privileges = self.parse_privilege_specification().ok_or_else(|| {
+        let privileges = self.parse_privilege_specification()?.ok_or_else(|| {
             self.expected::<_, PrivilegeSpecification>(
                 self.peek_pos(),
                 "ALL or INSERT or SELECT or UPDATE or DELETE or USAGE or CREATE",
@@ -4920,10 +5268,20 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement<Raw>, ParserStatementError> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
+        let keywords: &[_] = match object_type {
+            ObjectType::Table => &[SET, RENAME, OWNER, ADD, DROP],
+            _ => &[SET, RENAME, OWNER],
+        };
         let action = self
-            .expect_one_of_keywords(&[SET, RENAME, OWNER])
+            .expect_one_of_keywords(keywords)
             .map_no_statement_parser_err()?;
         match action {
+            ADD => self
+                .parse_alter_table_add_column(if_exists, name)
+                .map_parser_err(StatementKind::AlterTable),
+            DROP => self
+                .parse_alter_table_drop_column(if_exists, name)
+                .map_parser_err(StatementKind::AlterTable),
             RENAME => {
                 self.expect_keyword(TO).map_no_statement_parser_err()?;
                 let to_item_name = self
@@ -4953,6 +5311,45 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the remainder of `ALTER TABLE ... ADD [COLUMN] ...`, after `ADD`.
+    fn parse_alter_table_add_column(
+        &mut self,
+        if_exists: bool,
+        table_name: UnresolvedItemName,
+    ) -> Result<Statement<Raw>, ParserError> {
+        let _ = self.parse_keyword(COLUMN);
+        let if_col_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_identifier()?;
+        let column = self.parse_column_def(name)?;
+        Ok(Statement::AlterTable(AlterTableStatement {
+            table_name,
+            if_exists,
+            action: AlterTableAction::AddColumn {
+                if_col_not_exists,
+                column,
+            },
+        }))
+    }
+
+    /// Parses the remainder of `ALTER TABLE ... DROP [COLUMN] ...`, after `DROP`.
+    fn parse_alter_table_drop_column(
+        &mut self,
+        if_exists: bool,
+        table_name: UnresolvedItemName,
+    ) -> Result<Statement<Raw>, ParserError> {
+        let _ = self.parse_keyword(COLUMN);
+        let if_col_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        Ok(Statement::AlterTable(AlterTableStatement {
+            table_name,
+            if_exists,
+            action: AlterTableAction::DropColumn {
+                if_col_exists,
+                name,
+            },
+        }))
+    }
+
     fn parse_alter_schema(
         &mut self,
         object_type: ObjectType,
@@ -5584,7 +5981,9 @@ impl<'a> Parser<'a> {
             | ObjectType::Type
             | ObjectType::Secret
             | ObjectType::Connection
-            | ObjectType::Func => UnresolvedObjectName::Item(self.parse_item_name()?),
+            | ObjectType::Func
+            | ObjectType::Policy
+            | ObjectType::Subscription => UnresolvedObjectName::Item(self.parse_item_name()?),
             ObjectType::Role => UnresolvedObjectName::Role(self.parse_identifier()?),
             ObjectType::Cluster => UnresolvedObjectName::Cluster(self.parse_identifier()?),
             ObjectType::ClusterReplica => {
@@ -5624,6 +6023,18 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the name of a configuration parameter, which is either an
+    /// identifier or, for custom parameters, identifiers separated by a '.',
+    /// e.g. `app.tenant`.
+    fn parse_variable_name(&mut self) -> Result<Ident, ParserError> {
+        let mut name = self.parse_identifier()?;
+        while self.consume_token(&Token::Dot) {
+            let part = self.parse_identifier()?;
+            name = Ident::new_unchecked(format!("{}.{}", name.as_str(), part.as_str()));
+        }
+        Ok(name)
+    }
+
     fn consume_identifier(&mut self) -> Option<Ident> {
         match self.peek_token() {
             Some(Token::Keyword(kw)) => {
@@ -6150,7 +6561,7 @@ impl<'a> Parser<'a> {
 
     fn parse_set(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
         let modifier = self.parse_one_of_keywords(&[SESSION, LOCAL]);
-        let mut variable = self.parse_identifier().map_no_statement_parser_err()?;
+        let mut variable = self.parse_variable_name().map_no_statement_parser_err()?;
         let mut normal = self.consume_token(&Token::Eq) || self.parse_keyword(TO);
         if !normal {
             match variable.as_str().parse() {
@@ -6240,7 +6651,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_reset(&mut self) -> Result<Statement<Raw>, ParserError> {
-        let mut variable = self.parse_identifier()?;
+        let mut variable = self.parse_variable_name()?;
         if variable.as_str().parse() == Ok(SCHEMA) {
             variable = ident!("search_path");
         }
@@ -6342,7 +6753,7 @@ impl<'a> Parser<'a> {
                         on_object,
                     }
                 }
-                ObjectType::Func => {
+                ObjectType::Func | ObjectType::Policy | ObjectType::Subscription => {
                     return parser_err!(
                         self,
                         self.peek_prev_pos(),
@@ -6413,7 +6824,7 @@ impl<'a> Parser<'a> {
             } else if self.parse_keywords(&[TIME, ZONE]) {
                 ident!("timezone")
             } else {
-                self.parse_identifier()?
+                self.parse_variable_name()?
             };
             Ok(ShowStatement::ShowVariable(ShowVariableStatement {
                 variable,
@@ -6731,15 +7142,51 @@ impl<'a> Parser<'a> {
         } else {
             InsertSource::Query(self.parse_query()?)
         };
+        let on_conflict = if self.parse_keywords(&[ON, CONFLICT]) {
+            Some(self.parse_on_conflict()?)
+        } else {
+            None
+        };
         let returning = self.parse_returning()?;
         Ok(Statement::Insert(InsertStatement {
             table_name,
             columns,
             source,
+            on_conflict,
             returning,
         }))
     }
 
+    fn parse_on_conflict(&mut self) -> Result<OnConflict<Raw>, ParserError> {
+        let target = self.parse_parenthesized_column_list(Optional)?;
+        self.expect_keyword(DO)?;
+        let action = match self.expect_one_of_keywords(&[NOTHING, UPDATE])? {
+            NOTHING => OnConflictAction::DoNothing,
+            UPDATE => {
+                if target.is_empty() {
+                    return parser_err!(
+                        self,
+                        self.peek_prev_pos(),
+                        "ON CONFLICT DO UPDATE requires a conflict target"
+                    );
+                }
+                self.expect_keyword(SET)?;
+                let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                let selection = if self.parse_keyword(WHERE) {
+                    Some(self.parse_expr()?)
+                } else {
+                    None
+                };
+                OnConflictAction::DoUpdate {
+                    assignments,
+                    selection,
+                }
+            }
+            _ => unreachable!(),
+        };
+        Ok(OnConflict { target, action })
+    }
+
     fn parse_returning(&mut self) -> Result<Vec<SelectItem<Raw>>, ParserError> {
         Ok(if self.parse_keyword(RETURNING) {
             self.parse_comma_separated(Parser::parse_select_item)?
@@ -7255,6 +7702,24 @@ impl<'a> Parser<'a> {
         Ok(Statement::Close(CloseStatement { name }))
     }
 
+    /// Parse a `LISTEN` statement, assuming that the `LISTEN` token
+    /// has already been consumed.
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_item_name()?;
+        Ok(Statement::Listen(ListenStatement { name }))
+    }
+
+    /// Parse an `UNLISTEN` statement, assuming that the `UNLISTEN` token
+    /// has already been consumed.
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_item_name()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { name }))
+    }
+
     /// Parse a `PREPARE` statement, assuming that the `PREPARE` token
     /// has already been consumed.
     fn parse_prepare(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
@@ -7363,7 +7828,10 @@ impl<'a> Parser<'a> {
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::GrantPrivileges)?
+        {
             Some(privileges) => self
                 .parse_grant_privilege(privileges)
                 .map_parser_err(StatementKind::GrantPrivileges),
@@ -7405,7 +7873,10 @@ impl<'a> Parser<'a> {
     /// Parse a `REVOKE` statement, assuming that the `REVOKE` token
     /// has already been consumed.
     fn parse_revoke(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
-        match self.parse_privilege_specification() {
+        match self
+            .parse_privilege_specification()
+            .map_parser_err(StatementKind::RevokePrivileges)?
+        {
             Some(privileges) => self
                 .parse_revoke_privilege(privileges)
                 .map_parser_err(StatementKind::RevokePrivileges),
@@ -7547,7 +8018,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Index
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::Func
+            | ObjectType::Policy
+            | ObjectType::Subscription
             | ObjectType::Subsource => {
                 parser_err!(
                     self,
@@ -7556,6 +8028,7 @@ impl<'a> Parser<'a> {
                 )
             }
             ObjectType::Table
+            | ObjectType::Func
             | ObjectType::Type
             | ObjectType::Cluster
             | ObjectType::Secret
@@ -7584,6 +8057,8 @@ impl<'a> Parser<'a> {
                 DATABASE,
                 SCHEMA,
                 FUNCTION,
+                POLICY,
+                SUBSCRIPTION,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -7611,6 +8086,8 @@ impl<'a> Parser<'a> {
                 DATABASE => ObjectType::Database,
                 SCHEMA => ObjectType::Schema,
                 FUNCTION => ObjectType::Func,
+                POLICY => ObjectType::Policy,
+                SUBSCRIPTION => ObjectType::Subscription,
                 _ => unreachable!(),
             },
         )
@@ -7635,6 +8112,8 @@ impl<'a> Parser<'a> {
                 DATABASE,
                 SCHEMA,
                 FUNCTION,
+                POLICY,
+                SUBSCRIPTION,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -7663,6 +8142,8 @@ impl<'a> Parser<'a> {
                 DATABASE => ObjectType::Database,
                 SCHEMA => ObjectType::Schema,
                 FUNCTION => ObjectType::Func,
+                POLICY => ObjectType::Policy,
+                SUBSCRIPTION => ObjectType::Subscription,
                 _ => unreachable!(),
             },
         )
@@ -7868,6 +8349,7 @@ impl<'a> Parser<'a> {
                 SELECT,
                 UPDATE,
                 DELETE,
+                EXECUTE,
                 USAGE,
                 CREATE,
                 CREATEROLE,
@@ -7878,6 +8360,7 @@ impl<'a> Parser<'a> {
                 SELECT => Privilege::SELECT,
                 UPDATE => Privilege::UPDATE,
                 DELETE => Privilege::DELETE,
+                EXECUTE => Privilege::EXECUTE,
                 USAGE => Privilege::USAGE,
                 CREATE => Privilege::CREATE,
                 CREATEROLE => Privilege::CREATEROLE,
@@ -7889,24 +8372,46 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse one or more privileges separated by a ','.
-    fn parse_privilege_specification(&mut self) -> Option<PrivilegeSpecification> {
+    fn parse_privilege_specification(
+        &mut self,
+    ) -> Result<Option<PrivilegeSpecification>, ParserError> {
         if self.parse_keyword(ALL) {
             let _ = self.parse_keyword(PRIVILEGES);
-            return Some(PrivilegeSpecification::All);
+            return Ok(Some(PrivilegeSpecification::All));
         }
 
         let mut privileges = Vec::new();
-        while let Some(privilege) = self.parse_privilege() {
-            privileges.push(privilege);
+        let mut column_privileges = Vec::new();
+        loop {
+            let pos = self.peek_pos();
+            let Some(privilege) = self.parse_privilege() else {
+                break;
+            };
+            if self.consume_token(&Token::LParen) {
+                let columns = self.parse_comma_separated(Parser::parse_identifier)?;
+                self.expect_token(&Token::RParen)?;
+                column_privileges.push(ColumnPrivilege { privilege, columns });
+            } else {
+                privileges.push(privilege);
+            }
+            if !privileges.is_empty() && !column_privileges.is_empty() {
+                return parser_err!(
+                    self,
+                    pos,
+                    "cannot mix column privileges and object privileges"
+                );
+            }
             if !self.consume_token(&Token::Comma) {
                 break;
             }
         }
 
-        if privileges.is_empty() {
-            None
+        if !column_privileges.is_empty() {
+            Ok(Some(PrivilegeSpecification::Columns(column_privileges)))
+        } else if !privileges.is_empty() {
+            Ok(Some(PrivilegeSpecification::Privileges(privileges)))
         } else {
-            Some(PrivilegeSpecification::Privileges(privileges))
+            Ok(None)
         }
     }
 
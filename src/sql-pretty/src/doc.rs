@@ -216,6 +216,9 @@ pub(crate) fn doc_insert<T: AstInfo>(v: &InsertStatement<T>) -> RcDoc {
         _ => doc_display(&v.source, "insert source"),
     };
     let mut doc = intersperse_line_nest([intersperse_line_nest(first), sources]);
+    if let Some(on_conflict) = &v.on_conflict {
+        doc = nest(doc, doc_display_pass(on_conflict));
+    }
     if !v.returning.is_empty() {
         doc = nest(
             doc,
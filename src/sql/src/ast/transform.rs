@@ -17,10 +17,12 @@ use mz_repr::GlobalId;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
-    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    AstInfo, CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreatePolicyStatement, CreateSecretStatement,
+    CreateSinkStatement, CreateSourceStatement, CreateSubscriptionStatement,
+    CreateSubsourceStatement, CreateTableStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, Expr, FunctionBody, Ident, Query, Raw, RawItemName, Select,
+    SelectItem, SetExpr, Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -45,6 +47,9 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTable(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
+        | stmt @ Statement::CreateFunction(_)
+        | stmt @ Statement::CreatePolicy(_)
+        | stmt @ Statement::CreateSubscription(_)
         | stmt @ Statement::CreateSecret(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
@@ -136,6 +141,9 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         Statement::CreateIndex(CreateIndexStatement { name, .. }) => {
             *name = Some(Ident::new_unchecked(to_item_name));
         }
+        Statement::CreatePolicy(CreatePolicyStatement { name, .. }) => {
+            *name = Ident::new_unchecked(to_item_name);
+        }
         Statement::CreateSink(CreateSinkStatement {
             name: Some(name), ..
         })
@@ -148,6 +156,7 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         | Statement::CreateMaterializedView(CreateMaterializedViewStatement { name, .. })
         | Statement::CreateTable(CreateTableStatement { name, .. })
         | Statement::CreateSecret(CreateSecretStatement { name, .. })
+        | Statement::CreateSubscription(CreateSubscriptionStatement { name, .. })
         | Statement::CreateConnection(CreateConnectionStatement { name, .. })
         | Statement::CreateWebhookSource(CreateWebhookSourceStatement { name, .. }) => {
             // The last name in an ItemName is the item name. The item name
@@ -196,6 +205,10 @@ pub fn create_stmt_rename_refs(
         Statement::CreateSink(CreateSinkStatement { from, .. }) => {
             maybe_update_item_name(from.name_mut());
         }
+        Statement::CreatePolicy(CreatePolicyStatement { on_name, .. })
+        | Statement::CreateSubscription(CreateSubscriptionStatement { on_name, .. }) => {
+            maybe_update_item_name(on_name.name_mut());
+        }
         Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { query, .. },
             ..
@@ -203,6 +216,27 @@ pub fn create_stmt_rename_refs(
         | Statement::CreateMaterializedView(CreateMaterializedViewStatement { query, .. }) => {
             rewrite_query(from_name, to_item_name, query)?;
         }
+        Statement::CreateFunction(CreateFunctionStatement { body, .. }) => match body {
+            FunctionBody::Atomic(query) => rewrite_query(from_name, to_item_name, query)?,
+            FunctionBody::Return(expr) => {
+                // Rewrite the expression as the sole projection of a query,
+                // so that it is subject to the same ambiguity checks.
+                let mut query = Query::select(Select::default().project(SelectItem::Expr {
+                    expr: expr.clone(),
+                    alias: None,
+                }));
+                rewrite_query(from_name, to_item_name, &mut query)?;
+                match query.body {
+                    SetExpr::Select(mut select) => match select.projection.pop() {
+                        Some(SelectItem::Expr {
+                            expr: rewritten, ..
+                        }) => *expr = rewritten,
+                        _ => unreachable!("query constructed with a single projection"),
+                    },
+                    _ => unreachable!("query constructed from a select"),
+                }
+            }
+        },
         Statement::CreateSource(_)
         | Statement::CreateSubsource(_)
         | Statement::CreateTable(_)
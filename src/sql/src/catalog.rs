@@ -29,7 +29,7 @@ use mz_ore::str::StrExt;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
 use mz_repr::explain::ExprHumanizer;
 use mz_repr::role_id::RoleId;
-use mz_repr::{ColumnName, GlobalId, RelationDesc};
+use mz_repr::{ColumnName, GlobalId, RelationDesc, Timestamp};
 use mz_sql_parser::ast::{Expr, Ident, QualifiedReplica, UnresolvedItemName};
 use mz_storage_types::connections::inline::{ConnectionResolver, ReferencedConnection};
 use mz_storage_types::connections::{Connection, ConnectionContext};
@@ -50,6 +50,7 @@ use crate::normalize;
 use crate::plan::statement::ddl::PlannedRoleAttributes;
 use crate::plan::statement::StatementDesc;
 use crate::plan::{query, PlanError, PlanNotice};
+use crate::session::scram::ScramVerifier;
 use crate::session::vars::{OwnedVarInput, SystemVars};
 
 /// A catalog keeps track of SQL objects and session state available to the
@@ -84,6 +85,9 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync + ConnectionR
     /// Returns the id of the role that is issuing the query.
     fn active_role_id(&self) -> &RoleId;
 
+    /// Returns whether the session issuing the query is a superuser.
+    fn is_superuser(&self) -> bool;
+
     /// Returns the database to use if one is not explicitly specified.
     fn active_database_name(&self) -> Option<&str> {
         self.active_database()
@@ -409,6 +413,11 @@ pub trait CatalogSchema {
 pub struct RoleAttributes {
     /// Indicates whether the role has inheritance of privileges.
     pub inherit: bool,
+    /// The SCRAM-SHA-256 verifier of the role's password, if the role has a
+    /// password. See [`crate::session::scram::ScramVerifier`].
+    pub password_hash: Option<String>,
+    /// Indicates whether the role bypasses row-level security policies.
+    pub bypass_rls: bool,
     // Force use of constructor.
     _private: (),
 }
@@ -418,6 +427,8 @@ impl RoleAttributes {
     pub const fn new() -> RoleAttributes {
         RoleAttributes {
             inherit: true,
+            password_hash: None,
+            bypass_rls: false,
             _private: (),
         }
     }
@@ -425,6 +436,7 @@ impl RoleAttributes {
     /// Adds all attributes.
     pub const fn with_all(mut self) -> RoleAttributes {
         self.inherit = true;
+        self.bypass_rls = true;
         self
     }
 
@@ -432,13 +444,32 @@ impl RoleAttributes {
     pub const fn is_inherit(&self) -> bool {
         self.inherit
     }
+
+    /// Returns the verifier of the role's password, if the role has a valid
+    /// password.
+    pub fn password_verifier(&self) -> Option<ScramVerifier> {
+        self.password_hash.as_deref().and_then(ScramVerifier::parse)
+    }
+
+    /// Returns whether or not the role bypasses row-level security policies.
+    pub const fn is_bypass_rls(&self) -> bool {
+        self.bypass_rls
+    }
 }
 
 impl From<PlannedRoleAttributes> for RoleAttributes {
-    fn from(PlannedRoleAttributes { inherit }: PlannedRoleAttributes) -> RoleAttributes {
+    fn from(
+        PlannedRoleAttributes {
+            inherit,
+            password_hash,
+            bypass_rls,
+        }: PlannedRoleAttributes,
+    ) -> RoleAttributes {
         let default_attributes = RoleAttributes::new();
         RoleAttributes {
             inherit: inherit.unwrap_or(default_attributes.inherit),
+            password_hash: password_hash.unwrap_or(default_attributes.password_hash),
+            bypass_rls: bypass_rls.unwrap_or(default_attributes.bypass_rls),
             _private: (),
         }
     }
@@ -546,7 +577,7 @@ pub trait CatalogItem {
     ///
     /// If the catalog item is not of a type that produces functions (i.e.,
     /// anything other than a function), it returns an error.
-    fn func(&self) -> Result<&'static Func, CatalogError>;
+    fn func(&self) -> Result<&Func, CatalogError>;
 
     /// Returns the resolved source connection.
     ///
@@ -600,6 +631,16 @@ pub trait CatalogItem {
     /// catalog item is a table.
     fn table_details(&self) -> Option<&[Expr<Aug>]>;
 
+    /// Returns the `USING` expression and the restricted relation associated
+    /// with the catalog item, if the catalog item is a row-level security
+    /// policy.
+    fn policy_details(&self) -> Option<(&Expr<Aug>, GlobalId)>;
+
+    /// Returns the subscribed relation and the acknowledged frontier
+    /// associated with the catalog item, if the catalog item is a durable
+    /// subscription.
+    fn subscription_details(&self) -> Option<(GlobalId, Option<Timestamp>)>;
+
     /// Returns the type information associated with the catalog item, if the
     /// catalog item is a type.
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>>;
@@ -610,6 +651,9 @@ pub trait CatalogItem {
     /// Returns the privileges associated with the item.
     fn privileges(&self) -> &PrivilegeMap;
 
+    /// Returns the privileges associated with individual columns of the item.
+    fn column_privileges(&self) -> &BTreeMap<ColumnName, PrivilegeMap>;
+
     /// Returns the cluster the item belongs to.
     fn cluster_id(&self) -> Option<ClusterId>;
 }
@@ -637,6 +681,10 @@ pub enum CatalogItemType {
     Secret,
     /// A connection.
     Connection,
+    /// A row-level security policy.
+    Policy,
+    /// A durable subscription.
+    Subscription,
 }
 
 impl fmt::Display for CatalogItemType {
@@ -649,9 +697,11 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::MaterializedView => f.write_str("materialized view"),
             CatalogItemType::Index => f.write_str("index"),
             CatalogItemType::Type => f.write_str("type"),
-            CatalogItemType::Func => f.write_str("func"),
+            CatalogItemType::Func => f.write_str("function"),
             CatalogItemType::Secret => f.write_str("secret"),
             CatalogItemType::Connection => f.write_str("connection"),
+            CatalogItemType::Policy => f.write_str("policy"),
+            CatalogItemType::Subscription => f.write_str("subscription"),
         }
     }
 }
@@ -669,6 +719,8 @@ impl From<CatalogItemType> for ObjectType {
             CatalogItemType::Func => ObjectType::Func,
             CatalogItemType::Secret => ObjectType::Secret,
             CatalogItemType::Connection => ObjectType::Connection,
+            CatalogItemType::Policy => ObjectType::Policy,
+            CatalogItemType::Subscription => ObjectType::Subscription,
         }
     }
 }
@@ -731,6 +783,9 @@ pub enum CatalogType<T: TypeReference> {
     Bytes,
     Char,
     Date,
+    Enum {
+        labels: Vec<String>,
+    },
     Float32,
     Float64,
     Int16,
@@ -774,6 +829,9 @@ pub enum CatalogType<T: TypeReference> {
     VarChar,
     Int2Vector,
     MzAclItem,
+    Inet,
+    Cidr,
+    MacAddr,
 }
 
 impl CatalogType<IdReference> {
@@ -1278,6 +1336,8 @@ pub enum ObjectType {
     Database,
     Schema,
     Func,
+    Policy,
+    Subscription,
 }
 
 impl ObjectType {
@@ -1294,6 +1354,8 @@ impl ObjectType {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
+            | ObjectType::Policy
+            | ObjectType::Subscription
             | ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Cluster
@@ -1322,6 +1384,8 @@ impl From<mz_sql_parser::ast::ObjectType> for ObjectType {
             mz_sql_parser::ast::ObjectType::Database => ObjectType::Database,
             mz_sql_parser::ast::ObjectType::Schema => ObjectType::Schema,
             mz_sql_parser::ast::ObjectType::Func => ObjectType::Func,
+            mz_sql_parser::ast::ObjectType::Policy => ObjectType::Policy,
+            mz_sql_parser::ast::ObjectType::Subscription => ObjectType::Subscription,
         }
     }
 }
@@ -1366,6 +1430,8 @@ impl Display for ObjectType {
             ObjectType::Database => "DATABASE",
             ObjectType::Schema => "SCHEMA",
             ObjectType::Func => "FUNCTION",
+            ObjectType::Policy => "POLICY",
+            ObjectType::Subscription => "SUBSCRIPTION",
         })
     }
 }
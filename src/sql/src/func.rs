@@ -23,7 +23,7 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, ColumnType, Datum, RelationType, Row, ScalarBaseType, ScalarType};
 use once_cell::sync::Lazy;
 
-use crate::ast::{SelectStatement, Statement};
+use crate::ast::{Expr, FunctionBody, Query, Select, SelectItem, SelectStatement, Statement};
 use crate::catalog::{CatalogType, TypeCategory, TypeReference};
 use crate::names::{self, ResolvedItemName};
 use crate::plan::error::PlanError;
@@ -36,6 +36,7 @@ use crate::plan::scope::Scope;
 use crate::plan::side_effecting_func::PG_CATALOG_SEF_BUILTINS;
 use crate::plan::transform_ast;
 use crate::plan::typeconv::{self, CastContext};
+use crate::plan::{Function, FunctionReturns, StatementContext};
 use crate::session::vars::{self, ENABLE_TIME_AT_TIME_ZONE};
 
 /// A specifier for a function or an operator.
@@ -77,7 +78,9 @@ impl TypeCategory {
             | ScalarType::Bytes
             | ScalarType::Jsonb
             | ScalarType::Uuid
-            | ScalarType::MzAclItem => Self::UserDefined,
+            | ScalarType::MzAclItem
+            | ScalarType::MacAddr => Self::UserDefined,
+            ScalarType::Inet | ScalarType::Cidr => Self::NetworkAddress,
             ScalarType::Date
             | ScalarType::Time
             | ScalarType::Timestamp { .. }
@@ -112,6 +115,7 @@ impl TypeCategory {
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
             ScalarType::Range { .. } => Self::Range,
+            ScalarType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -131,6 +135,7 @@ impl TypeCategory {
             | ParamType::MapAny
             | ParamType::MapAnyCompatible
             | ParamType::RecordAny => Self::Pseudo,
+            ParamType::EnumAny => Self::Enum,
             ParamType::RangeAnyCompatible | ParamType::RangeAny => Self::Range,
             ParamType::Plain(t) => Self::from_type(t),
         }
@@ -151,7 +156,9 @@ impl TypeCategory {
             | CatalogType::Bytes
             | CatalogType::Jsonb
             | CatalogType::Uuid
-            | CatalogType::MzAclItem => Self::UserDefined,
+            | CatalogType::MzAclItem
+            | CatalogType::MacAddr => Self::UserDefined,
+            CatalogType::Inet | CatalogType::Cidr => Self::NetworkAddress,
             CatalogType::Date
             | CatalogType::Time
             | CatalogType::Timestamp
@@ -177,6 +184,7 @@ impl TypeCategory {
             | CatalogType::Char { .. }
             | CatalogType::VarChar { .. } => Self::String,
             CatalogType::Record { .. } => TypeCategory::Composite,
+            CatalogType::Enum { .. } => Self::Enum,
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
             CatalogType::Range { .. } => Self::Range,
@@ -198,13 +206,13 @@ impl TypeCategory {
             | Self::Enum
             | Self::Geometric
             | Self::List
-            | Self::NetworkAddress
             | Self::Pseudo
             | Self::Range
             | Self::Unknown
             | Self::UserDefined => None,
             Self::Boolean => Some(ScalarType::Bool),
             Self::DateTime => Some(ScalarType::TimestampTz { precision: None }),
+            Self::NetworkAddress => Some(ScalarType::Inet),
             Self::Numeric => Some(ScalarType::Float64),
             Self::String => Some(ScalarType::String),
             Self::Timespan => Some(ScalarType::Interval),
@@ -473,6 +481,170 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// Constructs the definition of a SQL-language function created via
+/// `CREATE FUNCTION`.
+///
+/// Like functions defined via `sql_impl_func`, the body is planned anew at
+/// each call site with its parameters bound to the declared parameter types,
+/// and the call's arguments are then spliced in for the parameters. The
+/// function is thus inlined into the calling query and optimized as if its
+/// body had been written out by hand.
+///
+/// User-defined functions have exactly one implementation. Its OID is left
+/// unset, as the function's OID is that of its catalog entry.
+pub fn sql_function(name: &str, function: &Function) -> Func {
+    let Function {
+        create_sql: _,
+        params,
+        returns,
+        body,
+    } = function.clone();
+    let param_list = ParamList::Exact(
+        params
+            .iter()
+            .map(|(_name, ty)| ParamType::Plain(ty.clone()))
+            .collect(),
+    );
+    let param_types: BTreeMap<usize, ScalarType> = params
+        .iter()
+        .enumerate()
+        .map(|(i, (_name, ty))| (i + 1, ty.clone()))
+        .collect();
+    let param_names: BTreeMap<ColumnName, usize> = params
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (name, _ty))| name.map(|name| (name, i + 1)))
+        .collect();
+
+    match returns {
+        FunctionReturns::Scalar(return_type) => {
+            // A query body of a scalar function produces its only row's only
+            // column.
+            let expr = match body {
+                FunctionBody::Return(expr) => expr,
+                FunctionBody::Atomic(query) => Expr::Subquery(Box::new(query)),
+            };
+            let ret = ReturnType::scalar(ParamType::Plain(return_type.clone()));
+            let op = Operation::variadic(move |ecx, args| {
+                let scx = bind_function_params(ecx.qcx.scx, &param_types, &param_names);
+                let qcx = QueryContext::root(&scx, ecx.qcx.lifetime);
+                let (mut expr, _) = names::resolve(qcx.scx.catalog, expr.clone())?;
+                transform_ast::transform(&scx, &mut expr)?;
+                let ecx = ExprContext {
+                    qcx: &qcx,
+                    name: "function body",
+                    scope: &Scope::empty(),
+                    relation_type: &RelationType::empty(),
+                    allow_aggregates: false,
+                    allow_subqueries: true,
+                    allow_parameters: true,
+                    allow_windows: false,
+                };
+                let mut out = query::plan_expr(&ecx, &expr)?.cast_to(
+                    &ecx,
+                    CastContext::Assignment,
+                    &return_type,
+                )?;
+                out.splice_parameters(&args, 0);
+                Ok(out)
+            });
+            Func::Scalar(vec![FuncImpl {
+                oid: 0,
+                params: param_list,
+                return_type: ret,
+                op,
+            }])
+        }
+        returns => {
+            let query = match body {
+                FunctionBody::Atomic(query) => query,
+                FunctionBody::Return(expr) => {
+                    Query::select(Select::default().project(SelectItem::Expr { expr, alias: None }))
+                }
+            };
+            let (return_type, columns) = match returns {
+                FunctionReturns::Scalar(_) => unreachable!("handled above"),
+                FunctionReturns::SetOf(ty) => (
+                    ReturnType::set_of(ParamType::Plain(ty.clone())),
+                    vec![(ColumnName::from(name), ty)],
+                ),
+                FunctionReturns::Table(columns) => {
+                    (ReturnType::set_of(ParamType::RecordAny), columns)
+                }
+            };
+            let op = Operation::variadic(move |ecx, args| {
+                let scx = bind_function_params(ecx.qcx.scx, &param_types, &param_names);
+                let mut qcx = QueryContext::root(&scx, ecx.qcx.lifetime);
+                let (mut query, _) = names::resolve(qcx.scx.catalog, query.clone())?;
+                transform_ast::transform(&scx, &mut query)?;
+                let (expr, scope) = query::plan_nested_query(&mut qcx, &query)?;
+                let mut expr = cast_function_output(&qcx, expr, &scope, &columns)?;
+                expr.splice_parameters(&args, 0);
+                Ok(TableFuncPlan {
+                    expr,
+                    column_names: columns.iter().map(|(name, _ty)| name.clone()).collect(),
+                })
+            });
+            Func::Table(vec![FuncImpl {
+                oid: 0,
+                params: param_list,
+                return_type,
+                op,
+            }])
+        }
+    }
+}
+
+/// Reconstructs a statement context where the parameter types and names are
+/// bound to those of a SQL function's parameters.
+fn bind_function_params<'a>(
+    scx: &StatementContext<'a>,
+    param_types: &BTreeMap<usize, ScalarType>,
+    param_names: &BTreeMap<ColumnName, usize>,
+) -> StatementContext<'a> {
+    let mut scx = scx.clone();
+    scx.param_types = RefCell::new(param_types.clone());
+    scx.function_param_names = param_names.clone();
+    scx
+}
+
+/// Casts the columns produced by the body of a set-returning SQL function to
+/// the function's declared output columns.
+fn cast_function_output(
+    qcx: &QueryContext,
+    expr: HirRelationExpr,
+    scope: &Scope,
+    columns: &[(ColumnName, ScalarType)],
+) -> Result<HirRelationExpr, PlanError> {
+    let relation_type = qcx.relation_type(&expr);
+    let arity = relation_type.arity();
+    if arity != columns.len() {
+        sql_bail!(
+            "function body returns {} columns, but the function is declared to return {}",
+            arity,
+            columns.len()
+        );
+    }
+    let ecx = ExprContext {
+        qcx,
+        name: "function body",
+        scope,
+        relation_type: &relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let casts = columns
+        .iter()
+        .enumerate()
+        .map(|(i, (_name, ty))| {
+            typeconv::plan_cast(&ecx, CastContext::Assignment, HirScalarExpr::column(i), ty)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(expr.map(casts).project((arity..arity * 2).collect()))
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -682,6 +854,9 @@ pub enum ParamType {
     /// A pseudotype permitting any array type, permitting other "Compatibility"-type
     /// parameters to find the best common type.
     ArrayAnyCompatible,
+    /// A pseudotype permitting any enum type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    EnumAny,
     /// An pseudotype permitting any list type, requiring other "Any"-type
     /// parameters to be of the same type.
     ListAny,
@@ -737,6 +912,7 @@ impl ParamType {
         match self {
             Any | AnyElement | AnyCompatible | ListElementAnyCompatible => true,
             ArrayAny | ArrayAnyCompatible => matches!(t, Array(..) | Int2Vector),
+            EnumAny => matches!(t, Enum { .. }),
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
@@ -783,6 +959,7 @@ impl ParamType {
             | ArrayAny
             | ArrayAnyCompatible
             | AnyCompatible
+            | EnumAny
             | ListAny
             | ListAnyCompatible
             | ListElementAnyCompatible
@@ -814,6 +991,7 @@ impl ParamType {
             ParamType::AnyElement => "anyelement",
             ParamType::ArrayAny => "anyarray",
             ParamType::ArrayAnyCompatible => "anycompatiblearray",
+            ParamType::EnumAny => "anyenum",
             ParamType::Internal => "internal",
             ParamType::ListAny => "list",
             ParamType::ListAnyCompatible => "anycompatiblelist",
@@ -856,7 +1034,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | List | Map | Record | Range | Enum => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             AclItem => ScalarType::AclItem,
@@ -890,6 +1068,9 @@ impl From<ScalarBaseType> for ParamType {
             Int2Vector => ScalarType::Int2Vector,
             MzTimestamp => ScalarType::MzTimestamp,
             MzAclItem => ScalarType::MzAclItem,
+            Inet => ScalarType::Inet,
+            Cidr => ScalarType::Cidr,
+            MacAddr => ScalarType::MacAddr,
         };
         ParamType::Plain(s)
     }
@@ -1308,7 +1489,7 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
+            AnyElement | ArrayAny | EnumAny | ListAny | MapAny | NonVecAny | RangeAny => {
                 PolymorphicCompatClass::Any
             }
             ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible | NonVecAnyCompatible => {
@@ -1381,7 +1562,7 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             // These represent the keys of their respective compatibility classes.
-            AnyElement | AnyCompatible | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
             MapAny => seen.map(|array| array.unwrap_map_value_type().clone()),
             ListAny => seen.map(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => seen.map(|array| array.unwrap_array_element_type().clone()),
@@ -1501,9 +1682,8 @@ impl PolymorphicSolution {
         );
 
         match param {
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny => {
-                self.key.clone()
-            }
+            AnyElement | AnyCompatible | EnumAny | ListAnyCompatible | MapAnyCompatible
+            | NonVecAny => self.key.clone(),
             ArrayAny | ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -1849,6 +2029,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Bytes) => UnaryFunc::BitLengthBytes(func::BitLengthBytes) => Int32, 1810;
             params!(String) => UnaryFunc::BitLengthString(func::BitLengthString) => Int32, 1811;
         },
+        "broadcast" => Scalar {
+            params!(Inet) => UnaryFunc::InetBroadcast(func::InetBroadcast) => Inet, 698;
+        },
         "btrim" => Scalar {
             params!(String) => UnaryFunc::TrimWhitespace(func::TrimWhitespace) => String, 885;
             params!(String, String) => BinaryFunc::Trim => String, 884;
@@ -2029,6 +2212,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Float64) => UnaryFunc::Exp(func::Exp) => Float64, 1347;
             params!(Numeric) => UnaryFunc::ExpNumeric(func::ExpNumeric) => Numeric, 1732;
         },
+        "family" => Scalar {
+            params!(Inet) => UnaryFunc::InetFamily(func::InetFamily) => Int32, 711;
+        },
         "floor" => Scalar {
             params!(Float32) => UnaryFunc::FloorFloat32(func::FloorFloat32) => Float32, oid::FUNC_FLOOR_F32_OID;
             params!(Float64) => UnaryFunc::FloorFloat64(func::FloorFloat64) => Float64, 2309;
@@ -2078,6 +2264,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String) => sql_impl_func("has_table_privilege(current_user, $1, $2)") => Bool, 1926;
             params!(Oid, String) => sql_impl_func("has_table_privilege(current_user, $1, $2)") => Bool, 1927;
         },
+        "host" => Scalar {
+            params!(Inet) => UnaryFunc::InetHost(func::InetHost) => String, 699;
+        },
         "hmac" => Scalar {
             params!(String, String, String) => VariadicFunc::HmacString => Bytes, oid::FUNC_PG_HMAC_STRING;
             params!(Bytes, Bytes, String) => VariadicFunc::HmacBytes => Bytes, oid::FUNC_PG_HMAC_BYTES;
@@ -2208,6 +2397,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "make_timestamp" => Scalar {
             params!(Int64, Int64, Int64, Int64, Int64, Float64) => VariadicFunc::MakeTimestamp => Timestamp, 3461;
         },
+        "masklen" => Scalar {
+            params!(Inet) => UnaryFunc::InetMasklen(func::InetMasklen) => Int32, 697;
+        },
         "md5" => Scalar {
             params!(String) => Operation::unary(move |_ecx, input| {
                 let algorithm = HirScalarExpr::literal(Datum::String("md5"), ScalarType::String);
@@ -2229,6 +2421,12 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(UInt32, UInt32) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt32, oid::FUNC_MOD_UINT32_OID;
             params!(UInt64, UInt64) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt64, oid::FUNC_MOD_UINT64_OID;
         },
+        "netmask" => Scalar {
+            params!(Inet) => UnaryFunc::InetNetmask(func::InetNetmask) => Inet, 696;
+        },
+        "network" => Scalar {
+            params!(Inet) => UnaryFunc::InetNetwork(func::InetNetwork) => Cidr, 683;
+        },
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, 1299;
         },
@@ -2521,6 +2719,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
+        "set_masklen" => Scalar {
+            params!(Inet, Int32) => BinaryFunc::InetSetMasklen => Inet, 605;
+        },
         "sha256" => Scalar {
             params!(Bytes) => digest("sha256") => Bytes, 3420;
         },
@@ -2670,6 +2871,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Float32) => UnaryFunc::TruncFloat32(func::TruncFloat32) => Float32, oid::FUNC_TRUNC_F32_OID;
             params!(Float64) => UnaryFunc::TruncFloat64(func::TruncFloat64) => Float64, 1343;
             params!(Numeric) => UnaryFunc::TruncNumeric(func::TruncNumeric) => Numeric, 1710;
+            params!(MacAddr) => UnaryFunc::MacAddrTrunc(func::MacAddrTrunc) => MacAddr, 753;
         },
         "tsrange" => Scalar {
             params!(Timestamp, Timestamp) => Operation::variadic(|_ecx, mut exprs| {
@@ -3012,6 +3214,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Timestamp) => AggregateFunc::MaxTimestamp => Timestamp, 2126;
             params!(TimestampTz) => AggregateFunc::MaxTimestampTz => TimestampTz, 2127;
             params!(Numeric) => AggregateFunc::MaxNumeric => Numeric, oid::FUNC_MAX_NUMERIC_OID;
+            // Enum values are represented by their ordinal, which orders them
+            // by their position in the type's labels.
+            params!(EnumAny) => AggregateFunc::MaxUInt32 => EnumAny, 3527;
         },
         "min" => Aggregate {
             params!(Bool) => AggregateFunc::MinBool => Bool, oid::FUNC_MIN_BOOL_OID;
@@ -3031,6 +3236,7 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(Timestamp) => AggregateFunc::MinTimestamp => Timestamp, 2142;
             params!(TimestampTz) => AggregateFunc::MinTimestampTz => TimestampTz, 2143;
             params!(Numeric) => AggregateFunc::MinNumeric => Numeric, oid::FUNC_MIN_NUMERIC_OID;
+            params!(EnumAny) => AggregateFunc::MinUInt32 => EnumAny, 3526;
         },
         "jsonb_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
@@ -3868,6 +4074,9 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
                 )
             }) => Numeric, oid::FUNC_MZ_AVG_PROMOTION_NUMERIC_OID;
         },
+        "mz_bypasses_rls" => Scalar {
+            params!() => UnmaterializableFunc::MzBypassesRls => Bool, oid::FUNC_MZ_BYPASSES_RLS_OID;
+        },
         "mz_error_if_null" => Scalar {
             // If the first argument is NULL, returns an EvalError::Internal whose error
             // message is the second argument.
@@ -4264,6 +4473,10 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftLeftUInt32 => UInt32, oid::FUNC_SHIFT_LEFT_UINT32;
             params!(UInt64, UInt32) => BitShiftLeftUInt64 => UInt64, oid::FUNC_SHIFT_LEFT_UINT64;
             params!(RangeAny, RangeAny) => RangeBefore => Bool, 3893;
+            params!(Inet, Inet) => InetSubnet => Bool, 931;
+        },
+        "<<=" => Scalar {
+            params!(Inet, Inet) => InetSubnetEq => Bool, 932;
         },
         ">>" => Scalar {
             params!(Int16, Int32) => BitShiftRightInt16 => Int16, 1879;
@@ -4273,6 +4486,10 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftRightUInt32 => UInt32, oid::FUNC_SHIFT_RIGHT_UINT32;
             params!(UInt64, UInt32) => BitShiftRightUInt64 => UInt64, oid::FUNC_SHIFT_RIGHT_UINT64;
             params!(RangeAny, RangeAny) => RangeAfter => Bool, 3894;
+            params!(Inet, Inet) => InetSupernet => Bool, 933;
+        },
+        ">>=" => Scalar {
+            params!(Inet, Inet) => InetSupernetEq => Bool, 934;
         },
 
         // ILIKE
@@ -4487,6 +4704,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
         },
         "&&" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverlaps => Bool, 3888;
+            params!(Inet, Inet) => BinaryFunc::InetOverlaps => Bool, 3552;
         },
         "&<" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverleft => Bool, 3895;
@@ -4516,6 +4734,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lt => Bool, 2062;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lt => Bool, 1322;
             params!(Uuid, Uuid) => BinaryFunc::Lt => Bool, 2974;
+            params!(Inet, Inet) => BinaryFunc::Lt => Bool, 1203;
+            params!(MacAddr, MacAddr) => BinaryFunc::Lt => Bool, 1222;
             params!(Interval, Interval) => BinaryFunc::Lt => Bool, 1332;
             params!(Bytes, Bytes) => BinaryFunc::Lt => Bool, 1957;
             params!(String, String) => BinaryFunc::Lt => Bool, 664;
@@ -4527,6 +4747,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lt => Bool, 2990;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lt =>Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lt => Bool, 3884;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lt => Bool, 3518;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lte => Bool, 1755;
@@ -4545,6 +4766,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lte => Bool, 2063;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lte => Bool, 1323;
             params!(Uuid, Uuid) => BinaryFunc::Lte => Bool, 2976;
+            params!(Inet, Inet) => BinaryFunc::Lte => Bool, 1204;
+            params!(MacAddr, MacAddr) => BinaryFunc::Lte => Bool, 1223;
             params!(Interval, Interval) => BinaryFunc::Lte => Bool, 1333;
             params!(Bytes, Bytes) => BinaryFunc::Lte => Bool, 1958;
             params!(String, String) => BinaryFunc::Lte => Bool, 665;
@@ -4556,6 +4779,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lte => Bool, 2992;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lte =>Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lte => Bool, 3885;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lte => Bool, 3520;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gt => Bool, 1756;
@@ -4574,6 +4798,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gt => Bool, 2064;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gt => Bool, 1324;
             params!(Uuid, Uuid) => BinaryFunc::Gt => Bool, 2975;
+            params!(Inet, Inet) => BinaryFunc::Gt => Bool, 1205;
+            params!(MacAddr, MacAddr) => BinaryFunc::Gt => Bool, 1224;
             params!(Interval, Interval) => BinaryFunc::Gt => Bool, 1334;
             params!(Bytes, Bytes) => BinaryFunc::Gt => Bool, 1959;
             params!(String, String) => BinaryFunc::Gt => Bool, 666;
@@ -4585,6 +4811,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gt => Bool, 2991;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gt =>Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gt => Bool, 3887;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gt => Bool, 3519;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gte => Bool, 1757;
@@ -4603,6 +4830,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gte => Bool, 2065;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gte => Bool, 1325;
             params!(Uuid, Uuid) => BinaryFunc::Gte => Bool, 2977;
+            params!(Inet, Inet) => BinaryFunc::Gte => Bool, 1206;
+            params!(MacAddr, MacAddr) => BinaryFunc::Gte => Bool, 1225;
             params!(Interval, Interval) => BinaryFunc::Gte => Bool, 1335;
             params!(Bytes, Bytes) => BinaryFunc::Gte => Bool, 1960;
             params!(String, String) => BinaryFunc::Gte => Bool, 667;
@@ -4614,6 +4843,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gte => Bool, 2993;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gte =>Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gte => Bool, 3886;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gte => Bool, 3521;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -4641,6 +4871,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Eq => Bool, 2060;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Eq => Bool, 1320;
             params!(Uuid, Uuid) => BinaryFunc::Eq => Bool, 2972;
+            params!(Inet, Inet) => BinaryFunc::Eq => Bool, 1201;
+            params!(MacAddr, MacAddr) => BinaryFunc::Eq => Bool, 1220;
             params!(Interval, Interval) => BinaryFunc::Eq => Bool, 1330;
             params!(Bytes, Bytes) => BinaryFunc::Eq => Bool, 1955;
             params!(String, String) => BinaryFunc::Eq => Bool, 98;
@@ -4653,6 +4885,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Eq => Bool, 2988;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Eq => Bool, 3882;
+            params!(EnumAny, EnumAny) => BinaryFunc::Eq => Bool, 3516;
             params!(MzAclItem, MzAclItem) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
             params!(AclItem, AclItem) => BinaryFunc::Eq => Bool, 974;
         },
@@ -4673,6 +4906,8 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::NotEq => Bool, 2061;
             params!(TimestampTz, TimestampTz) => BinaryFunc::NotEq => Bool, 1321;
             params!(Uuid, Uuid) => BinaryFunc::NotEq => Bool, 2973;
+            params!(Inet, Inet) => BinaryFunc::NotEq => Bool, 1202;
+            params!(MacAddr, MacAddr) => BinaryFunc::NotEq => Bool, 1221;
             params!(Interval, Interval) => BinaryFunc::NotEq => Bool, 1331;
             params!(Bytes, Bytes) => BinaryFunc::NotEq => Bool, 1956;
             params!(String, String) => BinaryFunc::NotEq => Bool, 531;
@@ -4684,6 +4919,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::NotEq => Bool, 2989;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::NotEq => Bool, 3883;
+            params!(EnumAny, EnumAny) => BinaryFunc::NotEq => Bool, 3517;
             params!(MzAclItem, MzAclItem) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
     }
@@ -33,7 +33,8 @@ use crate::ast::fold::{Fold, FoldNode};
 use crate::ast::visit::{Visit, VisitNode};
 use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
-    self, AstInfo, Cte, CteBlock, CteMutRec, Ident, Query, Raw, RawClusterName, RawDataType,
+    self, AstInfo, Cte, CteBlock, CteMutRec, GrantTargetSpecification,
+    GrantTargetSpecificationInner, Ident, ObjectType, Query, Raw, RawClusterName, RawDataType,
     RawItemName, Statement, UnresolvedItemName,
 };
 use crate::catalog::{CatalogError, CatalogItemType, CatalogTypeDetails, SessionCatalog};
@@ -1737,6 +1738,8 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                     .collect(),
             ),
             ConnectionKafkaBroker(broker) => ConnectionKafkaBroker(self.fold_kafka_broker(broker)),
+            RetainHistoryFor(value) => RetainHistoryFor(self.fold_value(value)),
+            Refresh(refresh) => Refresh(self.fold_refresh_option_value(refresh)),
         }
     }
 
@@ -1798,6 +1801,36 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
         }
     }
 
+    fn fold_grant_target_specification(
+        &mut self,
+        node: GrantTargetSpecification<Raw>,
+    ) -> GrantTargetSpecification<Aug> {
+        match node {
+            // Functions live in a separate namespace from other items.
+            GrantTargetSpecification::Object {
+                object_type: ObjectType::Func,
+                object_spec_inner: GrantTargetSpecificationInner::Objects { names },
+            } => GrantTargetSpecification::Object {
+                object_type: ObjectType::Func,
+                object_spec_inner: GrantTargetSpecificationInner::Objects {
+                    names: names
+                        .into_iter()
+                        .map(|name| match name {
+                            UnresolvedObjectName::Item(name) => {
+                                ResolvedObjectName::Item(self.fold_raw_object_name_name_internal(
+                                    RawItemName::Name(name),
+                                    true,
+                                ))
+                            }
+                            name => self.fold_object_name(name),
+                        })
+                        .collect(),
+                },
+            },
+            node => ast::fold::fold_grant_target_specification(self, node),
+        }
+    }
+
     fn fold_function(
         &mut self,
         node: mz_sql_parser::ast::Function<Raw>,
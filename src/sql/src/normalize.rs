@@ -21,11 +21,13 @@ use mz_repr::{ColumnName, GlobalId};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement,
-    CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior, MutRecBlock, Op, Query, Statement,
-    TableFactor, UnresolvedItemName, UnresolvedSchemaName, Value, ViewDefinition,
+    CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreatePolicyStatement, CreateSecretStatement,
+    CreateSinkStatement, CreateSourceStatement, CreateSubscriptionStatement,
+    CreateSubsourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
+    CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, FunctionBody, Ident,
+    IfExistsBehavior, MutRecBlock, Op, Query, Statement, TableFactor, UnresolvedItemName,
+    UnresolvedSchemaName, Value, ViewDefinition,
 };
 
 use crate::names::{Aug, FullItemName, PartialItemName, PartialSchemaName, RawDatabaseSpecifier};
@@ -301,6 +303,7 @@ pub fn create_statement(
             constraints: _,
             if_not_exists,
             temporary,
+            with_options: _,
         }) => {
             *name = if *temporary {
                 allocate_temporary_name(name)?
@@ -324,6 +327,7 @@ pub fn create_statement(
             body_format: _,
             validate_using: _,
             in_cluster: _,
+            with_options: _,
         }) => {
             *name = allocate_name(name)?;
             *if_not_exists = false;
@@ -416,6 +420,44 @@ pub fn create_statement(
                 return Err(err);
             }
         }
+        Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            or_replace,
+            params: _,
+            returns: _,
+            body,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            match body {
+                FunctionBody::Return(expr) => normalizer.visit_expr_mut(expr),
+                FunctionBody::Atomic(query) => normalizer.visit_query_mut(query),
+            }
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+            *or_replace = false;
+        }
+        Statement::CreatePolicy(CreatePolicyStatement {
+            name: _,
+            on_name: _,
+            using,
+        }) => {
+            let mut normalizer = QueryNormalizer::new();
+            normalizer.visit_expr_mut(using);
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+        }
+        Statement::CreateSubscription(CreateSubscriptionStatement {
+            name,
+            on_name: _,
+            if_not_exists,
+            with_options: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
         Statement::CreateSecret(CreateSecretStatement {
             name,
             if_not_exists,
@@ -39,8 +39,9 @@ use mz_ore::now::{self, NOW_ZERO};
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
+use mz_repr::refresh_schedule::RefreshSchedule;
 use mz_repr::role_id::RoleId;
-use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
+use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType, Timestamp};
 use mz_sql_parser::ast::{
     AlterSourceAddSubsourceOption, ConnectionOptionName, CreateSourceSubsource, QualifiedReplica,
     TransactionIsolationLevel, TransactionMode, WithOptionValue,
@@ -51,8 +52,8 @@ use mz_storage_types::sources::{SourceDesc, Timeline};
 use serde::{Deserialize, Serialize};
 
 use crate::ast::{
-    ExplainStage, Expr, FetchDirection, IndexOptionName, NoticeSeverity, Raw, Statement,
-    StatementKind, TransactionAccessMode,
+    ExplainStage, Expr, FetchDirection, FunctionBody, IndexOptionName, NoticeSeverity, Raw,
+    Statement, StatementKind, TransactionAccessMode,
 };
 use crate::catalog::{
     CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
@@ -87,7 +88,7 @@ pub use expr::{
 };
 pub use lowering::Config as HirToMirConfig;
 pub use notice::PlanNotice;
-pub use query::{ExprContext, QueryContext, QueryLifetime};
+pub use query::{plan_policy_expr, ExprContext, QueryContext, QueryLifetime};
 pub use scope::Scope;
 pub use side_effecting_func::SideEffectingFunc;
 pub use statement::ddl::{PlannedAlterRoleOption, PlannedRoleVariable};
@@ -112,6 +113,9 @@ pub enum Plan {
     CreateMaterializedView(CreateMaterializedViewPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreateFunction(CreateFunctionPlan),
+    CreatePolicy(CreatePolicyPlan),
+    CreateSubscription(CreateSubscriptionPlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
@@ -131,6 +135,8 @@ pub enum Plan {
     AbortTransaction(AbortTransactionPlan),
     Select(SelectPlan),
     Subscribe(SubscribePlan),
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
     CopyFrom(CopyFromPlan),
     ExplainPlan(ExplainPlanPlan),
     ExplainTimestamp(ExplainTimestampPlan),
@@ -143,6 +149,7 @@ pub enum Plan {
     AlterIndexResetOptions(AlterIndexResetOptionsPlan),
     AlterSetCluster(AlterSetClusterPlan),
     AlterSink(AlterSinkPlan),
+    AlterTable(AlterTablePlan),
     AlterConnection(AlterConnectionPlan),
     AlterSource(AlterSourcePlan),
     PurifiedAlterSource {
@@ -158,6 +165,7 @@ pub enum Plan {
     AlterSchemaRename(AlterSchemaRenamePlan),
     AlterSchemaSwap(AlterSchemaSwapPlan),
     AlterSecret(AlterSecretPlan),
+    AlterSubscription(AlterSubscriptionPlan),
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
     AlterSystemResetAll(AlterSystemResetAllPlan),
@@ -215,11 +223,15 @@ impl Plan {
             }
             StatementKind::AlterRole => vec![PlanKind::AlterRole],
             StatementKind::AlterSecret => vec![PlanKind::AlterNoop, PlanKind::AlterSecret],
+            StatementKind::AlterSubscription => {
+                vec![PlanKind::AlterNoop, PlanKind::AlterSubscription]
+            }
             StatementKind::AlterSetCluster => {
                 vec![PlanKind::AlterNoop, PlanKind::AlterSetCluster]
             }
             StatementKind::AlterSink => vec![PlanKind::AlterNoop, PlanKind::AlterSink],
             StatementKind::AlterSource => vec![PlanKind::AlterNoop, PlanKind::AlterSource],
+            StatementKind::AlterTable => vec![PlanKind::AlterNoop, PlanKind::AlterTable],
             StatementKind::AlterSystemReset => {
                 vec![PlanKind::AlterNoop, PlanKind::AlterSystemReset]
             }
@@ -249,6 +261,9 @@ impl Plan {
             }
             StatementKind::CreateTable => vec![PlanKind::CreateTable],
             StatementKind::CreateType => vec![PlanKind::CreateType],
+            StatementKind::CreateFunction => vec![PlanKind::CreateFunction],
+            StatementKind::CreatePolicy => vec![PlanKind::CreatePolicy],
+            StatementKind::CreateSubscription => vec![PlanKind::CreateSubscription],
             StatementKind::CreateView => vec![PlanKind::CreateView],
             StatementKind::Deallocate => vec![PlanKind::Deallocate],
             StatementKind::Declare => vec![PlanKind::Declare],
@@ -264,6 +279,7 @@ impl Plan {
             StatementKind::GrantPrivileges => vec![PlanKind::GrantPrivileges],
             StatementKind::GrantRole => vec![PlanKind::GrantRole],
             StatementKind::Insert => vec![PlanKind::Insert],
+            StatementKind::Listen => vec![PlanKind::Listen],
             StatementKind::Prepare => vec![PlanKind::Prepare],
             StatementKind::Raise => vec![PlanKind::Raise],
             StatementKind::ReassignOwned => vec![PlanKind::ReassignOwned],
@@ -284,6 +300,7 @@ impl Plan {
             ],
             StatementKind::StartTransaction => vec![PlanKind::StartTransaction],
             StatementKind::Subscribe => vec![PlanKind::Subscribe],
+            StatementKind::Unlisten => vec![PlanKind::Unlisten],
             StatementKind::Update => vec![PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => vec![PlanKind::ValidateConnection],
         }
@@ -307,6 +324,9 @@ impl Plan {
             Plan::CreateMaterializedView(_) => "create materialized view",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreateFunction(_) => "create function",
+            Plan::CreatePolicy(_) => "create policy",
+            Plan::CreateSubscription(_) => "create subscription",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -326,6 +346,8 @@ impl Plan {
                 ObjectType::Database => "drop database",
                 ObjectType::Schema => "drop schema",
                 ObjectType::Func => "drop function",
+                ObjectType::Policy => "drop policy",
+                ObjectType::Subscription => "drop subscription",
             },
             Plan::DropOwned(_) => "drop owned",
             Plan::EmptyQuery => "do nothing",
@@ -342,6 +364,8 @@ impl Plan {
             Plan::AbortTransaction(_) => "abort",
             Plan::Select(_) => "select",
             Plan::Subscribe(_) => "subscribe",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
             Plan::CopyFrom(_) => "copy from",
             Plan::ExplainPlan(_) => "explain plan",
             Plan::ExplainTimestamp(_) => "explain timestamp",
@@ -363,6 +387,8 @@ impl Plan {
                 ObjectType::Database => "alter database",
                 ObjectType::Schema => "alter schema",
                 ObjectType::Func => "alter function",
+                ObjectType::Policy => "alter policy",
+                ObjectType::Subscription => "alter subscription",
             },
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
@@ -372,6 +398,7 @@ impl Plan {
             Plan::AlterIndexSetOptions(_) => "alter index",
             Plan::AlterIndexResetOptions(_) => "alter index",
             Plan::AlterSink(_) => "alter sink",
+            Plan::AlterTable(_) => "alter table",
             Plan::AlterConnection(_) => "alter connection",
             Plan::AlterSource(_) | Plan::PurifiedAlterSource { .. } => "alter source",
             Plan::AlterItemRename(_) => "rename item",
@@ -379,6 +406,7 @@ impl Plan {
             Plan::AlterSchemaRename(_) => "alter rename schema",
             Plan::AlterSchemaSwap(_) => "alter swap schema",
             Plan::AlterSecret(_) => "alter secret",
+            Plan::AlterSubscription(_) => "alter subscription",
             Plan::AlterSystemSet(_) => "alter system",
             Plan::AlterSystemReset(_) => "alter system",
             Plan::AlterSystemResetAll(_) => "alter system",
@@ -399,6 +427,8 @@ impl Plan {
                 ObjectType::Database => "alter database owner",
                 ObjectType::Schema => "alter schema owner",
                 ObjectType::Func => "alter function owner",
+                ObjectType::Policy => "alter policy owner",
+                ObjectType::Subscription => "alter subscription owner",
             },
             Plan::Declare(_) => "declare",
             Plan::Fetch(_) => "fetch",
@@ -683,6 +713,31 @@ pub struct CreateTypePlan {
     pub typ: Type,
 }
 
+#[derive(Debug)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub function: Function,
+    /// The ID of the function that this function is replacing, if any.
+    pub replace: Option<GlobalId>,
+    /// Whether the function being replaced has the same signature as this
+    /// function, in which case it is updated in place rather than dropped, and
+    /// the objects that depend on it are kept.
+    pub in_place: bool,
+}
+
+#[derive(Debug)]
+pub struct CreatePolicyPlan {
+    pub name: QualifiedItemName,
+    pub policy: Policy,
+}
+
+#[derive(Debug)]
+pub struct CreateSubscriptionPlan {
+    pub name: QualifiedItemName,
+    pub subscription: Subscription,
+    pub if_not_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct DropObjectsPlan {
     /// The IDs of only the objects directly referenced in the `DROP` statement.
@@ -702,6 +757,8 @@ pub struct DropOwnedPlan {
     pub drop_ids: Vec<ObjectId>,
     /// The privileges to revoke.
     pub privilege_revokes: Vec<(SystemObjectId, MzAclItem)>,
+    /// The column privileges to revoke.
+    pub column_privilege_revokes: Vec<(GlobalId, ColumnName, MzAclItem)>,
     /// The default privileges to revoke.
     pub default_privilege_revokes: Vec<(DefaultPrivilegeObject, DefaultPrivilegeAclItem)>,
 }
@@ -746,6 +803,9 @@ pub struct SelectPlan {
     pub when: QueryWhen,
     pub finishing: RowSetFinishing,
     pub copy_to: Option<CopyFormat>,
+    /// The columns of each catalog relation that the query references. Used to check column
+    /// privileges.
+    pub column_references: BTreeMap<GlobalId, BTreeSet<usize>>,
 }
 
 #[derive(Debug)]
@@ -774,6 +834,11 @@ pub struct SubscribePlan {
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
     pub output: SubscribeOutput,
+    /// The columns of each catalog relation that the subscription references. Used to check
+    /// column privileges.
+    pub column_references: BTreeMap<GlobalId, BTreeSet<usize>>,
+    /// The durable subscription being resumed, if any.
+    pub subscription: Option<GlobalId>,
 }
 
 #[derive(Debug)]
@@ -801,6 +866,20 @@ impl SubscribeFrom {
     }
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    /// The name of the channel on which to deliver notifications.
+    pub channel: String,
+    /// The subscription whose changes become notifications.
+    pub subscribe: SubscribePlan,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` for all channels.
+    pub channel: Option<String>,
+}
+
 #[derive(Debug)]
 pub struct ShowCreatePlan {
     pub id: GlobalId,
@@ -861,6 +940,7 @@ pub enum ExplaineeStatement {
         /// Broken flag (see [`ExplaineeStatement::broken()`]).
         broken: bool,
         non_null_assertions: Vec<usize>,
+        refresh_schedule: Option<RefreshSchedule>,
     },
     /// The object to be explained is a CREATE INDEX.
     CreateIndex {
@@ -969,8 +1049,11 @@ pub struct SendDiffsPlan {
 #[derive(Debug)]
 pub struct InsertPlan {
     pub id: GlobalId,
+    /// The rows to insert. If `conflicts` is set, the rows additionally carry
+    /// a trailing boolean column; see [`InsertConflicts`].
     pub values: HirRelationExpr,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    pub conflicts: Option<InsertConflicts>,
 }
 
 #[derive(Debug)]
@@ -981,6 +1064,44 @@ pub struct ReadThenWritePlan {
     pub assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
     pub kind: MutationKind,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// Only set for inserts with an `ON CONFLICT` clause.
+    pub conflicts: Option<InsertConflicts>,
+}
+
+/// Describes how an insert into a table with keys resolves conflicts with the
+/// table's existing rows.
+///
+/// The selection of such an insert produces the rows to insert alongside the
+/// existing rows of the table that share a key with any of them. Each row
+/// carries a trailing boolean column that is `true` for existing rows.
+///
+/// Writes without an `ON CONFLICT` clause are instead checked against the
+/// table's keys when they are committed.
+#[derive(Debug, Clone)]
+pub struct InsertConflicts {
+    /// The keys of the table, each a list of column indices.
+    pub keys: Vec<Vec<usize>>,
+    pub on_conflict: OnConflict,
+}
+
+/// A planned `ON CONFLICT` clause.
+#[derive(Debug, Clone)]
+pub struct OnConflict {
+    /// The index into [`InsertConflicts::keys`] of the key that conflicts are
+    /// resolved on, or `None` if conflicts on any key are resolved.
+    pub key: Option<usize>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone)]
+pub enum OnConflictAction {
+    DoNothing,
+    /// The expressions refer to the columns of the existing row followed by
+    /// the columns of the proposed row.
+    DoUpdate {
+        assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
+        selection: Option<mz_expr::MirScalarExpr>,
+    },
 }
 
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
@@ -1021,6 +1142,23 @@ pub struct AlterSinkPlan {
     pub size: AlterOptionParameter,
 }
 
+#[derive(Debug)]
+pub struct AlterTablePlan {
+    pub id: GlobalId,
+    /// The table's definition after the alteration.
+    pub table: Table,
+    pub action: AlterTableAction,
+}
+
+#[derive(Debug)]
+pub enum AlterTableAction {
+    /// Appends a column to the table. Existing rows take on `default`, which
+    /// must not reference any columns.
+    AddColumn { default: MirScalarExpr },
+    /// Removes the table's column at `index`.
+    DropColumn { index: usize },
+}
+
 #[derive(Debug)]
 pub enum AlterConnectionAction {
     RotateKeys,
@@ -1125,6 +1263,18 @@ pub struct AlterSecretPlan {
     pub secret_as: MirScalarExpr,
 }
 
+#[derive(Debug)]
+pub struct AlterSubscriptionPlan {
+    pub id: GlobalId,
+    /// The definition of the subscription, with the new options applied.
+    pub create_sql: String,
+    /// The new acknowledged frontier, if it is being changed.
+    pub acknowledged: Option<Timestamp>,
+    /// The new bound on how far the acknowledged frontier may lag, if it is
+    /// being changed.
+    pub retain_history: Option<Duration>,
+}
+
 #[derive(Debug)]
 pub struct AlterSystemSetPlan {
     pub name: String,
@@ -1221,6 +1371,8 @@ pub struct RevokeRolePlan {
 pub struct UpdatePrivilege {
     /// The privileges being granted/revoked on an object.
     pub acl_mode: AclMode,
+    /// The privileges being granted/revoked on individual columns of the object.
+    pub column_acl_modes: BTreeMap<ColumnName, AclMode>,
     /// The ID of the object receiving privileges.
     pub target_id: SystemObjectId,
     /// The role that is granting the privileges.
@@ -1280,6 +1432,7 @@ pub struct Table {
     pub desc: RelationDesc,
     pub defaults: Vec<Expr<Aug>>,
     pub temporary: bool,
+    pub compaction_window: Option<Duration>,
 }
 
 #[derive(Clone, Debug)]
@@ -1287,6 +1440,7 @@ pub struct Source {
     pub create_sql: String,
     pub data_source: DataSourceDesc,
     pub desc: RelationDesc,
+    pub compaction_window: Option<Duration>,
 }
 
 #[derive(Debug, Clone)]
@@ -1431,6 +1585,10 @@ pub struct MaterializedView {
     pub column_names: Vec<ColumnName>,
     pub cluster_id: ClusterId,
     pub non_null_assertions: Vec<usize>,
+    pub compaction_window: Option<Duration>,
+    /// If set, the view is refreshed only at the times of this schedule,
+    /// rather than on every commit.
+    pub refresh_schedule: Option<RefreshSchedule>,
 }
 
 #[derive(Clone, Debug)]
@@ -1447,6 +1605,53 @@ pub struct Type {
     pub inner: CatalogType<IdReference>,
 }
 
+/// A row-level security policy created via `CREATE POLICY`.
+#[derive(Clone, Debug)]
+pub struct Policy {
+    pub create_sql: String,
+    /// The relation whose rows the policy restricts.
+    pub on: GlobalId,
+    /// The condition that rows must satisfy to be visible to roles that do not
+    /// bypass row-level security.
+    pub using: Expr<Aug>,
+}
+
+/// A durable subscription created via `CREATE SUBSCRIPTION`.
+#[derive(Clone, Debug)]
+pub struct Subscription {
+    pub create_sql: String,
+    /// The relation whose changes the subscription tracks.
+    pub on: GlobalId,
+    /// The frontier before which the consumer has processed all updates, if
+    /// any updates have been acknowledged.
+    pub acknowledged: Option<Timestamp>,
+    /// How far behind the current time the acknowledged frontier may lag
+    /// before updates are no longer retained for it.
+    pub retain_history: Option<Duration>,
+}
+
+/// A SQL-language function created via `CREATE FUNCTION`.
+#[derive(Clone, Debug)]
+pub struct Function {
+    pub create_sql: String,
+    /// The names, if any, and types of the function's parameters.
+    pub params: Vec<(Option<ColumnName>, ScalarType)>,
+    pub returns: FunctionReturns,
+    /// The function's body, with all names fully qualified.
+    pub body: FunctionBody<Raw>,
+}
+
+/// The return type of a [`Function`].
+#[derive(Clone, Debug)]
+pub enum FunctionReturns {
+    /// The function returns a single value.
+    Scalar(ScalarType),
+    /// The function returns a set of values.
+    SetOf(ScalarType),
+    /// The function returns a set of rows with the named columns.
+    Table(Vec<(ColumnName, ScalarType)>),
+}
+
 /// Specifies when a `Peek` or `Subscribe` should occur.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum QueryWhen {
@@ -1459,10 +1664,13 @@ pub enum QueryWhen {
     /// The peek should occur at the timestamp described by the specified
     /// expression.
     ///
-    /// The expression may have any type.
+    /// The expression may have any type. The peek fails if the timestamp is
+    /// not beyond the since of its inputs, which trails their upper by their
+    /// compaction window (see `RETAIN HISTORY`).
     AtTimestamp(MirScalarExpr),
     /// Same as Immediately, but will also advance to at least the specified
-    /// expression.
+    /// expression. Unlike `AtTimestamp`, a timestamp that has already been
+    /// compacted away is advanced to the since rather than rejected.
     AtLeastTimestamp(MirScalarExpr),
 }
 
@@ -37,6 +37,14 @@ pub enum PlanNotice {
         key: Vec<ColumnName>,
         name: String,
     },
+    ColumnAlreadyExists {
+        column_name: String,
+        object_name: String,
+    },
+    ColumnDoesNotExist {
+        column_name: String,
+        object_name: String,
+    },
 }
 
 impl PlanNotice {
@@ -83,6 +91,28 @@ impl fmt::Display for PlanNotice {
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
+            PlanNotice::ColumnAlreadyExists {
+                column_name,
+                object_name,
+            } => {
+                write!(
+                    f,
+                    "column {} of relation {} already exists, skipping",
+                    column_name.quoted(),
+                    object_name.quoted()
+                )
+            }
+            PlanNotice::ColumnDoesNotExist {
+                column_name,
+                object_name,
+            } => {
+                write!(
+                    f,
+                    "column {} of relation {} does not exist, skipping",
+                    column_name.quoted(),
+                    object_name.quoted()
+                )
+            }
         }
     }
 }
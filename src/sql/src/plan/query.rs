@@ -61,14 +61,15 @@ use mz_sql_parser::ast::{
     CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
     Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
     JoinConstraint, JoinOperator, Limit, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName,
-    OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator,
-    ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableWithJoins, UnresolvedItemName,
-    UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    OnConflict, OnConflictAction, OrderByExpr, Query, Select, SelectItem, SelectOption,
+    SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias,
+    TableFactor, TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
+    WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 use mz_sql_parser::ident;
 use uuid::Uuid;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, SessionCatalog};
 use crate::func::{self, Func, FuncSpec};
 use crate::names::{Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName};
 use crate::normalize;
@@ -76,8 +77,8 @@ use crate::plan::error::PlanError;
 use crate::plan::expr::{
     AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, AggregateWindowExpr,
     BinaryFunc, CoercibleScalarExpr, ColumnOrder, ColumnRef, Hir, HirRelationExpr, HirScalarExpr,
-    JoinKind, ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, ValueWindowExpr, ValueWindowFunc,
-    VariadicFunc, WindowExpr, WindowExprType,
+    JoinKind, ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, UnmaterializableFunc, ValueWindowExpr,
+    ValueWindowFunc, VariadicFunc, WindowExpr, WindowExprType,
 };
 use crate::plan::plan_utils::{self, GroupSizeHints, JoinSide};
 use crate::plan::scope::{Scope, ScopeItem};
@@ -86,8 +87,8 @@ use crate::plan::typeconv::{self, CastContext};
 use crate::plan::with_options::TryFromValue;
 use crate::plan::PlanError::InvalidWmrRecursionLimit;
 use crate::plan::{
-    transform_ast, Params, PlanContext, QueryWhen, ShowCreatePlan, WebhookValidation,
-    WebhookValidationSecret,
+    self, transform_ast, InsertConflicts, Params, PlanContext, QueryWhen, ShowCreatePlan,
+    WebhookValidation, WebhookValidationSecret,
 };
 use crate::session::vars::FeatureFlag;
 
@@ -185,8 +186,17 @@ pub fn plan_insert_query(
     table_name: ResolvedItemName,
     columns: Vec<Ident>,
     source: InsertSource<Aug>,
+    on_conflict: Option<OnConflict<Aug>>,
     returning: Vec<SelectItem<Aug>>,
-) -> Result<(GlobalId, HirRelationExpr, PlannedQuery<Vec<HirScalarExpr>>), PlanError> {
+) -> Result<
+    (
+        GlobalId,
+        HirRelationExpr,
+        PlannedQuery<Vec<HirScalarExpr>>,
+        Option<InsertConflicts>,
+    ),
+    PlanError,
+> {
     let mut qcx = QueryContext::root(scx, QueryLifetime::OneShot);
     let table = scx.get_item_by_resolved_name(&table_name)?;
 
@@ -327,6 +337,30 @@ pub fn plan_insert_query(
             map_exprs.push(hir);
         }
     }
+    let expr = expr.map(map_exprs).project(project_key);
+
+    let keys = desc.typ().keys.clone();
+    let (get, table_scope) = qcx.resolve_table_name_without_policies(table_name.clone())?;
+    let on_conflict = on_conflict
+        .map(|on_conflict| plan_on_conflict(&qcx, &desc, &keys, table_scope, on_conflict))
+        .transpose()?;
+
+    // Inserts that resolve conflicts must know which of the rows they insert
+    // conflict with the existing rows of the table. Alongside the rows to
+    // insert, read the existing rows that share a key with any of them, and
+    // tag each row with whether it already exists. All other inserts into
+    // tables with keys are checked against the table when they commit.
+    let (expr, conflicts) = match on_conflict {
+        // Without keys, no row can conflict with another.
+        Some(on_conflict) if !keys.is_empty() => {
+            let existing = plan_existing_key_rows(get, desc.typ(), &keys, expr.clone());
+            let expr = expr
+                .map(vec![HirScalarExpr::literal_false()])
+                .union(existing.map(vec![HirScalarExpr::literal_true()]));
+            (expr, Some(InsertConflicts { keys, on_conflict }))
+        }
+        _ => (expr, None),
+    };
 
     let returning = {
         let (scope, typ) = if let ResolvedItemName::Item { full_name, .. } = table_name {
@@ -379,11 +413,170 @@ pub fn plan_insert_query(
         }
     };
 
-    Ok((
-        table.id(),
-        expr.map(map_exprs).project(project_key),
-        returning,
-    ))
+    Ok((table.id(), expr, returning, conflicts))
+}
+
+/// Plans the rows of `table` that share any of the given `keys` with any of
+/// `rows`. `table` and `rows` must both have type `typ`.
+fn plan_existing_key_rows(
+    table: HirRelationExpr,
+    typ: &RelationType,
+    keys: &[Vec<usize>],
+    rows: HirRelationExpr,
+) -> HirRelationExpr {
+    let arity = typ.arity();
+    let mut existing: Option<HirRelationExpr> = None;
+    for key in keys {
+        let on = HirScalarExpr::variadic_and(
+            key.iter()
+                .enumerate()
+                .map(|(i, col)| {
+                    let existing = HirScalarExpr::column(*col);
+                    let proposed = HirScalarExpr::column(arity + i);
+                    let eq = existing
+                        .clone()
+                        .call_binary(proposed.clone(), BinaryFunc::Eq);
+                    if typ.column_types[*col].nullable {
+                        // Keys only contain nullable columns if they were
+                        // declared `NULLS NOT DISTINCT`, so NULLs must match.
+                        eq.or(existing.call_is_null().and(proposed.call_is_null()))
+                    } else {
+                        eq
+                    }
+                })
+                .collect(),
+        );
+        let matching = table
+            .clone()
+            .join(
+                rows.clone().project(key.clone()).distinct(),
+                on,
+                JoinKind::Inner,
+            )
+            .project((0..arity).collect());
+        existing = Some(match existing {
+            Some(existing) => existing.union(matching),
+            None => matching,
+        });
+    }
+    existing.expect("keys known to be non-empty").distinct()
+}
+
+/// Plans the `ON CONFLICT` clause of an `INSERT` into a table with the given
+/// `keys`. `table_scope` is the scope of the target table.
+fn plan_on_conflict(
+    qcx: &QueryContext,
+    desc: &RelationDesc,
+    keys: &[Vec<usize>],
+    table_scope: Scope,
+    OnConflict { target, action }: OnConflict<Aug>,
+) -> Result<plan::OnConflict, PlanError> {
+    let key = if target.is_empty() {
+        None
+    } else {
+        let mut columns = Vec::with_capacity(target.len());
+        for name in target {
+            let name = normalize::column_name(name);
+            match desc.get_by_name(&name) {
+                Some((idx, _)) => columns.push(idx),
+                None => sql_bail!("column {} does not exist", name.as_str().quoted()),
+            }
+        }
+        columns.sort();
+        columns.dedup();
+        let key = keys.iter().position(|key| {
+            let mut key = key.clone();
+            key.sort();
+            key == columns
+        });
+        match key {
+            Some(key) => Some(key),
+            None => sql_bail!(
+                "there is no unique or exclusion constraint matching the ON CONFLICT specification"
+            ),
+        }
+    };
+
+    let action = match action {
+        OnConflictAction::DoNothing => plan::OnConflictAction::DoNothing,
+        OnConflictAction::DoUpdate {
+            assignments,
+            selection,
+        } => {
+            // The existing row is visible under the table's name, and the row
+            // proposed for insertion under the special name `excluded`.
+            let excluded = PartialItemName {
+                database: None,
+                schema: None,
+                item: "excluded".into(),
+            };
+            let scope = table_scope.product(Scope::from_source(
+                Some(excluded),
+                desc.iter_names().cloned(),
+            ))?;
+            let relation_type = RelationType::new(
+                desc.iter_types()
+                    .chain(desc.iter_types())
+                    .cloned()
+                    .collect(),
+            );
+
+            let mut sets = BTreeMap::new();
+            for Assignment { id, mut value } in assignments {
+                transform_ast::transform(qcx.scx, &mut value)?;
+                let name = normalize::column_name(id);
+                match desc.get_by_name(&name) {
+                    Some((idx, typ)) => {
+                        let ecx = &ExprContext {
+                            qcx,
+                            name: "ON CONFLICT DO UPDATE SET clause",
+                            scope: &scope,
+                            relation_type: &relation_type,
+                            allow_aggregates: false,
+                            allow_subqueries: false,
+                            allow_parameters: false,
+                            allow_windows: false,
+                        };
+                        let expr = plan_expr(ecx, &value)?.cast_to(
+                            ecx,
+                            CastContext::Assignment,
+                            &typ.scalar_type,
+                        )?;
+                        if sets.insert(idx, expr.lower_uncorrelated()?).is_some() {
+                            sql_bail!("column {} set twice", name)
+                        }
+                    }
+                    None => sql_bail!("unknown column {}", name),
+                }
+            }
+
+            let selection = match selection {
+                Some(mut selection) => {
+                    transform_ast::transform(qcx.scx, &mut selection)?;
+                    let ecx = &ExprContext {
+                        qcx,
+                        name: "ON CONFLICT DO UPDATE WHERE clause",
+                        scope: &scope,
+                        relation_type: &relation_type,
+                        allow_aggregates: false,
+                        allow_subqueries: false,
+                        allow_parameters: false,
+                        allow_windows: false,
+                    };
+                    let expr = plan_expr(ecx, &selection)?.type_as(ecx, &ScalarType::Bool)?;
+                    Some(expr.lower_uncorrelated()?)
+                }
+                None => None,
+            };
+
+            plan::OnConflictAction::DoUpdate {
+                assignments: sets,
+                selection,
+            }
+        }
+    };
+
+    Ok(plan::OnConflict { key, action })
 }
 
 pub fn plan_copy_from(
@@ -856,6 +1049,41 @@ pub fn plan_up_to(
         .lower_uncorrelated()
 }
 
+/// Plans an expression in the `REFRESH AT` or `REFRESH EVERY ... ALIGNED TO`
+/// position of a `CREATE MATERIALIZED VIEW` statement, casting it to an
+/// `mz_timestamp`.
+///
+/// String literals are interpreted as `timestamp with time zone`s.
+pub fn plan_refresh_time(
+    scx: &StatementContext,
+    mut expr: Expr<Aug>,
+    name: &str,
+) -> Result<MirScalarExpr, PlanError> {
+    let scope = Scope::empty();
+    let desc = RelationDesc::empty();
+    // The refresh time is evaluated only once, at planning time.
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    transform_ast::transform(scx, &mut expr)?;
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name,
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let expr = match plan_expr(ecx, &expr)? {
+        expr @ CoercibleScalarExpr::LiteralString(_) => {
+            expr.type_as(ecx, &ScalarType::TimestampTz { precision: None })?
+        }
+        expr => expr.type_as_any(ecx)?,
+    };
+    typeconv::plan_cast(ecx, CastContext::Explicit, expr, &ScalarType::MzTimestamp)?
+        .lower_uncorrelated()
+}
+
 /// Plans an expression in the AS OF position of a `SELECT` or `SUBSCRIBE` statement.
 pub fn plan_as_of(
     scx: &StatementContext,
@@ -1178,6 +1406,71 @@ pub fn plan_index_exprs<'a>(
     Ok(out)
 }
 
+/// Returns the `USING` expressions of the row-level security policies on `item`.
+pub(crate) fn item_policies<'a>(
+    scx: &'a StatementContext,
+    item: &dyn CatalogItem,
+) -> Vec<&'a Expr<Aug>> {
+    item.used_by()
+        .iter()
+        .filter_map(|id| scx.catalog.get_item(id).policy_details())
+        .filter(|(_, on)| *on == item.id())
+        .map(|(using, _)| using)
+        .collect()
+}
+
+/// Errors if `item` has row-level security policies that the active role does
+/// not bypass.
+///
+/// Maintained dataflows, e.g. those of materialized views and sinks, share
+/// their results among all readers, so they may only read all of a relation's
+/// rows.
+pub(crate) fn ensure_bypasses_policies(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+) -> Result<(), PlanError> {
+    let role = scx.catalog.get_role(scx.catalog.active_role_id());
+    if item_policies(scx, item).is_empty()
+        || scx.catalog.is_superuser()
+        || role.attributes().is_bypass_rls()
+    {
+        return Ok(());
+    }
+    sql_bail!(
+        "cannot maintain a dataflow over {} because role {} does not bypass its row-level \
+        security policies",
+        scx.catalog
+            .resolve_full_name(item.name())
+            .to_string()
+            .quoted(),
+        role.name().quoted(),
+    )
+}
+
+/// Plans the `USING` expression of a row-level security policy on a relation
+/// described by `desc`.
+pub fn plan_policy_expr(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    expr: &Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    let scope = Scope::from_source(None, desc.iter_names());
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "USING clause",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let mut expr = expr.clone();
+    transform_ast::transform(scx, &mut expr)?;
+    plan_expr(ecx, &expr)?.type_as(ecx, &ScalarType::Bool)
+}
+
 fn plan_expr_or_col_index(ecx: &ExprContext, e: &Expr<Aug>) -> Result<HirScalarExpr, PlanError> {
     match check_col_index(ecx.name, e, ecx.relation_type.column_types.len())? {
         Some(column) => Ok(HirScalarExpr::column(column)),
@@ -3191,6 +3484,10 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.is_from_table(&table_name))
                 .map(|(i, item)| {
+                    ecx.record_column_reference(ColumnRef {
+                        level: 0,
+                        column: i,
+                    });
                     let name = item.column_name.clone();
                     (ExpandedSelectItem::InputOrdinal(i), name)
                 })
@@ -3261,6 +3558,10 @@ fn expand_select_item<'a>(
                 .enumerate()
                 .filter(|(_i, item)| item.allow_unqualified_references)
                 .map(|(i, item)| {
+                    ecx.record_column_reference(ColumnRef {
+                        level: 0,
+                        column: i,
+                    });
                     let name = item.column_name.clone();
                     (ExpandedSelectItem::InputOrdinal(i), name)
                 })
@@ -3445,6 +3746,11 @@ fn plan_using_constraint(
     for column_name in column_names {
         let lhs = left_scope.resolve_using_column(column_name, JoinSide::Left)?;
         let mut rhs = right_scope.resolve_using_column(column_name, JoinSide::Right)?;
+        for (scope, column) in [(&left_scope, lhs), (&right_scope, rhs)] {
+            if let Some((id, column)) = scope.item(&[], column).source {
+                ecx.qcx.scx.record_column_reference(id, column);
+            }
+        }
 
         // Adjust the RHS reference to its post-join location.
         rhs.column += left_scope.len();
@@ -4641,13 +4947,17 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         let i = ecx
             .scope
             .resolve_table_column(&ecx.qcx.outer_scopes, &table_name, &col_name)?;
+        ecx.record_column_reference(i);
         return Ok(HirScalarExpr::Column(i));
     }
 
     // If the name is unqualified, first check if it refers to a column. Track any similar names
     // that might exist for a better error message.
     let similar_names = match ecx.scope.resolve_column(&ecx.qcx.outer_scopes, &col_name) {
-        Ok(i) => return Ok(HirScalarExpr::Column(i)),
+        Ok(i) => {
+            ecx.record_column_reference(i);
+            return Ok(HirScalarExpr::Column(i));
+        }
         Err(PlanError::UnknownColumn { similar, .. }) => similar,
         Err(e) => return Err(e),
     };
@@ -4663,6 +4973,10 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         },
     )?;
     match items.as_slice() {
+        // The name refers to a parameter of the SQL function being planned.
+        [] if ecx.qcx.scx.function_param_names.contains_key(&col_name) => Ok(
+            HirScalarExpr::Parameter(ecx.qcx.scx.function_param_names[&col_name]),
+        ),
         // The name doesn't refer to a table either. Return an error.
         [] => Err(PlanError::UnknownColumn {
             table: None,
@@ -4677,6 +4991,9 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         // The name refers to a normal table. Return a record containing all the
         // columns of the table.
         _ => {
+            for (column, _item) in &items {
+                ecx.record_column_reference(*column);
+            }
             let mut has_exists_column = None;
             let (exprs, field_names): (Vec<_>, Vec<_>) = items
                 .into_iter()
@@ -4937,12 +5254,12 @@ pub const IGNORE_NULLS_ERROR_MSG: &str =
 
 /// Resolves the name to a set of function implementations.
 ///
-/// If the name does not specify a known built-in function, returns an error.
-pub fn resolve_func(
-    ecx: &ExprContext,
+/// If the name does not specify a known function, returns an error.
+pub fn resolve_func<'a>(
+    ecx: &ExprContext<'a>,
     name: &ResolvedItemName,
     args: &mz_sql_parser::ast::FunctionArgs<Aug>,
-) -> Result<&'static Func, PlanError> {
+) -> Result<&'a Func, PlanError> {
     if let Ok(i) = ecx.qcx.scx.get_item_by_resolved_name(name) {
         if let Ok(f) = i.func() {
             return Ok(f);
@@ -5579,6 +5896,14 @@ pub fn scalar_type_from_catalog(
                 CatalogType::Uuid => Ok(ScalarType::Uuid),
                 CatalogType::Int2Vector => Ok(ScalarType::Int2Vector),
                 CatalogType::MzAclItem => Ok(ScalarType::MzAclItem),
+                CatalogType::Inet => Ok(ScalarType::Inet),
+                CatalogType::Cidr => Ok(ScalarType::Cidr),
+                CatalogType::MacAddr => Ok(ScalarType::MacAddr),
+                CatalogType::Enum { labels } => Ok(ScalarType::Enum {
+                    labels: labels.clone(),
+                    custom_id: id,
+                    custom_oid: entry.oid(),
+                }),
                 CatalogType::Numeric => unreachable!("handled above"),
                 CatalogType::Char => unreachable!("handled above"),
                 CatalogType::VarChar => unreachable!("handled above"),
@@ -5895,7 +6220,7 @@ impl<'a> QueryContext<'a> {
         }
     }
 
-    fn relation_type(&self, expr: &HirRelationExpr) -> RelationType {
+    pub(crate) fn relation_type(&self, expr: &HirRelationExpr) -> RelationType {
         expr.typ(&self.outer_relation_types, &self.scx.param_types.borrow())
     }
 
@@ -5926,10 +6251,29 @@ impl<'a> QueryContext<'a> {
     }
 
     /// Resolves `object` to a table expr, i.e. creating a `Get` or inlining a
-    /// CTE.
+    /// CTE. The rows of an item are filtered by its row-level security
+    /// policies, if any.
     pub fn resolve_table_name(
         &self,
         object: ResolvedItemName,
+    ) -> Result<(HirRelationExpr, Scope), PlanError> {
+        self.resolve_table_name_inner(object, true)
+    }
+
+    /// Like [`QueryContext::resolve_table_name`], but without applying
+    /// row-level security policies, e.g. to check an insert against all of a
+    /// table's existing rows.
+    pub fn resolve_table_name_without_policies(
+        &self,
+        object: ResolvedItemName,
+    ) -> Result<(HirRelationExpr, Scope), PlanError> {
+        self.resolve_table_name_inner(object, false)
+    }
+
+    fn resolve_table_name_inner(
+        &self,
+        object: ResolvedItemName,
+        apply_policies: bool,
     ) -> Result<(HirRelationExpr, Scope), PlanError> {
         match object {
             ResolvedItemName::Item { id, full_name, .. } => {
@@ -5938,12 +6282,18 @@ impl<'a> QueryContext<'a> {
                 let desc = item
                     .desc(&self.scx.catalog.resolve_full_name(item.name()))?
                     .clone();
-                let expr = HirRelationExpr::Get {
+                let mut expr = HirRelationExpr::Get {
                     id: Id::Global(item.id()),
                     typ: desc.typ().clone(),
                 };
+                if apply_policies {
+                    if let Some(filter) = self.plan_policy_filter(item, &desc)? {
+                        expr = expr.filter(vec![filter]);
+                    }
+                }
 
-                let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let scope = Scope::from_item(Some(name), item.id(), desc.iter_names().cloned());
+                self.scx.record_relation_reference(item.id());
 
                 Ok((expr, scope))
             }
@@ -5963,6 +6313,39 @@ impl<'a> QueryContext<'a> {
         }
     }
 
+    /// Plans the filter that the row-level security policies on `item` impose
+    /// on its rows, or `None` if the filter does not apply.
+    ///
+    /// Roles that bypass row-level security see all rows. As this depends on
+    /// the role running the query, rather than the one planning it, the check
+    /// is deferred to `mz_bypasses_rls()` wherever possible.
+    fn plan_policy_filter(
+        &self,
+        item: &dyn CatalogItem,
+        desc: &RelationDesc,
+    ) -> Result<Option<HirScalarExpr>, PlanError> {
+        let policies = item_policies(self.scx, item);
+        if policies.is_empty() {
+            return Ok(None);
+        }
+
+        match self.lifetime {
+            QueryLifetime::OneShot | QueryLifetime::Subscribe | QueryLifetime::View => {}
+            QueryLifetime::Index | QueryLifetime::MaterializedView | QueryLifetime::Source => {
+                ensure_bypasses_policies(self.scx, item)?;
+                return Ok(None);
+            }
+        }
+
+        let mut filter = vec![HirScalarExpr::CallUnmaterializable(
+            UnmaterializableFunc::MzBypassesRls,
+        )];
+        for using in policies {
+            filter.push(plan_policy_expr(self.scx, desc, using)?);
+        }
+        Ok(Some(HirScalarExpr::variadic_or(filter)))
+    }
+
     pub fn humanize_scalar_type(&self, typ: &ScalarType) -> String {
         self.scx.humanize_scalar_type(typ)
     }
@@ -6007,6 +6390,15 @@ impl<'a> ExprContext<'a> {
         ecx
     }
 
+    /// Records a reference to the column `column` resolved in this context,
+    /// if it reads a catalog relation directly.
+    fn record_column_reference(&self, column: ColumnRef) {
+        let item = self.scope.item(&self.qcx.outer_scopes, column);
+        if let Some((id, column)) = item.source {
+            self.qcx.scx.record_column_reference(id, column);
+        }
+    }
+
     pub fn column_type<E>(&self, expr: &E) -> E::Type
     where
         E: AbstractExpr,
@@ -45,7 +45,7 @@ use std::collections::BTreeSet;
 use std::iter;
 
 use mz_ore::iter::IteratorExt;
-use mz_repr::ColumnName;
+use mz_repr::{ColumnName, GlobalId};
 
 use crate::ast::Expr;
 use crate::names::{Aug, PartialItemName};
@@ -92,6 +92,10 @@ pub struct ScopeItem {
     /// `*` expansion should yield a single `NULL` instead of a record with various
     /// datums.
     pub is_exists_column_for_a_table_function_that_was_in_the_target_list: bool,
+    /// The catalog relation and column that this item reads directly, if any.
+    /// Used to track which columns a statement references, for column
+    /// privilege checks.
+    pub source: Option<(GlobalId, usize)>,
     // Force use of the constructor methods.
     _private: (),
 }
@@ -132,6 +136,7 @@ impl ScopeItem {
             allow_unqualified_references: true,
             lateral_error_if_referenced: false,
             is_exists_column_for_a_table_function_that_was_in_the_target_list: false,
+            source: None,
             _private: (),
         }
     }
@@ -193,6 +198,33 @@ impl Scope {
         scope
     }
 
+    /// Like [`Scope::from_source`], but for the columns of the catalog
+    /// relation `id`.
+    pub fn from_item<I, N>(
+        table_name: Option<PartialItemName>,
+        id: GlobalId,
+        column_names: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<ColumnName>,
+    {
+        let mut scope = Scope::from_source(table_name, column_names);
+        for (column, item) in scope.items.iter_mut().enumerate() {
+            item.source = Some((id, column));
+        }
+        scope
+    }
+
+    /// Returns the item that `column` refers to, where `column` was resolved
+    /// against this scope and `outer_scopes`.
+    pub fn item<'a>(&'a self, outer_scopes: &'a [Scope], column: ColumnRef) -> &'a ScopeItem {
+        match column.level {
+            0 => &self.items[column.column],
+            level => &outer_scopes[level - 1].items[column.column],
+        }
+    }
+
     /// Constructs an iterator over the canonical name for each column.
     pub fn column_names(&self) -> impl Iterator<Item = &ColumnName> {
         self.items.iter().map(|item| &item.column_name)
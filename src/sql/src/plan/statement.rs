@@ -13,8 +13,9 @@
 
 use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
 
-use mz_repr::{ColumnType, GlobalId, RelationDesc, ScalarType};
+use mz_repr::{ColumnName, ColumnType, GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::{
     ColumnDef, RawItemName, ShowStatement, TableConstraint, UnresolvedDatabaseName,
     UnresolvedSchemaName,
@@ -113,6 +114,8 @@ pub fn describe(
         catalog,
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
+        function_param_names: BTreeMap::new(),
+        column_references: Default::default(),
     };
 
     let desc = match stmt {
@@ -124,9 +127,11 @@ pub fn describe(
         Statement::AlterObjectSwap(stmt) => ddl::describe_alter_object_swap(&scx, stmt)?,
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
+        Statement::AlterSubscription(stmt) => ddl::describe_alter_subscription(&scx, stmt)?,
         Statement::AlterSetCluster(stmt) => ddl::describe_alter_set_cluster(&scx, stmt)?,
         Statement::AlterSink(stmt) => ddl::describe_alter_sink(&scx, stmt)?,
         Statement::AlterSource(stmt) => ddl::describe_alter_source(&scx, stmt)?,
+        Statement::AlterTable(stmt) => ddl::describe_alter_table(&scx, stmt)?,
         Statement::AlterSystemSet(stmt) => ddl::describe_alter_system_set(&scx, stmt)?,
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
@@ -145,6 +150,9 @@ pub fn describe(
         Statement::CreateSubsource(stmt) => ddl::describe_create_subsource(&scx, stmt)?,
         Statement::CreateTable(stmt) => ddl::describe_create_table(&scx, stmt)?,
         Statement::CreateType(stmt) => ddl::describe_create_type(&scx, stmt)?,
+        Statement::CreateFunction(stmt) => ddl::describe_create_function(&scx, stmt)?,
+        Statement::CreatePolicy(stmt) => ddl::describe_create_policy(&scx, stmt)?,
+        Statement::CreateSubscription(stmt) => ddl::describe_create_subscription(&scx, stmt)?,
         Statement::CreateView(stmt) => ddl::describe_create_view(&scx, stmt)?,
         Statement::CreateMaterializedView(stmt) => {
             ddl::describe_create_materialized_view(&scx, stmt)?
@@ -213,8 +221,10 @@ pub fn describe(
         Statement::ExplainTimestamp(stmt) => dml::describe_explain_timestamp(&scx, stmt)?,
         Statement::ExplainSinkSchema(stmt) => dml::describe_explain_schema(&scx, stmt)?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
+        Statement::Listen(stmt) => dml::describe_listen(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
+        Statement::Unlisten(stmt) => dml::describe_unlisten(&scx, stmt)?,
         Statement::Update(stmt) => dml::describe_update(&scx, stmt)?,
 
         // TCL statements.
@@ -275,6 +285,8 @@ pub fn plan(
         catalog,
         param_types: RefCell::new(param_types),
         ambiguous_columns: RefCell::new(false),
+        function_param_names: BTreeMap::new(),
+        column_references: Default::default(),
     };
 
     if resolved_ids
@@ -300,9 +312,11 @@ pub fn plan(
         Statement::AlterObjectSwap(stmt) => ddl::plan_alter_object_swap(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
+        Statement::AlterSubscription(stmt) => ddl::plan_alter_subscription(scx, stmt),
         Statement::AlterSetCluster(stmt) => ddl::plan_alter_item_set_cluster(scx, stmt),
         Statement::AlterSink(stmt) => ddl::plan_alter_sink(scx, stmt),
         Statement::AlterSource(stmt) => ddl::plan_alter_source(scx, stmt),
+        Statement::AlterTable(stmt) => ddl::plan_alter_table(scx, stmt),
         Statement::AlterSystemSet(stmt) => ddl::plan_alter_system_set(scx, stmt),
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
@@ -321,6 +335,9 @@ pub fn plan(
         Statement::CreateSubsource(stmt) => ddl::plan_create_subsource(scx, stmt),
         Statement::CreateTable(stmt) => ddl::plan_create_table(scx, stmt),
         Statement::CreateType(stmt) => ddl::plan_create_type(scx, stmt),
+        Statement::CreateFunction(stmt) => ddl::plan_create_function(scx, stmt, resolved_ids),
+        Statement::CreatePolicy(stmt) => ddl::plan_create_policy(scx, stmt, resolved_ids),
+        Statement::CreateSubscription(stmt) => ddl::plan_create_subscription(scx, stmt),
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt, params),
         Statement::CreateMaterializedView(stmt) => {
             ddl::plan_create_materialized_view(scx, stmt, params)
@@ -344,8 +361,10 @@ pub fn plan(
         Statement::ExplainTimestamp(stmt) => dml::plan_explain_timestamp(scx, stmt, params),
         Statement::ExplainSinkSchema(stmt) => dml::plan_explain_schema(scx, stmt),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
+        Statement::Listen(stmt) => dml::plan_listen(scx, stmt),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, params, None),
+        Statement::Unlisten(stmt) => dml::plan_unlisten(scx, stmt),
         Statement::Update(stmt) => dml::plan_update(scx, stmt, params),
 
         // `SHOW` statements.
@@ -436,7 +455,9 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Index, ObjectType::Index)
             | (CatalogItemType::Type, ObjectType::Type)
             | (CatalogItemType::Secret, ObjectType::Secret)
-            | (CatalogItemType::Connection, ObjectType::Connection) => true,
+            | (CatalogItemType::Connection, ObjectType::Connection)
+            | (CatalogItemType::Policy, ObjectType::Policy)
+            | (CatalogItemType::Subscription, ObjectType::Subscription) => true,
             (_, _) => false,
         }
     }
@@ -463,6 +484,15 @@ pub struct StatementContext<'a> {
     /// Whether the statement contains an expression that can make the exact column list
     /// ambiguous. For example `NATURAL JOIN` or `SELECT *`. This is filled in as planning occurs.
     pub ambiguous_columns: RefCell<bool>,
+    /// The names of the parameters of the SQL function whose body is being planned, mapped to
+    /// their parameter positions. Unqualified identifiers that do not refer to a column resolve
+    /// to these parameters.
+    pub function_param_names: BTreeMap<ColumnName, usize>,
+    /// The columns of catalog relations that the statement references, keyed by relation. A
+    /// relation that is read without referencing any of its columns maps to an empty set. This is
+    /// shared with the contexts cloned from this one, e.g. to plan the bodies of SQL functions,
+    /// and is filled in as planning occurs.
+    pub column_references: Rc<RefCell<BTreeMap<GlobalId, BTreeSet<usize>>>>,
 }
 
 impl<'a> StatementContext<'a> {
@@ -475,9 +505,30 @@ impl<'a> StatementContext<'a> {
             catalog,
             param_types: Default::default(),
             ambiguous_columns: RefCell::new(false),
+            function_param_names: BTreeMap::new(),
+            column_references: Default::default(),
         }
     }
 
+    /// Records that the statement reads the catalog relation `id`.
+    pub fn record_relation_reference(&self, id: GlobalId) {
+        self.column_references.borrow_mut().entry(id).or_default();
+    }
+
+    /// Records that the statement references column `column` of the catalog relation `id`.
+    pub fn record_column_reference(&self, id: GlobalId, column: usize) {
+        self.column_references
+            .borrow_mut()
+            .entry(id)
+            .or_default()
+            .insert(column);
+    }
+
+    /// Returns the column references recorded so far, leaving none behind.
+    pub fn take_column_references(&self) -> BTreeMap<GlobalId, BTreeSet<usize>> {
+        std::mem::take(&mut *self.column_references.borrow_mut())
+    }
+
     /// Returns the schemas in order of search_path that exist in the catalog.
     pub fn current_schemas(&self) -> &[(ResolvedDatabaseSpecifier, SchemaSpecifier)] {
         self.catalog.search_path()
@@ -12,7 +12,7 @@
 //! This module houses the handlers for statements that modify privileges in the catalog, like
 //! `GRANT`, `REVOKE`, and `REASSIGN OWNED`.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use itertools::Itertools;
 use mz_sql_parser::ast::display::AstDisplay;
@@ -25,6 +25,7 @@ use crate::catalog::{
 use crate::names::{
     Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
+use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::{
     ensure_cluster_is_not_linked, resolve_cluster, resolve_database, resolve_item, resolve_schema,
@@ -38,9 +39,10 @@ use crate::session::user::SYSTEM_USER;
 use mz_ore::str::StrExt;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::role_id::RoleId;
+use mz_repr::{ColumnName, GlobalId};
 use mz_sql_parser::ast::{
     AbbreviatedGrantOrRevokeStatement, AlterDefaultPrivilegesStatement, AlterOwnerStatement,
-    GrantPrivilegesStatement, GrantRoleStatement, GrantTargetAllSpecification,
+    ColumnPrivilege, GrantPrivilegesStatement, GrantRoleStatement, GrantTargetAllSpecification,
     GrantTargetSpecification, GrantTargetSpecificationInner, Privilege, PrivilegeSpecification,
     ReassignOwnedStatement, RevokePrivilegesStatement, RevokeRoleStatement,
     TargetRoleSpecification, UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName,
@@ -397,6 +399,18 @@ fn plan_update_privilege(
     target: GrantTargetSpecification<Aug>,
     roles: Vec<ResolvedRoleName>,
 ) -> Result<UpdatePrivilegesPlan, PlanError> {
+    if let PrivilegeSpecification::Columns(_) = &privileges {
+        if !matches!(
+            &target,
+            GrantTargetSpecification::Object {
+                object_type: ObjectType::Table,
+                object_spec_inner: GrantTargetSpecificationInner::Objects { .. },
+            }
+        ) {
+            sql_bail!("column privileges can only be specified on individual relations");
+        }
+    }
+
     let (object_type, target_ids) = match target {
         GrantTargetSpecification::Object {
             object_type,
@@ -548,8 +562,17 @@ fn plan_update_privilege(
             SystemObjectId::System => scx.catalog.mz_system_role_id(),
         };
 
+        let column_acl_modes = match (&privileges, &target_id) {
+            (
+                PrivilegeSpecification::Columns(column_privileges),
+                SystemObjectId::Object(ObjectId::Item(id)),
+            ) => plan_column_acl_modes(scx, id, column_privileges)?,
+            _ => BTreeMap::new(),
+        };
+
         update_privileges.push(UpdatePrivilege {
             acl_mode,
+            column_acl_modes,
             target_id,
             grantor,
         });
@@ -563,6 +586,42 @@ fn plan_update_privilege(
     })
 }
 
+/// Validates column privileges on the relation identified by `id` and collects them per column.
+fn plan_column_acl_modes(
+    scx: &StatementContext,
+    id: &GlobalId,
+    column_privileges: &[ColumnPrivilege],
+) -> Result<BTreeMap<ColumnName, AclMode>, PlanError> {
+    let item = scx.get_item(id);
+    let name = scx.catalog.resolve_full_name(item.name());
+    let desc = item.desc(&name)?;
+    let mut column_acl_modes = BTreeMap::new();
+    for ColumnPrivilege { privilege, columns } in column_privileges {
+        match privilege {
+            Privilege::SELECT => {}
+            Privilege::INSERT | Privilege::UPDATE => {
+                bail_unsupported!(format!("{privilege} privileges on columns"))
+            }
+            _ => sql_bail!("invalid privilege type {privilege} for column"),
+        }
+        for column in columns {
+            let column = normalize::column_name(column.clone());
+            if desc.get_by_name(&column).is_none() {
+                sql_bail!(
+                    "column {} of relation {} does not exist",
+                    column.as_str().quoted(),
+                    name.to_string().quoted()
+                );
+            }
+            let acl_mode = column_acl_modes
+                .entry(column)
+                .or_insert_with(AclMode::empty);
+            *acl_mode = acl_mode.union(privilege_to_acl_mode(privilege.clone()));
+        }
+    }
+    Ok(column_acl_modes)
+}
+
 fn privilege_spec_to_acl_mode(
     scx: &StatementContext,
     privilege_spec: &PrivilegeSpecification,
@@ -575,6 +634,8 @@ fn privilege_spec_to_acl_mode(
             .map(|privilege| privilege_to_acl_mode(privilege.clone()))
             // PostgreSQL doesn't care about duplicate privileges, so we don't either.
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode)),
+        // Column privileges are tracked separately from the privileges on the object itself.
+        PrivilegeSpecification::Columns(_) => AclMode::empty(),
     }
 }
 
@@ -584,6 +645,7 @@ fn privilege_to_acl_mode(privilege: Privilege) -> AclMode {
         Privilege::INSERT => AclMode::INSERT,
         Privilege::UPDATE => AclMode::UPDATE,
         Privilege::DELETE => AclMode::DELETE,
+        Privilege::EXECUTE => AclMode::EXECUTE,
         Privilege::USAGE => AclMode::USAGE,
         Privilege::CREATE => AclMode::CREATE,
         Privilege::CREATEROLE => AclMode::CREATE_ROLE,
@@ -607,14 +669,20 @@ pub fn plan_alter_default_privileges(
         grant_or_revoke,
     }: AlterDefaultPrivilegesStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    if let PrivilegeSpecification::Columns(_) = grant_or_revoke.privileges() {
+        sql_bail!("column privileges are not valid for ALTER DEFAULT PRIVILEGES");
+    }
     let object_type: ObjectType = (*grant_or_revoke.object_type()).into();
     match object_type {
         ObjectType::View | ObjectType::MaterializedView | ObjectType::Source => sql_bail!(
             "{object_type}S is not valid for ALTER DEFAULT PRIVILEGES, use TABLES instead"
         ),
-        ObjectType::Sink | ObjectType::ClusterReplica | ObjectType::Role | ObjectType::Func => {
+        ObjectType::Sink | ObjectType::ClusterReplica | ObjectType::Role => {
             sql_bail!("{object_type}S do not have privileges")
         }
+        ObjectType::Func => bail_unsupported!("ALTER DEFAULT PRIVILEGES for FUNCTIONS"),
+        ObjectType::Policy => sql_bail!("POLICIES do not have privileges"),
+        ObjectType::Subscription => sql_bail!("SUBSCRIPTIONS do not have privileges"),
         ObjectType::Cluster | ObjectType::Database
             if matches!(
                 target_objects,
@@ -15,32 +15,38 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
+use std::time::Duration;
 
 use itertools::{Either, Itertools};
 use mz_controller_types::{ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL_MICROS};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, AvroSchemaOptions, DocTarget};
 use mz_ore::cast::{self, CastFrom, TryCastFrom};
-use mz_ore::collections::HashSet;
+use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::adt::pg_legacy_name::NAME_MAX_BYTES;
 use mz_repr::adt::system::Oid;
+use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
 use mz_repr::role_id::RoleId;
-use mz_repr::{strconv, ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{
+    strconv, ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, ScalarType,
+    Timestamp,
+};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterClusterAction, AlterClusterStatement, AlterConnectionAction, AlterConnectionOption,
     AlterConnectionOptionName, AlterRoleOption, AlterRoleStatement, AlterSetClusterStatement,
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
     AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, CommentObjectType, CommentStatement,
-    CreateConnectionOption, CreateConnectionOptionName, CreateConnectionType, CreateTypeListOption,
-    CreateTypeListOptionName, CreateTypeMapOption, CreateTypeMapOptionName, DeferredItemName,
-    DocOnIdentifier, DocOnSchema, DropOwnedStatement, MaterializedViewOption,
-    MaterializedViewOptionName, SetRoleVar, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value,
+    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAction, AlterTableStatement,
+    CommentObjectType, CommentStatement, CreateConnectionOption, CreateConnectionOptionName,
+    CreateConnectionType, CreateTypeListOption, CreateTypeListOptionName, CreateTypeMapOption,
+    CreateTypeMapOptionName, DeferredItemName, DocOnIdentifier, DocOnSchema, DropOwnedStatement,
+    MaterializedViewOption, MaterializedViewOptionName, SetRoleVar, TableOption, TableOptionName,
+    UnresolvedItemName, UnresolvedObjectName, UnresolvedSchemaName, Value,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::connections::inline::{ConnectionAccess, ReferencedConnection};
@@ -64,22 +70,25 @@ use prost::Message;
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
-    AlterObjectSwapStatement, AlterSecretStatement, AvroSchema, AvroSchemaOption,
-    AvroSchemaOptionName, ClusterOption, ClusterOptionName, ColumnOption,
+    AlterObjectSwapStatement, AlterSecretStatement, AlterSubscriptionStatement, AvroSchema,
+    AvroSchemaOption, AvroSchemaOptionName, ClusterOption, ClusterOptionName, ColumnOption,
     CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionStatement,
-    CreateDatabaseStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection,
-    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
-    CreateSourceFormat, CreateSourceOption, CreateSourceOptionName, CreateSourceStatement,
+    CreateDatabaseStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreatePolicyStatement, CreateRoleStatement,
+    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
+    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceFormat,
+    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubscriptionStatement,
     CreateSubsourceOption, CreateSubsourceOptionName, CreateSubsourceStatement,
     CreateTableStatement, CreateTypeAs, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
-    DropObjectsStatement, Envelope, Expr, Format, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaConfigOptionName, KeyConstraint, LoadGeneratorOption,
+    DropObjectsStatement, Envelope, Expr, Format, FunctionReturnType, Ident, IfExistsBehavior,
+    IndexOption, IndexOptionName, KafkaConfigOptionName, KeyConstraint, LoadGeneratorOption,
     LoadGeneratorOptionName, PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica,
-    ReferencedSubsources, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
-    SourceIncludeMetadata, Statement, TableConstraint, UnresolvedDatabaseName, ViewDefinition,
+    ReferencedSubsources, RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue,
+    ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute, SourceIncludeMetadata,
+    Statement, SubscriptionOption, SubscriptionOptionName, TableConstraint, UnresolvedDatabaseName,
+    ViewDefinition,
 };
 use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
@@ -89,7 +98,7 @@ use crate::kafka_util::{self, KafkaConfigOptionExtracted, KafkaStartOffsetType};
 use crate::names::{
     Aug, CommentObjectId, DatabaseId, ObjectId, PartialItemName, QualifiedItemName,
     RawDatabaseSpecifier, ResolvedClusterName, ResolvedColumnName, ResolvedDataType,
-    ResolvedDatabaseSpecifier, ResolvedItemName, SchemaSpecifier, SystemObjectId,
+    ResolvedDatabaseSpecifier, ResolvedIds, ResolvedItemName, SchemaSpecifier, SystemObjectId,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
@@ -99,24 +108,26 @@ use crate::plan::scope::Scope;
 use crate::plan::statement::ddl::connection::{INALTERABLE_OPTIONS, MUTUALLY_EXCLUSIVE_SETS};
 use crate::plan::statement::{scl, StatementContext, StatementDesc};
 use crate::plan::typeconv::{plan_cast, CastContext};
-use crate::plan::with_options::{OptionalInterval, TryFromValue};
+use crate::plan::with_options::{OptionalInterval, RetainHistoryFor, TryFromValue};
 use crate::plan::{
     plan_utils, query, transform_ast, AlterClusterPlan, AlterClusterRenamePlan,
     AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterConnectionPlan,
     AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterNoopPlan,
     AlterOptionParameter, AlterRolePlan, AlterSchemaRenamePlan, AlterSchemaSwapPlan,
-    AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan,
-    AlterSystemResetPlan, AlterSystemSetPlan, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan, CreateClusterPlan,
-    CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
-    DropOwnedPlan, FullItemName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
-    PlanClusterOption, PlanNotice, QueryContext, ReplicaConfig, Secret, Sink, Source,
-    SourceSinkClusterConfig, Table, Type, VariableValue, View, WebhookHeaderFilters,
+    AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan, AlterSourcePlan, AlterSubscriptionPlan,
+    AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan, AlterTablePlan, CommentPlan,
+    ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, CreateClusterManagedPlan,
+    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
+    CreateConnectionPlan, CreateDatabasePlan, CreateFunctionPlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreatePolicyPlan, CreateRolePlan, CreateSchemaPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateSubscriptionPlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, FullItemName,
+    Function, FunctionReturns, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
+    PlanClusterOption, PlanNotice, Policy, QueryContext, ReplicaConfig, Secret, Sink, Source,
+    SourceSinkClusterConfig, Subscription, Table, Type, VariableValue, View, WebhookHeaderFilters,
     WebhookHeaders, WebhookValidation,
 };
+use crate::session::scram::ScramVerifier;
 use crate::session::vars;
 
 mod connection;
@@ -206,6 +217,7 @@ pub fn plan_create_table(
         constraints,
         if_not_exists,
         temporary,
+        with_options,
     } = &stmt;
 
     let names: Vec<_> = columns
@@ -222,6 +234,8 @@ pub fn plan_create_table(
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
     let mut keys = Vec::new();
+    let mut seen_primary = false;
+    let mut seen_non_primary = false;
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
@@ -240,6 +254,15 @@ pub fn plan_create_table(
                     default = expr.clone();
                 }
                 ColumnOption::Unique { is_primary } => {
+                    if seen_primary && *is_primary {
+                        sql_bail!(
+                            "multiple primary keys for table {} are not allowed",
+                            name.to_ast_string_stable()
+                        );
+                    }
+                    seen_primary = *is_primary || seen_primary;
+                    seen_non_primary = !*is_primary || seen_non_primary;
+
                     keys.push(vec![i]);
                     if *is_primary {
                         nullable = false;
@@ -254,7 +277,6 @@ pub fn plan_create_table(
         defaults.push(default);
     }
 
-    let mut seen_primary = false;
     'c: for constraint in constraints {
         match constraint {
             TableConstraint::Unique {
@@ -289,7 +311,7 @@ pub fn plan_create_table(
                             } else if !(*nulls_not_distinct || !*nullable) {
                                 // Non-primary key unique constraints are only keys if all of their
                                 // columns are `NOT NULL` or the constraint is `NULLS NOT DISTINCT`.
-                                break 'c;
+                                continue 'c;
                             }
 
                             key.push(i);
@@ -300,6 +322,7 @@ pub fn plan_create_table(
                 if *is_primary {
                     keys.insert(0, key);
                 } else {
+                    seen_non_primary = true;
                     keys.push(key);
                 }
             }
@@ -316,9 +339,10 @@ pub fn plan_create_table(
         }
     }
 
-    if !keys.is_empty() {
-        // Unique constraints are not presently enforced. We allow them with feature flags for
-        // sqllogictest's sake.
+    if seen_non_primary {
+        // Primary keys are enforced when rows are inserted. Other unique
+        // constraints are enforced too, but do not yet match PostgreSQL's
+        // treatment of NULLs, so we only allow them with feature flags.
         scx.require_feature_flag(&vars::ENABLE_TABLE_KEYS)?
     }
 
@@ -343,12 +367,19 @@ pub fn plan_create_table(
 
     let desc = RelationDesc::new(typ, names);
 
+    let TableOptionExtracted {
+        retain_history,
+        seen: _,
+    } = with_options.clone().try_into()?;
+    let compaction_window = plan_retain_history(scx, retain_history)?;
+
     let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
     let table = Table {
         create_sql,
         desc,
         defaults,
         temporary,
+        compaction_window,
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
@@ -357,6 +388,23 @@ pub fn plan_create_table(
     }))
 }
 
+generate_extracted_config!(TableOption, (RetainHistory, RetainHistoryFor));
+
+/// Plans the value of a `RETAIN HISTORY` option into the compaction window
+/// that the coordinator should install for the object.
+fn plan_retain_history(
+    scx: &StatementContext,
+    retain_history: Option<RetainHistoryFor>,
+) -> Result<Option<Duration>, PlanError> {
+    match retain_history {
+        Some(RetainHistoryFor(duration)) => {
+            scx.require_feature_flag(&vars::ENABLE_RETAIN_HISTORY)?;
+            Ok(Some(duration))
+        }
+        None => Ok(None),
+    }
+}
+
 pub fn describe_create_webhook_source(
     _: &StatementContext,
     _: CreateWebhookSourceStatement<Aug>,
@@ -383,7 +431,8 @@ generate_extracted_config!(
     (IgnoreKeys, bool),
     (Size, String),
     (Timeline, String),
-    (TimestampInterval, Interval)
+    (TimestampInterval, Interval),
+    (RetainHistory, RetainHistoryFor)
 );
 
 generate_extracted_config!(
@@ -407,8 +456,25 @@ pub fn plan_create_webhook_source(
         include_headers,
         validate_using,
         in_cluster,
+        with_options,
     } = stmt;
 
+    let CreateSourceOptionExtracted {
+        retain_history,
+        seen,
+        ..
+    } = CreateSourceOptionExtracted::try_from(with_options)?;
+    if let Some(option) = seen
+        .iter()
+        .find(|o| !matches!(o, CreateSourceOptionName::RetainHistory))
+    {
+        sql_bail!(
+            "webhook sources do not support the {} option",
+            option.to_ast_string()
+        );
+    }
+    let compaction_window = plan_retain_history(scx, retain_history)?;
+
     let validate_using = validate_using
         .map(|stmt| query::plan_webhook_validate_using(scx, stmt))
         .transpose()?;
@@ -539,6 +605,7 @@ pub fn plan_create_webhook_source(
                 headers,
             },
             desc,
+            compaction_window,
         },
         if_not_exists,
         timeline,
@@ -1092,8 +1159,10 @@ pub fn plan_create_source(
         timeline,
         timestamp_interval,
         ignore_keys,
+        retain_history,
         seen: _,
     } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
+    let compaction_window = plan_retain_history(scx, retain_history)?;
 
     let (key_desc, value_desc) = encoding.desc()?;
 
@@ -1293,6 +1362,7 @@ pub fn plan_create_source(
             progress_subsource,
         }),
         desc,
+        compaction_window,
     };
 
     Ok(Plan::CreateSource(CreateSourcePlan {
@@ -1307,7 +1377,8 @@ pub fn plan_create_source(
 generate_extracted_config!(
     CreateSubsourceOption,
     (Progress, bool, Default(false)),
-    (References, bool, Default(false))
+    (References, bool, Default(false)),
+    (RetainHistory, RetainHistoryFor)
 );
 
 pub fn plan_create_subsource(
@@ -1325,8 +1396,10 @@ pub fn plan_create_subsource(
     let CreateSubsourceOptionExtracted {
         progress,
         references,
+        retain_history,
         ..
     } = with_options.clone().try_into()?;
+    let compaction_window = plan_retain_history(scx, retain_history)?;
 
     // This invariant is enforced during purification; we are responsible for
     // creating the AST for subsources as a response to CREATE SOURCE
@@ -1452,6 +1525,7 @@ pub fn plan_create_subsource(
             unreachable!("state prohibited above")
         },
         desc,
+        compaction_window,
     };
 
     Ok(Plan::CreateSource(CreateSourcePlan {
@@ -2078,8 +2152,12 @@ pub fn plan_create_materialized_view(
 
     let MaterializedViewOptionExtracted {
         assert_not_null,
+        retain_history,
+        refresh,
         seen: _,
     }: MaterializedViewOptionExtracted = stmt.with_options.try_into()?;
+    let compaction_window = plan_retain_history(scx, retain_history)?;
+    let refresh_schedule = plan_refresh_schedule(scx, refresh)?;
 
     if !assert_not_null.is_empty() {
         scx.require_feature_flag(&crate::session::vars::ENABLE_ASSERT_NOT_NULL)?;
@@ -2169,6 +2247,8 @@ pub fn plan_create_materialized_view(
             column_names,
             cluster_id,
             non_null_assertions,
+            compaction_window,
+            refresh_schedule,
         },
         replace,
         drop_ids,
@@ -2179,9 +2259,84 @@ pub fn plan_create_materialized_view(
 
 generate_extracted_config!(
     MaterializedViewOption,
-    (AssertNotNull, Ident, AllowMultiple)
+    (AssertNotNull, Ident, AllowMultiple),
+    (RetainHistory, RetainHistoryFor),
+    (Refresh, RefreshOptionValue<Aug>, AllowMultiple)
 );
 
+/// Plans the `REFRESH` options of a materialized view into a
+/// [`RefreshSchedule`], or `None` if the view is refreshed on every commit.
+fn plan_refresh_schedule(
+    scx: &StatementContext,
+    refresh: Vec<RefreshOptionValue<Aug>>,
+) -> Result<Option<RefreshSchedule>, PlanError> {
+    if refresh.is_empty() {
+        return Ok(None);
+    }
+    scx.require_feature_flag(&vars::ENABLE_REFRESH_EVERY_MVS)?;
+    if refresh.contains(&RefreshOptionValue::OnCommit) {
+        if refresh.len() > 1 {
+            sql_bail!("REFRESH ON COMMIT cannot be combined with other REFRESH options");
+        }
+        return Ok(None);
+    }
+
+    let mut schedule = RefreshSchedule::default();
+    for option in refresh {
+        match option {
+            RefreshOptionValue::OnCommit => unreachable!("checked above"),
+            RefreshOptionValue::At(RefreshAtOptionValue { time }) => {
+                schedule
+                    .ats
+                    .push(evaluate_refresh_time(scx, time, "REFRESH AT")?);
+            }
+            RefreshOptionValue::Every(RefreshEveryOptionValue {
+                interval,
+                aligned_to,
+            }) => {
+                let interval = Interval::try_from_value(interval)?.duration()?;
+                if interval < Duration::from_millis(1) {
+                    sql_bail!("REFRESH EVERY interval must be at least 1 millisecond");
+                }
+                let aligned_to = match aligned_to {
+                    Some(aligned_to) => evaluate_refresh_time(scx, aligned_to, "ALIGNED TO")?,
+                    None => Timestamp::MIN,
+                };
+                schedule.everies.push(RefreshEvery {
+                    interval,
+                    aligned_to,
+                });
+            }
+        }
+    }
+    Ok(Some(schedule))
+}
+
+/// Evaluates a `REFRESH AT` or `ALIGNED TO` expression to a timestamp.
+///
+/// The expression must be constant: the statement is re-planned from its
+/// `create_sql` on every restart, and the schedule must not change.
+fn evaluate_refresh_time(
+    scx: &StatementContext,
+    expr: Expr<Aug>,
+    name: &str,
+) -> Result<Timestamp, PlanError> {
+    let mut expr = query::plan_refresh_time(scx, expr, name)?;
+    if expr.contains_unmaterializable() {
+        sql_bail!(
+            "{name} argument must be a constant expression, \
+             and cannot call functions like now() or mz_now()"
+        );
+    }
+    expr.reduce(&[]);
+    match expr.as_literal() {
+        Some(Ok(Datum::MzTimestamp(ts))) => Ok(ts),
+        Some(Ok(Datum::Null)) => sql_bail!("{name} argument must not be NULL"),
+        Some(Err(e)) => Err(e.clone().into()),
+        _ => sql_bail!("{name} argument must be a constant expression"),
+    }
+}
+
 pub fn describe_create_sink(
     _: &StatementContext,
     _: CreateSinkStatement<Aug>,
@@ -2249,6 +2404,7 @@ pub fn plan_create_sink(
 
     let from_name = &from;
     let from = scx.get_item_by_resolved_name(&from)?;
+    query::ensure_bypasses_policies(scx, from)?;
     let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
     let key_indices = match &connection {
         CreateSinkConnection::Kafka { key, .. } => {
@@ -2868,6 +3024,22 @@ pub fn plan_create_type(
     }
 
     let inner = match as_type {
+        CreateTypeAs::Enum { labels } => {
+            let mut seen = BTreeSet::new();
+            for label in &labels {
+                if label.len() > NAME_MAX_BYTES {
+                    sql_bail!(
+                        "invalid enum label \"{}\": labels must be {} bytes or less",
+                        label,
+                        NAME_MAX_BYTES
+                    );
+                }
+                if !seen.insert(label) {
+                    sql_bail!("enum label \"{}\" used more than once", label);
+                }
+            }
+            CatalogType::Enum { labels }
+        }
         CreateTypeAs::List { options } => {
             let CreateTypeListOptionExtracted {
                 element_type,
@@ -2933,6 +3105,386 @@ pub fn plan_create_type(
     }))
 }
 
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    stmt: CreateFunctionStatement<Aug>,
+    resolved_ids: &ResolvedIds,
+) -> Result<Plan, PlanError> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+    let CreateFunctionStatement {
+        name,
+        or_replace,
+        params,
+        returns,
+        body: _,
+    } = stmt;
+
+    let function_type = |data_type: &ResolvedDataType| {
+        let ty = query::scalar_type_from_sql(scx, data_type)?;
+        if ty.is_custom_type() {
+            bail_unsupported!(format!(
+                "user-defined type {} in function signature",
+                data_type.human_readable_name()
+            ));
+        }
+        Ok::<_, PlanError>(ty)
+    };
+
+    let mut param_names = BTreeSet::new();
+    let params = params
+        .into_iter()
+        .map(|param| {
+            let name = param.name.map(normalize::column_name);
+            if let Some(name) = &name {
+                if !param_names.insert(name.clone()) {
+                    sql_bail!(
+                        "parameter name {} used more than once",
+                        name.as_str().quoted()
+                    );
+                }
+            }
+            Ok((name, function_type(&param.data_type)?))
+        })
+        .collect::<Result<Vec<_>, PlanError>>()?;
+
+    let returns = match returns {
+        FunctionReturnType::Scalar(ty) => FunctionReturns::Scalar(function_type(&ty)?),
+        FunctionReturnType::SetOf(ty) => FunctionReturns::SetOf(function_type(&ty)?),
+        FunctionReturnType::Table(columns) => FunctionReturns::Table(
+            columns
+                .into_iter()
+                .map(|column| {
+                    Ok((
+                        normalize::column_name(column.name),
+                        function_type(&column.data_type)?,
+                    ))
+                })
+                .collect::<Result<_, PlanError>>()?,
+        ),
+    };
+
+    // The function's body is planned anew at each call site, so it must be
+    // stored with all of its names fully qualified. The normalized create SQL
+    // already has that property.
+    let body = match mz_sql_parser::parser::parse_statements(&create_sql)?
+        .into_element()
+        .ast
+    {
+        Statement::CreateFunction(CreateFunctionStatement { body, .. }) => body,
+        _ => unreachable!("create SQL must be a CREATE FUNCTION statement"),
+    };
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    let function = Function {
+        create_sql,
+        params,
+        returns,
+        body,
+    };
+
+    // Ensure that the body is well typed by planning a call to the function.
+    let func = crate::func::sql_function(&name.item, &function);
+    let qcx = QueryContext::root(scx, QueryLifetime::View);
+    let ecx = ExprContext {
+        qcx: &qcx,
+        name: "CREATE FUNCTION",
+        scope: &Scope::empty(),
+        relation_type: &RelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: true,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let args = || {
+        function
+            .params
+            .iter()
+            .map(|(_name, ty)| HirScalarExpr::literal_null(ty.clone()).into())
+            .collect::<Vec<_>>()
+    };
+    match &func {
+        crate::func::Func::Scalar(impls) => {
+            let imp = impls.iter().exactly_one().expect("one implementation");
+            (imp.op.0)(&ecx, args(), &imp.params, vec![])?;
+        }
+        crate::func::Func::Table(impls) => {
+            let imp = impls.iter().exactly_one().expect("one implementation");
+            (imp.op.0)(&ecx, args(), &imp.params, vec![])?;
+        }
+        _ => unreachable!("SQL functions are scalar or table functions"),
+    }
+
+    // Check for a function in the catalog with this same name.
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    let mut in_place = false;
+    let replace = match scx.catalog.resolve_function(&partial_name) {
+        Ok(item) if or_replace => {
+            if item.id().is_system() {
+                sql_bail!(
+                    "cannot replace function {} because it is required by the database system",
+                    full_name
+                );
+            }
+            // A function whose signature is unchanged is replaced in place, so
+            // that the objects that depend on it need not be dropped. Both
+            // statements are normalized, so their signatures can be compared
+            // syntactically.
+            let signature =
+                |create_sql: &str| match mz_sql_parser::parser::parse_statements(create_sql)?
+                    .into_element()
+                    .ast
+                {
+                    Statement::CreateFunction(CreateFunctionStatement {
+                        params, returns, ..
+                    }) => Ok::<_, PlanError>((params, returns)),
+                    _ => unreachable!("create SQL must be a CREATE FUNCTION statement"),
+                };
+            in_place = signature(item.create_sql())? == signature(&function.create_sql)?;
+            let id = if in_place {
+                Some(item.id())
+            } else {
+                let cascade = false;
+                plan_drop_item(
+                    scx,
+                    ObjectType::Func,
+                    true,
+                    UnresolvedItemName::from(full_name.clone()),
+                    cascade,
+                )?
+            };
+            if let Some(id) = id {
+                // The body must not refer to the function, either directly or
+                // through the objects that depend on it.
+                let mut to_visit = vec![id];
+                let mut seen = BTreeSet::new();
+                while let Some(dependent) = to_visit.pop() {
+                    if resolved_ids.0.contains(&dependent) {
+                        sql_bail!(
+                            "cannot replace function {} because its body refers to itself",
+                            full_name
+                        );
+                    }
+                    if seen.insert(dependent) {
+                        to_visit.extend(scx.catalog.get_item(&dependent).used_by());
+                    }
+                }
+            }
+            id
+        }
+        Ok(item) => {
+            return Err(PlanError::ItemAlreadyExists {
+                name: full_name.to_string(),
+                item_type: item.item_type(),
+            })
+        }
+        Err(_) => None,
+    };
+
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        function,
+        replace,
+        in_place,
+    }))
+}
+
+pub fn describe_create_policy(
+    _: &StatementContext,
+    _: CreatePolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_policy(
+    scx: &StatementContext,
+    mut stmt: CreatePolicyStatement<Aug>,
+    resolved_ids: &ResolvedIds,
+) -> Result<Plan, PlanError> {
+    let on = scx.get_item_by_resolved_name(&stmt.on_name)?;
+    let full_on_name = scx.catalog.resolve_full_name(on.name());
+    match on.item_type() {
+        CatalogItemType::Table
+        | CatalogItemType::View
+        | CatalogItemType::MaterializedView
+        | CatalogItemType::Source => {}
+        item_type => sql_bail!(
+            "policy cannot be created on {} because it is a {}",
+            full_on_name,
+            item_type
+        ),
+    }
+    if on.id().is_system() {
+        sql_bail!(
+            "cannot create policy on {} because it is required by the database system",
+            full_on_name
+        );
+    }
+    if let SchemaSpecifier::Temporary = on.name().qualifiers.schema_spec {
+        bail_unsupported!("policies on temporary relations");
+    }
+    ensure_policies_alterable(scx, on)?;
+
+    // Policies are planned anew by every query that reads from the relation,
+    // so they must not call back into user-defined objects.
+    for id in &resolved_ids.0 {
+        if *id != on.id() && id.is_user() && scx.catalog.get_item(id).func().is_ok() {
+            bail_unsupported!("user-defined functions in policies");
+        }
+    }
+    let on_desc = on.desc(&full_on_name)?;
+    query::plan_policy_expr(scx, &on_desc, &stmt.using)?;
+
+    let name = QualifiedItemName {
+        qualifiers: on.name().qualifiers.clone(),
+        item: normalize::ident(stmt.name.clone()),
+    };
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let Ok(item) = scx.catalog.resolve_item(&partial_name) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    // Normalize `stmt`.
+    if let ResolvedItemName::Item { print_id, .. } = &mut stmt.on_name {
+        *print_id = false;
+    }
+    let using = stmt.using.clone();
+    let create_sql = normalize::create_statement(scx, Statement::CreatePolicy(stmt))?;
+
+    Ok(Plan::CreatePolicy(CreatePolicyPlan {
+        name,
+        policy: Policy {
+            create_sql,
+            on: on.id(),
+            using,
+        },
+    }))
+}
+
+/// Errors if any view, materialized view or sink reads from `on`.
+///
+/// Queries apply the policies on a relation when they are planned, so the
+/// policies on a relation must not change underneath the objects whose queries
+/// were already planned.
+fn ensure_policies_alterable(
+    scx: &StatementContext,
+    on: &dyn CatalogItem,
+) -> Result<(), PlanError> {
+    for id in on.used_by() {
+        let dep = scx.catalog.get_item(id);
+        if matches!(
+            dep.item_type(),
+            CatalogItemType::View | CatalogItemType::MaterializedView | CatalogItemType::Sink
+        ) {
+            sql_bail!(
+                "cannot change the policies on {} because {} {} depends on it",
+                scx.catalog.resolve_full_name(on.name()),
+                dep.item_type(),
+                scx.catalog.resolve_full_name(dep.name()),
+            );
+        }
+    }
+    Ok(())
+}
+
+generate_extracted_config!(
+    SubscriptionOption,
+    (Acknowledged, Timestamp),
+    (RetainHistory, RetainHistoryFor)
+);
+
+/// Plans an acknowledged frontier in a `CREATE SUBSCRIPTION` or `ALTER
+/// SUBSCRIPTION` statement.
+fn plan_subscription_acknowledged(
+    acknowledged: Option<Timestamp>,
+) -> Result<Option<Timestamp>, PlanError> {
+    match acknowledged {
+        Some(ts) if ts == Timestamp::MIN => {
+            sql_bail!("ACKNOWLEDGED must be greater than 0")
+        }
+        acknowledged => Ok(acknowledged),
+    }
+}
+
+pub fn describe_create_subscription(
+    _: &StatementContext,
+    _: CreateSubscriptionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_subscription(
+    scx: &StatementContext,
+    mut stmt: CreateSubscriptionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_DURABLE_SUBSCRIPTIONS)?;
+
+    let on = scx.get_item_by_resolved_name(&stmt.on_name)?;
+    let full_on_name = scx.catalog.resolve_full_name(on.name());
+    match on.item_type() {
+        CatalogItemType::Table
+        | CatalogItemType::View
+        | CatalogItemType::MaterializedView
+        | CatalogItemType::Source => {}
+        item_type => sql_bail!(
+            "subscription cannot be created on {} because it is a {}",
+            full_on_name,
+            item_type
+        ),
+    }
+    if on.id().is_system() {
+        sql_bail!(
+            "cannot create subscription on {} because it is required by the database system",
+            full_on_name
+        );
+    }
+    if let SchemaSpecifier::Temporary = on.name().qualifiers.schema_spec {
+        sql_bail!(
+            "cannot create subscription on {} because it is a temporary relation",
+            full_on_name
+        );
+    }
+
+    let SubscriptionOptionExtracted {
+        acknowledged,
+        retain_history,
+        ..
+    } = stmt.with_options.clone().try_into()?;
+    let acknowledged = plan_subscription_acknowledged(acknowledged)?;
+    let retain_history = retain_history.map(|RetainHistoryFor(duration)| duration);
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(stmt.name.clone())?)?;
+    let if_not_exists = stmt.if_not_exists;
+
+    // Normalize `stmt`.
+    if let ResolvedItemName::Item { print_id, .. } = &mut stmt.on_name {
+        *print_id = false;
+    }
+    let create_sql = normalize::create_statement(scx, Statement::CreateSubscription(stmt))?;
+
+    Ok(Plan::CreateSubscription(CreateSubscriptionPlan {
+        name,
+        subscription: Subscription {
+            create_sql,
+            on: on.id(),
+            acknowledged,
+            retain_history,
+        },
+        if_not_exists,
+    }))
+}
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -2950,10 +3502,21 @@ pub enum PlannedAlterRoleOption {
 #[derive(Debug)]
 pub struct PlannedRoleAttributes {
     pub inherit: Option<bool>,
+    /// The hashed new password of the role, `Some(None)` to remove the role's
+    /// password, or `None` to leave it unchanged.
+    ///
+    /// Passwords are hashed during planning so that plans never contain the
+    /// password in plain text.
+    pub password_hash: Option<Option<String>>,
+    pub bypass_rls: Option<bool>,
 }
 
 fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttributes, PlanError> {
-    let mut planned_attributes = PlannedRoleAttributes { inherit: None };
+    let mut planned_attributes = PlannedRoleAttributes {
+        inherit: None,
+        password_hash: None,
+        bypass_rls: None,
+    };
 
     for option in options {
         match option {
@@ -2968,6 +3531,14 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
             {
                 sql_bail!("conflicting or redundant options");
             }
+            RoleAttribute::Password(_) if planned_attributes.password_hash.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
+            RoleAttribute::BypassRls | RoleAttribute::NoBypassRls
+                if planned_attributes.bypass_rls.is_some() =>
+            {
+                sql_bail!("conflicting or redundant options");
+            }
             RoleAttribute::CreateCluster | RoleAttribute::NoCreateCluster => {
                 bail_never_supported!(
                     "CREATECLUSTER attribute",
@@ -2992,6 +3563,17 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
 
             RoleAttribute::Inherit => planned_attributes.inherit = Some(true),
             RoleAttribute::NoInherit => planned_attributes.inherit = Some(false),
+            RoleAttribute::BypassRls => planned_attributes.bypass_rls = Some(true),
+            RoleAttribute::NoBypassRls => planned_attributes.bypass_rls = Some(false),
+            RoleAttribute::Password(None) => planned_attributes.password_hash = Some(None),
+            RoleAttribute::Password(Some(password)) => {
+                if password.is_empty() {
+                    sql_bail!("empty string is not a valid password");
+                }
+                let verifier = ScramVerifier::hash(&password)
+                    .map_err(|e| sql_err!("failed to hash password: {e}"))?;
+                planned_attributes.password_hash = Some(Some(verifier.to_string()));
+            }
         }
     }
     if planned_attributes.inherit == Some(false) {
@@ -3511,11 +4093,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    assert_ne!(
-        object_type,
-        mz_sql_parser::ast::ObjectType::Func,
-        "rejected in parser"
-    );
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -3716,7 +4293,11 @@ fn plan_drop_item_inner(
     cascade: bool,
     allow_dropping_subsources: bool,
 ) -> Result<Option<GlobalId>, PlanError> {
-    Ok(match resolve_item(scx, name, if_exists)? {
+    let item = match object_type {
+        ObjectType::Func => resolve_function(scx, name, if_exists)?,
+        _ => resolve_item(scx, name, if_exists)?,
+    };
+    Ok(match item {
         Some(catalog_item) => {
             if catalog_item.id().is_system() {
                 sql_bail!(
@@ -3726,6 +4307,9 @@ fn plan_drop_item_inner(
                 );
             }
             let item_type = catalog_item.item_type();
+            if let Some((_, on)) = catalog_item.policy_details() {
+                ensure_policies_alterable(scx, scx.catalog.get_item(&on))?;
+            }
 
             // Return a more helpful error on `DROP VIEW <materialized-view>`.
             if object_type == ObjectType::View && item_type == CatalogItemType::MaterializedView {
@@ -3840,8 +4424,10 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
             | CatalogItemType::Sink
             | CatalogItemType::Type
             | CatalogItemType::Secret
-            | CatalogItemType::Connection => true,
-            CatalogItemType::Index => false,
+            | CatalogItemType::Connection
+            | CatalogItemType::Subscription => true,
+            // Policies are dropped along with the relation they restrict.
+            CatalogItemType::Index | CatalogItemType::Policy => false,
         },
     }
 }
@@ -3870,6 +4456,7 @@ pub fn plan_drop_owned(
     let role_ids: BTreeSet<_> = role_names.into_iter().map(|role| role.id).collect();
     let mut drop_ids = Vec::new();
     let mut privilege_revokes = Vec::new();
+    let mut column_privilege_revokes = Vec::new();
     let mut default_privilege_revokes = Vec::new();
 
     fn update_privilege_revokes(
@@ -3986,6 +4573,14 @@ pub fn plan_drop_owned(
             &role_ids,
             &mut privilege_revokes,
         );
+        for (column, privileges) in item.column_privileges() {
+            column_privilege_revokes.extend(
+                privileges
+                    .all_values()
+                    .filter(|privilege| role_ids.contains(&privilege.grantee))
+                    .map(|privilege| (item.id(), column.clone(), privilege.clone())),
+            );
+        }
     }
 
     // Schemas
@@ -4088,6 +4683,7 @@ pub fn plan_drop_owned(
         role_ids: role_ids.into_iter().collect(),
         drop_ids,
         privilege_revokes,
+        column_privilege_revokes,
         default_privilege_revokes,
     }))
 }
@@ -4421,7 +5017,8 @@ pub fn plan_alter_object_rename(
             | ObjectType::Index
             | ObjectType::Sink
             | ObjectType::Secret
-            | ObjectType::Connection,
+            | ObjectType::Connection
+            | ObjectType::Subscription,
             UnresolvedObjectName::Item(name),
         ) => plan_alter_item_rename(scx, object_type, name, to_item_name, if_exists),
         (ObjectType::Cluster, UnresolvedObjectName::Cluster(name)) => {
@@ -4765,6 +5362,111 @@ pub fn plan_alter_secret(
     Ok(Plan::AlterSecret(AlterSecretPlan { id, secret_as }))
 }
 
+pub fn describe_alter_subscription(
+    _: &StatementContext,
+    _: AlterSubscriptionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_subscription(
+    scx: &StatementContext,
+    stmt: AlterSubscriptionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_DURABLE_SUBSCRIPTIONS)?;
+
+    let AlterSubscriptionStatement {
+        name,
+        if_exists,
+        options,
+    } = stmt;
+    let name = normalize::unresolved_item_name(name)?;
+    let entry = match scx.catalog.resolve_item(&name) {
+        Ok(subscription) => subscription,
+        Err(_) if if_exists => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: name.to_string(),
+                object_type: ObjectType::Subscription,
+            });
+
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Subscription,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let Some((_, current_acknowledged)) = entry.subscription_details() else {
+        sql_bail!(
+            "\"{}\" is a {} not a subscription",
+            scx.catalog.resolve_full_name(entry.name()),
+            entry.item_type()
+        )
+    };
+
+    // Keep `RETAIN HISTORY` as written, for the subscription's definition.
+    let retain_history_value = options.iter().find_map(|option| match option {
+        SubscriptionOption {
+            name: SubscriptionOptionName::RetainHistory,
+            value: Some(mz_sql_parser::ast::WithOptionValue::RetainHistoryFor(value)),
+        } => Some(value.clone()),
+        _ => None,
+    });
+    let SubscriptionOptionExtracted {
+        acknowledged,
+        retain_history,
+        ..
+    } = options.try_into()?;
+    let acknowledged = plan_subscription_acknowledged(acknowledged)?;
+    if let (Some(acknowledged), Some(current)) = (acknowledged, current_acknowledged) {
+        if acknowledged < current {
+            sql_bail!(
+                "cannot acknowledge {} because {} has already been acknowledged",
+                acknowledged,
+                current
+            );
+        }
+    }
+    let retain_history = retain_history.map(|RetainHistoryFor(duration)| duration);
+
+    // Record the new options in the subscription's definition, so that they
+    // survive restarts.
+    let mut create_stmt = match mz_sql_parser::parser::parse_statements(entry.create_sql())?
+        .into_element()
+        .ast
+    {
+        Statement::CreateSubscription(stmt) => stmt,
+        _ => unreachable!("create SQL must be a CREATE SUBSCRIPTION statement"),
+    };
+    let mut set_option = |name: SubscriptionOptionName, value| {
+        create_stmt
+            .with_options
+            .retain(|option| option.name != name);
+        create_stmt.with_options.push(SubscriptionOption {
+            name,
+            value: Some(value),
+        });
+    };
+    if let Some(acknowledged) = acknowledged {
+        set_option(
+            SubscriptionOptionName::Acknowledged,
+            mz_sql_parser::ast::WithOptionValue::Value(Value::Number(acknowledged.to_string())),
+        );
+    }
+    if let Some(value) = retain_history_value {
+        set_option(
+            SubscriptionOptionName::RetainHistory,
+            mz_sql_parser::ast::WithOptionValue::RetainHistoryFor(value),
+        );
+    }
+
+    Ok(Plan::AlterSubscription(AlterSubscriptionPlan {
+        id: entry.id(),
+        create_sql: create_stmt.to_ast_string_stable(),
+        acknowledged,
+        retain_history,
+    }))
+}
+
 pub fn describe_alter_connection(
     _: &StatementContext,
     _: AlterConnectionStatement<Aug>,
@@ -5025,6 +5727,188 @@ pub fn plan_alter_sink(
     Ok(Plan::AlterSink(AlterSinkPlan { id, size }))
 }
 
+pub fn describe_alter_table(
+    _: &StatementContext,
+    _: AlterTableStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table(
+    scx: &mut StatementContext,
+    stmt: AlterTableStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let AlterTableStatement {
+        table_name,
+        if_exists,
+        action,
+    } = stmt;
+
+    let table_name = normalize::unresolved_item_name(table_name)?;
+    let entry = match scx.catalog.resolve_item(&table_name) {
+        Ok(table) => table,
+        Err(_) if if_exists => {
+            scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+                name: table_name.to_string(),
+                object_type: ObjectType::Table,
+            });
+
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Table,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.item_type() != CatalogItemType::Table {
+        sql_bail!("\"{}\" is a {} not a table", full_name, entry.item_type())
+    }
+    if entry.id().is_system() {
+        sql_bail!(
+            "cannot alter system table {}",
+            full_name.to_string().quoted()
+        )
+    }
+    let id = entry.id();
+
+    // Rebuild the table's definition from its create SQL, which has all of its
+    // names fully qualified.
+    let create_stmt = match mz_sql_parser::parser::parse_statements(entry.create_sql())?
+        .into_element()
+        .ast
+    {
+        Statement::CreateTable(stmt) => stmt,
+        _ => unreachable!("create SQL must be a CREATE TABLE statement"),
+    };
+    let (mut create_stmt, _) = crate::names::resolve(scx.catalog, create_stmt)?;
+    let table_str = full_name.item.clone();
+
+    let mut dropped = None;
+    match &action {
+        AlterTableAction::AddColumn {
+            if_col_not_exists,
+            column,
+        } => {
+            let column_name = normalize::column_name(column.name.clone());
+            if create_stmt
+                .columns
+                .iter()
+                .any(|c| normalize::column_name(c.name.clone()) == column_name)
+            {
+                if *if_col_not_exists {
+                    scx.catalog.add_notice(PlanNotice::ColumnAlreadyExists {
+                        column_name: column_name.to_string(),
+                        object_name: table_str,
+                    });
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} already exists",
+                    column_name.as_str().quoted(),
+                    table_str.quoted()
+                );
+            }
+
+            // The added column's constraints would not be checked against
+            // the rows already in the table, so it must have none.
+            for option in &column.options {
+                match &option.option {
+                    ColumnOption::Null | ColumnOption::Default(_) => (),
+                    ColumnOption::NotNull => {
+                        bail_unsupported!("ALTER TABLE ... ADD COLUMN ... NOT NULL")
+                    }
+                    other => bail_unsupported!(format!(
+                        "ALTER TABLE ... ADD COLUMN with column constraint: {}",
+                        other
+                    )),
+                }
+            }
+
+            create_stmt.columns.push(column.clone());
+        }
+        AlterTableAction::DropColumn {
+            if_col_exists,
+            name,
+        } => {
+            let column_name = normalize::column_name(name.clone());
+            let Some(position) = create_stmt
+                .columns
+                .iter()
+                .position(|c| normalize::column_name(c.name.clone()) == column_name)
+            else {
+                if *if_col_exists {
+                    scx.catalog.add_notice(PlanNotice::ColumnDoesNotExist {
+                        column_name: column_name.to_string(),
+                        object_name: table_str,
+                    });
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} does not exist",
+                    column_name.as_str().quoted(),
+                    table_str.quoted()
+                );
+            };
+
+            if create_stmt.columns.len() == 1 {
+                sql_bail!(
+                    "cannot drop column {} of table {} because it is the table's only column",
+                    column_name.as_str().quoted(),
+                    table_str.quoted()
+                );
+            }
+            let in_key = create_stmt.columns[position]
+                .options
+                .iter()
+                .any(|o| matches!(o.option, ColumnOption::Unique { .. }))
+                || create_stmt.constraints.iter().any(|c| match c {
+                    TableConstraint::Unique { columns, .. }
+                    | TableConstraint::ForeignKey { columns, .. } => columns
+                        .iter()
+                        .any(|c| normalize::column_name(c.clone()) == column_name),
+                    TableConstraint::Check { .. } => false,
+                });
+            if in_key {
+                bail_unsupported!("ALTER TABLE ... DROP COLUMN of a column used in a constraint");
+            }
+
+            create_stmt.columns.remove(position);
+            dropped = Some(position);
+        }
+    }
+
+    // The table already exists under this name, which is not an error here.
+    create_stmt.if_not_exists = true;
+    let table = match plan_create_table(scx, create_stmt)? {
+        Plan::CreateTable(CreateTablePlan { table, .. }) => table,
+        _ => unreachable!("plan_create_table must produce a CREATE TABLE plan"),
+    };
+
+    let action = match action {
+        AlterTableAction::AddColumn { .. } => {
+            let default = table.defaults.last().expect("table has an added column");
+            let ty = &table
+                .desc
+                .typ()
+                .column_types
+                .last()
+                .expect("table has an added column")
+                .scalar_type;
+            let default = query::plan_default_expr(scx, default, ty)?.lower_uncorrelated()?;
+            crate::plan::AlterTableAction::AddColumn { default }
+        }
+        AlterTableAction::DropColumn { .. } => crate::plan::AlterTableAction::DropColumn {
+            index: dropped.expect("table has a dropped column"),
+        },
+    };
+
+    Ok(Plan::AlterTable(AlterTablePlan { id, table, action }))
+}
+
 pub fn describe_alter_source(
     _: &StatementContext,
     _: AlterSourceStatement<Aug>,
@@ -5464,6 +6348,19 @@ pub(crate) fn resolve_item<'a>(
     }
 }
 
+pub(crate) fn resolve_function<'a>(
+    scx: &'a StatementContext,
+    name: UnresolvedItemName,
+    if_exists: bool,
+) -> Result<Option<&'a dyn CatalogItem>, PlanError> {
+    let name = normalize::unresolved_item_name(name)?;
+    match scx.catalog.resolve_function(&name) {
+        Ok(item) => Ok(Some(item)),
+        Err(_) if if_exists => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Returns an error if the given cluster is a linked cluster
 pub(crate) fn ensure_cluster_is_not_linked(
     scx: &StatementContext,
@@ -16,14 +16,15 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 
 use itertools::Itertools;
-use mz_expr::MirRelationExpr;
+use mz_expr::{MirRelationExpr, MirScalarExpr};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain::{ExplainConfig, ExplainFormat};
-use mz_repr::{RelationDesc, ScalarType};
+use mz_repr::{Datum, GlobalId, RelationDesc, ScalarType, Timestamp};
 use mz_sql_parser::ast::{
     ExplainSinkSchemaFor, ExplainSinkSchemaStatement, ExplainTimestampStatement, Expr,
-    IfExistsBehavior, OrderByExpr, SubscribeOutput, UnresolvedItemName,
+    IfExistsBehavior, ListenStatement, OrderByExpr, RawItemName, SubscribeOutput,
+    UnlistenStatement, UnresolvedItemName,
 };
 use mz_sql_parser::ident;
 use mz_storage_types::sinks::{KafkaSinkConnection, KafkaSinkFormat, StorageSinkConnection};
@@ -36,7 +37,7 @@ use crate::ast::{
     UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
-use crate::names::{Aug, ResolvedItemName};
+use crate::names::{self, Aug, ResolvedItemName};
 use crate::normalize;
 use crate::plan::query::{plan_up_to, ExprContext, QueryLifetime};
 use crate::plan::scope::Scope;
@@ -46,8 +47,9 @@ use crate::plan::{
     self, side_effecting_func, CreateSinkPlan, ExplainSinkSchemaPlan, ExplainTimestampPlan,
 };
 use crate::plan::{
-    query, CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan,
-    PlanError, QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan,
+    query, CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, ListenPlan, MutationKind, Params,
+    Plan, PlanError, QueryContext, QueryWhen, ReadThenWritePlan, SelectPlan, SubscribeFrom,
+    SubscribePlan, UnlistenPlan,
 };
 use crate::session::vars;
 
@@ -63,10 +65,12 @@ pub fn describe_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
 ) -> Result<StatementDesc, PlanError> {
-    let (_, _, returning) = query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (_, _, returning, _) =
+        query::plan_insert_query(scx, table_name, columns, source, on_conflict, returning)?;
     let desc = if returning.expr.is_empty() {
         None
     } else {
@@ -81,12 +85,13 @@ pub fn plan_insert(
         table_name,
         columns,
         source,
+        on_conflict,
         returning,
     }: InsertStatement<Aug>,
     params: &Params,
 ) -> Result<Plan, PlanError> {
-    let (id, mut expr, returning) =
-        query::plan_insert_query(scx, table_name, columns, source, returning)?;
+    let (id, mut expr, returning, conflicts) =
+        query::plan_insert_query(scx, table_name, columns, source, on_conflict, returning)?;
     expr.bind_parameters(params)?;
     let returning = returning
         .expr
@@ -98,6 +103,7 @@ pub fn plan_insert(
         id,
         values: expr,
         returning,
+        conflicts,
     }))
 }
 
@@ -162,6 +168,7 @@ pub fn plan_read_then_write(
         assignments: assignments_outer,
         kind,
         returning: Vec::new(),
+        conflicts: None,
     }))
 }
 
@@ -197,6 +204,7 @@ pub fn plan_select(
         when,
         finishing,
         copy_to,
+        column_references: scx.take_column_references(),
     }))
 }
 
@@ -371,6 +379,7 @@ pub fn plan_explain_plan(
                         column_names,
                         cluster_id,
                         non_null_assertions,
+                        refresh_schedule,
                         ..
                     },
                 ..
@@ -386,6 +395,7 @@ pub fn plan_explain_plan(
                 cluster_id,
                 broken,
                 non_null_assertions,
+                refresh_schedule,
             })
         }
         Explainee::CreateIndex(mut stmt, broken) => {
@@ -533,6 +543,7 @@ pub fn describe_subscribe(
 ) -> Result<StatementDesc, PlanError> {
     let relation_desc = match stmt.relation {
         SubscribeRelation::Name(name) => {
+            let (name, _) = resolve_subscription(scx, name)?;
             let item = scx.get_item_by_resolved_name(&name)?;
             item.desc(&scx.catalog.resolve_full_name(item.name()))?
                 .into_owned()
@@ -613,8 +624,11 @@ pub fn plan_subscribe(
     params: &Params,
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
+    let mut subscription = None;
     let (from, desc, scope) = match relation {
         SubscribeRelation::Name(name) => {
+            let (name, details) = resolve_subscription(scx, name)?;
+            subscription = details;
             let entry = scx.get_item_by_resolved_name(&name)?;
             let desc = match entry.desc(&scx.catalog.resolve_full_name(entry.name())) {
                 Ok(desc) => desc,
@@ -624,12 +638,28 @@ pub fn plan_subscribe(
                     entry.item_type(),
                 ),
             };
+            // Subscribing to a relation reads all of its columns.
+            for column in 0..desc.arity() {
+                scx.record_column_reference(entry.id(), column);
+            }
+            let from = if query::item_policies(scx, entry).is_empty() {
+                SubscribeFrom::Id(entry.id())
+            } else {
+                // Subscribe to the rows that the relation's row-level security
+                // policies make visible.
+                let qcx = QueryContext::root(scx, QueryLifetime::Subscribe);
+                let (expr, _) = qcx.resolve_table_name(name.clone())?;
+                SubscribeFrom::Query {
+                    expr: expr.lower(scx.catalog.system_vars())?,
+                    desc: desc.clone().into_owned(),
+                }
+            };
             let item_name = match name {
                 ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
                 _ => None,
             };
             let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
-            (SubscribeFrom::Id(entry.id()), desc.into_owned(), scope)
+            (from, desc.into_owned(), scope)
         }
         SubscribeRelation::Query(query) => {
             let query = plan_query(scx, query, params, QueryLifetime::Subscribe)?;
@@ -649,7 +679,23 @@ pub fn plan_subscribe(
         }
     };
 
-    let when = query::plan_as_of(scx, as_of)?;
+    let when = match subscription {
+        Some(_) if as_of.is_some() => {
+            sql_bail!("cannot specify AS OF when subscribing to a subscription")
+        }
+        // Resume from the acknowledged frontier: every update at a time
+        // before it has already been processed by the consumer.
+        Some((_, Some(acknowledged))) => {
+            let as_of = acknowledged
+                .step_back()
+                .expect("acknowledged frontiers are greater than zero");
+            QueryWhen::AtTimestamp(MirScalarExpr::literal_ok(
+                Datum::MzTimestamp(as_of),
+                ScalarType::MzTimestamp,
+            ))
+        }
+        Some((_, None)) | None => query::plan_as_of(scx, as_of)?,
+    };
     let up_to = up_to.map(|up_to| plan_up_to(scx, up_to)).transpose()?;
 
     let qcx = QueryContext::root(scx, QueryLifetime::Subscribe);
@@ -692,7 +738,6 @@ pub fn plan_subscribe(
             }
         }
         SubscribeOutput::EnvelopeDebezium { key_columns } => {
-            scx.require_feature_flag(&vars::ENABLE_ENVELOPE_DEBEZIUM_IN_SUBSCRIBE)?;
             let order_by = key_columns
                 .iter()
                 .map(|ident| OrderByExpr {
@@ -717,7 +762,6 @@ pub fn plan_subscribe(
             }
         }
         SubscribeOutput::WithinTimestampOrderBy { order_by } => {
-            scx.require_feature_flag(&vars::ENABLE_WITHIN_TIMESTAMP_ORDER_BY_IN_SUBSCRIBE)?;
             let mz_diff = "mz_diff".into();
             let output_columns = std::iter::once((0, &mz_diff))
                 .chain(output_columns.into_iter().map(|(i, c)| (i + 1, c)))
@@ -754,17 +798,104 @@ pub fn plan_subscribe(
     let SubscribeOptionExtracted {
         progress, snapshot, ..
     } = options.try_into()?;
+    // A resumed subscription has already delivered the snapshot, so by default
+    // only the updates since the acknowledged frontier are emitted.
+    let resumed = matches!(subscription, Some((_, Some(_))));
     Ok(Plan::Subscribe(SubscribePlan {
         from,
         when,
         up_to,
-        with_snapshot: snapshot.unwrap_or(true),
+        with_snapshot: snapshot.unwrap_or(!resumed),
         copy_to,
         emit_progress: progress.unwrap_or(false),
         output,
+        column_references: scx.take_column_references(),
+        subscription: subscription.map(|(id, _)| id),
     }))
 }
 
+/// Resolves the target of a `SUBSCRIBE` that may name a durable subscription.
+///
+/// If `name` refers to a subscription, returns the name of the relation that
+/// the subscription is on, along with the subscription's ID and acknowledged
+/// frontier. Otherwise returns `name` unchanged.
+fn resolve_subscription(
+    scx: &StatementContext,
+    name: ResolvedItemName,
+) -> Result<(ResolvedItemName, Option<(GlobalId, Option<Timestamp>)>), PlanError> {
+    let entry = scx.get_item_by_resolved_name(&name)?;
+    match entry.subscription_details() {
+        None => Ok((name, None)),
+        Some((on, acknowledged)) => {
+            let on = scx.get_item(&on);
+            let name = ResolvedItemName::Item {
+                id: on.id(),
+                qualifiers: on.name().qualifiers.clone(),
+                full_name: scx.catalog.resolve_full_name(on.name()),
+                print_id: true,
+            };
+            Ok((name, Some((entry.id(), acknowledged))))
+        }
+    }
+}
+
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    scx: &StatementContext,
+    ListenStatement { name }: ListenStatement,
+) -> Result<Plan, PlanError> {
+    let channel = listen_channel(&name);
+    // Listening on a relation subscribes to its changes. Unlike a `SUBSCRIBE`,
+    // the subscription outlives the statement, so the name is resolved here
+    // rather than with the rest of the statement.
+    let stmt = SubscribeStatement {
+        relation: SubscribeRelation::Name(RawItemName::Name(name)),
+        options: vec![],
+        as_of: None,
+        up_to: None,
+        output: SubscribeOutput::Diffs,
+    };
+    let (stmt, _) = names::resolve(scx.catalog, stmt)?;
+    let mut subscribe = match plan_subscribe(scx, stmt, &Params::empty(), None)? {
+        Plan::Subscribe(plan) => plan,
+        _ => unreachable!("plan_subscribe returns a subscribe plan"),
+    };
+    // Only changes that occur after the `LISTEN` are delivered.
+    subscribe.with_snapshot = false;
+    Ok(Plan::Listen(ListenPlan { channel, subscribe }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    _: &StatementContext,
+    UnlistenStatement { name }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: name.as_ref().map(listen_channel),
+    }))
+}
+
+/// Returns the name of the channel for a relation named `name` in a `LISTEN`
+/// or `UNLISTEN` statement.
+///
+/// The channel is named exactly as the relation was named in the statement,
+/// e.g., `LISTEN v` and `LISTEN public.v` listen on different channels.
+fn listen_channel(name: &UnresolvedItemName) -> String {
+    name.0.iter().map(|ident| ident.as_str()).join(".")
+}
+
 pub fn describe_table(
     scx: &StatementContext,
     table_name: <Aug as AstInfo>::ItemName,
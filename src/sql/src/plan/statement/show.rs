@@ -574,7 +574,9 @@ pub fn show_columns<'a>(
         | ty @ CatalogItemType::Func
         | ty @ CatalogItemType::Secret
         | ty @ CatalogItemType::Type
-        | ty @ CatalogItemType::Sink => {
+        | ty @ CatalogItemType::Sink
+        | ty @ CatalogItemType::Policy
+        | ty @ CatalogItemType::Subscription => {
             sql_bail!("{full_name} is a {ty} and so does not have columns");
         }
     }
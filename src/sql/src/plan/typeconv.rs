@@ -485,6 +485,9 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
         (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
         (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+        (String, Inet) => Explicit: CastStringToInet(func::CastStringToInet),
+        (String, Cidr) => Explicit: CastStringToCidr(func::CastStringToCidr),
+        (String, MacAddr) => Explicit: CastStringToMacAddr(func::CastStringToMacAddr),
         (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
             let return_ty = to_type.clone();
             let to_el_type = to_type.unwrap_array_element_type();
@@ -521,6 +524,10 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
                 cast_expr: Box::new(cast_expr),
             })))
         }),
+        (String, Enum) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+            let return_ty = to_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(CastStringToEnum(func::CastStringToEnum { return_ty })))
+        }),
         (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
         (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
             let length = to_type.unwrap_char_length();
@@ -670,6 +677,17 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         // UUID
         (Uuid, String) => Assignment: CastUuidToString(func::CastUuidToString),
 
+        // Inet
+        (Inet, String) => Assignment: CastInetToString(func::CastInetToString),
+        (Inet, Cidr) => Assignment: CastInetToCidr(func::CastInetToCidr),
+
+        // Cidr
+        (Cidr, String) => Assignment: CastCidrToString(func::CastCidrToString),
+        (Cidr, Inet) => Implicit: CastCidrToInet(func::CastCidrToInet),
+
+        // MacAddr
+        (MacAddr, String) => Assignment: CastMacAddrToString(func::CastMacAddrToString),
+
         // Numeric
         (Numeric, Numeric) => Assignment: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
             let scale = to_type.unwrap_numeric_max_scale();
@@ -694,6 +712,12 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             Some(|e: HirScalarExpr| e.call_unary(CastRangeToString(func::CastRangeToString { ty })))
         }),
 
+        // Enum
+        (Enum, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+            let ty = from_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(CastEnumToString(func::CastEnumToString { ty })))
+        }),
+
         // MzAclItem
         (MzAclItem, String) => Explicit: sql_impl_cast("(
                 SELECT
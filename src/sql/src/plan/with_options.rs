@@ -9,9 +9,11 @@
 
 //! Provides tooling to handle `WITH` options.
 
+use std::time::Duration;
+
 use mz_repr::adt::interval::Interval;
-use mz_repr::{strconv, GlobalId};
-use mz_sql_parser::ast::{Ident, KafkaBroker, ReplicaDefinition};
+use mz_repr::{strconv, GlobalId, Timestamp};
+use mz_sql_parser::ast::{Ident, KafkaBroker, RefreshOptionValue, ReplicaDefinition};
 use mz_storage_types::connections::StringOrSecret;
 use serde::{Deserialize, Serialize};
 
@@ -372,6 +374,26 @@ impl ImpliedValue for u64 {
     }
 }
 
+impl TryFromValue<Value> for Timestamp {
+    fn try_from_value(v: Value) -> Result<Self, PlanError> {
+        match v {
+            Value::Number(v) | Value::String(v) => v
+                .parse::<Timestamp>()
+                .map_err(|e| sql_err!("invalid mz_timestamp value: {e}")),
+            _ => sql_bail!("cannot use value as mz_timestamp"),
+        }
+    }
+    fn name() -> String {
+        "mz_timestamp".to_string()
+    }
+}
+
+impl ImpliedValue for Timestamp {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide an mz_timestamp value")
+    }
+}
+
 impl<V: TryFromValue<WithOptionValue<Aug>>> TryFromValue<WithOptionValue<Aug>> for Vec<V> {
     fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
         match v {
@@ -428,7 +450,9 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
             | WithOptionValue::Secret(_)
             | WithOptionValue::DataType(_)
             | WithOptionValue::ClusterReplicas(_)
-            | WithOptionValue::ConnectionKafkaBroker(_) => sql_bail!(
+            | WithOptionValue::ConnectionKafkaBroker(_)
+            | WithOptionValue::RetainHistoryFor(_)
+            | WithOptionValue::Refresh(_) => sql_bail!(
                 "incompatible value types: cannot convert {} to {}",
                 match v {
                     WithOptionValue::Sequence(_) => "sequences",
@@ -438,6 +462,8 @@ impl<V: TryFromValue<Value>, T: AstInfo + std::fmt::Debug> TryFromValue<WithOpti
                     WithOptionValue::DataType(_) => "data types",
                     WithOptionValue::ClusterReplicas(_) => "cluster replicas",
                     WithOptionValue::ConnectionKafkaBroker(_) => "connection kafka brokers",
+                    WithOptionValue::RetainHistoryFor(_) => "retain history",
+                    WithOptionValue::Refresh(_) => "refresh option values",
                     _ => unreachable!(),
                 },
                 V::name()
@@ -500,8 +526,52 @@ impl TryFromValue<WithOptionValue<Aug>> for Vec<KafkaBroker<Aug>> {
     }
 }
 
+impl TryFromValue<WithOptionValue<Aug>> for RefreshOptionValue<Aug> {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::Refresh(refresh) => Ok(refresh),
+            _ => sql_bail!("cannot use value as a refresh option"),
+        }
+    }
+    fn name() -> String {
+        "refresh option value".to_string()
+    }
+}
+
+impl ImpliedValue for RefreshOptionValue<Aug> {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a refresh option value")
+    }
+}
+
 impl ImpliedValue for Vec<KafkaBroker<Aug>> {
     fn implied_value() -> Result<Self, PlanError> {
         sql_bail!("must provide a kafka broker")
     }
 }
+
+/// The value of a `RETAIN HISTORY FOR <interval>` option, i.e. how long
+/// historical versions of an object's data should be kept readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RetainHistoryFor(pub Duration);
+
+impl TryFromValue<WithOptionValue<Aug>> for RetainHistoryFor {
+    fn try_from_value(v: WithOptionValue<Aug>) -> Result<Self, PlanError> {
+        match v {
+            WithOptionValue::RetainHistoryFor(value) => {
+                let interval = Interval::try_from_value(value)?;
+                Ok(RetainHistoryFor(interval.duration()?))
+            }
+            _ => sql_bail!("cannot use value as retain history"),
+        }
+    }
+    fn name() -> String {
+        "retain history".to_string()
+    }
+}
+
+impl ImpliedValue for RetainHistoryFor {
+    fn implied_value() -> Result<Self, PlanError> {
+        sql_bail!("must provide a RETAIN HISTORY FOR value")
+    }
+}
@@ -19,6 +19,7 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use mz_ccsr::{Client, GetByIdError, GetBySubjectError, Schema as CcsrSchema};
 use mz_kafka_util::client::{MzClientContext, DEFAULT_FETCH_METADATA_TIMEOUT};
+use mz_ore::collections::CollectionExt;
 use mz_ore::error::ErrorExt;
 use mz_ore::iter::IteratorExt;
 use mz_ore::str::StrExt;
@@ -26,13 +27,13 @@ use mz_proto::RustType;
 use mz_repr::{strconv, GlobalId};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
-    AlterSourceAction, AlterSourceAddSubsourceOptionName, AlterSourceStatement, AvroDocOn,
-    CreateSinkConnection, CreateSinkStatement, CreateSubsourceOption, CreateSubsourceOptionName,
-    CsrConfigOption, CsrConfigOptionName, CsrConnection, CsrSeedAvro, CsrSeedProtobuf,
-    CsrSeedProtobufSchema, DbzMode, DeferredItemName, DocOnIdentifier, DocOnSchema, Envelope,
-    Ident, KafkaConfigOption, KafkaConfigOptionName, KafkaConnection, KafkaSourceConnection,
-    PgConfigOption, PgConfigOptionName, RawItemName, ReaderSchemaSelectionStrategy, Statement,
-    UnresolvedItemName,
+    AlterSourceAction, AlterSourceAddSubsourceOptionName, AlterSourceStatement, AstInfo, AvroDocOn,
+    CreateSinkConnection, CreateSinkStatement, CreateSourceOption, CreateSourceOptionName,
+    CreateSubsourceOption, CreateSubsourceOptionName, CsrConfigOption, CsrConfigOptionName,
+    CsrConnection, CsrSeedAvro, CsrSeedProtobuf, CsrSeedProtobufSchema, DbzMode, DeferredItemName,
+    DocOnIdentifier, DocOnSchema, Envelope, Ident, KafkaConfigOption, KafkaConfigOptionName,
+    KafkaConnection, KafkaSourceConnection, PgConfigOption, PgConfigOptionName, RawItemName,
+    ReaderSchemaSelectionStrategy, Statement, UnresolvedItemName,
 };
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::connections::{Connection, ConnectionContext};
@@ -135,6 +136,29 @@ fn subsource_name_gen(
     Ok(UnresolvedItemName::from(partial))
 }
 
+/// Copies the `RETAIN HISTORY` option of a source, if any, to its subsources,
+/// so that they retain as much history as the source itself.
+fn retain_history_subsources<T: AstInfo>(
+    source_options: &[CreateSourceOption<T>],
+    subsources: &mut [(GlobalId, CreateSubsourceStatement<Aug>)],
+) {
+    let retain_history = source_options.iter().find_map(|option| match option {
+        CreateSourceOption {
+            name: CreateSourceOptionName::RetainHistory,
+            value: Some(WithOptionValue::RetainHistoryFor(value)),
+        } => Some(value),
+        _ => None,
+    });
+    if let Some(value) = retain_history {
+        for (_, subsource) in subsources {
+            subsource.with_options.push(CreateSubsourceOption {
+                name: CreateSubsourceOptionName::RetainHistory,
+                value: Some(WithOptionValue::RetainHistoryFor(value.clone())),
+            });
+        }
+    }
+}
+
 /// Purifies a statement, removing any dependencies on external state.
 ///
 /// See the section on [purification](crate#purification) in the crate
@@ -404,6 +428,7 @@ async fn purify_create_source(
         include_metadata: _,
         referenced_subsources,
         progress_subsource,
+        with_options,
         ..
     } = &mut stmt;
 
@@ -859,6 +884,8 @@ async fn purify_create_source(
     };
     subsources.push((transient_id, subsource));
 
+    retain_history_subsources(with_options, &mut subsources);
+
     purify_source_format(&catalog, format, connection, envelope, &connection_context).await?;
 
     Ok((subsources, Statement::CreateSource(stmt)))
@@ -889,7 +916,7 @@ async fn purify_alter_source(
     } = &mut stmt;
 
     // Get connection
-    let pg_source_connection = {
+    let (pg_source_connection, source_create_sql) = {
         // Get name.
         let item = match scx.resolve_item(RawItemName::Name(source_name.clone())) {
             Ok(item) => item,
@@ -913,7 +940,9 @@ async fn purify_alter_source(
         }
 
         match desc.connection {
-            GenericSourceConnection::Postgres(pg_connection) => pg_connection,
+            GenericSourceConnection::Postgres(pg_connection) => {
+                (pg_connection, item.create_sql().to_string())
+            }
             _ => sql_bail!(
                 "{} is a {} source, which does not support ALTER TABLE...ADD SUBSOURCES",
                 scx.catalog.minimal_qualification(item.name()),
@@ -1040,7 +1069,7 @@ async fn purify_alter_source(
         text_cols_option.value = Some(WithOptionValue::Sequence(seq));
     }
 
-    let (named_subsources, new_subsources) = postgres::generate_targeted_subsources(
+    let (named_subsources, mut new_subsources) = postgres::generate_targeted_subsources(
         &scx,
         validated_requested_subsources,
         text_cols_dict,
@@ -1050,6 +1079,17 @@ async fn purify_alter_source(
 
     *targeted_subsources = named_subsources;
 
+    // The new subsources retain as much history as the existing source.
+    match mz_sql_parser::parser::parse_statements(&source_create_sql)?
+        .into_element()
+        .ast
+    {
+        Statement::CreateSource(CreateSourceStatement { with_options, .. }) => {
+            retain_history_subsources(&with_options, &mut new_subsources)
+        }
+        _ => unreachable!("create SQL must be a CREATE SOURCE statement"),
+    }
+
     // An index from table name -> output index.
     let mut new_name_to_output_map = BTreeMap::new();
     for (i, table) in publication_tables.iter().enumerate() {
@@ -17,7 +17,7 @@ use mz_expr::CollectionPlan;
 use mz_ore::str::StrExt;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
-use mz_repr::GlobalId;
+use mz_repr::{ColumnName, GlobalId};
 use mz_sql_parser::ast::{Ident, QualifiedReplica};
 use once_cell::sync::Lazy;
 use tracing::debug;
@@ -87,9 +87,10 @@ macro_rules! rbac_preamble {
     };
 }
 
-// The default item types that most statements require USAGE privileges for.
+// The default item types that most statements require USAGE privileges for. Functions require
+// EXECUTE privileges instead of USAGE privileges.
 static DEFAULT_ITEM_USAGE: Lazy<BTreeSet<CatalogItemType>> = Lazy::new(|| {
-    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection}
+    btreeset! {CatalogItemType::Secret, CatalogItemType::Connection, CatalogItemType::Func}
 });
 // CREATE statements require USAGE privileges on the default item types and USAGE privileges on
 // Types.
@@ -163,6 +164,10 @@ struct RbacRequirements {
     /// The privileges required. The tuples are of the form:
     /// (What object the privilege is on, What privilege is required, Who must possess the privilege).
     privileges: Vec<(SystemObjectId, AclMode, RoleId)>,
+    /// The relations that the plan reads directly, mapped to the columns it references. For these
+    /// relations, a required `SELECT` privilege is also satisfied by `SELECT` privileges on all of
+    /// the referenced columns, or on any column if none are referenced.
+    column_reads: BTreeMap<GlobalId, BTreeSet<usize>>,
     /// The types of catalog items that this plan requires USAGE privileges on.
     ///
     /// Most plans will require USAGE on secrets and connections but some plans, like SHOW CREATE,
@@ -220,6 +225,7 @@ impl RbacRequirements {
         check_object_privileges(
             catalog,
             self.privileges,
+            &self.column_reads,
             role_membership,
             role_metadata.current_role,
         )?;
@@ -238,6 +244,7 @@ impl Default for RbacRequirements {
             role_membership: BTreeSet::new(),
             ownership: Vec::new(),
             privileges: Vec::new(),
+            column_reads: BTreeMap::new(),
             item_usage: &DEFAULT_ITEM_USAGE,
             superuser_action: None,
         }
@@ -278,6 +285,7 @@ pub fn check_usage(
     check_object_privileges(
         catalog,
         required_privileges,
+        &BTreeMap::new(),
         role_membership,
         role_metadata.current_role,
     )?;
@@ -373,10 +381,13 @@ fn generate_rbac_requirements(
         }
         Plan::CreateRole(plan::CreateRolePlan {
             name: _,
-            attributes: _,
+            attributes,
         }) => RbacRequirements {
             privileges: vec![(SystemObjectId::System, AclMode::CREATE_ROLE, role_id)],
             item_usage: &CREATE_ITEM_USAGE,
+            superuser_action: attributes
+                .bypass_rls
+                .then(|| "CREATE ROLE ... BYPASSRLS".to_string()),
             ..Default::default()
         },
         Plan::CreateCluster(plan::CreateClusterPlan {
@@ -572,6 +583,54 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::CreateFunction(plan::CreateFunctionPlan {
+            name,
+            function: _,
+            replace,
+            in_place: _,
+        }) => RbacRequirements {
+            ownership: replace
+                .map(|id| vec![ObjectId::Item(id)])
+                .unwrap_or_default(),
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreatePolicy(plan::CreatePolicyPlan { name, policy }) => RbacRequirements {
+            // Only the owner of a relation may restrict who can see its rows.
+            ownership: vec![ObjectId::Item(policy.on)],
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreateSubscription(plan::CreateSubscriptionPlan {
+            name,
+            subscription,
+            if_not_exists: _,
+        }) => {
+            // A subscription retains the changes to its relation, so creating
+            // one requires permission to read the relation.
+            let mut privileges =
+                generate_read_privileges(catalog, iter::once(subscription.on), role_id);
+            privileges.push((
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            ));
+            RbacRequirements {
+                privileges,
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::Comment(plan::CommentPlan {
             object_id,
             sub_component: _,
@@ -685,6 +744,7 @@ fn generate_rbac_requirements(
             when: _,
             finishing: _,
             copy_to: _,
+            column_references,
         }) => {
             let mut privileges =
                 generate_read_privileges(catalog, source.depends_on().into_iter(), role_id);
@@ -697,6 +757,11 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(
+                    catalog,
+                    source.depends_on().into_iter(),
+                    column_references,
+                ),
                 ..Default::default()
             }
         }
@@ -708,6 +773,23 @@ fn generate_rbac_requirements(
             copy_to: _,
             emit_progress: _,
             output: _,
+            column_references,
+            subscription: _,
+        })
+        | Plan::Listen(plan::ListenPlan {
+            channel: _,
+            subscribe:
+                plan::SubscribePlan {
+                    from,
+                    with_snapshot: _,
+                    when: _,
+                    up_to: _,
+                    copy_to: _,
+                    emit_progress: _,
+                    output: _,
+                    column_references,
+                    subscription: _,
+                },
         }) => {
             let mut privileges =
                 generate_read_privileges(catalog, from.depends_on().into_iter(), role_id);
@@ -720,6 +802,11 @@ fn generate_rbac_requirements(
             }
             RbacRequirements {
                 privileges,
+                column_reads: generate_column_reads(
+                    catalog,
+                    from.depends_on().into_iter(),
+                    column_references,
+                ),
                 ..Default::default()
             }
         }
@@ -796,6 +883,7 @@ fn generate_rbac_requirements(
             id,
             values,
             returning,
+            conflicts,
         }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
@@ -806,6 +894,17 @@ fn generate_rbac_requirements(
                 ),
                 (SystemObjectId::Object(id.into()), AclMode::INSERT, role_id),
             ];
+            if let Some(plan::InsertConflicts {
+                on_conflict:
+                    plan::OnConflict {
+                        action: plan::OnConflictAction::DoUpdate { .. },
+                        ..
+                    },
+                ..
+            }) = conflicts
+            {
+                privileges.push((SystemObjectId::Object(id.into()), AclMode::UPDATE, role_id));
+            }
             let mut seen = BTreeSet::from([(schema_id, role_id)]);
 
             // We don't allow arbitrary sub-queries in `returning`. So either it
@@ -885,6 +984,15 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterTable(plan::AlterTablePlan {
+            id,
+            table: _,
+            action: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterConnection(plan::AlterConnectionPlan { id, action: _ }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*id)],
             ..Default::default()
@@ -1031,6 +1139,15 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterSubscription(plan::AlterSubscriptionPlan {
+            id,
+            acknowledged: _,
+            retain_history: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterRole(plan::AlterRolePlan {
             id,
             name: _,
@@ -1040,6 +1157,19 @@ fn generate_rbac_requirements(
             plan::PlannedAlterRoleOption::Variable(_) if role_id == *id => {
                 RbacRequirements::default()
             }
+            // Only superusers can change whether a role bypasses row-level security.
+            plan::PlannedAlterRoleOption::Attributes(attrs) if attrs.bypass_rls.is_some() => {
+                RbacRequirements {
+                    superuser_action: Some("ALTER ROLE ... BYPASSRLS".to_string()),
+                    ..Default::default()
+                }
+            }
+            // Roles are allowed to change their own password, but no other attributes.
+            plan::PlannedAlterRoleOption::Attributes(attrs)
+                if role_id == *id && attrs.inherit.is_none() =>
+            {
+                RbacRequirements::default()
+            }
             // Otherwise to ALTER a role, you need to have the CREATE_ROLE privilege.
             _ => RbacRequirements {
                 privileges: vec![(SystemObjectId::System, AclMode::CREATE_ROLE, role_id)],
@@ -1094,6 +1224,7 @@ fn generate_rbac_requirements(
             assignments,
             kind,
             returning,
+            conflicts: _,
         }) => {
             let acl_mode = match kind {
                 MutationKind::Insert => AclMode::INSERT,
@@ -1339,6 +1470,7 @@ fn generate_rbac_requirements(
             timeout: _,
         })
         | Plan::Close(plan::ClosePlan { name: _ })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ })
         | Plan::Prepare(plan::PreparePlan {
             name: _,
             stmt: _,
@@ -1479,7 +1611,15 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
-                CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
+                // The bodies of SQL functions are inlined into the calling query, so the
+                // objects they read must be readable by the calling role, not the owner.
+                CatalogItemType::Func => {
+                    views.push((item.references().0.clone().into_iter(), role_id));
+                }
+                CatalogItemType::Sink
+                | CatalogItemType::Index
+                | CatalogItemType::Policy
+                | CatalogItemType::Subscription => {}
             }
         }
     }
@@ -1493,6 +1633,37 @@ fn generate_read_privileges_inner(
     privileges
 }
 
+/// Returns the entries of `column_references` whose relations may be read with column privileges.
+///
+/// Relations that are also read through a view, materialized view, or function body in `ids` are
+/// excluded, as the columns that those read are not tracked.
+fn generate_column_reads(
+    catalog: &impl SessionCatalog,
+    ids: impl Iterator<Item = GlobalId>,
+    column_references: &BTreeMap<GlobalId, BTreeSet<usize>>,
+) -> BTreeMap<GlobalId, BTreeSet<usize>> {
+    let mut read_through_views = BTreeSet::new();
+    let mut to_visit: Vec<_> = ids.collect();
+    while let Some(id) = to_visit.pop() {
+        let item = catalog.get_item(&id);
+        if matches!(
+            item.item_type(),
+            CatalogItemType::View | CatalogItemType::MaterializedView | CatalogItemType::Func
+        ) {
+            for reference in &item.references().0 {
+                if read_through_views.insert(*reference) {
+                    to_visit.push(*reference);
+                }
+            }
+        }
+    }
+    column_references
+        .iter()
+        .filter(|(id, _)| !read_through_views.contains(*id))
+        .map(|(id, columns)| (*id, columns.clone()))
+        .collect()
+}
+
 fn generate_usage_privileges(
     catalog: &impl SessionCatalog,
     ids: &ResolvedIds,
@@ -1505,10 +1676,16 @@ fn generate_usage_privileges(
         .filter_map(move |id| {
             let item = catalog.get_item(id);
             if item_types.contains(&item.item_type()) {
+                let acl_mode = match item.item_type() {
+                    // Builtin functions are always executable.
+                    CatalogItemType::Func if id.is_system() => return None,
+                    CatalogItemType::Func => AclMode::EXECUTE,
+                    _ => AclMode::USAGE,
+                };
                 let schema_id = item.name().qualifiers.clone().into();
                 Some([
                     (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
-                    (SystemObjectId::Object(id.into()), AclMode::USAGE, role_id),
+                    (SystemObjectId::Object(id.into()), acl_mode, role_id),
                 ])
             } else {
                 None
@@ -1541,6 +1718,7 @@ fn generate_cluster_usage_privileges(
 fn check_object_privileges(
     catalog: &impl SessionCatalog,
     privileges: Vec<(SystemObjectId, AclMode, RoleId)>,
+    column_reads: &BTreeMap<GlobalId, BTreeSet<usize>>,
     role_membership: BTreeSet<RoleId>,
     current_role_id: RoleId,
 ) -> Result<(), UnauthorizedError> {
@@ -1558,7 +1736,20 @@ fn check_object_privileges(
             .flat_map(|role_id| object_privileges.get_acl_items_for_grantee(role_id))
             .map(|mz_acl_item| mz_acl_item.acl_mode)
             .fold(AclMode::empty(), |accum, acl_mode| accum.union(acl_mode));
-        if !role_privileges.contains(acl_mode) {
+        if role_privileges.contains(acl_mode) {
+            continue;
+        }
+        let satisfied_by_columns = match &object_id {
+            SystemObjectId::Object(ObjectId::Item(id))
+                if acl_mode == AclMode::SELECT && role_id == current_role_id =>
+            {
+                column_reads.get(id).map_or(false, |columns| {
+                    check_column_privileges(catalog, id, columns, role_membership)
+                })
+            }
+            _ => false,
+        };
+        if !satisfied_by_columns {
             return Err(UnauthorizedError::Privilege {
                 object_description: ErrorMessageObjectDescription::from_sys_id(&object_id, catalog),
             });
@@ -1568,6 +1759,39 @@ fn check_object_privileges(
     Ok(())
 }
 
+/// Reports whether `role_membership` holds `SELECT` on each of `columns` of the relation `id`, or
+/// on any of its columns if `columns` is empty.
+fn check_column_privileges(
+    catalog: &impl SessionCatalog,
+    id: &GlobalId,
+    columns: &BTreeSet<usize>,
+    role_membership: &BTreeSet<RoleId>,
+) -> bool {
+    let item = catalog.get_item(id);
+    let column_privileges = item.column_privileges();
+    if column_privileges.is_empty() {
+        return false;
+    }
+    let Ok(desc) = item.desc(&catalog.resolve_full_name(item.name())) else {
+        return false;
+    };
+    let can_select = |name: &ColumnName| {
+        column_privileges.get(name).map_or(false, |privileges| {
+            role_membership
+                .iter()
+                .flat_map(|role_id| privileges.get_acl_items_for_grantee(role_id))
+                .any(|mz_acl_item| mz_acl_item.acl_mode.contains(AclMode::SELECT))
+        })
+    };
+    if columns.is_empty() {
+        desc.iter_names().any(can_select)
+    } else {
+        columns
+            .iter()
+            .all(|column| can_select(desc.get_name(*column)))
+    }
+}
+
 pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
     const TABLE_ACL_MODE: AclMode = AclMode::INSERT
         .union(AclMode::SELECT)
@@ -1593,7 +1817,9 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Connection) => AclMode::USAGE,
         SystemObjectType::Object(ObjectType::Database) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
-        SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Func) => AclMode::EXECUTE,
+        SystemObjectType::Object(ObjectType::Policy) => EMPTY_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Subscription) => EMPTY_ACL_MODE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -1621,7 +1847,9 @@ const fn default_builtin_object_acl_mode(object_type: ObjectType) -> AclMode {
         | ObjectType::Secret
         | ObjectType::Connection
         | ObjectType::Database
-        | ObjectType::Func => AclMode::empty(),
+        | ObjectType::Func
+        | ObjectType::Policy
+        | ObjectType::Subscription => AclMode::empty(),
     }
 }
 
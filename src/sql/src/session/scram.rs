@@ -0,0 +1,488 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Password storage and authentication with SCRAM-SHA-256.
+//!
+//! Passwords are never stored in plain text. Instead we store a
+//! [`ScramVerifier`], which contains enough information to verify a
+//! [SCRAM-SHA-256] exchange or a plain text password, but not enough to
+//! recover the password. The verifier uses the same textual representation as
+//! PostgreSQL.
+//!
+//! Unlike PostgreSQL, passwords are not normalized with SASLprep before they
+//! are hashed.
+//!
+//! [SCRAM-SHA-256]: https://datatracker.ietf.org/doc/html/rfc7677
+
+use std::fmt;
+
+use once_cell::sync::OnceCell;
+use openssl::error::ErrorStack;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+/// The name of the SASL mechanism implemented by this module.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+
+/// The number of PBKDF2 iterations used for new verifiers. Matches the default
+/// of PostgreSQL's `scram_iterations`.
+const DEFAULT_ITERATIONS: u32 = 4096;
+
+/// The length of the salt, in bytes, used for new verifiers.
+const SALT_LEN: usize = 16;
+
+/// The length of the server nonce, in bytes, before base64 encoding.
+const NONCE_LEN: usize = 18;
+
+/// The key from which the salts of mock verifiers are derived. Generated once
+/// per process, like PostgreSQL's `mock_auth_nonce` is once per cluster.
+static MOCK_AUTH_SECRET: OnceCell<Vec<u8>> = OnceCell::new();
+
+/// An error during a SCRAM exchange.
+#[derive(Debug, thiserror::Error)]
+pub enum ScramError {
+    /// The client sent a message that does not follow RFC 5802.
+    #[error("malformed SCRAM message")]
+    Malformed,
+    /// The client requested channel binding, which we do not support.
+    #[error("channel binding is not supported")]
+    ChannelBindingUnsupported,
+    /// The client's proof does not match the stored verifier.
+    #[error("invalid password")]
+    InvalidPassword,
+    /// A cryptographic primitive failed.
+    #[error(transparent)]
+    OpenSsl(#[from] ErrorStack),
+}
+
+/// The stored form of a role's password.
+///
+/// Displays as `SCRAM-SHA-256$<iterations>:<salt>$<StoredKey>:<ServerKey>`,
+/// with the salt and keys base64-encoded.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ScramVerifier {
+    iterations: u32,
+    salt: Vec<u8>,
+    stored_key: Vec<u8>,
+    server_key: Vec<u8>,
+}
+
+impl ScramVerifier {
+    /// Hashes `password` with a freshly generated salt.
+    pub fn hash(password: &str) -> Result<ScramVerifier, ErrorStack> {
+        let mut salt = vec![0; SALT_LEN];
+        openssl::rand::rand_bytes(&mut salt)?;
+        Self::with_salt(password, salt, DEFAULT_ITERATIONS)
+    }
+
+    fn with_salt(
+        password: &str,
+        salt: Vec<u8>,
+        iterations: u32,
+    ) -> Result<ScramVerifier, ErrorStack> {
+        let salted_password = salted_password(password, &salt, iterations)?;
+        let client_key = hmac(&salted_password, b"Client Key")?;
+        let server_key = hmac(&salted_password, b"Server Key")?;
+        Ok(ScramVerifier {
+            iterations,
+            salt,
+            stored_key: openssl::sha::sha256(&client_key).to_vec(),
+            server_key,
+        })
+    }
+
+    /// Returns a verifier for the role named `role_name` with random keys,
+    /// which no proof or password matches.
+    ///
+    /// Used to carry out an exchange for a role that does not exist or has no
+    /// password, so that clients cannot tell such roles apart from roles with
+    /// a password until the exchange fails. The salt is derived from the role
+    /// name, so that, like a real verifier's, it is the same on every attempt.
+    fn mock(role_name: &str) -> Result<ScramVerifier, ErrorStack> {
+        let secret = MOCK_AUTH_SECRET.get_or_try_init(|| {
+            let mut secret = vec![0; 32];
+            openssl::rand::rand_bytes(&mut secret)?;
+            Ok::<_, ErrorStack>(secret)
+        })?;
+        let mut salt = hmac(secret, role_name.as_bytes())?;
+        salt.truncate(SALT_LEN);
+        let mut verifier = ScramVerifier {
+            iterations: DEFAULT_ITERATIONS,
+            salt,
+            stored_key: vec![0; 32],
+            server_key: vec![0; 32],
+        };
+        openssl::rand::rand_bytes(&mut verifier.stored_key)?;
+        openssl::rand::rand_bytes(&mut verifier.server_key)?;
+        Ok(verifier)
+    }
+
+    /// Parses the textual representation of a verifier, as produced by its
+    /// `Display` implementation.
+    pub fn parse(s: &str) -> Option<ScramVerifier> {
+        let rest = s.strip_prefix(SCRAM_SHA_256)?.strip_prefix('$')?;
+        let (params, keys) = rest.split_once('$')?;
+        let (iterations, salt) = params.split_once(':')?;
+        let (stored_key, server_key) = keys.split_once(':')?;
+        let verifier = ScramVerifier {
+            iterations: iterations.parse().ok()?,
+            salt: openssl::base64::decode_block(salt).ok()?,
+            stored_key: openssl::base64::decode_block(stored_key).ok()?,
+            server_key: openssl::base64::decode_block(server_key).ok()?,
+        };
+        if verifier.iterations == 0 || verifier.stored_key.len() != 32 {
+            return None;
+        }
+        Some(verifier)
+    }
+
+    /// Reports whether `password` is the password this verifier was created
+    /// from.
+    ///
+    /// Used by authentication methods that receive the password in plain text,
+    /// like HTTP basic authentication.
+    pub fn verify_password(&self, password: &str) -> bool {
+        match Self::with_salt(password, self.salt.clone(), self.iterations) {
+            Ok(candidate) => openssl::memcmp::eq(&candidate.stored_key, &self.stored_key),
+            Err(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}${}:{}${}:{}",
+            SCRAM_SHA_256,
+            self.iterations,
+            openssl::base64::encode_block(&self.salt),
+            openssl::base64::encode_block(&self.stored_key),
+            openssl::base64::encode_block(&self.server_key),
+        )
+    }
+}
+
+impl fmt::Debug for ScramVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScramVerifier")
+            .field("iterations", &self.iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The server side of a SCRAM-SHA-256 exchange, after the server has answered
+/// the client's first message.
+///
+/// The exchange goes as follows:
+///
+///   1. The client sends its first message, which [`ScramExchange::start`]
+///      answers with [`ScramExchange::server_first_message`].
+///   2. The client sends its final message, which contains a proof that it
+///      knows the password. [`ScramExchange::finish`] checks the proof and
+///      returns the server's final message, which in turn proves to the
+///      client that the server knows the verifier.
+pub struct ScramExchange {
+    verifier: ScramVerifier,
+    gs2_header: String,
+    client_first_message_bare: String,
+    server_first_message: String,
+    nonce: String,
+}
+
+impl ScramExchange {
+    /// Starts an exchange for the role named `role_name` in response to the
+    /// client's first message.
+    ///
+    /// If `verifier` is `None`, e.g. because the role does not exist or has no
+    /// password, the exchange proceeds normally but [`ScramExchange::finish`]
+    /// always fails.
+    pub fn start(
+        role_name: &str,
+        verifier: Option<ScramVerifier>,
+        client_first_message: &[u8],
+    ) -> Result<ScramExchange, ScramError> {
+        let client_first_message =
+            std::str::from_utf8(client_first_message).map_err(|_| ScramError::Malformed)?;
+
+        // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+        let (cbind_flag, rest) = client_first_message
+            .split_once(',')
+            .ok_or(ScramError::Malformed)?;
+        match cbind_flag {
+            "n" | "y" => (),
+            _ if cbind_flag.starts_with("p=") => return Err(ScramError::ChannelBindingUnsupported),
+            _ => return Err(ScramError::Malformed),
+        }
+        let (authzid, client_first_message_bare) =
+            rest.split_once(',').ok_or(ScramError::Malformed)?;
+        if !authzid.is_empty() {
+            // We authenticate the user named in the startup message, and do
+            // not support acting on behalf of another user.
+            return Err(ScramError::Malformed);
+        }
+        let gs2_header = format!("{cbind_flag},{authzid},");
+
+        // client-first-message-bare = [ reserved-mext "," ] username "," nonce
+        //                             [ "," extensions ]
+        //
+        // The username is ignored, as the PostgreSQL protocol already
+        // specified it in the startup message.
+        let mut attrs = client_first_message_bare.split(',');
+        match attrs.next() {
+            Some(username) if username.starts_with("n=") => (),
+            _ => return Err(ScramError::Malformed),
+        }
+        let client_nonce = match attrs.next().and_then(|attr| attr.strip_prefix("r=")) {
+            Some(nonce) if !nonce.is_empty() && is_printable(nonce) => nonce,
+            _ => return Err(ScramError::Malformed),
+        };
+
+        let verifier = match verifier {
+            Some(verifier) => verifier,
+            None => ScramVerifier::mock(role_name)?,
+        };
+        let mut server_nonce = [0; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut server_nonce)?;
+        let nonce = format!(
+            "{client_nonce}{}",
+            openssl::base64::encode_block(&server_nonce)
+        );
+        let server_first_message = format!(
+            "r={nonce},s={},i={}",
+            openssl::base64::encode_block(&verifier.salt),
+            verifier.iterations,
+        );
+
+        Ok(ScramExchange {
+            verifier,
+            gs2_header,
+            client_first_message_bare: client_first_message_bare.into(),
+            server_first_message,
+            nonce,
+        })
+    }
+
+    /// Returns the message to send to the client in response to its first
+    /// message.
+    pub fn server_first_message(&self) -> &str {
+        &self.server_first_message
+    }
+
+    /// Checks the proof in the client's final message, returning the server's
+    /// final message if the proof is valid.
+    pub fn finish(self, client_final_message: &[u8]) -> Result<String, ScramError> {
+        let client_final_message =
+            std::str::from_utf8(client_final_message).map_err(|_| ScramError::Malformed)?;
+
+        // client-final-message = client-final-message-without-proof "," proof
+        let (without_proof, proof) = client_final_message
+            .rsplit_once(",p=")
+            .ok_or(ScramError::Malformed)?;
+        let proof = openssl::base64::decode_block(proof).map_err(|_| ScramError::Malformed)?;
+
+        // client-final-message-without-proof = channel-binding "," nonce
+        //                                      [ "," extensions ]
+        let mut attrs = without_proof.split(',');
+        let channel_binding = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("c="))
+            .ok_or(ScramError::Malformed)?;
+        let channel_binding =
+            openssl::base64::decode_block(channel_binding).map_err(|_| ScramError::Malformed)?;
+        if channel_binding != self.gs2_header.as_bytes() {
+            return Err(ScramError::Malformed);
+        }
+        match attrs.next().and_then(|attr| attr.strip_prefix("r=")) {
+            Some(nonce) if nonce == self.nonce => (),
+            _ => return Err(ScramError::Malformed),
+        }
+
+        let auth_message = format!(
+            "{},{},{}",
+            self.client_first_message_bare, self.server_first_message, without_proof
+        );
+        let client_signature = hmac(&self.verifier.stored_key, auth_message.as_bytes())?;
+        if proof.len() != client_signature.len() {
+            return Err(ScramError::InvalidPassword);
+        }
+        let client_key: Vec<u8> = proof
+            .iter()
+            .zip(&client_signature)
+            .map(|(p, s)| p ^ s)
+            .collect();
+        let stored_key = openssl::sha::sha256(&client_key);
+        if !openssl::memcmp::eq(&stored_key, &self.verifier.stored_key) {
+            return Err(ScramError::InvalidPassword);
+        }
+
+        let server_signature = hmac(&self.verifier.server_key, auth_message.as_bytes())?;
+        Ok(format!(
+            "v={}",
+            openssl::base64::encode_block(&server_signature)
+        ))
+    }
+}
+
+/// Reports whether `s` consists only of printable ASCII characters other than
+/// `,`, as required of SCRAM nonces.
+fn is_printable(s: &str) -> bool {
+    s.bytes().all(|b| (0x21..=0x7e).contains(&b) && b != b',')
+}
+
+fn salted_password(password: &str, salt: &[u8], iterations: u32) -> Result<Vec<u8>, ErrorStack> {
+    let mut salted_password = vec![0; 32];
+    openssl::pkcs5::pbkdf2_hmac(
+        password.as_bytes(),
+        salt,
+        usize::try_from(iterations).expect("u32 fits in usize"),
+        MessageDigest::sha256(),
+        &mut salted_password,
+    )?;
+    Ok(salted_password)
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Result<Vec<u8>, ErrorStack> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(data)?;
+    signer.sign_to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the client's final message, as a client that knows `password`
+    /// would.
+    fn client_final_message(
+        password: &str,
+        client_first_message_bare: &str,
+        server_first_message: &str,
+    ) -> String {
+        let mut attrs = server_first_message.split(',');
+        let nonce = attrs.next().unwrap().strip_prefix("r=").unwrap();
+        let salt = attrs.next().unwrap().strip_prefix("s=").unwrap();
+        let iterations = attrs.next().unwrap().strip_prefix("i=").unwrap();
+
+        let salt = openssl::base64::decode_block(salt).unwrap();
+        let salted_password =
+            salted_password(password, &salt, iterations.parse().unwrap()).unwrap();
+        let client_key = hmac(&salted_password, b"Client Key").unwrap();
+        let stored_key = openssl::sha::sha256(&client_key);
+
+        let without_proof = format!("c=biws,r={nonce}");
+        let auth_message =
+            format!("{client_first_message_bare},{server_first_message},{without_proof}");
+        let client_signature = hmac(&stored_key, auth_message.as_bytes()).unwrap();
+        let proof: Vec<u8> = client_key
+            .iter()
+            .zip(&client_signature)
+            .map(|(k, s)| k ^ s)
+            .collect();
+        format!(
+            "{without_proof},p={}",
+            openssl::base64::encode_block(&proof)
+        )
+    }
+
+    fn exchange(verifier: Option<ScramVerifier>, password: &str) -> Result<String, ScramError> {
+        let client_first_message_bare = "n=,r=rOprNGfwEbeRWgbNEkqO";
+        let exchange = ScramExchange::start(
+            "alice",
+            verifier,
+            format!("n,,{client_first_message_bare}").as_bytes(),
+        )?;
+        let client_final = client_final_message(
+            password,
+            client_first_message_bare,
+            exchange.server_first_message(),
+        );
+        exchange.finish(client_final.as_bytes())
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn verifier_roundtrip() {
+        let verifier = ScramVerifier::hash("hunter2").unwrap();
+        let s = verifier.to_string();
+        assert!(s.starts_with("SCRAM-SHA-256$4096:"));
+        assert_eq!(ScramVerifier::parse(&s), Some(verifier.clone()));
+        assert!(!format!("{verifier:?}").contains(&s));
+
+        assert_eq!(ScramVerifier::parse("hunter2"), None);
+        assert_eq!(
+            ScramVerifier::parse("SCRAM-SHA-256$0:c2FsdA==$a2V5:a2V5"),
+            None
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn verify_password() {
+        let verifier = ScramVerifier::hash("hunter2").unwrap();
+        assert!(verifier.verify_password("hunter2"));
+        assert!(!verifier.verify_password("hunter3"));
+        assert!(!verifier.verify_password(""));
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn scram_exchange() {
+        let verifier = ScramVerifier::hash("hunter2").unwrap();
+
+        let server_final = exchange(Some(verifier.clone()), "hunter2").unwrap();
+        assert!(server_final.starts_with("v="));
+
+        assert!(matches!(
+            exchange(Some(verifier), "hunter3"),
+            Err(ScramError::InvalidPassword)
+        ));
+        assert!(matches!(
+            exchange(None, "hunter2"),
+            Err(ScramError::InvalidPassword)
+        ));
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn mock_salt() {
+        let alice = ScramVerifier::mock("alice").unwrap();
+        assert_eq!(alice.salt.len(), SALT_LEN);
+        assert_eq!(ScramVerifier::mock("alice").unwrap().salt, alice.salt);
+        assert_ne!(ScramVerifier::mock("bob").unwrap().salt, alice.salt);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn malformed_client_first_message() {
+        for message in [
+            "",
+            "n,,",
+            "x,,n=,r=abc",
+            "n,a=admin,n=,r=abc",
+            "n,,r=abc",
+            "n,,n=,r=",
+            "n,,n=,r=a b",
+        ] {
+            assert!(
+                matches!(
+                    ScramExchange::start("alice", None, message.as_bytes()),
+                    Err(ScramError::Malformed)
+                ),
+                "{message}"
+            );
+        }
+        assert!(matches!(
+            ScramExchange::start("alice", None, b"p=tls-server-end-point,,n=,r=abc"),
+            Err(ScramError::ChannelBindingUnsupported)
+        ));
+    }
+}
@@ -81,7 +81,7 @@ use mz_ore::cast;
 use mz_ore::cast::CastFrom;
 use mz_ore::str::StrExt;
 use mz_persist_client::batch::UntrimmableColumns;
-use mz_persist_client::cfg::{PersistConfig, PersistFeatureFlag};
+use mz_persist_client::cfg::{CompressionFormat, PersistConfig, PersistFeatureFlag};
 use mz_pgwire_common::Severity;
 use mz_repr::adt::numeric::Numeric;
 use mz_repr::adt::timestamp::CheckedTimestamp;
@@ -208,7 +208,7 @@ impl VarError {
 /// We don't want to hold a static reference to a variable while erroring, so take an owned version
 /// of the fields we want.
 pub struct VarErrParam {
-    name: &'static str,
+    name: String,
     value: String,
     type_name: String,
 }
@@ -216,7 +216,7 @@ pub struct VarErrParam {
 impl<'a, V: Var + Send + Sync + ?Sized> From<&'a V> for VarErrParam {
     fn from(var: &'a V) -> VarErrParam {
         VarErrParam {
-            name: var.name(),
+            name: var.name().to_string(),
             value: var.value(),
             type_name: var.type_name(),
         }
@@ -327,6 +327,15 @@ pub static INTERVAL_STYLE: ServerVar<IntervalStyle> = ServerVar {
 const MZ_VERSION_NAME: &UncasedStr = UncasedStr::new("mz_version");
 const IS_SUPERUSER_NAME: &UncasedStr = UncasedStr::new("is_superuser");
 
+/// Returns whether `name` names a custom configuration parameter.
+///
+/// Like PostgreSQL, any parameter whose name contains a `.` (e.g.
+/// `app.tenant`) may be set to an arbitrary string without being declared
+/// first.
+fn is_custom_var_name(name: &UncasedStr) -> bool {
+    name.as_str().contains('.')
+}
+
 // Schema can be used an alias for a search path with a single element.
 pub const SCHEMA_ALIAS: &UncasedStr = UncasedStr::new("schema");
 static DEFAULT_SEARCH_PATH: Lazy<Vec<Ident>> = Lazy::new(|| vec![ident!(DEFAULT_SCHEMA)]);
@@ -599,6 +608,15 @@ const PERSIST_BLOB_CACHE_MEM_LIMIT_BYTES: ServerVar<usize> = ServerVar {
     internal: true,
 };
 
+/// Controls [`mz_persist_client::cfg::DynamicConfig::blob_cache_disk_limit_bytes`].
+const PERSIST_BLOB_CACHE_DISK_LIMIT_BYTES: ServerVar<usize> = ServerVar {
+    name: UncasedStr::new("persist_blob_cache_disk_limit_bytes"),
+    value: &PersistConfig::DEFAULT_BLOB_CACHE_DISK_LIMIT_BYTES,
+    description: "Capacity of the on-disk blob cache in bytes, or 0 to disable it. Only used by \
+                  replicas with a scratch directory (Materialize).",
+    internal: true,
+};
+
 /// Controls [`mz_persist_client::cfg::DynamicConfig::compaction_minimum_timeout`].
 const PERSIST_COMPACTION_MINIMUM_TIMEOUT: ServerVar<Duration> = ServerVar {
     name: UncasedStr::new("persist_compaction_minimum_timeout"),
@@ -624,6 +642,16 @@ const PERSIST_READER_LEASE_DURATION: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// Controls [`mz_persist_client::cfg::DynamicConfig::state_history_retention`], and how long the
+/// catalog and storage stashes keep old versions of their contents.
+pub const PERSIST_STATE_HISTORY_RETENTION: ServerVar<Duration> = ServerVar {
+    name: UncasedStr::new("persist_state_history_retention"),
+    value: &PersistConfig::DEFAULT_STATE_HISTORY_RETENTION,
+    description: "How far back every version of shard and stash state is retained, \
+        bounding how far back an environment can be recovered (0 disables).",
+    internal: true,
+};
+
 /// Controls [`mz_persist_client::cfg::DynamicConfig::consensus_connection_pool_ttl_stagger`].
 const PERSIST_CONSENSUS_CONNECTION_POOL_TTL_STAGGER: ServerVar<Duration> = ServerVar {
     name: UncasedStr::new("persist_consensus_connection_pool_ttl_stagger"),
@@ -1199,6 +1227,15 @@ static PERSIST_STATS_UNTRIMMABLE_COLUMNS: Lazy<ServerVar<UntrimmableColumns>> =
         internal: true,
     });
 
+/// Controls [`mz_persist_client::cfg::DynamicConfig::blob_compression`].
+const PERSIST_BLOB_COMPRESSION: ServerVar<CompressionFormat> = ServerVar {
+    name: UncasedStr::new("persist_blob_compression"),
+    value: &PersistConfig::DEFAULT_BLOB_COMPRESSION,
+    description: "The compression applied to newly written persist batch parts, one of \
+                  none, lz4, or zstd (Materialize).",
+    internal: true,
+};
+
 /// Controls [`mz_persist_client::cfg::DynamicConfig::pubsub_client_enabled`].
 const PERSIST_PUBSUB_CLIENT_ENABLED: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("persist_pubsub_client_enabled"),
@@ -1747,13 +1784,6 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: true,
     },
-    {
-        name: enable_envelope_debezium_in_subscribe,
-        desc: "`ENVELOPE DEBEZIUM (KEY (..))`",
-        default: false,
-        internal: true,
-        enable_for_item_parsing: true,
-    },
     {
         name: enable_envelope_materialize,
         desc: "ENVELOPE MATERIALIZE",
@@ -1803,6 +1833,27 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_retain_history,
+        desc: "RETAIN HISTORY",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_durable_subscriptions,
+        desc: "CREATE SUBSCRIPTION",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_refresh_every_mvs,
+        desc: "REFRESH EVERY and REFRESH AT materialized views",
+        default: false,
+        internal: true,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_primary_key_not_enforced,
         desc: "PRIMARY KEY NOT ENFORCED",
@@ -1868,7 +1919,7 @@ feature_flags!(
     },
     {
         name: enable_table_keys,
-        desc: "CREATE TABLE with a primary key or unique constraint",
+        desc: "CREATE TABLE with a unique constraint",
         default: false,
         internal: true,
         enable_for_item_parsing: true,
@@ -1894,13 +1945,6 @@ feature_flags!(
         internal: true,
         enable_for_item_parsing: true,
     },
-    {
-        name: enable_within_timestamp_order_by_in_subscribe,
-        desc: "`WITHIN TIMESTAMP ORDER BY ..`",
-        default: false,
-        internal: true,
-        enable_for_item_parsing: true,
-    },
     {
         name: enable_cardinality_estimates,
         desc: "join planning with cardinality estimates",
@@ -2116,6 +2160,9 @@ impl OwnedVarInput {
 #[derive(Debug)]
 pub struct SessionVars {
     vars: BTreeMap<&'static UncasedStr, Box<dyn SessionVarMut>>,
+    /// Custom configuration parameters set in this session, keyed by their
+    /// lowercased name.
+    custom_vars: BTreeMap<String, CustomVar>,
     // Inputs to computed variables.
     build_info: &'static BuildInfo,
     user: User,
@@ -2125,6 +2172,7 @@ impl SessionVars {
     pub fn new(build_info: &'static BuildInfo, user: User) -> SessionVars {
         let s = SessionVars {
             vars: BTreeMap::new(),
+            custom_vars: BTreeMap::new(),
             build_info,
             user,
         };
@@ -2240,6 +2288,25 @@ impl SessionVars {
             .chain([self.build_info as &dyn Var, &self.user])
     }
 
+    /// Returns an iterator over the names and current values of the custom
+    /// configuration parameters that have been set in this session.
+    ///
+    /// Custom parameters are not included in [`SessionVars::iter`] because
+    /// their names are not known statically.
+    pub fn custom_vars(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.custom_vars
+            .values()
+            .map(|v| (v.name.as_str(), v.value()))
+    }
+
+    /// Returns the current value of the custom configuration parameter named
+    /// `name`, if it has been set in this session.
+    pub fn custom_var(&self, name: &str) -> Option<&str> {
+        self.custom_vars
+            .get(&name.to_lowercase())
+            .map(|v| v.value())
+    }
+
     /// Returns an iterator over configuration parameters (and their current
     /// values for this session) that are expected to be sent to the client when
     /// a new connection is established or when their value changes.
@@ -2305,6 +2372,9 @@ impl SessionVars {
     /// Sets the configuration parameter named `name` to the value represented
     /// by `value`.
     ///
+    /// If `name` contains a `.`, it is treated as a custom configuration
+    /// parameter and is created on first use.
+    ///
     /// The new value may be either committed or rolled back by the next call to
     /// [`SessionVars::end_transaction`]. If `local` is true, the new value is always
     /// discarded by the next call to [`SessionVars::end_transaction`], even if the
@@ -2324,6 +2394,15 @@ impl SessionVars {
         let name = UncasedStr::new(name);
         self.check_read_only(name)?;
 
+        if is_custom_var_name(name) {
+            let value = CustomVar::parse(name, input)?;
+            self.custom_vars
+                .entry(name.as_str().to_lowercase())
+                .or_insert_with_key(|name| CustomVar::new(name.clone()))
+                .set(value, local);
+            return Ok(());
+        }
+
         self.vars
             .get_mut(name)
             .map(|v| {
@@ -2382,6 +2461,14 @@ impl SessionVars {
         let name = UncasedStr::new(name);
         self.check_read_only(name)?;
 
+        if is_custom_var_name(name) {
+            // Resetting a custom parameter that was never set is a no-op.
+            if let Some(v) = self.custom_vars.get_mut(&name.as_str().to_lowercase()) {
+                v.reset(local);
+            }
+            return Ok(());
+        }
+
         self.vars
             .get_mut(name)
             .map(|v| {
@@ -2427,6 +2514,10 @@ impl SessionVars {
                 changed.insert(var.name(), after);
             }
         }
+        // Like PostgreSQL, changes to custom parameters are not reported.
+        for var in self.custom_vars.values_mut() {
+            var.end_transaction(action);
+        }
         changed
     }
 
@@ -2748,10 +2839,12 @@ impl SystemVars {
             .with_var(&upsert_rocksdb::UPSERT_ROCKSDB_WRITE_BUFFER_MANAGER_ALLOW_STALL)
             .with_var(&PERSIST_BLOB_TARGET_SIZE)
             .with_var(&PERSIST_BLOB_CACHE_MEM_LIMIT_BYTES)
+            .with_var(&PERSIST_BLOB_CACHE_DISK_LIMIT_BYTES)
             .with_var(&PERSIST_COMPACTION_MINIMUM_TIMEOUT)
             .with_var(&PERSIST_CONSENSUS_CONNECTION_POOL_TTL)
             .with_var(&PERSIST_CONSENSUS_CONNECTION_POOL_TTL_STAGGER)
             .with_var(&PERSIST_READER_LEASE_DURATION)
+            .with_var(&PERSIST_STATE_HISTORY_RETENTION)
             .with_var(&CRDB_CONNECT_TIMEOUT)
             .with_var(&CRDB_TCP_USER_TIMEOUT)
             .with_var(&COMPUTE_DATAFLOW_MAX_INFLIGHT_BYTES)
@@ -2776,6 +2869,7 @@ impl SystemVars {
             .with_var(&PERSIST_STATS_FILTER_ENABLED)
             .with_var(&PERSIST_STATS_BUDGET_BYTES)
             .with_var(&PERSIST_STATS_UNTRIMMABLE_COLUMNS)
+            .with_var(&PERSIST_BLOB_COMPRESSION)
             .with_var(&PERSIST_PUBSUB_CLIENT_ENABLED)
             .with_var(&PERSIST_PUBSUB_PUSH_DIFF_ENABLED)
             .with_var(&PERSIST_ROLLUP_THRESHOLD)
@@ -3243,6 +3337,11 @@ impl SystemVars {
         *self.expect_value(&PERSIST_BLOB_CACHE_MEM_LIMIT_BYTES)
     }
 
+    /// Returns the `persist_blob_cache_disk_limit_bytes` configuration parameter.
+    pub fn persist_blob_cache_disk_limit_bytes(&self) -> usize {
+        *self.expect_value(&PERSIST_BLOB_CACHE_DISK_LIMIT_BYTES)
+    }
+
     /// Returns the `persist_next_listen_batch_retryer_initial_backoff` configuration parameter.
     pub fn persist_next_listen_batch_retryer_initial_backoff(&self) -> Duration {
         *self.expect_value(&PERSIST_NEXT_LISTEN_BATCH_RETRYER_INITIAL_BACKOFF)
@@ -3281,6 +3380,11 @@ impl SystemVars {
         *self.expect_value(&PERSIST_READER_LEASE_DURATION)
     }
 
+    /// Returns the `persist_state_history_retention` configuration parameter.
+    pub fn persist_state_history_retention(&self) -> Duration {
+        *self.expect_value(&PERSIST_STATE_HISTORY_RETENTION)
+    }
+
     /// Returns the `persist_compaction_minimum_timeout` configuration parameter.
     pub fn persist_compaction_minimum_timeout(&self) -> Duration {
         *self.expect_value(&PERSIST_COMPACTION_MINIMUM_TIMEOUT)
@@ -3411,6 +3515,11 @@ impl SystemVars {
             .clone()
     }
 
+    /// Returns the `persist_blob_compression` configuration parameter.
+    pub fn persist_blob_compression(&self) -> CompressionFormat {
+        *self.expect_value(&PERSIST_BLOB_COMPRESSION)
+    }
+
     /// Returns the `persist_pubsub_client_enabled` configuration parameter.
     pub fn persist_pubsub_client_enabled(&self) -> bool {
         *self.expect_value(&PERSIST_PUBSUB_CLIENT_ENABLED)
@@ -4218,6 +4327,81 @@ where
     }
 }
 
+/// A `CustomVar` is the session value for a custom configuration parameter.
+///
+/// Custom parameters only exist in the session that set them, so unlike
+/// [`SessionVar`] they own their name and have no server default; a custom
+/// parameter that has been reset holds the empty string.
+#[derive(Debug, Clone)]
+struct CustomVar {
+    name: String,
+    /// Value `LOCAL` to a transaction, will be unset at the completion of the transaction.
+    local_value: Option<String>,
+    /// Value set during a transaction, will be set if the transaction is committed.
+    staged_value: Option<String>,
+    /// Value that persists for the rest of the session.
+    session_value: Option<String>,
+}
+
+impl CustomVar {
+    fn new(name: String) -> CustomVar {
+        CustomVar {
+            name,
+            local_value: None,
+            staged_value: None,
+            session_value: None,
+        }
+    }
+
+    /// Parses the value for the custom parameter named `name` from `input`.
+    fn parse(name: &UncasedStr, input: VarInput) -> Result<String, VarError> {
+        match input {
+            VarInput::Flat(value) => Ok(value.to_owned()),
+            VarInput::SqlSet([value]) => Ok(value.clone()),
+            VarInput::SqlSet(values) => Err(VarError::InvalidParameterValue {
+                parameter: VarErrParam {
+                    name: name.as_str().to_lowercase(),
+                    value: String::new(),
+                    type_name: String::type_name(),
+                },
+                values: values.to_vec(),
+                reason: "expects a single value".to_string(),
+            }),
+        }
+    }
+
+    fn value(&self) -> &str {
+        self.local_value
+            .as_deref()
+            .or(self.staged_value.as_deref())
+            .or(self.session_value.as_deref())
+            .unwrap_or("")
+    }
+
+    fn set(&mut self, value: String, local: bool) {
+        if local {
+            self.local_value = Some(value);
+        } else {
+            self.local_value = None;
+            self.staged_value = Some(value);
+        }
+    }
+
+    fn reset(&mut self, local: bool) {
+        self.set(String::new(), local)
+    }
+
+    fn end_transaction(&mut self, action: EndTransactionAction) {
+        self.local_value = None;
+        match action {
+            EndTransactionAction::Commit if self.staged_value.is_some() => {
+                self.session_value = self.staged_value.take()
+            }
+            _ => self.staged_value = None,
+        }
+    }
+}
+
 impl Var for BuildInfo {
     fn name(&self) -> &'static str {
         MZ_VERSION_NAME.as_str()
@@ -5164,6 +5348,32 @@ impl Value for UntrimmableColumns {
     }
 }
 
+impl Value for CompressionFormat {
+    fn type_name() -> String {
+        "string".to_string()
+    }
+
+    fn parse<'a>(
+        param: &'a (dyn Var + Send + Sync),
+        input: VarInput,
+    ) -> Result<Self::Owned, VarError> {
+        let s = extract_single_value(param, input)?;
+        s.parse().map_err(|_| VarError::ConstrainedParameter {
+            parameter: param.into(),
+            values: input.to_vec(),
+            valid_values: Some(vec![
+                CompressionFormat::None.as_str(),
+                CompressionFormat::Lz4.as_str(),
+                CompressionFormat::Zstd.as_str(),
+            ]),
+        })
+    }
+
+    fn format(&self) -> String {
+        self.as_str().to_string()
+    }
+}
+
 impl Value for CloneableEnvFilter {
     fn type_name() -> String {
         "EnvFilter".to_string()
@@ -5246,10 +5456,12 @@ fn is_upsert_rocksdb_config_var(name: &str) -> bool {
 fn is_persist_config_var(name: &str) -> bool {
     name == PERSIST_BLOB_TARGET_SIZE.name()
         || name == PERSIST_BLOB_CACHE_MEM_LIMIT_BYTES.name()
+        || name == PERSIST_BLOB_CACHE_DISK_LIMIT_BYTES.name()
         || name == PERSIST_COMPACTION_MINIMUM_TIMEOUT.name()
         || name == PERSIST_CONSENSUS_CONNECTION_POOL_TTL.name()
         || name == PERSIST_CONSENSUS_CONNECTION_POOL_TTL_STAGGER.name()
         || name == PERSIST_READER_LEASE_DURATION.name()
+        || name == PERSIST_STATE_HISTORY_RETENTION.name()
         || name == CRDB_CONNECT_TIMEOUT.name()
         || name == CRDB_TCP_USER_TIMEOUT.name()
         || name == PERSIST_SINK_MINIMUM_BATCH_UPDATES.name()
@@ -5268,6 +5480,7 @@ fn is_persist_config_var(name: &str) -> bool {
         || name == PERSIST_STATS_FILTER_ENABLED.name()
         || name == PERSIST_STATS_BUDGET_BYTES.name()
         || name == PERSIST_STATS_UNTRIMMABLE_COLUMNS.name()
+        || name == PERSIST_BLOB_COMPRESSION.name()
         || name == PERSIST_PUBSUB_CLIENT_ENABLED.name()
         || name == PERSIST_PUBSUB_PUSH_DIFF_ENABLED.name()
         || PersistFeatureFlag::ALL.iter().any(|f| f.name == name)
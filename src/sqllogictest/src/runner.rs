@@ -57,7 +57,7 @@ use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::PersistConfig;
 use mz_persist_client::rpc::PubSubClientConnection;
 use mz_persist_client::PersistLocation;
-use mz_pgrepr::{oid, Interval, Jsonb, Numeric, UInt2, UInt4, UInt8, Value};
+use mz_pgrepr::{oid, Inet, Interval, Jsonb, MacAddr, Numeric, UInt2, UInt4, UInt8, Value};
 use mz_repr::adt::date::Date;
 use mz_repr::adt::mz_acl_item::{AclItem, MzAclItem};
 use mz_repr::adt::numeric;
@@ -436,8 +436,11 @@ impl<'a> FromSql<'a> for Slt {
             PgType::INT2 => Self(Value::Int2(types::int2_from_sql(raw)?)),
             PgType::INT4 => Self(Value::Int4(types::int4_from_sql(raw)?)),
             PgType::INT8 => Self(Value::Int8(types::int8_from_sql(raw)?)),
+            PgType::INET => Self(Value::Inet(Inet::from_sql(ty, raw)?)),
+            PgType::CIDR => Self(Value::Cidr(Inet::from_sql(ty, raw)?)),
             PgType::INTERVAL => Self(Value::Interval(Interval::from_sql(ty, raw)?)),
             PgType::JSONB => Self(Value::Jsonb(Jsonb::from_sql(ty, raw)?)),
+            PgType::MACADDR => Self(Value::MacAddr(MacAddr::from_sql(ty, raw)?)),
             PgType::NAME => Self(Value::Name(types::text_from_sql(raw)?.to_string())),
             PgType::NUMERIC => Self(Value::Numeric(Numeric::from_sql(ty, raw)?)),
             PgType::OID => Self(Value::Oid(types::oid_from_sql(raw)?)),
@@ -509,6 +512,7 @@ impl<'a> FromSql<'a> for Slt {
                         elements,
                     })
                 }
+                PgKind::Enum(_) => Self(Value::Text(types::text_from_sql(raw)?.to_string())),
                 _ => match ty.oid() {
                     oid::TYPE_UINT2_OID => Self(Value::UInt2(UInt2::from_sql(ty, raw)?)),
                     oid::TYPE_UINT4_OID => Self(Value::UInt4(UInt4::from_sql(ty, raw)?)),
@@ -528,7 +532,7 @@ impl<'a> FromSql<'a> for Slt {
     }
     fn accepts(ty: &PgType) -> bool {
         match ty.kind() {
-            PgKind::Array(_) | PgKind::Composite(_) => return true,
+            PgKind::Array(_) | PgKind::Composite(_) | PgKind::Enum(_) => return true,
             _ => {}
         }
         match ty.oid() {
@@ -551,8 +555,11 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::INT2
                 | PgType::INT4
                 | PgType::INT8
+                | PgType::INET
+                | PgType::CIDR
                 | PgType::INTERVAL
                 | PgType::JSONB
+                | PgType::MACADDR
                 | PgType::NAME
                 | PgType::NUMERIC
                 | PgType::OID
@@ -1016,7 +1023,10 @@ impl<'a> RunnerInner<'a> {
             secrets_controller,
             cloud_resource_controller: None,
             tls: None,
-            frontegg: None,
+            auth_provider: None,
+            password_auth: false,
+            tls_client_auth: None,
+            balancer_proxy_protocol: false,
             cors_allowed_origin: AllowOrigin::list([]),
             unsafe_mode: true,
             all_features: false,
@@ -78,6 +78,15 @@ CREATE TABLE uppers (
 // connections after it restarts during maintenance or upgrades.
 const RECONNECT_INTERVAL: Duration = Duration::from_secs(300);
 
+// The GC TTL of the stash tables when no history is retained.
+const DEFAULT_GC_TTL: Duration = Duration::from_secs(600);
+
+const FENCE_EXISTS: &str = r#"
+SELECT EXISTS (
+    SELECT 1 FROM pg_tables
+    WHERE schemaname = current_schema() AND tablename = 'fence'
+)"#;
+
 struct PreparedStatements {
     fetch_epoch: Statement,
     iter_key: Statement,
@@ -395,6 +404,8 @@ impl StashFactory {
             sinces_tx,
             metrics: Arc::clone(&self.metrics),
             collections: BTreeMap::new(),
+            history_retention: Duration::ZERO,
+            configure_gc_ttl: matches!(txn_mode, TransactionMode::Writeable),
         };
 
         // Do the initial connection once here so we don't get stuck in transact's retry loop if the
@@ -448,6 +459,10 @@ pub struct Stash {
     pub(crate) sinces_tx: mpsc::UnboundedSender<ConsolidateRequest>,
     pub(crate) collections: BTreeMap<String, Id>,
     pub metrics: Arc<Metrics>,
+    history_retention: Duration,
+    /// Whether the GC TTL of the stash tables needs to be set on the next
+    /// connection.
+    configure_gc_ttl: bool,
 }
 
 #[derive(Debug)]
@@ -492,6 +507,129 @@ impl Stash {
         Ok(())
     }
 
+    /// Copies the stash at `url` (in `schema`, if any) as it was at
+    /// `as_of_walltime_ms` into a new stash at `to_url` (in `to_schema`, if
+    /// any).
+    ///
+    /// Everything is read from a single historical snapshot of the source, so
+    /// the copy is exactly what the stash contained at that moment. This relies
+    /// on CockroachDB's `AS OF SYSTEM TIME` and so can only go as far back as
+    /// the history retention of the source, see [`Stash::set_history_retention`].
+    /// The destination must not already contain a stash.
+    pub async fn restore_as_of(
+        url: &str,
+        schema: Option<&str>,
+        to_url: &str,
+        to_schema: Option<&str>,
+        tls: MakeTlsConnector,
+        as_of_walltime_ms: u64,
+    ) -> Result<(), StashError> {
+        async fn connect(
+            url: &str,
+            schema: Option<&str>,
+            tls: MakeTlsConnector,
+        ) -> Result<Client, StashError> {
+            let (client, connection) = tokio_postgres::connect(url, tls).await?;
+            mz_ore::task::spawn(|| "tokio-postgres stash connection", async move {
+                if let Err(e) = connection.await {
+                    tracing::warn!("postgres stash connection error: {}", e);
+                }
+            });
+            if let Some(schema) = schema {
+                client
+                    .execute(format!("SET search_path TO {schema}").as_str(), &[])
+                    .await?;
+            }
+            Ok(client)
+        }
+
+        let src = connect(url, schema, tls.clone()).await?;
+        let as_of_nanos = u128::from(as_of_walltime_ms) * 1_000_000;
+        src.batch_execute(&format!("BEGIN AS OF SYSTEM TIME {as_of_nanos}"))
+            .await?;
+        let fence_exists: bool = src.query_one(FENCE_EXISTS, &[]).await?.get(0);
+        if !fence_exists {
+            return Err("stash did not exist yet at the restore time".into());
+        }
+        let fence = src
+            .query_one("SELECT epoch, nonce, version FROM fence", &[])
+            .await?;
+        let collections = src
+            .query("SELECT collection_id, name FROM collections", &[])
+            .await?;
+        let data = src
+            .query(
+                "SELECT collection_id, key, value, time, diff FROM data",
+                &[],
+            )
+            .await?;
+        let sinces = src
+            .query("SELECT collection_id, since FROM sinces", &[])
+            .await?;
+        let uppers = src
+            .query("SELECT collection_id, upper FROM uppers", &[])
+            .await?;
+        src.batch_execute("COMMIT").await?;
+        info!(
+            "restoring stash as of {}: {} collections, {} rows",
+            as_of_walltime_ms,
+            collections.len(),
+            data.len()
+        );
+
+        let mut dest = connect(to_url, to_schema, tls).await?;
+        let tx = dest.transaction().await?;
+        let fence_exists: bool = tx.query_one(FENCE_EXISTS, &[]).await?.get(0);
+        if fence_exists {
+            return Err("destination stash already exists".into());
+        }
+        tx.batch_execute(SCHEMA).await?;
+        let (epoch, nonce, version): (i64, Vec<u8>, i64) =
+            (fence.get(0), fence.get(1), fence.get(2));
+        tx.execute(
+            "UPDATE fence SET epoch = $1, nonce = $2, version = $3",
+            &[&epoch, &nonce, &version],
+        )
+        .await?;
+        for row in collections {
+            let (id, name): (Id, String) = (row.get(0), row.get(1));
+            tx.execute(
+                "INSERT INTO collections (collection_id, name) VALUES ($1, $2)",
+                &[&id, &name],
+            )
+            .await?;
+        }
+        let insert = tx
+            .prepare(
+                "INSERT INTO data (collection_id, key, value, time, diff) VALUES ($1, $2, $3, $4, $5)",
+            )
+            .await?;
+        for row in data {
+            let (id, key, value, time, diff): (Id, Vec<u8>, Vec<u8>, Timestamp, Diff) =
+                (row.get(0), row.get(1), row.get(2), row.get(3), row.get(4));
+            tx.execute(&insert, &[&id, &key, &value, &time, &diff])
+                .await?;
+        }
+        for row in sinces {
+            let (id, since): (Id, Option<Timestamp>) = (row.get(0), row.get(1));
+            tx.execute(
+                "INSERT INTO sinces (collection_id, since) VALUES ($1, $2)",
+                &[&id, &since],
+            )
+            .await?;
+        }
+        for row in uppers {
+            let (id, upper): (Id, Option<Timestamp>) = (row.get(0), row.get(1));
+            tx.execute(
+                "INSERT INTO uppers (collection_id, upper) VALUES ($1, $2)",
+                &[&id, &upper],
+            )
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
     /// Creates a debug stash from the current COCKROACH_URL with a random
     /// schema, and DROPs it after `f` has returned.
     pub async fn with_debug_stash<F, T, Fut>(f: F) -> Result<T, StashError>
@@ -535,6 +673,17 @@ impl Stash {
         self.config.lock().await.connect_timeout(connect_timeout);
     }
 
+    /// Sets the length of wall-clock time for which the stash keeps every
+    /// version of its contents around, so that it can be restored as of any
+    /// point in that window with [`Stash::restore_as_of`]. Takes effect on the
+    /// next transaction.
+    pub fn set_history_retention(&mut self, retention: Duration) {
+        if self.history_retention != retention {
+            self.history_retention = retention;
+            self.configure_gc_ttl = matches!(self.txn_mode, TransactionMode::Writeable);
+        }
+    }
+
     /// Sets `client` to a new connection to the Postgres server.
     #[tracing::instrument(name = "stash::connect", level = "debug", skip_all)]
     async fn connect(&mut self) -> Result<(), StashError> {
@@ -564,17 +713,7 @@ impl Stash {
                 .read_only(matches!(self.txn_mode, TransactionMode::Readonly))
                 .start()
                 .await?;
-            let fence_exists: bool = tx
-                .query_one(
-                    r#"
-            SELECT EXISTS (
-                SELECT 1 FROM pg_tables
-                WHERE schemaname = current_schema() AND tablename = 'fence'
-            )"#,
-                    &[],
-                )
-                .await?
-                .get(0);
+            let fence_exists: bool = tx.query_one(FENCE_EXISTS, &[]).await?.get(0);
             if !fence_exists {
                 if !matches!(self.txn_mode, TransactionMode::Writeable) {
                     return Err(StashError {
@@ -588,21 +727,6 @@ impl Stash {
             }
 
             let epoch = if matches!(self.txn_mode, TransactionMode::Writeable) {
-                // The `data`, `sinces`, and `uppers` tables can create and delete
-                // rows at a high frequency, generating many tombstoned rows. If
-                // Cockroach's GC interval is set high (the default is 25h) and
-                // these tombstones accumulate, scanning over the table will take
-                // increasingly and prohibitively long.
-                //
-                // See: https://github.com/MaterializeInc/materialize/issues/15842
-                // See: https://www.cockroachlabs.com/docs/stable/configure-zone.html#variables
-                tx.batch_execute("ALTER TABLE data CONFIGURE ZONE USING gc.ttlseconds = 600;")
-                    .await?;
-                tx.batch_execute("ALTER TABLE sinces CONFIGURE ZONE USING gc.ttlseconds = 600;")
-                    .await?;
-                tx.batch_execute("ALTER TABLE uppers CONFIGURE ZONE USING gc.ttlseconds = 600;")
-                    .await?;
-
                 // Bump the epoch, which will cause any previous connection to fail. Add a
                 // unique nonce so that if some other thing recreates the entire schema, we
                 // can't accidentally have the same epoch, nonce pair (especially risky if the
@@ -627,6 +751,29 @@ impl Stash {
             self.epoch = Some(epoch);
         }
 
+        if self.configure_gc_ttl {
+            // The `data`, `sinces`, and `uppers` tables can create and delete
+            // rows at a high frequency, generating many tombstoned rows. If
+            // Cockroach's GC interval is set high (the default is 25h) and
+            // these tombstones accumulate, scanning over the table will take
+            // increasingly and prohibitively long. So we only keep them around
+            // for as long as history retention asks us to.
+            //
+            // See: https://github.com/MaterializeInc/materialize/issues/15842
+            // See: https://www.cockroachlabs.com/docs/stable/configure-zone.html#variables
+            let gc_ttl = std::cmp::max(self.history_retention, DEFAULT_GC_TTL).as_secs();
+            let mut stmt = String::new();
+            for table in ["data", "sinces", "uppers", "collections", "fence"] {
+                write!(
+                    stmt,
+                    "ALTER TABLE {table} CONFIGURE ZONE USING gc.ttlseconds = {gc_ttl};"
+                )
+                .expect("writing to string cannot fail");
+            }
+            client.batch_execute(&stmt).await?;
+            self.configure_gc_ttl = false;
+        }
+
         self.statements = Some(PreparedStatements::from(&client, self.txn_mode).await?);
 
         // In savepoint mode start a transaction that will never be committed.
@@ -673,8 +820,9 @@ impl Stash {
         let mut retry = Box::pin(retry);
         let mut attempt: u64 = 0;
 
-        // Actively reconnect to allow cockroach to rebalanace.
-        if self.reconnect.tick().now_or_never().is_some() {
+        // Actively reconnect to allow cockroach to rebalanace, or to apply a
+        // new history retention.
+        if self.reconnect.tick().now_or_never().is_some() || self.configure_gc_ttl {
             self.client = None;
         }
 
@@ -84,6 +84,7 @@ use crate::{
 use futures::Future;
 use mz_ore::assert_contains;
 use mz_ore::metrics::MetricsRegistry;
+use mz_ore::now::SYSTEM_TIME;
 use mz_ore::task::spawn;
 use timely::progress::Antichain;
 use tokio::sync::oneshot;
@@ -744,6 +745,67 @@ async fn test_stash_fence() {
     factory.drop().await;
 }
 
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_stash_restore_as_of() {
+    let factory = DebugStashFactory::try_new().await.expect("must succeed");
+    let mut stash = factory.open().await;
+    stash.set_history_retention(Duration::from_secs(60 * 60));
+    let c1 = collection::<i64, i64>(&mut stash, "c1").await.unwrap();
+    let mut batch = make_batch(&c1, &mut stash).await.unwrap();
+    c1.append_to_batch(&mut batch, &1, &2, 1);
+    append(&mut stash, vec![batch]).await.unwrap();
+
+    // Leave some room on both sides of the restore time for clock skew with
+    // the database.
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let as_of = SYSTEM_TIME();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let mut batch = make_batch(&c1, &mut stash).await.unwrap();
+    c1.append_to_batch(&mut batch, &3, &4, 1);
+    append(&mut stash, vec![batch]).await.unwrap();
+
+    let restored = DebugStashFactory::try_new().await.expect("must succeed");
+    Stash::restore_as_of(
+        factory.url(),
+        Some(factory.schema()),
+        restored.url(),
+        Some(restored.schema()),
+        factory.tls().clone(),
+        as_of,
+    )
+    .await
+    .unwrap();
+    let mut restored_stash = restored.open().await;
+    assert_eq!(
+        C1.peek_one(&mut restored_stash).await.unwrap(),
+        BTreeMap::from([(1, 2)])
+    );
+    restored_stash.verify().await.unwrap();
+
+    // A stash can't be restored over an existing one.
+    let err = Stash::restore_as_of(
+        factory.url(),
+        Some(factory.schema()),
+        restored.url(),
+        Some(restored.schema()),
+        factory.tls().clone(),
+        as_of,
+    )
+    .await
+    .unwrap_err();
+    assert_contains!(err.to_string(), "destination stash already exists");
+
+    // The original is untouched.
+    assert_eq!(
+        C1.peek_one(&mut stash).await.unwrap(),
+        BTreeMap::from([(1, 2), (3, 4)])
+    );
+    factory.drop().await;
+    restored.drop().await;
+}
+
 #[mz_ore::test(tokio::test)]
 #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
 async fn test_stash_append() {
@@ -305,6 +305,49 @@ pub trait StorageController: Debug + Send {
         collections: BTreeMap<GlobalId, IngestionDescription>,
     ) -> Result<(), StorageError>;
 
+    /// Prepares to alter the columns of the table identified by `id` to
+    /// `new_desc`, which must consist of its current columns, less any that
+    /// are dropped, followed by any that are added. `missing` holds the values
+    /// that the rows already in the table take on for the added columns.
+    ///
+    /// The alteration is durably recorded, but does not take effect until it
+    /// is committed with [`StorageController::commit_alter_table`], which the
+    /// caller must do iff it durably records `new_desc` as the table's columns,
+    /// and otherwise abort it with [`StorageController::abort_alter_table`]. If
+    /// the process restarts in between, [`StorageController::create_collections`]
+    /// decides the alteration according to the columns the table is described
+    /// with, and those that took place are listed by
+    /// [`StorageController::uncommitted_table_alters`].
+    async fn prepare_alter_table(
+        &mut self,
+        id: GlobalId,
+        new_desc: RelationDesc,
+        missing: Row,
+    ) -> Result<(), StorageError>;
+
+    /// Undoes the alteration of the columns of the table identified by `id`
+    /// that was prepared with [`StorageController::prepare_alter_table`].
+    async fn abort_alter_table(&mut self, id: GlobalId) -> Result<(), StorageError>;
+
+    /// Completes the alteration of the columns of the table identified by `id`
+    /// that was prepared with [`StorageController::prepare_alter_table`].
+    ///
+    /// Subsequent reads and writes of the table use its new columns. The rows
+    /// already stored in the table's shard are rewritten to match the rows
+    /// that later writes retract, by reading them at `read_ts` and writing at
+    /// `write_ts`, which advances the table's upper to `advance_to`.
+    async fn commit_alter_table(
+        &mut self,
+        id: GlobalId,
+        read_ts: Self::Timestamp,
+        write_ts: Self::Timestamp,
+        advance_to: Self::Timestamp,
+    ) -> Result<(), StorageError>;
+
+    /// Returns the tables whose alterations were prepared, but have been
+    /// neither committed nor aborted.
+    fn uncommitted_table_alters(&self) -> Vec<GlobalId>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError>;
 
@@ -110,6 +110,7 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{ColumnName, Datum, Diff, GlobalId, RelationDesc, Row, TimestampManipulation};
 use mz_stash::{self, AppendBatch, StashFactory, TypedCollection};
 use mz_stash_types::metrics::Metrics as StashMetrics;
+use mz_stash_types::StashError;
 use mz_storage_client::client::{
     ProtoStorageCommand, ProtoStorageResponse, RunIngestionCommand, RunSinkCommand,
     SinkStatisticsUpdate, SourceStatisticsUpdate, StatusUpdate, StorageCommand, StorageResponse,
@@ -123,7 +124,8 @@ use mz_storage_client::controller::{
 use mz_storage_client::metrics::StorageControllerMetrics;
 use mz_storage_types::collections as proto;
 use mz_storage_types::controller::{
-    CollectionMetadata, DurableCollectionMetadata, StorageError, TxnsCodecRow,
+    CollectionMetadata, DurableCollectionMetadata, DurableTableLayout, StorageError, TableLayout,
+    TxnsCodecRow,
 };
 use mz_storage_types::instances::StorageInstanceId;
 use mz_storage_types::parameters::StorageParameters;
@@ -160,10 +162,14 @@ pub static METADATA_EXPORT: TypedCollection<proto::GlobalId, proto::DurableExpor
 pub static PERSIST_TXNS_SHARD: TypedCollection<(), String> =
     TypedCollection::new("persist-txns-shard");
 
+pub static TABLE_LAYOUT_COLLECTION: TypedCollection<proto::GlobalId, proto::DurableTableLayout> =
+    TypedCollection::new("storage-table-layouts");
+
 pub static ALL_COLLECTIONS: &[&str] = &[
     METADATA_COLLECTION.name(),
     METADATA_EXPORT.name(),
     PERSIST_TXNS_SHARD.name(),
+    TABLE_LAYOUT_COLLECTION.name(),
     command_wals::SHARD_FINALIZATION.name(),
 ];
 
@@ -331,6 +337,12 @@ pub struct Controller<T: Timestamp + Lattice + Codec64 + From<EpochMillis> + Tim
     /// Compaction commands to send during the next call to
     /// `StorageController::process`.
     pending_compaction_commands: Vec<(GlobalId, Antichain<T>, Option<StorageInstanceId>)>,
+    /// The layouts of the tables whose columns have been altered, as durably
+    /// stored in `TABLE_LAYOUT_COLLECTION`.
+    table_layouts: BTreeMap<GlobalId, DurableTableLayout>,
+    /// The columns that tables are being altered to by alterations that have
+    /// been prepared but neither committed nor aborted.
+    pending_table_alters: BTreeMap<GlobalId, RelationDesc>,
 
     /// Interface for managed collections
     pub(crate) collection_manager: collection_mgmt::CollectionManager<T>,
@@ -411,6 +423,9 @@ where
 
     fn update_configuration(&mut self, config_params: StorageParameters) {
         config_params.persist.apply(self.persist.cfg());
+        if let Some(retention) = config_params.persist.state_history_retention {
+            self.stash.set_history_retention(retention);
+        }
 
         for client in self.clients.values_mut() {
             client.send(StorageCommand::UpdateConfiguration(config_params.clone()));
@@ -536,6 +551,7 @@ where
                 }
             }
         }
+        self.resolve_table_alters(&collections).await?;
 
         // Install collection state for each bound description. Note that this
         // method implementation attempts to do AS MUCH work concurrently as
@@ -620,6 +636,13 @@ where
                     | DataSource::Other(DataSourceOther::Source) => None,
                 };
 
+                let table_layout = match description.data_source {
+                    DataSource::Other(DataSourceOther::TableWrites) => {
+                        self.table_layout(id, &description.desc)?
+                    }
+                    _ => None,
+                };
+
                 let metadata = CollectionMetadata {
                     persist_location: self.persist_location.clone(),
                     remap_shard,
@@ -627,6 +650,7 @@ where
                     status_shard,
                     relation_desc: description.desc.clone(),
                     txns_shard,
+                    table_layout,
                 };
 
                 Ok((id, description, metadata))
@@ -661,7 +685,7 @@ where
                         &id,
                         metadata.data_shard,
                         description.since.as_ref(),
-                        metadata.relation_desc.clone(),
+                        metadata.stored_desc().clone(),
                         persist_client,
                     )
                     .await;
@@ -1012,6 +1036,148 @@ where
         Ok(())
     }
 
+    async fn prepare_alter_table(
+        &mut self,
+        id: GlobalId,
+        new_desc: RelationDesc,
+        missing: Row,
+    ) -> Result<(), StorageError> {
+        let collection = self.collection(id)?;
+        if collection.description.data_source != DataSource::Other(DataSourceOther::TableWrites) {
+            return Err(StorageError::InvalidAlter { id });
+        }
+        let layout = match &collection.collection_metadata.table_layout {
+            Some(layout) => layout.clone(),
+            None => TableLayout::new(&collection.description.desc),
+        };
+        let pending = layout.alter(&new_desc, &missing).map_err(|e| {
+            warn!("cannot alter the columns of table {id}: {e}");
+            StorageError::InvalidAlter { id }
+        })?;
+
+        self.store_table_layout(
+            id,
+            DurableTableLayout {
+                layout,
+                pending: Some(pending),
+            },
+        )
+        .await?;
+        self.pending_table_alters.insert(id, new_desc);
+
+        Ok(())
+    }
+
+    async fn abort_alter_table(&mut self, id: GlobalId) -> Result<(), StorageError> {
+        self.pending_table_alters.remove(&id);
+        let Some(DurableTableLayout { layout, .. }) = self.table_layouts.get(&id).cloned() else {
+            return Ok(());
+        };
+        if layout == TableLayout::new(&self.collection(id)?.description.desc) {
+            TABLE_LAYOUT_COLLECTION
+                .delete_keys(&mut self.stash, BTreeSet::from([id.into_proto()]))
+                .await?;
+            self.table_layouts.remove(&id);
+        } else {
+            self.store_table_layout(
+                id,
+                DurableTableLayout {
+                    layout,
+                    pending: None,
+                },
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn commit_alter_table(
+        &mut self,
+        id: GlobalId,
+        read_ts: Self::Timestamp,
+        write_ts: Self::Timestamp,
+        advance_to: Self::Timestamp,
+    ) -> Result<(), StorageError> {
+        let Some(DurableTableLayout {
+            pending: Some(layout),
+            ..
+        }) = self.table_layouts.get(&id).cloned()
+        else {
+            return Err(StorageError::InvalidAlter { id });
+        };
+        if !write_ts.less_than(&advance_to) {
+            return Err(StorageError::UpdateBeyondUpper(id));
+        }
+
+        // Following a restart, `create_collections` already adopted the layout.
+        if let Some(new_desc) = self.pending_table_alters.remove(&id) {
+            let data_shard = self.collection(id)?.collection_metadata.data_shard;
+            let persist_client = self
+                .persist
+                .open(self.persist_location.clone())
+                .await
+                .unwrap();
+            let write = persist_client
+                .open_writer(
+                    data_shard,
+                    Arc::new(layout.stored_desc.clone()),
+                    Arc::new(UnitSchema),
+                    Diagnostics {
+                        shard_name: id.to_string(),
+                        handle_purpose: format!("controller data for {}", id),
+                    },
+                )
+                .await
+                .expect("invalid persist usage");
+
+            let collection = self.collection_mut(id).expect("validated exists");
+            collection.description.desc = new_desc.clone();
+            collection.collection_metadata.relation_desc = new_desc;
+            collection.collection_metadata.table_layout = Some(layout.clone());
+            self.persist_table_worker.update(id, write);
+        }
+
+        // Rewrite the rows already stored in the table's shard to the form in
+        // which they are written under the new layout, so that they match the
+        // rows that later writes retract. This is idempotent, so it is safe to
+        // repeat if the process restarts before the layout is stored as
+        // committed.
+        let mut snapshot = self.snapshot_stored(id, read_ts).await?;
+        differential_dataflow::consolidation::consolidate(&mut snapshot);
+        let mut updates = vec![];
+        for (row, diff) in snapshot {
+            let normalized = layout.write_row(&layout.read_row(&row));
+            if normalized != row {
+                updates.push(TimestamplessUpdate { row, diff: -diff });
+                updates.push(TimestamplessUpdate {
+                    row: normalized,
+                    diff,
+                });
+            }
+        }
+        self.persist_table_worker
+            .append(write_ts, advance_to, vec![(id, updates)])
+            .await
+            .map_err(|_| StorageError::ShuttingDown("table write worker"))??;
+
+        self.store_table_layout(
+            id,
+            DurableTableLayout {
+                layout,
+                pending: None,
+            },
+        )
+        .await
+    }
+
+    fn uncommitted_table_alters(&self) -> Vec<GlobalId> {
+        self.table_layouts
+            .iter()
+            .filter(|(_, layout)| layout.pending.is_some())
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError> {
         self.exports
             .get(&id)
@@ -1328,6 +1494,29 @@ where
             }
         }
 
+        // Tables whose columns have been altered store rows differently from
+        // how they are read and written.
+        let commands = commands
+            .into_iter()
+            .map(|(id, updates)| {
+                let layout = self
+                    .collections
+                    .get(&id)
+                    .and_then(|c| c.collection_metadata.table_layout.as_ref());
+                let updates = match layout {
+                    Some(layout) => updates
+                        .into_iter()
+                        .map(|TimestamplessUpdate { row, diff }| TimestamplessUpdate {
+                            row: layout.write_row(&row),
+                            diff,
+                        })
+                        .collect(),
+                    None => updates,
+                };
+                (id, updates)
+            })
+            .collect();
+
         Ok(self
             .persist_table_worker
             .append(write_ts, advance_to, commands))
@@ -1348,58 +1537,13 @@ where
         id: GlobalId,
         as_of: Self::Timestamp,
     ) -> Result<Vec<(Row, Diff)>, StorageError> {
-        let metadata = &self.collection(id)?.collection_metadata;
-        let contents = match metadata.txns_shard.as_ref() {
-            None => {
-                // We're not using persist-txn for tables, so we can take a snapshot directly.
-                let mut read_handle = self.read_handle_for_snapshot(id).await?;
-                read_handle
-                    .snapshot_and_fetch(Antichain::from_elem(as_of))
-                    .await
-            }
-            Some(txns_id) => {
-                // We _are_ using persist-txn for tables. It advances the physical upper of the
-                // shard lazily, so we need to ask it for the snapshot to ensure the read is
-                // unblocked.
-                //
-                // Consider the following scenario:
-                // - Table A is written to via txns at time 5
-                // - Tables other than A are written to via txns consuming timestamps up to 10
-                // - We'd like to read A at 7
-                // - The application process of A's txn has advanced the upper to 5+1, but we need
-                //   it to be past 7, but the txns shard knows that (5,10) is empty of writes to A
-                // - This branch allows it to handle that advancing the physical upper of Table A to
-                //   10 (NB but only once we see it get past the write at 5!)
-                // - Then we can read it normally.
-                //
-                // TODO(txn): We do a series of snapshots at boot and then never again. It's
-                // wasteful to create this TxnsCache and then throw it away for each of them, but
-                // it's better than the alternative of keeping it alive for snapshot calls that will
-                // never come (worse, it's tricky to keep it making progress, which results in a
-                // stuck since). Replace this with the shared TxnsCache thing we'll have to do
-                // anyway for the dataflow operators.
-                let txns_read = self.txns.expect_enabled(txns_id);
-                txns_read.update_gt(as_of.clone()).await;
-                let data_snapshot = txns_read
-                    .data_snapshot(metadata.data_shard, as_of.clone())
-                    .await;
-                let mut read_handle = self.read_handle_for_snapshot(id).await?;
-                data_snapshot.snapshot_and_fetch(&mut read_handle).await
-            }
-        };
-        match contents {
-            Ok(contents) => {
-                let mut snapshot = Vec::with_capacity(contents.len());
-                for ((data, _), _, diff) in contents {
-                    // TODO(petrosagg): We should accumulate the errors too and let the user
-                    // interprret the result
-                    let row = data.expect("invalid protobuf data").0?;
-                    snapshot.push((row, diff));
-                }
-                Ok(snapshot)
+        let mut snapshot = self.snapshot_stored(id, as_of).await?;
+        if let Some(layout) = &self.collection(id)?.collection_metadata.table_layout {
+            for (row, _) in snapshot.iter_mut() {
+                *row = layout.read_row(row);
             }
-            Err(_) => Err(StorageError::ReadBeforeSince(id)),
         }
+        Ok(snapshot)
     }
 
     async fn snapshot_cursor(
@@ -1722,13 +1866,25 @@ where
                 METADATA_COLLECTION
                     .delete_keys(
                         &mut self.stash,
-                        ids.into_iter()
-                            .map(|id| RustType::into_proto(&id))
-                            .collect(),
+                        ids.iter().map(RustType::into_proto).collect(),
                     )
                     .await
                     .expect("stash operation must succeed");
 
+                let mut altered_tables = BTreeSet::new();
+                for id in ids.iter() {
+                    self.pending_table_alters.remove(id);
+                    if self.table_layouts.remove(id).is_some() {
+                        altered_tables.insert(id.into_proto());
+                    }
+                }
+                if !altered_tables.is_empty() {
+                    TABLE_LAYOUT_COLLECTION
+                        .delete_keys(&mut self.stash, altered_tables)
+                        .await
+                        .expect("stash operation must succeed");
+                }
+
                 if self.config.finalize_shards {
                     info!("triggering shard finalization due to dropped storage object");
                     self.finalize_shards().await;
@@ -2243,17 +2399,20 @@ where
                         metadata_collection,
                         metadata_export,
                         persist_txns_shard,
+                        table_layouts,
                         shard_finalization,
                     ) = futures::join!(
                         maybe_get_init_batch(&tx, &METADATA_COLLECTION),
                         maybe_get_init_batch(&tx, &METADATA_EXPORT),
                         maybe_get_init_batch(&tx, &PERSIST_TXNS_SHARD),
+                        maybe_get_init_batch(&tx, &TABLE_LAYOUT_COLLECTION),
                         maybe_get_init_batch(&tx, &command_wals::SHARD_FINALIZATION),
                     );
                     let batches: Vec<AppendBatch> = [
                         metadata_collection,
                         metadata_export,
                         persist_txns_shard,
+                        table_layouts,
                         shard_finalization,
                     ]
                     .into_iter()
@@ -2266,6 +2425,17 @@ where
             .await
             .expect("stash operation must succeed");
 
+        let table_layouts = TABLE_LAYOUT_COLLECTION
+            .peek_one(&mut stash)
+            .await
+            .expect("could not get table layouts")
+            .into_iter()
+            .map(|(id, layout)| {
+                Ok::<_, StashError>((id.into_rust()?, DurableTableLayout::from_stash(layout)?))
+            })
+            .collect::<Result<_, _>>()
+            .expect("table layouts must be valid");
+
         let txns_client = persist_clients
             .open(persist_location.clone())
             .await
@@ -2334,6 +2504,8 @@ where
             txns_init_run: false,
             stashed_response: None,
             pending_compaction_commands: vec![],
+            table_layouts,
+            pending_table_alters: BTreeMap::new(),
             collection_manager,
             collection_status_manager,
             introspection_ids,
@@ -2865,7 +3037,7 @@ where
             c.collection_metadata.data_shard = data_shard;
 
             let collection_desc = c.description.clone();
-            let relation_desc = c.collection_metadata.relation_desc.clone();
+            let relation_desc = c.collection_metadata.stored_desc().clone();
 
             // This will halt! if any of the handles cannot be acquired
             // because we're not the leader anymore. But that's fine, we
@@ -3236,6 +3408,131 @@ where
         })
     }
 
+    /// Returns the layout of the rows stored in the shard of table `id`, whose
+    /// columns are `desc`, or None if they are stored as they are read.
+    fn table_layout(
+        &self,
+        id: GlobalId,
+        desc: &RelationDesc,
+    ) -> Result<Option<TableLayout>, StorageError> {
+        let Some(DurableTableLayout { layout, pending }) = self.table_layouts.get(&id) else {
+            return Ok(None);
+        };
+        let layout = match pending {
+            Some(pending) if pending.matches(desc) => pending,
+            _ if layout.matches(desc) => layout,
+            _ => return Err(StorageError::InvalidAlter { id }),
+        };
+        Ok(Some(layout.clone()).filter(|layout| *layout != TableLayout::new(desc)))
+    }
+
+    /// Decides the prepared alterations of the columns of any of the tables
+    /// among `collections`, which must be described with the columns that the
+    /// catalog records for them. An alteration took place iff the table's
+    /// columns match it; those that did not are durably forgotten, while those
+    /// that did remain to be committed.
+    async fn resolve_table_alters(
+        &mut self,
+        collections: &[(GlobalId, CollectionDescription<T>)],
+    ) -> Result<(), StorageError> {
+        let aborted: Vec<_> = collections
+            .iter()
+            .filter_map(|(id, description)| {
+                let DurableTableLayout { layout, pending } = self.table_layouts.get(id)?;
+                match pending {
+                    Some(pending) if !pending.matches(&description.desc) => Some((
+                        *id,
+                        DurableTableLayout {
+                            layout: layout.clone(),
+                            pending: None,
+                        },
+                    )),
+                    _ => None,
+                }
+            })
+            .collect();
+        for (id, layout) in aborted {
+            info!("forgetting alteration of the columns of table {id}, which did not take place");
+            self.store_table_layout(id, layout).await?;
+        }
+        Ok(())
+    }
+
+    /// Durably stores `layout` as the layout of table `id`.
+    async fn store_table_layout(
+        &mut self,
+        id: GlobalId,
+        layout: DurableTableLayout,
+    ) -> Result<(), StorageError> {
+        TABLE_LAYOUT_COLLECTION
+            .upsert(&mut self.stash, [(id.into_proto(), layout.into_stash())])
+            .await?;
+        self.table_layouts.insert(id, layout);
+        Ok(())
+    }
+
+    /// Returns the contents of collection `id` at `as_of` as stored in its
+    /// shard, i.e. without adapting the rows of altered tables to their
+    /// columns.
+    async fn snapshot_stored(
+        &self,
+        id: GlobalId,
+        as_of: T,
+    ) -> Result<Vec<(Row, Diff)>, StorageError> {
+        let metadata = &self.collection(id)?.collection_metadata;
+        let contents = match metadata.txns_shard.as_ref() {
+            None => {
+                // We're not using persist-txn for tables, so we can take a snapshot directly.
+                let mut read_handle = self.read_handle_for_snapshot(id).await?;
+                read_handle
+                    .snapshot_and_fetch(Antichain::from_elem(as_of))
+                    .await
+            }
+            Some(txns_id) => {
+                // We _are_ using persist-txn for tables. It advances the physical upper of the
+                // shard lazily, so we need to ask it for the snapshot to ensure the read is
+                // unblocked.
+                //
+                // Consider the following scenario:
+                // - Table A is written to via txns at time 5
+                // - Tables other than A are written to via txns consuming timestamps up to 10
+                // - We'd like to read A at 7
+                // - The application process of A's txn has advanced the upper to 5+1, but we need
+                //   it to be past 7, but the txns shard knows that (5,10) is empty of writes to A
+                // - This branch allows it to handle that advancing the physical upper of Table A to
+                //   10 (NB but only once we see it get past the write at 5!)
+                // - Then we can read it normally.
+                //
+                // TODO(txn): We do a series of snapshots at boot and then never again. It's
+                // wasteful to create this TxnsCache and then throw it away for each of them, but
+                // it's better than the alternative of keeping it alive for snapshot calls that will
+                // never come (worse, it's tricky to keep it making progress, which results in a
+                // stuck since). Replace this with the shared TxnsCache thing we'll have to do
+                // anyway for the dataflow operators.
+                let txns_read = self.txns.expect_enabled(txns_id);
+                txns_read.update_gt(as_of.clone()).await;
+                let data_snapshot = txns_read
+                    .data_snapshot(metadata.data_shard, as_of.clone())
+                    .await;
+                let mut read_handle = self.read_handle_for_snapshot(id).await?;
+                data_snapshot.snapshot_and_fetch(&mut read_handle).await
+            }
+        };
+        match contents {
+            Ok(contents) => {
+                let mut snapshot = Vec::with_capacity(contents.len());
+                for ((data, _), _, diff) in contents {
+                    // TODO(petrosagg): We should accumulate the errors too and let the user
+                    // interprret the result
+                    let row = data.expect("invalid protobuf data").0?;
+                    snapshot.push((row, diff));
+                }
+                Ok(snapshot)
+            }
+            Err(_) => Err(StorageError::ReadBeforeSince(id)),
+        }
+    }
+
     async fn read_handle_for_snapshot(
         &self,
         id: GlobalId,
@@ -3255,7 +3552,7 @@ where
         let read_handle = persist_client
             .open_leased_reader::<SourceData, (), _, _>(
                 metadata.data_shard,
-                Arc::new(metadata.relation_desc.clone()),
+                Arc::new(metadata.stored_desc().clone()),
                 Arc::new(UnitSchema),
                 Diagnostics {
                     shard_name: id.to_string(),
@@ -421,7 +421,10 @@ async fn legacy_table_worker<T: Timestamp + Lattice + Codec64 + TimestampManipul
                         }
                     }
                 }
-                PersistTableWriteCmd::Update(id, write_handle) => {
+                PersistTableWriteCmd::Update(id, mut write_handle) => {
+                    // The handle may have been opened before appends that
+                    // were queued ahead of this command were applied.
+                    write_handle.fetch_recent_upper().await;
                     write_handles.insert(id, write_handle).expect(
                         "PersistTableWriteCmd::Update only valid for updating extant write handles",
                     );
@@ -527,8 +530,9 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
                         .instrument(span)
                         .await
                 }
-                PersistTableWriteCmd::Update(_id, _write_handle) => {
-                    unimplemented!("TODO: Support migrations on persist-txn backed collections")
+                PersistTableWriteCmd::Update(id, write_handle) => {
+                    let _guard = span.enter();
+                    self.update(id, write_handle)
                 }
                 PersistTableWriteCmd::DropHandle(id) => self.drop_handle(id).instrument(span).await,
                 PersistTableWriteCmd::Append {
@@ -594,6 +598,20 @@ impl<T: Timestamp + Lattice + Codec64 + TimestampManipulation> TxnsTableWorker<T
         }
     }
 
+    fn update(&mut self, id: GlobalId, write_handle: WriteHandle<SourceData, (), T, i64>) {
+        debug!(
+            "tables update {} {:.9}",
+            id,
+            write_handle.shard_id().to_string()
+        );
+        let data_id = self
+            .write_handles
+            .get(&id)
+            .expect("PersistTableWriteCmd::Update only valid for updating extant write handles");
+        assert_eq!(*data_id, write_handle.shard_id());
+        self.txns.update_write(write_handle);
+    }
+
     async fn drop_handle(&mut self, id: GlobalId) {
         debug!("tables drop {}", id);
         // n.b. this should only remove the handle from the persist
@@ -30,9 +30,9 @@ use mz_persist_txn::operator::txns_progress;
 use mz_persist_types::codec_impls::UnitSchema;
 use mz_persist_types::Codec64;
 use mz_repr::{Datum, DatumVec, Diff, GlobalId, RelationType, Row, RowArena, Timestamp};
-use mz_storage_types::controller::{CollectionMetadata, TxnsCodecRow};
+use mz_storage_types::controller::{CollectionMetadata, TableLayout, TxnsCodecRow};
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sources::SourceData;
+use mz_storage_types::sources::{adapt_row_arity, SourceData};
 use mz_storage_types::stats::RelationPartStats;
 use mz_timely_util::buffer::ConsolidateBuffer;
 use mz_timely_util::builder_async::{
@@ -233,7 +233,7 @@ where
                 .expect("as_of is provided for table sources")
                 .into_option()
                 .expect("shard is not closed"),
-            Arc::new(metadata.relation_desc),
+            Arc::new(metadata.stored_desc().clone()),
             Arc::new(UnitSchema),
         ),
         None => (stream, vec![]),
@@ -283,7 +283,15 @@ where
     let cfg = persist_clients.cfg().clone();
     let name = source_id.to_string();
     let desc = metadata.relation_desc.clone();
-    let filter_plan = map_filter_project.as_ref().map(|p| (*p).clone());
+    let arity = desc.arity();
+    let layout = metadata.table_layout.clone();
+    // The shard's stats describe its stored columns, which differ from the
+    // collection's once a table's columns have been altered.
+    let filter_plan = match layout {
+        Some(_) => None,
+        None => map_filter_project.as_ref().map(|p| (*p).clone()),
+    };
+    let stored_desc = metadata.stored_desc().clone();
 
     let desc_transformer = match flow_control {
         Some(flow_control) => Some(move |mut scope: _, descs: &Stream<_, _>, chosen_worker| {
@@ -320,7 +328,7 @@ where
         as_of,
         until.clone(),
         desc_transformer,
-        Arc::new(metadata.relation_desc),
+        Arc::new(stored_desc),
         Arc::new(UnitSchema),
         move |stats, frontier| {
             let time_range = if let Some(lower) = frontier.as_option().copied() {
@@ -337,7 +345,15 @@ where
         },
         listen_sleep,
     );
-    let rows = decode_and_mfp(cfg, &fetched, &name, until, map_filter_project);
+    let rows = decode_and_mfp(
+        cfg,
+        &fetched,
+        &name,
+        arity,
+        layout,
+        until,
+        map_filter_project,
+    );
     (rows, token)
 }
 
@@ -368,6 +384,8 @@ pub fn decode_and_mfp<G>(
     cfg: PersistConfig,
     fetched: &Stream<G, FetchedPart<SourceData, (), Timestamp, Diff>>,
     name: &str,
+    arity: usize,
+    layout: Option<TableLayout>,
     until: Antichain<Timestamp>,
     mut map_filter_project: Option<&mut MfpPlan>,
 ) -> Stream<G, (Result<Row, DataflowError>, G::Timestamp, Diff)>
@@ -427,6 +445,8 @@ where
                     &name,
                     start_time,
                     yield_fn,
+                    arity,
+                    layout.as_ref(),
                     &until,
                     map_filter_project.as_ref(),
                     &mut datum_vec,
@@ -463,6 +483,8 @@ impl PendingWork {
         name: &str,
         start_time: Instant,
         yield_fn: YFn,
+        arity: usize,
+        layout: Option<&TableLayout>,
         until: &Antichain<Timestamp>,
         map_filter_project: Option<&MfpPlan>,
         datum_vec: &mut DatumVec,
@@ -504,6 +526,12 @@ impl PendingWork {
                         *work += 1;
                         let arena = mz_repr::RowArena::new();
                         let mut datums_local = datum_vec.borrow_with(&row);
+                        // Rows stored in the shard of a table whose columns
+                        // have been altered differ from the table's columns.
+                        match layout {
+                            Some(layout) => layout.read_datums(&mut datums_local),
+                            None => datums_local.resize(arity, Datum::Null),
+                        }
                         for result in mfp.evaluate(
                             &mut datums_local,
                             &arena,
@@ -539,6 +567,10 @@ impl PendingWork {
                             }
                         }
                     } else {
+                        let row = match layout {
+                            Some(layout) => layout.read_row(&row),
+                            None => adapt_row_arity(row, arity),
+                        };
                         let mut emit_time = *self.capability.time();
                         emit_time.0 = time;
                         output.give_at(&self.capability, (Ok(row), emit_time, diff));
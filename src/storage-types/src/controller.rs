@@ -18,10 +18,13 @@ use mz_persist_types::codec_impls::UnitSchema;
 use mz_persist_types::columnar::Data;
 use mz_persist_types::dyn_struct::DynStruct;
 use mz_persist_types::stats::StructStats;
-use mz_proto::{IntoRustIfSome, RustType, TryFromProtoError};
-use mz_repr::{Datum, GlobalId, RelationDesc, Row, ScalarType};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use mz_repr::{
+    ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, Row, ScalarType,
+};
 use mz_stash_types::StashError;
 use proptest_derive::Arbitrary;
+use prost::Message;
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
@@ -47,6 +50,20 @@ pub struct CollectionMetadata {
     /// The shard id of the persist-txn shard, if `self.data_shard` is managed
     /// by the persist-txn system, or None if it's not.
     pub txns_shard: Option<ShardId>,
+    /// How the rows stored in `self.data_shard` map onto `self.relation_desc`,
+    /// if this is a table whose columns have been altered, or None if they
+    /// are stored as they are read.
+    pub table_layout: Option<TableLayout>,
+}
+
+impl CollectionMetadata {
+    /// Returns the `RelationDesc` of the rows stored in `self.data_shard`.
+    pub fn stored_desc(&self) -> &RelationDesc {
+        match &self.table_layout {
+            Some(layout) => &layout.stored_desc,
+            None => &self.relation_desc,
+        }
+    }
 }
 
 impl crate::AlterCompatible for CollectionMetadata {
@@ -69,6 +86,7 @@ impl crate::AlterCompatible for CollectionMetadata {
             status_shard,
             relation_desc,
             txns_shard,
+            table_layout,
         } = self;
 
         let compatibility_checks = [
@@ -77,6 +95,7 @@ impl crate::AlterCompatible for CollectionMetadata {
             (status_shard == &other.status_shard, "status_shard"),
             (relation_desc == &other.relation_desc, "relation_desc"),
             (txns_shard == &other.txns_shard, "txns_shard"),
+            (table_layout == &other.table_layout, "table_layout"),
         ];
 
         for (compatible, field) in compatibility_checks {
@@ -105,6 +124,7 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
             status_shard: self.status_shard.map(|s| s.to_string()),
             relation_desc: Some(self.relation_desc.into_proto()),
             txns_shard: self.txns_shard.map(|x| x.to_string()),
+            table_layout: self.table_layout.into_proto(),
         }
     }
 
@@ -133,6 +153,169 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
                 .txns_shard
                 .map(|s| s.parse().map_err(TryFromProtoError::InvalidShardId))
                 .transpose()?,
+            table_layout: value.table_layout.into_rust()?,
+        })
+    }
+}
+
+/// How the rows stored in a table's data shard map onto the table's columns.
+///
+/// Altering a table's columns does not change how the rows already in its
+/// shard are stored. Instead, the stored columns only ever grow: a column that
+/// is dropped from the table stays among them, and holds nulls in the rows
+/// written since, while a column that is added to the table is appended to
+/// them. Rows written before a column was added end before it, and read it as
+/// the value that it was added with.
+#[derive(Arbitrary, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableLayout {
+    /// The columns of the rows stored in the table's shard. Columns that have
+    /// been dropped from the table are renamed and nullable.
+    pub stored_desc: RelationDesc,
+    /// For each of the table's columns, in order, its position among the
+    /// stored columns.
+    pub positions: Vec<usize>,
+    /// For each stored column, the value that it has in rows written before it
+    /// was added to the table.
+    pub missing: Row,
+}
+
+impl TableLayout {
+    /// Returns the layout of a table with columns `desc` that have never been
+    /// altered, whose rows are stored as they are read.
+    pub fn new(desc: &RelationDesc) -> Self {
+        TableLayout {
+            stored_desc: desc.clone(),
+            positions: (0..desc.arity()).collect(),
+            missing: Row::pack(std::iter::repeat(Datum::Null).take(desc.arity())),
+        }
+    }
+
+    /// Reports whether this is the layout of a table with columns `desc`.
+    pub fn matches(&self, desc: &RelationDesc) -> bool {
+        self.positions.len() == desc.arity()
+            && self
+                .positions
+                .iter()
+                .zip(desc.iter())
+                .all(|(position, (name, typ))| {
+                    self.stored_desc.get_name(*position) == name
+                        && &self.stored_desc.typ().column_types[*position] == typ
+                })
+    }
+
+    /// Returns the layout of the table once its columns are altered to `desc`.
+    ///
+    /// `desc` must consist of the table's columns, less any that are dropped,
+    /// followed by any that are added, whose values in the rows already in the
+    /// table are given by `missing`.
+    pub fn alter(&self, desc: &RelationDesc, missing: &Row) -> Result<Self, String> {
+        let mut names: Vec<ColumnName> = self.stored_desc.iter_names().cloned().collect();
+        let mut types: Vec<ColumnType> = self.stored_desc.iter_types().cloned().collect();
+        let mut stored_missing: Vec<Datum> = self.missing.iter().collect();
+        let mut added_missing = missing.iter();
+        let mut positions = Vec::with_capacity(desc.arity());
+        for (name, typ) in desc.iter() {
+            let kept = self
+                .positions
+                .iter()
+                .find(|position| names[**position] == *name);
+            match kept {
+                Some(position) => {
+                    if types[*position] != *typ || positions.last() >= Some(position) {
+                        return Err(format!("column {name} cannot be altered in place"));
+                    }
+                    positions.push(*position);
+                }
+                None => {
+                    let Some(datum) = added_missing.next() else {
+                        return Err(format!("no value for added column {name}"));
+                    };
+                    // A dropped column may have had the added column's name.
+                    if let Some(dropped) = names.iter().position(|n| n == name) {
+                        names[dropped] = Self::dropped_name(&names, dropped);
+                    }
+                    positions.push(names.len());
+                    names.push(name.clone());
+                    types.push(typ.clone());
+                    stored_missing.push(datum);
+                }
+            }
+        }
+        if added_missing.next().is_some() {
+            return Err("more values than added columns".into());
+        }
+        for dropped in self.positions.iter().filter(|p| !positions.contains(p)) {
+            names[*dropped] = Self::dropped_name(&names, *dropped);
+            types[*dropped].nullable = true;
+        }
+
+        Ok(TableLayout {
+            stored_desc: RelationDesc::new(RelationType::new(types), names),
+            positions,
+            missing: Row::pack_slice(&stored_missing),
+        })
+    }
+
+    /// Returns a name for the dropped column at `position` that no other stored
+    /// column has, so that they remain distinguishable in the shard's stats.
+    fn dropped_name(names: &[ColumnName], position: usize) -> ColumnName {
+        (0..)
+            .map(|i| ColumnName::from(format!("?dropped_column_{position}_{i}?")))
+            .find(|name| !names.contains(name))
+            .expect("names are finite")
+    }
+
+    /// Adapts the datums of a row stored in the table's shard, in place, to the
+    /// table's columns.
+    pub fn read_datums<'a>(&'a self, datums: &mut Vec<Datum<'a>>) {
+        // Rows written once later columns were added are longer than the
+        // stored columns of earlier layouts, which still-running readers use.
+        datums.truncate(self.stored_desc.arity());
+        let len = datums.len();
+        datums.extend(self.missing.iter().skip(len));
+        for (i, position) in self.positions.iter().enumerate() {
+            datums[i] = datums[*position];
+        }
+        datums.truncate(self.positions.len());
+    }
+
+    /// Returns the row of the table's columns that `row`, a row stored in the
+    /// table's shard, represents.
+    pub fn read_row(&self, row: &Row) -> Row {
+        let mut datums: Vec<_> = row.iter().collect();
+        self.read_datums(&mut datums);
+        Row::pack_slice(&datums)
+    }
+
+    /// Returns the row to store in the table's shard for `row`, a row of the
+    /// table's columns.
+    pub fn write_row(&self, row: &Row) -> Row {
+        let mut datums = vec![Datum::Null; self.stored_desc.arity()];
+        for (position, datum) in self.positions.iter().zip(row.iter()) {
+            datums[*position] = datum;
+        }
+        Row::pack_slice(&datums)
+    }
+}
+
+impl RustType<ProtoTableLayout> for TableLayout {
+    fn into_proto(&self) -> ProtoTableLayout {
+        ProtoTableLayout {
+            stored_desc: Some(self.stored_desc.into_proto()),
+            positions: self.positions.into_proto(),
+            missing: Some(self.missing.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoTableLayout) -> Result<Self, TryFromProtoError> {
+        Ok(TableLayout {
+            stored_desc: proto
+                .stored_desc
+                .into_rust_if_some("ProtoTableLayout::stored_desc")?,
+            positions: proto.positions.into_rust()?,
+            missing: proto
+                .missing
+                .into_rust_if_some("ProtoTableLayout::missing")?,
         })
     }
 }
@@ -143,6 +326,45 @@ pub struct DurableCollectionMetadata {
     pub data_shard: ShardId,
 }
 
+/// The [`TableLayout`] of a table whose columns have been altered, as durably
+/// stored.
+#[derive(Arbitrary, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DurableTableLayout {
+    /// The table's layout.
+    pub layout: TableLayout,
+    /// The layout that an in-progress alteration of the table's columns changes
+    /// it to, if any.
+    ///
+    /// Whichever of `layout` and `pending` matches the table's columns in the
+    /// catalog is in effect. If that is `pending`, the rows stored in the
+    /// table's shard might not yet have been rewritten to it.
+    pub pending: Option<TableLayout>,
+}
+
+impl DurableTableLayout {
+    /// Returns the form in which `self` is stored in the stash.
+    pub fn into_stash(&self) -> crate::collections::DurableTableLayout {
+        crate::collections::DurableTableLayout {
+            layout: self.layout.into_proto().encode_to_vec(),
+            pending: self
+                .pending
+                .as_ref()
+                .map(|pending| pending.into_proto().encode_to_vec()),
+        }
+    }
+
+    /// Returns the layout stored in the stash as `stashed`.
+    pub fn from_stash(stashed: crate::collections::DurableTableLayout) -> Result<Self, StashError> {
+        let decode = |bytes: &[u8]| -> Result<TableLayout, StashError> {
+            Ok(ProtoTableLayout::decode(bytes)?.into_rust()?)
+        };
+        Ok(DurableTableLayout {
+            layout: decode(&stashed.layout)?,
+            pending: stashed.pending.as_deref().map(decode).transpose()?,
+        })
+    }
+}
+
 impl RustType<ProtoDurableCollectionMetadata> for DurableCollectionMetadata {
     fn into_proto(&self) -> ProtoDurableCollectionMetadata {
         ProtoDurableCollectionMetadata {
@@ -2761,6 +2761,26 @@ impl RustType<ProtoSourceData> for SourceData {
     }
 }
 
+/// Pads `row` with trailing nulls, or truncates it, so that it has exactly
+/// `arity` columns.
+///
+/// The shard of a table that has had columns added to or dropped from its end
+/// may contain rows written under an earlier version of the table's schema.
+/// Readers use this to reinterpret such rows under the schema they read with.
+pub fn adapt_row_arity(row: Row, arity: usize) -> Row {
+    let len = row.iter().count();
+    if len == arity {
+        return row;
+    }
+    let mut adapted = Row::with_capacity(row.byte_len());
+    let mut packer = adapted.packer();
+    packer.extend(row.iter().take(arity));
+    for _ in len..arity {
+        packer.push(Datum::Null);
+    }
+    adapted
+}
+
 impl Codec for SourceData {
     type Schema = RelationDesc;
 
@@ -2799,8 +2819,11 @@ impl<'a> PartEncoder<'a, SourceData> for SourceDataEncoder<'a> {
             Ok(row) => {
                 self.ok_validity.push(true);
                 self.ok.inc_len();
-                for (encoder, datum) in self.ok.col_encoders().iter_mut().zip(row.iter()) {
-                    encoder.encode(datum);
+                // A table's shard can contain rows written before trailing
+                // columns were added to its schema, which encode as nulls.
+                let mut datums = row.iter();
+                for encoder in self.ok.col_encoders() {
+                    encoder.encode(datums.next().unwrap_or(Datum::Null));
                 }
                 ColumnPush::<Option<Vec<u8>>>::push(self.err, None);
             }
@@ -694,6 +694,7 @@ mod tests {
             status_shard: None,
             relation_desc: RelationDesc::empty(),
             txns_shard: None,
+            table_layout: None,
         };
 
         let clock_stream = futures::stream::iter((0..).map(|seconds| {
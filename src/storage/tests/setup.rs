@@ -254,6 +254,7 @@ where
                 // schema.
                 relation_desc: RelationDesc::empty(),
                 txns_shard: None,
+                table_layout: None,
             };
             let data_shard = collection_metadata.data_shard.clone();
             let id = GlobalId::User(1);
@@ -65,6 +65,9 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &State) -> Result<ControlFlow,
         | CreateTable(_)
         | CreateIndex(_)
         | CreateType(_)
+        | CreateFunction(_)
+        | CreatePolicy(_)
+        | CreateSubscription(_)
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)